@@ -0,0 +1,108 @@
+//! Measures the construction of primitive readers for a document with many
+//! sparse accessors, with and without an `AccessorLayoutCache`.
+//!
+//! Run with `cargo bench --bench accessor_cache`.
+
+// Benchmarks are not held to the minimum supported Rust version.
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use gltf::accessor::AccessorLayoutCache;
+use gltf::{buffer, json, Document};
+
+/// The number of meshes, each with a sparse `POSITION` accessor.
+const MESHES: usize = 1000;
+
+/// The number of vertices of every mesh.
+const VERTICES: usize = 1024;
+
+/// The number of vertices of every mesh that are substituted.
+const SPARSE: usize = 256;
+
+/// The number of times every reader is constructed.
+const FRAMES: usize = 100;
+
+/// Builds the document, whose sparse accessors share their indices and
+/// values.
+fn document() -> (Document, Vec<buffer::Data>) {
+    let mut data = Vec::new();
+    for index in 0..SPARSE {
+        data.extend_from_slice(&(index as u16 * 4).to_le_bytes());
+    }
+    for index in 0..SPARSE * 3 {
+        data.extend_from_slice(&(index as f32).to_le_bytes());
+    }
+    let meshes: Vec<_> = (0..MESHES)
+        .map(|mesh| {
+            format!(
+                r#"{{"primitives": [{{"attributes": {{"POSITION": {}}}}}]}}"#,
+                mesh
+            )
+        })
+        .collect();
+    let accessor = format!(
+        r#"{{"componentType": 5126, "count": {}, "type": "VEC3",
+            "min": [0, 0, 0], "max": [1, 1, 1],
+            "sparse": {{"count": {}, "indices": {{"bufferView": 0, "componentType": 5123}},
+                        "values": {{"bufferView": 1}}}}}}"#,
+        VERTICES, SPARSE
+    );
+    let gltf = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "meshes": [{}],
+            "accessors": [{}],
+            "bufferViews": [
+                {{"buffer": 0, "byteLength": {}}},
+                {{"buffer": 0, "byteOffset": {}, "byteLength": {}}}
+            ],
+            "buffers": [{{"byteLength": {}}}]
+        }}"#,
+        meshes.join(", "),
+        vec![accessor; MESHES].join(", "),
+        SPARSE * 2,
+        SPARSE * 2,
+        SPARSE * 12,
+        data.len()
+    );
+    let root = json::Root::from_slice(gltf.as_bytes()).unwrap();
+    (Document::from_json(root).unwrap(), vec![buffer::Data(data)])
+}
+
+/// Constructs a reader of the positions of every primitive, every frame.
+fn construct(
+    document: &Document,
+    buffers: &[buffer::Data],
+    cache: Option<&AccessorLayoutCache>,
+) -> Duration {
+    let get = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        for mesh in document.meshes() {
+            for primitive in mesh.primitives() {
+                let reader = match cache {
+                    Some(cache) => primitive.reader_cached(cache, get),
+                    None => primitive.reader(get),
+                };
+                black_box(reader.read_positions());
+            }
+        }
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let (document, buffers) = document();
+    let readers = (MESHES * FRAMES) as u32;
+    let uncached = construct(&document, &buffers, None);
+    let cache = AccessorLayoutCache::new(&document);
+    let cached = construct(&document, &buffers, Some(&cache));
+    println!("uncached: {:?} per reader", uncached / readers);
+    println!("cached:   {:?} per reader", cached / readers);
+    println!(
+        "speedup:  {:.1}x",
+        uncached.as_secs_f64() / cached.as_secs_f64()
+    );
+}
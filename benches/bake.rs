@@ -0,0 +1,68 @@
+//! Compares loading the benchmark corpus from JSON with loading it from
+//! documents baked by `Document::bake`.
+//!
+//! Run with `cargo bench --bench bake --features bake`.
+
+// Benchmarks are not held to the minimum supported Rust version.
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use gltf::{Document, Gltf};
+
+/// The glTF files of the corpus.
+const CORPUS: &[&str] = &[
+    "examples/Box.gltf",
+    "examples/Lantern.gltf",
+    "tests/box_sparse.gltf",
+    "tests/minimal_accessor_min_max.gltf",
+    "tests/number_syntax.gltf",
+];
+
+/// The number of times every file is loaded in a batch.
+const ROUNDS: usize = 2000;
+
+/// The number of batches, of which the fastest is kept.
+const BATCHES: usize = 5;
+
+fn time(f: impl Fn()) -> Duration {
+    (0..BATCHES)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..ROUNDS {
+                f();
+            }
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    let sources: Vec<Vec<u8>> = CORPUS
+        .iter()
+        .map(|path| std::fs::read(path).unwrap())
+        .collect();
+    let baked: Vec<Vec<u8>> = sources
+        .iter()
+        .map(|source| Gltf::from_slice(source).unwrap().document.bake())
+        .collect();
+    let json = time(|| {
+        for source in &sources {
+            black_box(Gltf::from_slice(source).unwrap());
+        }
+    });
+    let from_baked = time(|| {
+        for baked in &baked {
+            black_box(Document::from_baked(baked).unwrap());
+        }
+    });
+    let loads = (ROUNDS * CORPUS.len()) as u32;
+    println!("json:  {:?} per document", json / loads);
+    println!("baked: {:?} per document", from_baked / loads);
+    println!(
+        "speedup: {:.1}x",
+        json.as_secs_f64() / from_baked.as_secs_f64()
+    );
+}
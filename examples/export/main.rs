@@ -189,6 +189,9 @@ fn export(output: Output) {
                         .expect("file size exceeds binary glTF limit"),
                 },
                 bin: Some(Cow::Owned(to_padded_byte_vector(triangle_vertices))),
+                extra_chunks: Vec::new(),
+                length_read: None,
+                chunk_alignment: gltf::binary::ChunkAlignment::Exact,
                 json: Cow::Owned(json_string.into_bytes()),
             };
             let writer = std::fs::File::create("triangle.glb").expect("I/O error");
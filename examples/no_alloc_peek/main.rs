@@ -0,0 +1,78 @@
+//! Locates the chunks of a GLB file in memory without allocating.
+//!
+//! On a host, the file named on the command line is read and its layout is
+//! printed. On bare-metal targets such as `thumbv7em-none-eabihf`, which have
+//! no file system, the example instead exports a function that finds the BIN
+//! chunk of a GLB file in flash. Its allocator fails every allocation, which
+//! the layout API never makes.
+
+#![cfg_attr(target_os = "none", no_std, no_main)]
+
+use gltf::binary::ChunkIter;
+use gltf::GlbLayout;
+
+/// Returns the chunk layout of a glTF 2.0 GLB file, reading the chunks in
+/// place.
+fn peek(data: &[u8]) -> Option<GlbLayout> {
+    let layout = ChunkIter::new(data).ok()?.layout().ok()?;
+    Some(layout).filter(|layout| layout.header.version == 2)
+}
+
+#[cfg(target_os = "none")]
+mod bare_metal {
+    use core::alloc::{GlobalAlloc, Layout};
+    use core::panic::PanicInfo;
+
+    /// An allocator without memory.
+    struct NoAlloc;
+
+    unsafe impl GlobalAlloc for NoAlloc {
+        unsafe fn alloc(&self, _: Layout) -> *mut u8 {
+            core::ptr::null_mut()
+        }
+
+        unsafe fn dealloc(&self, _: *mut u8, _: Layout) {}
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: NoAlloc = NoAlloc;
+
+    /// Returns the offset of the BIN chunk data of the GLB file at `data`, or
+    /// `usize::MAX` if it is not a GLB file or has no BIN chunk.
+    ///
+    /// # Safety
+    ///
+    /// `data` must point to `length` readable bytes.
+    #[no_mangle]
+    pub unsafe extern "C" fn glb_bin_offset(data: *const u8, length: usize) -> usize {
+        let data = core::slice::from_raw_parts(data, length);
+        super::peek(data)
+            .and_then(|layout| layout.bin)
+            .map_or(usize::MAX, |bin| bin.start)
+    }
+
+    #[panic_handler]
+    fn panic(_: &PanicInfo) -> ! {
+        loop {}
+    }
+}
+
+#[cfg(not(target_os = "none"))]
+fn main() {
+    if let Some(path) = std::env::args().nth(1) {
+        let data = std::fs::read(&path).expect("runtime error");
+        match peek(&data) {
+            Some(layout) => {
+                println!("container version {}", layout.header.version);
+                println!("JSON chunk at {:?}", layout.json);
+                match layout.bin {
+                    Some(bin) => println!("BIN chunk at {:?}", bin),
+                    None => println!("no BIN chunk"),
+                }
+            }
+            None => println!("{} is not a GLB file", path),
+        }
+    } else {
+        println!("usage: no_alloc_peek <FILE>");
+    }
+}
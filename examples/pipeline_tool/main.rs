@@ -0,0 +1,80 @@
+//! Optimizes a GLB file for delivery.
+//!
+//! Unused entities are pruned, duplicate accessors are merged and vertex
+//! attributes are quantized, then the result is pruned again to drop the
+//! replaced float data and written as a new GLB file.
+
+use std::borrow::Cow;
+use std::boxed::Box;
+use std::error::Error as StdError;
+use std::fs;
+
+use gltf::binary::Header;
+use gltf::transform::{self, QuantizeOptions};
+use gltf::{buffer, Glb, Gltf};
+
+fn summary(root: &gltf::json::Root, buffers: &[buffer::Data]) -> String {
+    let bytes: usize = buffers.iter().map(|data| data.len()).sum();
+    format!(
+        "{} accessors, {} buffer views, {} bytes of buffer data",
+        root.accessors.len(),
+        root.buffer_views.len(),
+        bytes
+    )
+}
+
+fn run(input: &str, output: &str) -> Result<(), Box<dyn StdError>> {
+    let data = fs::read(input)?;
+    let Gltf { document, blob } = Gltf::from_slice(&data)?;
+    let mut buffers: Vec<buffer::Data> = document
+        .buffers()
+        .map(|buffer| match buffer.source() {
+            buffer::Source::Bin => buffer::Data(blob.clone().unwrap_or_default()),
+            buffer::Source::Uri(_) => buffer::Data(Vec::new()),
+        })
+        .collect();
+    let mut root = document.into_json();
+    println!("input: {}", summary(&root, &buffers));
+
+    transform::prune(&mut root, &mut buffers);
+    println!("pruned: {}", summary(&root, &buffers));
+
+    let accessors = root.accessors.len();
+    transform::dedup_accessors(&mut root, &buffers);
+    println!("merged {} accessors", accessors - root.accessors.len());
+
+    let quantized = transform::quantize(&mut root, &mut buffers, QuantizeOptions::default());
+    for accessor in &quantized {
+        println!(
+            "quantized accessor {} ({:?}) with a maximum error of {}",
+            accessor.accessor, accessor.semantic, accessor.max_error
+        );
+    }
+
+    transform::prune(&mut root, &mut buffers);
+    println!("output: {}", summary(&root, &buffers));
+
+    let glb = Glb {
+        header: Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: Cow::Owned(root.to_vec()?),
+        bin: buffers.first().map(|data| Cow::Borrowed(&data[..])),
+        extra_chunks: Vec::new(),
+        length_read: None,
+        chunk_alignment: gltf::ChunkAlignment::Exact,
+    };
+    fs::write(output, glb.to_vec()?)?;
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if let [_, input, output] = &args[..] {
+        run(input, output).expect("runtime error");
+    } else {
+        println!("usage: pipeline_tool <INPUT> <OUTPUT>");
+    }
+}
@@ -0,0 +1,134 @@
+//! Prepares the meshes of a GLB file for uploading to a GPU.
+//!
+//! The vertex attributes of every primitive are interleaved into one vertex
+//! buffer and its indices are appended to one index buffer. Each primitive
+//! instanced by a node then becomes a draw descriptor, referring to ranges of
+//! the two buffers, that a renderer can record without touching the document.
+
+use std::boxed::Box;
+use std::collections::BTreeMap;
+use std::error::Error as StdError;
+use std::fs;
+
+use gltf::mesh::{self, Mode, VertexAttribute};
+use gltf::snapshot::SnapshotOptions;
+use gltf::{buffer, Gltf};
+
+/// The vertex layout and buffer ranges of a primitive.
+#[derive(Clone, Debug)]
+struct Upload {
+    attributes: Vec<VertexAttribute>,
+    stride: usize,
+    /// Byte offset of the first vertex in the vertex buffer.
+    vertex_offset: usize,
+    vertex_count: usize,
+    /// Offset of the first index in the index buffer.
+    first_index: usize,
+    /// The number of indices, or `None` for non-indexed primitives.
+    index_count: Option<usize>,
+}
+
+/// A draw of one primitive instanced by one node.
+#[derive(Clone, Debug)]
+struct DrawDescriptor {
+    node: usize,
+    mode: Mode,
+    material: Option<usize>,
+    upload: Upload,
+    world: [[f32; 4]; 4],
+}
+
+fn run(path: &str) -> Result<(), Box<dyn StdError>> {
+    let data = fs::read(path)?;
+    let gltf = Gltf::from_slice(&data)?;
+    let buffers: Vec<buffer::Data> = gltf
+        .buffers()
+        .map(|buffer| match buffer.source() {
+            buffer::Source::Bin => buffer::Data(gltf.blob.clone().unwrap_or_default()),
+            buffer::Source::Uri(_) => buffer::Data(Vec::new()),
+        })
+        .collect();
+    let options = SnapshotOptions { keyframes: false };
+    let scene = gltf.snapshot_with(&buffers, options)?;
+
+    let mut vertex_buffer = Vec::new();
+    let mut index_buffer: Vec<u32> = Vec::new();
+    let mut uploads = BTreeMap::new();
+    let mut draws = Vec::new();
+    for (index, node) in scene.nodes.iter().enumerate() {
+        let mesh = match node.mesh {
+            Some(mesh) => mesh,
+            None => continue,
+        };
+        for (primitive, owned) in scene.meshes[mesh].primitives.iter().enumerate() {
+            let upload = match uploads.get(&(mesh, primitive)) {
+                Some(upload) => Upload::clone(upload),
+                None => {
+                    let json = gltf.meshes().nth(mesh).unwrap();
+                    let primitive_ref = json.primitives().nth(primitive).unwrap();
+                    let interleaved = mesh::interleave(&primitive_ref, &buffers)?;
+                    let reader = primitive_ref.reader(|buffer| Some(&buffers[buffer.index()]));
+                    let first_index = index_buffer.len();
+                    let index_count = reader.read_indices().map(|indices| {
+                        index_buffer.extend(indices.into_u32());
+                        index_buffer.len() - first_index
+                    });
+                    let upload = Upload {
+                        attributes: interleaved.attributes,
+                        stride: interleaved.stride,
+                        vertex_offset: vertex_buffer.len(),
+                        vertex_count: interleaved.count,
+                        first_index,
+                        index_count,
+                    };
+                    vertex_buffer.extend_from_slice(&interleaved.data);
+                    uploads.insert((mesh, primitive), upload.clone());
+                    upload
+                }
+            };
+            draws.push(DrawDescriptor {
+                node: index,
+                mode: owned.mode,
+                material: owned.material,
+                upload,
+                world: node.world,
+            });
+        }
+    }
+
+    println!(
+        "vertex buffer: {} bytes, index buffer: {} indices",
+        vertex_buffer.len(),
+        index_buffer.len()
+    );
+    for draw in &draws {
+        let upload = &draw.upload;
+        println!(
+            "node {}: {:?} with material {:?} at {:?}",
+            draw.node, draw.mode, draw.material, draw.world[3]
+        );
+        println!(
+            "  {} vertices of {} bytes from byte {}, {:?} indices from {}",
+            upload.vertex_count,
+            upload.stride,
+            upload.vertex_offset,
+            upload.index_count,
+            upload.first_index
+        );
+        for attribute in &upload.attributes {
+            println!(
+                "  {:?} at +{}: {:?} x {:?}",
+                attribute.semantic, attribute.offset, attribute.data_type, attribute.dimensions
+            );
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    if let Some(path) = std::env::args().nth(1) {
+        run(&path).expect("runtime error");
+    } else {
+        println!("usage: renderer_upload <FILE>");
+    }
+}
@@ -18,6 +18,11 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
     expand(&syn::parse_macro_input!(input as DeriveInput)).into()
 }
 
+#[proc_macro_derive(Bake)]
+pub fn derive_bake(input: TokenStream) -> TokenStream {
+    expand_bake(&syn::parse_macro_input!(input as DeriveInput)).into()
+}
+
 struct ValidateHook(pub syn::Ident);
 
 impl syn::parse::Parse for ValidateHook {
@@ -93,3 +98,119 @@ fn expand(ast: &DeriveInput) -> proc_macro2::TokenStream {
         }
     )
 }
+
+/// Returns the expressions that bake the given bindings and the expression
+/// that loads the fields in order into `constructor`.
+fn bake_fields(
+    fields: &syn::Fields,
+    bindings: &[proc_macro2::TokenStream],
+    constructor: proc_macro2::TokenStream,
+) -> (Vec<proc_macro2::TokenStream>, proc_macro2::TokenStream) {
+    use quote::quote;
+
+    let bakes = bindings
+        .iter()
+        .map(|binding| quote!(crate::bake::Bake::bake(#binding, out)))
+        .collect();
+    let load = quote!(crate::bake::Bake::load(input)?);
+    let loads = match fields {
+        syn::Fields::Named(named) => {
+            let idents = named.named.iter().map(|f| f.ident.as_ref().unwrap());
+            quote!(#constructor { #(#idents: #load,)* })
+        }
+        syn::Fields::Unnamed(unnamed) => {
+            let loads = unnamed.unnamed.iter().map(|_| &load);
+            quote!(#constructor(#(#loads,)*))
+        }
+        syn::Fields::Unit => constructor,
+    };
+    (bakes, loads)
+}
+
+/// Returns the bindings of the fields of a struct or enum variant in a
+/// pattern, and that pattern.
+fn bind_fields(
+    fields: &syn::Fields,
+    path: proc_macro2::TokenStream,
+) -> (Vec<proc_macro2::TokenStream>, proc_macro2::TokenStream) {
+    use quote::{format_ident, quote};
+
+    match fields {
+        syn::Fields::Named(named) => {
+            let idents: Vec<_> = named
+                .named
+                .iter()
+                .map(|f| f.ident.clone().unwrap())
+                .collect();
+            let pattern = quote!(#path { #(ref #idents,)* });
+            (idents.iter().map(|i| quote!(#i)).collect(), pattern)
+        }
+        syn::Fields::Unnamed(unnamed) => {
+            let idents: Vec<_> = (0..unnamed.unnamed.len())
+                .map(|i| format_ident!("field{}", i))
+                .collect();
+            let pattern = quote!(#path(#(ref #idents,)*));
+            (idents.iter().map(|i| quote!(#i)).collect(), pattern)
+        }
+        syn::Fields::Unit => (Vec::new(), path),
+    }
+}
+
+fn expand_bake(ast: &DeriveInput) -> proc_macro2::TokenStream {
+    use quote::quote;
+
+    let ident = &ast.ident;
+    let (bake, load) = match ast.data {
+        syn::Data::Struct(ref data_struct) => {
+            let (bindings, pattern) = bind_fields(&data_struct.fields, quote!(#ident));
+            let (bakes, load) = bake_fields(&data_struct.fields, &bindings, quote!(#ident));
+            let bake = quote!(
+                let #pattern = *self;
+                #(#bakes;)*
+            );
+            (bake, quote!(Ok(#load)))
+        }
+        syn::Data::Enum(ref data_enum) => {
+            assert!(
+                data_enum.variants.len() <= 256,
+                "#[derive(Bake)] only works on `enum`s of up to 256 variants"
+            );
+            let mut bakes = Vec::new();
+            let mut loads = Vec::new();
+            for (tag, variant) in data_enum.variants.iter().enumerate() {
+                let tag = tag as u8;
+                let name = &variant.ident;
+                let (bindings, pattern) = bind_fields(&variant.fields, quote!(#ident::#name));
+                let (bake, load) = bake_fields(&variant.fields, &bindings, quote!(#ident::#name));
+                bakes.push(quote!(#pattern => {
+                    out.push(#tag);
+                    #(#bake;)*
+                }));
+                loads.push(quote!(#tag => Ok(#load)));
+            }
+            let bake = quote!(match *self {
+                #(#bakes)*
+            });
+            let load = quote!(match <u8 as crate::bake::Bake>::load(input)? {
+                #(#loads,)*
+                _ => Err(crate::bake::Malformed),
+            });
+            (bake, load)
+        }
+        syn::Data::Union(_) => panic!("#[derive(Bake)] does not work on `union`s"),
+    };
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    quote!(
+        #[allow(unused_variables)]
+        impl #impl_generics crate::bake::Bake for #ident #ty_generics #where_clause {
+            fn bake(&self, out: &mut alloc::vec::Vec<u8>) {
+                #bake
+            }
+
+            #[inline]
+            fn load(input: &mut &[u8]) -> Result<Self, crate::bake::Malformed> {
+                #load
+            }
+        }
+    )
+}
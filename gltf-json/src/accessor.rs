@@ -1,4 +1,3 @@
-use alloc::string::String;
 use core::fmt;
 use crate::validation::{Checked, Error, USize64};
 use crate::{buffer, extensions, Extras, Index, Path, Root};
@@ -9,6 +8,7 @@ use serde_json::Value;
 
 /// The component data type.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub enum ComponentType {
     /// Corresponds to `GL_BYTE`.
     I8 = 1,
@@ -26,6 +26,7 @@ pub enum ComponentType {
 
 /// Specifies whether an attribute, vector, or matrix.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub enum Type {
     /// Scalar quantity.
     Scalar = 1,
@@ -85,6 +86,7 @@ pub mod sparse {
 
     /// Indices of those attributes that deviate from their initialization value.
     #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
     pub struct Indices {
         /// The parent buffer view containing the sparse indices.
         ///
@@ -95,6 +97,7 @@ pub mod sparse {
 
         /// The offset relative to the start of the parent `BufferView` in bytes.
         #[serde(default, rename = "byteOffset")]
+        #[serde(skip_serializing_if = "is_byte_offset_default")]
         pub byte_offset: USize64,
 
         /// The data type of each index.
@@ -114,6 +117,7 @@ pub mod sparse {
 
     /// Sparse storage of attributes that deviate from their initialization value.
     #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
     pub struct Sparse {
         /// The number of attributes encoded in this sparse accessor.
         pub count: USize64,
@@ -145,6 +149,7 @@ pub mod sparse {
     /// Array of size `count * number_of_components` storing the displaced
     /// accessor attributes pointed by `accessor::sparse::Indices`.
     #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
     pub struct Values {
         /// The parent buffer view containing the sparse indices.
         ///
@@ -155,6 +160,7 @@ pub mod sparse {
 
         /// The offset relative to the start of the parent buffer view in bytes.
         #[serde(default, rename = "byteOffset")]
+        #[serde(skip_serializing_if = "is_byte_offset_default")]
         pub byte_offset: USize64,
 
         /// Extension specific data.
@@ -171,6 +177,7 @@ pub mod sparse {
 
 /// A typed view into a buffer view.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[gltf(validate_hook = "accessor_validate_hook")]
 pub struct Accessor {
     /// The parent buffer view this accessor reads from.
@@ -210,17 +217,23 @@ pub struct Accessor {
     pub type_: Checked<Type>,
 
     /// Minimum value of each component in this attribute.
+    ///
+    /// Numbers keep the double precision of the JSON source; they are only
+    /// converted to `f32` on access.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub min: Option<Value>,
 
     /// Maximum value of each component in this attribute.
+    ///
+    /// Numbers keep the double precision of the JSON source; they are only
+    /// converted to `f32` on access.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max: Option<Value>,
 
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// Specifies whether integer data values should be normalized.
     #[serde(default, skip_serializing_if = "is_normalized_default")]
@@ -233,7 +246,7 @@ pub struct Accessor {
     pub sparse: Option<sparse::Sparse>,
 }
 
-fn accessor_validate_hook<P, R>(accessor: &Accessor, _root: &Root, path: P, report: &mut R)
+fn accessor_validate_hook<P, R>(accessor: &Accessor, root: &Root, path: P, report: &mut R)
 where
     P: Fn() -> Path,
     R: FnMut(&dyn Fn() -> Path, Error),
@@ -243,6 +256,20 @@ where
         // missing since it is the more common one to require.
         report(&|| path().field("bufferView"), Error::Missing);
     }
+
+    // Consecutive elements must not overlap, so a byte stride must span at
+    // least the components of an element.
+    let stride = accessor
+        .buffer_view
+        .and_then(|view| root.get(view))
+        .and_then(|view| view.byte_stride);
+    if let (Some(stride), Checked::Valid(component_type), Checked::Valid(type_)) =
+        (stride, &accessor.component_type, &accessor.type_)
+    {
+        if stride.0 < component_type.0.size() * type_.multiplicity() {
+            report(&|| path().field("bufferView"), Error::Invalid);
+        }
+    }
 }
 
 // Help serde avoid serializing this glTF 2.0 default value.
@@ -250,12 +277,18 @@ fn is_normalized_default(b: &bool) -> bool {
     !*b
 }
 
+fn is_byte_offset_default(offset: &USize64) -> bool {
+    offset.0 == 0
+}
+
 /// The data type of an index.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct IndexComponentType(pub ComponentType);
 
 /// The data type of a generic vertex attribute.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct GenericComponentType(pub ComponentType);
 
 impl<'de> de::Deserialize<'de> for Checked<GenericComponentType> {
@@ -275,18 +308,21 @@ impl<'de> de::Deserialize<'de> for Checked<GenericComponentType> {
             where
                 E: de::Error,
             {
-                use self::ComponentType::*;
                 use crate::validation::Checked::*;
-                Ok(match value as u32 {
-                    BYTE => Valid(GenericComponentType(I8)),
-                    UNSIGNED_BYTE => Valid(GenericComponentType(U8)),
-                    SHORT => Valid(GenericComponentType(I16)),
-                    UNSIGNED_SHORT => Valid(GenericComponentType(U16)),
-                    UNSIGNED_INT => Valid(GenericComponentType(U32)),
-                    FLOAT => Valid(GenericComponentType(F32)),
+                Ok(match ComponentType::from_gl(value as u32) {
+                    Some(component_type) if value <= u32::MAX as u64 => {
+                        Valid(GenericComponentType(component_type))
+                    }
                     _ => Invalid,
                 })
             }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_u64(crate::validation::integral(value)?)
+            }
         }
         deserializer.deserialize_u64(Visitor)
     }
@@ -318,6 +354,13 @@ impl<'de> de::Deserialize<'de> for Checked<IndexComponentType> {
                     _ => Invalid,
                 })
             }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_u64(crate::validation::integral(value)?)
+            }
         }
         deserializer.deserialize_u64(Visitor)
     }
@@ -340,18 +383,8 @@ impl<'de> de::Deserialize<'de> for Checked<Type> {
             where
                 E: de::Error,
             {
-                use self::Type::*;
                 use crate::validation::Checked::*;
-                Ok(match value {
-                    "SCALAR" => Valid(Scalar),
-                    "VEC2" => Valid(Vec2),
-                    "VEC3" => Valid(Vec3),
-                    "VEC4" => Valid(Vec4),
-                    "MAT2" => Valid(Mat2),
-                    "MAT3" => Valid(Mat3),
-                    "MAT4" => Valid(Mat4),
-                    _ => Invalid,
-                })
+                Ok(Type::from_str(value).map_or(Invalid, Valid))
             }
         }
         deserializer.deserialize_str(Visitor)
@@ -363,31 +396,27 @@ impl ser::Serialize for Type {
     where
         S: ser::Serializer,
     {
-        serializer.serialize_str(match *self {
-            Type::Scalar => "SCALAR",
-            Type::Vec2 => "VEC2",
-            Type::Vec3 => "VEC3",
-            Type::Vec4 => "VEC4",
-            Type::Mat2 => "MAT2",
-            Type::Mat3 => "MAT3",
-            Type::Mat4 => "MAT4",
-        })
+        serializer.serialize_str(self.as_str())
     }
 }
 
 impl ComponentType {
-    /// Returns the number of bytes this value represents.
-    pub fn size(&self) -> usize {
-        use self::ComponentType::*;
-        match *self {
-            I8 | U8 => 1,
-            I16 | U16 => 2,
-            F32 | U32 => 4,
-        }
+    /// Returns the component type corresponding to a `GLenum`, or `None` if
+    /// the value is not a valid accessor component type.
+    pub const fn from_gl(value: u32) -> Option<Self> {
+        Some(match value {
+            BYTE => ComponentType::I8,
+            UNSIGNED_BYTE => ComponentType::U8,
+            SHORT => ComponentType::I16,
+            UNSIGNED_SHORT => ComponentType::U16,
+            UNSIGNED_INT => ComponentType::U32,
+            FLOAT => ComponentType::F32,
+            _ => return None,
+        })
     }
 
     /// Returns the corresponding `GLenum`.
-    pub fn as_gl_enum(self) -> u32 {
+    pub const fn to_gl(self) -> u32 {
         match self {
             ComponentType::I8 => BYTE,
             ComponentType::U8 => UNSIGNED_BYTE,
@@ -397,6 +426,23 @@ impl ComponentType {
             ComponentType::F32 => FLOAT,
         }
     }
+
+    /// Returns the number of bytes this value represents.
+    pub const fn size(&self) -> usize {
+        use self::ComponentType::*;
+        match *self {
+            I8 | U8 => 1,
+            I16 | U16 => 2,
+            F32 | U32 => 4,
+        }
+    }
+
+    /// Returns the corresponding `GLenum`.
+    ///
+    /// This is the same as [`Self::to_gl`].
+    pub const fn as_gl_enum(self) -> u32 {
+        self.to_gl()
+    }
 }
 
 impl ser::Serialize for ComponentType {
@@ -404,13 +450,43 @@ impl ser::Serialize for ComponentType {
     where
         S: ser::Serializer,
     {
-        serializer.serialize_u32(self.as_gl_enum())
+        serializer.serialize_u32(self.to_gl())
     }
 }
 
 impl Type {
+    /// Returns the type named by an accessor `type` string such as `"VEC3"`,
+    /// or `None` if the name is not valid.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(name: &str) -> Option<Self> {
+        use self::Type::*;
+        Some(match name {
+            "SCALAR" => Scalar,
+            "VEC2" => Vec2,
+            "VEC3" => Vec3,
+            "VEC4" => Vec4,
+            "MAT2" => Mat2,
+            "MAT3" => Mat3,
+            "MAT4" => Mat4,
+            _ => return None,
+        })
+    }
+
+    /// Returns the accessor `type` string naming this type.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Type::Scalar => "SCALAR",
+            Type::Vec2 => "VEC2",
+            Type::Vec3 => "VEC3",
+            Type::Vec4 => "VEC4",
+            Type::Mat2 => "MAT2",
+            Type::Mat3 => "MAT3",
+            Type::Mat4 => "MAT4",
+        }
+    }
+
     /// Returns the equivalent number of scalar quantities this type represents.
-    pub fn multiplicity(&self) -> usize {
+    pub const fn multiplicity(&self) -> usize {
         use self::Type::*;
         match *self {
             Scalar => 1,
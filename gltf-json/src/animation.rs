@@ -1,4 +1,3 @@
-use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt;
 use crate::validation::{Checked, Error, Validate};
@@ -15,6 +14,7 @@ pub const VALID_PROPERTIES: &[&str] = &["translation", "rotation", "scale", "wei
 
 /// Specifies an interpolation algorithm.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub enum Interpolation {
     /// Linear interpolation.
     ///
@@ -43,6 +43,7 @@ pub enum Interpolation {
 
 /// Specifies a property to animate.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub enum Property {
     /// XYZ translation vector.
     Translation = 1,
@@ -56,6 +57,7 @@ pub enum Property {
 
 /// A keyframe animation.
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Animation {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -77,7 +79,7 @@ pub struct Animation {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// An array of samplers that combine input and output accessors with an
     /// interpolation algorithm to define a keyframe graph (but not its target).
@@ -87,6 +89,7 @@ pub struct Animation {
 
 /// Targets an animation's sampler at a node's property.
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Channel {
     /// The index of a sampler in this animation used to compute the value for the
     /// target.
@@ -108,6 +111,7 @@ pub struct Channel {
 
 /// The index of the node and TRS property that an animation channel targets.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Target {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -129,6 +133,7 @@ pub struct Target {
 
 /// Defines a keyframe graph but not its target.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Sampler {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -144,13 +149,17 @@ pub struct Sampler {
     pub input: Index<accessor::Accessor>,
 
     /// The interpolation algorithm.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_interpolation_default")]
     pub interpolation: Checked<Interpolation>,
 
     /// The index of an accessor containing keyframe output values.
     pub output: Index<accessor::Accessor>,
 }
 
+fn is_interpolation_default(interpolation: &Checked<Interpolation>) -> bool {
+    *interpolation == Checked::Valid(Interpolation::Linear)
+}
+
 impl Validate for Animation {
     fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
     where
@@ -0,0 +1,320 @@
+use alloc::alloc::{alloc, dealloc};
+use alloc::sync::Arc;
+use core::alloc::Layout;
+use core::cell::{Cell, UnsafeCell};
+use core::ops::Range;
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use core::{fmt, hint, slice, str};
+
+use crate::text::Str;
+use crate::Root;
+
+/// A memory block that strings are copied into.
+pub(crate) struct Block {
+    ptr: NonNull<u8>,
+    layout: Layout,
+    used: AtomicUsize,
+}
+
+// The bytes of a block are written once, through `Block::alloc`, after being
+// reserved by bumping `used` and before any `Str` refers to them, and only
+// read afterwards.
+unsafe impl Send for Block {}
+unsafe impl Sync for Block {}
+
+impl Block {
+    fn new(capacity: usize) -> Option<Self> {
+        let layout = Layout::from_size_align(capacity, 1).ok()?;
+        if layout.size() == 0 {
+            return None;
+        }
+        let ptr = NonNull::new(unsafe { alloc(layout) })?;
+        Some(Self {
+            ptr,
+            layout,
+            used: AtomicUsize::new(0),
+        })
+    }
+
+    /// Copies `text` into the block, unless it does not fit.
+    fn alloc(self: &Arc<Self>, text: &str) -> Option<Str> {
+        let start = self
+            .used
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |used| {
+                used.checked_add(text.len())
+                    .filter(|&end| end <= self.layout.size())
+            })
+            .ok()?;
+        unsafe {
+            let ptr = self.ptr.as_ptr().add(start);
+            ptr.copy_from_nonoverlapping(text.as_ptr(), text.len());
+            Some(Str::from_block(self.clone(), start, text.len()))
+        }
+    }
+
+    /// Returns the text stored at `start`.
+    ///
+    /// # Safety
+    ///
+    /// The `len` bytes at `start` must be within the block and hold valid
+    /// UTF-8.
+    pub(crate) unsafe fn str(&self, start: usize, len: usize) -> &str {
+        let bytes = slice::from_raw_parts(self.ptr.as_ptr().add(start), len);
+        str::from_utf8_unchecked(bytes)
+    }
+}
+
+impl Drop for Block {
+    fn drop(&mut self) {
+        unsafe { dealloc(self.ptr.as_ptr(), self.layout) }
+    }
+}
+
+/// An input that is being deserialized into the block of an arena; see
+/// [`ImportArena::scope`].
+struct Scope {
+    /// The addresses of the input.
+    input: Range<usize>,
+    block: Arc<Block>,
+    /// The scope entered before this one.
+    next: Cell<*const Scope>,
+}
+
+/// The scopes that are entered, most recent first, guarded by a spin lock.
+struct Scopes {
+    locked: AtomicBool,
+    head: UnsafeCell<*const Scope>,
+    /// The number of scopes, read without taking the lock.
+    len: AtomicUsize,
+}
+
+// Entered scopes, and the list linking them, are only accessed while the
+// lock is held.
+unsafe impl Sync for Scopes {}
+
+static SCOPES: Scopes = Scopes {
+    locked: AtomicBool::new(false),
+    head: UnsafeCell::new(ptr::null()),
+    len: AtomicUsize::new(0),
+};
+
+impl Scopes {
+    fn lock<R>(&self, f: impl FnOnce(&mut *const Scope) -> R) -> R {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            hint::spin_loop();
+        }
+        let result = f(unsafe { &mut *self.head.get() });
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+}
+
+impl Scope {
+    fn enter(&self) -> Entered<'_> {
+        SCOPES.lock(|head| {
+            self.next.set(*head);
+            *head = self;
+        });
+        SCOPES.len.fetch_add(1, Ordering::Release);
+        Entered(self)
+    }
+}
+
+/// Leaves a scope when dropped.
+struct Entered<'a>(&'a Scope);
+
+impl Drop for Entered<'_> {
+    fn drop(&mut self) {
+        let this: *const Scope = self.0;
+        SCOPES.lock(|head| {
+            if *head == this {
+                *head = self.0.next.get();
+                return;
+            }
+            let mut scope = *head;
+            while let Some(entered) = unsafe { scope.as_ref() } {
+                if entered.next.get() == this {
+                    entered.next.set(self.0.next.get());
+                    return;
+                }
+                scope = entered.next.get();
+            }
+        });
+        SCOPES.len.fetch_sub(1, Ordering::Release);
+    }
+}
+
+/// Copies `text` into the arena of the input it was deserialized from, if
+/// that input is being deserialized into an arena.
+pub(crate) fn alloc_scoped(text: &str) -> Option<Str> {
+    if SCOPES.len.load(Ordering::Acquire) == 0 {
+        return None;
+    }
+    let start = text.as_ptr() as usize;
+    let end = start.checked_add(text.len())?;
+    SCOPES.lock(|head| {
+        let mut scope = *head;
+        while let Some(entered) = unsafe { scope.as_ref() } {
+            if entered.input.start <= start && end <= entered.input.end {
+                return entered.block.alloc(text);
+            }
+            scope = entered.next.get();
+        }
+        None
+    })
+}
+
+/// A fixed-capacity memory block holding the names, URIs and extras of
+/// documents loaded with [`Root::from_slice_in`].
+///
+/// The strings are bump allocated and stay in the block until both the arena
+/// and every [`Str`] in it have been dropped, when the block is freed at
+/// once. Strings that do not fit in the remaining capacity stay on the heap,
+/// as do all strings if the block cannot be allocated. An arena may be shared
+/// by several documents, including documents loaded concurrently.
+pub struct ImportArena {
+    block: Option<Arc<Block>>,
+}
+
+impl ImportArena {
+    /// Allocates an arena of `capacity` bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            block: Block::new(capacity).map(Arc::new),
+        }
+    }
+
+    /// Returns the capacity of the arena in bytes, which is zero if its block
+    /// could not be allocated.
+    pub fn capacity(&self) -> usize {
+        self.block.as_ref().map_or(0, |block| block.layout.size())
+    }
+
+    /// Returns the number of bytes handed out so far.
+    pub fn used(&self) -> usize {
+        self.block
+            .as_ref()
+            .map_or(0, |block| block.used.load(Ordering::Acquire))
+    }
+
+    /// Copies `text` into the arena, or onto the heap if it does not fit.
+    pub fn alloc_str(&self, text: &str) -> Str {
+        self.block
+            .as_ref()
+            .and_then(|block| block.alloc(text))
+            .unwrap_or_else(|| Str::from(text))
+    }
+
+    /// Runs `parse`, which deserializes `input`, storing the strings that it
+    /// borrows from `input` directly in the arena.
+    ///
+    /// Names, URIs and extras are copied from `input` into the arena as they
+    /// are deserialized, so that no heap allocation is made for them. Strings
+    /// containing escape sequences are unescaped on the heap instead; see
+    /// [`Root::move_strings_into`] for moving them afterwards. Strings that
+    /// other code deserializes from the same `input` while `parse` runs, for
+    /// example on another thread, are stored in the arena as well.
+    pub fn scope<R>(&self, input: &[u8], parse: impl FnOnce() -> R) -> R {
+        let block = match self.block.as_ref() {
+            Some(block) => block.clone(),
+            None => return parse(),
+        };
+        let start = input.as_ptr() as usize;
+        let scope = Scope {
+            input: start..start + input.len(),
+            block,
+            next: Cell::new(ptr::null()),
+        };
+        let _entered = scope.enter();
+        parse()
+    }
+
+    /// Moves a string from the heap into the arena.
+    fn place(&self, text: &mut Option<Str>) {
+        if let Some(text) = text.as_mut().filter(|text| !text.is_in_arena()) {
+            *text = self.alloc_str(text);
+        }
+    }
+}
+
+impl fmt::Debug for ImportArena {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ImportArena")
+            .field("capacity", &self.capacity())
+            .field("used", &self.used())
+            .finish()
+    }
+}
+
+impl Root {
+    /// Deserializes glTF JSON, storing its names, URIs and extras in
+    /// `arena`; see [`ImportArena::scope`].
+    ///
+    /// Names and URIs containing escape sequences are moved into `arena`
+    /// after deserialization.
+    pub fn from_slice_in(slice: &[u8], arena: &ImportArena) -> Result<Self, crate::Error> {
+        let mut root: Root = arena.scope(slice, || serde_json::from_slice(slice))?;
+        root.move_strings_into(arena);
+        Ok(root)
+    }
+
+    /// Moves the names and URIs of the document from the heap into `arena`.
+    ///
+    /// The heap strings are freed as they are moved, so that the document
+    /// retains no small heap allocation for them. Strings that are already in
+    /// an arena are left in place.
+    pub fn move_strings_into(&mut self, arena: &ImportArena) {
+        for buffer in &mut self.buffers {
+            arena.place(&mut buffer.uri);
+        }
+        for image in &mut self.images {
+            arena.place(&mut image.uri);
+        }
+        #[cfg(feature = "names")]
+        self.move_names_into(arena);
+    }
+
+    #[cfg(feature = "names")]
+    fn move_names_into(&mut self, arena: &ImportArena) {
+        macro_rules! place_names {
+            ($($entities:expr),*) => {
+                $(
+                    for entity in $entities.iter_mut() {
+                        arena.place(&mut entity.name);
+                    }
+                )*
+            };
+        }
+        place_names!(
+            self.accessors,
+            self.buffers,
+            self.buffer_views,
+            self.images,
+            self.materials,
+            self.meshes,
+            self.nodes,
+            self.samplers,
+            self.scenes,
+            self.textures
+        );
+        #[cfg(feature = "animation")]
+        place_names!(self.animations);
+        #[cfg(feature = "cameras")]
+        place_names!(self.cameras);
+        #[cfg(feature = "skinning")]
+        place_names!(self.skins);
+        #[cfg(feature = "KHR_lights_punctual")]
+        if let Some(lights) = self
+            .extensions
+            .as_mut()
+            .and_then(|extensions| extensions.khr_lights_punctual.as_mut())
+        {
+            place_names!(lights.lights);
+        }
+    }
+}
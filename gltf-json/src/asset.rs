@@ -5,6 +5,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// Metadata about the glTF asset.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Asset {
     /// A copyright message suitable for display to credit the content creator.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,429 @@
+//! The JSON structs are baked field by field in declaration order, as
+//! little-endian numbers, with a `u8` tag before options, enum variants and
+//! JSON values, and a `u32` length before strings, sequences and maps.
+//!
+//! The layout depends on the features of this crate that add fields or
+//! variants; see [`LAYOUT`].
+//!
+//! [`LAYOUT`]: crate::bake::LAYOUT
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use serde_json::{Map, Number, Value};
+
+use crate::validation::{Checked, USize64};
+use crate::{Index, Str};
+
+/// The features of this crate that change the baked layout, one bit each.
+pub const LAYOUT: u32 = {
+    let features = [
+        cfg!(feature = "animation"),
+        cfg!(feature = "cameras"),
+        cfg!(feature = "skinning"),
+        cfg!(feature = "names"),
+        cfg!(feature = "extensions"),
+        cfg!(feature = "extras"),
+        cfg!(feature = "KHR_lights_punctual"),
+        cfg!(feature = "KHR_materials_ior"),
+        cfg!(feature = "KHR_materials_pbrSpecularGlossiness"),
+        cfg!(feature = "KHR_materials_specular"),
+        cfg!(feature = "KHR_materials_transmission"),
+        cfg!(feature = "KHR_materials_unlit"),
+        cfg!(feature = "KHR_materials_variants"),
+        cfg!(feature = "KHR_materials_volume"),
+        cfg!(feature = "KHR_texture_transform"),
+        cfg!(feature = "KHR_materials_emissive_strength"),
+        cfg!(feature = "EXT_meshopt_compression"),
+        cfg!(feature = "KHR_texture_basisu"),
+        cfg!(feature = "EXT_texture_webp"),
+    ];
+    let mut layout = 0;
+    let mut i = 0;
+    while i < features.len() {
+        if features[i] {
+            layout |= 1 << i;
+        }
+        i += 1;
+    }
+    layout
+};
+
+/// Baked data is truncated or does not encode the expected value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Malformed;
+
+impl fmt::Display for Malformed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "malformed baked data")
+    }
+}
+
+impl core::error::Error for Malformed {}
+
+/// A value with a compact binary encoding.
+pub trait Bake: Sized {
+    /// Appends the encoding of the value.
+    fn bake(&self, out: &mut Vec<u8>);
+
+    /// Decodes a value from the start of `input` and advances past it.
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed>;
+}
+
+/// Splits `len` bytes off the start of `input`.
+#[inline]
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], Malformed> {
+    if input.len() < len {
+        return Err(Malformed);
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Ok(head)
+}
+
+#[inline]
+fn bake_len(len: usize, out: &mut Vec<u8>) {
+    let len = u32::try_from(len).expect("length exceeds u32");
+    len.bake(out);
+}
+
+#[inline]
+fn load_len(input: &mut &[u8]) -> Result<usize, Malformed> {
+    u32::load(input).map(|len| len as usize)
+}
+
+#[inline]
+fn bake_str(string: &str, out: &mut Vec<u8>) {
+    bake_len(string.len(), out);
+    out.extend_from_slice(string.as_bytes());
+}
+
+#[inline]
+fn load_str<'a>(input: &mut &'a [u8]) -> Result<&'a str, Malformed> {
+    let len = load_len(input)?;
+    core::str::from_utf8(take(input, len)?).map_err(|_| Malformed)
+}
+
+macro_rules! bake_numbers {
+    ($($ty:ty),*) => {
+        $(
+            impl Bake for $ty {
+                #[inline]
+                fn bake(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+
+
+                #[inline]
+                fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+                    let bytes = take(input, core::mem::size_of::<$ty>())?;
+                    Ok(<$ty>::from_le_bytes(bytes.try_into().unwrap()))
+                }
+            }
+        )*
+    };
+}
+
+bake_numbers!(u8, u32, u64, i64, f32, f64);
+
+impl Bake for () {
+    #[inline]
+    fn bake(&self, _: &mut Vec<u8>) {}
+
+    #[inline]
+    fn load(_: &mut &[u8]) -> Result<Self, Malformed> {
+        Ok(())
+    }
+}
+
+impl Bake for bool {
+    #[inline]
+    fn bake(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    #[inline]
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        match u8::load(input)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Malformed),
+        }
+    }
+}
+
+impl Bake for usize {
+    #[inline]
+    fn bake(&self, out: &mut Vec<u8>) {
+        (*self as u64).bake(out);
+    }
+
+    #[inline]
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        usize::try_from(u64::load(input)?).map_err(|_| Malformed)
+    }
+}
+
+impl<const N: usize> Bake for [f32; N] {
+    fn bake(&self, out: &mut Vec<u8>) {
+        for x in self {
+            x.bake(out);
+        }
+    }
+
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        let mut array = [0.0; N];
+        for x in &mut array {
+            *x = f32::load(input)?;
+        }
+        Ok(array)
+    }
+}
+
+impl Bake for String {
+    #[inline]
+    fn bake(&self, out: &mut Vec<u8>) {
+        bake_str(self, out);
+    }
+
+    #[inline]
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        load_str(input).map(String::from)
+    }
+}
+
+impl Bake for Str {
+    #[inline]
+    fn bake(&self, out: &mut Vec<u8>) {
+        bake_str(self, out);
+    }
+
+    #[inline]
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        load_str(input).map(Str::from)
+    }
+}
+
+impl<T: Bake> Bake for Option<T> {
+    fn bake(&self, out: &mut Vec<u8>) {
+        match self {
+            None => out.push(0),
+            Some(value) => {
+                out.push(1);
+                value.bake(out);
+            }
+        }
+    }
+
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        match u8::load(input)? {
+            0 => Ok(None),
+            1 => T::load(input).map(Some),
+            _ => Err(Malformed),
+        }
+    }
+}
+
+impl<T: Bake> Bake for Vec<T> {
+    fn bake(&self, out: &mut Vec<u8>) {
+        bake_len(self.len(), out);
+        for element in self {
+            element.bake(out);
+        }
+    }
+
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        let len = load_len(input)?;
+        // Bounds the allocation by the input left, since the elements of
+        // baked sequences take at least a byte each.
+        let mut vec = Vec::with_capacity(len.min(input.len()));
+        for _ in 0..len {
+            vec.push(T::load(input)?);
+        }
+        Ok(vec)
+    }
+}
+
+impl<K: Bake + Ord, V: Bake> Bake for BTreeMap<K, V> {
+    fn bake(&self, out: &mut Vec<u8>) {
+        bake_len(self.len(), out);
+        for (key, value) in self {
+            key.bake(out);
+            value.bake(out);
+        }
+    }
+
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        let len = load_len(input)?;
+        let mut map = BTreeMap::new();
+        for _ in 0..len {
+            let key = K::load(input)?;
+            map.insert(key, V::load(input)?);
+        }
+        Ok(map)
+    }
+}
+
+impl Bake for Map<String, Value> {
+    fn bake(&self, out: &mut Vec<u8>) {
+        bake_len(self.len(), out);
+        for (key, value) in self {
+            key.bake(out);
+            value.bake(out);
+        }
+    }
+
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        load_map(input, 1)
+    }
+}
+
+impl Bake for Value {
+    fn bake(&self, out: &mut Vec<u8>) {
+        match self {
+            Value::Null => out.push(0),
+            Value::Bool(false) => out.push(1),
+            Value::Bool(true) => out.push(2),
+            Value::Number(number) => {
+                if let Some(n) = number.as_u64() {
+                    out.push(3);
+                    n.bake(out);
+                } else if let Some(n) = number.as_i64() {
+                    out.push(4);
+                    n.bake(out);
+                } else {
+                    out.push(5);
+                    number.as_f64().unwrap().bake(out);
+                }
+            }
+            Value::String(string) => {
+                out.push(6);
+                string.bake(out);
+            }
+            Value::Array(array) => {
+                out.push(7);
+                array.bake(out);
+            }
+            Value::Object(object) => {
+                out.push(8);
+                object.bake(out);
+            }
+        }
+    }
+
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        load_value(input, 0)
+    }
+}
+
+/// The deepest nesting of arrays and objects that JSON values are loaded
+/// with, which is the recursion limit of `serde_json`, so that crafted data
+/// cannot exhaust the stack.
+const MAX_DEPTH: usize = 128;
+
+/// Loads a JSON object nested in `depth` arrays and objects, itself included.
+fn load_map(input: &mut &[u8], depth: usize) -> Result<Map<String, Value>, Malformed> {
+    let len = load_len(input)?;
+    let mut map = Map::new();
+    for _ in 0..len {
+        let key = String::load(input)?;
+        map.insert(key, load_value(input, depth)?);
+    }
+    Ok(map)
+}
+
+/// Loads a JSON value nested in `depth` arrays and objects.
+fn load_value(input: &mut &[u8], depth: usize) -> Result<Value, Malformed> {
+    Ok(match u8::load(input)? {
+        0 => Value::Null,
+        1 => Value::Bool(false),
+        2 => Value::Bool(true),
+        3 => Value::from(u64::load(input)?),
+        4 => Value::from(i64::load(input)?),
+        5 => Number::from_f64(f64::load(input)?)
+            .map(Value::Number)
+            .ok_or(Malformed)?,
+        6 => Value::String(String::load(input)?),
+        7 | 8 if depth >= MAX_DEPTH => return Err(Malformed),
+        7 => {
+            let len = load_len(input)?;
+            let mut array = Vec::with_capacity(len.min(input.len()));
+            for _ in 0..len {
+                array.push(load_value(input, depth + 1)?);
+            }
+            Value::Array(array)
+        }
+        8 => Value::Object(load_map(input, depth + 1)?),
+        _ => return Err(Malformed),
+    })
+}
+
+/// Raw values are baked as their JSON text, which is checked on loading.
+impl Bake for Box<serde_json::value::RawValue> {
+    fn bake(&self, out: &mut Vec<u8>) {
+        bake_str(self.get(), out);
+    }
+
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        let json = load_str(input)?;
+        serde_json::value::RawValue::from_string(json.into()).map_err(|_| Malformed)
+    }
+}
+
+/// Extras are baked as their JSON text, which is checked on loading.
+#[cfg(feature = "extras")]
+impl Bake for crate::extras::RawJson {
+    fn bake(&self, out: &mut Vec<u8>) {
+        bake_str(self.get(), out);
+    }
+
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        let json = load_str(input)?;
+        Self::from_string(json.into()).map_err(|_| Malformed)
+    }
+}
+
+impl<T> Bake for Index<T> {
+    fn bake(&self, out: &mut Vec<u8>) {
+        (self.value() as u32).bake(out);
+    }
+
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        u32::load(input).map(Index::new)
+    }
+}
+
+/// Invalid items are baked too, and stay invalid.
+impl<T: Bake> Bake for Checked<T> {
+    fn bake(&self, out: &mut Vec<u8>) {
+        match self {
+            Checked::Invalid => out.push(0),
+            Checked::Valid(item) => {
+                out.push(1);
+                item.bake(out);
+            }
+        }
+    }
+
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        match u8::load(input)? {
+            0 => Ok(Checked::Invalid),
+            1 => T::load(input).map(Checked::Valid),
+            _ => Err(Malformed),
+        }
+    }
+}
+
+impl Bake for USize64 {
+    #[inline]
+    fn bake(&self, out: &mut Vec<u8>) {
+        self.0.bake(out);
+    }
+
+    #[inline]
+    fn load(input: &mut &[u8]) -> Result<Self, Malformed> {
+        u64::load(input).map(USize64)
+    }
+}
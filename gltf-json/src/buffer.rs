@@ -1,6 +1,5 @@
-use alloc::string::String;
 use core::fmt;
-use crate::validation::{Checked, Error, USize64, Validate};
+use crate::validation::{self, Checked, Error, USize64, Validate};
 use crate::{extensions, Extras, Index, Path, Root};
 use gltf_derive::Validate;
 use serde::{de, ser};
@@ -23,6 +22,7 @@ pub const VALID_TARGETS: &[u32] = &[ARRAY_BUFFER, ELEMENT_ARRAY_BUFFER];
 
 /// Specifies the target a GPU buffer should be bound to.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub enum Target {
     /// Corresponds to `GL_ARRAY_BUFFER`.
     ArrayBuffer = 1,
@@ -45,7 +45,8 @@ impl ser::Serialize for Target {
 
 /// Distance between individual items in a buffer view, measured in bytes.
 #[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
-pub struct Stride(pub usize);
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
+pub struct Stride(#[serde(deserialize_with = "crate::validation::deserialize_usize")] pub usize);
 
 impl Validate for Stride {
     fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
@@ -61,6 +62,8 @@ impl Validate for Stride {
 
 /// A buffer points to binary data representing geometry, animations, or skins.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
+#[gltf(validate_hook = "buffer_validate_hook")]
 pub struct Buffer {
     /// The length of the buffer in bytes.
     #[serde(default, rename = "byteLength")]
@@ -69,12 +72,12 @@ pub struct Buffer {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// The uri of the buffer.  Relative paths are relative to the .gltf file.
     /// Instead of referencing an external file, the uri can also be a data-uri.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub uri: Option<String>,
+    pub uri: Option<crate::Str>,
 
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -87,11 +90,22 @@ pub struct Buffer {
     pub extras: Extras,
 }
 
+fn buffer_validate_hook<P, R>(buffer: &Buffer, _root: &Root, path: P, report: &mut R)
+where
+    P: Fn() -> Path,
+    R: FnMut(&dyn Fn() -> Path, Error),
+{
+    if buffer.uri.as_deref().map_or(false, validation::is_blank) {
+        report(&|| path().field("uri"), Error::Invalid);
+    }
+}
+
 /// A view into a buffer generally representing a subset of the buffer.
 ///
 /// <https://github.com/KhronosGroup/glTF/tree/master/specification/2.0#reference-bufferview>
 ///
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct View {
     /// The parent `Buffer`.
     pub buffer: Index<Buffer>,
@@ -118,7 +132,7 @@ pub struct View {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// Optional target the buffer should be bound to.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -160,6 +174,13 @@ impl<'de> de::Deserialize<'de> for Checked<Target> {
                     _ => Invalid,
                 })
             }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_u64(crate::validation::integral(value)?)
+            }
         }
         deserializer.deserialize_u64(Visitor)
     }
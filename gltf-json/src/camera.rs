@@ -1,4 +1,3 @@
-use alloc::string::String;
 use core::fmt;
 use crate::validation::{Checked, Error};
 use crate::{extensions, Extras, Path, Root};
@@ -11,6 +10,7 @@ pub const VALID_CAMERA_TYPES: &[&str] = &["perspective", "orthographic"];
 
 /// Specifies the camera type.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub enum Type {
     /// A perspective projection.
     Perspective = 1,
@@ -24,12 +24,13 @@ pub enum Type {
 /// A node can reference a camera to apply a transform to place the camera in the
 /// scene.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[gltf(validate_hook = "camera_validate_hook")]
 pub struct Camera {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// An orthographic camera containing properties to create an orthographic
     /// projection matrix.
@@ -68,6 +69,7 @@ where
 
 /// Values for an orthographic camera.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Orthographic {
     /// The horizontal magnification of the view.
     pub xmag: f32,
@@ -94,6 +96,7 @@ pub struct Orthographic {
 
 /// Values for a perspective camera.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Perspective {
     /// Aspect ratio of the field of view.
     #[serde(rename = "aspectRatio")]
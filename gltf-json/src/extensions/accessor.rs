@@ -10,20 +10,36 @@ pub mod sparse {
 
     /// Indices of those attributes that deviate from their initialization value.
     #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-    pub struct Indices {}
+    #[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
+    pub struct Indices {
+        #[cfg(feature = "extensions")]
+        #[serde(default, flatten)]
+        pub others: Map<String, Value>,
+    }
 
     /// Sparse storage of attributes that deviate from their initialization value.
     #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-    pub struct Sparse {}
+    #[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
+    pub struct Sparse {
+        #[cfg(feature = "extensions")]
+        #[serde(default, flatten)]
+        pub others: Map<String, Value>,
+    }
 
     /// Array of size `count * number_of_components` storing the displaced
     /// accessor attributes pointed by `accessor::sparse::Indices`.
     #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-    pub struct Values {}
+    #[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
+    pub struct Values {
+        #[cfg(feature = "extensions")]
+        #[serde(default, flatten)]
+        pub others: Map<String, Value>,
+    }
 }
 
 /// A typed view into a buffer view.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Accessor {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
@@ -6,6 +6,7 @@ use serde_json::{Map, Value};
 
 /// A keyframe animation.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Animation {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
@@ -14,12 +15,27 @@ pub struct Animation {
 
 /// Targets an animation's sampler at a node's property.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Channel {}
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
+pub struct Channel {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: Map<String, Value>,
+}
 
 /// The index of the node and TRS property that an animation channel targets.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Target {}
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
+pub struct Target {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: Map<String, Value>,
+}
 
 /// Defines a keyframe graph but not its target.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Sampler {}
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
+pub struct Sampler {
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: Map<String, Value>,
+}
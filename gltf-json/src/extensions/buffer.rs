@@ -6,14 +6,36 @@ use serde_json::{Map, Value};
 
 /// A buffer points to binary data representing geometry, animations, or skins.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Buffer {
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[serde(
+        default,
+        rename = "EXT_meshopt_compression",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub meshopt_compression: Option<MeshoptCompression>,
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
 }
 
+/// Marks a buffer that holds the uncompressed data of `EXT_meshopt_compression`
+/// buffer views.
+#[cfg(feature = "EXT_meshopt_compression")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
+#[serde(default)]
+pub struct MeshoptCompression {
+    /// Set if the buffer is a fallback for loaders that do not implement the
+    /// extension, which may then have neither a URI nor data in the `BIN`
+    /// chunk.
+    pub fallback: bool,
+}
+
 /// A view into a buffer generally representing a subset of the buffer.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct View {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
@@ -9,6 +9,7 @@ use serde_json::{Map, Value};
 /// A node can reference a camera to apply a transform to place the camera in the
 /// scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Camera {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
@@ -17,6 +18,7 @@ pub struct Camera {
 
 /// Values for an orthographic camera.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Orthographic {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
@@ -25,6 +27,7 @@ pub struct Orthographic {
 
 /// Values for a perspective camera.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Perspective {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
@@ -6,6 +6,7 @@ use serde_json::{Map, Value};
 
 /// Image data used to create a texture.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Image {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
@@ -8,6 +8,7 @@ use serde_json::{Map, Value};
 
 /// The material appearance of a primitive.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Material {
     #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
     #[serde(
@@ -73,6 +74,7 @@ pub struct Material {
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct PbrMetallicRoughness {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
@@ -87,6 +89,7 @@ pub struct PbrMetallicRoughness {
 /// preferred.
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[serde(default, rename_all = "camelCase")]
 pub struct PbrSpecularGlossiness {
     /// The material's diffuse factor.
@@ -95,6 +98,7 @@ pub struct PbrSpecularGlossiness {
     /// material. Metals have a diffuse value of `[0.0, 0.0, 0.0]`. The fourth
     /// component (A) is the alpha coverage of the material. The `alphaMode`
     /// property specifies how alpha is interpreted. The values are linear.
+    #[serde(skip_serializing_if = "is_pbr_diffuse_factor_default")]
     pub diffuse_factor: PbrDiffuseFactor,
 
     /// The diffuse texture.
@@ -108,6 +112,7 @@ pub struct PbrSpecularGlossiness {
     pub diffuse_texture: Option<texture::Info>,
 
     /// The material's specular factor.
+    #[serde(skip_serializing_if = "is_pbr_specular_factor_default")]
     pub specular_factor: PbrSpecularFactor,
 
     /// The glossiness or smoothness of the material.
@@ -115,6 +120,7 @@ pub struct PbrSpecularGlossiness {
     /// A value of 1.0 means the material has full glossiness or is perfectly
     /// smooth. A value of 0.0 means the material has no glossiness or is
     /// completely rough. This value is linear.
+    #[serde(skip_serializing_if = "crate::material::is_strength_factor_default")]
     pub glossiness_factor: StrengthFactor,
 
     /// The specular-glossiness texture.
@@ -137,7 +143,16 @@ pub struct PbrSpecularGlossiness {
 
 /// Defines the normal texture of a material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct NormalTexture {
+    #[cfg(feature = "KHR_texture_transform")]
+    #[serde(
+        default,
+        rename = "KHR_texture_transform",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_transform: Option<crate::extensions::texture::TextureTransform>,
+
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
@@ -145,7 +160,16 @@ pub struct NormalTexture {
 
 /// Defines the occlusion texture of a material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct OcclusionTexture {
+    #[cfg(feature = "KHR_texture_transform")]
+    #[serde(
+        default,
+        rename = "KHR_texture_transform",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_transform: Option<crate::extensions::texture::TextureTransform>,
+
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
@@ -154,6 +178,7 @@ pub struct OcclusionTexture {
 /// The diffuse factor of a material.
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct PbrDiffuseFactor(pub [f32; 4]);
 
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
@@ -166,9 +191,15 @@ impl Default for PbrDiffuseFactor {
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
 impl Validate for PbrDiffuseFactor {}
 
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+fn is_pbr_diffuse_factor_default(factor: &PbrDiffuseFactor) -> bool {
+    factor.0 == [1.0; 4]
+}
+
 /// The specular factor of a material.
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct PbrSpecularFactor(pub [f32; 3]);
 
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
@@ -181,14 +212,21 @@ impl Default for PbrSpecularFactor {
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
 impl Validate for PbrSpecularFactor {}
 
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+fn is_pbr_specular_factor_default(factor: &PbrSpecularFactor) -> bool {
+    factor.0 == [1.0; 3]
+}
+
 /// Empty struct that should be present for primitives which should not be shaded with the PBR shading model.
 #[cfg(feature = "KHR_materials_unlit")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Unlit {}
 
 /// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
 #[cfg(feature = "KHR_materials_transmission")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct TransmissionFactor(pub f32);
 
 #[cfg(feature = "KHR_materials_transmission")]
@@ -201,8 +239,14 @@ impl Default for TransmissionFactor {
 #[cfg(feature = "KHR_materials_transmission")]
 impl Validate for TransmissionFactor {}
 
+#[cfg(feature = "KHR_materials_transmission")]
+fn is_transmission_factor_default(factor: &TransmissionFactor) -> bool {
+    factor.0 == 0.0
+}
+
 #[cfg(feature = "KHR_materials_transmission")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[serde(default, rename_all = "camelCase")]
 pub struct Transmission {
     /// The base percentage of light that is transmitted through the surface.
@@ -211,6 +255,7 @@ pub struct Transmission {
     /// This is a percentage of all the light that penetrates a surface (i.e. isn’t specularly reflected)
     /// rather than a percentage of the total light that hits a surface.
     /// A value of 1.0 means that 100% of the light that penetrates the surface is transmitted through.
+    #[serde(skip_serializing_if = "is_transmission_factor_default")]
     pub transmission_factor: TransmissionFactor,
 
     /// The transmission texture.
@@ -231,6 +276,7 @@ pub struct Transmission {
 /// A positive number with default value of 1.5
 #[cfg(feature = "KHR_materials_ior")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct IndexOfRefraction(pub f32);
 
 #[cfg(feature = "KHR_materials_ior")]
@@ -243,8 +289,14 @@ impl Default for IndexOfRefraction {
 #[cfg(feature = "KHR_materials_ior")]
 impl Validate for IndexOfRefraction {}
 
+#[cfg(feature = "KHR_materials_ior")]
+fn is_index_of_refraction_default(factor: &IndexOfRefraction) -> bool {
+    factor.0 == 1.5
+}
+
 #[cfg(feature = "KHR_materials_ior")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[serde(default, rename_all = "camelCase")]
 pub struct Ior {
     /// The index of refraction.
@@ -252,6 +304,7 @@ pub struct Ior {
     /// Typical values for the index of refraction range from 1 to 2.
     /// In rare cases values greater than 2 are possible.
     /// For example, the ior of water is 1.33, and diamond is 2.42
+    #[serde(skip_serializing_if = "is_index_of_refraction_default")]
     pub ior: IndexOfRefraction,
 
     /// Optional application specific data.
@@ -263,6 +316,7 @@ pub struct Ior {
 /// A positive number with 1.0 as the default value.
 #[cfg(feature = "KHR_materials_emissive_strength")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct EmissiveStrengthFactor(pub f32);
 
 #[cfg(feature = "KHR_materials_emissive_strength")]
@@ -275,17 +329,25 @@ impl Default for EmissiveStrengthFactor {
 #[cfg(feature = "KHR_materials_emissive_strength")]
 impl Validate for EmissiveStrengthFactor {}
 
+#[cfg(feature = "KHR_materials_emissive_strength")]
+fn is_emissive_strength_factor_default(factor: &EmissiveStrengthFactor) -> bool {
+    factor.0 == 1.0
+}
+
 #[cfg(feature = "KHR_materials_emissive_strength")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[serde(default, rename_all = "camelCase")]
 pub struct EmissiveStrength {
     /// The factor by which to scale the emissive factor or emissive texture.
+    #[serde(skip_serializing_if = "is_emissive_strength_factor_default")]
     pub emissive_strength: EmissiveStrengthFactor,
 }
 
 /// A number in the inclusive range [0.0, +inf] with a default value of 0.0.
 #[cfg(feature = "KHR_materials_volume")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct ThicknessFactor(pub f32);
 
 #[cfg(feature = "KHR_materials_volume")]
@@ -298,9 +360,15 @@ impl Default for ThicknessFactor {
 #[cfg(feature = "KHR_materials_volume")]
 impl Validate for ThicknessFactor {}
 
+#[cfg(feature = "KHR_materials_volume")]
+fn is_thickness_factor_default(factor: &ThicknessFactor) -> bool {
+    factor.0 == 0.0
+}
+
 /// A number in the inclusive range [0.0, +inf] with a default value of +inf.
 #[cfg(feature = "KHR_materials_volume")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct AttenuationDistance(pub f32);
 
 #[cfg(feature = "KHR_materials_volume")]
@@ -313,9 +381,15 @@ impl Default for AttenuationDistance {
 #[cfg(feature = "KHR_materials_volume")]
 impl Validate for AttenuationDistance {}
 
+#[cfg(feature = "KHR_materials_volume")]
+fn is_attenuation_distance_default(factor: &AttenuationDistance) -> bool {
+    factor.0 == f32::INFINITY
+}
+
 /// A colour in the inclusive range [[0.0; 3], [1.0; 3]] with a default value of [1.0; 3].
 #[cfg(feature = "KHR_materials_volume")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct AttenuationColor(pub [f32; 3]);
 
 #[cfg(feature = "KHR_materials_volume")]
@@ -328,8 +402,14 @@ impl Default for AttenuationColor {
 #[cfg(feature = "KHR_materials_volume")]
 impl Validate for AttenuationColor {}
 
+#[cfg(feature = "KHR_materials_volume")]
+fn is_attenuation_color_default(factor: &AttenuationColor) -> bool {
+    factor.0 == [1.0; 3]
+}
+
 #[cfg(feature = "KHR_materials_volume")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[serde(default, rename_all = "camelCase")]
 pub struct Volume {
     /// The thickness of the volume beneath the surface. The value is
@@ -337,6 +417,7 @@ pub struct Volume {
     /// the material is thin-walled. Otherwise the material is a
     /// volume boundary. The `doubleSided` property has no effect on
     /// volume boundaries. Range is [0, +inf).
+    #[serde(skip_serializing_if = "is_thickness_factor_default")]
     pub thickness_factor: ThicknessFactor,
 
     /// A texture that defines the thickness, stored in the G channel.
@@ -347,10 +428,12 @@ pub struct Volume {
     /// Density of the medium given as the average distance that light
     /// travels in the medium before interacting with a particle. The
     /// value is given in world space. Range is (0, +inf).
+    #[serde(skip_serializing_if = "is_attenuation_distance_default")]
     pub attenuation_distance: AttenuationDistance,
 
     /// The color that white light turns into due to absorption when
     /// reaching the attenuation distance.
+    #[serde(skip_serializing_if = "is_attenuation_color_default")]
     pub attenuation_color: AttenuationColor,
 
     /// Optional application specific data.
@@ -362,6 +445,7 @@ pub struct Volume {
 /// A number in the inclusive range [0.0, +inf] with a default value of 1.0.
 #[cfg(feature = "KHR_materials_specular")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct SpecularFactor(pub f32);
 
 #[cfg(feature = "KHR_materials_specular")]
@@ -374,9 +458,15 @@ impl Default for SpecularFactor {
 #[cfg(feature = "KHR_materials_specular")]
 impl Validate for SpecularFactor {}
 
+#[cfg(feature = "KHR_materials_specular")]
+fn is_specular_factor_default(factor: &SpecularFactor) -> bool {
+    factor.0 == 1.0
+}
+
 /// A colour in the inclusive range [[0.0; 3], [1.0; 3]] with a default value of [1.0; 3].
 #[cfg(feature = "KHR_materials_specular")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct SpecularColorFactor(pub [f32; 3]);
 
 #[cfg(feature = "KHR_materials_specular")]
@@ -389,11 +479,18 @@ impl Default for SpecularColorFactor {
 #[cfg(feature = "KHR_materials_specular")]
 impl Validate for SpecularColorFactor {}
 
+#[cfg(feature = "KHR_materials_specular")]
+fn is_specular_color_factor_default(factor: &SpecularColorFactor) -> bool {
+    factor.0 == [1.0; 3]
+}
+
 #[cfg(feature = "KHR_materials_specular")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[serde(default, rename_all = "camelCase")]
 pub struct Specular {
     /// The strength of the specular reflection.
+    #[serde(skip_serializing_if = "is_specular_factor_default")]
     pub specular_factor: SpecularFactor,
 
     /// A texture that defines the strength of the specular reflection,
@@ -403,6 +500,7 @@ pub struct Specular {
     pub specular_texture: Option<texture::Info>,
 
     /// The F0 color of the specular reflection (linear RGB).
+    #[serde(skip_serializing_if = "is_specular_color_factor_default")]
     pub specular_color_factor: SpecularColorFactor,
 
     /// A texture that defines the F0 color of the specular reflection,
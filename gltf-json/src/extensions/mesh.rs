@@ -10,6 +10,7 @@ use serde_json::{Map, Value};
 /// A node can contain one or more meshes and its transform places the meshes in
 /// the scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Mesh {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
@@ -18,6 +19,7 @@ pub struct Mesh {
 
 /// Geometry to be rendered with the given material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Primitive {
     #[cfg(feature = "KHR_materials_variants")]
     #[serde(
@@ -33,13 +35,17 @@ pub struct Primitive {
 
 #[cfg(feature = "KHR_materials_variants")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct KhrMaterialsVariants {
     pub mappings: Vec<Mapping>,
 }
 
 #[cfg(feature = "KHR_materials_variants")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Mapping {
+    #[serde(deserialize_with = "crate::validation::deserialize_u32")]
     pub material: u32,
+    #[serde(deserialize_with = "crate::validation::deserialize_u32_vec")]
     pub variants: Vec<u32>,
 }
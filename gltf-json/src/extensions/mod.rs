@@ -2,6 +2,7 @@
 pub mod accessor;
 
 /// Contains `Animation` and other related data structures.
+#[cfg(feature = "animation")]
 pub mod animation;
 
 /// Contains `Asset` metadata.
@@ -11,6 +12,7 @@ pub mod asset;
 pub mod buffer;
 
 /// Contains `Camera` and other related data structures.
+#[cfg(feature = "cameras")]
 pub mod camera;
 
 /// Contains `Image` and other related data structures.
@@ -29,6 +31,7 @@ pub mod root;
 pub mod scene;
 
 /// Contains `Skin` and other related data structures.
+#[cfg(feature = "skinning")]
 pub mod skin;
 
 /// Contains `Texture`, `Sampler`, and other related data structures.
@@ -36,8 +39,11 @@ pub mod texture;
 
 pub use self::root::Root;
 
-/// Names of glTF 2.0 extensions enabled by the user.
-pub const ENABLED_EXTENSIONS: &[&str] = &[
+/// Names of the glTF 2.0 extensions understood by this build of the crate.
+///
+/// The contents depend on the enabled cargo features. This is the registry
+/// consulted when checking `extensionsRequired` during validation.
+pub const SUPPORTED: &[&str] = &[
     #[cfg(feature = "KHR_lights_punctual")]
     "KHR_lights_punctual",
     #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
@@ -52,15 +58,45 @@ pub const ENABLED_EXTENSIONS: &[&str] = &[
     "KHR_materials_ior",
     #[cfg(feature = "KHR_materials_emissive_strength")]
     "KHR_materials_emissive_strength",
-    // Allowlisted texture extensions. Processing is delegated to the user.
-    #[cfg(feature = "allow_empty_texture")]
+    #[cfg(feature = "KHR_materials_variants")]
+    "KHR_materials_variants",
+    #[cfg(feature = "KHR_materials_volume")]
+    "KHR_materials_volume",
+    #[cfg(feature = "KHR_materials_specular")]
+    "KHR_materials_specular",
+    // Quantized attributes are read as normalized or unnormalized integers.
+    "KHR_mesh_quantization",
+    // Decompression of buffer views is delegated to the user.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    "EXT_meshopt_compression",
+    // Transcoding of KTX2 images is delegated to the user.
+    #[cfg(any(feature = "KHR_texture_basisu", feature = "allow_empty_texture"))]
     "KHR_texture_basisu",
-    #[cfg(feature = "allow_empty_texture")]
+    // Decoding of WebP images is delegated to the user.
+    #[cfg(any(feature = "EXT_texture_webp", feature = "allow_empty_texture"))]
     "EXT_texture_webp",
+    // Allowlisted texture extensions. Processing is delegated to the user.
     #[cfg(feature = "allow_empty_texture")]
     "MSFT_texture_dds",
 ];
 
+/// Names of glTF 2.0 extensions enabled by the user.
+///
+/// This is an alias of [`SUPPORTED`].
+pub const ENABLED_EXTENSIONS: &[&str] = SUPPORTED;
+
+/// Returns the names of the glTF 2.0 extensions understood by this build of
+/// the crate.
+pub fn supported_extensions() -> &'static [&'static str] {
+    SUPPORTED
+}
+
+/// Returns `true` if the named extension is understood by this build of the
+/// crate.
+pub fn is_supported(name: &str) -> bool {
+    SUPPORTED.contains(&name)
+}
+
 /// Names of glTF 2.0 extensions supported by the library.
 pub const SUPPORTED_EXTENSIONS: &[&str] = &[
     "KHR_lights_punctual",
@@ -70,4 +106,84 @@ pub const SUPPORTED_EXTENSIONS: &[&str] = &[
     "KHR_materials_transmission",
     "KHR_materials_ior",
     "KHR_materials_emissive_strength",
+    "KHR_materials_variants",
+    "KHR_materials_volume",
+    "KHR_materials_specular",
+    "KHR_mesh_quantization",
+    "EXT_meshopt_compression",
+    "KHR_texture_basisu",
+    "EXT_texture_webp",
+    "MSFT_texture_dds",
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_matches_features() {
+        let features = [
+            ("KHR_lights_punctual", cfg!(feature = "KHR_lights_punctual")),
+            (
+                "KHR_materials_pbrSpecularGlossiness",
+                cfg!(feature = "KHR_materials_pbrSpecularGlossiness"),
+            ),
+            ("KHR_materials_unlit", cfg!(feature = "KHR_materials_unlit")),
+            (
+                "KHR_texture_transform",
+                cfg!(feature = "KHR_texture_transform"),
+            ),
+            (
+                "KHR_materials_transmission",
+                cfg!(feature = "KHR_materials_transmission"),
+            ),
+            ("KHR_materials_ior", cfg!(feature = "KHR_materials_ior")),
+            (
+                "KHR_materials_emissive_strength",
+                cfg!(feature = "KHR_materials_emissive_strength"),
+            ),
+            (
+                "KHR_materials_variants",
+                cfg!(feature = "KHR_materials_variants"),
+            ),
+            (
+                "KHR_materials_volume",
+                cfg!(feature = "KHR_materials_volume"),
+            ),
+            (
+                "KHR_materials_specular",
+                cfg!(feature = "KHR_materials_specular"),
+            ),
+            ("KHR_mesh_quantization", true),
+            (
+                "EXT_meshopt_compression",
+                cfg!(feature = "EXT_meshopt_compression"),
+            ),
+            (
+                "KHR_texture_basisu",
+                cfg!(any(
+                    feature = "KHR_texture_basisu",
+                    feature = "allow_empty_texture"
+                )),
+            ),
+            (
+                "EXT_texture_webp",
+                cfg!(any(
+                    feature = "EXT_texture_webp",
+                    feature = "allow_empty_texture"
+                )),
+            ),
+            ("MSFT_texture_dds", cfg!(feature = "allow_empty_texture")),
+        ];
+        for (name, enabled) in features {
+            assert_eq!(is_supported(name), enabled, "{}", name);
+        }
+        let enabled = features.iter().filter(|(_, enabled)| *enabled).count();
+        assert_eq!(supported_extensions().len(), enabled);
+        assert!(!is_supported("EXT_unknown"));
+
+        for name in SUPPORTED {
+            assert!(SUPPORTED_EXTENSIONS.contains(name), "{}", name);
+        }
+    }
+}
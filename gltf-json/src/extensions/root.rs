@@ -7,6 +7,7 @@ use serde_json::{Map, Value};
 
 /// The root object of a glTF 2.0 asset.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Root {
     #[cfg(feature = "KHR_lights_punctual")]
     #[serde(
@@ -31,6 +32,7 @@ pub struct Root {
 
 #[cfg(feature = "KHR_lights_punctual")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct KhrLightsPunctual {
     /// Lights at this node.
     pub lights: Vec<crate::extensions::scene::khr_lights_punctual::Light>,
@@ -78,6 +80,7 @@ impl AsMut<Vec<crate::extensions::scene::khr_lights_punctual::Light>> for crate:
 
 #[cfg(feature = "KHR_materials_variants")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct KhrMaterialsVariants {
     pub variants: Vec<crate::extensions::scene::khr_materials_variants::Variant>,
 }
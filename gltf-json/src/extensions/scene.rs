@@ -15,6 +15,7 @@ use serde_json::{Map, Value};
 /// animation.channel.target), only TRS properties may be present; `matrix` will not
 /// be present.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Node {
     #[cfg(feature = "KHR_lights_punctual")]
     #[serde(
@@ -31,7 +32,6 @@ pub struct Node {
 
 #[cfg(feature = "KHR_lights_punctual")]
 pub mod khr_lights_punctual {
-    use alloc::string::String;
     use core::fmt;
     use crate::validation::{Checked, Error};
     use crate::{Extras, Index, Path, Root};
@@ -43,12 +43,14 @@ pub mod khr_lights_punctual {
     pub const VALID_TYPES: &[&str] = &["directional", "point", "spot"];
 
     #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
     pub struct KhrLightsPunctual {
         pub light: Index<Light>,
     }
 
     /// Specifies the light type.
     #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    #[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
     pub enum Type {
         /// Directional lights act as though they are infinitely far away and emit light in
         /// the direction of the local -z axis. This light type inherits the orientation of
@@ -79,10 +81,12 @@ pub mod khr_lights_punctual {
     }
 
     #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
     #[gltf(validate_hook = "light_validate_hook")]
     pub struct Light {
         /// Color of the light source.
         #[serde(default = "color_default")]
+        #[serde(skip_serializing_if = "is_color_default")]
         pub color: [f32; 3],
 
         /// Extension specific data.
@@ -98,12 +102,13 @@ pub mod khr_lights_punctual {
         /// Intensity of the light source. `point` and `spot` lights use luminous intensity
         /// in candela (lm/sr) while `directional` lights use illuminance in lux (lm/m^2).
         #[serde(default = "intensity_default")]
+        #[serde(skip_serializing_if = "is_intensity_default")]
         pub intensity: f32,
 
         /// Optional user-defined name for this object.
         #[cfg(feature = "names")]
         #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-        pub name: Option<String>,
+        pub name: Option<crate::Str>,
 
         /// A distance cutoff at which the light's intensity may be considered to have reached
         /// zero.
@@ -139,16 +144,28 @@ pub mod khr_lights_punctual {
         1.0
     }
 
+    fn is_color_default(color: &[f32; 3]) -> bool {
+        *color == color_default()
+    }
+
+    fn is_intensity_default(intensity: &f32) -> bool {
+        *intensity == intensity_default()
+    }
+
     /// Spot light parameters.
     #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
     #[serde(rename_all = "camelCase")]
     pub struct Spot {
         /// Angle in radians from centre of spotlight where falloff begins.
-        #[serde(default)]
+        #[serde(default, skip_serializing_if = "is_inner_cone_angle_default")]
         pub inner_cone_angle: f32,
 
         /// Angle in radians from centre of spotlight where falloff ends.
-        #[serde(default = "outer_cone_angle_default")]
+        #[serde(
+            default = "outer_cone_angle_default",
+            skip_serializing_if = "is_outer_cone_angle_default"
+        )]
         pub outer_cone_angle: f32,
     }
 
@@ -156,6 +173,14 @@ pub mod khr_lights_punctual {
         core::f32::consts::FRAC_PI_4
     }
 
+    fn is_inner_cone_angle_default(angle: &f32) -> bool {
+        *angle == 0.0
+    }
+
+    fn is_outer_cone_angle_default(angle: &f32) -> bool {
+        *angle == outer_cone_angle_default()
+    }
+
     impl<'de> de::Deserialize<'de> for Checked<Type> {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where
@@ -209,6 +234,7 @@ pub mod khr_materials_variants {
     use serde_derive::{Deserialize, Serialize};
 
     #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
     pub struct Variant {
         pub name: String,
     }
@@ -226,6 +252,7 @@ pub mod khr_materials_variants {
 
 /// The root `Node`s of a scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Scene {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
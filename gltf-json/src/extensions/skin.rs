@@ -6,6 +6,7 @@ use serde_json::{Map, Value};
 
 /// Joints and matrices defining a skin.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Skin {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
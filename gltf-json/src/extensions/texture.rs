@@ -1,6 +1,14 @@
 use alloc::string::String;
+#[cfg(any(
+    feature = "KHR_texture_transform",
+    feature = "KHR_texture_basisu",
+    feature = "EXT_texture_webp"
+))]
+use crate::extras::Extras;
 #[cfg(feature = "KHR_texture_transform")]
-use crate::{extras::Extras, validation::Validate};
+use crate::validation::Validate;
+#[cfg(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp"))]
+use crate::{image, Index};
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 #[cfg(feature = "extensions")]
@@ -8,6 +16,7 @@ use serde_json::{Map, Value};
 
 /// Texture sampler properties for filtering and wrapping modes.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Sampler {
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
@@ -16,13 +25,68 @@ pub struct Sampler {
 
 /// A texture and its sampler.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Texture {
+    #[cfg(feature = "KHR_texture_basisu")]
+    #[serde(
+        default,
+        rename = "KHR_texture_basisu",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_basisu: Option<TextureBasisu>,
+
+    #[cfg(feature = "EXT_texture_webp")]
+    #[serde(
+        default,
+        rename = "EXT_texture_webp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_webp: Option<TextureWebp>,
+
     #[cfg(feature = "extensions")]
     #[serde(default, flatten)]
     pub others: Map<String, Value>,
 }
 
+/// A texture whose image is a KTX2 image with Basis Universal
+/// supercompression.
+///
+/// The KTX2 image is used instead of the image of `texture.source`, which is a
+/// fallback for clients that do not support the extension, if any.
+#[cfg(feature = "KHR_texture_basisu")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
+pub struct TextureBasisu {
+    /// The index of the KTX2 image used by the texture.
+    pub source: Index<image::Image>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
+/// A texture whose image is a WebP image.
+///
+/// The WebP image is used instead of the image of `texture.source`, which is a
+/// fallback for clients that do not support the extension, if any.
+#[cfg(feature = "EXT_texture_webp")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
+pub struct TextureWebp {
+    /// The index of the WebP image used by the texture.
+    pub source: Index<image::Image>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    #[cfg_attr(not(feature = "extras"), serde(skip_serializing))]
+    pub extras: Extras,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 /// Reference to a `Texture`.
 pub struct Info {
     #[cfg(feature = "KHR_texture_transform")]
@@ -47,19 +111,27 @@ pub struct Info {
 /// These properties would typically be implemented as an affine transform on the UV coordinates.
 #[cfg(feature = "KHR_texture_transform")]
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[serde(default, rename_all = "camelCase")]
 pub struct TextureTransform {
     // The offset of the UV coordinate origin as a factor of the texture dimensions.
+    #[serde(skip_serializing_if = "is_offset_default")]
     pub offset: TextureTransformOffset,
 
     /// Rotate the UVs by this many radians counter-clockwise around the origin.
     /// This is equivalent to a similar rotation of the image clockwise.
+    #[serde(skip_serializing_if = "is_rotation_default")]
     pub rotation: TextureTransformRotation,
 
     /// The scale factor applied to the components of the UV coordinates.
+    #[serde(skip_serializing_if = "is_scale_default")]
     pub scale: TextureTransformScale,
 
     /// Overrides the textureInfo texCoord value if supplied, and if this extension is supported.
+    #[serde(
+        deserialize_with = "crate::validation::deserialize_optional_u32",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub tex_coord: Option<u32>,
 
     /// Optional application specific data.
@@ -71,6 +143,7 @@ pub struct TextureTransform {
 /// The offset of the UV coordinate origin as a factor of the texture dimensions.
 #[cfg(feature = "KHR_texture_transform")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct TextureTransformOffset(pub [f32; 2]);
 
 #[cfg(feature = "KHR_texture_transform")]
@@ -83,10 +156,16 @@ impl Default for TextureTransformOffset {
 #[cfg(feature = "KHR_texture_transform")]
 impl Validate for TextureTransformOffset {}
 
+#[cfg(feature = "KHR_texture_transform")]
+fn is_offset_default(offset: &TextureTransformOffset) -> bool {
+    offset.0 == [0.0; 2]
+}
+
 /// Rotate the UVs by this many radians counter-clockwise around the origin.
 /// This is equivalent to a similar rotation of the image clockwise.
 #[cfg(feature = "KHR_texture_transform")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct TextureTransformRotation(pub f32);
 
 #[cfg(feature = "KHR_texture_transform")]
@@ -99,9 +178,15 @@ impl Default for TextureTransformRotation {
 #[cfg(feature = "KHR_texture_transform")]
 impl Validate for TextureTransformRotation {}
 
+#[cfg(feature = "KHR_texture_transform")]
+fn is_rotation_default(rotation: &TextureTransformRotation) -> bool {
+    rotation.0 == 0.0
+}
+
 /// The scale factor applied to the components of the UV coordinates.
 #[cfg(feature = "KHR_texture_transform")]
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct TextureTransformScale(pub [f32; 2]);
 
 #[cfg(feature = "KHR_texture_transform")]
@@ -113,3 +198,8 @@ impl Default for TextureTransformScale {
 
 #[cfg(feature = "KHR_texture_transform")]
 impl Validate for TextureTransformScale {}
+
+#[cfg(feature = "KHR_texture_transform")]
+fn is_scale_default(scale: &TextureTransformScale) -> bool {
+    scale.0 == [1.0; 2]
+}
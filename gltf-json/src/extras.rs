@@ -2,12 +2,22 @@ use core::fmt;
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
+#[cfg(feature = "extras")]
+use alloc::{boxed::Box, string::String};
+#[cfg(feature = "extras")]
+use serde::{de, ser};
+
+#[cfg(feature = "extras")]
+use crate::text::Str;
+#[cfg(feature = "extras")]
+use crate::validation::Validate;
+
 #[cfg(feature = "extras")]
 pub use serde_json::value::RawValue;
 
 /// Data type of the `extras` attribute on all glTF objects.
 #[cfg(feature = "extras")]
-pub type Extras = Option<alloc::boxed::Box<RawValue>>;
+pub type Extras = Option<RawJson>;
 
 /// Data type of the `extras` attribute on all glTF objects.
 #[cfg(not(feature = "extras"))]
@@ -15,6 +25,7 @@ pub type Extras = Void;
 
 /// Type representing no user-defined data.
 #[derive(Clone, Default, Serialize, Deserialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Void {
     #[serde(default, skip_serializing)]
     _allow_unknown_fields: (),
@@ -31,3 +42,152 @@ impl fmt::Display for Void {
         write!(f, "{{}}")
     }
 }
+
+/// The JSON text of a user-defined value, kept as it appears in the
+/// document.
+///
+/// Like [`RawValue`], the text is not parsed beyond being checked; use
+/// `serde_json::from_str(raw.get())` to read it. Like [`Str`], the text of
+/// documents loaded with an [`ImportArena`] is stored in the arena.
+///
+/// [`ImportArena`]: crate::arena::ImportArena
+#[cfg(feature = "extras")]
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct RawJson(Str);
+
+#[cfg(feature = "extras")]
+impl RawJson {
+    /// Checks that `json` is valid JSON and wraps it.
+    pub fn from_string(json: String) -> Result<Self, serde_json::Error> {
+        serde_json::from_str::<&RawValue>(&json)?;
+        Ok(RawJson(Str::from(json)))
+    }
+
+    /// Returns the JSON text.
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "extras")]
+impl From<Box<RawValue>> for RawJson {
+    fn from(raw: Box<RawValue>) -> Self {
+        RawJson(Str::from(String::from(Box::<str>::from(raw))))
+    }
+}
+
+#[cfg(feature = "extras")]
+impl fmt::Debug for RawJson {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("RawJson")
+            .field(&format_args!("{}", self.get()))
+            .finish()
+    }
+}
+
+#[cfg(feature = "extras")]
+impl fmt::Display for RawJson {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.get())
+    }
+}
+
+#[cfg(feature = "extras")]
+impl ser::Serialize for RawJson {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let raw: &RawValue = serde_json::from_str(self.get()).map_err(ser::Error::custom)?;
+        raw.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "extras")]
+impl<'de> de::Deserialize<'de> for RawJson {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match raw_value_name() {
+            Some(name) => deserializer.deserialize_newtype_struct(name, RawJsonVisitor),
+            None => Box::<RawValue>::deserialize(deserializer).map(RawJson::from),
+        }
+    }
+}
+
+/// Receives the text of a raw value from serde_json, which is borrowed from
+/// the input when possible, unlike for `Box<RawValue>`.
+#[cfg(feature = "extras")]
+struct RawJsonVisitor;
+
+#[cfg(feature = "extras")]
+impl<'de> de::Visitor<'de> for RawJsonVisitor {
+    type Value = RawJson;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any valid JSON value")
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<RawJson, A::Error> {
+        if map.next_key::<de::IgnoredAny>()?.is_none() {
+            return Err(de::Error::invalid_type(de::Unexpected::Map, &self));
+        }
+        map.next_value().map(RawJson)
+    }
+}
+
+/// Returns the name of the newtype struct that serde_json deserializes raw
+/// values as, which it does not export, by asking `&RawValue` for it.
+#[cfg(feature = "extras")]
+fn raw_value_name() -> Option<&'static str> {
+    match <&RawValue as de::Deserialize>::deserialize(NameProbe) {
+        Err(NameOf(name)) => name,
+        Ok(_) => None,
+    }
+}
+
+/// A deserializer that only records the name of a newtype struct.
+#[cfg(feature = "extras")]
+struct NameProbe;
+
+#[cfg(feature = "extras")]
+#[derive(Debug)]
+struct NameOf(Option<&'static str>);
+
+#[cfg(feature = "extras")]
+impl fmt::Display for NameOf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("not a newtype struct")
+    }
+}
+
+#[cfg(feature = "extras")]
+impl de::StdError for NameOf {}
+
+#[cfg(feature = "extras")]
+impl de::Error for NameOf {
+    fn custom<T: fmt::Display>(_: T) -> Self {
+        NameOf(None)
+    }
+}
+
+#[cfg(feature = "extras")]
+impl<'de> de::Deserializer<'de> for NameProbe {
+    type Error = NameOf;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, _: V) -> Result<V::Value, NameOf> {
+        Err(NameOf(None))
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        _: V,
+    ) -> Result<V::Value, NameOf> {
+        Err(NameOf(Some(name)))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct seq tuple tuple_struct map
+        struct enum identifier ignored_any
+    }
+}
+
+#[cfg(feature = "extras")]
+impl Validate for RawJson {}
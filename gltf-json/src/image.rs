@@ -1,14 +1,23 @@
 use alloc::string::String;
-use crate::validation::Validate;
-use crate::{buffer, extensions, Extras, Index};
+use crate::validation::{is_blank, Error, Validate};
+use crate::{buffer, extensions, Extras, Index, Path, Root};
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
 /// All valid MIME types.
-pub const VALID_MIME_TYPES: &[&str] = &["image/jpeg", "image/png"];
+pub const VALID_MIME_TYPES: &[&str] = &[
+    "image/jpeg",
+    "image/png",
+    #[cfg(feature = "KHR_texture_basisu")]
+    "image/ktx2",
+    #[cfg(feature = "EXT_texture_webp")]
+    "image/webp",
+];
 
 /// Image data used to create a texture.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
+#[gltf(validate_hook = "image_validate_hook")]
 pub struct Image {
     /// The index of the buffer view that contains the image. Use this instead of
     /// the image's uri property.
@@ -24,13 +33,13 @@ pub struct Image {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// The uri of the image.  Relative paths are relative to the .gltf file.
     /// Instead of referencing an external file, the uri can also be a data-uri.
     /// The image format must be jpg or png.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub uri: Option<String>,
+    pub uri: Option<crate::Str>,
 
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -43,8 +52,26 @@ pub struct Image {
     pub extras: Extras,
 }
 
+fn image_validate_hook<P, R>(image: &Image, _root: &Root, path: P, report: &mut R)
+where
+    P: Fn() -> Path,
+    R: FnMut(&dyn Fn() -> Path, Error),
+{
+    if image.uri.as_deref().map_or(false, is_blank) {
+        report(&|| path().field("uri"), Error::Invalid);
+    }
+    if image
+        .mime_type
+        .as_ref()
+        .map_or(false, |mime| is_blank(&mime.0))
+    {
+        report(&|| path().field("mimeType"), Error::Invalid);
+    }
+}
+
 /// An image MIME type.
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct MimeType(pub String);
 
 impl Validate for MimeType {}
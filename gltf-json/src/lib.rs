@@ -8,15 +8,25 @@ extern crate alloc;
 pub mod accessor;
 
 /// Contains `Animation` and other related data structures.
+#[cfg(feature = "animation")]
 pub mod animation;
 
+/// Contains `ImportArena`, which holds the names, URIs and extras of documents.
+#[cfg(feature = "arena")]
+pub mod arena;
+
 /// Contains `Asset` metadata.
 pub mod asset;
 
+/// Contains `Bake`, the compact binary encoding of the JSON data structures.
+#[cfg(feature = "bake")]
+pub mod bake;
+
 /// Contains `Buffer`, `View`, and other related data structures.
 pub mod buffer;
 
 /// Contains `Camera` and other related data structures.
+#[cfg(feature = "cameras")]
 pub mod camera;
 
 /// Contains extension specific data structures and the names of all
@@ -45,8 +55,12 @@ pub mod root;
 pub mod scene;
 
 /// Contains `Skin` and other related data structures.
+#[cfg(feature = "skinning")]
 pub mod skin;
 
+/// Contains `Str`, the string type of names and URIs.
+pub mod text;
+
 /// Contains `Texture`, `Sampler`, and other related data structures.
 pub mod texture;
 
@@ -55,12 +69,14 @@ pub mod validation;
 
 #[doc(inline)]
 pub use accessor::Accessor;
+#[cfg(feature = "animation")]
 #[doc(inline)]
 pub use animation::Animation;
 #[doc(inline)]
 pub use asset::Asset;
 #[doc(inline)]
 pub use buffer::Buffer;
+#[cfg(feature = "cameras")]
 #[doc(inline)]
 pub use camera::Camera;
 #[doc(inline)]
@@ -73,6 +89,7 @@ pub use mesh::Mesh;
 pub use scene::Node;
 #[doc(inline)]
 pub use scene::Scene;
+#[cfg(feature = "skinning")]
 #[doc(inline)]
 pub use skin::Skin;
 #[doc(inline)]
@@ -86,6 +103,8 @@ pub use self::path::Path;
 pub use self::root::Index;
 #[doc(inline)]
 pub use self::root::Root;
+#[doc(inline)]
+pub use self::text::Str;
 
 #[doc(inline)]
 pub use serde_json::Error;
@@ -1,4 +1,3 @@
-use alloc::string::String;
 use core::fmt;
 use crate::validation::{Checked, Validate};
 use crate::{extensions, texture, Extras, Index};
@@ -11,6 +10,7 @@ pub const VALID_ALPHA_MODES: &[&str] = &["OPAQUE", "MASK", "BLEND"];
 
 /// The alpha rendering mode of a material.
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub enum AlphaMode {
     /// The alpha value is ignored and the rendered output is fully opaque.
     Opaque = 1,
@@ -39,6 +39,7 @@ impl ser::Serialize for AlphaMode {
 
 /// The material appearance of a primitive.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[serde(default)]
 pub struct Material {
     /// The alpha cutoff value of the material.
@@ -64,6 +65,7 @@ pub struct Material {
     ///   background using the normal painting operation (i.e. the Porter and
     ///   Duff over operator).
     #[serde(rename = "alphaMode")]
+    #[serde(skip_serializing_if = "is_alpha_mode_default")]
     pub alpha_mode: Checked<AlphaMode>,
 
     /// Specifies whether the material is double-sided.
@@ -76,17 +78,19 @@ pub struct Material {
     /// The back-face must have its normals reversed before the lighting
     /// equation is evaluated.
     #[serde(rename = "doubleSided")]
+    #[serde(skip_serializing_if = "is_double_sided_default")]
     pub double_sided: bool,
 
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// A set of parameter values that are used to define the metallic-roughness
     /// material model from Physically-Based Rendering (PBR) methodology. When not
     /// specified, all the default values of `pbrMetallicRoughness` apply.
     #[serde(default, rename = "pbrMetallicRoughness")]
+    #[serde(skip_serializing_if = "is_pbr_metallic_roughness_default")]
     pub pbr_metallic_roughness: PbrMetallicRoughness,
 
     /// A tangent space normal map. The texture contains RGB components in linear
@@ -117,6 +121,7 @@ pub struct Material {
 
     /// The emissive color of the material.
     #[serde(rename = "emissiveFactor")]
+    #[serde(skip_serializing_if = "is_emissive_factor_default")]
     pub emissive_factor: EmissiveFactor,
 
     /// Extension specific data.
@@ -129,13 +134,42 @@ pub struct Material {
     pub extras: Extras,
 }
 
+fn is_alpha_mode_default(mode: &Checked<AlphaMode>) -> bool {
+    *mode == Checked::Valid(AlphaMode::Opaque)
+}
+
+fn is_double_sided_default(double_sided: &bool) -> bool {
+    !*double_sided
+}
+
+fn is_emissive_factor_default(factor: &EmissiveFactor) -> bool {
+    factor.0 == [0.0; 3]
+}
+
+// Omitted altogether when every property has its default value.
+fn is_pbr_metallic_roughness_default(pbr: &PbrMetallicRoughness) -> bool {
+    #[cfg(feature = "extras")]
+    let extras = pbr.extras.is_none();
+    #[cfg(not(feature = "extras"))]
+    let extras = true;
+    is_base_color_factor_default(&pbr.base_color_factor)
+        && pbr.base_color_texture.is_none()
+        && is_strength_factor_default(&pbr.metallic_factor)
+        && is_strength_factor_default(&pbr.roughness_factor)
+        && pbr.metallic_roughness_texture.is_none()
+        && pbr.extensions.is_none()
+        && extras
+}
+
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[serde(default)]
 pub struct PbrMetallicRoughness {
     /// The material's base color factor.
     #[serde(rename = "baseColorFactor")]
+    #[serde(skip_serializing_if = "is_base_color_factor_default")]
     pub base_color_factor: PbrBaseColorFactor,
 
     /// The base color texture.
@@ -145,6 +179,7 @@ pub struct PbrMetallicRoughness {
 
     /// The metalness of the material.
     #[serde(rename = "metallicFactor")]
+    #[serde(skip_serializing_if = "is_strength_factor_default")]
     pub metallic_factor: StrengthFactor,
 
     /// The roughness of the material.
@@ -152,6 +187,7 @@ pub struct PbrMetallicRoughness {
     /// * A value of 1.0 means the material is completely rough.
     /// * A value of 0.0 means the material is completely smooth.
     #[serde(rename = "roughnessFactor")]
+    #[serde(skip_serializing_if = "is_strength_factor_default")]
     pub roughness_factor: StrengthFactor,
 
     /// The metallic-roughness texture.
@@ -178,6 +214,7 @@ pub struct PbrMetallicRoughness {
 
 /// Defines the normal texture of a material.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct NormalTexture {
     /// The index of the texture.
     pub index: Index<texture::Texture>,
@@ -185,11 +222,19 @@ pub struct NormalTexture {
     /// The scalar multiplier applied to each normal vector of the texture.
     ///
     /// This value is ignored if normalTexture is not specified.
-    #[serde(default = "material_normal_texture_scale_default")]
+    #[serde(
+        default = "material_normal_texture_scale_default",
+        skip_serializing_if = "is_normal_texture_scale_default"
+    )]
     pub scale: f32,
 
     /// The set index of the texture's `TEXCOORD` attribute.
-    #[serde(default, rename = "texCoord")]
+    #[serde(
+        default,
+        rename = "texCoord",
+        deserialize_with = "crate::validation::deserialize_u32",
+        skip_serializing_if = "texture::is_tex_coord_default"
+    )]
     pub tex_coord: u32,
 
     /// Extension specific data.
@@ -207,18 +252,28 @@ fn material_normal_texture_scale_default() -> f32 {
     1.0
 }
 
+fn is_normal_texture_scale_default(scale: &f32) -> bool {
+    *scale == 1.0
+}
+
 /// Defines the occlusion texture of a material.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct OcclusionTexture {
     /// The index of the texture.
     pub index: Index<texture::Texture>,
 
     /// The scalar multiplier controlling the amount of occlusion applied.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_strength_factor_default")]
     pub strength: StrengthFactor,
 
     /// The set index of the texture's `TEXCOORD` attribute.
-    #[serde(default, rename = "texCoord")]
+    #[serde(
+        default,
+        rename = "texCoord",
+        deserialize_with = "crate::validation::deserialize_u32",
+        skip_serializing_if = "texture::is_tex_coord_default"
+    )]
     pub tex_coord: u32,
 
     /// Extension specific data.
@@ -234,18 +289,22 @@ pub struct OcclusionTexture {
 
 /// The alpha cutoff value of a material.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct AlphaCutoff(pub f32);
 
 /// The emissive color of a material.
 #[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct EmissiveFactor(pub [f32; 3]);
 
 /// The base color factor of a material.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct PbrBaseColorFactor(pub [f32; 4]);
 
 /// A number in the inclusive range [0.0, 1.0] with a default value of 1.0.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct StrengthFactor(pub f32);
 
 impl Default for AlphaCutoff {
@@ -303,6 +362,10 @@ impl Default for PbrBaseColorFactor {
 
 impl Validate for PbrBaseColorFactor {}
 
+fn is_base_color_factor_default(factor: &PbrBaseColorFactor) -> bool {
+    factor.0 == [1.0; 4]
+}
+
 impl Default for StrengthFactor {
     fn default() -> Self {
         StrengthFactor(1.0)
@@ -310,3 +373,7 @@ impl Default for StrengthFactor {
 }
 
 impl Validate for StrengthFactor {}
+
+pub(crate) fn is_strength_factor_default(factor: &StrengthFactor) -> bool {
+    factor.0 == 1.0
+}
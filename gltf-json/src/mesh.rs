@@ -47,6 +47,7 @@ pub const VALID_MORPH_TARGETS: &[&str] = &["POSITION", "NORMAL", "TANGENT"];
 
 /// The type of primitives to render.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub enum Mode {
     /// Corresponds to `GL_POINTS`.
     Points = 1,
@@ -75,6 +76,7 @@ pub enum Mode {
 /// A node can contain one or more meshes and its transform places the meshes in
 /// the scene.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Mesh {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -89,7 +91,7 @@ pub struct Mesh {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// Defines the geometry to be renderered with a material.
     pub primitives: Vec<Primitive>,
@@ -101,6 +103,7 @@ pub struct Mesh {
 
 /// Geometry to be rendered with the given material.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[gltf(validate_hook = "primitive_validate_hook")]
 pub struct Primitive {
     /// Maps attribute semantic names to the `Accessor`s containing the
@@ -151,7 +154,11 @@ where
         .get(&Checked::Valid(Semantic::Positions))
     {
         // spec: POSITION accessor **must** have `min` and `max` properties defined.
-        let pos_accessor = &root.accessors[pos_accessor_index.value()];
+        // An out of bounds index is reported by the index validation instead.
+        let pos_accessor = match root.accessors.get(pos_accessor_index.value()) {
+            Some(accessor) => accessor,
+            None => return,
+        };
 
         let min_path = &|| position_path().field("min");
         if let Some(ref min) = pos_accessor.min {
@@ -177,6 +184,7 @@ where
 
 /// A dictionary mapping attributes to their deviations in the Morph Target.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct MorphTarget {
     /// XYZ vertex position displacements of type `[f32; 3]`.
     #[serde(rename = "POSITION")]
@@ -196,6 +204,7 @@ pub struct MorphTarget {
 
 /// Vertex attribute semantic name.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub enum Semantic {
     /// Extra attribute name.
     #[cfg(feature = "extras")]
@@ -231,8 +240,30 @@ impl Default for Mode {
 }
 
 impl Mode {
+    /// Returns the primitive mode corresponding to a `GLenum`, or `None` if
+    /// the value is not a valid primitive mode.
+    pub const fn from_gl(value: u32) -> Option<Self> {
+        Some(match value {
+            POINTS => Mode::Points,
+            LINES => Mode::Lines,
+            LINE_LOOP => Mode::LineLoop,
+            LINE_STRIP => Mode::LineStrip,
+            TRIANGLES => Mode::Triangles,
+            TRIANGLE_STRIP => Mode::TriangleStrip,
+            TRIANGLE_FAN => Mode::TriangleFan,
+            _ => return None,
+        })
+    }
+
+    /// Returns the equivalent `GLenum`.
+    ///
+    /// This is the same as [`Self::to_gl`].
+    pub const fn as_gl_enum(self) -> u32 {
+        self.to_gl()
+    }
+
     /// Returns the equivalent `GLenum`.
-    pub fn as_gl_enum(self) -> u32 {
+    pub const fn to_gl(self) -> u32 {
         match self {
             Mode::Points => POINTS,
             Mode::Lines => LINES,
@@ -262,19 +293,19 @@ impl<'de> de::Deserialize<'de> for Checked<Mode> {
             where
                 E: de::Error,
             {
-                use self::Mode::*;
                 use crate::validation::Checked::*;
-                Ok(match value as u32 {
-                    POINTS => Valid(Points),
-                    LINES => Valid(Lines),
-                    LINE_LOOP => Valid(LineLoop),
-                    LINE_STRIP => Valid(LineStrip),
-                    TRIANGLES => Valid(Triangles),
-                    TRIANGLE_STRIP => Valid(TriangleStrip),
-                    TRIANGLE_FAN => Valid(TriangleFan),
+                Ok(match Mode::from_gl(value as u32) {
+                    Some(mode) if value <= u32::MAX as u64 => Valid(mode),
                     _ => Invalid,
                 })
             }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_u64(crate::validation::integral(value)?)
+            }
         }
         deserializer.deserialize_u64(Visitor)
     }
@@ -285,7 +316,7 @@ impl ser::Serialize for Mode {
     where
         S: ser::Serializer,
     {
-        serializer.serialize_u32(self.as_gl_enum())
+        serializer.serialize_u32(self.to_gl())
     }
 }
 
@@ -3,7 +3,7 @@ use alloc::string::{String, ToString};
 use core::fmt;
 
 /// An immutable JSON source path.
-#[derive(Default, Clone, Debug, PartialEq)]
+#[derive(Default, Clone, Debug, Eq, PartialEq)]
 pub struct Path(pub String);
 
 impl Path {
@@ -12,9 +12,14 @@ use core2::io;
 
 use crate::path::Path;
 use crate::{
-    Accessor, Animation, Asset, Buffer, Camera, Error, Extras, Image, Material, Mesh, Node, Scene,
-    Skin, Texture, Value,
+    Accessor, Asset, Buffer, Error, Extras, Image, Material, Mesh, Node, Scene, Texture, Value,
 };
+#[cfg(feature = "animation")]
+use crate::Animation;
+#[cfg(feature = "cameras")]
+use crate::Camera;
+#[cfg(feature = "skinning")]
+use crate::Skin;
 use validation::Validate;
 
 // TODO: As a breaking change, simplify by replacing uses of `Get<T>` with `AsRef<[T]>`.
@@ -61,6 +66,7 @@ impl<T> Index<T> {
 
 /// The root object of a glTF 2.0 asset.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[gltf(validate_hook = "root_validate_hook")]
 pub struct Root {
     /// An array of accessors.
@@ -69,6 +75,9 @@ pub struct Root {
     pub accessors: Vec<Accessor>,
 
     /// An array of keyframe animations.
+    ///
+    /// Skipped when deserializing without the `animation` feature.
+    #[cfg(feature = "animation")]
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub animations: Vec<Animation>,
@@ -111,6 +120,9 @@ pub struct Root {
     pub extensions_required: Vec<String>,
 
     /// An array of cameras.
+    ///
+    /// Skipped when deserializing without the `cameras` feature.
+    #[cfg(feature = "cameras")]
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub cameras: Vec<Camera>,
@@ -146,6 +158,9 @@ pub struct Root {
     pub scenes: Vec<Scene>,
 
     /// An array of skins.
+    ///
+    /// Skipped when deserializing without the `skinning` feature.
+    #[cfg(feature = "skinning")]
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub skins: Vec<Skin>,
@@ -162,7 +177,7 @@ where
     R: FnMut(&dyn Fn() -> Path, crate::validation::Error),
 {
     for (i, ext) in root.extensions_required.iter().enumerate() {
-        if !crate::extensions::ENABLED_EXTENSIONS.contains(&ext.as_str()) {
+        if !crate::extensions::is_supported(ext) {
             report(
                 &|| {
                     path()
@@ -174,6 +189,8 @@ where
             );
         }
     }
+
+    crate::scene::validate_hierarchy(root, path, report);
 }
 
 impl Root {
@@ -249,6 +266,37 @@ impl Root {
         serde_json::to_vec_pretty(self)
     }
 
+    /// Sets the extension `name` of the object at `path` to `value`, adding
+    /// `name` to `extensionsUsed` if it is not already listed.
+    ///
+    /// `path` uses the notation of validation paths, for example
+    /// `Path::new().field("materials").index(0)`. The empty path refers to the
+    /// root object itself. Extensions this crate does not model are preserved
+    /// verbatim on serialization.
+    ///
+    /// Returns an error if `path` does not refer to an object, or if it refers
+    /// to an object that cannot be deserialized with the new extension.
+    #[cfg(feature = "extensions")]
+    pub fn set_extension(&mut self, path: &Path, name: &str, value: Value) -> Result<(), Error> {
+        use serde::de::Error as _;
+        let mut root = self.to_value()?;
+        let object = lookup(&mut root, path.as_str())
+            .and_then(Value::as_object_mut)
+            .ok_or_else(|| Error::custom(alloc::format!("no object at path `{}`", path)))?;
+        let extensions = object
+            .entry("extensions")
+            .or_insert_with(|| Value::Object(Default::default()));
+        match extensions.as_object_mut() {
+            Some(extensions) => extensions.insert(String::from(name), value),
+            None => return Err(Error::custom("`extensions` is not an object")),
+        };
+        *self = serde_json::from_value(root)?;
+        if !self.extensions_used.iter().any(|used| used == name) {
+            self.extensions_used.push(String::from(name));
+        }
+        Ok(())
+    }
+
     // /// Serialize as a JSON byte writertor.
     // pub fn to_writer<W>(&self, writer: W) -> Result<(), Error>
     // where
@@ -266,6 +314,29 @@ impl Root {
     // }
 }
 
+/// Returns the value at the given JSON source path.
+#[cfg(feature = "extensions")]
+fn lookup<'a>(mut value: &'a mut Value, path: &str) -> Option<&'a mut Value> {
+    let mut rest = path;
+    while !rest.is_empty() {
+        if let Some(tail) = rest.strip_prefix("[\"") {
+            let end = tail.find("\"]")?;
+            value = value.get_mut(&tail[..end])?;
+            rest = &tail[end + 2..];
+        } else if let Some(tail) = rest.strip_prefix('[') {
+            let end = tail.find(']')?;
+            value = value.get_mut(tail[..end].parse::<usize>().ok()?)?;
+            rest = &tail[end + 1..];
+        } else {
+            let tail = rest.strip_prefix('.').unwrap_or(rest);
+            let end = tail.find(['.', '[']).unwrap_or(tail.len());
+            value = value.get_mut(&tail[..end])?;
+            rest = &tail[end..];
+        }
+    }
+    Some(value)
+}
+
 impl<T> Index<T> {
     /// Creates a new `Index` representing an offset into an array containing `T`.
     pub fn new(value: u32) -> Self {
@@ -306,6 +377,13 @@ impl<'de, T> serde::Deserialize<'de> for Index<T> {
             {
                 Ok(Index::new(value as u32))
             }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                self.visit_u64(crate::validation::integral(value)?)
+            }
         }
         deserializer.deserialize_u64(Visitor::<T>(marker::PhantomData))
     }
@@ -391,9 +469,11 @@ macro_rules! impl_get {
 }
 
 impl_get!(Accessor, accessors);
+#[cfg(feature = "animation")]
 impl_get!(Animation, animations);
 impl_get!(Buffer, buffers);
 impl_get!(buffer::View, buffer_views);
+#[cfg(feature = "cameras")]
 impl_get!(Camera, cameras);
 impl_get!(Image, images);
 impl_get!(Material, materials);
@@ -401,6 +481,7 @@ impl_get!(Mesh, meshes);
 impl_get!(Node, nodes);
 impl_get!(texture::Sampler, samplers);
 impl_get!(Scene, scenes);
+#[cfg(feature = "skinning")]
 impl_get!(Skin, skins);
 impl_get!(Texture, textures);
 
@@ -491,7 +572,7 @@ mod tests {
             assert_eq!(*error, Error::Unsupported);
         }
 
-        root.extensions_required = vec!["KHR_mesh_quantization".to_owned()];
+        root.extensions_required = vec!["KHR_draco_mesh_compression".to_owned()];
         errors.clear();
         root.validate(&root, Path::new, &mut |path, error| {
             errors.push((path(), error));
@@ -500,7 +581,7 @@ mod tests {
         let (path, error) = errors.get(0).unwrap();
         assert_eq!(
             path.as_str(),
-            "extensionsRequired[0] = \"KHR_mesh_quantization\""
+            "extensionsRequired[0] = \"KHR_draco_mesh_compression\""
         );
         assert_eq!(*error, Error::Unsupported);
     }
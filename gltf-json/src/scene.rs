@@ -1,7 +1,13 @@
-use alloc::string::String;
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec;
 use alloc::vec::Vec;
-use crate::validation::Validate;
-use crate::{camera, extensions, mesh, scene, skin, Extras, Index};
+use crate::validation::{Error, Validate};
+use crate::{extensions, mesh, scene, Extras, Index, Path, Root};
+#[cfg(feature = "cameras")]
+use crate::camera;
+#[cfg(feature = "skinning")]
+use crate::skin;
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
@@ -16,8 +22,12 @@ use serde_derive::{Deserialize, Serialize};
 /// animation.channel.target), only TRS properties may be present; `matrix` will not
 /// be present.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Node {
     /// The index of the camera referenced by this node.
+    ///
+    /// Skipped when deserializing without the `cameras` feature.
+    #[cfg(feature = "cameras")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub camera: Option<Index<camera::Camera>>,
 
@@ -55,7 +65,7 @@ pub struct Node {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// The node's unit quaternion rotation in the order (x, y, z, w), where w is
     /// the scalar.
@@ -71,6 +81,9 @@ pub struct Node {
     pub translation: Option<[f32; 3]>,
 
     /// The index of the skin referenced by this node.
+    ///
+    /// Skipped when deserializing without the `skinning` feature.
+    #[cfg(feature = "skinning")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skin: Option<Index<skin::Skin>>,
 
@@ -82,6 +95,7 @@ pub struct Node {
 
 /// The root `Node`s of a scene.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Scene {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -96,15 +110,16 @@ pub struct Scene {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// The indices of each root node.
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub nodes: Vec<Index<Node>>,
 }
 
 /// Unit quaternion rotation in the order (x, y, z, w), where w is the scalar.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct UnitQuaternion(pub [f32; 4]);
 
 impl Default for UnitQuaternion {
@@ -114,3 +129,185 @@ impl Default for UnitQuaternion {
 }
 
 impl Validate for UnitQuaternion {}
+
+/// Validates the node hierarchy as a whole.
+///
+/// The glTF 2.0 specification requires the nodes to form a set of disjoint
+/// strict trees. The following violations are reported as `Error::Invalid`
+/// against the offending `nodes[i].children[j]` entry:
+///
+/// * a node listing itself as a child, with the value `"i -> i"`;
+/// * a node listed as a child of more than one node (or more than once by
+///   the same node), naming the node that claimed it first;
+/// * a cycle, with the value being the full cycle path such as `"1 -> 2 -> 1"`.
+///
+/// Out of range child indices are skipped since they are already reported
+/// as `Error::IndexOutOfBounds`.
+pub(crate) fn validate_hierarchy<P, R>(root: &Root, path: P, report: &mut R)
+where
+    P: Fn() -> Path,
+    R: FnMut(&dyn Fn() -> Path, Error),
+{
+    let count = root.nodes.len();
+    let children = |node: usize| root.nodes[node].children.as_deref().unwrap_or(&[]);
+    let child_path = |node: usize, child: usize| {
+        path()
+            .field("nodes")
+            .index(node)
+            .field("children")
+            .index(child)
+    };
+
+    let mut parents: Vec<Option<usize>> = vec![None; count];
+    for node in 0..count {
+        for (j, child) in children(node).iter().enumerate() {
+            let child = child.value();
+            if child >= count {
+                continue;
+            }
+            if child == node {
+                let cycle = format!("{} -> {}", node, node);
+                report(&|| child_path(node, j).value_str(&cycle), Error::Invalid);
+                continue;
+            }
+            match parents[child] {
+                Some(parent) => {
+                    let message = format!("{} is already a child of {}", child, parent);
+                    report(&|| child_path(node, j).value_str(&message), Error::Invalid);
+                }
+                None => parents[child] = Some(node),
+            }
+        }
+    }
+
+    // Depth-first search over every child edge, reporting back edges.
+    const UNVISITED: u8 = 0;
+    const ON_STACK: u8 = 1;
+    const DONE: u8 = 2;
+    let mut state = vec![UNVISITED; count];
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+    for start in 0..count {
+        if state[start] != UNVISITED {
+            continue;
+        }
+        state[start] = ON_STACK;
+        stack.push((start, 0));
+        while let Some(&(node, j)) = stack.last() {
+            let child = match children(node).get(j) {
+                Some(child) => child,
+                None => {
+                    state[node] = DONE;
+                    stack.pop();
+                    continue;
+                }
+            };
+            stack.last_mut().unwrap().1 += 1;
+            let child = child.value();
+            if child >= count || child == node {
+                continue;
+            }
+            match state[child] {
+                UNVISITED => {
+                    state[child] = ON_STACK;
+                    stack.push((child, 0));
+                }
+                ON_STACK => {
+                    let position = stack.iter().position(|&(n, _)| n == child).unwrap();
+                    let cycle = stack[position..]
+                        .iter()
+                        .map(|&(n, _)| n.to_string())
+                        .chain(Some(child.to_string()))
+                        .collect::<Vec<_>>()
+                        .join(" -> ");
+                    report(&|| child_path(node, j).value_str(&cycle), Error::Invalid);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    fn hierarchy_errors(children: &[&[u32]]) -> Vec<(String, Error)> {
+        let root = Root {
+            nodes: children
+                .iter()
+                .map(|children| Node {
+                    children: Some(children.iter().map(|&i| Index::new(i)).collect()),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let mut errors = Vec::new();
+        validate_hierarchy(&root, Path::new, &mut |path, error| {
+            errors.push((path().0, error));
+        });
+        errors
+    }
+
+    #[test]
+    fn valid_hierarchy() {
+        assert!(hierarchy_errors(&[&[1, 2], &[3], &[], &[]]).is_empty());
+    }
+
+    #[test]
+    fn self_reference() {
+        assert_eq!(
+            hierarchy_errors(&[&[], &[0, 1]]),
+            vec![(
+                "nodes[1].children[1] = \"1 -> 1\"".to_string(),
+                Error::Invalid
+            )]
+        );
+    }
+
+    #[test]
+    fn multiple_parents() {
+        assert_eq!(
+            hierarchy_errors(&[&[2], &[2], &[]]),
+            vec![(
+                "nodes[1].children[0] = \"2 is already a child of 0\"".to_string(),
+                Error::Invalid
+            )]
+        );
+    }
+
+    #[test]
+    fn cycle() {
+        assert_eq!(
+            hierarchy_errors(&[&[1], &[2], &[0]]),
+            vec![(
+                "nodes[2].children[0] = \"0 -> 1 -> 2 -> 0\"".to_string(),
+                Error::Invalid
+            )]
+        );
+    }
+
+    #[test]
+    fn cycle_below_root() {
+        let errors = hierarchy_errors(&[&[1], &[2], &[1]]);
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "nodes[2].children[0] = \"1 is already a child of 0\"".to_string(),
+                    Error::Invalid
+                ),
+                (
+                    "nodes[2].children[0] = \"1 -> 2 -> 1\"".to_string(),
+                    Error::Invalid
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn out_of_range_children_are_skipped() {
+        assert!(hierarchy_errors(&[&[7]]).is_empty());
+    }
+}
@@ -1,4 +1,3 @@
-use alloc::string::String;
 use alloc::vec::Vec;
 use crate::{accessor, extensions, scene, Extras, Index};
 use gltf_derive::Validate;
@@ -6,6 +5,7 @@ use serde_derive::{Deserialize, Serialize};
 
 /// Joints and matrices defining a skin.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Skin {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -35,7 +35,7 @@ pub struct Skin {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// The index of the node used as a skeleton root.
     ///
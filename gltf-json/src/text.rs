@@ -0,0 +1,234 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+#[cfg(feature = "arena")]
+use alloc::sync::Arc;
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
+use core::{cmp, fmt, ops};
+
+use serde::{de, ser};
+
+#[cfg(feature = "arena")]
+use crate::arena::Block;
+use crate::validation::Validate;
+
+/// An immutable string, such as the name of an entity or a URI.
+///
+/// The text is either owned on the heap or, for documents loaded with an
+/// [`ImportArena`], stored in the memory block of the arena, which is
+/// released once the arena and every string in it have been dropped.
+/// Either way, `Str` dereferences to `str`.
+///
+/// [`ImportArena`]: crate::arena::ImportArena
+#[derive(Clone, Default)]
+pub struct Str(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Heap(Box<str>),
+    #[cfg(feature = "arena")]
+    Arena {
+        block: Arc<Block>,
+        start: usize,
+        len: usize,
+    },
+}
+
+impl Default for Repr {
+    fn default() -> Self {
+        Repr::Heap(Box::default())
+    }
+}
+
+impl Str {
+    /// Creates a string stored in the block of an arena.
+    ///
+    /// # Safety
+    ///
+    /// The `len` bytes at `start` must have been initialized with valid UTF-8
+    /// and must never be written again.
+    #[cfg(feature = "arena")]
+    pub(crate) unsafe fn from_block(block: Arc<Block>, start: usize, len: usize) -> Self {
+        Str(Repr::Arena { block, start, len })
+    }
+
+    /// Creates a string borrowed from the input of a deserializer, stored in
+    /// the arena that the input is deserialized into, if any.
+    pub(crate) fn from_input(text: &str) -> Self {
+        #[cfg(feature = "arena")]
+        if let Some(text) = crate::arena::alloc_scoped(text) {
+            return text;
+        }
+        Str::from(text)
+    }
+
+    /// Returns whether the text is stored in an arena rather than on the
+    /// heap.
+    pub fn is_in_arena(&self) -> bool {
+        match self.0 {
+            Repr::Heap(_) => false,
+            #[cfg(feature = "arena")]
+            Repr::Arena { .. } => true,
+        }
+    }
+
+    /// Returns the text.
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Heap(text) => text,
+            #[cfg(feature = "arena")]
+            Repr::Arena { block, start, len } => unsafe { block.str(*start, *len) },
+        }
+    }
+}
+
+impl ops::Deref for Str {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Str {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl Borrow<str> for Str {
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl From<&str> for Str {
+    fn from(text: &str) -> Self {
+        Str(Repr::Heap(text.into()))
+    }
+}
+
+impl From<String> for Str {
+    fn from(text: String) -> Self {
+        Str(Repr::Heap(text.into_boxed_str()))
+    }
+}
+
+impl From<Str> for String {
+    fn from(text: Str) -> Self {
+        match text.0 {
+            Repr::Heap(text) => text.into(),
+            #[cfg(feature = "arena")]
+            Repr::Arena { .. } => String::from(text.as_str()),
+        }
+    }
+}
+
+impl fmt::Debug for Str {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for Str {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for Str {
+    fn eq(&self, other: &Str) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Str {}
+
+impl PartialEq<str> for Str {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Str {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for Str {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<Str> for str {
+    fn eq(&self, other: &Str) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Str> for &str {
+    fn eq(&self, other: &Str) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialEq<Str> for String {
+    fn eq(&self, other: &Str) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialOrd for Str {
+    fn partial_cmp(&self, other: &Str) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Str {
+    fn cmp(&self, other: &Str) -> cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for Str {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl ser::Serialize for Str {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Str {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(StrVisitor)
+    }
+}
+
+struct StrVisitor;
+
+impl<'de> de::Visitor<'de> for StrVisitor {
+    type Value = Str;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, text: &'de str) -> Result<Str, E> {
+        Ok(Str::from_input(text))
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Str, E> {
+        Ok(Str::from(text))
+    }
+
+    fn visit_string<E: de::Error>(self, text: String) -> Result<Str, E> {
+        Ok(Str::from(text))
+    }
+}
+
+impl Validate for Str {}
@@ -1,4 +1,3 @@
-use alloc::string::String;
 use core::fmt;
 use crate::validation::{Checked, Validate};
 use crate::{extensions, image, Extras, Index};
@@ -51,6 +50,7 @@ pub const VALID_WRAPPING_MODES: &[u32] = &[CLAMP_TO_EDGE, MIRRORED_REPEAT, REPEA
 
 /// Magnification filter.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub enum MagFilter {
     /// Corresponds to `GL_NEAREST`.
     Nearest = 1,
@@ -71,6 +71,7 @@ impl MagFilter {
 
 /// Minification filter.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub enum MinFilter {
     /// Corresponds to `GL_NEAREST`.
     Nearest = 1,
@@ -107,6 +108,7 @@ impl MinFilter {
 
 /// Texture co-ordinate wrapping mode.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub enum WrappingMode {
     /// Corresponds to `GL_CLAMP_TO_EDGE`.
     ClampToEdge = 1,
@@ -131,6 +133,7 @@ impl WrappingMode {
 
 /// Texture sampler properties for filtering and wrapping modes.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 #[serde(default)]
 pub struct Sampler {
     /// Magnification filter.
@@ -146,14 +149,16 @@ pub struct Sampler {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// `s` wrapping mode.
     #[serde(default, rename = "wrapS")]
+    #[serde(skip_serializing_if = "is_wrapping_mode_default")]
     pub wrap_s: Checked<WrappingMode>,
 
     /// `t` wrapping mode.
     #[serde(default, rename = "wrapT")]
+    #[serde(skip_serializing_if = "is_wrapping_mode_default")]
     pub wrap_t: Checked<WrappingMode>,
 
     /// Extension specific data.
@@ -175,6 +180,14 @@ fn source_is_empty(source: &Index<image::Image>) -> bool {
     source.value() == u32::MAX as usize
 }
 
+fn is_wrapping_mode_default(mode: &Checked<WrappingMode>) -> bool {
+    *mode == Checked::Valid(WrappingMode::Repeat)
+}
+
+pub(crate) fn is_tex_coord_default(tex_coord: &u32) -> bool {
+    *tex_coord == 0
+}
+
 fn source_validate<P, R>(source: &Index<image::Image>, root: &crate::Root, path: P, report: &mut R)
 where
     P: Fn() -> crate::Path,
@@ -193,18 +206,20 @@ where
 
 /// A texture and its sampler.
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 pub struct Texture {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
-    pub name: Option<String>,
+    pub name: Option<crate::Str>,
 
     /// The index of the sampler used by this texture.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sampler: Option<Index<Sampler>>,
 
     /// The index of the image used by this texture.
-    #[serde(default = "source_default", skip_serializing_if = "source_is_empty")]
+    #[serde(default = "source_default")]
+    #[serde(skip_serializing_if = "source_is_empty")]
     pub source: Index<image::Image>,
 
     /// Extension specific data.
@@ -228,18 +243,42 @@ impl Validate for Texture {
             .validate(root, || path().field("sampler"), report);
         self.extensions
             .validate(root, || path().field("extensions"), report);
+        // The image of `KHR_texture_basisu` or `EXT_texture_webp` replaces a
+        // missing source.
+        #[cfg(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp"))]
+        if source_is_empty(&self.source)
+            && self.extensions.as_ref().map_or(false, |extensions| {
+                #[cfg(feature = "KHR_texture_basisu")]
+                if extensions.texture_basisu.is_some() {
+                    return true;
+                }
+                #[cfg(feature = "EXT_texture_webp")]
+                if extensions.texture_webp.is_some() {
+                    return true;
+                }
+                false
+            })
+        {
+            return;
+        }
         source_validate(&self.source, root, || path().field("source"), report);
     }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[cfg_attr(feature = "bake", derive(gltf_derive::Bake))]
 /// Reference to a `Texture`.
 pub struct Info {
     /// The index of the texture.
     pub index: Index<Texture>,
 
     /// The set index of the texture's `TEXCOORD` attribute.
-    #[serde(default, rename = "texCoord")]
+    #[serde(
+        default,
+        rename = "texCoord",
+        deserialize_with = "crate::validation::deserialize_u32",
+        skip_serializing_if = "is_tex_coord_default"
+    )]
     pub tex_coord: u32,
 
     /// Extension specific data.
@@ -278,6 +317,13 @@ impl<'de> de::Deserialize<'de> for Checked<MagFilter> {
                     _ => Invalid,
                 })
             }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_u64(crate::validation::integral(value)?)
+            }
         }
         deserializer.deserialize_u64(Visitor)
     }
@@ -312,6 +358,13 @@ impl<'de> de::Deserialize<'de> for Checked<MinFilter> {
                     _ => Invalid,
                 })
             }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_u64(crate::validation::integral(value)?)
+            }
         }
         deserializer.deserialize_u64(Visitor)
     }
@@ -352,6 +405,13 @@ impl<'de> de::Deserialize<'de> for Checked<WrappingMode> {
                     _ => Invalid,
                 })
             }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                self.visit_u64(crate::validation::integral(value)?)
+            }
         }
         deserializer.deserialize_u64(Visitor)
     }
@@ -1,7 +1,8 @@
 use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use serde::{ser, Serialize, Serializer};
+use core::fmt;
+use serde::{de, ser, Serialize, Serializer};
 
 use crate::{Path, Root};
 
@@ -111,19 +112,18 @@ impl<T> Validate for Checked<T> {
 }
 
 /// Validates the suitability of 64-bit byte offsets/sizes on 32-bit systems.
-#[derive(
-    Clone,
-    Copy,
-    Debug,
-    Default,
-    Eq,
-    Hash,
-    PartialEq,
-    serde_derive::Deserialize,
-    serde_derive::Serialize,
-)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, serde_derive::Serialize)]
 pub struct USize64(pub u64);
 
+impl<'de> de::Deserialize<'de> for USize64 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_u64(Integral).map(USize64)
+    }
+}
+
 impl From<u64> for USize64 {
     fn from(value: u64) -> Self {
         Self(value)
@@ -208,6 +208,117 @@ impl Validate for alloc::boxed::Box<serde_json::value::RawValue> {
     }
 }
 
+/// Returns the integer denoted by a number written with float syntax, such as
+/// `1e3` or `4.0`.
+///
+/// JSON does not distinguish integers from other numbers, so integral fields
+/// accept such numbers, but only if they are exactly non-negative integers.
+/// Otherwise the error names the offending value.
+pub(crate) fn integral<E: de::Error>(value: f64) -> Result<u64, E> {
+    // The upper bound is 2^64, the first float beyond `u64::MAX`.
+    if (0.0..18_446_744_073_709_551_616.0).contains(&value) && value as u64 as f64 == value {
+        Ok(value as u64)
+    } else {
+        Err(E::invalid_value(
+            de::Unexpected::Float(value),
+            &"a non-negative integer",
+        ))
+    }
+}
+
+/// Deserializes a non-negative integer, which may be written with float
+/// syntax; see [`integral`].
+struct Integral;
+
+impl<'de> de::Visitor<'de> for Integral {
+    type Value = u64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a non-negative integer")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(value)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        integral(value)
+    }
+}
+
+/// Deserializes a `u32` field, which may be written with float syntax; see
+/// [`integral`].
+pub(crate) fn deserialize_u32<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let value = deserializer.deserialize_u64(Integral)?;
+    u32::try_from(value)
+        .map_err(|_| de::Error::invalid_value(de::Unexpected::Unsigned(value), &"a 32-bit integer"))
+}
+
+/// A `u32` which may be written with float syntax; see [`integral`].
+#[cfg(any(feature = "KHR_materials_variants", feature = "KHR_texture_transform"))]
+struct LenientU32(u32);
+
+#[cfg(any(feature = "KHR_materials_variants", feature = "KHR_texture_transform"))]
+impl<'de> de::Deserialize<'de> for LenientU32 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserialize_u32(deserializer).map(LenientU32)
+    }
+}
+
+/// Deserializes an optional `u32` field, which may be written with float
+/// syntax; see [`integral`].
+#[cfg(feature = "KHR_texture_transform")]
+pub(crate) fn deserialize_optional_u32<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let value: Option<LenientU32> = de::Deserialize::deserialize(deserializer)?;
+    Ok(value.map(|value| value.0))
+}
+
+/// Deserializes an array of `u32`, whose elements may be written with float
+/// syntax; see [`integral`].
+#[cfg(feature = "KHR_materials_variants")]
+pub(crate) fn deserialize_u32_vec<'de, D>(deserializer: D) -> Result<Vec<u32>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let values: Vec<LenientU32> = de::Deserialize::deserialize(deserializer)?;
+    Ok(values.into_iter().map(|value| value.0).collect())
+}
+
+/// Deserializes a `usize` field, which may be written with float syntax; see
+/// [`integral`].
+pub(crate) fn deserialize_usize<'de, D>(deserializer: D) -> Result<usize, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let value = deserializer.deserialize_u64(Integral)?;
+    usize::try_from(value).map_err(|_| {
+        de::Error::invalid_value(de::Unexpected::Unsigned(value), &"a pointer-sized integer")
+    })
+}
+
+/// Returns `true` if the string is empty or consists only of whitespace.
+///
+/// Such names, URIs, and MIME types are treated as absent or invalid rather
+/// than as meaningful values.
+pub(crate) fn is_blank(value: &str) -> bool {
+    value.trim().is_empty()
+}
+
 impl core::error::Error for Error {}
 
 impl core::fmt::Display for Error {
@@ -0,0 +1,86 @@
+use gltf_json::accessor::{ComponentType, Type};
+use gltf_json::mesh::Mode;
+
+/// Accessor component types, their `GLenum` values and sizes in bytes, as
+/// tabulated by the glTF 2.0 specification.
+const COMPONENT_TYPES: &[(ComponentType, u32, usize)] = &[
+    (ComponentType::I8, 5120, 1),
+    (ComponentType::U8, 5121, 1),
+    (ComponentType::I16, 5122, 2),
+    (ComponentType::U16, 5123, 2),
+    (ComponentType::U32, 5125, 4),
+    (ComponentType::F32, 5126, 4),
+];
+
+/// Accessor types and their number of components.
+const TYPES: &[(Type, &str, usize)] = &[
+    (Type::Scalar, "SCALAR", 1),
+    (Type::Vec2, "VEC2", 2),
+    (Type::Vec3, "VEC3", 3),
+    (Type::Vec4, "VEC4", 4),
+    (Type::Mat2, "MAT2", 4),
+    (Type::Mat3, "MAT3", 9),
+    (Type::Mat4, "MAT4", 16),
+];
+
+/// Primitive topology types and their `GLenum` values.
+const MODES: &[(Mode, u32)] = &[
+    (Mode::Points, 0),
+    (Mode::Lines, 1),
+    (Mode::LineLoop, 2),
+    (Mode::LineStrip, 3),
+    (Mode::Triangles, 4),
+    (Mode::TriangleStrip, 5),
+    (Mode::TriangleFan, 6),
+];
+
+#[test]
+fn test_component_types() {
+    for &(component_type, gl, size) in COMPONENT_TYPES {
+        assert_eq!(ComponentType::from_gl(gl), Some(component_type));
+        assert_eq!(component_type.to_gl(), gl);
+        assert_eq!(component_type.size(), size);
+    }
+    assert_eq!(ComponentType::from_gl(5124), None);
+    assert_eq!(ComponentType::from_gl(0), None);
+}
+
+#[test]
+fn test_types() {
+    for &(type_, name, multiplicity) in TYPES {
+        assert_eq!(Type::from_str(name), Some(type_));
+        assert_eq!(type_.as_str(), name);
+        assert_eq!(type_.multiplicity(), multiplicity);
+    }
+    assert_eq!(Type::from_str("vec3"), None);
+    assert_eq!(Type::from_str("MAT2x3"), None);
+}
+
+#[test]
+fn test_modes() {
+    for &(mode, gl) in MODES {
+        assert_eq!(Mode::from_gl(gl), Some(mode));
+        assert_eq!(mode.to_gl(), gl);
+    }
+    assert_eq!(Mode::from_gl(7), None);
+}
+
+#[test]
+fn test_const_conversions() {
+    const FLOAT: Option<ComponentType> = ComponentType::from_gl(5126);
+    const VEC3: usize = Type::Vec3.multiplicity();
+    const TRIANGLES: u32 = Mode::Triangles.to_gl();
+    assert_eq!(FLOAT, Some(ComponentType::F32));
+    assert_eq!(VEC3, 3);
+    assert_eq!(TRIANGLES, 4);
+}
+
+#[test]
+fn test_out_of_range_enums_are_invalid() {
+    let json = r#"{"componentType": 4294972422, "count": 1, "type": "SCALAR"}"#;
+    let accessor: gltf_json::Accessor = serde_json::from_str(json).unwrap();
+    assert!(matches!(
+        accessor.component_type,
+        gltf_json::validation::Checked::Invalid
+    ));
+}
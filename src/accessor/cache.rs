@@ -0,0 +1,292 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use core::{fmt, mem, ptr};
+
+use crate::accessor::util::{Item, ItemIter, Iter, Sanitizer, SparseIndicesIter, SparseIter};
+use crate::accessor::{check_sparse_indices, extent, sparse, Accessor, Error};
+use crate::{buffer, Document};
+
+/// A byte range of an accessor within a buffer view, resolved once.
+#[derive(Clone, Debug)]
+struct Span {
+    /// The index of the buffer.
+    buffer: usize,
+    /// The index of the buffer if it is an `EXT_meshopt_compression`
+    /// fallback buffer.
+    fallback: Option<usize>,
+    /// The offset of the buffer view in the buffer.
+    view_offset: usize,
+    /// The length of the buffer view.
+    view_length: usize,
+    /// The offset of the range in the buffer view.
+    offset: usize,
+    /// The length of the range.
+    length: usize,
+    /// The stride between elements.
+    stride: usize,
+}
+
+impl Span {
+    fn new(view: &buffer::View, offset: usize, count: usize, size: usize) -> Self {
+        let stride = view.stride().unwrap_or(size);
+        let buffer = view.buffer();
+        Self {
+            buffer: buffer.index(),
+            fallback: (!buffer.has_data()).then(|| buffer.index()),
+            view_offset: view.offset(),
+            view_length: view.length(),
+            offset,
+            length: extent(count, stride, size),
+            stride,
+        }
+    }
+
+    /// Returns the bytes of the range, with the checks of `view_range`.
+    fn data<'a, 's>(
+        &self,
+        accessor: &Accessor<'a>,
+        get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    ) -> Result<&'s [u8], Error> {
+        let buffer = accessor.document.buffers().nth(self.buffer).unwrap();
+        let data = get_buffer_data(buffer)
+            .and_then(|data| data.get(self.view_offset..))
+            .unwrap_or(&[]);
+        let data = &data[..data.len().min(self.view_length)];
+        let end = self.offset.saturating_add(self.length);
+        data.get(self.offset..end).ok_or(match self.fallback {
+            Some(buffer) => Error::MeshoptFallback {
+                accessor: accessor.index(),
+                buffer,
+            },
+            None => Error::MissingData {
+                accessor: accessor.index(),
+                required: end,
+                available: data.len(),
+            },
+        })
+    }
+}
+
+/// The resolved layout of an accessor, see [`AccessorLayoutCache`].
+#[derive(Clone, Debug)]
+pub(crate) struct Layout {
+    /// The size of an element in bytes.
+    size: usize,
+    /// The range of the base values, if the accessor has a buffer view.
+    base: Option<Span>,
+    /// The number of elements.
+    count: usize,
+    /// The sparse indices as little-endian `u32`s, and the range of the
+    /// sparse values.
+    sparse: Option<(Vec<u8>, Span)>,
+}
+
+impl Layout {
+    /// Resolves the layout of an accessor with elements of `size` bytes, or
+    /// returns `None` if its sparse indices are unavailable or invalid.
+    fn new<'a, 's>(
+        accessor: &Accessor<'a>,
+        size: usize,
+        get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    ) -> Option<Self> {
+        let count = accessor.count();
+        let base = accessor
+            .view()
+            .map(|view| Span::new(&view, accessor.offset(), count, size));
+        let sparse = match accessor.sparse() {
+            Some(sparse) => {
+                let indices = sparse.indices();
+                let index_size = indices.index_type().size();
+                let span = Span::new(
+                    &indices.view(),
+                    indices.offset(),
+                    sparse.count(),
+                    index_size,
+                );
+                let data = span.data(accessor, get_buffer_data).ok()?;
+                let mut parsed = Vec::with_capacity(sparse.count() * mem::size_of::<u32>());
+                let mut push = |index: u32| parsed.extend_from_slice(&index.to_le_bytes());
+                match indices.index_type() {
+                    sparse::IndexType::U8 => {
+                        ItemIter::<u8>::new(data, span.stride).for_each(|x| push(x as u32))
+                    }
+                    sparse::IndexType::U16 => {
+                        ItemIter::<u16>::new(data, span.stride).for_each(|x| push(x as u32))
+                    }
+                    sparse::IndexType::U32 => {
+                        ItemIter::<u32>::new(data, span.stride).for_each(push)
+                    }
+                }
+                let parsed_indices = ItemIter::<u32>::new(&parsed, mem::size_of::<u32>());
+                let parsed_indices = parsed_indices.map(|index| index as usize);
+                check_sparse_indices(accessor.index(), count, parsed_indices).ok()?;
+                let values = sparse.values();
+                let values = Span::new(&values.view(), values.offset(), sparse.count(), size);
+                Some((parsed, values))
+            }
+            None => None,
+        };
+        Some(Self {
+            size,
+            base,
+            count,
+            sparse,
+        })
+    }
+
+    /// Constructs an iterator over the accessor data, as
+    /// [`Iter::try_new`] does.
+    pub(crate) fn iter<'a, 's, T: Item>(
+        &'s self,
+        accessor: &Accessor<'a>,
+        get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+        sanitizer: Option<&Sanitizer>,
+    ) -> Result<Option<Iter<'s, T>>, Error> {
+        let items = |span: &Span| -> Result<ItemIter<'s, T>, Error> {
+            let data = span.data(accessor, get_buffer_data)?;
+            Ok(ItemIter::new(data, span.stride).sanitized_by(sanitizer))
+        };
+        match (self.sparse.as_ref(), self.base.as_ref()) {
+            (Some((indices, values)), base) => {
+                let base = base.map(items).transpose()?;
+                let indices = SparseIndicesIter::U32(ItemIter::new(indices, mem::size_of::<u32>()));
+                Ok(Some(Iter::Sparse(SparseIter::new(
+                    base,
+                    self.count,
+                    indices,
+                    items(values)?,
+                ))))
+            }
+            (None, Some(base)) => Ok(Some(Iter::Standard(items(base)?))),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+/// A cache of the resolved layouts of the accessors of a document, for
+/// readers that are constructed repeatedly.
+///
+/// Reading an accessor resolves its buffer view and stride, checks its byte
+/// ranges and, for sparse accessors, locates the sparse indices. Readers
+/// constructed with [`Primitive::reader_cached`] or [`Channel::reader_cached`]
+/// do this once per accessor and keep the result here, with the sparse
+/// indices parsed, so that constructing them again only checks the resolved
+/// ranges against the buffer data.
+///
+/// The cache may be shared between threads. It must be used with the
+/// document it was created for, and invalidated with
+/// [`AccessorLayoutCache::invalidate`] when that document or its buffer data
+/// changes, which the borrow checker enforces for as long as readers that
+/// use it are alive.
+///
+/// ```
+/// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// use gltf::accessor::AccessorLayoutCache;
+///
+/// let gltf = gltf::Gltf::from_slice(&std::fs::read("examples/Box.glb")?)?;
+/// let blob = gltf.blob.as_deref();
+/// let cache = AccessorLayoutCache::new(&gltf);
+/// for _frame in 0..3 {
+///     for mesh in gltf.meshes() {
+///         for primitive in mesh.primitives() {
+///             let reader = primitive.reader_cached(&cache, |_| blob);
+///             let _positions = reader.read_positions();
+///         }
+///     }
+/// }
+/// assert_eq!(cache.cached(), 1);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+///
+/// [`Primitive::reader_cached`]: crate::mesh::Primitive::reader_cached
+/// [`Channel::reader_cached`]: crate::animation::Channel::reader_cached
+pub struct AccessorLayoutCache {
+    /// The layout of every accessor, or null until it is resolved.
+    ///
+    /// A layout is only freed through `&mut self`, so the references handed
+    /// out through `&self` remain valid.
+    layouts: Vec<AtomicPtr<Layout>>,
+}
+
+impl AccessorLayoutCache {
+    /// Creates an empty cache for the accessors of a document.
+    pub fn new(document: &Document) -> Self {
+        let layouts = (0..document.as_json().accessors.len())
+            .map(|_| AtomicPtr::new(ptr::null_mut()))
+            .collect();
+        Self { layouts }
+    }
+
+    /// Returns the number of accessors whose layouts are cached.
+    pub fn cached(&self) -> usize {
+        self.layouts
+            .iter()
+            .filter(|layout| !layout.load(Ordering::Acquire).is_null())
+            .count()
+    }
+
+    /// Discards the cached layouts, which are resolved again when next read.
+    pub fn invalidate(&mut self) {
+        for layout in self.layouts.iter_mut() {
+            let layout = mem::replace(layout.get_mut(), ptr::null_mut());
+            if !layout.is_null() {
+                // SAFETY: Non-null pointers come from `Box::into_raw` and are
+                // only freed here, once they have been replaced.
+                drop(unsafe { Box::from_raw(layout) });
+            }
+        }
+    }
+
+    /// Returns the layout of an accessor with elements of `size` bytes,
+    /// resolving it if it is not cached yet.
+    ///
+    /// Returns `None` if the accessor is not in the cache, if its elements
+    /// are of a different size, or if it cannot be resolved, in which case
+    /// it is read without the cache.
+    pub(crate) fn layout<'a, 's>(
+        &self,
+        accessor: &Accessor<'a>,
+        size: usize,
+        get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    ) -> Option<&Layout> {
+        let slot = self.layouts.get(accessor.index())?;
+        let mut layout = slot.load(Ordering::Acquire);
+        if layout.is_null() {
+            let resolved = Box::into_raw(Box::new(Layout::new(accessor, size, get_buffer_data)?));
+            layout = match slot.compare_exchange(
+                ptr::null_mut(),
+                resolved,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => resolved,
+                Err(existing) => {
+                    // SAFETY: `resolved` was never shared.
+                    drop(unsafe { Box::from_raw(resolved) });
+                    existing
+                }
+            };
+        }
+        // SAFETY: The layout is only freed through `&mut self`.
+        let layout = unsafe { &*layout };
+        (layout.size == size).then(|| layout)
+    }
+}
+
+impl Drop for AccessorLayoutCache {
+    fn drop(&mut self) {
+        self.invalidate();
+    }
+}
+
+impl fmt::Debug for AccessorLayoutCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AccessorLayoutCache")
+            .field("accessors", &self.layouts.len())
+            .field("cached", &self.cached())
+            .finish()
+    }
+}
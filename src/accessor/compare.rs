@@ -0,0 +1,151 @@
+use alloc::vec;
+
+use super::{elements::Elements, Accessor, Error};
+use crate::buffer;
+
+/// How [`content_eq_with`] compares the elements of two accessors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Comparison {
+    /// Requires the same component type, dimensions and normalization, and
+    /// identical component bytes.
+    Bytes,
+
+    /// Requires the same dimensions, and component values that differ by at
+    /// most `epsilon` after normalized integers are mapped to floats, so that
+    /// for example normalized `u16` data may equal `f32` data.
+    ///
+    /// NaN components only equal NaN components.
+    Values {
+        /// The largest difference allowed between two components.
+        epsilon: f64,
+    },
+}
+
+impl Default for Comparison {
+    fn default() -> Self {
+        Comparison::Bytes
+    }
+}
+
+/// Options for [`content_eq_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CompareOptions {
+    /// How elements are compared.
+    ///
+    /// The default is [`Comparison::Bytes`].
+    pub comparison: Comparison,
+}
+
+/// Returns whether two accessors have equal contents, comparing their
+/// component bytes.
+///
+/// See [`content_eq_with`].
+pub fn content_eq<'a, 'b, 's, 't, F, G>(
+    a: (Accessor<'a>, F),
+    b: (Accessor<'b>, G),
+) -> Result<bool, Error>
+where
+    F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    G: Fn(buffer::Buffer<'b>) -> Option<&'t [u8]>,
+{
+    content_eq_with(a, b, Default::default())
+}
+
+/// Returns whether two accessors have equal contents, each read with its own
+/// buffer data provider.
+///
+/// Elements are streamed from both accessors in step, so memory use does not
+/// depend on the number of elements, and the comparison stops at the first
+/// mismatch. Sparse accessors are compared by their effective values rather
+/// than by their storage. Accessors with different element counts are never
+/// equal.
+pub fn content_eq_with<'a, 'b, 's, 't, F, G>(
+    (a, get_a): (Accessor<'a>, F),
+    (b, get_b): (Accessor<'b>, G),
+    options: CompareOptions,
+) -> Result<bool, Error>
+where
+    F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    G: Fn(buffer::Buffer<'b>) -> Option<&'t [u8]>,
+{
+    if a.count() != b.count() || a.dimensions().multiplicity() != b.dimensions().multiplicity() {
+        return Ok(false);
+    }
+    let mut left = Elements::new(&a, &get_a)?;
+    let mut right = Elements::new(&b, &get_b)?;
+    match options.comparison {
+        Comparison::Bytes => {
+            if a.data_type() != b.data_type()
+                || a.dimensions() != b.dimensions()
+                || a.normalized() != b.normalized()
+            {
+                return Ok(false);
+            }
+            let components = left.components();
+            let size = a.data_type().size();
+            let mut bytes = vec![0; components * size];
+            loop {
+                let mut offset = 0;
+                let more = left.next_components(|component| {
+                    bytes[offset..offset + size].copy_from_slice(component);
+                    offset += size;
+                });
+                if !more {
+                    return Ok(true);
+                }
+                let mut offset = 0;
+                let mut equal = true;
+                right.next_components(|component| {
+                    equal &= bytes[offset..offset + size] == *component;
+                    offset += size;
+                });
+                if !equal {
+                    return Ok(false);
+                }
+            }
+        }
+        Comparison::Values { epsilon } => {
+            let components = left.components();
+            let mut lhs = vec![0.0; components];
+            let mut rhs = vec![0.0; components];
+            while left.next_values(true, &mut lhs) {
+                right.next_values(true, &mut rhs);
+                let equal = lhs
+                    .iter()
+                    .zip(&rhs)
+                    .all(|(&l, &r)| (l.is_nan() && r.is_nan()) || (l - r).abs() <= epsilon);
+                if !equal {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+    }
+}
+
+/// Computes a hash of the contents of an accessor that is consistent with
+/// [`content_eq`]: accessors with equal component bytes have equal hashes.
+///
+/// The hash covers the component type, dimensions, normalization, element
+/// count and the effective component bytes, including sparse substitutions.
+/// It is stable across platforms and crate versions with the same major
+/// version, so it may be stored.
+pub fn content_hash<'a, 's, F>(accessor: &Accessor<'a>, get_buffer_data: F) -> Result<u64, Error>
+where
+    F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    // 64-bit FNV-1a.
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    let mut write = |bytes: &[u8]| {
+        for &byte in bytes {
+            hash = (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    };
+    write(&accessor.data_type().to_gl().to_le_bytes());
+    write(accessor.dimensions().as_str().as_bytes());
+    write(&[accessor.normalized() as u8]);
+    write(&(accessor.count() as u64).to_le_bytes());
+    let mut elements = Elements::new(accessor, &get_buffer_data)?;
+    while elements.next_components(&mut write) {}
+    Ok(hash)
+}
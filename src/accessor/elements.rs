@@ -0,0 +1,211 @@
+use byteorder::{ByteOrder, LE};
+
+use super::{
+    check_sparse_indices, extent, sparse::IndexType, view_range, Accessor, DataType, Dimensions,
+    Error,
+};
+use crate::buffer;
+use crate::transform::read_component;
+
+/// Returns the byte offset of a component within an element, accounting for
+/// the column padding of small matrices.
+fn component_offset(dimensions: Dimensions, data_type: DataType, component: usize) -> usize {
+    let size = data_type.size();
+    let rows = match dimensions {
+        Dimensions::Mat2 => 2,
+        Dimensions::Mat3 => 3,
+        Dimensions::Mat4 => 4,
+        _ => return component * size,
+    };
+    let column_stride = (rows * size + 3) & !3;
+    component / rows * column_stride + component % rows * size
+}
+
+fn read_index(index_type: &IndexType, bytes: &[u8]) -> usize {
+    match *index_type {
+        IndexType::U8 => bytes[0] as usize,
+        IndexType::U16 => LE::read_u16(bytes) as usize,
+        IndexType::U32 => LE::read_u32(bytes) as usize,
+    }
+}
+
+/// The sparse substitutions of an accessor.
+struct Substitutions<'s> {
+    count: usize,
+    index_type: IndexType,
+    indices: &'s [u8],
+    index_stride: usize,
+    values: &'s [u8],
+    value_stride: usize,
+}
+
+/// A cursor over the elements of an accessor, including sparse
+/// substitutions, that reads them one at a time from the buffer data.
+///
+/// The sparse indices are checked to be strictly increasing, as the
+/// specification requires.
+pub(crate) struct Elements<'s> {
+    data_type: DataType,
+    dimensions: Dimensions,
+    normalized: bool,
+    count: usize,
+    base: Option<(&'s [u8], usize)>,
+    sparse: Option<Substitutions<'s>>,
+    substitution: usize,
+    element: usize,
+}
+
+impl<'s> Elements<'s> {
+    /// Locates the data of an accessor, failing if any of it is missing.
+    pub(crate) fn new<'a>(
+        accessor: &Accessor<'a>,
+        get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    ) -> Result<Self, Error> {
+        accessor.check_faulted()?;
+        let data_type = accessor.data_type();
+        let dimensions = accessor.dimensions();
+        let components = dimensions.multiplicity();
+        let size = component_offset(dimensions, data_type, components - 1) + data_type.size();
+        let size = match dimensions {
+            Dimensions::Mat2 | Dimensions::Mat3 | Dimensions::Mat4 => (size + 3) & !3,
+            _ => size,
+        };
+        let count = accessor.count();
+
+        let base = match accessor.view() {
+            Some(view) => {
+                let stride = view.stride().unwrap_or(size);
+                let length = extent(count, stride, size);
+                let data = view_range(
+                    accessor.index(),
+                    &view,
+                    accessor.offset(),
+                    length,
+                    get_buffer_data,
+                )?;
+                Some((data, stride))
+            }
+            None => None,
+        };
+        let sparse = match accessor.sparse() {
+            Some(sparse) => {
+                let indices = sparse.indices();
+                let index_type = indices.index_type();
+                let index_view = indices.view();
+                let index_stride = index_view.stride().unwrap_or(index_type.size());
+                let index_data = view_range(
+                    accessor.index(),
+                    &index_view,
+                    indices.offset(),
+                    extent(sparse.count(), index_stride, index_type.size()),
+                    get_buffer_data,
+                )?;
+                let sparse_indices = (0..sparse.count()).map(|position| {
+                    read_index(&index_type, &index_data[position * index_stride..])
+                });
+                check_sparse_indices(accessor.index(), count, sparse_indices)?;
+                let values = sparse.values();
+                let value_view = values.view();
+                let value_stride = value_view.stride().unwrap_or(size);
+                let value_data = view_range(
+                    accessor.index(),
+                    &value_view,
+                    values.offset(),
+                    extent(sparse.count(), value_stride, size),
+                    get_buffer_data,
+                )?;
+                Some(Substitutions {
+                    count: sparse.count(),
+                    index_type,
+                    indices: index_data,
+                    index_stride,
+                    values: value_data,
+                    value_stride,
+                })
+            }
+            None => None,
+        };
+        Ok(Self {
+            data_type,
+            dimensions,
+            normalized: accessor.normalized(),
+            count,
+            base,
+            sparse,
+            substitution: 0,
+            element: 0,
+        })
+    }
+
+    /// Returns the number of components per element.
+    pub(crate) fn components(&self) -> usize {
+        self.dimensions.multiplicity()
+    }
+
+    /// Returns the bytes of the next element, which are `None` for the zeros
+    /// of a sparse accessor without a buffer view, or `None` at the end.
+    fn next_bytes(&mut self) -> Option<Option<&'s [u8]>> {
+        if self.element >= self.count {
+            return None;
+        }
+        let element = self.element;
+        self.element += 1;
+        if let Some(sparse) = self.sparse.as_ref() {
+            if self.substitution < sparse.count {
+                let index_bytes = &sparse.indices[self.substitution * sparse.index_stride..];
+                if read_index(&sparse.index_type, index_bytes) == element {
+                    self.substitution += 1;
+                    let offset = (self.substitution - 1) * sparse.value_stride;
+                    return Some(Some(&sparse.values[offset..]));
+                }
+            }
+        }
+        Some(self.base.map(|(data, stride)| &data[element * stride..]))
+    }
+
+    /// Reads the components of the next element, returning `false` at the
+    /// end.
+    ///
+    /// Normalized integers are mapped to floats if `normalize` is set, and
+    /// are otherwise read as stored.
+    pub(crate) fn next_values(&mut self, normalize: bool, values: &mut [f64]) -> bool {
+        let bytes = match self.next_bytes() {
+            Some(bytes) => bytes,
+            None => return false,
+        };
+        let normalized = normalize && self.normalized;
+        for (component, value) in values.iter_mut().enumerate() {
+            *value = match bytes {
+                Some(bytes) => {
+                    let offset = component_offset(self.dimensions, self.data_type, component);
+                    read_component(self.data_type, normalized, &bytes[offset..])
+                }
+                None => 0.0,
+            };
+        }
+        true
+    }
+
+    /// Passes the bytes of each component of the next element to `f`,
+    /// skipping matrix column padding, and returns `false` at the end.
+    pub(crate) fn next_components<F>(&mut self, mut f: F) -> bool
+    where
+        F: FnMut(&[u8]),
+    {
+        let bytes = match self.next_bytes() {
+            Some(bytes) => bytes,
+            None => return false,
+        };
+        let size = self.data_type.size();
+        for component in 0..self.components() {
+            match bytes {
+                Some(bytes) => {
+                    let offset = component_offset(self.dimensions, self.data_type, component);
+                    f(&bytes[offset..offset + size]);
+                }
+                None => f(&[0; 4][..size]),
+            }
+        }
+        true
+    }
+}
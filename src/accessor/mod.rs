@@ -57,6 +57,8 @@
 //! ```
 
 use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
 use crate::{buffer, Document};
 
 pub use json::accessor::ComponentType as DataType;
@@ -72,9 +74,228 @@ pub mod util;
 /// Contains data structures for sparse storage.
 pub mod sparse;
 
+#[cfg(feature = "utils")]
+mod cache;
+mod compare;
+mod elements;
+
+#[doc(inline)]
+pub use self::compare::{content_eq, content_eq_with, content_hash, CompareOptions, Comparison};
+pub(crate) use self::elements::Elements;
+
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+#[doc(inline)]
+pub use self::cache::AccessorLayoutCache;
 #[cfg(feature = "utils")]
 #[doc(inline)]
-pub use self::util::{Item, Iter};
+pub use self::util::{Item, Iter, ReaderOptions};
+
+/// Errors that may occur when reading accessor data.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+    /// The accessor data extends beyond the buffer data that is available,
+    /// for example because the buffer was truncated.
+    MissingData {
+        /// The index of the accessor.
+        accessor: usize,
+        /// The number of bytes required from the start of the buffer view.
+        required: usize,
+        /// The number of bytes of the buffer view that are available.
+        available: usize,
+    },
+
+    /// The accessor was faulted by a fault-tolerant import, see
+    /// [`Document::is_faulted`].
+    Faulted {
+        /// The index of the accessor.
+        accessor: usize,
+    },
+
+    /// The primitive read was faulted by a fault-tolerant import, see
+    /// [`Document::is_faulted`].
+    FaultedPrimitive {
+        /// The index of the mesh.
+        mesh: usize,
+        /// The index of the primitive within the mesh.
+        primitive: usize,
+    },
+
+    /// The accessor reads an `EXT_meshopt_compression` fallback buffer, whose
+    /// data must be provided by decompressing the buffer views that refer to
+    /// it.
+    MeshoptFallback {
+        /// The index of the accessor.
+        accessor: usize,
+        /// The index of the fallback buffer.
+        buffer: usize,
+    },
+
+    /// A sparse index is beyond the count of the accessor, or not greater
+    /// than the sparse index before it.
+    SparseIndex {
+        /// The index of the accessor.
+        accessor: usize,
+        /// The position of the sparse index among the sparse indices.
+        position: usize,
+        /// The value of the sparse index.
+        index: usize,
+    },
+
+    /// The accessor holds the indices of a primitive, but its buffer view
+    /// declares a byte stride, which the specification forbids.
+    IndexStride {
+        /// The index of the accessor.
+        accessor: usize,
+        /// The byte stride of the buffer view.
+        stride: usize,
+    },
+
+    /// An attribute accessor of a primitive has a different number of
+    /// elements than its other attributes; see [`Primitive::vertex_count`].
+    ///
+    /// [`Primitive::vertex_count`]: crate::Primitive::vertex_count
+    AttributeCount {
+        /// The index of the accessor.
+        accessor: usize,
+        /// The number of elements of the accessor.
+        count: usize,
+        /// The number of vertices of the primitive.
+        expected: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::MissingData {
+                accessor,
+                required,
+                available,
+            } => write!(
+                f,
+                "accessor {} requires {} bytes of its buffer view but only {} are available",
+                accessor, required, available
+            ),
+            Error::Faulted { accessor } => write!(f, "accessor {} is faulted", accessor),
+            Error::FaultedPrimitive { mesh, primitive } => {
+                write!(f, "primitive {} of mesh {} is faulted", primitive, mesh)
+            }
+            Error::MeshoptFallback { accessor, buffer } => write!(
+                f,
+                "accessor {} reads fallback buffer {}, which requires meshopt decompression",
+                accessor, buffer
+            ),
+            Error::SparseIndex {
+                accessor,
+                position,
+                index,
+            } => write!(
+                f,
+                "sparse index {} of accessor {} is {}, which is out of range or out of order",
+                position, accessor, index
+            ),
+            Error::IndexStride { accessor, stride } => write!(
+                f,
+                "accessor {} holds indices but its buffer view has a byte stride of {}",
+                accessor, stride
+            ),
+            Error::AttributeCount {
+                accessor,
+                count,
+                expected,
+            } => write!(
+                f,
+                "accessor {} has {} elements but its primitive has {} vertices",
+                accessor, count, expected
+            ),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Returns the number of bytes spanned by `count` elements of `size` bytes
+/// placed `stride` bytes apart.
+///
+/// Saturates at `usize::MAX`, which is beyond any buffer data, so that the
+/// extent of a hostile count fails the checks of `view_range`.
+pub(crate) fn extent(count: usize, stride: usize, size: usize) -> usize {
+    match count {
+        0 => 0,
+        n => stride.saturating_mul(n - 1).saturating_add(size),
+    }
+}
+
+/// Checks that the sparse indices of an accessor of `count` elements are
+/// strictly increasing and less than `count`.
+pub(crate) fn check_sparse_indices<I>(
+    accessor: usize,
+    count: usize,
+    indices: I,
+) -> Result<(), Error>
+where
+    I: IntoIterator<Item = usize>,
+{
+    let mut next = 0;
+    for (position, index) in indices.into_iter().enumerate() {
+        if index < next || index >= count {
+            return Err(Error::SparseIndex {
+                accessor,
+                position,
+                index,
+            });
+        }
+        next = index + 1;
+    }
+    Ok(())
+}
+
+/// Converts the `min` or `max` value of an accessor to `N` floats.
+///
+/// Components beyond the range of `f32` are clamped to it rather than
+/// becoming infinite. Returns `None` unless there are exactly `N` components.
+pub(crate) fn bound_f32<const N: usize>(values: Option<Vec<f64>>) -> Option<[f32; N]> {
+    let values = values?;
+    if values.len() != N {
+        return None;
+    }
+    let mut bound = [0.0; N];
+    for (x, &value) in bound.iter_mut().zip(&values) {
+        *x = value.clamp(f32::MIN as f64, f32::MAX as f64) as f32;
+    }
+    Some(bound)
+}
+
+/// Returns `length` bytes at `offset` within a buffer view.
+///
+/// The buffer view may itself be cut short by the end of the buffer data, in
+/// which case only the elements that lie within the available data can be read.
+/// Fallback buffers of `EXT_meshopt_compression` are read like any other buffer
+/// once `get_buffer_data` provides their decompressed data.
+pub(crate) fn view_range<'a, 's>(
+    accessor: usize,
+    view: &buffer::View<'a>,
+    offset: usize,
+    length: usize,
+    get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+) -> Result<&'s [u8], Error> {
+    let buffer = view.buffer();
+    let fallback = (!buffer.has_data()).then(|| buffer.index());
+    let data = get_buffer_data(buffer)
+        .and_then(|data| data.get(view.offset()..))
+        .unwrap_or(&[]);
+    let data = &data[..data.len().min(view.length())];
+    let end = offset.saturating_add(length);
+    data.get(offset..end).ok_or(match fallback {
+        Some(buffer) => Error::MeshoptFallback { accessor, buffer },
+        None => Error::MissingData {
+            accessor,
+            required: end,
+            available: data.len(),
+        },
+    })
+}
 
 /// A typed view into a buffer view.
 #[derive(Clone, Debug)]
@@ -128,13 +349,13 @@ impl<'a> Accessor<'a> {
     pub fn offset(&self) -> usize {
         // TODO: Change this function to return Option<usize> in the next
         // version and return None for sparse accessors.
-        self.json.byte_offset.unwrap_or_default().0 as usize
+        crate::saturating_usize(self.json.byte_offset.unwrap_or_default().0)
     }
 
     /// Returns the number of components within the buffer view - not to be confused
     /// with the number of bytes in the buffer view.
     pub fn count(&self) -> usize {
-        self.json.count.0 as usize
+        crate::saturating_usize(self.json.count.0)
     }
 
     /// Returns the data type of components in the attribute.
@@ -178,11 +399,23 @@ impl<'a> Accessor<'a> {
         self.json.max.clone()
     }
 
+    /// Returns the minimum value of each component at the double precision of
+    /// the JSON source, or `None` if it is absent or not an array of numbers.
+    pub fn min_f64(&self) -> Option<Vec<f64>> {
+        json::deserialize::from_value(self.json.min.clone()?).ok()
+    }
+
+    /// Returns the maximum value of each component at the double precision of
+    /// the JSON source, or `None` if it is absent or not an array of numbers.
+    pub fn max_f64(&self) -> Option<Vec<f64>> {
+        json::deserialize::from_value(self.json.max.clone()?).ok()
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
     }
 
     /// Specifies whether integer data values should be normalized.
@@ -198,4 +431,77 @@ impl<'a> Accessor<'a> {
             .as_ref()
             .map(|json| sparse::Sparse::new(self.document, json))
     }
+
+    /// Returns `true` if the accessor was faulted by a fault-tolerant import.
+    ///
+    /// See [`Document::is_faulted`].
+    pub fn is_faulted(&self) -> bool {
+        self.document
+            .is_faulted(crate::fault::EntityKind::Accessor, self.index)
+    }
+
+    /// Returns an error if the accessor was faulted by a fault-tolerant
+    /// import.
+    pub(crate) fn check_faulted(&self) -> Result<(), Error> {
+        match self.is_faulted() {
+            true => Err(Error::Faulted {
+                accessor: self.index,
+            }),
+            false => Ok(()),
+        }
+    }
+
+    /// Returns the bytes of the buffer view that hold the elements of this
+    /// accessor, from the start of the first element to the end of the last,
+    /// or `None` if it has no buffer view.
+    ///
+    /// Fails with [`Error::MissingData`] if any of them lies beyond the buffer
+    /// view or the data returned by `get_buffer_data`, including when the
+    /// declared count is too large for the range to be computed. Sparse
+    /// substitutions are not applied.
+    pub fn view_data<'s, F>(&self, get_buffer_data: F) -> Result<Option<&'s [u8]>, Error>
+    where
+        F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        self.check_faulted()?;
+        let view = match self.view() {
+            Some(view) => view,
+            None => return Ok(None),
+        };
+        let size = self.size();
+        let stride = view.stride().unwrap_or(size);
+        let length = extent(self.count(), stride, size);
+        view_range(self.index, &view, self.offset(), length, &get_buffer_data).map(Some)
+    }
+
+    /// Checks that every byte range read by this accessor, including its sparse
+    /// indices and values, lies within the available buffer data.
+    pub fn check_data<'s, F>(&self, get_buffer_data: F) -> Result<(), Error>
+    where
+        F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        self.view_data(&get_buffer_data)?;
+        let size = self.size();
+        if let Some(sparse) = self.sparse() {
+            let indices = sparse.indices();
+            let view = indices.view();
+            let index_size = indices.index_type().size();
+            let stride = view.stride().unwrap_or(index_size);
+            let length = extent(sparse.count(), stride, index_size);
+            view_range(
+                self.index,
+                &view,
+                indices.offset(),
+                length,
+                &get_buffer_data,
+            )?;
+
+            let values = sparse.values();
+            let view = values.view();
+            let stride = view.stride().unwrap_or(size);
+            let length = extent(sparse.count(), stride, size);
+            view_range(self.index, &view, values.offset(), length, &get_buffer_data)?;
+        }
+        Ok(())
+    }
 }
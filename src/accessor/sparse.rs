@@ -38,7 +38,7 @@ impl<'a> Indices<'a> {
 
     /// The offset relative to the start of the parent buffer view in bytes.
     pub fn offset(&self) -> usize {
-        self.json.byte_offset.0 as usize
+        crate::saturating_usize(self.json.byte_offset.0)
     }
 
     /// The data type of each index.
@@ -74,7 +74,7 @@ impl<'a> Sparse<'a> {
 
     /// Returns the number of attributes encoded in this sparse accessor.
     pub fn count(&self) -> usize {
-        self.json.count.0 as usize
+        crate::saturating_usize(self.json.count.0)
     }
 
     /// Returns an index array of size `count` that points to those accessor
@@ -121,7 +121,7 @@ impl<'a> Values<'a> {
 
     /// The offset relative to the start of the parent buffer view in bytes.
     pub fn offset(&self) -> usize {
-        self.json.byte_offset.0 as usize
+        crate::saturating_usize(self.json.byte_offset.0)
     }
 
     /// Optional application specific data.
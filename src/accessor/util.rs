@@ -1,17 +1,12 @@
-use core::{iter, mem};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::{mem, ops, slice};
 use core::marker::PhantomData;
+use core::sync::atomic::{AtomicUsize, Ordering};
 use byteorder::{ByteOrder, LE};
 
-use crate::{accessor, buffer};
-
-fn buffer_view_slice<'a, 's>(
-    view: buffer::View<'a>,
-    get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
-) -> Option<&'s [u8]> {
-    let start = view.offset();
-    let end = start + view.length();
-    get_buffer_data(view.buffer()).and_then(|slice| slice.get(start..end))
-}
+use crate::accessor::{self, check_sparse_indices, extent, view_range, AccessorLayoutCache, Error};
+use crate::buffer;
 
 /// General iterator for an accessor.
 #[derive(Clone, Debug)]
@@ -64,6 +59,114 @@ impl<'a, T: Item> Iterator for Iter<'a, T> {
 
 impl<'a, T: Item> ExactSizeIterator for Iter<'a, T> {}
 
+impl<'a, T: Item> Iter<'a, T> {
+    /// Returns an iterator over a range of the remaining items, or `None` if
+    /// the range is out of bounds.
+    ///
+    /// The first item of the range is located without reading the items
+    /// before it; see [`ItemIter::slice`] and [`SparseIter::slice`].
+    pub fn slice(self, range: ops::Range<usize>) -> Option<Self> {
+        match self {
+            Iter::Standard(iter) => iter.slice(range).map(Iter::Standard),
+            Iter::Sparse(iter) => iter.slice(range).map(Iter::Sparse),
+        }
+    }
+
+    /// Appends the remaining items to `out`.
+    ///
+    /// This is equivalent to `out.extend(self)`, except that tightly packed
+    /// items are converted in bulk; see [`ItemIter::read_into`].
+    pub fn read_into(self, out: &mut Vec<T>) {
+        match self {
+            Iter::Standard(iter) => iter.read_into(out),
+            Iter::Sparse(iter) => out.extend(iter),
+        }
+    }
+
+    /// Returns the number of components replaced so far according to the
+    /// [`ReaderOptions`] of the iterator.
+    ///
+    /// The count is shared with the clones of the iterator, so it remains
+    /// available through a clone after the iterator is consumed, for example
+    /// by [`Iter::read_into`].
+    pub fn replaced(&self) -> usize {
+        let sanitizer = match self {
+            Iter::Standard(iter) => iter.sanitizer.as_ref(),
+            Iter::Sparse(iter) => iter.values.sanitizer.as_ref(),
+        };
+        sanitizer.map_or(0, Sanitizer::replaced)
+    }
+}
+
+/// Options for sanitizing the floating-point components read by an [`Iter`].
+///
+/// Sanitization applies to `f32` components only, whichever way they are
+/// read, including [`Iter::read_into`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ReaderOptions {
+    /// Replaces NaN and infinite components with this value.
+    ///
+    /// The default is `None`, which keeps them.
+    pub replace_non_finite: Option<f32>,
+
+    /// Replaces subnormal components with zero of the same sign.
+    ///
+    /// Arithmetic on subnormals is very slow on some FPUs, or not supported
+    /// at all. The default is `false`.
+    pub flush_denormals: bool,
+}
+
+impl ReaderOptions {
+    /// Replaces a component according to the options, returning whether it
+    /// was replaced.
+    fn sanitize(&self, value: &mut f32) -> bool {
+        let original = *value;
+        if let (false, Some(replacement)) = (value.is_finite(), self.replace_non_finite) {
+            *value = replacement;
+        }
+        if self.flush_denormals && value.is_subnormal() {
+            *value = if value.is_sign_negative() { -0.0 } else { 0.0 };
+        }
+        value.to_bits() != original.to_bits()
+    }
+}
+
+/// Applies [`ReaderOptions`] to the items read by iterators, counting the
+/// replaced components across all of them.
+#[derive(Clone, Debug)]
+pub(crate) struct Sanitizer {
+    options: ReaderOptions,
+    replaced: Arc<AtomicUsize>,
+}
+
+impl Sanitizer {
+    /// Returns `None` if the options never replace anything.
+    pub(crate) fn new(options: ReaderOptions) -> Option<Self> {
+        if options.replace_non_finite.is_none() && !options.flush_denormals {
+            return None;
+        }
+        Some(Self {
+            options,
+            replaced: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// Returns the number of components replaced so far.
+    pub(crate) fn replaced(&self) -> usize {
+        self.replaced.load(Ordering::Relaxed)
+    }
+
+    fn apply<T: Item>(&self, items: &mut [T]) {
+        let replaced: usize = items
+            .iter_mut()
+            .map(|item| item.sanitize(&self.options))
+            .sum();
+        if replaced > 0 {
+            self.replaced.fetch_add(replaced, Ordering::Relaxed);
+        }
+    }
+}
+
 /// Iterator over indices of sparse accessor.
 #[derive(Clone, Debug)]
 pub enum SparseIndicesIter<'a> {
@@ -84,8 +187,26 @@ impl<'a> Iterator for SparseIndicesIter<'a> {
             SparseIndicesIter::U32(ref mut iter) => iter.next(),
         }
     }
+
+    fn nth(&mut self, nth: usize) -> Option<Self::Item> {
+        match *self {
+            SparseIndicesIter::U8(ref mut iter) => iter.nth(nth).map(|x| x as u32),
+            SparseIndicesIter::U16(ref mut iter) => iter.nth(nth).map(|x| x as u32),
+            SparseIndicesIter::U32(ref mut iter) => iter.nth(nth),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match *self {
+            SparseIndicesIter::U8(ref iter) => iter.size_hint(),
+            SparseIndicesIter::U16(ref iter) => iter.size_hint(),
+            SparseIndicesIter::U32(ref iter) => iter.size_hint(),
+        }
+    }
 }
 
+impl<'a> ExactSizeIterator for SparseIndicesIter<'a> {}
+
 /// Iterates over a sparse accessor.
 #[derive(Clone, Debug)]
 pub struct SparseIter<'a, T: Item> {
@@ -94,13 +215,14 @@ pub struct SparseIter<'a, T: Item> {
     /// This can be `None` if the base buffer view is not set. In this case the base values are all zero.
     base: Option<ItemIter<'a, T>>,
 
-    /// Number of values in the base accessor
+    /// Number of values in the base accessor, or the end of the range for
+    /// a sliced iterator.
     ///
     /// Valid even when `base` is not set.
     base_count: usize,
 
     /// Sparse indices iterator.
-    indices: iter::Peekable<SparseIndicesIter<'a>>,
+    indices: SparseIndicesIter<'a>,
 
     /// Sparse values iterator.
     values: ItemIter<'a, T>,
@@ -122,11 +244,58 @@ impl<'a, T: Item> SparseIter<'a, T> {
         SparseIter {
             base,
             base_count,
-            indices: indices.peekable(),
+            indices,
             values,
             counter: 0,
         }
     }
+
+    /// Skips `n` items without reading them.
+    ///
+    /// The sparse indices are strictly increasing, so the substitutions
+    /// before the new position are found with a binary search.
+    fn advance(&mut self, n: usize) {
+        let n = n.min(self.len());
+        if n == 0 {
+            return;
+        }
+        if let Some(base) = self.base.as_mut() {
+            base.advance(n);
+        }
+        let target = self.counter as usize + n;
+        let (mut low, mut high) = (0, self.indices.len());
+        while low < high {
+            let middle = (low + high) / 2;
+            match self.indices.clone().nth(middle) {
+                Some(index) if (index as usize) < target => low = middle + 1,
+                _ => high = middle,
+            }
+        }
+        if low > 0 {
+            self.indices.nth(low - 1);
+            self.values.advance(low);
+        }
+        self.counter = target as u32;
+    }
+
+    /// Returns an iterator over a range of the remaining items, or `None` if
+    /// the range is out of bounds.
+    ///
+    /// The base values are located directly from the stride, and the sparse
+    /// substitutions that apply within the range are found with a binary
+    /// search.
+    pub fn slice(mut self, range: ops::Range<usize>) -> Option<Self> {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+        self.advance(range.start);
+        self.base = match self.base {
+            Some(base) => Some(base.slice(0..range.len())?),
+            None => None,
+        };
+        self.base_count = self.counter as usize + range.len();
+        Some(self)
+    }
 }
 
 impl<'a, T: Item> Iterator for SparseIter<'a, T> {
@@ -134,8 +303,9 @@ impl<'a, T: Item> Iterator for SparseIter<'a, T> {
     fn next(&mut self) -> Option<Self::Item> {
         let mut next_value = if let Some(base) = self.base.as_mut() {
             // If accessor.bufferView is set we let base decide when we have reached the end
-            // of the iteration sequence.
-            base.next()?
+            // of the iteration sequence. The base value is sanitized only
+            // if it is not substituted.
+            base.next_unsanitized()?
         } else if (self.counter as usize) < self.base_count {
             // Else, we continue iterating until we have generated the number of items
             // specified by accessor.count
@@ -144,12 +314,12 @@ impl<'a, T: Item> Iterator for SparseIter<'a, T> {
             return None;
         };
 
-        let next_sparse_index = self.indices.peek();
-        if let Some(index) = next_sparse_index {
-            if *index == self.counter {
-                self.indices.next(); // advance
-                next_value = self.values.next().unwrap();
-            }
+        let next_sparse_index = self.indices.clone().next();
+        if next_sparse_index == Some(self.counter) {
+            self.indices.next(); // advance
+            next_value = self.values.next().unwrap();
+        } else if let Some(base) = self.base.as_ref() {
+            next_value = base.sanitized(next_value);
         }
 
         self.counter += 1;
@@ -157,6 +327,11 @@ impl<'a, T: Item> Iterator for SparseIter<'a, T> {
         Some(next_value)
     }
 
+    fn nth(&mut self, nth: usize) -> Option<Self::Item> {
+        self.advance(nth);
+        self.next()
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         let hint = self.base_count - (self.counter as usize).min(self.base_count);
         (hint, Some(hint))
@@ -173,15 +348,50 @@ pub trait Item {
     fn from_slice(slice: &[u8]) -> Self;
     /// Create an object of this type that represents a zero value.
     fn zero() -> Self;
+
+    /// Fill `out` with tightly packed objects read from a byte slice of
+    /// exactly `out.len() * size_of::<Self>()` bytes.
+    ///
+    /// The default implementation calls [`Item::from_slice`] once per object.
+    /// The implementations for `u16`, `i16`, `u32` and `f32`, and for arrays
+    /// of them, copy the whole slice and then convert it from little-endian,
+    /// which is a no-op on little-endian targets and a vectorizable byte swap
+    /// on big-endian ones.
+    fn read_packed(slice: &[u8], out: &mut [Self])
+    where
+        Self: Sized,
+    {
+        let size = mem::size_of::<Self>();
+        for (item, bytes) in out.iter_mut().zip(slice.chunks_exact(size)) {
+            *item = Self::from_slice(bytes);
+        }
+    }
+
+    /// Replaces the floating-point components of the object according to
+    /// `options`, returning the number of components replaced.
+    ///
+    /// The default implementation replaces nothing. The implementations for
+    /// `f32`, and for arrays of it, follow [`ReaderOptions`].
+    fn sanitize(&mut self, options: &ReaderOptions) -> usize {
+        let _ = options;
+        0
+    }
+}
+
+/// Returns a slice of arrays as a slice of their elements.
+fn flatten_mut<T, const N: usize>(items: &mut [[T; N]]) -> &mut [T] {
+    // Safety: arrays are laid out as `N` consecutive elements, without padding.
+    unsafe { slice::from_raw_parts_mut(items.as_mut_ptr() as *mut T, items.len() * N) }
 }
 
 /// Visits the items in an [`Accessor`].
 ///
 /// [`Accessor`]: struct.Accessor.html
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct ItemIter<'a, T: Item> {
     stride: usize,
     data: &'a [u8],
+    sanitizer: Option<Sanitizer>,
     _phantom: PhantomData<T>,
 }
 
@@ -201,6 +411,9 @@ impl Item for i16 {
     fn zero() -> Self {
         0
     }
+    fn read_packed(slice: &[u8], out: &mut [Self]) {
+        LE::read_i16_into(slice, out)
+    }
 }
 
 impl Item for u8 {
@@ -210,6 +423,9 @@ impl Item for u8 {
     fn zero() -> Self {
         0
     }
+    fn read_packed(slice: &[u8], out: &mut [Self]) {
+        out.copy_from_slice(slice)
+    }
 }
 
 impl Item for u16 {
@@ -219,6 +435,9 @@ impl Item for u16 {
     fn zero() -> Self {
         0
     }
+    fn read_packed(slice: &[u8], out: &mut [Self]) {
+        LE::read_u16_into(slice, out)
+    }
 }
 
 impl Item for u32 {
@@ -228,6 +447,9 @@ impl Item for u32 {
     fn zero() -> Self {
         0
     }
+    fn read_packed(slice: &[u8], out: &mut [Self]) {
+        LE::read_u32_into(slice, out)
+    }
 }
 
 impl Item for f32 {
@@ -237,47 +459,34 @@ impl Item for f32 {
     fn zero() -> Self {
         0.0
     }
-}
-
-impl<T: Item + Copy> Item for [T; 2] {
-    fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 2 * mem::size_of::<T>());
-        [
-            T::from_slice(slice),
-            T::from_slice(&slice[mem::size_of::<T>()..]),
-        ]
+    fn read_packed(slice: &[u8], out: &mut [Self]) {
+        LE::read_f32_into(slice, out)
     }
-    fn zero() -> Self {
-        [T::zero(); 2]
+    fn sanitize(&mut self, options: &ReaderOptions) -> usize {
+        options.sanitize(self) as usize
     }
 }
 
-impl<T: Item + Copy> Item for [T; 3] {
+impl<T: Item + Copy, const N: usize> Item for [T; N] {
     fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 3 * mem::size_of::<T>());
-        [
-            T::from_slice(slice),
-            T::from_slice(&slice[mem::size_of::<T>()..]),
-            T::from_slice(&slice[2 * mem::size_of::<T>()..]),
-        ]
+        let size = mem::size_of::<T>();
+        assert!(slice.len() >= N * size);
+        let mut item = [T::zero(); N];
+        for (i, component) in item.iter_mut().enumerate() {
+            *component = T::from_slice(&slice[i * size..]);
+        }
+        item
     }
     fn zero() -> Self {
-        [T::zero(); 3]
+        [T::zero(); N]
     }
-}
-
-impl<T: Item + Copy> Item for [T; 4] {
-    fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 4 * mem::size_of::<T>());
-        [
-            T::from_slice(slice),
-            T::from_slice(&slice[mem::size_of::<T>()..]),
-            T::from_slice(&slice[2 * mem::size_of::<T>()..]),
-            T::from_slice(&slice[3 * mem::size_of::<T>()..]),
-        ]
+    fn read_packed(slice: &[u8], out: &mut [Self]) {
+        T::read_packed(slice, flatten_mut(out))
     }
-    fn zero() -> Self {
-        [T::zero(); 4]
+    fn sanitize(&mut self, options: &ReaderOptions) -> usize {
+        self.iter_mut()
+            .map(|component| component.sanitize(options))
+            .sum()
     }
 }
 
@@ -287,29 +496,210 @@ impl<'a, T: Item> ItemIter<'a, T> {
         ItemIter {
             data: slice,
             stride,
+            sanitizer: None,
             _phantom: PhantomData,
         }
     }
+
+    /// Sanitizes the items read according to a sanitizer, if any.
+    pub(crate) fn sanitized_by(self, sanitizer: Option<&Sanitizer>) -> Self {
+        ItemIter {
+            sanitizer: sanitizer.cloned(),
+            ..self
+        }
+    }
+
+    /// Skips `n` items without reading them.
+    fn advance(&mut self, n: usize) {
+        self.data = self.data.get(n * self.stride..).unwrap_or(&[]);
+    }
+
+    /// Reads the next item without sanitizing it.
+    fn next_unsanitized(&mut self) -> Option<T> {
+        let stride = if self.data.len() >= self.stride {
+            Some(self.stride)
+        } else if self.data.len() >= mem::size_of::<T>() {
+            Some(mem::size_of::<T>())
+        } else {
+            None
+        };
+        if let Some(stride) = stride {
+            let (val, data) = self.data.split_at(stride);
+            let val = T::from_slice(val);
+            self.data = data;
+            Some(val)
+        } else {
+            None
+        }
+    }
+
+    /// Sanitizes an item that was read.
+    fn sanitized(&self, mut item: T) -> T {
+        if let Some(sanitizer) = self.sanitizer.as_ref() {
+            sanitizer.apply(slice::from_mut(&mut item));
+        }
+        item
+    }
+
+    /// Returns an iterator over a range of the remaining items, or `None` if
+    /// the range is out of bounds.
+    ///
+    /// The range is located directly from the stride, without reading the
+    /// items before it.
+    pub fn slice(self, range: ops::Range<usize>) -> Option<Self> {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+        let length = extent(range.len(), self.stride, mem::size_of::<T>());
+        let data = match length {
+            0 => &[],
+            _ => &self.data[range.start * self.stride..][..length],
+        };
+        Some(ItemIter { data, ..self })
+    }
+
+    /// Appends the remaining items to `out`.
+    ///
+    /// If the items are tightly packed, they are read with a single call to
+    /// [`Item::read_packed`] instead of one [`Item::from_slice`] per item,
+    /// and then sanitized together.
+    pub fn read_into(self, out: &mut Vec<T>) {
+        let size = mem::size_of::<T>();
+        if size == 0 || self.stride != size {
+            out.extend(self);
+            return;
+        }
+        let count = self.data.len() / size;
+        let start = out.len();
+        out.extend((0..count).map(|_| T::zero()));
+        T::read_packed(&self.data[..count * size], &mut out[start..]);
+        if let Some(sanitizer) = self.sanitizer.as_ref() {
+            sanitizer.apply(&mut out[start..]);
+        }
+    }
 }
 
 impl<'a, 's, T: Item> Iter<'s, T> {
     /// Constructor.
+    ///
+    /// Returns `None` if the accessor has no data or its data is not available;
+    /// see [`Iter::try_new`] to tell the two apart.
     pub fn new<F>(accessor: super::Accessor<'a>, get_buffer_data: F) -> Option<Iter<'s, T>>
     where
         F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
     {
+        Self::try_new(accessor, get_buffer_data).ok().flatten()
+    }
+
+    /// Constructs an iterator that sanitizes the items it reads.
+    ///
+    /// Like [`Iter::new`], but the floating-point components are replaced
+    /// according to `options`. See [`Iter::replaced`].
+    pub fn new_with<F>(
+        accessor: super::Accessor<'a>,
+        get_buffer_data: F,
+        options: ReaderOptions,
+    ) -> Option<Iter<'s, T>>
+    where
+        F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Self::try_new_with(accessor, get_buffer_data, options)
+            .ok()
+            .flatten()
+    }
+
+    /// Fallible constructor of an iterator that sanitizes the items it reads.
+    ///
+    /// See [`Iter::try_new`] and [`Iter::new_with`].
+    pub fn try_new_with<F>(
+        accessor: super::Accessor<'a>,
+        get_buffer_data: F,
+        options: ReaderOptions,
+    ) -> Result<Option<Iter<'s, T>>, Error>
+    where
+        F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Self::try_new_sanitized(accessor, get_buffer_data, Sanitizer::new(options).as_ref())
+    }
+
+    /// Constructs an iterator that shares the count of replaced components of
+    /// a sanitizer, reading the layout of the accessor from a cache if any.
+    pub(crate) fn new_sanitized<F>(
+        accessor: super::Accessor<'a>,
+        get_buffer_data: F,
+        sanitizer: Option<&Sanitizer>,
+        cache: Option<&'s AccessorLayoutCache>,
+    ) -> Option<Iter<'s, T>>
+    where
+        F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Self::try_new_cached(accessor, get_buffer_data, sanitizer, cache)
+            .ok()
+            .flatten()
+    }
+
+    /// Fallible constructor of an iterator that shares the count of replaced
+    /// components of a sanitizer, reading the layout of the accessor from a
+    /// cache if any.
+    pub(crate) fn try_new_cached<F>(
+        accessor: super::Accessor<'a>,
+        get_buffer_data: F,
+        sanitizer: Option<&Sanitizer>,
+        cache: Option<&'s AccessorLayoutCache>,
+    ) -> Result<Option<Iter<'s, T>>, Error>
+    where
+        F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        if let Some(cache) = cache {
+            accessor.check_faulted()?;
+            let size = mem::size_of::<T>();
+            if let Some(layout) = cache.layout(&accessor, size, &get_buffer_data) {
+                return layout.iter(&accessor, &get_buffer_data, sanitizer);
+            }
+        }
+        Self::try_new_sanitized(accessor, get_buffer_data, sanitizer)
+    }
+
+    /// Fallible constructor.
+    ///
+    /// Returns `Ok(None)` if the accessor is neither sparse nor backed by a
+    /// buffer view, `Err(Error::MissingData { .. })` if any of its data lies
+    /// beyond the data returned by `get_buffer_data`,
+    /// `Err(Error::SparseIndex { .. })` if its sparse indices are out of range
+    /// or out of order, and `Err(Error::Faulted { .. })` if it was faulted by
+    /// a fault-tolerant import.
+    pub fn try_new<F>(
+        accessor: super::Accessor<'a>,
+        get_buffer_data: F,
+    ) -> Result<Option<Iter<'s, T>>, Error>
+    where
+        F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Self::try_new_sanitized(accessor, get_buffer_data, None)
+    }
+
+    fn try_new_sanitized<F>(
+        accessor: super::Accessor<'a>,
+        get_buffer_data: F,
+        sanitizer: Option<&Sanitizer>,
+    ) -> Result<Option<Iter<'s, T>>, Error>
+    where
+        F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let sanitized = |iter: ItemIter<'s, T>| iter.sanitized_by(sanitizer);
+        accessor.check_faulted()?;
+        let index = accessor.index();
         match accessor.sparse() {
             Some(sparse) => {
                 // Using `if let` here instead of map to preserve the early return behavior.
                 let base_iter = if let Some(view) = accessor.view() {
                     let stride = view.stride().unwrap_or(mem::size_of::<T>());
 
-                    let start = accessor.offset();
-                    let end = start + stride * (accessor.count() - 1) + mem::size_of::<T>();
-                    let subslice = buffer_view_slice(view, &get_buffer_data)
-                        .and_then(|slice| slice.get(start..end))?;
+                    let length = extent(accessor.count(), stride, mem::size_of::<T>());
+                    let subslice =
+                        view_range(index, &view, accessor.offset(), length, &get_buffer_data)?;
 
-                    Some(ItemIter::new(subslice, stride))
+                    Some(sanitized(ItemIter::new(subslice, stride)))
                 } else {
                     None
                 };
@@ -324,10 +714,9 @@ impl<'a, 's, T: Item> Iter<'s, T> {
                     let index_size = indices.index_type().size();
                     let stride = view.stride().unwrap_or(index_size);
 
-                    let start = indices.offset();
-                    let end = start + stride * (sparse_count - 1) + index_size;
-                    let subslice = buffer_view_slice(view, &get_buffer_data)
-                        .and_then(|slice| slice.get(start..end))?;
+                    let length = extent(sparse_count, stride, index_size);
+                    let subslice =
+                        view_range(index, &view, indices.offset(), length, &get_buffer_data)?;
 
                     match indices.index_type() {
                         accessor::sparse::IndexType::U8 => {
@@ -341,47 +730,47 @@ impl<'a, 's, T: Item> Iter<'s, T> {
                         }
                     }
                 };
+                let indices = index_iter.clone().map(|index| index as usize);
+                check_sparse_indices(index, base_count, indices)?;
 
                 let value_iter = {
                     let view = values.view();
                     let stride = view.stride().unwrap_or(mem::size_of::<T>());
 
-                    let start = values.offset();
-                    let end = start + stride * (sparse_count - 1) + mem::size_of::<T>();
-                    let subslice = buffer_view_slice(view, &get_buffer_data)
-                        .and_then(|slice| slice.get(start..end))?;
+                    let length = extent(sparse_count, stride, mem::size_of::<T>());
+                    let subslice =
+                        view_range(index, &view, values.offset(), length, &get_buffer_data)?;
 
-                    ItemIter::new(subslice, stride)
+                    sanitized(ItemIter::new(subslice, stride))
                 };
 
-                Some(Iter::Sparse(SparseIter::new(
+                Ok(Some(Iter::Sparse(SparseIter::new(
                     base_iter, base_count, index_iter, value_iter,
-                )))
+                ))))
             }
             None => {
                 debug_assert_eq!(mem::size_of::<T>(), accessor.size());
                 debug_assert!(mem::size_of::<T>() > 0);
 
-                accessor.view().and_then(|view| {
-                    let stride = view.stride().unwrap_or(mem::size_of::<T>());
-                    debug_assert!(
-                        stride >= mem::size_of::<T>(),
-                        "Mismatch in stride, expected at least {} stride but found {}",
-                        mem::size_of::<T>(),
-                        stride
-                    );
-
-                    let start = accessor.offset();
-                    let end = start + stride * (accessor.count() - 1) + mem::size_of::<T>();
-                    let subslice = buffer_view_slice(view, &get_buffer_data)
-                        .and_then(|slice| slice.get(start..end))?;
-
-                    Some(Iter::Standard(ItemIter {
-                        stride,
-                        data: subslice,
-                        _phantom: PhantomData,
-                    }))
-                })
+                let view = match accessor.view() {
+                    Some(view) => view,
+                    None => return Ok(None),
+                };
+                let stride = view.stride().unwrap_or(mem::size_of::<T>());
+                debug_assert!(
+                    stride >= mem::size_of::<T>(),
+                    "Mismatch in stride, expected at least {} stride but found {}",
+                    mem::size_of::<T>(),
+                    stride
+                );
+
+                let length = extent(accessor.count(), stride, mem::size_of::<T>());
+                let subslice =
+                    view_range(index, &view, accessor.offset(), length, &get_buffer_data)?;
+
+                Ok(Some(Iter::Standard(sanitized(ItemIter::new(
+                    subslice, stride,
+                )))))
             }
         }
     }
@@ -392,21 +781,7 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let stride = if self.data.len() >= self.stride {
-            Some(self.stride)
-        } else if self.data.len() >= mem::size_of::<T>() {
-            Some(mem::size_of::<T>())
-        } else {
-            None
-        };
-        if let Some(stride) = stride {
-            let (val, data) = self.data.split_at(stride);
-            let val = T::from_slice(val);
-            self.data = data;
-            Some(val)
-        } else {
-            None
-        }
+        self.next_unsanitized().map(|val| self.sanitized(val))
     }
 
     fn nth(&mut self, nth: usize) -> Option<Self::Item> {
@@ -414,7 +789,7 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
             if val_data.len() >= mem::size_of::<T>() {
                 let val = T::from_slice(val_data);
                 self.data = &val_data[self.stride.min(val_data.len())..];
-                Some(val)
+                Some(self.sanitized(val))
             } else {
                 None
             }
@@ -428,6 +803,7 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
             self.data
                 .get((self.data.len() - 1) / self.stride * self.stride..)
                 .map(T::from_slice)
+                .map(|val| self.sanitized(val))
         } else {
             None
         }
@@ -443,3 +819,70 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
         (hint, Some(hint))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    /// Two `VEC3` positions followed by two `u16` and two `u32` indices, all
+    /// little-endian as glTF requires.
+    #[rustfmt::skip]
+    const DATA: [u8; 36] = [
+        0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0xbf,
+        0x00, 0x00, 0x40, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0xc0,
+        0x02, 0x01, 0xff, 0x00,
+        0x04, 0x03, 0x02, 0x01, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    const GLTF: &str = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 36}],
+        "bufferViews": [
+            {"buffer": 0, "byteLength": 24},
+            {"buffer": 0, "byteOffset": 24, "byteLength": 4},
+            {"buffer": 0, "byteOffset": 28, "byteLength": 8},
+            {"buffer": 0, "byteLength": 24, "byteStride": 12}
+        ],
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3"},
+            {"bufferView": 1, "componentType": 5123, "count": 2, "type": "SCALAR"},
+            {"bufferView": 2, "componentType": 5125, "count": 2, "type": "SCALAR"},
+            {"bufferView": 3, "componentType": 5126, "count": 2, "type": "SCALAR"}
+        ]
+    }"#;
+
+    fn read<T: Item + Clone>(index: usize) -> (Vec<T>, Vec<T>) {
+        let gltf = crate::Gltf::from_slice(GLTF.as_bytes()).unwrap();
+        let accessor = gltf.accessors().nth(index).unwrap();
+        let iter = Iter::<T>::new(accessor, |_| Some(&DATA[..])).unwrap();
+        let mut bulk = vec![T::zero()];
+        iter.clone().read_into(&mut bulk);
+        (bulk.split_off(1), iter.collect())
+    }
+
+    #[test]
+    fn packed_items() {
+        let positions = vec![[1.0, 2.0, -0.5], [3.0, 0.0, -4.0]];
+        assert_eq!(read::<[f32; 3]>(0), (positions.clone(), positions));
+        assert_eq!(read::<u16>(1), (vec![0x0102, 0xff], vec![0x0102, 0xff]));
+        let indices = vec![0x0102_0304, 1];
+        assert_eq!(read::<u32>(2), (indices.clone(), indices));
+    }
+
+    #[test]
+    fn strided_items() {
+        assert_eq!(read::<f32>(3), (vec![1.0, 3.0], vec![1.0, 3.0]));
+    }
+
+    /// Run on a big-endian target, for example with
+    /// `cross +nightly test --target powerpc-unknown-linux-gnu --lib accessor`.
+    #[cfg(target_endian = "big")]
+    #[test]
+    fn big_endian_host() {
+        assert_ne!(u32::from_ne_bytes([0x04, 0x03, 0x02, 0x01]), 0x0102_0304);
+        let mut out = vec![0; 2];
+        u32::read_packed(&DATA[28..], &mut out);
+        assert_eq!(out, [0x0102_0304, 1]);
+    }
+}
@@ -0,0 +1,18 @@
+use alloc::vec::Vec;
+
+use crate::Document;
+
+/// Returns the animation channels that target nodes outside the scenes, as
+/// `(animation, channel, node)`.
+///
+/// A node is inside the scenes if a scene contains it, or if it is a joint or
+/// the skeleton of a skin used by a node inside the scenes, such as the bones
+/// of a rig that is kept apart from the scene hierarchy. If the document has
+/// no scenes, every node is inside them.
+///
+/// Such channels typically drive helpers or control rigs that were left
+/// behind by an exporter. They can be removed with
+/// [`strip_dangling_channels`](crate::transform::strip_dangling_channels).
+pub fn dangling_animation_targets(document: &Document) -> Vec<(usize, usize, usize)> {
+    crate::transform::dangling_channels(document.as_json())
+}
@@ -0,0 +1,335 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::image::{bytes_per_pixel, Metadata};
+use crate::transform::{for_each_image_mut, for_each_texture_mut};
+use crate::{buffer, Document};
+
+/// The perceptual cost of halving an image that is only used by textures
+/// without a more specific weight, such as occlusion or metallic-roughness.
+const OTHER_WEIGHT: u32 = 1;
+
+/// The perceptual cost of halving an image used as an emissive texture.
+const EMISSIVE_WEIGHT: u32 = 2;
+
+/// The perceptual cost of halving an image used as a base color texture.
+const BASE_COLOR_WEIGHT: u32 = 3;
+
+/// The perceptual cost of halving an image used as a normal texture.
+const NORMAL_WEIGHT: u32 = 4;
+
+/// Options for [`texture_downgrade_plan`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DowngradeOptions {
+    /// Images are not reduced to less than this many pixels along their
+    /// longer side. Defaults to 64.
+    pub min_size: u32,
+
+    /// If `true`, the estimated decoded size of every image includes a full
+    /// mipmap chain, which adds a third. Defaults to `true`.
+    pub mipmaps: bool,
+
+    /// If `true`, images used as emissive textures are never reduced.
+    /// Defaults to `true`.
+    pub protect_emissive: bool,
+
+    /// Images are never reduced if the extras of the image, or of a texture
+    /// or material that uses it, are an object with this key set to `true`.
+    /// Defaults to `"protected"`.
+    ///
+    /// Extras are only read with the `extras` feature.
+    pub protect_tag: &'static str,
+}
+
+impl Default for DowngradeOptions {
+    fn default() -> Self {
+        Self {
+            min_size: 64,
+            mipmaps: true,
+            protect_emissive: true,
+            protect_tag: "protected",
+        }
+    }
+}
+
+/// An image that a [`DowngradePlan`] reduces.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DowngradeEntry {
+    /// The index of the image.
+    pub image: usize,
+
+    /// The dimensions of the image.
+    pub original: Metadata,
+
+    /// The dimensions to resample the image to.
+    pub target: Metadata,
+
+    /// The estimated decoded size at the target dimensions, in bytes.
+    pub bytes: u64,
+}
+
+/// The result of [`texture_downgrade_plan`].
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DowngradePlan {
+    /// The images to reduce, in image order.
+    pub entries: Vec<DowngradeEntry>,
+
+    /// The images whose dimensions could not be read, which are not counted.
+    pub unknown: Vec<usize>,
+
+    /// The estimated decoded size of the images before the plan, in bytes.
+    pub original_bytes: u64,
+
+    /// The estimated decoded size of the images after the plan, in bytes.
+    pub planned_bytes: u64,
+
+    /// `true` if the planned size is within the budget; otherwise the
+    /// protected images and those at their minimum size alone exceed it.
+    pub fits: bool,
+}
+
+/// An image considered for reduction.
+struct Candidate {
+    image: usize,
+    metadata: Metadata,
+    bytes_per_pixel: u32,
+    weight: u32,
+    protected: bool,
+    halvings: u32,
+}
+
+impl Candidate {
+    /// Returns the dimensions after the given number of halvings.
+    fn dimensions(&self, halvings: u32) -> Metadata {
+        Metadata {
+            width: (self.metadata.width >> halvings).max(1),
+            height: (self.metadata.height >> halvings).max(1),
+        }
+    }
+
+    /// Returns the estimated decoded size after the given number of halvings.
+    fn bytes(&self, halvings: u32, options: &DowngradeOptions) -> u64 {
+        let Metadata { width, height } = self.dimensions(halvings);
+        let bytes = width as u64 * height as u64 * self.bytes_per_pixel as u64;
+        match options.mipmaps {
+            true => bytes * 4 / 3,
+            false => bytes,
+        }
+    }
+
+    /// Returns `true` if the image may be halved once more.
+    fn can_halve(&self, options: &DowngradeOptions) -> bool {
+        if self.protected || self.halvings >= 31 {
+            return false;
+        }
+        let next = self.dimensions(self.halvings + 1);
+        next != self.dimensions(self.halvings) && next.width.max(next.height) >= options.min_size
+    }
+}
+
+/// Returns `true` if extras are an object with the given key set to `true`.
+#[cfg(feature = "extras")]
+fn tagged(extras: &json::Extras, tag: &str) -> bool {
+    extras
+        .as_ref()
+        .and_then(|raw| serde_json::from_str::<json::Value>(raw.get()).ok())
+        .map_or(false, |value| {
+            value.get(tag) == Some(&json::Value::Bool(true))
+        })
+}
+
+#[cfg(not(feature = "extras"))]
+fn tagged(_: &json::Extras, _: &str) -> bool {
+    false
+}
+
+/// Proposes target dimensions for the images of a document that bring their
+/// estimated decoded size within `budget_bytes`.
+///
+/// The decoded size of an image is its width times its height times the bytes
+/// per pixel read from its PNG or JPEG header, plus a third for mipmaps if
+/// enabled. Only images stored in buffer views can be measured; the others are
+/// listed as unknown.
+///
+/// Images are halved along both axes, one step at a time. Each step goes to
+/// the image that saves the most bytes per unit of perceptual cost, where the
+/// cost of the `n`th halving of an image is `2^(n - 1)` times the weight of
+/// its most demanding use: 4 for normal textures, 3 for base color textures,
+/// 2 for emissive textures and 1 for any other texture. Images that no
+/// texture uses have no cost and are reduced first. Protected images, see
+/// [`DowngradeOptions`], are left untouched.
+///
+/// The plan only contains data. The resampling is left to the caller, who
+/// may then store the results with
+/// [`DocumentMut::replace_image`](crate::transform::DocumentMut::replace_image):
+///
+/// ```
+/// use gltf::analysis::{texture_downgrade_plan, DowngradeOptions};
+/// use gltf::transform::{DocumentMut, EditError};
+///
+/// /// Decodes an image, resizes it and encodes it as PNG with an image library.
+/// fn resample(data: &[u8], width: u32, height: u32) -> Vec<u8> {
+///     # let _ = (width, height);
+///     // ...
+///     # data.to_vec()
+/// }
+///
+/// fn fit(
+///     document: gltf::Document,
+///     buffers: Vec<gltf::buffer::Data>,
+/// ) -> Result<DocumentMut, EditError> {
+///     let options = DowngradeOptions::default();
+///     let plan = texture_downgrade_plan(&document, &buffers, 64 << 20, options);
+///     let mut data = Vec::new();
+///     for entry in plan.entries.iter() {
+///         let image = document.images().nth(entry.image).unwrap();
+///         let encoded = image.view_data(&buffers).unwrap();
+///         data.push(resample(encoded, entry.target.width, entry.target.height));
+///     }
+///     let mut document = DocumentMut::new(document, buffers);
+///     for (entry, data) in plan.entries.iter().zip(data) {
+///         document.replace_image(entry.image, data, "image/png")?;
+///     }
+///     Ok(document)
+/// }
+/// ```
+pub fn texture_downgrade_plan(
+    document: &Document,
+    buffers: &[buffer::Data],
+    budget_bytes: u64,
+    options: DowngradeOptions,
+) -> DowngradePlan {
+    let root = document.as_json();
+    let mut weights = vec![0; root.images.len()];
+    let mut protected: Vec<bool> = root
+        .images
+        .iter()
+        .map(|image| tagged(&image.extras, options.protect_tag))
+        .collect();
+    let texture_weights = texture_weights(root, &options);
+    for (texture, &(weight, tagged)) in root.textures.iter().zip(texture_weights.iter()) {
+        for_each_image_mut(&mut texture.clone(), |image| {
+            let image = image.value();
+            if let Some(slot) = weights.get_mut(image) {
+                *slot = weight.max(*slot);
+                protected[image] |= tagged;
+            }
+        });
+    }
+
+    let mut plan = DowngradePlan::default();
+    let mut candidates = Vec::new();
+    for image in document.images() {
+        let data = match image.view_data(buffers) {
+            Some(data) => data,
+            None => {
+                plan.unknown.push(image.index());
+                continue;
+            }
+        };
+        match (Metadata::peek(data), bytes_per_pixel(data)) {
+            (Some(metadata), Some(bytes_per_pixel)) => candidates.push(Candidate {
+                image: image.index(),
+                metadata,
+                bytes_per_pixel,
+                weight: weights[image.index()],
+                protected: protected[image.index()],
+                halvings: 0,
+            }),
+            _ => plan.unknown.push(image.index()),
+        }
+    }
+
+    plan.original_bytes = candidates.iter().map(|x| x.bytes(0, &options)).sum();
+    plan.planned_bytes = plan.original_bytes;
+    while plan.planned_bytes > budget_bytes {
+        // Compares the bytes saved per cost as `saved / cost`, with a cost
+        // of zero first.
+        let mut best: Option<(usize, u64, u64)> = None;
+        for (index, candidate) in candidates.iter().enumerate() {
+            if !candidate.can_halve(&options) {
+                continue;
+            }
+            let saved = candidate.bytes(candidate.halvings, &options)
+                - candidate.bytes(candidate.halvings + 1, &options);
+            let cost = (candidate.weight as u64) << candidate.halvings;
+            let better = match best {
+                None => true,
+                Some((_, best_saved, best_cost)) => {
+                    saved as u128 * best_cost as u128 > best_saved as u128 * cost as u128
+                        || (cost == 0 && best_cost == 0 && saved > best_saved)
+                }
+            };
+            if better {
+                best = Some((index, saved, cost));
+            }
+        }
+        match best {
+            Some((index, saved, _)) => {
+                candidates[index].halvings += 1;
+                plan.planned_bytes -= saved;
+            }
+            None => break,
+        }
+    }
+    plan.fits = plan.planned_bytes <= budget_bytes;
+
+    plan.entries = candidates
+        .iter()
+        .filter(|candidate| candidate.halvings > 0)
+        .map(|candidate| DowngradeEntry {
+            image: candidate.image,
+            original: candidate.metadata,
+            target: candidate.dimensions(candidate.halvings),
+            bytes: candidate.bytes(candidate.halvings, &options),
+        })
+        .collect();
+    plan
+}
+
+/// Returns the weight of the most demanding use of every texture, and whether
+/// a material that uses it, or the texture itself, is tagged as protected.
+fn texture_weights(root: &json::Root, options: &DowngradeOptions) -> Vec<(u32, bool)> {
+    let mut weights: Vec<(u32, bool)> = root
+        .textures
+        .iter()
+        .map(|texture| (0, tagged(&texture.extras, options.protect_tag)))
+        .collect();
+    let mut use_texture = |index: json::Index<json::Texture>, weight: u32, protect: bool| {
+        if let Some((slot, protected)) = weights.get_mut(index.value()) {
+            *slot = weight.max(*slot);
+            *protected |= protect;
+        }
+    };
+    for material in root.materials.iter() {
+        let protect = tagged(&material.extras, options.protect_tag);
+        let mut textures = Vec::new();
+        for_each_texture_mut(&mut material.clone(), |index| textures.push(*index));
+        for index in textures {
+            use_texture(index, OTHER_WEIGHT, protect);
+        }
+        if let Some(info) = material.pbr_metallic_roughness.base_color_texture.as_ref() {
+            use_texture(info.index, BASE_COLOR_WEIGHT, protect);
+        }
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        if let Some(info) = material
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.pbr_specular_glossiness.as_ref())
+            .and_then(|sg| sg.diffuse_texture.as_ref())
+        {
+            use_texture(info.index, BASE_COLOR_WEIGHT, protect);
+        }
+        if let Some(info) = material.normal_texture.as_ref() {
+            use_texture(info.index, NORMAL_WEIGHT, protect);
+        }
+        if let Some(info) = material.emissive_texture.as_ref() {
+            use_texture(
+                info.index,
+                EMISSIVE_WEIGHT,
+                protect || options.protect_emissive,
+            );
+        }
+    }
+    weights
+}
@@ -0,0 +1,381 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::fault::EntityKind;
+use crate::transform::{for_each_image_mut, for_each_texture_mut};
+use crate::Document;
+
+/// An entity of a [`Graph`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GraphEntity<'a> {
+    /// The kind of the entity.
+    pub kind: EntityKind,
+
+    /// The index of the entity, within its mesh for primitives.
+    pub index: usize,
+
+    /// The name of the entity, if it has one.
+    pub name: Option<&'a str>,
+}
+
+/// The references between the entities of a document.
+///
+/// Entities are held in a fixed order: scenes, nodes, meshes followed by
+/// their primitives, skins, animations, cameras, materials, textures,
+/// samplers, images, accessors, buffer views and buffers.
+#[derive(Clone, Debug)]
+pub struct Graph<'a> {
+    entities: Vec<GraphEntity<'a>>,
+    positions: BTreeMap<(EntityKind, usize), usize>,
+    edges: Vec<(usize, usize)>,
+}
+
+/// Visits every entity that an entity references directly.
+///
+/// Meshes reference their primitives, and animations reference the nodes
+/// their channels target and the accessors of their samplers. References
+/// are visited as stored, including those to entities that do not exist.
+pub(crate) fn for_each_reference<F>(root: &json::Root, kind: EntityKind, index: usize, mut f: F)
+where
+    F: FnMut(EntityKind, usize),
+{
+    use EntityKind::*;
+    match kind {
+        Scene => {
+            if let Some(scene) = root.scenes.get(index) {
+                scene.nodes.iter().for_each(|node| f(Node, node.value()));
+            }
+        }
+        Node => {
+            let node = match root.nodes.get(index) {
+                Some(node) => node,
+                None => return,
+            };
+            node.children
+                .iter()
+                .flatten()
+                .for_each(|child| f(Node, child.value()));
+            #[cfg(feature = "cameras")]
+            if let Some(camera) = node.camera {
+                f(Camera, camera.value());
+            }
+            if let Some(mesh) = node.mesh {
+                f(Mesh, mesh.value());
+            }
+            #[cfg(feature = "skinning")]
+            if let Some(skin) = node.skin {
+                f(Skin, skin.value());
+            }
+        }
+        Mesh => {
+            if let Some(mesh) = root.meshes.get(index) {
+                for primitive in 0..mesh.primitives.len() {
+                    f(Primitive { mesh: index }, primitive);
+                }
+            }
+        }
+        Primitive { mesh } => {
+            let primitive = match root.meshes.get(mesh).and_then(|x| x.primitives.get(index)) {
+                Some(primitive) => primitive,
+                None => return,
+            };
+            for accessor in primitive.attributes.values() {
+                f(Accessor, accessor.value());
+            }
+            if let Some(accessor) = primitive.indices {
+                f(Accessor, accessor.value());
+            }
+            if let Some(material) = primitive.material {
+                f(Material, material.value());
+            }
+            for target in primitive.targets.iter().flatten() {
+                let accessors = [target.positions, target.normals, target.tangents];
+                for accessor in accessors.iter().flatten() {
+                    f(Accessor, accessor.value());
+                }
+            }
+            #[cfg(feature = "KHR_materials_variants")]
+            if let Some(variants) = primitive
+                .extensions
+                .as_ref()
+                .and_then(|extensions| extensions.khr_materials_variants.as_ref())
+            {
+                for mapping in variants.mappings.iter() {
+                    f(Material, mapping.material as usize);
+                }
+            }
+        }
+        #[cfg(feature = "skinning")]
+        Skin => {
+            if let Some(skin) = root.skins.get(index) {
+                if let Some(accessor) = skin.inverse_bind_matrices {
+                    f(Accessor, accessor.value());
+                }
+                skin.joints.iter().for_each(|joint| f(Node, joint.value()));
+                if let Some(skeleton) = skin.skeleton {
+                    f(Node, skeleton.value());
+                }
+            }
+        }
+        #[cfg(feature = "animation")]
+        Animation => {
+            if let Some(animation) = root.animations.get(index) {
+                for channel in animation.channels.iter() {
+                    f(Node, channel.target.node.value());
+                }
+                for sampler in animation.samplers.iter() {
+                    f(Accessor, sampler.input.value());
+                    f(Accessor, sampler.output.value());
+                }
+            }
+        }
+        Material => {
+            if let Some(material) = root.materials.get(index) {
+                for_each_texture_mut(&mut material.clone(), |texture| f(Texture, texture.value()));
+            }
+        }
+        Texture => {
+            if let Some(texture) = root.textures.get(index) {
+                if let Some(sampler) = texture.sampler {
+                    f(Sampler, sampler.value());
+                }
+                for_each_image_mut(&mut texture.clone(), |image| f(Image, image.value()));
+            }
+        }
+        Image => {
+            if let Some(view) = root.images.get(index).and_then(|image| image.buffer_view) {
+                f(View, view.value());
+            }
+        }
+        Accessor => {
+            if let Some(accessor) = root.accessors.get(index) {
+                if let Some(view) = accessor.buffer_view {
+                    f(View, view.value());
+                }
+                if let Some(sparse) = accessor.sparse.as_ref() {
+                    f(View, sparse.indices.buffer_view.value());
+                    f(View, sparse.values.buffer_view.value());
+                }
+            }
+        }
+        View => {
+            if let Some(view) = root.buffer_views.get(index) {
+                f(Buffer, view.buffer.value());
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the name of an entity.
+#[cfg(feature = "names")]
+fn name(root: &json::Root, kind: EntityKind, index: usize) -> Option<&str> {
+    use EntityKind::*;
+    let name = match kind {
+        Scene => &root.scenes[index].name,
+        Node => &root.nodes[index].name,
+        Mesh => &root.meshes[index].name,
+        #[cfg(feature = "skinning")]
+        Skin => &root.skins[index].name,
+        #[cfg(feature = "animation")]
+        Animation => &root.animations[index].name,
+        #[cfg(feature = "cameras")]
+        Camera => &root.cameras[index].name,
+        Material => &root.materials[index].name,
+        Texture => &root.textures[index].name,
+        Sampler => &root.samplers[index].name,
+        Image => &root.images[index].name,
+        Accessor => &root.accessors[index].name,
+        View => &root.buffer_views[index].name,
+        Buffer => &root.buffers[index].name,
+        _ => return None,
+    };
+    name.as_deref()
+}
+
+#[cfg(not(feature = "names"))]
+fn name(_: &json::Root, _: EntityKind, _: usize) -> Option<&str> {
+    None
+}
+
+/// Writes the Graphviz identifier of an entity.
+fn write_id(out: &mut dyn fmt::Write, entity: &GraphEntity) -> fmt::Result {
+    use EntityKind::*;
+    let prefix = match entity.kind {
+        Root => "root",
+        Buffer => "buffer",
+        View => "view",
+        Accessor => "accessor",
+        Image => "image",
+        Mesh => "mesh",
+        Primitive { mesh } => return write!(out, "primitive_{}_{}", mesh, entity.index),
+        Skin => "skin",
+        Animation => "animation",
+        Camera => "camera",
+        Material => "material",
+        Node => "node",
+        Sampler => "sampler",
+        Scene => "scene",
+        Texture => "texture",
+    };
+    write!(out, "{}_{}", prefix, entity.index)
+}
+
+/// Writes the Graphviz label of an entity, without the surrounding quotes.
+fn write_label(out: &mut dyn fmt::Write, entity: &GraphEntity) -> fmt::Result {
+    use EntityKind::*;
+    match entity.kind {
+        Primitive { mesh } => write!(out, "Primitive {}.{}", mesh, entity.index)?,
+        kind => write!(out, "{:?} {}", kind, entity.index)?,
+    }
+    if let Some(name) = entity.name {
+        out.write_str("\\n")?;
+        for c in name.chars() {
+            match c {
+                '"' | '\\' => write!(out, "\\{}", c)?,
+                '\n' => out.write_str("\\n")?,
+                c => out.write_char(c)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+impl<'a> Graph<'a> {
+    /// Returns the entities of the graph.
+    pub fn entities(&self) -> &[GraphEntity<'a>] {
+        &self.entities
+    }
+
+    /// Returns the entity of the given kind and index, if it exists.
+    pub fn get(&self, kind: EntityKind, index: usize) -> Option<&GraphEntity<'a>> {
+        self.positions
+            .get(&(kind, index))
+            .map(|&position| &self.entities[position])
+    }
+
+    /// Returns every reference as a pair of the referencing and the
+    /// referenced entity, in entity order.
+    pub fn edges(&self) -> impl Iterator<Item = (&GraphEntity<'a>, &GraphEntity<'a>)> + '_ {
+        self.edges
+            .iter()
+            .map(move |&(from, to)| (&self.entities[from], &self.entities[to]))
+    }
+
+    /// Returns the entities that the given entity references directly.
+    pub fn references(
+        &self,
+        kind: EntityKind,
+        index: usize,
+    ) -> impl Iterator<Item = &GraphEntity<'a>> + '_ {
+        let from = self.positions.get(&(kind, index)).copied();
+        let start = self.edges.partition_point(|&(x, _)| Some(x) < from);
+        self.edges[start..]
+            .iter()
+            .take_while(move |&&(x, _)| Some(x) == from)
+            .map(move |&(_, to)| &self.entities[to])
+    }
+
+    /// Writes the graph in the Graphviz DOT language.
+    ///
+    /// With a filter, only the entities of that kind, the entities they
+    /// reference or are referenced by, and the references between them are
+    /// written. A primitive filter only matches the primitives of its mesh.
+    pub fn to_dot(&self, out: &mut dyn fmt::Write, filter: Option<EntityKind>) -> fmt::Result {
+        let matches = |position: usize| match filter {
+            Some(kind) => self.entities[position].kind == kind,
+            None => true,
+        };
+        let mut included: Vec<bool> = (0..self.entities.len()).map(matches).collect();
+        if filter.is_some() {
+            for &(from, to) in self.edges.iter() {
+                if matches(from) || matches(to) {
+                    included[from] = true;
+                    included[to] = true;
+                }
+            }
+        }
+
+        out.write_str("digraph gltf {\n")?;
+        for (position, entity) in self.entities.iter().enumerate() {
+            if !included[position] {
+                continue;
+            }
+            out.write_str("    ")?;
+            write_id(out, entity)?;
+            out.write_str(" [label=\"")?;
+            write_label(out, entity)?;
+            out.write_str("\"];\n")?;
+        }
+        for &(from, to) in self.edges.iter() {
+            if !matches(from) && !matches(to) {
+                continue;
+            }
+            out.write_str("    ")?;
+            write_id(out, &self.entities[from])?;
+            out.write_str(" -> ")?;
+            write_id(out, &self.entities[to])?;
+            out.write_str(";\n")?;
+        }
+        out.write_str("}\n")
+    }
+}
+
+/// Builds the graph of the references between the entities of a document.
+///
+/// References to entities that do not exist are left out.
+pub fn dependency_graph(document: &Document) -> Graph<'_> {
+    use EntityKind::*;
+    let root = document.as_json();
+    let mut keys = Vec::new();
+    let push = |keys: &mut Vec<_>, kind: EntityKind, count: usize| {
+        keys.extend((0..count).map(|index| (kind, index)));
+    };
+    push(&mut keys, Scene, root.scenes.len());
+    push(&mut keys, Node, root.nodes.len());
+    for (index, mesh) in root.meshes.iter().enumerate() {
+        keys.push((Mesh, index));
+        push(&mut keys, Primitive { mesh: index }, mesh.primitives.len());
+    }
+    #[cfg(feature = "skinning")]
+    push(&mut keys, Skin, root.skins.len());
+    #[cfg(feature = "animation")]
+    push(&mut keys, Animation, root.animations.len());
+    #[cfg(feature = "cameras")]
+    push(&mut keys, Camera, root.cameras.len());
+    push(&mut keys, Material, root.materials.len());
+    push(&mut keys, Texture, root.textures.len());
+    push(&mut keys, Sampler, root.samplers.len());
+    push(&mut keys, Image, root.images.len());
+    push(&mut keys, Accessor, root.accessors.len());
+    push(&mut keys, View, root.buffer_views.len());
+    push(&mut keys, Buffer, root.buffers.len());
+
+    let positions: BTreeMap<_, _> = keys
+        .iter()
+        .enumerate()
+        .map(|(position, &key)| (key, position))
+        .collect();
+    let mut edges = Vec::new();
+    for (from, &(kind, index)) in keys.iter().enumerate() {
+        for_each_reference(root, kind, index, |kind, index| {
+            if let Some(&to) = positions.get(&(kind, index)) {
+                edges.push((from, to));
+            }
+        });
+    }
+    let entities = keys
+        .into_iter()
+        .map(|(kind, index)| GraphEntity {
+            kind,
+            index,
+            name: name(root, kind, index),
+        })
+        .collect();
+    Graph {
+        entities,
+        positions,
+        edges,
+    }
+}
@@ -0,0 +1,35 @@
+//! Analyses report optimisation opportunities or problems in an asset
+//! without modifying the document; see [`transform`](crate::transform) for
+//! the functions that act on them.
+
+#[cfg(feature = "animation")]
+mod dangling;
+mod downgrade;
+mod graph;
+mod render;
+mod retention;
+mod stats;
+mod textures;
+
+#[cfg(feature = "animation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
+#[doc(inline)]
+pub use self::dangling::dangling_animation_targets;
+#[doc(inline)]
+pub use self::downgrade::{
+    texture_downgrade_plan, DowngradeEntry, DowngradeOptions, DowngradePlan,
+};
+pub(crate) use self::graph::for_each_reference;
+#[doc(inline)]
+pub use self::graph::{dependency_graph, Graph, GraphEntity};
+pub(crate) use self::render::render_buckets;
+#[doc(inline)]
+pub use self::render::{RenderBuckets, RenderItem};
+pub(crate) use self::retention::cpu_required_ranges;
+#[doc(inline)]
+pub use self::retention::RetentionPolicy;
+pub(crate) use self::stats::scan_for_nans;
+#[doc(inline)]
+pub use self::stats::{accessor_stats, AccessorStats, NAN_HITS_PER_ACCESSOR};
+#[doc(inline)]
+pub use self::textures::{find_packable_textures, PackableTextures};
@@ -0,0 +1,256 @@
+use alloc::vec::Vec;
+
+use crate::material::{AlphaMode, MaterialOverrides};
+use crate::math::Matrix4;
+use crate::mesh::Semantic;
+use crate::{Document, Material, Node, Primitive};
+
+/// A primitive instance to be drawn, as classified by
+/// [`Document::render_buckets`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderItem {
+    /// The index of the node instancing the mesh.
+    pub node: usize,
+
+    /// The index of the mesh.
+    pub mesh: usize,
+
+    /// The index of the primitive within the mesh.
+    pub primitive: usize,
+
+    /// The index of the primitive's material, or `None` for the default
+    /// material or a synthetic one.
+    pub material: Option<usize>,
+
+    /// The index of the synthetic material overriding the primitive's
+    /// material, if any.
+    pub synthetic_material: Option<usize>,
+
+    /// The world transform of the node in column-major order.
+    pub transform: [[f32; 4]; 4],
+
+    /// The world space center of the primitive's bounding box, or the node's
+    /// origin if the bounds are unavailable.
+    pub center: [f32; 3],
+
+    /// Whether back-face culling must be disabled.
+    pub double_sided: bool,
+
+    /// Whether the material transmits light through its surface.
+    ///
+    /// Always `false` unless the `KHR_materials_transmission` feature is
+    /// enabled.
+    pub transmission: bool,
+
+    /// Whether the material is unlit.
+    ///
+    /// Always `false` unless the `KHR_materials_unlit` feature is enabled.
+    pub unlit: bool,
+}
+
+/// Primitive instances of a scene grouped by how they must be drawn.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RenderBuckets {
+    /// Primitives with an `OPAQUE` material.
+    pub opaque: Vec<RenderItem>,
+
+    /// Primitives with a `MASK` material, which require alpha testing.
+    pub mask: Vec<RenderItem>,
+
+    /// Primitives with a `BLEND` or light transmitting material, which must be
+    /// drawn after the others in back-to-front order.
+    pub blend: Vec<RenderItem>,
+}
+
+impl RenderBuckets {
+    /// Sorts the blended primitives back-to-front from the given eye position.
+    pub fn sort_blend(&mut self, eye: [f32; 3]) {
+        let distance = |item: &RenderItem| {
+            (0..3)
+                .map(|i| (item.center[i] - eye[i]) * (item.center[i] - eye[i]))
+                .sum::<f32>()
+        };
+        self.blend.sort_by(|a, b| {
+            distance(b)
+                .partial_cmp(&distance(a))
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+    }
+}
+
+#[cfg(feature = "KHR_materials_transmission")]
+fn transmission(material: &Material) -> bool {
+    material.transmission().map_or(false, |transmission| {
+        transmission.transmission_factor() > 0.0 || transmission.transmission_texture().is_some()
+    })
+}
+
+#[cfg(not(feature = "KHR_materials_transmission"))]
+fn transmission(_: &Material) -> bool {
+    false
+}
+
+#[cfg(feature = "KHR_materials_unlit")]
+fn unlit(material: &Material) -> bool {
+    material.unlit()
+}
+
+#[cfg(not(feature = "KHR_materials_unlit"))]
+fn unlit(_: &Material) -> bool {
+    false
+}
+
+/// Returns the center of the bounds of the `POSITION` attribute, if declared.
+fn local_center(primitive: &Primitive) -> Option<[f32; 3]> {
+    let accessor = primitive.get(&Semantic::Positions)?;
+    let min: [f32; 3] = crate::accessor::bound_f32(accessor.min_f64())?;
+    let max: [f32; 3] = crate::accessor::bound_f32(accessor.max_f64())?;
+    Some([
+        (min[0] + max[0]) * 0.5,
+        (min[1] + max[1]) * 0.5,
+        (min[2] + max[2]) * 0.5,
+    ])
+}
+
+fn classify(
+    buckets: &mut RenderBuckets,
+    overrides: &MaterialOverrides,
+    node: &Node,
+    world: &Matrix4,
+) {
+    let mesh = match node.mesh() {
+        Some(mesh) => mesh,
+        None => return,
+    };
+    for primitive in mesh.primitives() {
+        let material = primitive.material_with(overrides, node.index());
+        let local = local_center(&primitive).unwrap_or([0.0; 3]);
+        let item = RenderItem {
+            node: node.index(),
+            mesh: mesh.index(),
+            primitive: primitive.index(),
+            material: material.index(),
+            synthetic_material: material.synthetic_index(),
+            transform: world.as_array(),
+            center: world.transform_point(local),
+            double_sided: material.double_sided(),
+            transmission: transmission(&material),
+            unlit: unlit(&material),
+        };
+        let bucket = match material.alpha_mode() {
+            _ if item.transmission => &mut buckets.blend,
+            AlphaMode::Opaque => &mut buckets.opaque,
+            AlphaMode::Mask => &mut buckets.mask,
+            AlphaMode::Blend => &mut buckets.blend,
+        };
+        bucket.push(item);
+    }
+}
+
+pub(crate) fn render_buckets(document: &Document, overrides: &MaterialOverrides) -> RenderBuckets {
+    let mut buckets = RenderBuckets::default();
+    let scene = match document
+        .default_scene()
+        .or_else(|| document.scenes().next())
+    {
+        Some(scene) => scene,
+        None => return buckets,
+    };
+    for (node, world) in scene.traverse().map_while(Result::ok) {
+        classify(&mut buckets, overrides, &node, &Matrix4::from_array(world));
+    }
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GLTF: &str = r#"{
+        "asset": {"version": "2.0"},
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+             "min": [0, 0, 0], "max": [2, 2, 2]}
+        ],
+        "bufferViews": [{"buffer": 0, "byteLength": 36}],
+        "buffers": [{"byteLength": 36}],
+        "materials": [
+            {"alphaMode": "MASK", "doubleSided": true},
+            {"alphaMode": "BLEND"}
+        ],
+        "meshes": [
+            {"primitives": [
+                {"attributes": {"POSITION": 0}},
+                {"attributes": {"POSITION": 0}, "material": 0},
+                {"attributes": {"POSITION": 0}, "material": 1}
+            ]}
+        ],
+        "nodes": [
+            {"translation": [10, 0, 0], "children": [1]},
+            {"translation": [0, 5, 0], "mesh": 0},
+            {"translation": [0, 0, -20], "mesh": 0}
+        ],
+        "scenes": [{"nodes": [0, 2]}]
+    }"#;
+
+    #[test]
+    fn buckets() {
+        let document = crate::Gltf::from_slice(GLTF.as_bytes()).unwrap().document;
+        let mut buckets = document.render_buckets();
+        assert_eq!(buckets.opaque.len(), 2);
+        assert_eq!(buckets.mask.len(), 2);
+        assert_eq!(buckets.blend.len(), 2);
+
+        let opaque = &buckets.opaque[0];
+        assert_eq!(
+            (opaque.node, opaque.primitive, opaque.material),
+            (1, 0, None)
+        );
+        assert_eq!(opaque.center, [11.0, 6.0, 1.0]);
+        assert_eq!(opaque.transform[3], [10.0, 5.0, 0.0, 1.0]);
+        assert!(buckets.mask.iter().all(|item| item.double_sided));
+        assert!(!opaque.double_sided);
+
+        buckets.sort_blend([0.0, 0.0, 10.0]);
+        let nodes: Vec<_> = buckets.blend.iter().map(|item| item.node).collect();
+        assert_eq!(nodes, [2, 1]);
+    }
+
+    #[test]
+    fn overridden_buckets() {
+        use crate::material::SyntheticMaterial;
+
+        let document = crate::Gltf::from_slice(GLTF.as_bytes()).unwrap().document;
+        let mut overrides = MaterialOverrides::new(&document);
+        let highlight = overrides.add(
+            SyntheticMaterial::new()
+                .alpha_mode(AlphaMode::Blend)
+                .emissive_factor([1.0, 0.5, 0.0]),
+        );
+        overrides.set(1, 0, highlight);
+        overrides.set(2, 2, 0);
+        let buckets = document.render_buckets_with(&overrides);
+        assert_eq!(buckets.opaque.len(), 1);
+        assert_eq!(buckets.mask.len(), 3);
+        assert_eq!(buckets.blend.len(), 2);
+
+        let item = &buckets.blend[0];
+        assert_eq!((item.node, item.primitive), (1, 0));
+        assert_eq!((item.material, item.synthetic_material), (None, Some(0)));
+        let node = document.nodes().nth(2).unwrap();
+        let primitive = node.mesh().unwrap().primitives().nth(2).unwrap();
+        assert_eq!(primitive.material_with(&overrides, 2).index(), Some(0));
+        assert_eq!(primitive.material_with(&overrides, 1).index(), Some(1));
+
+        overrides.remove(1, 0);
+        assert_eq!(document.render_buckets_with(&overrides).opaque.len(), 2);
+        assert_eq!(document.render_buckets().mask.len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn missing_override() {
+        let document = crate::Gltf::from_slice(GLTF.as_bytes()).unwrap().document;
+        MaterialOverrides::new(&document).set(1, 0, 2);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::accessor::{extent, Accessor};
+use crate::buffer::{RangeSet, View};
+use crate::Document;
+
+/// Selects the accessor data that [`Document::cpu_required_ranges`] keeps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    /// Keeps the inverse bind matrices of skins, for skinning on the CPU.
+    ///
+    /// The default is `true`.
+    pub inverse_bind_matrices: bool,
+
+    /// Keeps the keyframe times and values of animation samplers.
+    ///
+    /// The default is `true`.
+    pub animations: bool,
+
+    /// Keeps the position, normal and tangent displacements of morph targets,
+    /// for applying morph targets on the CPU.
+    ///
+    /// The default is `false`.
+    pub morph_targets: bool,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            inverse_bind_matrices: true,
+            animations: true,
+            morph_targets: false,
+        }
+    }
+}
+
+fn insert(ranges: &mut RangeSet, view: &View, offset: usize, length: usize) {
+    let start = view.offset().saturating_add(offset);
+    ranges.insert(view.buffer().index(), start..start.saturating_add(length));
+}
+
+/// Adds the byte ranges read by an accessor, including its sparse storage.
+fn insert_accessor(ranges: &mut RangeSet, accessor: &Accessor) {
+    let size = accessor.size();
+    if let Some(view) = accessor.view() {
+        let stride = view.stride().unwrap_or(size);
+        let length = extent(accessor.count(), stride, size);
+        insert(ranges, &view, accessor.offset(), length);
+    }
+    if let Some(sparse) = accessor.sparse() {
+        let indices = sparse.indices();
+        let length = sparse.count() * indices.index_type().size();
+        insert(ranges, &indices.view(), indices.offset(), length);
+        let values = sparse.values();
+        insert(
+            ranges,
+            &values.view(),
+            values.offset(),
+            sparse.count() * size,
+        );
+    }
+}
+
+pub(crate) fn cpu_required_ranges(document: &Document, policy: RetentionPolicy) -> RangeSet {
+    let mut ranges = RangeSet::new();
+    #[cfg(feature = "skinning")]
+    if policy.inverse_bind_matrices {
+        for skin in document.skins() {
+            if let Some(accessor) = skin.inverse_bind_matrices() {
+                insert_accessor(&mut ranges, &accessor);
+            }
+        }
+    }
+    #[cfg(feature = "animation")]
+    if policy.animations {
+        for animation in document.animations() {
+            for sampler in animation.samplers() {
+                insert_accessor(&mut ranges, &sampler.input());
+                insert_accessor(&mut ranges, &sampler.output());
+            }
+        }
+    }
+    if policy.morph_targets {
+        for mesh in document.meshes() {
+            for primitive in mesh.primitives() {
+                for target in primitive.morph_targets() {
+                    let accessors = [target.positions(), target.normals(), target.tangents()];
+                    for accessor in accessors.iter().flatten() {
+                        insert_accessor(&mut ranges, accessor);
+                    }
+                }
+            }
+        }
+    }
+    ranges
+}
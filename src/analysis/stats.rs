@@ -0,0 +1,257 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::intrinsics::sqrtf64;
+
+use crate::accessor::{self, DataType, Elements};
+use crate::{buffer, Accessor, Document};
+
+/// The maximum number of NaN elements reported per accessor by
+/// [`Document::scan_for_nans`].
+pub const NAN_HITS_PER_ACCESSOR: usize = 4;
+
+/// Per-component statistics of the data of an accessor, as computed by
+/// [`accessor_stats`].
+///
+/// Values are the stored component values; normalized integers are not
+/// mapped to `0.0..=1.0`, so that they compare with the declared bounds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessorStats {
+    /// The number of elements read, including sparse substitutions.
+    pub count: usize,
+
+    /// The number of NaN components.
+    pub nan: usize,
+
+    /// The number of infinite components.
+    pub infinite: usize,
+
+    /// The number of components that are exactly zero.
+    pub zeros: usize,
+
+    /// The smallest finite value of each component, or `None` if there are
+    /// no finite values.
+    pub min: Option<Vec<f64>>,
+
+    /// The largest finite value of each component, or `None` if there are no
+    /// finite values.
+    pub max: Option<Vec<f64>>,
+
+    /// The mean of the finite values of each component.
+    pub mean: Vec<f64>,
+
+    /// The population standard deviation of the finite values of each
+    /// component.
+    pub std_dev: Vec<f64>,
+
+    /// The `min` declared by the accessor.
+    pub declared_min: Option<Vec<f64>>,
+
+    /// The `max` declared by the accessor.
+    pub declared_max: Option<Vec<f64>>,
+}
+
+impl AccessorStats {
+    /// Returns the fraction of components that are exactly zero, such as
+    /// unused joint weights.
+    pub fn zero_fraction(&self) -> f64 {
+        let components = self.count * self.mean.len();
+        match components {
+            0 => 0.0,
+            n => self.zeros as f64 / n as f64,
+        }
+    }
+
+    /// Returns `true` if the declared `min` and `max` equal the actual bounds
+    /// at single precision.
+    ///
+    /// Returns `false` if either bound is not declared.
+    pub fn bounds_match(&self) -> bool {
+        let matches =
+            |declared: &Option<Vec<f64>>, actual: &Option<Vec<f64>>| match (declared, actual) {
+                (Some(declared), Some(actual)) => {
+                    declared.len() == actual.len()
+                        && declared
+                            .iter()
+                            .zip(actual)
+                            .all(|(&a, &b)| a as f32 == b as f32)
+                }
+                _ => false,
+            };
+        matches(&self.declared_min, &self.min) && matches(&self.declared_max, &self.max)
+    }
+}
+
+/// Streams the elements of an accessor, including sparse substitutions, until
+/// `f` returns `false`.
+fn for_each_element<F>(
+    accessor: &Accessor,
+    buffers: &[buffer::Data],
+    mut f: F,
+) -> Result<(), accessor::Error>
+where
+    F: FnMut(usize, &[f64]) -> bool,
+{
+    let get_buffer_data = |buffer: buffer::Buffer| buffers.get(buffer.index()).map(|x| &x.0[..]);
+    let mut elements = Elements::new(accessor, &get_buffer_data)?;
+    let mut values = vec![0.0; elements.components()];
+    let mut element = 0;
+    while elements.next_values(false, &mut values) {
+        if !f(element, &values) {
+            break;
+        }
+        element += 1;
+    }
+    Ok(())
+}
+
+/// Computes statistics of the data of an accessor in a single pass.
+///
+/// Memory use is proportional to the number of components per element, not
+/// the number of elements. Fails with [`accessor::Error::SparseIndex`] unless
+/// the sparse indices are strictly increasing, as the specification requires.
+pub fn accessor_stats(
+    accessor: &Accessor,
+    buffers: &[buffer::Data],
+) -> Result<AccessorStats, accessor::Error> {
+    let components = accessor.dimensions().multiplicity();
+    let mut stats = AccessorStats {
+        count: 0,
+        nan: 0,
+        infinite: 0,
+        zeros: 0,
+        min: None,
+        max: None,
+        mean: vec![0.0; components],
+        std_dev: vec![0.0; components],
+        declared_min: accessor.min_f64(),
+        declared_max: accessor.max_f64(),
+    };
+    let mut min = vec![f64::INFINITY; components];
+    let mut max = vec![f64::NEG_INFINITY; components];
+    // Welford's algorithm, with the sum of squared deviations in `std_dev`.
+    let mut finite = vec![0usize; components];
+    for_each_element(accessor, buffers, |_, values| {
+        stats.count += 1;
+        for (component, &value) in values.iter().enumerate() {
+            if value.is_nan() {
+                stats.nan += 1;
+                continue;
+            }
+            if value.is_infinite() {
+                stats.infinite += 1;
+                continue;
+            }
+            if value == 0.0 {
+                stats.zeros += 1;
+            }
+            min[component] = min[component].min(value);
+            max[component] = max[component].max(value);
+            finite[component] += 1;
+            let delta = value - stats.mean[component];
+            stats.mean[component] += delta / finite[component] as f64;
+            stats.std_dev[component] += delta * (value - stats.mean[component]);
+        }
+        true
+    })?;
+    for (component, &n) in finite.iter().enumerate() {
+        stats.std_dev[component] = match n {
+            0 => 0.0,
+            #[allow(unused_unsafe)]
+            n => unsafe { sqrtf64(stats.std_dev[component] / n as f64) },
+        };
+    }
+    if finite.iter().all(|&n| n > 0) {
+        stats.min = Some(min);
+        stats.max = Some(max);
+    }
+    Ok(stats)
+}
+
+pub(crate) fn scan_for_nans(document: &Document, buffers: &[buffer::Data]) -> Vec<(usize, usize)> {
+    let mut hits = Vec::new();
+    for accessor in document.accessors() {
+        if accessor.data_type() != DataType::F32 {
+            continue;
+        }
+        let mut found = 0;
+        // Accessors whose data is missing have no elements to report.
+        let _ = for_each_element(&accessor, buffers, |element, values| {
+            if values.iter().any(|value| value.is_nan()) {
+                hits.push((accessor.index(), element));
+                found += 1;
+            }
+            found < NAN_HITS_PER_ACCESSOR
+        });
+    }
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use byteorder::{ByteOrder, LE};
+
+    const GLTF: &str = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 44}],
+        "bufferViews": [
+            {"buffer": 0, "byteLength": 24},
+            {"buffer": 0, "byteOffset": 24, "byteLength": 8},
+            {"buffer": 0, "byteOffset": 32, "byteLength": 4},
+            {"buffer": 0, "byteOffset": 36, "byteLength": 8}
+        ],
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC2",
+             "min": [1, 2], "max": [5, 6]},
+            {"bufferView": 1, "componentType": 5121, "count": 2, "type": "VEC4",
+             "normalized": true},
+            {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC2",
+             "sparse": {"count": 1,
+                        "indices": {"bufferView": 2, "componentType": 5125},
+                        "values": {"bufferView": 3}}}
+        ]
+    }"#;
+
+    fn document() -> (Document, Vec<buffer::Data>) {
+        let mut data = vec![0; 44];
+        LE::write_f32_into(&[1.0, 2.0, f32::NAN, 4.0, 5.0, 6.0], &mut data[..24]);
+        data[24..32].copy_from_slice(&[255, 0, 0, 0, 128, 127, 0, 0]);
+        LE::write_u32(&mut data[32..36], 2);
+        LE::write_f32_into(&[f32::NAN, f32::INFINITY], &mut data[36..44]);
+        let document = crate::Gltf::from_slice(GLTF.as_bytes()).unwrap().document;
+        (document, vec![buffer::Data(data)])
+    }
+
+    #[test]
+    fn float_stats() {
+        let (document, buffers) = document();
+        let stats = accessor_stats(&document.accessors().next().unwrap(), &buffers).unwrap();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.nan, 1);
+        assert_eq!(stats.min, Some(vec![1.0, 2.0]));
+        assert_eq!(stats.max, Some(vec![5.0, 6.0]));
+        assert_eq!(stats.mean, [3.0, 4.0]);
+        assert_relative_eq!(stats.std_dev[1], 1.632_993_161_855_452);
+        assert_eq!(stats.std_dev[0], 2.0);
+        assert!(stats.bounds_match());
+    }
+
+    #[test]
+    fn zero_weights() {
+        let (document, buffers) = document();
+        let stats = accessor_stats(&document.accessors().nth(1).unwrap(), &buffers).unwrap();
+        assert_eq!(stats.zeros, 5);
+        assert_eq!(stats.zero_fraction(), 5.0 / 8.0);
+        assert_eq!(stats.max, Some(vec![255.0, 127.0, 0.0, 0.0]));
+        assert!(!stats.bounds_match());
+    }
+
+    #[test]
+    fn sparse_nans() {
+        let (document, buffers) = document();
+        let stats = accessor_stats(&document.accessors().nth(2).unwrap(), &buffers).unwrap();
+        assert_eq!((stats.nan, stats.infinite), (2, 1));
+        assert_eq!(stats.min, Some(vec![1.0, 2.0]));
+        assert_eq!(document.scan_for_nans(&buffers), [(0, 1), (2, 1), (2, 2)]);
+    }
+}
@@ -0,0 +1,117 @@
+use alloc::vec::Vec;
+
+use crate::{buffer, image, Document};
+
+/// A material whose occlusion and metallic-roughness textures sample two
+/// different images that could be packed into a single image.
+///
+/// glTF reads occlusion from the red channel and metallic-roughness from the
+/// blue and green channels, so both maps fit in one image.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PackableTextures {
+    /// The index of the material.
+    pub material: usize,
+
+    /// The index of the occlusion texture.
+    pub occlusion: usize,
+
+    /// The index of the metallic-roughness texture.
+    pub metallic_roughness: usize,
+
+    /// The dimensions shared by both images.
+    pub metadata: image::Metadata,
+}
+
+/// Finds the materials whose occlusion and metallic-roughness textures
+/// reference different images that could be merged.
+///
+/// A pair is reported when both images are stored in buffer views and have
+/// the same dimensions, the textures use equivalent samplers, and both texture
+/// references use the same texture co-ordinate set and texture transform.
+pub fn find_packable_textures(
+    document: &Document,
+    buffers: &[buffer::Data],
+) -> Vec<PackableTextures> {
+    let root = document.as_json();
+    let mut packable = Vec::new();
+    for (index, material) in root.materials.iter().enumerate() {
+        let occlusion = match material.occlusion_texture.as_ref() {
+            Some(info) => info,
+            None => continue,
+        };
+        let metallic_roughness = match material
+            .pbr_metallic_roughness
+            .metallic_roughness_texture
+            .as_ref()
+        {
+            Some(info) => info,
+            None => continue,
+        };
+        let (a, b) = match (
+            root.textures.get(occlusion.index.value()),
+            root.textures.get(metallic_roughness.index.value()),
+        ) {
+            (Some(a), Some(b)) => (a, b),
+            _ => continue,
+        };
+        if a.source == b.source
+            || occlusion.tex_coord != metallic_roughness.tex_coord
+            || texture_transform(occlusion.extensions.as_ref().map(serde_json::to_value))
+                != texture_transform(
+                    metallic_roughness
+                        .extensions
+                        .as_ref()
+                        .map(serde_json::to_value),
+                )
+            || !samplers_match(root, a.sampler, b.sampler)
+        {
+            continue;
+        }
+        let metadata = |source: json::Index<json::Image>| {
+            document
+                .images()
+                .nth(source.value())
+                .and_then(|image| image.metadata(buffers))
+        };
+        match (metadata(a.source), metadata(b.source)) {
+            (Some(x), Some(y)) if x == y => packable.push(PackableTextures {
+                material: index,
+                occlusion: occlusion.index.value(),
+                metallic_roughness: metallic_roughness.index.value(),
+                metadata: x,
+            }),
+            _ => {}
+        }
+    }
+    packable
+}
+
+/// Returns the `KHR_texture_transform` object of serialized texture reference
+/// extensions, whether or not the extension is enabled as a crate feature.
+fn texture_transform(extensions: Option<serde_json::Result<json::Value>>) -> Option<json::Value> {
+    extensions?.ok()?.get("KHR_texture_transform").cloned()
+}
+
+fn samplers_match(
+    root: &json::Root,
+    a: Option<json::Index<json::texture::Sampler>>,
+    b: Option<json::Index<json::texture::Sampler>>,
+) -> bool {
+    if a == b {
+        return true;
+    }
+    let default = json::texture::Sampler::default();
+    let resolve = |index: Option<json::Index<json::texture::Sampler>>| match index {
+        Some(index) => root.samplers.get(index.value()),
+        None => Some(&default),
+    };
+    match (resolve(a), resolve(b)) {
+        (Some(a), Some(b)) => {
+            a.mag_filter == b.mag_filter
+                && a.min_filter == b.min_filter
+                && a.wrap_s == b.wrap_s
+                && a.wrap_t == b.wrap_t
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,325 @@
+use alloc::vec::Vec;
+use core::ops;
+
+use super::{Animation, Property};
+use crate::Document;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A set of node properties, stored as one bit per [`Property`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct TargetMask(pub u8);
+
+impl TargetMask {
+    /// The empty set.
+    pub const EMPTY: Self = Self(0);
+
+    /// The `translation` bit.
+    pub const TRANSLATION: Self = Self(1 << 0);
+
+    /// The `rotation` bit.
+    pub const ROTATION: Self = Self(1 << 1);
+
+    /// The `scale` bit.
+    pub const SCALE: Self = Self(1 << 2);
+
+    /// The morph target `weights` bit.
+    pub const WEIGHTS: Self = Self(1 << 3);
+
+    /// Returns the bit of a property.
+    pub fn of(property: Property) -> Self {
+        match property {
+            Property::Translation => Self::TRANSLATION,
+            Property::Rotation => Self::ROTATION,
+            Property::Scale => Self::SCALE,
+            Property::MorphTargetWeights => Self::WEIGHTS,
+        }
+    }
+
+    /// Returns `true` if the property is in the set.
+    pub fn contains(self, property: Property) -> bool {
+        self.0 & Self::of(property).0 != 0
+    }
+
+    /// Returns `true` if no property is in the set.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl ops::BitOr for TargetMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl ops::BitOrAssign for TargetMask {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// An animation channel that targets a node.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ChannelBinding {
+    /// The index of the channel within its animation.
+    pub channel: usize,
+
+    /// The property of the node that the channel animates.
+    #[cfg_attr(feature = "serde", serde(with = "property"))]
+    pub property: Property,
+}
+
+/// The channels of one animation that target one node.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct NodeBinding {
+    /// The index of the node.
+    pub node: usize,
+
+    /// The channels that target the node, in channel order.
+    pub channels: Vec<ChannelBinding>,
+
+    /// The properties animated by at least one channel.
+    pub targets_mask: TargetMask,
+
+    /// The properties animated by more than one channel, which the
+    /// specification disallows within one animation.
+    ///
+    /// Players typically let the last such channel win.
+    pub conflicts: TargetMask,
+}
+
+impl NodeBinding {
+    /// Returns the last channel that animates a property, if any.
+    pub fn channel(&self, property: Property) -> Option<usize> {
+        self.channels
+            .iter()
+            .rev()
+            .find(|binding| binding.property == property)
+            .map(|binding| binding.channel)
+    }
+}
+
+/// The nodes targeted by an animation, as computed by
+/// [`Animation::binding_table`].
+///
+/// The table holds only indices and bit masks, so it may be computed offline
+/// and stored alongside the asset; it is serializable with the `serde`
+/// feature.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct BindingTable {
+    /// The targeted nodes, sorted by node index.
+    pub nodes: Vec<NodeBinding>,
+}
+
+impl BindingTable {
+    /// Returns the binding of a node, or `None` if the animation does not
+    /// target it.
+    pub fn get(&self, node: usize) -> Option<&NodeBinding> {
+        let position = self.nodes.binary_search_by_key(&node, |x| x.node).ok()?;
+        Some(&self.nodes[position])
+    }
+
+    /// Returns the properties of a node that the animation targets.
+    pub fn targets_mask(&self, node: usize) -> TargetMask {
+        self.get(node).map_or(TargetMask::EMPTY, |x| x.targets_mask)
+    }
+
+    /// Returns `true` if any node is targeted by conflicting channels.
+    pub fn has_conflicts(&self) -> bool {
+        self.nodes.iter().any(|x| !x.conflicts.is_empty())
+    }
+}
+
+/// The nodes targeted by every animation of a document, as computed by
+/// [`Document::animation_bindings`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct AnimationBindings {
+    /// The animation index and node binding of each targeted node, sorted by
+    /// animation and then node index.
+    pub entries: Vec<(usize, NodeBinding)>,
+}
+
+impl AnimationBindings {
+    /// Returns the binding of a node in an animation.
+    pub fn get(&self, animation: usize, node: usize) -> Option<&NodeBinding> {
+        let position = self
+            .entries
+            .binary_search_by_key(&(animation, node), |(animation, x)| (*animation, x.node))
+            .ok()?;
+        Some(&self.entries[position].1)
+    }
+
+    /// Returns an `Iterator` over the animations that target a node, with the
+    /// node's binding in each.
+    pub fn node(&self, node: usize) -> impl Iterator<Item = (usize, &NodeBinding)> + '_ {
+        self.entries
+            .iter()
+            .filter(move |(_, x)| x.node == node)
+            .map(|(animation, x)| (*animation, x))
+    }
+
+    /// Returns the properties of a node that any animation targets.
+    pub fn targets_mask(&self, node: usize) -> TargetMask {
+        self.node(node)
+            .fold(TargetMask::EMPTY, |mask, (_, x)| mask | x.targets_mask)
+    }
+}
+
+impl<'a> Animation<'a> {
+    /// Computes the nodes targeted by this animation and, for each, the
+    /// channels that target it.
+    pub fn binding_table(&self) -> BindingTable {
+        let mut nodes: Vec<NodeBinding> = Vec::new();
+        for (channel, json) in self.json.channels.iter().enumerate() {
+            let property = match json.target.path {
+                json::validation::Checked::Valid(property) => property,
+                json::validation::Checked::Invalid => continue,
+            };
+            let node = json.target.node.value();
+            let position = match nodes.binary_search_by_key(&node, |x| x.node) {
+                Ok(position) => position,
+                Err(position) => {
+                    nodes.insert(
+                        position,
+                        NodeBinding {
+                            node,
+                            channels: Vec::new(),
+                            targets_mask: TargetMask::EMPTY,
+                            conflicts: TargetMask::EMPTY,
+                        },
+                    );
+                    position
+                }
+            };
+            let binding = &mut nodes[position];
+            let bit = TargetMask::of(property);
+            if binding.targets_mask.contains(property) {
+                binding.conflicts |= bit;
+            }
+            binding.targets_mask |= bit;
+            binding.channels.push(ChannelBinding { channel, property });
+        }
+        BindingTable { nodes }
+    }
+}
+
+pub(crate) fn animation_bindings(document: &Document) -> AnimationBindings {
+    let mut entries = Vec::new();
+    for animation in document.animations() {
+        let index = animation.index();
+        entries.extend(
+            animation
+                .binding_table()
+                .nodes
+                .into_iter()
+                .map(|x| (index, x)),
+        );
+    }
+    AnimationBindings { entries }
+}
+
+/// Serializes properties with the names used by glTF.
+#[cfg(feature = "serde")]
+mod property {
+    use super::Property;
+    use json::validation::Checked;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(property: &Property, serializer: S) -> Result<S::Ok, S::Error> {
+        property.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Property, D::Error> {
+        match Checked::<Property>::deserialize(deserializer)? {
+            Checked::Valid(property) => Ok(property),
+            Checked::Invalid => Err(de::Error::custom("invalid animation target property")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GLTF: &str = r#"{
+        "asset": {"version": "2.0"},
+        "nodes": [{}, {}, {}],
+        "buffers": [{"byteLength": 16}],
+        "bufferViews": [{"buffer": 0, "byteLength": 16}],
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 1, "type": "SCALAR",
+             "min": [0], "max": [0]},
+            {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3"}
+        ],
+        "animations": [
+            {
+                "samplers": [{"input": 0, "output": 1}],
+                "channels": [
+                    {"sampler": 0, "target": {"node": 2, "path": "scale"}},
+                    {"sampler": 0, "target": {"node": 0, "path": "translation"}},
+                    {"sampler": 0, "target": {"node": 2, "path": "rotation"}},
+                    {"sampler": 0, "target": {"node": 0, "path": "translation"}}
+                ]
+            },
+            {
+                "samplers": [{"input": 0, "output": 1}],
+                "channels": [
+                    {"sampler": 0, "target": {"node": 2, "path": "weights"}}
+                ]
+            }
+        ]
+    }"#;
+
+    fn document() -> Document {
+        crate::Gltf::from_slice(GLTF.as_bytes()).unwrap().document
+    }
+
+    #[test]
+    fn binding_table() {
+        let document = document();
+        let table = document.animations().next().unwrap().binding_table();
+        assert_eq!(table.nodes.len(), 2);
+        assert!(table.get(1).is_none());
+
+        let node = table.get(0).unwrap();
+        assert_eq!(node.targets_mask, TargetMask::TRANSLATION);
+        assert_eq!(node.conflicts, TargetMask::TRANSLATION);
+        assert_eq!(node.channel(Property::Translation), Some(3));
+
+        let node = table.get(2).unwrap();
+        assert_eq!(node.targets_mask, TargetMask::ROTATION | TargetMask::SCALE);
+        assert!(node.conflicts.is_empty());
+        assert_eq!(node.channel(Property::Scale), Some(0));
+        assert_eq!(node.channel(Property::Translation), None);
+        assert!(table.has_conflicts());
+    }
+
+    #[test]
+    fn document_bindings() {
+        let bindings = document().animation_bindings();
+        assert_eq!(bindings.entries.len(), 3);
+        assert_eq!(
+            bindings
+                .get(1, 2)
+                .unwrap()
+                .channel(Property::MorphTargetWeights),
+            Some(0)
+        );
+        assert!(bindings.get(1, 0).is_none());
+        assert_eq!(bindings.node(2).count(), 2);
+        let mask = bindings.targets_mask(2);
+        assert!(mask.contains(Property::MorphTargetWeights) && mask.contains(Property::Scale));
+        assert!(!mask.contains(Property::Translation));
+        assert!(bindings.targets_mask(1).is_empty());
+    }
+}
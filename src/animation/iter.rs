@@ -68,3 +68,7 @@ impl<'a> Iterator for Samplers<'a> {
             .map(|json| Sampler::new(self.anim.clone(), json))
     }
 }
+
+impl<'a> ExactSizeIterator for Channels<'a> {}
+
+impl<'a> ExactSizeIterator for Samplers<'a> {}
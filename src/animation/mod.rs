@@ -11,6 +11,10 @@ use serde_json::{Map, Value};
 /// Iterators.
 pub mod iter;
 
+mod binding;
+mod morph;
+mod sample;
+
 /// Utility functions.
 #[cfg(feature = "utils")]
 #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
@@ -20,6 +24,13 @@ pub mod util;
 #[doc(inline)]
 pub use self::util::Reader;
 
+pub(crate) use self::binding::animation_bindings;
+pub use self::binding::{AnimationBindings, BindingTable, ChannelBinding, NodeBinding, TargetMask};
+pub(crate) use self::morph::read_floats;
+pub use self::morph::{apply_morph_weights, effective_morph_weights, AnimError};
+pub(crate) use self::sample::sample;
+pub use self::sample::Output;
+
 /// A keyframe animation.
 #[derive(Clone, Debug)]
 pub struct Animation<'a> {
@@ -100,7 +111,7 @@ impl<'a> Animation<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
     }
 
     /// Returns an `Iterator` over the animation samplers.
@@ -163,6 +174,28 @@ impl<'a> Channel<'a> {
         Reader {
             channel: self.clone(),
             get_buffer_data,
+            cache: None,
+        }
+    }
+
+    /// Constructs an animation channel reader that reads the layouts of the
+    /// sampler accessors from a cache, resolving them on first use.
+    ///
+    /// See [`AccessorLayoutCache`](crate::accessor::AccessorLayoutCache).
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn reader_cached<'s, F>(
+        &self,
+        cache: &'s crate::accessor::AccessorLayoutCache,
+        get_buffer_data: F,
+    ) -> Reader<'a, 's, F>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Reader {
+            channel: self.clone(),
+            get_buffer_data,
+            cache: Some(cache),
         }
     }
 
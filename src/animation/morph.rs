@@ -0,0 +1,228 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::accessor::{self, Elements};
+use crate::animation::{sample, Interpolation, Property};
+use crate::{buffer, Accessor, Document, Primitive};
+
+/// Error returned when evaluating or applying morph target weights.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnimError {
+    /// The node does not exist.
+    MissingNode(usize),
+
+    /// The animation does not exist.
+    MissingAnimation(usize),
+
+    /// A slice of weights is shorter than the number of morph targets.
+    TargetCount {
+        /// The number of morph targets.
+        expected: usize,
+        /// The length of the slice.
+        actual: usize,
+    },
+
+    /// The keyframes of a channel do not match the morph targets, or its
+    /// input and output accessors disagree on the number of keyframes.
+    Keyframes {
+        /// The index of the channel within its animation.
+        channel: usize,
+    },
+
+    /// The primitive has no `POSITION` attribute.
+    MissingPositions,
+
+    /// The displacements of a morph target have a different number of
+    /// elements than `POSITION`.
+    PositionCount {
+        /// The index of the morph target.
+        target: usize,
+        /// The number of displacements.
+        count: usize,
+        /// The number of positions.
+        expected: usize,
+    },
+
+    /// The data of an accessor is unavailable.
+    Accessor(accessor::Error),
+}
+
+impl fmt::Display for AnimError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnimError::MissingNode(node) => write!(f, "node {} does not exist", node),
+            AnimError::MissingAnimation(animation) => {
+                write!(f, "animation {} does not exist", animation)
+            }
+            AnimError::TargetCount { expected, actual } => {
+                write!(f, "{} weights given for {} morph targets", actual, expected)
+            }
+            AnimError::Keyframes { channel } => {
+                write!(f, "channel {} has mismatched keyframes", channel)
+            }
+            AnimError::MissingPositions => write!(f, "missing POSITION attribute"),
+            AnimError::PositionCount {
+                target,
+                count,
+                expected,
+            } => write!(
+                f,
+                "morph target {} has {} displacements but there are {} positions",
+                target, count, expected
+            ),
+            AnimError::Accessor(error) => write!(f, "accessor: {}", error),
+        }
+    }
+}
+
+impl core::error::Error for AnimError {}
+
+impl From<accessor::Error> for AnimError {
+    fn from(error: accessor::Error) -> Self {
+        AnimError::Accessor(error)
+    }
+}
+
+/// Reads the components of every element of an accessor as floats.
+pub(crate) fn read_floats(
+    accessor: &Accessor,
+    buffers: &[buffer::Data],
+) -> Result<Vec<f32>, accessor::Error> {
+    let get_buffer_data = |buffer: buffer::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+    let mut elements = Elements::new(accessor, &get_buffer_data)?;
+    let mut values = vec![0.0; elements.components()];
+    let mut floats = Vec::with_capacity(accessor.count() * values.len());
+    while elements.next_values(true, &mut values) {
+        floats.extend(values.iter().map(|&x| x as f32));
+    }
+    Ok(floats)
+}
+
+/// Computes the morph target weights of a node, optionally at a time of an
+/// animation, and returns the number of morph targets.
+///
+/// The weights are taken from the first available of, in order of
+/// precedence: the last channel of the animation that targets the weights of
+/// the node, sampled at the time; the weights of the node; the default
+/// weights of its mesh; and zeros. The number of morph targets is that of
+/// the first primitive of the mesh, or zero for a node without a mesh.
+///
+/// `out` must hold at least one weight per morph target; only that many are
+/// written. Buffer data is only read for the keyframes of the channel.
+pub fn effective_morph_weights(
+    document: &Document,
+    node: usize,
+    animation: Option<(usize, f32)>,
+    buffers: &[buffer::Data],
+    out: &mut [f32],
+) -> Result<usize, AnimError> {
+    let node = document
+        .nodes()
+        .nth(node)
+        .ok_or(AnimError::MissingNode(node))?;
+    let mesh = match node.mesh() {
+        Some(mesh) => mesh,
+        None => return Ok(0),
+    };
+    let count = match mesh.primitives().next() {
+        Some(primitive) => primitive.morph_targets().len(),
+        None => mesh.weights().map_or(0, |weights| weights.len()),
+    };
+    if out.len() < count {
+        return Err(AnimError::TargetCount {
+            expected: count,
+            actual: out.len(),
+        });
+    }
+    let out = &mut out[..count];
+
+    let channel = match animation {
+        Some((index, time)) => {
+            let animation = document
+                .animations()
+                .nth(index)
+                .ok_or(AnimError::MissingAnimation(index))?;
+            animation
+                .binding_table()
+                .get(node.index())
+                .and_then(|binding| binding.channel(Property::MorphTargetWeights))
+                .map(|index| (index, animation.channels().nth(index).unwrap(), time))
+        }
+        None => None,
+    };
+    if let Some((index, channel, time)) = channel {
+        let sampler = channel.sampler();
+        let times = read_floats(&sampler.input(), buffers)?;
+        let values = read_floats(&sampler.output(), buffers)?;
+        let keyframe = match sampler.interpolation() {
+            Interpolation::CubicSpline => 3 * count,
+            _ => count,
+        };
+        if times.is_empty() || values.len() != times.len() * keyframe {
+            return Err(AnimError::Keyframes { channel: index });
+        }
+        sample(sampler.interpolation(), false, &times, &values, time, out);
+        return Ok(count);
+    }
+
+    out.iter_mut().for_each(|x| *x = 0.0);
+    if let Some(weights) = node.weights().or_else(|| mesh.weights()) {
+        out.iter_mut().zip(weights).for_each(|(x, &w)| *x = w);
+    }
+    Ok(count)
+}
+
+/// Computes the positions of a primitive displaced by its morph targets.
+///
+/// Each position is the sum of `POSITION` and the displacements of every
+/// morph target multiplied by its weight; see [`effective_morph_weights`].
+/// `weights` must hold at least one weight per morph target. Targets with
+/// zero weight or without position displacements are not read. Sparse
+/// displacements have their substitutions applied.
+pub fn apply_morph_weights(
+    primitive: &Primitive,
+    weights: &[f32],
+    buffers: &[buffer::Data],
+) -> Result<Vec<[f32; 3]>, AnimError> {
+    let targets = primitive.morph_targets();
+    if weights.len() < targets.len() {
+        return Err(AnimError::TargetCount {
+            expected: targets.len(),
+            actual: weights.len(),
+        });
+    }
+    let positions = primitive
+        .get(&crate::Semantic::Positions)
+        .ok_or(AnimError::MissingPositions)?;
+    let mut positions: Vec<[f32; 3]> = read_floats(&positions, buffers)?
+        .chunks_exact(3)
+        .map(|x| [x[0], x[1], x[2]])
+        .collect();
+
+    let get_buffer_data = |buffer: buffer::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+    for (target, (morph_target, &weight)) in targets.zip(weights).enumerate() {
+        let displacements = match morph_target.positions() {
+            Some(accessor) if weight != 0.0 => accessor,
+            _ => continue,
+        };
+        if displacements.count() != positions.len() {
+            return Err(AnimError::PositionCount {
+                target,
+                count: displacements.count(),
+                expected: positions.len(),
+            });
+        }
+        let mut elements = Elements::new(&displacements, &get_buffer_data)?;
+        let mut values = [0.0; 3];
+        for position in positions.iter_mut() {
+            if !elements.next_values(true, &mut values) {
+                break;
+            }
+            for (x, d) in position.iter_mut().zip(&values) {
+                *x += weight * *d as f32;
+            }
+        }
+    }
+    Ok(positions)
+}
@@ -0,0 +1,237 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::intrinsics::{sinf32, sqrtf32};
+
+use crate::animation::{read_floats, AnimError, Channel, Interpolation, Property};
+use crate::buffer;
+
+/// The value of an animated property at a time; see [`Channel::sample`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Output {
+    /// XYZ translation.
+    Translation([f32; 3]),
+
+    /// XYZW unit quaternion rotation.
+    Rotation([f32; 4]),
+
+    /// XYZ scale.
+    Scale([f32; 3]),
+
+    /// One weight per morph target.
+    MorphTargetWeights(Vec<f32>),
+}
+
+impl<'a> Channel<'a> {
+    /// Evaluates the target property of the channel at a time.
+    ///
+    /// Keyframes are interpolated as the specification defines: `STEP` holds
+    /// the value of the previous keyframe, `LINEAR` interpolates linearly,
+    /// or spherically for rotations, and `CUBICSPLINE` evaluates a Hermite
+    /// spline whose tangents are scaled by the time between keyframes, with
+    /// rotations normalized afterwards. Times before the first or after the
+    /// last keyframe are clamped to it, so a channel with one keyframe always
+    /// has its value. Normalized integer outputs are converted to floats.
+    ///
+    /// Returns [`AnimError::Keyframes`] if the channel has no keyframes or
+    /// the number of outputs does not match them.
+    pub fn sample(&self, time: f32, buffers: &[buffer::Data]) -> Result<Output, AnimError> {
+        let channel = self
+            .anim
+            .json
+            .channels
+            .iter()
+            .position(|json| core::ptr::eq(json, self.json))
+            .unwrap_or_default();
+        let sampler = self.sampler();
+        let interpolation = sampler.interpolation();
+        let times = read_floats(&sampler.input(), buffers)?;
+        let values = read_floats(&sampler.output(), buffers)?;
+        let property = self.target().property();
+        let per_keyframe = match interpolation {
+            Interpolation::CubicSpline => 3 * times.len(),
+            _ => times.len(),
+        };
+        let width = match property {
+            Property::Translation | Property::Scale => 3,
+            Property::Rotation => 4,
+            Property::MorphTargetWeights if per_keyframe > 0 => values.len() / per_keyframe,
+            Property::MorphTargetWeights => 0,
+        };
+        if width == 0 || values.len() != per_keyframe * width {
+            return Err(AnimError::Keyframes { channel });
+        }
+
+        let mut out = vec![0.0; width];
+        let rotation = property == Property::Rotation;
+        sample(interpolation, rotation, &times, &values, time, &mut out);
+        Ok(match property {
+            Property::Translation => Output::Translation([out[0], out[1], out[2]]),
+            Property::Rotation => Output::Rotation([out[0], out[1], out[2], out[3]]),
+            Property::Scale => Output::Scale([out[0], out[1], out[2]]),
+            Property::MorphTargetWeights => Output::MorphTargetWeights(out),
+        })
+    }
+}
+
+/// Samples keyframes at a time, writing one value per component of `out`.
+///
+/// `values` holds `out.len()` components per keyframe, or an in-tangent, a
+/// value and an out-tangent per keyframe for cubic splines. Times before the
+/// first or after the last keyframe are clamped to it. Linear `rotation`s
+/// are interpolated spherically and cubic ones are normalized.
+pub(crate) fn sample(
+    interpolation: Interpolation,
+    rotation: bool,
+    times: &[f32],
+    values: &[f32],
+    time: f32,
+    out: &mut [f32],
+) {
+    let n = out.len();
+    let (stride, offset) = match interpolation {
+        Interpolation::CubicSpline => (3 * n, n),
+        _ => (n, 0),
+    };
+    let value = |k: usize| &values[k * stride + offset..][..n];
+    let next = times.partition_point(|&t| t <= time);
+    if next == 0 || next == times.len() {
+        let k = if next == 0 { 0 } else { times.len() - 1 };
+        out.copy_from_slice(value(k));
+        return;
+    }
+    let (t0, t1) = (times[next - 1], times[next]);
+    let u = (time - t0) / (t1 - t0);
+    let (v0, v1) = (value(next - 1), value(next));
+    match interpolation {
+        Interpolation::Step => out.copy_from_slice(v0),
+        Interpolation::Linear if rotation => slerp(v0, v1, u, out),
+        Interpolation::Linear => {
+            for (x, (a, b)) in out.iter_mut().zip(v0.iter().zip(v1)) {
+                *x = a + (b - a) * u;
+            }
+        }
+        Interpolation::CubicSpline => {
+            let dt = t1 - t0;
+            let out_tangent = &values[(next - 1) * stride + 2 * n..][..n];
+            let in_tangent = &values[next * stride..][..n];
+            let (u2, u3) = (u * u, u * u * u);
+            for (i, x) in out.iter_mut().enumerate() {
+                *x = (2.0 * u3 - 3.0 * u2 + 1.0) * v0[i]
+                    + (u3 - 2.0 * u2 + u) * dt * out_tangent[i]
+                    + (-2.0 * u3 + 3.0 * u2) * v1[i]
+                    + (u3 - u2) * dt * in_tangent[i];
+            }
+            if rotation {
+                normalize(out);
+            }
+        }
+    }
+}
+
+/// Scales a quaternion to unit length, leaving a zero one untouched.
+fn normalize(q: &mut [f32]) {
+    let length = sqrtf32(q.iter().map(|x| x * x).sum());
+    if length > 0.0 {
+        q.iter_mut().for_each(|x| *x /= length);
+    }
+}
+
+/// Spherically interpolates between two quaternions along the shorter arc,
+/// interpolating linearly where they are too close for the angle between them
+/// to be accurate.
+fn slerp(q0: &[f32], q1: &[f32], u: f32, out: &mut [f32]) {
+    let (mut a, mut b) = ([0.0; 4], [0.0; 4]);
+    a.copy_from_slice(q0);
+    b.copy_from_slice(q1);
+    normalize(&mut a);
+    normalize(&mut b);
+    let mut cos = a.iter().zip(&b).map(|(x, y)| x * y).sum::<f32>();
+    if cos < 0.0 {
+        cos = -cos;
+        b.iter_mut().for_each(|x| *x = -*x);
+    }
+    let (s0, s1) = if 1.0 - cos > 1e-6 {
+        let angle = acos(cos.min(1.0));
+        let sin = sinf32(angle);
+        (sinf32((1.0 - u) * angle) / sin, sinf32(u * angle) / sin)
+    } else {
+        (1.0 - u, u)
+    };
+    for (x, (a, b)) in out.iter_mut().zip(a.iter().zip(&b)) {
+        *x = s0 * a + s1 * b;
+    }
+    normalize(out);
+}
+
+/// The arc cosine of `x` in `[0, 1]`, within 2e-8 radians.
+///
+/// This is Abramowitz and Stegun 4.4.46, as `core` has no arc cosine.
+fn acos(x: f32) -> f32 {
+    const A: [f32; 8] = [
+        core::f32::consts::FRAC_PI_2,
+        -0.214_598_8,
+        0.088_978_99,
+        -0.050_174_303,
+        0.030_891_88,
+        -0.017_088_126,
+        0.006_670_09,
+        -0.001_262_491_1,
+    ];
+    let polynomial = A.iter().rev().fold(0.0, |sum, a| sum * x + a);
+    sqrtf32(1.0 - x) * polynomial
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_keyframes() {
+        let times = [1.0, 2.0, 4.0];
+        let values = [0.0, 10.0, 2.0, 20.0, 4.0, 40.0];
+        let mut out = [0.0; 2];
+        sample(Interpolation::Linear, false, &times, &values, 3.0, &mut out);
+        assert_eq!(out, [3.0, 30.0]);
+        sample(Interpolation::Step, false, &times, &values, 3.0, &mut out);
+        assert_eq!(out, [2.0, 20.0]);
+        sample(Interpolation::Linear, false, &times, &values, 0.0, &mut out);
+        assert_eq!(out, [0.0, 10.0]);
+        sample(Interpolation::Linear, false, &times, &values, 9.0, &mut out);
+        assert_eq!(out, [4.0, 40.0]);
+
+        // A cubic spline through 0 and 1 with unit tangents is a line.
+        let values = [1.0, 0.0, 1.0, 1.0, 1.0, 1.0];
+        let mut out = [0.0];
+        sample(
+            Interpolation::CubicSpline,
+            false,
+            &[0.0, 1.0],
+            &values,
+            0.25,
+            &mut out,
+        );
+        assert_relative_eq!(out[0], 0.25);
+    }
+
+    #[test]
+    fn arc_cosine() {
+        for i in 0..=1000 {
+            let x = i as f32 / 1000.0;
+            assert_abs_diff_eq!(acos(x), x.acos(), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn slerp_halves_angles() {
+        // Identity and a quarter turn about Z, the latter negated so that the
+        // shorter arc must be taken.
+        let h = core::f32::consts::FRAC_1_SQRT_2;
+        let (q0, q1) = ([0.0, 0.0, 0.0, 1.0], [0.0, 0.0, -h, -h]);
+        let mut out = [0.0; 4];
+        slerp(&q0, &q1, 0.5, &mut out);
+        let (sin, cos) = core::f32::consts::FRAC_PI_8.sin_cos();
+        assert_relative_eq!(out[..], [0.0, 0.0, sin, cos][..]);
+        slerp(&q0, &q0, 0.5, &mut out);
+        assert_eq!(out, q0);
+    }
+}
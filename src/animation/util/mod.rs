@@ -4,9 +4,11 @@ pub mod rotations;
 /// Casting iterator adapters for morph target weights.
 pub mod morph_target_weights;
 
+use alloc::vec::Vec;
+
 use crate::accessor;
 
-use crate::animation::Channel;
+use crate::animation::{Channel, Interpolation};
 use crate::Buffer;
 
 /// Animation input sampler values of type `f32`.
@@ -26,6 +28,7 @@ where
 {
     pub(crate) channel: Channel<'a>,
     pub(crate) get_buffer_data: F,
+    pub(crate) cache: Option<&'s accessor::AccessorLayoutCache>,
 }
 
 /// Rotation animations
@@ -73,6 +76,76 @@ pub enum ReadOutputs<'a> {
     MorphTargetWeights(MorphTargetWeights<'a>),
 }
 
+/// The output samples of a keyframe of a `CUBICSPLINE` sampler.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CubicKeyframe<T> {
+    /// The in-tangent of the keyframe.
+    pub in_tangent: T,
+    /// The value of the keyframe.
+    pub value: T,
+    /// The out-tangent of the keyframe.
+    pub out_tangent: T,
+}
+
+/// Groups the output samples of a `CUBICSPLINE` sampler into keyframes.
+///
+/// The samples of each keyframe are `width` in-tangents, then `width` values
+/// and then `width` out-tangents, where `width` is 1 except for morph target
+/// weights, which have one of each per morph target; see
+/// [`Reader::output_width`]. The keyframes are yielded in order, with those of
+/// every morph target of a time in the order of the targets. A trailing
+/// keyframe with missing samples is not yielded.
+#[derive(Clone, Debug)]
+pub struct CubicKeyframes<I: Iterator> {
+    outputs: I,
+    width: usize,
+    samples: Vec<I::Item>,
+    next: usize,
+}
+
+impl<I: Iterator> CubicKeyframes<I>
+where
+    I::Item: Copy,
+{
+    /// Groups the output samples of a `CUBICSPLINE` sampler `width` at a time.
+    pub fn new(outputs: I, width: usize) -> Self {
+        Self {
+            outputs,
+            width,
+            samples: Vec::with_capacity(3 * width),
+            next: 0,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for CubicKeyframes<I>
+where
+    I::Item: Copy,
+{
+    type Item = CubicKeyframe<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.width == 0 {
+            return None;
+        }
+        if self.next == 0 {
+            self.samples.clear();
+            self.samples
+                .extend(self.outputs.by_ref().take(3 * self.width));
+            if self.samples.len() < 3 * self.width {
+                return None;
+            }
+        }
+        let target = self.next;
+        self.next = (self.next + 1) % self.width;
+        Some(CubicKeyframe {
+            in_tangent: self.samples[target],
+            value: self.samples[self.width + target],
+            out_tangent: self.samples[2 * self.width + target],
+        })
+    }
+}
+
 impl<'a> Rotations<'a> {
     /// Reinterpret rotations as u16.  Lossy if underlying iterator yields u8,
     /// i16, u16 or f32.
@@ -140,48 +213,83 @@ impl<'a, 's, F> Reader<'a, 's, F>
 where
     F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
 {
+    /// Returns the items of an accessor.
+    fn iter<T: accessor::Item>(
+        &self,
+        accessor: crate::Accessor<'a>,
+    ) -> Option<accessor::Iter<'s, T>> {
+        let get_buffer_data = self.get_buffer_data.clone();
+        accessor::Iter::new_sanitized(accessor, get_buffer_data, None, self.cache)
+    }
+
+    /// Returns the number of output samples of a channel per input sample, or
+    /// per element of a keyframe of a `CUBICSPLINE` sampler.
+    ///
+    /// This is the number of morph targets for weights, and 1 otherwise.
+    pub fn output_width(&self) -> usize {
+        let sampler = self.channel.sampler();
+        let elements = match sampler.interpolation() {
+            Interpolation::CubicSpline => 3,
+            _ => 1,
+        };
+        match sampler.input().count() * elements {
+            0 => 0,
+            n => sampler.output().count() / n,
+        }
+    }
+
     /// Visits the input samples of a channel.
     pub fn read_inputs(&self) -> Option<ReadInputs<'s>> {
-        accessor::Iter::new(self.channel.sampler().input(), self.get_buffer_data.clone())
+        self.iter(self.channel.sampler().input())
     }
 
     /// Visits the output samples of a channel.
+    ///
+    /// The samples of a `CUBICSPLINE` sampler may be grouped into keyframes
+    /// with [`CubicKeyframes`]. Returns `None` if the outputs are not of a
+    /// component type that the target property allows.
     pub fn read_outputs(&self) -> Option<ReadOutputs<'s>> {
         use crate::animation::Property;
-        use accessor::{DataType, Iter};
+        use accessor::DataType;
         let output = self.channel.sampler().output();
         match self.channel.target().property() {
-            Property::Translation => {
-                Iter::new(output, self.get_buffer_data.clone()).map(ReadOutputs::Translations)
-            }
+            Property::Translation => self.iter(output).map(ReadOutputs::Translations),
             Property::Rotation => match output.data_type() {
-                DataType::I8 => Iter::new(output, self.get_buffer_data.clone())
+                DataType::I8 => self
+                    .iter(output)
                     .map(|x| ReadOutputs::Rotations(Rotations::I8(x))),
-                DataType::U8 => Iter::new(output, self.get_buffer_data.clone())
+                DataType::U8 => self
+                    .iter(output)
                     .map(|x| ReadOutputs::Rotations(Rotations::U8(x))),
-                DataType::I16 => Iter::new(output, self.get_buffer_data.clone())
+                DataType::I16 => self
+                    .iter(output)
                     .map(|x| ReadOutputs::Rotations(Rotations::I16(x))),
-                DataType::U16 => Iter::new(output, self.get_buffer_data.clone())
+                DataType::U16 => self
+                    .iter(output)
                     .map(|x| ReadOutputs::Rotations(Rotations::U16(x))),
-                DataType::F32 => Iter::new(output, self.get_buffer_data.clone())
+                DataType::F32 => self
+                    .iter(output)
                     .map(|x| ReadOutputs::Rotations(Rotations::F32(x))),
-                _ => unreachable!(),
+                _ => None,
             },
-            Property::Scale => {
-                Iter::new(output, self.get_buffer_data.clone()).map(ReadOutputs::Scales)
-            }
+            Property::Scale => self.iter(output).map(ReadOutputs::Scales),
             Property::MorphTargetWeights => match output.data_type() {
-                DataType::I8 => Iter::new(output, self.get_buffer_data.clone())
+                DataType::I8 => self
+                    .iter(output)
                     .map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::I8(x))),
-                DataType::U8 => Iter::new(output, self.get_buffer_data.clone())
+                DataType::U8 => self
+                    .iter(output)
                     .map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::U8(x))),
-                DataType::I16 => Iter::new(output, self.get_buffer_data.clone())
+                DataType::I16 => self
+                    .iter(output)
                     .map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::I16(x))),
-                DataType::U16 => Iter::new(output, self.get_buffer_data.clone())
+                DataType::U16 => self
+                    .iter(output)
                     .map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::U16(x))),
-                DataType::F32 => Iter::new(output, self.get_buffer_data.clone())
+                DataType::F32 => self
+                    .iter(output)
                     .map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::F32(x))),
-                _ => unreachable!(),
+                _ => None,
             },
         }
     }
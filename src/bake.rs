@@ -0,0 +1,224 @@
+//! Documents baked by [`Document::bake`] are parsed from JSON once, typically
+//! at build time, and loaded at runtime with [`Document::from_baked`] without
+//! parsing JSON.
+//!
+//! The cache format is a header followed by the JSON structs of the
+//! document:
+//!
+//! * the magic bytes `glTFbake`;
+//! * the format version, [`VERSION`], as a little-endian `u32`;
+//! * the layout of the JSON structs, [`json::bake::LAYOUT`], as a
+//!   little-endian `u32`;
+//! * the [`content_hash`] of the document as a little-endian `u64`;
+//! * `1` as a little-endian `u32` if the document was valid when baked, and
+//!   `0` otherwise;
+//! * the root of the document encoded with [`json::bake::Bake`], which lays
+//!   out the fields of every struct in order, with numbers in little-endian
+//!   and strings, sequences and maps prefixed by their length.
+//!
+//! Data baked with another format version or with other `gltf-json` features
+//! that add fields is rejected, and [`baked_content_hash`] lets a build
+//! detect that a cache no longer matches its source.
+//!
+//! [`VERSION`]: crate::bake::VERSION
+//! [`content_hash`]: crate::bake::content_hash
+//! [`baked_content_hash`]: crate::bake::baked_content_hash
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use json::bake::{Bake, Malformed, LAYOUT};
+
+use crate::Document;
+
+/// The version of the cache format, which changes whenever the encoding or
+/// the JSON structs change.
+pub const VERSION: u32 = 1;
+
+/// The magic bytes that baked documents start with.
+const MAGIC: &[u8; 8] = b"glTFbake";
+
+/// The length of the header.
+const HEADER: usize = 28;
+
+/// Reasons for [`Document::from_baked`] to reject data.
+#[derive(Debug)]
+pub enum BakeError {
+    /// The data is not a baked document.
+    NotBaked,
+
+    /// The data was baked with another version of the cache format.
+    Version {
+        /// The version the data was baked with.
+        found: u32,
+
+        /// The version this crate reads, [`VERSION`].
+        expected: u32,
+    },
+
+    /// The data was baked with other `gltf-json` features, which lay out the
+    /// JSON structs differently.
+    Layout {
+        /// The layout the data was baked with.
+        found: u32,
+
+        /// The layout of this build, [`json::bake::LAYOUT`].
+        expected: u32,
+    },
+
+    /// The data is truncated or does not encode the JSON structs of this
+    /// crate version.
+    Malformed,
+
+    /// The baked document is not valid.
+    Document(crate::Error),
+}
+
+impl fmt::Display for BakeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BakeError::NotBaked => write!(f, "not a baked document"),
+            BakeError::Version { found, expected } => write!(
+                f,
+                "baked with cache format version {} but version {} is required",
+                found, expected
+            ),
+            BakeError::Layout { found, expected } => write!(
+                f,
+                "baked with layout {:#x} but layout {:#x} is required",
+                found, expected
+            ),
+            BakeError::Malformed => Malformed.fmt(f),
+            BakeError::Document(error) => error.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for BakeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            BakeError::Document(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<Malformed> for BakeError {
+    fn from(_: Malformed) -> Self {
+        BakeError::Malformed
+    }
+}
+
+/// Returns the hash of the JSON of a document that [`Document::bake`]
+/// embeds.
+///
+/// This is the 64-bit FNV-1a hash of the document serialized as compact
+/// JSON, so it changes with any change to the source that survives parsing.
+///
+/// # Panics
+///
+/// Panics if the document holds invalid items, which only documents
+/// constructed without validation may.
+pub fn content_hash(document: &Document) -> u64 {
+    let json = document
+        .as_json()
+        .to_vec()
+        .expect("documents with invalid items cannot be hashed");
+    crate::transform::hash(&json)
+}
+
+/// Returns the [`content_hash`] embedded in baked data, which may be compared
+/// with that of the source document to detect stale caches.
+pub fn baked_content_hash(data: &[u8]) -> Result<u64, BakeError> {
+    header(data).map(|header| header.content_hash)
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes(bytes.try_into().unwrap())
+}
+
+/// The header of baked data.
+struct Header<'a> {
+    content_hash: u64,
+    validated: bool,
+    body: &'a [u8],
+}
+
+/// Checks the header of baked data and splits it from the encoded structs.
+fn header(data: &[u8]) -> Result<Header<'_>, BakeError> {
+    if data.len() < HEADER || &data[..8] != MAGIC {
+        return Err(BakeError::NotBaked);
+    }
+    let found = read_u32(&data[8..12]);
+    if found != VERSION {
+        return Err(BakeError::Version {
+            found,
+            expected: VERSION,
+        });
+    }
+    let found = read_u32(&data[12..16]);
+    if found != LAYOUT {
+        return Err(BakeError::Layout {
+            found,
+            expected: LAYOUT,
+        });
+    }
+    let validated = match read_u32(&data[24..28]) {
+        0 => false,
+        1 => true,
+        _ => return Err(BakeError::Malformed),
+    };
+    Ok(Header {
+        content_hash: u64::from_le_bytes(data[16..24].try_into().unwrap()),
+        validated,
+        body: &data[HEADER..],
+    })
+}
+
+impl Document {
+    /// Encodes the document in the cache format of the [`bake`] module.
+    ///
+    /// Source spans and the faults of a resilient import are not baked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the document holds invalid items, which only documents
+    /// constructed without validation may.
+    ///
+    /// [`bake`]: crate::bake
+    pub fn bake(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(4096);
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&VERSION.to_le_bytes());
+        out.extend_from_slice(&LAYOUT.to_le_bytes());
+        out.extend_from_slice(&content_hash(self).to_le_bytes());
+        let validated = self.validate().is_ok() as u32;
+        out.extend_from_slice(&validated.to_le_bytes());
+        self.as_json().bake(&mut out);
+        out
+    }
+
+    /// Loads a document baked by [`Document::bake`].
+    ///
+    /// Documents that were valid when baked are not validated again, which
+    /// would take about as long as decoding them; like those of
+    /// [`Document::from_root_unchecked`], their methods may panic if the
+    /// baked data was tampered with. Other documents are validated like
+    /// [`Document::from_json`].
+    pub fn from_baked(data: &[u8]) -> Result<Self, BakeError> {
+        let Header {
+            validated,
+            mut body,
+            ..
+        } = header(data)?;
+        let root = json::Root::load(&mut body)?;
+        if !body.is_empty() {
+            return Err(BakeError::Malformed);
+        }
+        if validated {
+            Ok(Document::from_root_unchecked(root))
+        } else {
+            Document::from_json(root).map_err(BakeError::Document)
+        }
+    }
+}
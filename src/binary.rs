@@ -1,14 +1,18 @@
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
 use core::fmt;
+use core::ops::Range;
 use core2::io;
 use crate::binary::Error::Io;
 
 /// Represents a Glb loader error.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
-    /// Some error when reading slice.
-    Io,
-    /// Unsupported version.
+    /// Reading or writing failed, for example because the data ended before
+    /// a header was complete.
+    Io(IoError),
+    /// The container version is not one of [`GlbOptions::accepted_versions`].
     Version(u32),
     /// Magic says that file is not glTF.
     Magic([u8; 4]),
@@ -19,6 +23,8 @@ pub enum Error {
         /// Actual length of data read.
         length_read: usize,
     },
+    /// Length specified in GLB header is smaller than the header itself.
+    HeaderLength(u32),
     /// Stream ended before we could read the chunk.
     ChunkLength {
         /// chunkType error happened at.
@@ -30,8 +36,40 @@ pub enum Error {
     },
     /// Chunk of this chunkType was not expected.
     ChunkType(ChunkType),
-    /// Unknown chunk type.
+    /// The first chunk has an unknown chunk type instead of `JSON`.
     UnknownChunkType([u8; 4]),
+    /// The contents are too large for the 32-bit length of a `.glb` file.
+    TooLarge {
+        /// The length that the file would have.
+        length: usize,
+    },
+    /// A buffer view of the BIN chunk extends past the end of the chunk.
+    ViewOutOfBounds {
+        /// The index of the offending buffer view.
+        view: usize,
+        /// The end of the view relative to the start of the BIN chunk data.
+        end: usize,
+        /// The length of the BIN chunk data, which is zero if it is absent.
+        length: usize,
+    },
+    /// The length of a chunk is not a multiple of four, which is rejected
+    /// with [`ChunkAlignment::Strict`].
+    ChunkAlignment {
+        /// The type of the chunk.
+        ty: ChunkType,
+        /// The chunk length.
+        length: u32,
+    },
+}
+
+/// An I/O error that interrupted reading or writing a `.glb` file.
+#[derive(Debug)]
+pub struct IoError {
+    /// The underlying error.
+    pub error: io::Error,
+    /// The number of bytes of the header or chunk being read or written that
+    /// were transferred before the error occurred.
+    pub transferred: usize,
 }
 
 /// Binary glTF contents.
@@ -43,6 +81,185 @@ pub struct Glb<'a> {
     pub json: Cow<'a, [u8]>,
     /// The optional BIN section of the `.glb` file.
     pub bin: Option<Cow<'a, [u8]>>,
+    /// The chunks of unknown types that follow the JSON and BIN chunks, with
+    /// their chunk types, in file order.
+    ///
+    /// Readers ignore these chunks, as the specification requires. They are
+    /// written after the BIN chunk, padded with zeros.
+    pub extra_chunks: Vec<([u8; 4], Cow<'a, [u8]>)>,
+    /// The length of the file, if it is shorter than the header length and
+    /// this was accepted with [`GlbOptions::allow_length_mismatch`];
+    /// otherwise `None`.
+    ///
+    /// The writer ignores this and writes the length of its output.
+    pub length_read: Option<usize>,
+    /// How the chunks were read, which locates the BIN chunk for
+    /// [`Glb::layout`] and [`Glb::view_ranges`].
+    ///
+    /// The writer ignores this and always pads its chunks.
+    pub chunk_alignment: ChunkAlignment,
+}
+
+/// The byte ranges that the chunks of a `.glb` file occupy within it.
+///
+/// See [`Glb::layout`].
+#[derive(Clone, Debug)]
+pub struct GlbLayout {
+    /// The header section of the `.glb` file.
+    pub header: Header,
+    /// The range of the JSON chunk data, excluding its chunk header.
+    pub json: Range<usize>,
+    /// The range of the BIN chunk data, excluding its chunk header, or `None`
+    /// if the file has no BIN chunk.
+    pub bin: Option<Range<usize>>,
+}
+
+/// Options for [`Glb::debug_json`].
+#[derive(Clone, Copy, Debug)]
+pub struct DebugJsonOptions<'a> {
+    /// The number of spaces per level of indentation.
+    pub indent: usize,
+    /// If set, arrays with more than twice this many elements are truncated
+    /// to their first and last elements, separated by the number of elements
+    /// omitted.
+    pub array_items: Option<usize>,
+    /// If set, arrays and objects nested this deep are elided.
+    pub max_depth: Option<usize>,
+    /// If set, only this member of the root object is printed.
+    pub key: Option<&'a str>,
+}
+
+impl<'a> Default for DebugJsonOptions<'a> {
+    fn default() -> Self {
+        Self {
+            indent: 2,
+            array_items: None,
+            max_depth: None,
+            key: None,
+        }
+    }
+}
+
+/// Options for [`Glb::from_slice_with`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GlbOptions<'a> {
+    /// The accepted container versions of the GLB header.
+    ///
+    /// The container version is independent of the glTF version of the asset,
+    /// which is checked when the JSON is loaded. The default is `[2]`; other
+    /// versions are split into chunks like version 2.
+    pub accepted_versions: &'a [u32],
+
+    /// If `true`, a file that is shorter than its header length is read up
+    /// to its end instead of being rejected with [`Error::Length`], as long
+    /// as it ends between chunks. The shortfall is reported by
+    /// [`Glb::length_read`]. Defaults to `false`.
+    ///
+    /// Some exporters count the final padding in the header length without
+    /// writing it. A chunk that extends past the end of the file is still an
+    /// error.
+    pub allow_length_mismatch: bool,
+
+    /// How chunks whose lengths are not a multiple of four are read.
+    /// Defaults to [`ChunkAlignment::Exact`].
+    pub chunk_alignment: ChunkAlignment,
+}
+
+impl<'a> Default for GlbOptions<'a> {
+    fn default() -> Self {
+        Self {
+            accepted_versions: &[2],
+            allow_length_mismatch: false,
+            chunk_alignment: ChunkAlignment::Exact,
+        }
+    }
+}
+
+/// How chunks whose lengths are not a multiple of four are read; see
+/// [`GlbOptions::chunk_alignment`].
+///
+/// Every chunk must start at a 4-byte boundary, so chunk lengths include the
+/// padding of the chunk data. Some writers pad chunks without counting the
+/// padding, and others do not pad them at all.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ChunkAlignment {
+    /// The next chunk header is read right after the chunk data, as declared,
+    /// which reads files that are not padded at all.
+    Exact,
+    /// The next chunk header is read at the next 4-byte boundary after the
+    /// chunk data, skipping the padding that the chunk length does not
+    /// count.
+    Padded,
+    /// Chunk lengths must be multiples of four, or reading fails with
+    /// [`Error::ChunkAlignment`].
+    Strict,
+}
+
+impl Default for ChunkAlignment {
+    fn default() -> Self {
+        ChunkAlignment::Exact
+    }
+}
+
+impl ChunkAlignment {
+    /// Checks the length of a chunk, returning the number of padding bytes
+    /// that follow its data.
+    fn padding(self, chunk_h: &ChunkHeader) -> Result<usize, Error> {
+        match self {
+            ChunkAlignment::Exact => Ok(0),
+            ChunkAlignment::Padded => Ok(padding(chunk_h.length as usize)),
+            ChunkAlignment::Strict if chunk_h.length % 4 != 0 => Err(Error::ChunkAlignment {
+                ty: chunk_h.ty,
+                length: chunk_h.length,
+            }),
+            ChunkAlignment::Strict => Ok(0),
+        }
+    }
+
+    /// Returns the number of padding bytes that follow chunk data of the
+    /// given length without being counted by its chunk length.
+    fn skipped(self, length: usize) -> usize {
+        match self {
+            ChunkAlignment::Padded => padding(length),
+            ChunkAlignment::Exact | ChunkAlignment::Strict => 0,
+        }
+    }
+}
+
+/// Binary glTF contents of a possibly truncated `.glb` file.
+///
+/// See [`Glb::from_slice_partial`].
+#[derive(Clone, Debug)]
+pub struct PartialGlb<'a> {
+    /// The header section of the `.glb` file.
+    pub header: Header,
+    /// The JSON section of the `.glb` file, which is always complete.
+    pub json: Cow<'a, [u8]>,
+    /// The BIN section bytes that are present in the file.
+    pub bin: Option<Cow<'a, [u8]>>,
+    /// The BIN section length declared by its chunk header, or `None` if the
+    /// file ends before the BIN chunk header.
+    pub bin_length: Option<u32>,
+}
+
+/// The absolute byte ranges that the contents of the BIN chunk occupy within
+/// a `.glb` file.
+///
+/// See [`Glb::view_ranges`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ViewRangeTable {
+    /// The range of the BIN chunk data, excluding its chunk header, or `None`
+    /// if the file has no BIN chunk.
+    pub bin: Option<Range<usize>>,
+    /// The range of each buffer view by index, or `None` for views of
+    /// buffers that are not stored in the BIN chunk.
+    pub views: Vec<Option<Range<usize>>>,
+    /// The range of each image by index, or `None` for images that are not
+    /// stored in the BIN chunk.
+    pub images: Vec<Option<Range<usize>>>,
+    /// The pairs of buffer views whose ranges overlap, lower index first,
+    /// sorted.
+    pub overlaps: Vec<(usize, usize)>,
 }
 
 /// The header section of a .glb file.
@@ -51,19 +268,22 @@ pub struct Glb<'a> {
 pub struct Header {
     /// Must be `b"glTF"`.
     pub magic: [u8; 4],
-    /// Must be `2`.
+    /// The container version, which is `2` unless other versions are
+    /// accepted with [`GlbOptions`].
     pub version: u32,
     /// Must match the length of the parent .glb file.
     pub length: u32,
 }
 
 /// GLB chunk type.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum ChunkType {
     /// `JSON` chunk.
     Json,
     /// `BIN` chunk.
     Bin,
+    /// A chunk of an unknown type, with its chunk type.
+    Other([u8; 4]),
 }
 
 /// Chunk header with no data read yet.
@@ -76,15 +296,62 @@ struct ChunkHeader {
     ty: ChunkType,
 }
 
+/// A chunk of a `.glb` file, as yielded by [`ChunkIter`].
+#[derive(Copy, Clone, Debug)]
+pub struct Chunk<'a> {
+    /// The chunk type.
+    pub ty: ChunkType,
+    /// The offset of the chunk data from the start of the file, past the
+    /// chunk header.
+    pub offset: usize,
+    /// The chunk data.
+    pub data: &'a [u8],
+}
+
+/// An iterator over the chunks of a `.glb` file in memory.
+///
+/// Chunks are yielded lazily in file order, borrowing their data, so that the
+/// byte range of a chunk may be located without copying it or building a
+/// [`Glb`]. Their types and order are not checked. Bytes beyond the header
+/// length are ignored.
+///
+/// A chunk that extends past the header length is yielded as
+/// [`Error::ChunkLength`], and a partial chunk header as [`Error::Io`], after
+/// which the iterator ends.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::binary::Error> {
+/// use gltf::binary::{ChunkIter, ChunkType};
+///
+/// # let data = std::fs::read("examples/Box.glb").unwrap();
+/// for chunk in ChunkIter::new(&data)? {
+///     let chunk = chunk?;
+///     if chunk.ty == ChunkType::Bin {
+///         let range = chunk.offset..chunk.offset + chunk.data.len();
+///         println!("BIN chunk at bytes {:?}", range);
+/// #       assert_eq!(&data[range], chunk.data);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChunkIter<'a> {
+    header: Header,
+    /// The file up to the header length.
+    data: &'a [u8],
+    /// The offset of the next chunk header.
+    offset: usize,
+    /// How the offset of the next chunk header is found.
+    alignment: ChunkAlignment,
+}
+
 impl Header {
     fn from_reader<R: io::Read>(mut reader: R) -> Result<Self, Error> {
-        use self::Error::Io;
-        let mut magic = [0u8; 4];
-        let mut version = [0u8; 4];
-        let mut length = [0u8; 4];
-        reader.read_exact(&mut magic).map_err(|_| Io)?;
-        reader.read_exact(&mut version).map_err(|_| Io)?;
-        reader.read_exact(&mut length).map_err(|_| Io)?;
+        let mut bytes = [0u8; 12];
+        read_exact(&mut reader, &mut bytes)?;
+        let [magic, version, length] = [0, 4, 8].map(|i| field(&bytes, i));
         // We only validate magic as we don't care for version and length of
         // contents, the caller does.  Let them decide what to do next with
         // regard to version and length.
@@ -105,28 +372,122 @@ impl Header {
 }
 
 impl ChunkHeader {
+    fn size_of() -> usize {
+        8
+    }
+
     fn from_reader<R: io::Read>(mut reader: R) -> Result<Self, Error> {
-        let mut length = [0u8; 4];
-        let mut ty = [0u8; 4];
-        reader.read_exact(&mut length).map_err(|_| Io)?;
-        reader.read_exact(&mut ty).map_err(|_| Io)?;
+        let mut bytes = [0u8; 8];
+        read_exact(&mut reader, &mut bytes)?;
+        let [length, ty] = [0, 4].map(|i| field(&bytes, i));
         let ty = match &ty {
-            b"JSON" => Ok(ChunkType::Json),
-            b"BIN\0" => Ok(ChunkType::Bin),
-            _ => Err(Error::UnknownChunkType(ty)),
-        }?;
-        Ok(Self { length: u8_arr_to_u32(length), ty })
+            b"JSON" => ChunkType::Json,
+            b"BIN\0" => ChunkType::Bin,
+            _ => ChunkType::Other(ty),
+        };
+        Ok(Self {
+            length: u8_arr_to_u32(length),
+            ty,
+        })
     }
 }
 
-fn split_binary_gltf(mut data: &[u8]) -> Result<(&[u8], Option<&[u8]>), Error> {
-    let (json, mut data) = ChunkHeader::from_reader(&mut data)
-        .and_then(|json_h| {
-            if let ChunkType::Json = json_h.ty {
-                Ok(json_h)
-            } else {
-                Err(Error::ChunkType(json_h.ty))
+impl<'a> ChunkIter<'a> {
+    /// Reads the header of a `.glb` file and returns an iterator over the
+    /// chunks that follow it.
+    ///
+    /// Returns [`Error::Length`] if the header length exceeds the length of
+    /// `data`. The container version is not checked.
+    pub fn new(data: &'a [u8]) -> Result<Self, Error> {
+        Self::open(data, &GlbOptions::default())
+    }
+
+    /// Like [`ChunkIter::new`], but with the length mismatch and the chunk
+    /// alignment of `options`.
+    fn open(data: &'a [u8], options: &GlbOptions) -> Result<Self, Error> {
+        let header = Header::from_reader(data)?;
+        let contents_length = (header.length as usize)
+            .checked_sub(Header::size_of())
+            .ok_or(Error::HeaderLength(header.length))?;
+        let length_read = data.len() - Header::size_of();
+        if contents_length > length_read && !options.allow_length_mismatch {
+            return Err(Error::Length {
+                length: contents_length as u32,
+                length_read,
+            });
+        }
+        Ok(Self {
+            header,
+            data: &data[..data.len().min(header.length as usize)],
+            offset: Header::size_of(),
+            alignment: options.chunk_alignment,
+        })
+    }
+
+    /// Returns the header of the file.
+    pub fn header(&self) -> Header {
+        self.header
+    }
+
+    /// Returns the byte ranges that the JSON and BIN chunks occupy within the
+    /// file, reading the chunks that the iterator has yet to yield.
+    ///
+    /// The chunk types are checked like those of [`Glb::from_slice`], and
+    /// chunks of unknown types are skipped. Unlike [`Glb::from_slice`], this
+    /// never allocates, so it may locate the BIN chunk of a file in memory
+    /// that has no heap. The container version is not checked.
+    pub fn layout(self) -> Result<GlbLayout, Error> {
+        let header = self.header;
+        let (json, bin) = split_binary_gltf(self, |_, _| {})?;
+        let range = |chunk: Chunk| chunk.offset..chunk.offset + chunk.data.len();
+        Ok(GlbLayout {
+            header,
+            json: range(json),
+            bin: bin.map(range),
+        })
+    }
+}
+
+impl<'a> Iterator for ChunkIter<'a> {
+    type Item = Result<Chunk<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut rest = &self.data[self.offset..];
+        if rest.is_empty() {
+            return None;
+        }
+        let offset = self.offset + ChunkHeader::size_of();
+        let mut padding = 0;
+        let chunk = ChunkHeader::from_reader(&mut rest).and_then(|chunk_h| {
+            padding = self.alignment.padding(&chunk_h)?;
+            if chunk_h.length as usize > rest.len() {
+                return Err(Error::ChunkLength {
+                    ty: chunk_h.ty,
+                    length: chunk_h.length,
+                    length_read: rest.len(),
+                });
             }
+            Ok(Chunk {
+                ty: chunk_h.ty,
+                offset,
+                data: &rest[..chunk_h.length as usize],
+            })
+        });
+        self.offset = match &chunk {
+            // Padding that the file ends within is not required.
+            Ok(chunk) => (offset + chunk.data.len() + padding).min(self.data.len()),
+            Err(_) => self.data.len(),
+        };
+        Some(chunk)
+    }
+}
+
+fn split_json_chunk(mut data: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    ChunkHeader::from_reader(&mut data)
+        .and_then(|json_h| match json_h.ty {
+            ChunkType::Json => Ok(json_h),
+            ChunkType::Other(ty) => Err(Error::UnknownChunkType(ty)),
+            ty => Err(Error::ChunkType(ty)),
         })
         .and_then(|json_h| {
             if json_h.length as usize <= data.len() {
@@ -141,64 +502,423 @@ fn split_binary_gltf(mut data: &[u8]) -> Result<(&[u8], Option<&[u8]>), Error> {
         })
         // We have verified that json_h.length is no greater than that of
         // data.len().
-        .map(|json_h| data.split_at(json_h.length as usize))?;
-
-    let bin = if !data.is_empty() {
-        ChunkHeader::from_reader(&mut data)
-            .and_then(|bin_h| {
-                if let ChunkType::Bin = bin_h.ty {
-                    Ok(bin_h)
-                } else {
-                    Err(Error::ChunkType(bin_h.ty))
-                }
-            })
-            .and_then(|bin_h| {
-                if bin_h.length as usize <= data.len() {
-                    Ok(bin_h)
-                } else {
-                    Err(Error::ChunkLength {
-                        ty: bin_h.ty,
-                        length: bin_h.length,
-                        length_read: data.len(),
-                    })
-                }
-            })
-            // We have verified that bin_h.length is no greater than that
-            // of data.len().
-            .map(|bin_h| data.split_at(bin_h.length as usize))
-            .map(|(x, _)| Some(x))?
-    } else {
-        None
-    };
+        .map(|json_h| data.split_at(json_h.length as usize))
+}
+
+/// Checks the type of a chunk that follows the JSON chunk, which may be the
+/// BIN chunk only if it is the first to follow.
+fn check_trailing_chunk(ty: ChunkType, first: bool) -> Result<(), Error> {
+    match ty {
+        ChunkType::Bin if first => Ok(()),
+        ChunkType::Other(_) => Ok(()),
+        ty => Err(Error::ChunkType(ty)),
+    }
+}
+
+/// Returns the JSON chunk and the BIN chunk, passing the chunks of unknown
+/// types to `extra` in file order.
+fn split_binary_gltf<'a>(
+    mut chunks: ChunkIter<'a>,
+    mut extra: impl FnMut([u8; 4], &'a [u8]),
+) -> Result<(Chunk<'a>, Option<Chunk<'a>>), Error> {
+    // A file that ends after its header has no room for the JSON chunk.
+    let json = chunks.next().unwrap_or_else(|| Err(eof(0)))?;
+    match json.ty {
+        ChunkType::Json => {}
+        ChunkType::Other(ty) => return Err(Error::UnknownChunkType(ty)),
+        ty => return Err(Error::ChunkType(ty)),
+    }
+    let mut bin = None;
+    let mut first = true;
+    for chunk in chunks {
+        let chunk = chunk?;
+        check_trailing_chunk(chunk.ty, first)?;
+        first = false;
+        match chunk.ty {
+            ChunkType::Other(ty) => extra(ty, chunk.data),
+            _ => bin = Some(chunk),
+        }
+    }
     Ok((json, bin))
 }
 
+/// The number of bytes by which a chunk buffer grows while it is read from a
+/// stream, so that a corrupt chunk length does not allocate up front.
+const READ_SIZE: usize = 4096;
+
+/// Reads the data of a chunk whose header has been read, checking that the
+/// chunk fits in the `remaining` bytes of the file.
+fn read_chunk<R: io::Read>(
+    reader: &mut R,
+    chunk_h: ChunkHeader,
+    remaining: usize,
+) -> Result<Vec<u8>, Error> {
+    let length = chunk_h.length as usize;
+    let chunk_length = |length_read| Error::ChunkLength {
+        ty: chunk_h.ty,
+        length: chunk_h.length,
+        length_read,
+    };
+    if length > remaining {
+        return Err(chunk_length(remaining));
+    }
+    let mut data = Vec::new();
+    while data.len() < length {
+        let start = data.len();
+        data.resize(length.min(start + READ_SIZE), 0);
+        match reader.read(&mut data[start..]) {
+            Ok(0) => return Err(chunk_length(start)),
+            Ok(n) => data.truncate(start + n),
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => data.truncate(start),
+            Err(error) => {
+                return Err(Io(IoError {
+                    error,
+                    transferred: start,
+                }))
+            }
+        }
+    }
+    Ok(data)
+}
+
+/// The JSON chunk, the BIN chunk and the chunks of unknown types, read from a
+/// stream.
+type OwnedChunks = (Vec<u8>, Option<Vec<u8>>, Vec<([u8; 4], Vec<u8>)>);
+
+/// Reads the chunks that follow the header of a GLB stream, and the length of
+/// the stream if it ended between chunks before the header length, which is
+/// only accepted with [`GlbOptions::allow_length_mismatch`].
+fn read_binary_gltf<R: io::Read>(
+    mut reader: R,
+    header: &Header,
+    options: &GlbOptions,
+) -> Result<(OwnedChunks, Option<usize>), Error> {
+    let mut remaining = (header.length as usize).saturating_sub(Header::size_of());
+    let json_h = ChunkHeader::from_reader(&mut reader)?;
+    match json_h.ty {
+        ChunkType::Json => {}
+        ChunkType::Other(ty) => return Err(Error::UnknownChunkType(ty)),
+        ty => return Err(Error::ChunkType(ty)),
+    }
+    let mut padding = options.chunk_alignment.padding(&json_h)?;
+    remaining = remaining.saturating_sub(ChunkHeader::size_of());
+    let json = read_chunk(&mut reader, json_h, remaining)?;
+    remaining -= json.len();
+
+    // Whatever follows the header length is not read.
+    let mut bin = None;
+    let mut extra_chunks = Vec::new();
+    let mut length_read = None;
+    let ended = |error: &IoError| {
+        options.allow_length_mismatch && error.error.kind() == io::ErrorKind::UnexpectedEof
+    };
+    while remaining > 0 {
+        let skip = padding.min(remaining);
+        match read_exact(&mut reader, &mut [0; 3][..skip]) {
+            Err(Io(ref error)) if ended(error) => {
+                length_read = Some(header.length as usize - remaining + error.transferred);
+                break;
+            }
+            skipped => skipped?,
+        }
+        remaining -= skip;
+        if remaining == 0 {
+            break;
+        }
+        let chunk_h = match ChunkHeader::from_reader(&mut reader) {
+            Err(Io(ref error)) if error.transferred == 0 && ended(error) => {
+                length_read = Some(header.length as usize - remaining);
+                break;
+            }
+            chunk_h => chunk_h?,
+        };
+        check_trailing_chunk(chunk_h.ty, bin.is_none() && extra_chunks.is_empty())?;
+        padding = options.chunk_alignment.padding(&chunk_h)?;
+        remaining = remaining.saturating_sub(ChunkHeader::size_of());
+        let chunk = read_chunk(&mut reader, chunk_h, remaining)?;
+        remaining -= chunk.len();
+        match chunk_h.ty {
+            ChunkType::Other(ty) => extra_chunks.push((ty, chunk)),
+            _ => bin = Some(chunk),
+        }
+    }
+    Ok(((json, bin, extra_chunks), length_read))
+}
+
+/// The JSON chunk, the BIN chunk bytes present and the declared BIN length.
+type PartialChunks<'a> = (&'a [u8], Option<&'a [u8]>, Option<u32>);
+
+/// Splits the chunks of a possibly truncated GLB, returning the JSON chunk and
+/// whatever is present of the BIN chunk along with its declared length.
+fn split_partial_binary_gltf(data: &[u8]) -> Result<PartialChunks<'_>, Error> {
+    let (json, mut data) = split_json_chunk(data)?;
+    if data.len() < 8 {
+        // Not even the BIN chunk header survived.
+        return Ok((json, None, None));
+    }
+    let bin_h = ChunkHeader::from_reader(&mut data)?;
+    match bin_h.ty {
+        ChunkType::Bin => {}
+        // The chunk after the JSON chunk is not the BIN chunk.
+        ChunkType::Other(_) => return Ok((json, None, None)),
+        ty => return Err(Error::ChunkType(ty)),
+    }
+    let present = data.len().min(bin_h.length as usize);
+    Ok((json, Some(&data[..present]), Some(bin_h.length)))
+}
+
+impl Glb<'static> {
+    /// Reads a GLB from a stream into owned chunks.
+    ///
+    /// The header is read first, and then each chunk, checking the chunk
+    /// lengths against the length in the header before their data is read.
+    /// The stream is read no further than the header length. Returns
+    /// [`Error::ChunkLength`] if the stream ends within a chunk.
+    pub fn from_reader<R: io::Read>(reader: R) -> Result<Self, crate::Error> {
+        Self::from_reader_with(reader, GlbOptions::default())
+    }
+
+    /// Reads a GLB from a stream into owned chunks, accepting the container
+    /// versions of `options`.
+    pub fn from_reader_with<R: io::Read>(
+        mut reader: R,
+        options: GlbOptions,
+    ) -> Result<Self, crate::Error> {
+        let header = Header::from_reader(&mut reader).map_err(crate::Error::Binary)?;
+        if (header.length as usize) < Header::size_of() {
+            return Err(crate::Error::Binary(Error::HeaderLength(header.length)));
+        }
+        if !options.accepted_versions.contains(&header.version) {
+            return Err(crate::Error::Binary(Error::Version(header.version)));
+        }
+        read_binary_gltf(reader, &header, &options)
+            .map(|((json, bin, extra_chunks), length_read)| Glb {
+                header,
+                json: json.into(),
+                bin: bin.map(Into::into),
+                extra_chunks: extra_chunks
+                    .into_iter()
+                    .map(|(ty, data)| (ty, data.into()))
+                    .collect(),
+                length_read,
+                chunk_alignment: options.chunk_alignment,
+            })
+            .map_err(crate::Error::Binary)
+    }
+}
+
 impl<'a> Glb<'a> {
     /// Splits loaded GLB into its three chunks.
     ///
     /// * Mandatory GLB header.
     /// * Mandatory JSON chunk.
     /// * Optional BIN chunk.
-    pub fn from_slice(mut data: &'a [u8]) -> Result<Self, crate::Error> {
-        let header = Header::from_reader(&mut data)
-            .and_then(|header| {
-                let contents_length = header.length as usize - Header::size_of();
-                if contents_length <= data.len() {
-                    Ok(header)
-                } else {
-                    Err(Error::Length {
-                        length: contents_length as u32,
-                        length_read: data.len(),
-                    })
+    ///
+    /// Chunks of unknown types that follow the JSON chunk are collected into
+    /// [`Glb::extra_chunks`], which allocates if there are any; see
+    /// [`ChunkIter::layout`] for locating the chunks without allocating.
+    /// Bytes beyond the header length are ignored.
+    pub fn from_slice(data: &'a [u8]) -> Result<Self, crate::Error> {
+        Self::from_slice_with(data, GlbOptions::default())
+    }
+
+    /// Splits loaded GLB into its three chunks, accepting the container
+    /// versions and the length mismatch of `options`.
+    pub fn from_slice_with(data: &'a [u8], options: GlbOptions) -> Result<Self, crate::Error> {
+        let chunks = ChunkIter::open(data, &options).map_err(crate::Error::Binary)?;
+        let header = chunks.header();
+        if !options.accepted_versions.contains(&header.version) {
+            return Err(crate::Error::Binary(Error::Version(header.version)));
+        }
+        let mut extra_chunks = Vec::new();
+        split_binary_gltf(chunks, |ty, data| extra_chunks.push((ty, data.into())))
+            .map(|(json, bin)| Glb {
+                header,
+                json: json.data.into(),
+                bin: bin.map(|bin| bin.data.into()),
+                extra_chunks,
+                length_read: Some(data.len()).filter(|&n| n < header.length as usize),
+                chunk_alignment: options.chunk_alignment,
+            })
+            .map_err(crate::Error::Binary)
+    }
+
+    /// Returns the byte ranges of the chunks within the original `.glb` file.
+    ///
+    /// Like [`Glb::view_ranges`], this assumes that the BIN chunk follows the
+    /// JSON chunk as read with [`Glb::chunk_alignment`]. To locate the chunks
+    /// of a file in memory that has no heap, use [`ChunkIter::layout`].
+    pub fn layout(&self) -> GlbLayout {
+        let json = Header::size_of() + ChunkHeader::size_of();
+        let bin = self.bin_offset();
+        GlbLayout {
+            header: self.header,
+            json: json..json + self.json.len(),
+            bin: self.bin.as_ref().map(|data| bin..bin + data.len()),
+        }
+    }
+
+    /// Returns the offset of the BIN chunk data within the original `.glb`
+    /// file, past the padding of the JSON chunk that its length does not
+    /// count.
+    fn bin_offset(&self) -> usize {
+        let json = self.json.len();
+        Header::size_of()
+            + ChunkHeader::size_of()
+            + json
+            + self.chunk_alignment.skipped(json)
+            + ChunkHeader::size_of()
+    }
+
+    /// Writes the `.glb` file to a new vector.
+    ///
+    /// The header is written with the magic and container version of
+    /// [`Glb::header`] and the length of the output. The JSON chunk is padded
+    /// with spaces and the BIN chunk with zeros to a multiple of four bytes.
+    /// Returns an error if the output would exceed the 32-bit length of a
+    /// `.glb` file.
+    pub fn to_vec(&self) -> Result<Vec<u8>, crate::Error> {
+        let mut data = Vec::with_capacity(self.output_length()?);
+        self.to_writer(&mut data)?;
+        Ok(data)
+    }
+
+    /// Writes the `.glb` file to a writer.
+    ///
+    /// The output is the same as that of [`Glb::to_vec`], written without
+    /// intermediate allocations. Returns an error if the output would exceed
+    /// the 32-bit length of a `.glb` file or if writing fails.
+    pub fn to_writer<W: io::Write>(&self, mut writer: W) -> Result<(), crate::Error> {
+        let length = self.output_length()?;
+        let json_padding = padding(self.json.len());
+        let mut write = |data: &[u8]| write_all(&mut writer, data).map_err(crate::Error::Binary);
+        write(&self.header.magic)?;
+        write(&self.header.version.to_le_bytes())?;
+        write(&(length as u32).to_le_bytes())?;
+        write(&((self.json.len() + json_padding) as u32).to_le_bytes())?;
+        write(b"JSON")?;
+        write(&self.json)?;
+        write(&b"   "[..json_padding])?;
+        if let Some(bin) = self.bin.as_ref() {
+            let bin_padding = padding(bin.len());
+            write(&((bin.len() + bin_padding) as u32).to_le_bytes())?;
+            write(b"BIN\0")?;
+            write(bin)?;
+            write(&[0; 3][..bin_padding])?;
+        }
+        for (ty, data) in self.extra_chunks.iter() {
+            let data_padding = padding(data.len());
+            write(&((data.len() + data_padding) as u32).to_le_bytes())?;
+            write(ty)?;
+            write(data)?;
+            write(&[0; 3][..data_padding])?;
+        }
+        Ok(())
+    }
+
+    /// Returns the length of the output of [`Glb::to_vec`], or an error if it
+    /// exceeds the 32-bit length of a `.glb` file.
+    pub(crate) fn output_length(&self) -> Result<usize, crate::Error> {
+        let chunk = |length: usize| ChunkHeader::size_of() + length + padding(length);
+        let length = Header::size_of()
+            + chunk(self.json.len())
+            + self.bin.as_ref().map_or(0, |bin| chunk(bin.len()))
+            + self
+                .extra_chunks
+                .iter()
+                .map(|(_, data)| chunk(data.len()))
+                .sum::<usize>();
+        if length > u32::MAX as usize {
+            return Err(crate::Error::Binary(Error::TooLarge { length }));
+        }
+        Ok(length)
+    }
+
+    /// Writes a re-indented copy of the JSON chunk for debugging.
+    ///
+    /// The JSON is tokenized as it is written rather than parsed, so memory use
+    /// stays constant regardless of the document size. The output is not
+    /// valid JSON when arrays are truncated or containers are elided. Returns
+    /// an error if writing fails or the JSON chunk is malformed.
+    pub fn debug_json(&self, out: &mut dyn fmt::Write, options: DebugJsonOptions) -> fmt::Result {
+        crate::debug_json::write(&self.json, out, options)
+    }
+
+    /// Computes the absolute byte ranges that the buffer views and images
+    /// stored in the BIN chunk occupy within the original `.glb` file.
+    ///
+    /// The ranges assume that the BIN chunk follows the JSON chunk as read
+    /// with [`Glb::chunk_alignment`], so that a pager can fault in exactly the
+    /// bytes of a view without loading the buffer. A [`reader`] may then be
+    /// given the BIN chunk as paged memory in place of a loaded buffer.
+    ///
+    /// Views that overlap are reported rather than rejected, since the
+    /// specification does not forbid them. Returns an error if a view extends
+    /// past the end of the BIN chunk, or if views refer to the BIN chunk but
+    /// the file has none.
+    ///
+    /// [`reader`]: crate::Primitive::reader
+    pub fn view_ranges(&self, document: &crate::Document) -> Result<ViewRangeTable, crate::Error> {
+        let start = self.bin_offset();
+        let length = self.bin.as_ref().map_or(0, |bin| bin.len());
+        let mut views = Vec::new();
+        for view in document.views() {
+            let buffer = view.buffer();
+            let range = match buffer.source() {
+                crate::buffer::Source::Bin if buffer.has_data() => {
+                    let end = view.offset().checked_add(view.length());
+                    match end {
+                        Some(end) if end <= length && self.bin.is_some() => {
+                            Some(start + view.offset()..start + end)
+                        }
+                        _ => {
+                            return Err(crate::Error::Binary(Error::ViewOutOfBounds {
+                                view: view.index(),
+                                end: end.unwrap_or(usize::MAX),
+                                length,
+                            }))
+                        }
+                    }
                 }
+                _ => None,
+            };
+            views.push(range);
+        }
+        let images = document
+            .images()
+            .map(|image| match image.source() {
+                crate::image::Source::View { view, .. } => views[view.index()].clone(),
+                crate::image::Source::Uri { .. } => None,
             })
-            .map_err(crate::Error::Binary)?;
+            .collect();
+        Ok(ViewRangeTable {
+            bin: self.bin.as_ref().map(|_| start..start + length),
+            overlaps: overlapping(&views),
+            views,
+            images,
+        })
+    }
+
+    /// Splits a possibly truncated GLB into its chunks.
+    ///
+    /// The header and the JSON chunk must be complete, but the BIN chunk may
+    /// be cut short, in which case the bytes that are present are returned
+    /// together with the declared BIN length. The header length is not
+    /// checked against the length of `data`.
+    ///
+    /// The result may be used with [`import_buffers_partial`] to find the
+    /// accessors that can still be read.
+    ///
+    /// [`import_buffers_partial`]: crate::import_buffers_partial
+    pub fn from_slice_partial(mut data: &'a [u8]) -> Result<PartialGlb<'a>, crate::Error> {
+        let header = Header::from_reader(&mut data).map_err(crate::Error::Binary)?;
         match header.version {
-            2 => split_binary_gltf(data)
-                .map(|(json, bin)| Glb {
+            2 => split_partial_binary_gltf(data)
+                .map(|(json, bin, bin_length)| PartialGlb {
                     header,
                     json: json.into(),
                     bin: bin.map(Into::into),
+                    bin_length,
                 })
                 .map_err(crate::Error::Binary),
             x => Err(crate::Error::Binary(Error::Version(x))),
@@ -206,32 +926,562 @@ impl<'a> Glb<'a> {
     }
 }
 
+/// Returns the pairs of ranges that overlap, lower index first, sorted.
+fn overlapping(ranges: &[Option<Range<usize>>]) -> Vec<(usize, usize)> {
+    let mut sorted: Vec<_> = ranges
+        .iter()
+        .enumerate()
+        .filter_map(|(index, range)| Some((index, range.clone()?)))
+        .filter(|(_, range)| !range.is_empty())
+        .collect();
+    sorted.sort_by_key(|(_, range)| range.start);
+    let mut overlaps = Vec::new();
+    for (i, (a, range)) in sorted.iter().enumerate() {
+        for (b, other) in &sorted[i + 1..] {
+            if other.start >= range.end {
+                break;
+            }
+            overlaps.push((*a.min(b), *a.max(b)));
+        }
+    }
+    overlaps.sort_unstable();
+    overlaps
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
             "{}",
             match *self {
-                Error::Io => "Io error",
-                Error::Version(_) => "unsupported version",
+                Error::Io(ref error) => return write!(f, "Io error: {}", error),
+                Error::Version(version) => {
+                    return write!(f, "unsupported GLB container version {}", version);
+                }
                 Error::Magic(_) => "not glTF magic",
                 Error::Length { .. } => "could not completely read the object",
+                Error::HeaderLength(_) => "GLB header length is smaller than the header",
                 Error::ChunkLength { ty, .. } => match ty {
                     ChunkType::Json => "JSON chunk length exceeds that of slice",
                     ChunkType::Bin => "BIN\\0 chunk length exceeds that of slice",
+                    ChunkType::Other(_) => "chunk length exceeds that of slice",
                 },
                 Error::ChunkType(ty) => match ty {
                     ChunkType::Json => "was not expecting JSON chunk",
                     ChunkType::Bin => "was not expecting BIN\\0 chunk",
+                    ChunkType::Other(_) => "was not expecting chunk of unknown type",
                 },
                 Error::UnknownChunkType(_) => "unknown chunk type",
+                Error::ViewOutOfBounds { .. } => "buffer view exceeds the BIN\\0 chunk",
+                Error::TooLarge { .. } => "contents exceed the maximum length of a GLB file",
+                Error::ChunkAlignment { .. } => "chunk length is not a multiple of four",
             }
         )
     }
 }
 
-impl core::error::Error for Error {}
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} after {} bytes", self.error, self.transferred)
+    }
+}
+
+impl core::error::Error for IoError {}
+
+/// Returns the error of data that ends after `transferred` bytes of a header
+/// or chunk.
+fn eof(transferred: usize) -> Error {
+    Io(IoError {
+        error: io::ErrorKind::UnexpectedEof.into(),
+        transferred,
+    })
+}
+
+/// Fills `buf` from a reader like [`io::Read::read_exact`], but keeps count
+/// of the bytes read before an error.
+fn read_exact<R: io::Read>(reader: &mut R, buf: &mut [u8]) -> Result<(), Error> {
+    let mut transferred = 0;
+    while transferred < buf.len() {
+        match reader.read(&mut buf[transferred..]) {
+            Ok(0) => return Err(eof(transferred)),
+            Ok(n) => transferred += n,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(Io(IoError { error, transferred })),
+        }
+    }
+    Ok(())
+}
+
+/// Writes all of `data` like [`io::Write::write_all`], but keeps count of
+/// the bytes written before an error.
+fn write_all<W: io::Write>(writer: &mut W, data: &[u8]) -> Result<(), Error> {
+    let mut transferred = 0;
+    while transferred < data.len() {
+        match writer.write(&data[transferred..]) {
+            Ok(0) => {
+                return Err(Io(IoError {
+                    error: io::ErrorKind::WriteZero.into(),
+                    transferred,
+                }))
+            }
+            Ok(n) => transferred += n,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(Io(IoError { error, transferred })),
+        }
+    }
+    Ok(())
+}
+
+/// Returns the four bytes of a header field at an offset.
+fn field(bytes: &[u8], offset: usize) -> [u8; 4] {
+    [
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ]
+}
+
+/// Returns the number of bytes that pad a chunk to a multiple of four bytes.
+fn padding(length: usize) -> usize {
+    (4 - length % 4) % 4
+}
 
 fn u8_arr_to_u32(arr: [u8; 4]) -> u32 {
     arr[0] as u32 | (arr[1] as u32) << 8 | (arr[2] as u32) << 16 | (arr[3] as u32) << 24 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn glb(json: &[u8], bin: &[u8], bin_length: u32) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(b"glTF");
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&(json.len() as u32).to_le_bytes());
+        data.extend_from_slice(b"JSON");
+        data.extend_from_slice(json);
+        data.extend_from_slice(&bin_length.to_le_bytes());
+        data.extend_from_slice(b"BIN\0");
+        data.extend_from_slice(bin);
+        let length = data.len() as u32;
+        data[8..12].copy_from_slice(&length.to_le_bytes());
+        data
+    }
+
+    fn debug_json(json: &str, options: DebugJsonOptions) -> alloc::string::String {
+        let data = glb(json.as_bytes(), &[], 0);
+        let glb = Glb::from_slice(&data).unwrap();
+        let mut out = alloc::string::String::new();
+        glb.debug_json(&mut out, options).unwrap();
+        out
+    }
+
+    const JSON: &str = r#"{"asset":{"version":"2.0"},"nodes":[{"name":"a \"}\""},{}],
+        "accessors":[{"min":[1,2,3,4,5,6],"max":[]}]}"#;
+
+    #[test]
+    fn debug_json_reindents() {
+        let expected = r#"{
+  "asset": {
+    "version": "2.0"
+  },
+  "nodes": [
+    {
+      "name": "a \"}\""
+    },
+    {}
+  ],
+  "accessors": [
+    {
+      "min": [
+        1,
+        2,
+        3,
+        4,
+        5,
+        6
+      ],
+      "max": []
+    }
+  ]
+}
+"#;
+        assert_eq!(debug_json(JSON, DebugJsonOptions::default()), expected);
+    }
+
+    #[test]
+    fn debug_json_options() {
+        let options = DebugJsonOptions {
+            indent: 1,
+            array_items: Some(2),
+            key: Some("accessors"),
+            ..Default::default()
+        };
+        let expected = r#"{
+ "accessors": [
+  {
+   "min": [
+    1,
+    2,
+    ... 2 more items (6 total),
+    5,
+    6
+   ],
+   "max": []
+  }
+ ]
+}
+"#;
+        assert_eq!(debug_json(JSON, options), expected);
+
+        let options = DebugJsonOptions {
+            max_depth: Some(1),
+            ..Default::default()
+        };
+        let expected = "{\n  \"asset\": {...},\n  \"nodes\": [...],\n  \"accessors\": [...]\n}\n";
+        assert_eq!(debug_json(JSON, options), expected);
+
+        let options = DebugJsonOptions {
+            key: Some("scenes"),
+            ..Default::default()
+        };
+        assert_eq!(debug_json(JSON, options), "{}\n");
+    }
+
+    #[test]
+    fn debug_json_malformed() {
+        let data = glb(b"{\"a\":[1,2  ", &[], 0);
+        let glb = Glb::from_slice(&data).unwrap();
+        let mut out = alloc::string::String::new();
+        assert!(glb
+            .debug_json(&mut out, DebugJsonOptions::default())
+            .is_err());
+    }
+
+    /// Yields at most three bytes per read, interrupted every other read,
+    /// like a slow serial link.
+    struct Trickle<'a> {
+        data: &'a [u8],
+        interrupt: bool,
+    }
+
+    impl<'a> io::Read for Trickle<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.interrupt = !self.interrupt;
+            if self.interrupt {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            let n = buf.len().min(self.data.len()).min(3);
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    fn from_reader(data: &[u8]) -> Result<Glb<'static>, Error> {
+        let reader = Trickle {
+            data,
+            interrupt: false,
+        };
+        Glb::from_reader(reader).map_err(|err| match err {
+            crate::Error::Binary(err) => err,
+            err => panic!("{:?}", err),
+        })
+    }
+
+    #[test]
+    fn stream() {
+        let mut data = glb(b"{}  ", &[1, 2, 3, 4, 5, 6, 7, 8], 8);
+        let glb = from_reader(&data).unwrap();
+        assert_eq!(&*glb.json, b"{}  ");
+        assert_eq!(glb.bin.as_deref(), Some(&[1, 2, 3, 4, 5, 6, 7, 8][..]));
+        assert!(matches!(glb.json, Cow::Owned(_)));
+
+        // Bytes beyond the header length are not read.
+        data[8..12].copy_from_slice(&24u32.to_le_bytes());
+        let glb = from_reader(&data).unwrap();
+        assert_eq!(&*glb.json, b"{}  ");
+        assert!(glb.bin.is_none());
+    }
+
+    #[test]
+    fn truncated_stream() {
+        let data = glb(b"{}  ", &[1, 2, 3, 4, 5, 6, 7, 8], 8);
+        match from_reader(&data[..data.len() - 3]) {
+            Err(Error::ChunkLength {
+                ty: ChunkType::Bin,
+                length: 8,
+                length_read: 5,
+            }) => {}
+            other => panic!("{:?}", other),
+        }
+
+        // The chunk header does not fit in the header length.
+        let mut data = glb(b"{}  ", &[], 0);
+        data[12..16].copy_from_slice(&100u32.to_le_bytes());
+        match from_reader(&data) {
+            Err(Error::ChunkLength {
+                ty: ChunkType::Json,
+                length: 100,
+                length_read: 12,
+            }) => {}
+            other => panic!("{:?}", other),
+        }
+
+        // The stream ends two bytes into the JSON chunk header.
+        match from_reader(&data[..14]) {
+            Err(Error::Io(error)) => {
+                assert_eq!(error.error.kind(), io::ErrorKind::UnexpectedEof);
+                assert_eq!(error.transferred, 2);
+            }
+            other => panic!("{:?}", other),
+        }
+        let error = from_reader(b"glTO").unwrap_err();
+        assert!(matches!(error, Error::Io(IoError { transferred: 4, .. })));
+        assert!(core::error::Error::source(&error).is_some());
+    }
+
+    /// Builds a `.glb` file from chunk types and data.
+    fn chunks(chunks: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(b"glTF");
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        for (ty, chunk) in chunks {
+            data.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+            data.extend_from_slice(*ty);
+            data.extend_from_slice(chunk);
+        }
+        let length = data.len() as u32;
+        data[8..12].copy_from_slice(&length.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn unknown_chunks() {
+        let data = chunks(&[
+            (b"JSON", b"{}  "),
+            (b"BIN\0", &[1, 2, 3, 4]),
+            (b"LGHT", &[5, 6, 7, 8]),
+            (b"XYZ\0", &[]),
+        ]);
+        let expected = [(*b"LGHT", &[5, 6, 7, 8][..]), (*b"XYZ\0", &[][..])];
+        for glb in [Glb::from_slice(&data).unwrap(), from_reader(&data).unwrap()] {
+            assert_eq!(glb.bin.as_deref(), Some(&[1, 2, 3, 4][..]));
+            let extra: Vec<_> = glb.extra_chunks.iter().map(|(ty, x)| (*ty, &**x)).collect();
+            assert_eq!(extra, expected);
+            assert_eq!(glb.to_vec().unwrap(), data);
+        }
+
+        // An unknown chunk may take the place of the BIN chunk.
+        let data = chunks(&[(b"JSON", b"{}  "), (b"LGHT", &[5, 6, 7, 8])]);
+        for glb in [Glb::from_slice(&data).unwrap(), from_reader(&data).unwrap()] {
+            assert!(glb.bin.is_none());
+            assert_eq!(glb.extra_chunks.len(), 1);
+        }
+        let partial = Glb::from_slice_partial(&data).unwrap();
+        assert!(partial.bin.is_none());
+
+        // The JSON chunk comes first, and the BIN chunk second.
+        let data = chunks(&[(b"LGHT", &[5, 6, 7, 8]), (b"JSON", b"{}  ")]);
+        match Glb::from_slice(&data) {
+            Err(crate::Error::Binary(Error::UnknownChunkType(ty))) => assert_eq!(&ty, b"LGHT"),
+            other => panic!("{:?}", other.map(|_| ())),
+        }
+        assert!(matches!(
+            from_reader(&data),
+            Err(Error::UnknownChunkType(_))
+        ));
+        let data = chunks(&[
+            (b"JSON", b"{}  "),
+            (b"LGHT", &[5, 6, 7, 8]),
+            (b"BIN\0", &[1, 2, 3, 4]),
+        ]);
+        assert!(matches!(
+            Glb::from_slice(&data),
+            Err(crate::Error::Binary(Error::ChunkType(ChunkType::Bin)))
+        ));
+        assert!(matches!(
+            from_reader(&data),
+            Err(Error::ChunkType(ChunkType::Bin))
+        ));
+    }
+
+    #[test]
+    fn chunk_iter() {
+        let mut data = chunks(&[
+            (b"JSON", b"{}  "),
+            (b"BIN\0", &[1, 2, 3, 4]),
+            (b"LGHT", &[]),
+        ]);
+        data.extend_from_slice(b"junk");
+        let chunks: Vec<_> = ChunkIter::new(&data)
+            .unwrap()
+            .map(|chunk| chunk.map(|x| (x.ty, x.offset, x.data)))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            chunks,
+            [
+                (ChunkType::Json, 20, &b"{}  "[..]),
+                (ChunkType::Bin, 32, &[1, 2, 3, 4][..]),
+                (ChunkType::Other(*b"LGHT"), 44, &[][..]),
+            ]
+        );
+
+        // The last chunk extends past the header length.
+        let mut data = glb(b"{}  ", &[1, 2, 3, 4], 8);
+        let mut iter = ChunkIter::new(&data).unwrap();
+        assert!(matches!(
+            iter.next(),
+            Some(Ok(Chunk {
+                ty: ChunkType::Json,
+                ..
+            }))
+        ));
+        match iter.next() {
+            Some(Err(Error::ChunkLength {
+                ty: ChunkType::Bin,
+                length: 8,
+                length_read: 4,
+            })) => {}
+            other => panic!("{:?}", other),
+        }
+        assert!(iter.next().is_none());
+
+        // The header length exceeds the slice.
+        data.truncate(30);
+        match ChunkIter::new(&data) {
+            Err(Error::Length {
+                length: 24,
+                length_read: 18,
+            }) => {}
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn header_length_smaller_than_header() {
+        for length in [0u32, 1, 11] {
+            let mut data = glb(b"{}  ", &[], 0);
+            data[8..12].copy_from_slice(&length.to_le_bytes());
+            match Glb::from_slice(&data) {
+                Err(crate::Error::Binary(Error::HeaderLength(x))) if x == length => {}
+                other => panic!("{:?}", other.map(|_| ())),
+            }
+            match from_reader(&data) {
+                Err(Error::HeaderLength(x)) if x == length => {}
+                other => panic!("{:?}", other.map(|_| ())),
+            }
+        }
+
+        // A header of exactly 12 bytes has no room for the JSON chunk.
+        let mut data = glb(b"{}  ", &[], 0)[..12].to_vec();
+        data[8..12].copy_from_slice(&12u32.to_le_bytes());
+        assert!(matches!(
+            Glb::from_slice(&data),
+            Err(crate::Error::Binary(Error::Io(_)))
+        ));
+        assert!(matches!(from_reader(&data), Err(Error::Io(_))));
+    }
+
+    #[test]
+    fn partial_bin_chunk() {
+        let data = glb(b"{}  ", &[1, 2, 3, 4, 5, 6, 7, 8], 16);
+        assert!(Glb::from_slice(&data).is_err());
+
+        let glb = Glb::from_slice_partial(&data).unwrap();
+        assert_eq!(&*glb.json, b"{}  ");
+        assert_eq!(glb.bin.as_deref(), Some(&[1, 2, 3, 4, 5, 6, 7, 8][..]));
+        assert_eq!(glb.bin_length, Some(16));
+    }
+
+    #[test]
+    fn partial_without_bin_header() {
+        let data = glb(b"{}  ", &[], 0);
+        let glb = Glb::from_slice_partial(&data[..data.len() - 4]).unwrap();
+        assert!(glb.bin.is_none());
+        assert!(glb.bin_length.is_none());
+    }
+
+    #[test]
+    fn partial_requires_json_chunk() {
+        let data = glb(b"{}  ", &[], 0);
+        assert!(Glb::from_slice_partial(&data[..22]).is_err());
+    }
+
+    const VIEWS: &str = r#"{"asset":{"version":"2.0"},
+        "buffers":[{"byteLength":16},{"uri":"a.bin","byteLength":4}],
+        "bufferViews":[
+            {"buffer":0,"byteLength":8},
+            {"buffer":0,"byteOffset":4,"byteLength":8},
+            {"buffer":1,"byteLength":4},
+            {"buffer":0,"byteOffset":12,"byteLength":4}
+        ],
+        "images":[{"bufferView":3,"mimeType":"image/png"}]}"#;
+
+    fn padded(json: &str) -> Vec<u8> {
+        let mut json = json.as_bytes().to_vec();
+        while json.len() % 4 != 0 {
+            json.push(b' ');
+        }
+        json
+    }
+
+    #[test]
+    fn view_ranges() {
+        let json = padded(VIEWS);
+        let data = glb(&json, &[0; 16], 16);
+        let document = crate::Gltf::from_slice(&data).unwrap().document;
+        let table = Glb::from_slice(&data)
+            .unwrap()
+            .view_ranges(&document)
+            .unwrap();
+        let start = 28 + json.len();
+        assert_eq!(table.bin, Some(start..start + 16));
+        assert_eq!(
+            table.views,
+            [
+                Some(start..start + 8),
+                Some(start + 4..start + 12),
+                None,
+                Some(start + 12..start + 16),
+            ]
+        );
+        assert_eq!(table.images, [Some(start + 12..start + 16)]);
+        assert_eq!(table.overlaps, [(0, 1)]);
+        assert_eq!(&data[table.views[3].clone().unwrap()], &[0; 4]);
+    }
+
+    #[test]
+    fn view_ranges_out_of_bounds() {
+        let json = padded(VIEWS);
+        let data = glb(&json, &[0; 12], 12);
+        let document = crate::Gltf::from_slice_without_validation(&data)
+            .unwrap()
+            .document;
+        match Glb::from_slice(&data).unwrap().view_ranges(&document) {
+            Err(crate::Error::Binary(Error::ViewOutOfBounds { view, end, length })) => {
+                assert_eq!((view, end, length), (3, 16, 12))
+            }
+            other => panic!("expected an out of bounds view, got {:?}", other),
+        }
+
+        let mut glb = Glb::from_slice(&data).unwrap();
+        glb.bin = None;
+        assert!(glb.view_ranges(&document).is_err());
+    }
+}
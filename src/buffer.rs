@@ -1,7 +1,6 @@
 use alloc::string::String;
 use alloc::vec::Vec;
-#[cfg(feature = "import")]
-use core::ops;
+use core::{fmt, ops};
 
 use crate::Document;
 
@@ -23,6 +22,16 @@ pub struct Buffer<'a> {
     json: &'a json::buffer::Buffer,
 }
 
+/// Describes a buffer data source.
+#[derive(Clone, Debug)]
+pub enum Source<'a> {
+    /// Buffer data is contained in the `BIN` section of binary glTF.
+    Bin,
+
+    /// Buffer data is contained in an external data source.
+    Uri(&'a str),
+}
+
 /// A view into a buffer generally representing a subset of the buffer.
 #[derive(Clone, Debug)]
 pub struct View<'a> {
@@ -41,13 +50,9 @@ pub struct View<'a> {
 }
 
 /// Buffer data belonging to an imported glTF asset.
-#[cfg(feature = "import")]
-#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
 #[derive(Clone, Debug)]
 pub struct Data(pub Vec<u8>);
 
-#[cfg(feature = "import")]
-#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
 impl ops::Deref for Data {
     type Target = [u8];
     fn deref(&self) -> &Self::Target {
@@ -55,6 +60,167 @@ impl ops::Deref for Data {
     }
 }
 
+/// Sets of disjoint byte ranges, one per buffer.
+///
+/// Ranges are kept sorted and merged with ranges they overlap or adjoin.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeSet {
+    buffers: Vec<Vec<ops::Range<usize>>>,
+}
+
+impl RangeSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a byte range of a buffer to the set.
+    pub fn insert(&mut self, buffer: usize, range: ops::Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        if self.buffers.len() <= buffer {
+            self.buffers.resize(buffer + 1, Vec::new());
+        }
+        let ranges = &mut self.buffers[buffer];
+        let first = ranges.partition_point(|other| other.end < range.start);
+        let last = ranges.partition_point(|other| other.start <= range.end);
+        let merged = ranges[first..last].iter().fold(range, |merged, other| {
+            merged.start.min(other.start)..merged.end.max(other.end)
+        });
+        ranges.splice(first..last, Some(merged));
+    }
+
+    /// Returns the ranges of a buffer in ascending order.
+    pub fn get(&self, buffer: usize) -> &[ops::Range<usize>] {
+        self.buffers.get(buffer).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns whether the set covers the whole of a byte range of a buffer.
+    pub fn contains(&self, buffer: usize, range: ops::Range<usize>) -> bool {
+        range.is_empty()
+            || self
+                .get(buffer)
+                .iter()
+                .any(|other| other.start <= range.start && range.end <= other.end)
+    }
+
+    /// Returns the number of bytes covered in a buffer.
+    pub fn byte_count(&self, buffer: usize) -> usize {
+        self.get(buffer).iter().map(|range| range.len()).sum()
+    }
+}
+
+/// Buffer data of which only some byte ranges are kept in memory.
+///
+/// Created from [`Data`] and narrowed down with [`retain_ranges`], for
+/// example to the ranges returned by [`Document::cpu_required_ranges`] once
+/// everything else has been uploaded. The retained bytes keep their offsets
+/// within the buffer, so buffer views and accessors can still locate them.
+/// Since the data is no longer contiguous, it is read with [`get`] and
+/// [`view`] rather than through the readers, which expect whole buffers.
+///
+/// [`get`]: Self::get
+/// [`view`]: Self::view
+/// [`retain_ranges`]: Self::retain_ranges
+/// [`Document::cpu_required_ranges`]: crate::Document::cpu_required_ranges
+#[derive(Clone, Debug)]
+pub struct RetainedData {
+    /// The length of the original buffer data.
+    length: usize,
+
+    /// The retained segments and their offsets, in ascending order.
+    segments: Vec<(usize, Vec<u8>)>,
+}
+
+/// Error returned when reading bytes that were dropped from [`RetainedData`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DroppedRange {
+    /// The requested byte range.
+    pub range: ops::Range<usize>,
+}
+
+impl fmt::Display for DroppedRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "bytes {}..{} of the buffer are not retained",
+            self.range.start, self.range.end
+        )
+    }
+}
+
+impl core::error::Error for DroppedRange {}
+
+impl From<Data> for RetainedData {
+    fn from(data: Data) -> Self {
+        let length = data.0.len();
+        Self {
+            length,
+            segments: Vec::from([(0, data.0)]),
+        }
+    }
+}
+
+impl RetainedData {
+    /// Returns the length of the original buffer data in bytes.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns whether the original buffer data was empty.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the number of bytes still held in memory.
+    pub fn retained(&self) -> usize {
+        self.segments.iter().map(|(_, bytes)| bytes.len()).sum()
+    }
+
+    /// Frees every byte outside of `ranges`, which must be sorted and
+    /// disjoint, as returned by [`RangeSet::get`]. Returns the number of
+    /// bytes freed.
+    ///
+    /// Bytes that were already dropped are not restored.
+    pub fn retain_ranges(&mut self, ranges: &[ops::Range<usize>]) -> usize {
+        let before = self.retained();
+        let mut segments = Vec::new();
+        for (offset, bytes) in &self.segments {
+            let end = offset + bytes.len();
+            for range in ranges {
+                let start = range.start.max(*offset);
+                let stop = range.end.min(end);
+                if start < stop {
+                    let kept = &bytes[start - offset..stop - offset];
+                    segments.push((start, kept.to_vec()));
+                }
+            }
+        }
+        self.segments = segments;
+        before - self.retained()
+    }
+
+    /// Returns the bytes of a range that lies within one retained segment.
+    pub fn get(&self, range: ops::Range<usize>) -> Result<&[u8], DroppedRange> {
+        let index = self
+            .segments
+            .partition_point(|(offset, _)| *offset <= range.start);
+        index
+            .checked_sub(1)
+            .and_then(|index| {
+                let (offset, bytes) = &self.segments[index];
+                bytes.get(range.start - offset..range.end.checked_sub(*offset)?)
+            })
+            .ok_or(DroppedRange { range })
+    }
+
+    /// Returns the bytes of a buffer view, if they are all retained.
+    pub fn view(&self, view: &View) -> Result<&[u8], DroppedRange> {
+        self.get(view.offset()..view.offset() + view.length())
+    }
+}
+
 impl<'a> Buffer<'a> {
     /// Constructs a `Buffer`.
     pub(crate) fn new(
@@ -74,16 +240,55 @@ impl<'a> Buffer<'a> {
         self.index
     }
 
+    /// Returns the buffer data source.
+    pub fn source(&self) -> Source<'a> {
+        match self.json.uri.as_deref() {
+            Some(uri) => Source::Uri(uri),
+            None => Source::Bin,
+        }
+    }
+
     /// The length of the buffer in bytes.
     pub fn length(&self) -> usize {
-        self.json.byte_length.0 as usize
+        crate::saturating_usize(self.json.byte_length.0)
+    }
+
+    /// Returns `true` if this is an `EXT_meshopt_compression` fallback buffer
+    /// without a URI.
+    ///
+    /// Such a buffer has no data of its own: [`import_buffers`] represents it
+    /// with empty data, and accessors that read it report
+    /// [`accessor::Error::MeshoptFallback`] until `get_buffer_data` provides
+    /// the decompressed data of its views.
+    ///
+    /// [`import_buffers`]: crate::import_buffers
+    /// [`accessor::Error::MeshoptFallback`]: crate::accessor::Error::MeshoptFallback
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+    pub fn is_meshopt_fallback(&self) -> bool {
+        self.json.uri.is_none()
+            && self
+                .json
+                .extensions
+                .as_ref()
+                .and_then(|ext| ext.meshopt_compression.as_ref())
+                .map_or(false, |meshopt| meshopt.fallback)
+    }
+
+    /// Returns `false` if the buffer has no data to load.
+    pub(crate) fn has_data(&self) -> bool {
+        #[cfg(feature = "EXT_meshopt_compression")]
+        if self.is_meshopt_fallback() {
+            return false;
+        }
+        true
     }
 
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
     }
 
     /// Returns extension data unknown to this crate version.
@@ -135,12 +340,12 @@ impl<'a> View<'a> {
 
     /// Returns the length of the buffer view in bytes.
     pub fn length(&self) -> usize {
-        self.json.byte_length.0 as usize
+        crate::saturating_usize(self.json.byte_length.0)
     }
 
     /// Returns the offset into the parent buffer in bytes.
     pub fn offset(&self) -> usize {
-        self.json.byte_offset.unwrap_or_default().0 as usize
+        crate::saturating_usize(self.json.byte_offset.unwrap_or_default().0)
     }
 
     /// Returns the stride in bytes between vertex attributes or other interleavable
@@ -161,7 +366,7 @@ impl<'a> View<'a> {
     #[cfg(feature = "names")]
     #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
     }
 
     /// Optional target the buffer should be bound to.
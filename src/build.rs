@@ -0,0 +1,304 @@
+//! [`DocumentBuilder`] assembles a document and its buffer data from vertex
+//! data, for assets generated at runtime.
+//!
+//! ```
+//! use gltf::build::{DocumentBuilder, Primitive};
+//!
+//! let mut builder = DocumentBuilder::new();
+//! let positions = builder.push_positions(&[[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+//! let indices = builder.push_indices(&[0, 1, 2]);
+//! let mesh = builder.push_mesh(vec![Primitive::new(positions).indices(indices)]);
+//! let node = builder.push_node(gltf::json::Node {
+//!     mesh: Some(mesh),
+//!     ..Default::default()
+//! });
+//! builder.push_scene(&[node]);
+//! let (document, buffers) = builder.build().unwrap();
+//! let glb = gltf::export::to_glb(&document, &buffers).unwrap();
+//! # assert_eq!(&glb[..4], b"glTF");
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use json::accessor::{ComponentType, GenericComponentType, Type};
+use json::buffer::Target;
+use json::validation::{Checked, USize64};
+use json::Index;
+
+use crate::mesh::{Mode, Semantic};
+use crate::{buffer, Document};
+
+/// A primitive to add with [`DocumentBuilder::push_mesh`].
+#[derive(Clone, Debug)]
+pub struct Primitive {
+    json: json::mesh::Primitive,
+}
+
+impl Primitive {
+    /// Starts a list of triangles with the given positions.
+    pub fn new(positions: Index<json::Accessor>) -> Self {
+        let mut attributes = BTreeMap::new();
+        attributes.insert(Checked::Valid(Semantic::Positions), positions);
+        Self {
+            json: json::mesh::Primitive {
+                attributes,
+                extensions: None,
+                extras: Default::default(),
+                indices: None,
+                material: None,
+                mode: Checked::Valid(Mode::Triangles),
+                targets: None,
+            },
+        }
+    }
+
+    /// Sets a vertex attribute, replacing any with the same semantic.
+    pub fn attribute(mut self, semantic: Semantic, accessor: Index<json::Accessor>) -> Self {
+        self.json
+            .attributes
+            .insert(Checked::Valid(semantic), accessor);
+        self
+    }
+
+    /// Sets the index accessor.
+    pub fn indices(mut self, accessor: Index<json::Accessor>) -> Self {
+        self.json.indices = Some(accessor);
+        self
+    }
+
+    /// Sets the material.
+    pub fn material(mut self, material: Index<json::Material>) -> Self {
+        self.json.material = Some(material);
+        self
+    }
+
+    /// Sets the topology.
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.json.mode = Checked::Valid(mode);
+        self
+    }
+}
+
+/// Builds a document and its buffer data.
+///
+/// Every `push` method returns the index of what it added, for later methods
+/// to refer to. The data of buffer views is appended to its buffer at 4-byte
+/// boundaries. The typed helpers such as [`push_positions`] write to the first
+/// buffer, which is created if none has been pushed.
+///
+/// [`push_positions`]: Self::push_positions
+#[derive(Clone, Debug, Default)]
+pub struct DocumentBuilder {
+    root: json::Root,
+    buffers: Vec<buffer::Data>,
+}
+
+impl DocumentBuilder {
+    /// Starts an empty glTF 2.0 document.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The document built so far, to add objects that the builder has no
+    /// methods for, such as materials or animations.
+    pub fn root_mut(&mut self) -> &mut json::Root {
+        &mut self.root
+    }
+
+    /// Adds a buffer without a URI, holding `data`.
+    pub fn push_buffer(&mut self, data: Vec<u8>) -> Index<json::Buffer> {
+        self.buffers.push(buffer::Data(data));
+        self.root.push(json::Buffer {
+            byte_length: USize64::from(self.buffers.last().unwrap().len()),
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+            extensions: None,
+            extras: Default::default(),
+        })
+    }
+
+    /// Appends `data` to a buffer and adds a buffer view over it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` was not pushed to this builder.
+    pub fn push_view(
+        &mut self,
+        buffer: Index<json::Buffer>,
+        data: &[u8],
+        target: Option<Target>,
+    ) -> Index<json::buffer::View> {
+        let bytes = &mut self.buffers[buffer.value()].0;
+        bytes.resize((bytes.len() + 3) / 4 * 4, 0);
+        let offset = bytes.len();
+        bytes.extend_from_slice(data);
+        self.root.buffers[buffer.value()].byte_length = USize64::from(bytes.len());
+        self.root.push(json::buffer::View {
+            buffer,
+            byte_length: USize64::from(data.len()),
+            byte_offset: Some(USize64::from(offset)),
+            byte_stride: None,
+            #[cfg(feature = "names")]
+            name: None,
+            target: target.map(Checked::Valid),
+            extensions: None,
+            extras: Default::default(),
+        })
+    }
+
+    /// Adds an accessor.
+    pub fn push_accessor(&mut self, accessor: json::Accessor) -> Index<json::Accessor> {
+        self.root.push(accessor)
+    }
+
+    /// Adds an accessor of `VEC3` float positions, with their bounds.
+    pub fn push_positions(&mut self, positions: &[[f32; 3]]) -> Index<json::Accessor> {
+        let mut bounds = None;
+        for &position in positions {
+            crate::mesh::extend_bounds(&mut bounds, position);
+        }
+        let data = floats(positions.iter().flatten());
+        let count = positions.len();
+        let index = self.push_elements(&data, count, ComponentType::F32, Type::Vec3);
+        if let Some(bounds) = bounds {
+            let accessor = &mut self.root.accessors[index.value()];
+            accessor.min = Some(json::Value::from(bounds.min.to_vec()));
+            accessor.max = Some(json::Value::from(bounds.max.to_vec()));
+        }
+        index
+    }
+
+    /// Adds an accessor of `VEC3` float normals.
+    pub fn push_normals(&mut self, normals: &[[f32; 3]]) -> Index<json::Accessor> {
+        let data = floats(normals.iter().flatten());
+        self.push_elements(&data, normals.len(), ComponentType::F32, Type::Vec3)
+    }
+
+    /// Adds an accessor of `VEC2` float texture co-ordinates.
+    pub fn push_tex_coords(&mut self, tex_coords: &[[f32; 2]]) -> Index<json::Accessor> {
+        let data = floats(tex_coords.iter().flatten());
+        self.push_elements(&data, tex_coords.len(), ComponentType::F32, Type::Vec2)
+    }
+
+    /// Adds an accessor of vertex indices, stored in the smallest component
+    /// type that can hold them without using its maximum value, which the
+    /// specification reserves for primitive restart.
+    pub fn push_indices(&mut self, indices: &[u32]) -> Index<json::Accessor> {
+        let max = indices.iter().copied().max().unwrap_or_default();
+        let (component_type, data): (_, Vec<u8>) = if max < u8::MAX as u32 {
+            let data = indices.iter().map(|&index| index as u8).collect();
+            (ComponentType::U8, data)
+        } else if max < u16::MAX as u32 {
+            let data = indices
+                .iter()
+                .flat_map(|&index| (index as u16).to_le_bytes())
+                .collect();
+            (ComponentType::U16, data)
+        } else {
+            let data = indices
+                .iter()
+                .flat_map(|index| index.to_le_bytes())
+                .collect();
+            (ComponentType::U32, data)
+        };
+        let index = self.push_elements(&data, indices.len(), component_type, Type::Scalar);
+        let view = self.root.accessors[index.value()].buffer_view.unwrap();
+        self.root.buffer_views[view.value()].target =
+            Some(Checked::Valid(Target::ElementArrayBuffer));
+        index
+    }
+
+    /// Adds a mesh of the given primitives.
+    pub fn push_mesh(
+        &mut self,
+        primitives: impl IntoIterator<Item = Primitive>,
+    ) -> Index<json::Mesh> {
+        self.root.push(json::Mesh {
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: None,
+            primitives: primitives
+                .into_iter()
+                .map(|primitive| primitive.json)
+                .collect(),
+            weights: None,
+        })
+    }
+
+    /// Adds a node.
+    pub fn push_node(&mut self, node: json::Node) -> Index<json::Node> {
+        self.root.push(node)
+    }
+
+    /// Makes a node a child of another.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parent` was not pushed to this builder.
+    pub fn add_child(&mut self, parent: Index<json::Node>, child: Index<json::Node>) {
+        self.root.nodes[parent.value()]
+            .children
+            .get_or_insert_with(Vec::new)
+            .push(child);
+    }
+
+    /// Adds a scene of the given root nodes, which becomes the default scene
+    /// if it is the first.
+    pub fn push_scene(&mut self, nodes: &[Index<json::Node>]) -> Index<json::Scene> {
+        let scene = self.root.push(json::Scene {
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: None,
+            nodes: nodes.to_vec(),
+        });
+        self.root.scene.get_or_insert(scene);
+        scene
+    }
+
+    /// Validates the document and returns it with its buffer data, in the
+    /// form that [`export::to_glb`] takes.
+    ///
+    /// [`export::to_glb`]: crate::export::to_glb
+    pub fn build(self) -> crate::Result<(Document, Vec<buffer::Data>)> {
+        Ok((Document::from_json(self.root)?, self.buffers))
+    }
+
+    /// Adds a view of vertex attribute `data` in the first buffer and an
+    /// accessor of tightly packed elements over it.
+    fn push_elements(
+        &mut self,
+        data: &[u8],
+        count: usize,
+        component_type: ComponentType,
+        type_: Type,
+    ) -> Index<json::Accessor> {
+        if self.root.buffers.is_empty() {
+            self.push_buffer(Vec::new());
+        }
+        let view = self.push_view(Index::new(0), data, Some(Target::ArrayBuffer));
+        self.root.push(json::Accessor {
+            buffer_view: Some(view),
+            byte_offset: None,
+            count: USize64::from(count),
+            component_type: Checked::Valid(GenericComponentType(component_type)),
+            extensions: None,
+            extras: Default::default(),
+            type_: Checked::Valid(type_),
+            min: None,
+            max: None,
+            #[cfg(feature = "names")]
+            name: None,
+            normalized: false,
+            sparse: None,
+        })
+    }
+}
+
+/// Encodes floats as little-endian bytes.
+fn floats<'a>(values: impl Iterator<Item = &'a f32>) -> Vec<u8> {
+    values.flat_map(|value| value.to_le_bytes()).collect()
+}
@@ -1,4 +1,5 @@
 use alloc::string::String;
+use core::intrinsics::{cosf32, sinf32};
 use crate::Document;
 
 #[cfg(feature = "extensions")]
@@ -73,7 +74,7 @@ impl<'a> Camera<'a> {
     #[cfg(feature = "names")]
     #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
     }
 
     /// Returns the camera's projection.
@@ -112,6 +113,134 @@ impl<'a> Camera<'a> {
     }
 }
 
+/// The distance, as a multiple of the near distance, at which
+/// [`Projection::frustum_corners`] places an infinite far plane.
+pub const INFINITE_FAR_FACTOR: f32 = 100.0;
+
+impl<'a> Projection<'a> {
+    /// Returns the corners of the view frustum in camera space, where the
+    /// camera looks down the negative Z axis with positive Y up.
+    ///
+    /// The near corners come first, followed by the far corners, each in the
+    /// order bottom left, bottom right, top right, top left, as connected by
+    /// [`frustum_lines`].
+    ///
+    /// `aspect` is the viewport aspect ratio, which is used by perspective
+    /// projections that do not declare one. `near_override` and
+    /// `far_override` replace the clipping plane distances of the projection.
+    /// An infinite far plane without an override is placed at
+    /// [`INFINITE_FAR_FACTOR`] times the near distance.
+    pub fn frustum_corners(
+        &self,
+        aspect: f32,
+        near_override: Option<f32>,
+        far_override: Option<f32>,
+    ) -> [[f32; 3]; 8] {
+        let (near, far, near_half, far_half) = match *self {
+            Projection::Orthographic(ref orthographic) => {
+                let near = near_override.unwrap_or_else(|| orthographic.znear());
+                let far = far_override.unwrap_or_else(|| orthographic.zfar());
+                let half = [orthographic.xmag(), orthographic.ymag()];
+                (near, far, half, half)
+            }
+            Projection::Perspective(ref perspective) => {
+                let near = near_override.unwrap_or_else(|| perspective.znear());
+                let far = far_override
+                    .or_else(|| perspective.zfar())
+                    .unwrap_or(near * INFINITE_FAR_FACTOR);
+                let half_angle = perspective.yfov() * 0.5;
+                let tan = sinf32(half_angle) / cosf32(half_angle);
+                let aspect = perspective.aspect_ratio().unwrap_or(aspect);
+                let half = |distance: f32| [distance * tan * aspect, distance * tan];
+                (near, far, half(near), half(far))
+            }
+        };
+        let mut corners = [[0.0; 3]; 8];
+        let planes = [(near, near_half), (far, far_half)];
+        for (plane, &(distance, [x, y])) in planes.iter().enumerate() {
+            let quad = [[-x, -y], [x, -y], [x, y], [-x, y]];
+            for (corner, [x, y]) in quad.iter().enumerate() {
+                corners[plane * 4 + corner] = [*x, *y, -distance];
+            }
+        }
+        corners
+    }
+}
+
+/// Returns the index pairs of [`Projection::frustum_corners`] that form the
+/// edges of the frustum: the near rectangle, the far rectangle and the four
+/// edges joining them.
+pub fn frustum_lines() -> [[u32; 2]; 12] {
+    [
+        [0, 1],
+        [1, 2],
+        [2, 3],
+        [3, 0],
+        [4, 5],
+        [5, 6],
+        [6, 7],
+        [7, 4],
+        [0, 4],
+        [1, 5],
+        [2, 6],
+        [3, 7],
+    ]
+}
+
+/// Returns the view matrix of a camera placed by a node with the given world
+/// transform, in column-major order.
+///
+/// The view matrix is the inverse of the world transform. It is computed as
+/// the inverse of an affine transform, which is exact for any composition of
+/// translations, rotations and scales, including the shear that non-uniform
+/// scales introduce in a hierarchy. When the transform is not invertible,
+/// such as when a node is scaled by zero, each degenerate axis is dropped
+/// instead of producing non-finite values.
+pub fn view_matrix(world: [[f32; 4]; 4]) -> [[f32; 4]; 4] {
+    let [x, y, z, w] = world;
+    let cross = |a: [f32; 4], b: [f32; 4]| {
+        [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ]
+    };
+    let dot = |a: [f32; 3], b: [f32; 4]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let length_squared = |a: [f32; 4]| dot([a[0], a[1], a[2]], a);
+
+    // The rows of the inverse of the upper 3x3 block are the rows of its
+    // adjugate divided by its determinant. The determinant is compared with
+    // the product of the axis lengths, so that the test does not depend on
+    // the overall scale of the transform.
+    let adjugate = [cross(y, z), cross(z, x), cross(x, y)];
+    let determinant = dot(adjugate[0], x);
+    let volume = length_squared(x) * length_squared(y) * length_squared(z);
+    let rows = if determinant * determinant > volume * 1e-12 && volume > 0.0 {
+        adjugate.map(|row| row.map(|e| e / determinant))
+    } else {
+        // Invert the axes independently, as if they were orthogonal, dropping
+        // those of zero length.
+        [x, y, z].map(|axis| {
+            let length = length_squared(axis);
+            if length > 0.0 {
+                [axis[0] / length, axis[1] / length, axis[2] / length]
+            } else {
+                [0.0; 3]
+            }
+        })
+    };
+
+    let mut view = [[0.0; 4]; 4];
+    for (i, row) in rows.iter().enumerate() {
+        for (j, &e) in row.iter().enumerate() {
+            view[j][i] = e;
+        }
+        view[3][i] = -dot(*row, w);
+    }
+    view[3][3] = 1.0;
+    view
+}
+
 impl<'a> Orthographic<'a> {
     /// Constructs a `Orthographic` camera projection.
     pub(crate) fn new(document: &'a Document, json: &'a json::camera::Orthographic) -> Self {
@@ -207,3 +336,80 @@ impl<'a> Perspective<'a> {
         &self.json.extras
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GLTF: &str = r#"{
+        "asset": {"version": "2.0"},
+        "cameras": [
+            {"type": "perspective",
+             "perspective": {"yfov": 1.5707964, "znear": 1, "zfar": 10, "aspectRatio": 2}},
+            {"type": "perspective", "perspective": {"yfov": 1.5707964, "znear": 0.5}},
+            {"type": "orthographic",
+             "orthographic": {"xmag": 3, "ymag": 2, "znear": 0, "zfar": 5}}
+        ]
+    }"#;
+
+    fn corners(index: usize, aspect: f32, near: Option<f32>, far: Option<f32>) -> [[f32; 3]; 8] {
+        let document = crate::Gltf::from_slice(GLTF.as_bytes()).unwrap().document;
+        let camera = document.cameras().nth(index).unwrap();
+        camera.projection().frustum_corners(aspect, near, far)
+    }
+
+    fn assert_points(actual: &[[f32; 3]], expected: &[[f32; 3]]) {
+        assert_eq!(actual.len(), expected.len());
+        for (actual, expected) in actual.iter().zip(expected.iter()) {
+            for (a, e) in actual.iter().zip(expected.iter()) {
+                assert_relative_eq!(a, e, epsilon = 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn perspective_frustum() {
+        // A 90 degree field of view spans the near distance either way.
+        assert_points(
+            &corners(0, 1.0, None, None),
+            &[
+                [-2.0, -1.0, -1.0],
+                [2.0, -1.0, -1.0],
+                [2.0, 1.0, -1.0],
+                [-2.0, 1.0, -1.0],
+                [-20.0, -10.0, -10.0],
+                [20.0, -10.0, -10.0],
+                [20.0, 10.0, -10.0],
+                [-20.0, 10.0, -10.0],
+            ],
+        );
+
+        // The viewport aspect is used without a declared one, and an infinite
+        // far plane is placed at a multiple of the near distance.
+        let far = 0.5 * INFINITE_FAR_FACTOR;
+        let actual = corners(1, 1.5, None, None);
+        assert_points(
+            &[actual[0], actual[6]],
+            &[[-0.75, -0.5, -0.5], [1.5 * far, far, -far]],
+        );
+        assert_relative_eq!(corners(1, 1.0, Some(2.0), Some(4.0))[6][2], -4.0);
+    }
+
+    #[test]
+    fn orthographic_frustum() {
+        let actual = corners(2, 9.0, None, Some(8.0));
+        assert_eq!(actual[0], [-3.0, -2.0, 0.0]);
+        assert_eq!(actual[6], [3.0, 2.0, -8.0]);
+    }
+
+    #[test]
+    fn lines_cover_each_edge_once() {
+        let mut degree = [0; 8];
+        for [a, b] in frustum_lines().iter() {
+            assert_ne!(a, b);
+            degree[*a as usize] += 1;
+            degree[*b as usize] += 1;
+        }
+        assert_eq!(degree, [3; 8]);
+    }
+}
@@ -0,0 +1,750 @@
+use alloc::string::String;
+use core::{fmt, str};
+
+use crate::binary;
+
+/// Bounds enforced by [`Gltf::check`](crate::Gltf::check).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CheckLimits {
+    /// The maximum nesting depth of JSON arrays and objects.
+    ///
+    /// The check recurses once per level, so this also bounds its stack use.
+    pub max_depth: usize,
+
+    /// The maximum length of each top-level array, such as `nodes`.
+    pub max_array_length: usize,
+
+    /// The maximum sum of the declared `byteLength` of all buffers.
+    pub max_buffer_bytes: u64,
+
+    /// The maximum sum of the sizes of the images embedded in `data:` URIs.
+    pub max_image_bytes: u64,
+}
+
+impl Default for CheckLimits {
+    /// Limits the depth to 128, like the JSON parser, and nothing else.
+    fn default() -> Self {
+        Self {
+            max_depth: 128,
+            max_array_length: usize::MAX,
+            max_buffer_bytes: u64::MAX,
+            max_image_bytes: u64::MAX,
+        }
+    }
+}
+
+/// The lengths of the top-level arrays of a glTF document.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ArrayLengths {
+    pub accessors: usize,
+    pub animations: usize,
+    pub buffers: usize,
+    pub buffer_views: usize,
+    pub cameras: usize,
+    pub images: usize,
+    pub materials: usize,
+    pub meshes: usize,
+    pub nodes: usize,
+    pub samplers: usize,
+    pub scenes: usize,
+    pub skins: usize,
+    pub textures: usize,
+}
+
+/// The names of the top-level arrays.
+const ARRAYS: [&str; 13] = [
+    "accessors",
+    "animations",
+    "buffers",
+    "bufferViews",
+    "cameras",
+    "images",
+    "materials",
+    "meshes",
+    "nodes",
+    "samplers",
+    "scenes",
+    "skins",
+    "textures",
+];
+
+impl ArrayLengths {
+    /// Returns the length of the named top-level array.
+    fn get_mut(&mut self, name: &str) -> &mut usize {
+        match name {
+            "accessors" => &mut self.accessors,
+            "animations" => &mut self.animations,
+            "buffers" => &mut self.buffers,
+            "bufferViews" => &mut self.buffer_views,
+            "cameras" => &mut self.cameras,
+            "images" => &mut self.images,
+            "materials" => &mut self.materials,
+            "meshes" => &mut self.meshes,
+            "nodes" => &mut self.nodes,
+            "samplers" => &mut self.samplers,
+            "scenes" => &mut self.scenes,
+            "skins" => &mut self.skins,
+            _ => &mut self.textures,
+        }
+    }
+}
+
+/// The facts gathered by [`Gltf::check`](crate::Gltf::check).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CheckSummary {
+    /// Whether the slice is binary glTF.
+    pub binary: bool,
+
+    /// The length of the JSON, which is the whole slice for text glTF.
+    pub json_length: usize,
+
+    /// The length of the `BIN` chunk of binary glTF, if present.
+    pub bin_length: Option<usize>,
+
+    /// The major and minor numbers of `asset.version`.
+    pub version: (u32, u32),
+
+    /// The length of `extensionsUsed`.
+    pub extensions_used: usize,
+
+    /// The length of `extensionsRequired`.
+    pub extensions_required: usize,
+
+    /// The lengths of the top-level arrays.
+    pub lengths: ArrayLengths,
+
+    /// The sum of the declared `byteLength` of all buffers.
+    pub buffer_bytes: u64,
+
+    /// The sum of the sizes of the images embedded in `data:` URIs.
+    ///
+    /// Images stored in buffer views are part of `buffer_bytes` instead.
+    pub image_bytes: u64,
+}
+
+/// Reasons for [`Gltf::check`](crate::Gltf::check) to reject an asset.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CheckError {
+    /// The JSON is malformed at the given byte offset.
+    Syntax {
+        /// The offset of the offending byte within the JSON.
+        offset: usize,
+    },
+
+    /// A value has an unexpected type or content.
+    InvalidValue {
+        /// The offset of the value within the JSON.
+        offset: usize,
+    },
+
+    /// JSON containers are nested deeper than allowed.
+    Depth {
+        /// The maximum depth.
+        limit: usize,
+    },
+
+    /// `asset.version` is missing.
+    MissingVersion,
+
+    /// `asset.version` or `asset.minVersion` is not a glTF 2.0 version.
+    UnsupportedVersion {
+        /// The major version number.
+        major: u32,
+
+        /// The minor version number.
+        minor: u32,
+    },
+
+    /// An extension in `extensionsRequired` is not supported by this build.
+    UnsupportedExtension(String),
+
+    /// A top-level array is longer than allowed.
+    ArrayLength {
+        /// The name of the array.
+        name: &'static str,
+
+        /// The maximum length.
+        limit: usize,
+    },
+
+    /// The declared buffer sizes exceed the limit.
+    BufferBytes {
+        /// The maximum sum of buffer sizes.
+        limit: u64,
+    },
+
+    /// The embedded image sizes exceed the limit.
+    ImageBytes {
+        /// The maximum sum of image sizes.
+        limit: u64,
+    },
+}
+
+impl fmt::Display for CheckError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckError::Syntax { offset } => write!(f, "malformed JSON at byte {}", offset),
+            CheckError::InvalidValue { offset } => {
+                write!(f, "invalid value at byte {} of the JSON", offset)
+            }
+            CheckError::Depth { limit } => {
+                write!(f, "JSON is nested deeper than {} levels", limit)
+            }
+            CheckError::MissingVersion => write!(f, "missing asset version"),
+            CheckError::UnsupportedVersion { major, minor } => {
+                write!(f, "unsupported glTF version {}.{}", major, minor)
+            }
+            CheckError::UnsupportedExtension(name) => {
+                write!(f, "required extension {} is not supported", name)
+            }
+            CheckError::ArrayLength { name, limit } => {
+                write!(f, "{} has more than {} elements", name, limit)
+            }
+            CheckError::BufferBytes { limit } => {
+                write!(f, "buffers declare more than {} bytes", limit)
+            }
+            CheckError::ImageBytes { limit } => {
+                write!(f, "embedded images exceed {} bytes", limit)
+            }
+        }
+    }
+}
+
+impl core::error::Error for CheckError {}
+
+type Result<T> = core::result::Result<T, CheckError>;
+
+/// Decodes the characters of a well-formed JSON string without its quotes.
+#[derive(Clone)]
+struct Chars<'j> {
+    rest: &'j str,
+}
+
+impl<'j> Iterator for Chars<'j> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let mut chars = self.rest.chars();
+        let c = match chars.next()? {
+            '\\' => match chars.next()? {
+                'b' => '\u{8}',
+                'f' => '\u{c}',
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'u' => {
+                    let rest = chars.as_str();
+                    let high = u32::from_str_radix(&rest[..4], 16).ok()?;
+                    chars = rest[4..].chars();
+                    let rest = chars.as_str();
+                    let low = rest
+                        .strip_prefix("\\u")
+                        .and_then(|x| u32::from_str_radix(x.get(..4)?, 16).ok());
+                    match low {
+                        Some(low @ 0xdc00..=0xdfff) if (0xd800..0xdc00).contains(&high) => {
+                            chars = rest[6..].chars();
+                            let code = 0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00);
+                            char::from_u32(code)?
+                        }
+                        _ => char::from_u32(high).unwrap_or(char::REPLACEMENT_CHARACTER),
+                    }
+                }
+                c => c,
+            },
+            c => c,
+        };
+        self.rest = chars.as_str();
+        Some(c)
+    }
+}
+
+/// A validating scanner over JSON text that visits values in place.
+pub(crate) struct Scanner<'j> {
+    json: &'j [u8],
+    max_depth: usize,
+}
+
+impl<'j> Scanner<'j> {
+    pub(crate) fn new(json: &'j [u8], max_depth: usize) -> Self {
+        Self { json, max_depth }
+    }
+
+    pub(crate) fn skip_ws(&self, mut pos: usize) -> usize {
+        while matches!(self.json.get(pos), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            pos += 1;
+        }
+        pos
+    }
+
+    /// Skips whitespace and the given byte.
+    fn expect(&self, pos: usize, byte: u8) -> Result<usize> {
+        let pos = self.skip_ws(pos);
+        match self.json.get(pos) {
+            Some(&x) if x == byte => Ok(pos + 1),
+            _ => Err(CheckError::Syntax { offset: pos }),
+        }
+    }
+
+    fn digits(&self, mut pos: usize) -> usize {
+        while matches!(self.json.get(pos), Some(b'0'..=b'9')) {
+            pos += 1;
+        }
+        pos
+    }
+
+    /// Scans the string starting at `pos`, returning its contents without
+    /// quotes and the position after it.
+    fn string(&self, pos: usize) -> Result<(&'j str, usize)> {
+        if self.json.get(pos) != Some(&b'"') {
+            return Err(CheckError::Syntax { offset: pos });
+        }
+        let mut i = pos + 1;
+        loop {
+            match self.json.get(i) {
+                Some(b'"') => break,
+                Some(b'\\') => match self.json.get(i + 1) {
+                    Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => i += 2,
+                    Some(b'u') => {
+                        let hex = self.json.get(i + 2..i + 6);
+                        if !hex.map_or(false, |x| x.iter().all(u8::is_ascii_hexdigit)) {
+                            return Err(CheckError::Syntax { offset: i });
+                        }
+                        i += 6;
+                    }
+                    _ => return Err(CheckError::Syntax { offset: i }),
+                },
+                Some(0..=0x1f) | None => return Err(CheckError::Syntax { offset: i }),
+                Some(_) => i += 1,
+            }
+        }
+        let contents = str::from_utf8(&self.json[pos + 1..i]).map_err(|e| CheckError::Syntax {
+            offset: pos + 1 + e.valid_up_to(),
+        })?;
+        Ok((contents, i + 1))
+    }
+
+    /// Scans the number starting at `pos`, returning the position after it.
+    fn number(&self, pos: usize) -> Result<usize> {
+        let mut i = pos;
+        if self.json.get(i) == Some(&b'-') {
+            i += 1;
+        }
+        match self.json.get(i) {
+            Some(b'0') => i += 1,
+            Some(b'1'..=b'9') => i = self.digits(i),
+            _ => return Err(CheckError::Syntax { offset: i }),
+        }
+        if self.json.get(i) == Some(&b'.') {
+            let end = self.digits(i + 1);
+            if end == i + 1 {
+                return Err(CheckError::Syntax { offset: end });
+            }
+            i = end;
+        }
+        if matches!(self.json.get(i), Some(b'e' | b'E')) {
+            i += 1;
+            if matches!(self.json.get(i), Some(b'+' | b'-')) {
+                i += 1;
+            }
+            let end = self.digits(i);
+            if end == i {
+                return Err(CheckError::Syntax { offset: end });
+            }
+            i = end;
+        }
+        Ok(i)
+    }
+
+    /// Scans the value starting at `pos`, returning the position after it.
+    pub(crate) fn skip(&self, pos: usize, depth: usize) -> Result<usize> {
+        let literal = |text: &[u8]| match self.json.get(pos..pos + text.len()) {
+            Some(x) if x == text => Ok(pos + text.len()),
+            _ => Err(CheckError::Syntax { offset: pos }),
+        };
+        match self.json.get(pos) {
+            Some(b'"') => self.string(pos).map(|(_, end)| end),
+            Some(b'[') => self.array(pos, depth, |scanner, start, _| {
+                scanner.skip(start, depth + 1)
+            }),
+            Some(b'{') => self.object(pos, depth, |scanner, _, start| {
+                scanner.skip(start, depth + 1)
+            }),
+            Some(b't') => literal(b"true"),
+            Some(b'f') => literal(b"false"),
+            Some(b'n') => literal(b"null"),
+            _ => self.number(pos),
+        }
+    }
+
+    fn enter(&self, depth: usize) -> Result<()> {
+        if depth >= self.max_depth {
+            Err(CheckError::Depth {
+                limit: self.max_depth,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Scans the array starting at `pos`, calling `element` with the start
+    /// and index of each element, which must return the position after it.
+    pub(crate) fn array<F, E>(
+        &self,
+        pos: usize,
+        depth: usize,
+        mut element: F,
+    ) -> core::result::Result<usize, E>
+    where
+        F: FnMut(&Self, usize, usize) -> core::result::Result<usize, E>,
+        E: From<CheckError>,
+    {
+        if self.json.get(pos) != Some(&b'[') {
+            return Err(CheckError::InvalidValue { offset: pos }.into());
+        }
+        self.enter(depth)?;
+        let mut i = self.skip_ws(pos + 1);
+        if self.json.get(i) == Some(&b']') {
+            return Ok(i + 1);
+        }
+        let mut index = 0;
+        loop {
+            i = self.skip_ws(element(self, i, index)?);
+            index += 1;
+            match self.json.get(i) {
+                Some(b',') => i = self.skip_ws(i + 1),
+                Some(b']') => return Ok(i + 1),
+                _ => return Err(CheckError::Syntax { offset: i }.into()),
+            }
+        }
+    }
+
+    /// Scans the object starting at `pos`, calling `member` with the key as
+    /// written, without quotes and with its escape sequences intact, and the
+    /// start of the value of each member, which must return the position
+    /// after the value.
+    pub(crate) fn members<F, E>(
+        &self,
+        pos: usize,
+        depth: usize,
+        mut member: F,
+    ) -> core::result::Result<usize, E>
+    where
+        F: FnMut(&Self, &'j str, usize) -> core::result::Result<usize, E>,
+        E: From<CheckError>,
+    {
+        if self.json.get(pos) != Some(&b'{') {
+            return Err(CheckError::InvalidValue { offset: pos }.into());
+        }
+        self.enter(depth)?;
+        let mut i = self.skip_ws(pos + 1);
+        if self.json.get(i) == Some(&b'}') {
+            return Ok(i + 1);
+        }
+        loop {
+            let (key, end) = self.string(i)?;
+            let start = self.skip_ws(self.expect(end, b':')?);
+            i = self.skip_ws(member(self, key, start)?);
+            match self.json.get(i) {
+                Some(b',') => i = self.skip_ws(i + 1),
+                Some(b'}') => return Ok(i + 1),
+                _ => return Err(CheckError::Syntax { offset: i }.into()),
+            }
+        }
+    }
+
+    /// Scans the object starting at `pos`, calling `member` with the decoded
+    /// key and the start of the value of each member, which must return the
+    /// position after the value.
+    pub(crate) fn object<F>(&self, pos: usize, depth: usize, mut member: F) -> Result<usize>
+    where
+        F: FnMut(&Self, &str, usize) -> Result<usize>,
+    {
+        self.members(pos, depth, |scanner, key, start| {
+            let mut buffer = [0; 32];
+            member(scanner, unescape_key(key, &mut buffer), start)
+        })
+    }
+
+    /// Scans the string value starting at `pos`, returning its decoded
+    /// characters and the position after it.
+    fn string_value(&self, pos: usize) -> Result<(Chars<'j>, usize)> {
+        if self.json.get(pos) != Some(&b'"') {
+            return Err(CheckError::InvalidValue { offset: pos });
+        }
+        let (rest, end) = self.string(pos)?;
+        Ok((Chars { rest }, end))
+    }
+
+    /// Scans the non-negative integer starting at `pos`.
+    fn integer(&self, pos: usize) -> Result<(u64, usize)> {
+        if !matches!(self.json.get(pos), Some(b'0'..=b'9')) {
+            return Err(CheckError::InvalidValue { offset: pos });
+        }
+        let end = self.number(pos)?;
+        let digits = &self.json[pos..end];
+        let mut value = 0u64;
+        for &digit in digits {
+            value = match digit {
+                b'0'..=b'9' => value
+                    .checked_mul(10)
+                    .and_then(|x| x.checked_add(u64::from(digit - b'0'))),
+                _ => None,
+            }
+            .ok_or(CheckError::InvalidValue { offset: pos })?;
+        }
+        Ok((value, end))
+    }
+}
+
+/// Returns an object key with its escape sequences decoded into `buffer`.
+///
+/// Keys longer than the buffer are returned empty, since they cannot match
+/// any of the keys that are checked.
+fn unescape_key<'k>(key: &'k str, buffer: &'k mut [u8; 32]) -> &'k str {
+    if !key.contains('\\') {
+        return key;
+    }
+    let mut length = 0;
+    for c in (Chars { rest: key }) {
+        match buffer.get_mut(length..length + c.len_utf8()) {
+            Some(bytes) => length += c.encode_utf8(bytes).len(),
+            None => return "",
+        }
+    }
+    str::from_utf8(&buffer[..length]).unwrap_or("")
+}
+
+/// Parses a `major.minor` version string.
+fn version(chars: Chars, offset: usize) -> Result<(u32, u32)> {
+    let mut numbers = [0u32; 2];
+    let mut part = 0;
+    let mut empty = true;
+    for c in chars {
+        match c {
+            '.' if part == 0 && !empty => {
+                part = 1;
+                empty = true;
+            }
+            '0'..='9' => {
+                numbers[part] = numbers[part]
+                    .checked_mul(10)
+                    .and_then(|x| x.checked_add(c as u32 - '0' as u32))
+                    .ok_or(CheckError::InvalidValue { offset })?;
+                empty = false;
+            }
+            _ => return Err(CheckError::InvalidValue { offset }),
+        }
+    }
+    if part == 0 || empty {
+        return Err(CheckError::InvalidValue { offset });
+    }
+    Ok((numbers[0], numbers[1]))
+}
+
+/// Returns the size of the data of a `data:` URI, or zero for other URIs.
+fn data_uri_size(uri: &str) -> u64 {
+    let rest = match uri.strip_prefix("data:") {
+        Some(rest) => rest,
+        None => return 0,
+    };
+    let (header, payload) = match rest.find(',') {
+        Some(comma) => (&rest[..comma], &rest[comma + 1..]),
+        None => return 0,
+    };
+    let characters = Chars { rest: payload }
+        .filter(|c| !matches!(c, '=' | ' ' | '\t' | '\n' | '\r'))
+        .count() as u64;
+    if header.ends_with(";base64") {
+        characters * 3 / 4
+    } else {
+        characters
+    }
+}
+
+fn supported(name: &Chars) -> bool {
+    json::extensions::SUPPORTED
+        .iter()
+        .any(|supported| name.clone().eq(supported.chars()))
+}
+
+/// Checks the JSON of a glTF asset against `limits`, filling in the JSON
+/// fields of `summary`.
+fn check_json(json: &[u8], limits: &CheckLimits, summary: &mut CheckSummary) -> Result<()> {
+    let scanner = Scanner::new(json, limits.max_depth);
+    let mut version_found = false;
+    let start = scanner.skip_ws(0);
+    let end = scanner.object(start, 0, |scanner, key, pos| match key {
+        "asset" => scanner.object(pos, 1, |scanner, key, pos| match key {
+            "version" | "minVersion" => {
+                let (chars, end) = scanner.string_value(pos)?;
+                let (major, minor) = version(chars, pos)?;
+                let supported = major == 2 && (key == "version" || minor == 0);
+                if !supported {
+                    return Err(CheckError::UnsupportedVersion { major, minor });
+                }
+                if key == "version" {
+                    summary.version = (major, minor);
+                    version_found = true;
+                }
+                Ok(end)
+            }
+            _ => scanner.skip(pos, 2),
+        }),
+        "extensionsUsed" => scanner.array(pos, 1, |scanner, pos, _| {
+            summary.extensions_used += 1;
+            scanner.string_value(pos).map(|(_, end)| end)
+        }),
+        "extensionsRequired" => scanner.array(pos, 1, |scanner, pos, _| {
+            summary.extensions_required += 1;
+            let (name, end) = scanner.string_value(pos)?;
+            if supported(&name) {
+                Ok(end)
+            } else {
+                Err(CheckError::UnsupportedExtension(name.collect()))
+            }
+        }),
+        _ => {
+            let name = match ARRAYS.iter().find(|&&name| name == key) {
+                Some(&name) => name,
+                None => return scanner.skip(pos, 1),
+            };
+            let mut length = 0;
+            let end = scanner.array(pos, 1, |scanner, pos, index| {
+                length = index + 1;
+                if index >= limits.max_array_length {
+                    return Err(CheckError::ArrayLength {
+                        name,
+                        limit: limits.max_array_length,
+                    });
+                }
+                match name {
+                    "buffers" => scanner.object(pos, 2, |scanner, key, pos| match key {
+                        "byteLength" => {
+                            let (length, end) = scanner.integer(pos)?;
+                            summary.buffer_bytes = summary.buffer_bytes.saturating_add(length);
+                            if summary.buffer_bytes > limits.max_buffer_bytes {
+                                return Err(CheckError::BufferBytes {
+                                    limit: limits.max_buffer_bytes,
+                                });
+                            }
+                            Ok(end)
+                        }
+                        _ => scanner.skip(pos, 3),
+                    }),
+                    "images" => scanner.object(pos, 2, |scanner, key, pos| match key {
+                        "uri" => {
+                            let (uri, end) = scanner.string_value(pos)?;
+                            let size = data_uri_size(uri.rest);
+                            summary.image_bytes = summary.image_bytes.saturating_add(size);
+                            if summary.image_bytes > limits.max_image_bytes {
+                                return Err(CheckError::ImageBytes {
+                                    limit: limits.max_image_bytes,
+                                });
+                            }
+                            Ok(end)
+                        }
+                        _ => scanner.skip(pos, 3),
+                    }),
+                    _ => scanner.skip(pos, 2),
+                }
+            })?;
+            *summary.lengths.get_mut(name) = length;
+            Ok(end)
+        }
+    })?;
+    let end = scanner.skip_ws(end);
+    if end != json.len() {
+        return Err(CheckError::Syntax { offset: end });
+    }
+    if !version_found {
+        return Err(CheckError::MissingVersion);
+    }
+    Ok(())
+}
+
+pub(crate) fn check(slice: &[u8], limits: &CheckLimits) -> crate::Result<CheckSummary> {
+    let mut summary = CheckSummary::default();
+    let json = if slice.starts_with(b"glTF") {
+        let glb = binary::Glb::from_slice(slice)?;
+        summary.binary = true;
+        summary.bin_length = glb.bin.as_ref().map(|bin| bin.len());
+        glb.json
+    } else {
+        slice.into()
+    };
+    summary.json_length = json.len();
+    check_json(&json, limits, &mut summary).map_err(crate::Error::Check)?;
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    fn check_str(json: &str) -> Result<CheckSummary> {
+        let mut summary = CheckSummary::default();
+        check_json(json.as_bytes(), &CheckLimits::default(), &mut summary)?;
+        Ok(summary)
+    }
+
+    #[test]
+    fn escaped_strings() {
+        let chars = Chars {
+            rest: r#"a\"\n\u00e9\ud83d\ude00\/"#,
+        };
+        assert!(chars.eq("a\"\né😀/".chars()));
+        let mut buffer = [0; 32];
+        assert_eq!(unescape_key(r"bu\u0066fers", &mut buffer), "buffers");
+        assert_eq!(unescape_key("buffers", &mut buffer), "buffers");
+        assert_eq!(unescape_key(&"\\u0061".repeat(33), &mut buffer), "");
+    }
+
+    #[test]
+    fn versions() {
+        let version = |text: &str| super::version(Chars { rest: text }, 0);
+        assert_eq!(version("2.0"), Ok((2, 0)));
+        assert_eq!(version("2.10"), Ok((2, 10)));
+        for invalid in ["2", "2.", ".0", "2.0.1", "x.0", "99999999999.0"] {
+            assert_eq!(
+                version(invalid),
+                Err(CheckError::InvalidValue { offset: 0 })
+            );
+        }
+    }
+
+    #[test]
+    fn data_uris() {
+        assert_eq!(data_uri_size("data:image/png;base64,AAAA\\/\\/8="), 5);
+        assert_eq!(data_uri_size("data:,hello"), 5);
+        assert_eq!(data_uri_size("image.png"), 0);
+    }
+
+    #[test]
+    fn syntax() {
+        let asset = r#""asset": {"version": "2.0"}"#;
+        let malformed = [
+            (format!("{{{}", asset), 28),
+            (format!("{{{},}}", asset), 29),
+            (format!("{{{}, \"x\": 01}}", asset), 36),
+            (format!("{{{}, \"x\": [1 2]}}", asset), 38),
+            (format!("{{{}, \"x\": \"\\q\"}}", asset), 36),
+            (format!("{{{}, \"x\": tru}}", asset), 35),
+            (format!("{{{}}} {{}}", asset), 30),
+        ];
+        for (json, offset) in &malformed {
+            assert_eq!(
+                check_str(json),
+                Err(CheckError::Syntax { offset: *offset }),
+                "{}",
+                json
+            );
+        }
+        let valid = format!(
+            "{{ {}, \"extras\": [-1.5e3, 0, true, null, {{\"\\u00e9\": []}}] }}",
+            asset
+        );
+        assert_eq!(check_str(&valid).unwrap().version, (2, 0));
+    }
+}
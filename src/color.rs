@@ -0,0 +1,80 @@
+//! Per the glTF 2.0 specification, material factors are always linear while
+//! the base color and emissive textures are sRGB encoded. The remaining core
+//! textures (metallic-roughness, normal and occlusion) contain linear data.
+
+use core::intrinsics::powf32;
+
+/// Converts an sRGB encoded channel value to linear using the sRGB
+/// electro-optical transfer function.
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        powf32((value + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Converts a linear channel value to sRGB using the inverse of the sRGB
+/// electro-optical transfer function.
+pub fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * powf32(value, 1.0 / 2.4) - 0.055
+    }
+}
+
+/// Converts an sRGB encoded RGB color to linear.
+pub fn srgb_to_linear_rgb(color: [f32; 3]) -> [f32; 3] {
+    color.map(srgb_to_linear)
+}
+
+/// Converts a linear RGB color to sRGB.
+pub fn linear_to_srgb_rgb(color: [f32; 3]) -> [f32; 3] {
+    color.map(linear_to_srgb)
+}
+
+/// Converts an sRGB encoded RGBA color to linear.
+///
+/// Alpha is always linear and is returned unchanged.
+pub fn srgb_to_linear_rgba([r, g, b, a]: [f32; 4]) -> [f32; 4] {
+    [srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b), a]
+}
+
+/// Converts a linear RGBA color to sRGB.
+///
+/// Alpha is always linear and is returned unchanged.
+pub fn linear_to_srgb_rgba([r, g, b, a]: [f32; 4]) -> [f32; 4] {
+    [linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b), a]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Pairs of (sRGB, linear) values.
+    const PAIRS: [(f32, f32); 6] = [
+        (0.0, 0.0),
+        (0.04045, 0.0031308),
+        (0.2, 0.033104766),
+        (0.5, 0.21404114),
+        (0.7353569, 0.5),
+        (1.0, 1.0),
+    ];
+
+    #[test]
+    fn transfer_functions() {
+        for (srgb, linear) in PAIRS {
+            assert_relative_eq!(srgb_to_linear(srgb), linear, epsilon = 1.0e-6);
+            assert_relative_eq!(linear_to_srgb(linear), srgb, epsilon = 1.0e-6);
+        }
+    }
+
+    #[test]
+    fn rgba_keeps_alpha() {
+        let color = srgb_to_linear_rgba([0.5, 1.0, 0.0, 0.5]);
+        assert_relative_eq!(color[0], 0.21404114, epsilon = 1.0e-6);
+        assert_eq!(color[3], 0.5);
+        assert_eq!(linear_to_srgb_rgba([0.0, 0.0, 0.0, 0.25])[3], 0.25);
+    }
+}
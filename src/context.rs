@@ -0,0 +1,518 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::analysis::{dependency_graph, Graph};
+use crate::fault::{self, EntityKind, FaultError, ImportFault};
+use crate::{accessor, Document};
+
+/// The number of uses of an entity that are listed before the rest are only
+/// counted.
+const MAX_USES: usize = 4;
+
+/// Describes errors in terms of the names of the entities they concern and
+/// of how those entities are used.
+///
+/// Errors only hold indices, so that they are cheap to create and report.
+/// Given the document they came from, a context resolves the indices to names
+/// and to the uses found in the reverse references of the
+/// [dependency graph](crate::analysis::dependency_graph), for example
+/// `accessor 73 (POSITION of mesh 'Chair_LOD0' primitive 2)`.
+///
+/// A document may be built without validation in order to describe its
+/// validation errors:
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use gltf::context::ErrorContext;
+/// use gltf::{Document, Error};
+///
+/// # let slice = br#"{"asset": {"version": "2.0"}, "nodes": [{"mesh": 0}]}"#;
+/// let gltf = gltf::Gltf::from_slice_without_validation(slice)?;
+/// let root = gltf.document.as_json().clone();
+/// if let Err(Error::Validation(errors)) = Document::from_json(root) {
+///     let context = ErrorContext::new(&gltf.document);
+///     for error in errors.iter() {
+///         println!("{}", context.describe(error));
+///     }
+/// #   assert_eq!(
+/// #       context.describe(&errors[0]),
+/// #       "node 0: nodes[0].mesh: Index out of bounds",
+/// #   );
+/// }
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ErrorContext<'a> {
+    root: &'a json::Root,
+    graph: Graph<'a>,
+    users: BTreeMap<(EntityKind, usize), Vec<(EntityKind, usize)>>,
+}
+
+/// An error that an [`ErrorContext`] can describe.
+pub trait Describe {
+    /// Describes the error, naming the entities it concerns.
+    fn describe(&self, context: &ErrorContext) -> String;
+}
+
+/// Returns the word for a kind of entity.
+fn word(kind: EntityKind) -> &'static str {
+    use EntityKind::*;
+    match kind {
+        Root => "document",
+        Buffer => "buffer",
+        View => "buffer view",
+        Accessor => "accessor",
+        Image => "image",
+        Mesh | Primitive { .. } => "mesh",
+        Skin => "skin",
+        Animation => "animation",
+        Camera => "camera",
+        Material => "material",
+        Node => "node",
+        Sampler => "sampler",
+        Scene => "scene",
+        Texture => "texture",
+    }
+}
+
+/// Returns the name of an animated property.
+#[cfg(feature = "animation")]
+fn property(path: &json::validation::Checked<json::animation::Property>) -> &'static str {
+    use json::animation::Property;
+    use json::validation::Checked;
+    match path {
+        Checked::Valid(Property::Translation) => "translation",
+        Checked::Valid(Property::Rotation) => "rotation",
+        Checked::Valid(Property::Scale) => "scale",
+        Checked::Valid(Property::MorphTargetWeights) => "weights",
+        Checked::Invalid => "property",
+    }
+}
+
+/// Appends notes to a description in parentheses, counting those beyond
+/// [`MAX_USES`].
+fn annotate(mut out: String, notes: Vec<String>) -> String {
+    if notes.is_empty() {
+        return out;
+    }
+    out.push_str(" (");
+    for (position, note) in notes.iter().take(MAX_USES).enumerate() {
+        if position > 0 {
+            out.push_str(", ");
+        }
+        out.push_str(note);
+    }
+    if notes.len() > MAX_USES {
+        out.push_str(&format!(", and {} more", notes.len() - MAX_USES));
+    }
+    out.push(')');
+    out
+}
+
+impl<'a> ErrorContext<'a> {
+    /// Creates the context of the errors of a document.
+    pub fn new(document: &'a Document) -> Self {
+        let graph = dependency_graph(document);
+        let mut users: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for (from, to) in graph.edges() {
+            let list = users.entry((to.kind, to.index)).or_default();
+            if list.last() != Some(&(from.kind, from.index)) {
+                list.push((from.kind, from.index));
+            }
+        }
+        Self {
+            root: document.as_json(),
+            graph,
+            users,
+        }
+    }
+
+    /// Describes an error.
+    pub fn describe<E: Describe + ?Sized>(&self, error: &E) -> String {
+        error.describe(self)
+    }
+
+    /// Describes an entity by its index, its name and its uses, for example
+    /// `texture 1 'wood' (base color texture of material 'Table')`.
+    ///
+    /// Nodes are described with their path from the root of the hierarchy,
+    /// and primitives with the uses of their mesh.
+    pub fn entity(&self, kind: EntityKind, index: usize) -> String {
+        use EntityKind::*;
+        let mut notes = Vec::new();
+        let used = match kind {
+            Root => return String::from("the document"),
+            Node => {
+                if let Some(path) = self.path(index) {
+                    notes.push(format!("at {}", path));
+                }
+                (kind, index)
+            }
+            Primitive { mesh } => (Mesh, mesh),
+            _ => (kind, index),
+        };
+        self.uses(used, &mut notes);
+        annotate(self.head(kind, index), notes)
+    }
+
+    /// Returns the name of an entity, if it exists and has one.
+    fn name(&self, kind: EntityKind, index: usize) -> Option<&str> {
+        self.graph.get(kind, index).and_then(|entity| entity.name)
+    }
+
+    /// Returns the index and name of an entity, such as `mesh 3 'Chair'`.
+    fn head(&self, kind: EntityKind, index: usize) -> String {
+        if let EntityKind::Primitive { mesh } = kind {
+            return format!("{} primitive {}", self.head(EntityKind::Mesh, mesh), index);
+        }
+        match self.name(kind, index) {
+            Some(name) => format!("{} {} '{}'", word(kind), index, name),
+            None => format!("{} {}", word(kind), index),
+        }
+    }
+
+    /// Returns the name of an entity, or its index if it has no name, such
+    /// as `mesh 'Chair'`.
+    fn label(&self, kind: EntityKind, index: usize) -> String {
+        if let EntityKind::Primitive { mesh } = kind {
+            return format!("{} primitive {}", self.label(EntityKind::Mesh, mesh), index);
+        }
+        match self.name(kind, index) {
+            Some(name) => format!("{} '{}'", word(kind), name),
+            None => format!("{} {}", word(kind), index),
+        }
+    }
+
+    /// Returns the path of a node from the root of its hierarchy, if it has
+    /// a parent.
+    fn path(&self, node: usize) -> Option<String> {
+        let parent = |node: usize| {
+            self.users
+                .get(&(EntityKind::Node, node))?
+                .iter()
+                .find(|&&(kind, _)| kind == EntityKind::Node)
+                .map(|&(_, index)| index)
+        };
+        let mut nodes = Vec::new();
+        let mut current = node;
+        while let Some(next) = parent(current) {
+            // Breaks cycles, which validation rejects.
+            if nodes.len() > self.root.nodes.len() {
+                break;
+            }
+            nodes.push(current);
+            current = next;
+        }
+        if nodes.is_empty() {
+            return None;
+        }
+        nodes.push(current);
+        let segments: Vec<String> = nodes
+            .iter()
+            .rev()
+            .map(|&node| match self.name(EntityKind::Node, node) {
+                Some(name) => name.to_string(),
+                None => node.to_string(),
+            })
+            .collect();
+        Some(segments.join("/"))
+    }
+
+    /// Lists how an entity is used by the entities that reference it.
+    fn uses(&self, used: (EntityKind, usize), notes: &mut Vec<String>) {
+        if let Some(users) = self.users.get(&used) {
+            for &user in users.iter() {
+                self.roles(user, used, notes);
+            }
+        }
+    }
+
+    /// Lists how one entity uses another.
+    fn roles(&self, user: (EntityKind, usize), used: (EntityKind, usize), notes: &mut Vec<String>) {
+        use EntityKind::*;
+        let label = self.label(user.0, user.1);
+        let mut role = |role: &str| notes.push(format!("{} of {}", role, label));
+        let matches = |index: Option<usize>| index == Some(used.1);
+        match (user.0, used.0) {
+            (Primitive { mesh }, _) => {
+                let primitive = match self
+                    .root
+                    .meshes
+                    .get(mesh)
+                    .and_then(|mesh| mesh.primitives.get(user.1))
+                {
+                    Some(primitive) => primitive,
+                    None => return,
+                };
+                if used.0 == Material {
+                    if matches(primitive.material.map(|x| x.value())) {
+                        role("material");
+                    }
+                    #[cfg(feature = "KHR_materials_variants")]
+                    if let Some(variants) = primitive
+                        .extensions
+                        .as_ref()
+                        .and_then(|extensions| extensions.khr_materials_variants.as_ref())
+                    {
+                        if variants
+                            .mappings
+                            .iter()
+                            .any(|mapping| mapping.material as usize == used.1)
+                        {
+                            role("variant material");
+                        }
+                    }
+                    return;
+                }
+                for (semantic, accessor) in primitive.attributes.iter() {
+                    if accessor.value() == used.1 {
+                        role(&semantic.to_string());
+                    }
+                }
+                if matches(primitive.indices.map(|x| x.value())) {
+                    role("indices");
+                }
+                for (target, accessors) in primitive.targets.iter().flatten().enumerate() {
+                    let attributes = [
+                        ("POSITION", accessors.positions),
+                        ("NORMAL", accessors.normals),
+                        ("TANGENT", accessors.tangents),
+                    ];
+                    for (semantic, accessor) in attributes.iter() {
+                        if matches(accessor.map(|x| x.value())) {
+                            role(&format!("morph target {} {}", target, semantic));
+                        }
+                    }
+                }
+            }
+            #[cfg(feature = "skinning")]
+            (Skin, _) => {
+                let skin = &self.root.skins[user.1];
+                if used.0 == Accessor {
+                    role("inverse bind matrices");
+                    return;
+                }
+                if skin.joints.iter().any(|joint| joint.value() == used.1) {
+                    role("joint");
+                }
+                if matches(skin.skeleton.map(|x| x.value())) {
+                    role("skeleton");
+                }
+            }
+            #[cfg(feature = "animation")]
+            (Animation, _) => {
+                let animation = &self.root.animations[user.1];
+                let channels = animation.channels.iter().enumerate();
+                if used.0 == Node {
+                    for (index, channel) in channels {
+                        if channel.target.node.value() == used.1 {
+                            let path = property(&channel.target.path);
+                            notes.push(format!("{} target of {} channel {}", path, label, index));
+                        }
+                    }
+                    return;
+                }
+                for (index, sampler) in animation.samplers.iter().enumerate() {
+                    let mut found = false;
+                    for (channel, target) in channels.clone() {
+                        if target.sampler.value() != index {
+                            continue;
+                        }
+                        let path = property(&target.target.path);
+                        if sampler.input.value() == used.1 {
+                            notes.push(format!("keyframe times of {} channel {}", label, channel));
+                        }
+                        if sampler.output.value() == used.1 {
+                            notes.push(format!("{} values of {} channel {}", path, label, channel));
+                        }
+                        found = true;
+                    }
+                    if !found {
+                        if sampler.input.value() == used.1 {
+                            notes.push(format!("input of {} sampler {}", label, index));
+                        }
+                        if sampler.output.value() == used.1 {
+                            notes.push(format!("output of {} sampler {}", label, index));
+                        }
+                    }
+                }
+            }
+            (Scene, _) => role("root node"),
+            (Node, Node) => {}
+            (Node, _) => role(word(used.0)),
+            (Mesh, _) => {}
+            (Material, _) => {
+                let material = &self.root.materials[user.1];
+                let pbr = &material.pbr_metallic_roughness;
+                let slots = [
+                    (
+                        "base color texture",
+                        pbr.base_color_texture.as_ref().map(|x| x.index),
+                    ),
+                    (
+                        "metallic-roughness texture",
+                        pbr.metallic_roughness_texture.as_ref().map(|x| x.index),
+                    ),
+                    (
+                        "normal texture",
+                        material.normal_texture.as_ref().map(|x| x.index),
+                    ),
+                    (
+                        "occlusion texture",
+                        material.occlusion_texture.as_ref().map(|x| x.index),
+                    ),
+                    (
+                        "emissive texture",
+                        material.emissive_texture.as_ref().map(|x| x.index),
+                    ),
+                ];
+                let mut found = 0;
+                for (slot, texture) in slots.iter() {
+                    if matches(texture.map(|x| x.value())) {
+                        role(slot);
+                        found += 1;
+                    }
+                }
+                let mut count = 0;
+                crate::transform::for_each_texture_mut(&mut material.clone(), |texture| {
+                    count += (texture.value() == used.1) as usize;
+                });
+                if count > found {
+                    role("extension texture");
+                }
+            }
+            (Texture, Sampler) => role("sampler"),
+            (Texture, _) => role("source"),
+            (Accessor, _) => {
+                let accessor = &self.root.accessors[user.1];
+                if matches(accessor.buffer_view.map(|x| x.value())) {
+                    role("data");
+                }
+                if let Some(sparse) = accessor.sparse.as_ref() {
+                    if sparse.indices.buffer_view.value() == used.1 {
+                        role("sparse indices");
+                    }
+                    if sparse.values.buffer_view.value() == used.1 {
+                        role("sparse values");
+                    }
+                }
+            }
+            (Image, _) => role("data"),
+            _ => notes.push(label),
+        }
+    }
+
+    /// Describes a channel of an animation by its target.
+    #[cfg(feature = "animation")]
+    fn channel(&self, animation: usize, index: usize) -> String {
+        let head = format!(
+            "{} channel {}",
+            self.head(EntityKind::Animation, animation),
+            index
+        );
+        let channel = self
+            .root
+            .animations
+            .get(animation)
+            .and_then(|animation| animation.channels.get(index));
+        let mut notes = Vec::new();
+        if let Some(channel) = channel {
+            let node = self.label(EntityKind::Node, channel.target.node.value());
+            notes.push(format!("{} of {}", property(&channel.target.path), node));
+        }
+        annotate(head, notes)
+    }
+
+    /// Describes a sampler of an animation by the channels that use it.
+    #[cfg(feature = "animation")]
+    fn sampler(&self, animation: usize, index: usize) -> String {
+        let head = format!(
+            "{} sampler {}",
+            self.head(EntityKind::Animation, animation),
+            index
+        );
+        let mut notes = Vec::new();
+        if let Some(animation) = self.root.animations.get(animation) {
+            for (channel, target) in animation.channels.iter().enumerate() {
+                if target.sampler.value() == index {
+                    notes.push(format!("sampler of channel {}", channel));
+                }
+            }
+        }
+        annotate(head, notes)
+    }
+
+    /// Describes the entity that a validation path points into, or returns
+    /// `None` if it concerns the document as a whole.
+    fn subject(&self, path: &str) -> Option<String> {
+        #[cfg(feature = "animation")]
+        if let Some(("animations", animation, rest)) = fault::indexed(path) {
+            match rest.strip_prefix('.').and_then(fault::indexed) {
+                Some(("channels", channel, _)) => return Some(self.channel(animation, channel)),
+                Some(("samplers", sampler, _)) => return Some(self.sampler(animation, sampler)),
+                _ => {}
+            }
+        }
+        match fault::entity(path) {
+            (EntityKind::Root, _) => None,
+            (kind, index) => Some(self.entity(kind, index)),
+        }
+    }
+}
+
+/// Prefixes a message with the description of its subject, if any.
+fn prefixed(subject: Option<String>, message: impl fmt::Display) -> String {
+    match subject {
+        Some(subject) => format!("{}: {}", subject, message),
+        None => message.to_string(),
+    }
+}
+
+impl Describe for (json::Path, json::validation::Error) {
+    fn describe(&self, context: &ErrorContext) -> String {
+        let (path, error) = self;
+        let message = format!("{}: {}", path, error);
+        prefixed(context.subject(path.as_str()), message)
+    }
+}
+
+impl Describe for accessor::Error {
+    fn describe(&self, context: &ErrorContext) -> String {
+        let accessor = match *self {
+            accessor::Error::MissingData { accessor, .. } => accessor,
+            accessor::Error::Faulted { accessor } => accessor,
+            accessor::Error::FaultedPrimitive { mesh, primitive } => {
+                let subject = context.entity(EntityKind::Primitive { mesh }, primitive);
+                return prefixed(Some(subject), self);
+            }
+            accessor::Error::MeshoptFallback { accessor, .. } => accessor,
+            accessor::Error::SparseIndex { accessor, .. } => accessor,
+            accessor::Error::IndexStride { accessor, .. } => accessor,
+            accessor::Error::AttributeCount { accessor, .. } => accessor,
+        };
+        let subject = context.entity(EntityKind::Accessor, accessor);
+        prefixed(Some(subject), self)
+    }
+}
+
+impl Describe for ImportFault {
+    fn describe(&self, context: &ErrorContext) -> String {
+        let subject = match (&self.error, self.entity_kind) {
+            (FaultError::Validation(path, _), _) => context.subject(path.as_str()),
+            (_, EntityKind::Root) => None,
+            (_, kind) => Some(context.entity(kind, self.index)),
+        };
+        match self.error {
+            FaultError::Poisoned { entity_kind, index } => {
+                let cause = context.entity(entity_kind, index);
+                prefixed(subject, format_args!("depends on faulted {}", cause))
+            }
+            ref error => prefixed(subject, error),
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::Document;
+
+/// A coordinate axis.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Axis {
+    /// The X axis.
+    X,
+
+    /// The Y axis.
+    Y,
+
+    /// The Z axis.
+    Z,
+}
+
+impl Axis {
+    /// Parses an axis declared as `"X"`, `"Y"` or `"Z"`, in either case and
+    /// optionally preceded by `+`.
+    fn parse(name: &str) -> Option<Self> {
+        match name.strip_prefix('+').unwrap_or(name) {
+            "X" | "x" => Some(Axis::X),
+            "Y" | "y" => Some(Axis::Y),
+            "Z" | "z" => Some(Axis::Z),
+            _ => None,
+        }
+    }
+
+    /// Returns the rotation, as a unit quaternion `[x, y, z, w]`, that turns
+    /// this axis into the +Y axis of glTF and keeps the coordinates right
+    /// handed.
+    ///
+    /// +Z is turned up about the X axis, as for assets exported from Blender,
+    /// and +X about the Z axis.
+    pub fn rotation_to_y_up(self) -> [f32; 4] {
+        let half = core::f32::consts::FRAC_1_SQRT_2;
+        match self {
+            Axis::X => [0.0, 0.0, half, half],
+            Axis::Y => [0.0, 0.0, 0.0, 1.0],
+            Axis::Z => [-half, 0.0, 0.0, half],
+        }
+    }
+
+    /// Rotates a point by [`Axis::rotation_to_y_up`].
+    pub(crate) fn rotate_to_y_up(self, [x, y, z]: [f32; 3]) -> [f32; 3] {
+        match self {
+            Axis::X => [-y, x, z],
+            Axis::Y => [x, y, z],
+            Axis::Z => [x, z, -y],
+        }
+    }
+
+    /// Rotates a point by the inverse of [`Axis::rotation_to_y_up`].
+    pub(crate) fn rotate_from_y_up(self, [x, y, z]: [f32; 3]) -> [f32; 3] {
+        match self {
+            Axis::X => [y, -x, z],
+            Axis::Y => [x, y, z],
+            Axis::Z => [x, -z, y],
+        }
+    }
+}
+
+/// The keys of `asset.extras` that declare the conventions of a document.
+///
+/// The extras schema is an object such as
+/// `{"unit_scale": 0.01, "original_up": "Z"}`, where the unit scale is the
+/// length of one unit in meters and the up axis is one of `"X"`, `"Y"` or
+/// `"Z"`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ConventionKeys {
+    /// The key of the unit scale. Defaults to `"unit_scale"`.
+    pub unit_scale: &'static str,
+
+    /// The key of the up axis. Defaults to `"original_up"`.
+    pub up_axis: &'static str,
+}
+
+impl Default for ConventionKeys {
+    fn default() -> Self {
+        Self {
+            unit_scale: "unit_scale",
+            up_axis: "original_up",
+        }
+    }
+}
+
+/// The unit and up axis that the coordinates of a document are in, as
+/// consumed by [`convert_coordinates`] and [`normalize_scene`].
+///
+/// [`convert_coordinates`]: crate::transform::convert_coordinates
+/// [`normalize_scene`]: crate::transform::normalize_scene
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Convention {
+    /// Meters and +Y up, as glTF defines. This is the default.
+    Gltf,
+
+    /// The conventions declared in `asset.extras` with the default
+    /// [`ConventionKeys`], falling back to those of glTF for what is not
+    /// declared.
+    FromDeclared,
+
+    /// The given conventions.
+    Explicit {
+        /// The length of one unit in meters.
+        unit_scale: f64,
+
+        /// The up axis.
+        up_axis: Axis,
+    },
+}
+
+impl Default for Convention {
+    fn default() -> Self {
+        Convention::Gltf
+    }
+}
+
+impl Convention {
+    /// Returns the unit scale and up axis of a document in this convention.
+    pub fn resolve(self, document: &Document) -> (f64, Axis) {
+        self.resolve_asset(&document.as_json().asset)
+    }
+
+    pub(crate) fn resolve_asset(self, asset: &json::Asset) -> (f64, Axis) {
+        match self {
+            Convention::Gltf => (1.0, Axis::Y),
+            Convention::FromDeclared => {
+                let keys = ConventionKeys::default();
+                let unit_scale = unit_scale(&asset.extras, keys.unit_scale);
+                let up_axis = up_axis(&asset.extras, keys.up_axis);
+                (unit_scale.unwrap_or(1.0), up_axis.unwrap_or(Axis::Y))
+            }
+            Convention::Explicit {
+                unit_scale,
+                up_axis,
+            } => (unit_scale, up_axis),
+        }
+    }
+}
+
+impl Document {
+    /// Returns the length of one unit in meters declared in `asset.extras`
+    /// under the default [`ConventionKeys`].
+    ///
+    /// Returns `None` unless the declared value is a positive finite number.
+    /// Extras are only read with the `extras` feature.
+    pub fn declared_unit_scale(&self) -> Option<f64> {
+        self.declared_unit_scale_with(ConventionKeys::default())
+    }
+
+    /// Returns the length of one unit in meters declared in `asset.extras`
+    /// under the given keys; see [`Document::declared_unit_scale`].
+    pub fn declared_unit_scale_with(&self, keys: ConventionKeys) -> Option<f64> {
+        unit_scale(&self.as_json().asset.extras, keys.unit_scale)
+    }
+
+    /// Returns the up axis declared in `asset.extras` under the default
+    /// [`ConventionKeys`].
+    ///
+    /// Returns `None` unless the declared value is one of `"X"`, `"Y"` or
+    /// `"Z"`. Extras are only read with the `extras` feature.
+    pub fn declared_up_axis(&self) -> Option<Axis> {
+        self.declared_up_axis_with(ConventionKeys::default())
+    }
+
+    /// Returns the up axis declared in `asset.extras` under the given keys;
+    /// see [`Document::declared_up_axis`].
+    pub fn declared_up_axis_with(&self, keys: ConventionKeys) -> Option<Axis> {
+        up_axis(&self.as_json().asset.extras, keys.up_axis)
+    }
+}
+
+fn unit_scale(extras: &json::Extras, key: &str) -> Option<f64> {
+    declared(extras, key)
+        .and_then(|value| value.as_f64())
+        .filter(|scale| scale.is_finite() && *scale > 0.0)
+}
+
+fn up_axis(extras: &json::Extras, key: &str) -> Option<Axis> {
+    declared(extras, key).and_then(|value| value.as_str().and_then(Axis::parse))
+}
+
+/// Returns the value of a key of extras that are an object.
+#[cfg(feature = "extras")]
+fn declared(extras: &json::Extras, key: &str) -> Option<json::Value> {
+    let raw = extras.as_ref()?;
+    match serde_json::from_str::<json::Value>(raw.get()).ok()? {
+        json::Value::Object(mut object) => object.remove(key),
+        _ => None,
+    }
+}
+
+#[cfg(not(feature = "extras"))]
+fn declared(_: &json::Extras, _: &str) -> Option<json::Value> {
+    None
+}
@@ -0,0 +1,153 @@
+use core::fmt::{self, Write};
+use core::str;
+
+use crate::binary::DebugJsonOptions;
+use crate::check::{CheckError, CheckLimits, Scanner};
+
+/// Malformed JSON or a failed write, both reported as [`fmt::Error`].
+struct Failed;
+
+impl From<CheckError> for Failed {
+    fn from(_: CheckError) -> Self {
+        Failed
+    }
+}
+
+impl From<fmt::Error> for Failed {
+    fn from(_: fmt::Error) -> Self {
+        Failed
+    }
+}
+
+/// Streams a re-indented copy of `json` to `out` without building a DOM.
+///
+/// Values are located by scanning the bytes, so memory use is independent of
+/// the document size. Nesting deeper than the default [`CheckLimits`] is
+/// rejected, so that hostile input cannot exhaust the stack.
+pub(crate) fn write(json: &[u8], out: &mut dyn Write, options: DebugJsonOptions) -> fmt::Result {
+    let scanner = Scanner::new(json, CheckLimits::default().max_depth);
+    let mut printer = Printer { json, out, options };
+    let start = scanner.skip_ws(0);
+    let result = match options.key {
+        Some(key) => printer.filtered(&scanner, start, key),
+        None => printer.value(&scanner, start, 0).map(|_| ()),
+    };
+    result.map_err(|Failed| fmt::Error)?;
+    printer.out.write_char('\n')
+}
+
+struct Printer<'j, 'o, 'k> {
+    json: &'j [u8],
+    out: &'o mut dyn Write,
+    options: DebugJsonOptions<'k>,
+}
+
+impl<'j, 'o, 'k> Printer<'j, 'o, 'k> {
+    fn raw(&mut self, start: usize, end: usize) -> Result<(), Failed> {
+        let text = str::from_utf8(&self.json[start..end]).map_err(|_| Failed)?;
+        Ok(self.out.write_str(text)?)
+    }
+
+    fn indent(&mut self, depth: usize) -> fmt::Result {
+        for _ in 0..depth * self.options.indent {
+            self.out.write_char(' ')?;
+        }
+        Ok(())
+    }
+
+    /// Writes the separator before the next line of a container.
+    fn separator(&mut self, first: &mut bool, depth: usize) -> fmt::Result {
+        if *first {
+            *first = false;
+            self.out.write_char('\n')?;
+        } else {
+            self.out.write_str(",\n")?;
+        }
+        self.indent(depth)
+    }
+
+    /// Writes the value starting at `pos`, returning the position after it.
+    fn value(&mut self, scanner: &Scanner, pos: usize, depth: usize) -> Result<usize, Failed> {
+        let (open, close) = match self.json.get(pos) {
+            Some(b'[') => ('[', ']'),
+            Some(b'{') => ('{', '}'),
+            _ => {
+                let end = scanner.skip(pos, depth)?;
+                self.raw(pos, end)?;
+                return Ok(end);
+            }
+        };
+        let empty = self.json.get(scanner.skip_ws(pos + 1)) == Some(&(close as u8));
+        if empty || depth >= self.options.max_depth.unwrap_or(usize::MAX) {
+            let end = scanner.skip(pos, depth)?;
+            self.out.write_char(open)?;
+            if !empty {
+                self.out.write_str("...")?;
+            }
+            self.out.write_char(close)?;
+            return Ok(end);
+        }
+
+        self.out.write_char(open)?;
+        let mut first = true;
+        let end = if open == '[' {
+            let mut count = 0;
+            if self.options.array_items.is_some() {
+                scanner.array(pos, depth, |scanner, start, _| {
+                    count += 1;
+                    scanner.skip(start, depth + 1)
+                })?;
+            }
+            let keep = self.options.array_items.filter(|&keep| count > 2 * keep);
+            scanner.array(pos, depth, |scanner, start, index| match keep {
+                Some(keep) if index >= keep && index < count - keep => {
+                    if index == keep {
+                        self.separator(&mut first, depth + 1)?;
+                        write!(
+                            self.out,
+                            "... {} more items ({} total)",
+                            count - 2 * keep,
+                            count
+                        )?;
+                    }
+                    Ok(scanner.skip(start, depth + 1)?)
+                }
+                _ => {
+                    self.separator(&mut first, depth + 1)?;
+                    self.value(scanner, start, depth + 1)
+                }
+            })?
+        } else {
+            scanner.members(pos, depth, |scanner, key, start| {
+                self.separator(&mut first, depth + 1)?;
+                write!(self.out, "\"{}\": ", key)?;
+                self.value(scanner, start, depth + 1)
+            })?
+        };
+        self.out.write_char('\n')?;
+        self.indent(depth)?;
+        self.out.write_char(close)?;
+        Ok(end)
+    }
+
+    /// Writes the root object reduced to the given top-level key.
+    fn filtered(&mut self, scanner: &Scanner, pos: usize, key: &str) -> Result<(), Failed> {
+        let mut found = false;
+        scanner.members(pos, 0, |scanner, name, start| {
+            if found || name != key {
+                return Ok(scanner.skip(start, 1)?);
+            }
+            found = true;
+            self.out.write_str("{\n")?;
+            self.indent(1)?;
+            write!(self.out, "\"{}\": ", name)?;
+            let end = self.value(scanner, start, 1)?;
+            self.out.write_str("\n}")?;
+            Ok::<_, Failed>(end)
+        })?;
+        if !found {
+            self.out.write_str("{}")?;
+        }
+        Ok(())
+    }
+}
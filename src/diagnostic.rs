@@ -0,0 +1,415 @@
+//! Every finding of validation, import and [`Gltf::warnings`] has a stable
+//! [`Code`], such as `E0001`, and a default [`Severity`]. A [`Report`]
+//! collects findings as [`Diagnostic`]s, honoring the suppressions and
+//! severity overrides of [`ValidationOptions`], so that a build can gate on
+//! [`Report::max_severity`] without matching messages. Reports are made by
+//! [`Gltf::report`] and [`Report::from_faults`].
+//!
+//! Codes are never reused or changed once released; the full list is kept
+//! in `DIAGNOSTICS.md` at the root of the repository, which a test checks
+//! against [`Code::ALL`].
+//!
+//! [`Gltf::warnings`]: crate::Gltf::warnings
+//! [`Gltf::report`]: crate::Gltf::report
+//! [`Code`]: crate::diagnostic::Code
+//! [`Code::ALL`]: crate::diagnostic::Code::ALL
+//! [`Severity`]: crate::diagnostic::Severity
+//! [`Report`]: crate::diagnostic::Report
+//! [`Report::max_severity`]: crate::diagnostic::Report::max_severity
+//! [`Report::from_faults`]: crate::diagnostic::Report::from_faults
+//! [`Diagnostic`]: crate::diagnostic::Diagnostic
+//! [`ValidationOptions`]: crate::diagnostic::ValidationOptions
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::fault::{FaultError, ImportFault};
+use crate::{Error, Gltf, Warning};
+
+/// How serious a finding is.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /// The finding is informational.
+    Info,
+
+    /// The asset loads but may not render as intended.
+    Warning,
+
+    /// The asset, or an entity of it, cannot be loaded.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// The stable code of a kind of finding.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Code {
+    /// An index is out of bounds; see
+    /// [`json::validation::Error::IndexOutOfBounds`].
+    IndexOutOfBounds,
+
+    /// A value is invalid; see [`json::validation::Error::Invalid`].
+    Invalid,
+
+    /// Required data is missing; see [`json::validation::Error::Missing`].
+    Missing,
+
+    /// A size or offset exceeds the system limits; see
+    /// [`json::validation::Error::Oversize`].
+    Oversize,
+
+    /// A required extension is not supported; see
+    /// [`json::validation::Error::Unsupported`].
+    Unsupported,
+
+    /// The asset is not a glTF 2.0 asset; see [`Error::UnsupportedVersion`].
+    UnsupportedVersion,
+
+    /// The document or buffer data could not be imported.
+    Import,
+
+    /// A buffer is shorter than declared; see [`Error::BufferLength`].
+    ///
+    /// [`Error::BufferLength`]: crate::Error::BufferLength
+    BufferLength,
+
+    /// An entity depends on another entity that is faulted; see
+    /// [`FaultError::Poisoned`].
+    Poisoned,
+
+    /// See [`Warning::UnreferencedBlob`].
+    UnreferencedBlob,
+
+    /// See [`Warning::MissingAttributes`].
+    MissingAttributes,
+
+    /// See [`Warning::EmptyMesh`].
+    EmptyMesh,
+
+    /// See [`Warning::FactorOutOfRange`].
+    FactorOutOfRange,
+
+    /// See [`Warning::DanglingAnimationTarget`].
+    DanglingAnimationTarget,
+
+    /// See [`Warning::DeprecatedExtension`].
+    DeprecatedExtension,
+
+    /// See [`Warning::GeneratedTangents`].
+    GeneratedTangents,
+}
+
+impl Code {
+    /// Every code, in order.
+    pub const ALL: &'static [Code] = &[
+        Code::IndexOutOfBounds,
+        Code::Invalid,
+        Code::Missing,
+        Code::Oversize,
+        Code::Unsupported,
+        Code::UnsupportedVersion,
+        Code::Import,
+        Code::BufferLength,
+        Code::Poisoned,
+        Code::UnreferencedBlob,
+        Code::MissingAttributes,
+        Code::EmptyMesh,
+        Code::FactorOutOfRange,
+        Code::DanglingAnimationTarget,
+        Code::DeprecatedExtension,
+        Code::GeneratedTangents,
+    ];
+
+    /// Returns the code, such as `"E0001"`.
+    ///
+    /// The letter is that of the default severity when the code was
+    /// introduced.
+    pub fn code(self) -> &'static str {
+        match self {
+            Code::IndexOutOfBounds => "E0001",
+            Code::Invalid => "E0002",
+            Code::Missing => "E0003",
+            Code::Oversize => "E0004",
+            Code::Unsupported => "E0005",
+            Code::UnsupportedVersion => "E0006",
+            Code::Import => "E0101",
+            Code::BufferLength => "E0102",
+            Code::Poisoned => "E0103",
+            Code::UnreferencedBlob => "W0001",
+            Code::MissingAttributes => "W0002",
+            Code::EmptyMesh => "W0003",
+            Code::FactorOutOfRange => "W0004",
+            Code::DanglingAnimationTarget => "W0005",
+            Code::DeprecatedExtension => "I0001",
+            Code::GeneratedTangents => "I0002",
+        }
+    }
+
+    /// Returns the code with the given text, such as `"E0001"`.
+    pub fn from_code(code: &str) -> Option<Code> {
+        Code::ALL.iter().copied().find(|x| x.code() == code)
+    }
+
+    /// Returns the severity of findings with this code, unless overridden.
+    pub fn severity(self) -> Severity {
+        match self {
+            Code::IndexOutOfBounds
+            | Code::Invalid
+            | Code::Missing
+            | Code::Oversize
+            | Code::Unsupported
+            | Code::UnsupportedVersion
+            | Code::Import
+            | Code::BufferLength
+            | Code::Poisoned => Severity::Error,
+            Code::UnreferencedBlob
+            | Code::MissingAttributes
+            | Code::EmptyMesh
+            | Code::FactorOutOfRange
+            | Code::DanglingAnimationTarget => Severity::Warning,
+            Code::DeprecatedExtension | Code::GeneratedTangents => Severity::Info,
+        }
+    }
+
+    /// Returns a one-line description of findings with this code.
+    pub fn description(self) -> &'static str {
+        match self {
+            Code::IndexOutOfBounds => "an index is out of bounds",
+            Code::Invalid => "a value is invalid",
+            Code::Missing => "required data is missing",
+            Code::Oversize => "a size or offset exceeds the system limits",
+            Code::Unsupported => "a required extension is not supported",
+            Code::UnsupportedVersion => "the asset is not a glTF 2.0 asset",
+            Code::Import => "the document or buffer data could not be imported",
+            Code::BufferLength => "a buffer is shorter than declared",
+            Code::Poisoned => "an entity depends on a faulted entity",
+            Code::UnreferencedBlob => "the binary chunk is not referenced by any buffer",
+            Code::MissingAttributes => "a primitive lacks attributes read by its material",
+            Code::EmptyMesh => "a mesh has no primitives",
+            Code::FactorOutOfRange => "a material factor exceeds the range of f32",
+            Code::DanglingAnimationTarget => {
+                "an animation channel targets a node outside the scenes"
+            }
+            Code::DeprecatedExtension => "a deprecated extension is used",
+            Code::GeneratedTangents => "tangents will be generated for a normal-mapped primitive",
+        }
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl From<json::validation::Error> for Code {
+    fn from(error: json::validation::Error) -> Self {
+        use json::validation::Error::*;
+        match error {
+            IndexOutOfBounds => Code::IndexOutOfBounds,
+            Invalid => Code::Invalid,
+            Missing => Code::Missing,
+            Oversize => Code::Oversize,
+            Unsupported => Code::Unsupported,
+        }
+    }
+}
+
+impl Warning {
+    /// Returns the code of the warning.
+    pub fn code(&self) -> Code {
+        match self {
+            Warning::UnreferencedBlob { .. } => Code::UnreferencedBlob,
+            Warning::MissingAttributes { .. } => Code::MissingAttributes,
+            Warning::EmptyMesh { .. } => Code::EmptyMesh,
+            Warning::FactorOutOfRange { .. } => Code::FactorOutOfRange,
+            Warning::DanglingAnimationTarget { .. } => Code::DanglingAnimationTarget,
+            Warning::DeprecatedExtension { .. } => Code::DeprecatedExtension,
+            Warning::GeneratedTangents { .. } => Code::GeneratedTangents,
+        }
+    }
+
+    /// Returns the path of the entity that the warning concerns, if any.
+    fn path(&self) -> Option<json::Path> {
+        let path = json::Path::new();
+        match self {
+            Warning::MissingAttributes {
+                mesh, primitive, ..
+            }
+            | Warning::GeneratedTangents { mesh, primitive } => Some(
+                path.field("meshes")
+                    .index(*mesh)
+                    .field("primitives")
+                    .index(*primitive),
+            ),
+            Warning::EmptyMesh { mesh } => Some(path.field("meshes").index(*mesh)),
+            Warning::FactorOutOfRange { path } => Some(path.clone()),
+            Warning::DanglingAnimationTarget {
+                animation, channel, ..
+            } => Some(
+                path.field("animations")
+                    .index(*animation)
+                    .field("channels")
+                    .index(*channel),
+            ),
+            Warning::UnreferencedBlob { .. } | Warning::DeprecatedExtension { .. } => None,
+        }
+    }
+}
+
+impl FaultError {
+    /// Returns the code of the fault.
+    pub fn code(&self) -> Code {
+        match self {
+            #[cfg(feature = "import")]
+            FaultError::Import(Error::BufferLength { .. }) => Code::BufferLength,
+            FaultError::Import(Error::UnsupportedVersion { .. }) => Code::UnsupportedVersion,
+            FaultError::Import(_) => Code::Import,
+            FaultError::Validation(_, error) => Code::from(*error),
+            FaultError::Poisoned { .. } => Code::Poisoned,
+        }
+    }
+}
+
+/// Suppressions and severity overrides applied to a [`Report`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ValidationOptions<'a> {
+    /// Codes of findings that are left out of reports.
+    ///
+    /// Suppression only affects reporting: an entity that fails validation is
+    /// still faulted by [`import_slice_resilient_with`], and still fails
+    /// [`Gltf::from_slice`].
+    ///
+    /// [`import_slice_resilient_with`]: crate::import_slice_resilient_with
+    /// [`Gltf::from_slice`]: crate::Gltf::from_slice
+    pub suppress: &'a [Code],
+
+    /// Codes of findings that are reported with another severity than their
+    /// default one.
+    pub promote: &'a [(Code, Severity)],
+}
+
+impl<'a> ValidationOptions<'a> {
+    /// Returns the severity of findings with a code, or `None` if they are
+    /// suppressed.
+    pub fn severity(&self, code: Code) -> Option<Severity> {
+        if self.suppress.contains(&code) {
+            return None;
+        }
+        let promoted = self.promote.iter().rev().find(|(x, _)| *x == code);
+        Some(promoted.map_or(code.severity(), |&(_, severity)| severity))
+    }
+}
+
+/// A finding, as collected by a [`Report`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    /// The code of the finding.
+    pub code: Code,
+
+    /// The severity of the finding, after any override.
+    pub severity: Severity,
+
+    /// The path of the entity concerned, if any.
+    pub path: Option<json::Path>,
+
+    /// A description of the finding.
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}[{}]: ", self.severity, self.code)?;
+        if let Some(path) = &self.path {
+            write!(f, "{}: ", path)?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+/// The findings about an asset.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    /// The findings that are not suppressed, in the order they were found.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Report {
+    /// Reports the validation errors and warnings of an asset; see
+    /// [`Gltf::report`].
+    pub(crate) fn new(gltf: &Gltf, options: &ValidationOptions) -> Self {
+        let mut report = Report::default();
+        match gltf.document.validate() {
+            Ok(()) => {}
+            Err(Error::Validation(errors)) => {
+                for (path, error) in errors {
+                    report.push(options, Code::from(error), Some(path), error.to_string());
+                }
+            }
+            Err(error) => {
+                let code = match error {
+                    Error::UnsupportedVersion { .. } => Code::UnsupportedVersion,
+                    _ => Code::Import,
+                };
+                report.push(options, code, None, error.to_string());
+            }
+        }
+        for warning in gltf.warnings() {
+            report.push(options, warning.code(), warning.path(), warning.to_string());
+        }
+        report
+    }
+
+    /// Reports the faults of a resilient import.
+    pub fn from_faults(faults: &[ImportFault], options: &ValidationOptions) -> Self {
+        let mut report = Report::default();
+        for fault in faults {
+            let path = match &fault.error {
+                FaultError::Validation(path, _) => Some(path.clone()),
+                _ => None,
+            };
+            let message = fault.error.to_string();
+            report.push(options, fault.error.code(), path, message);
+        }
+        report
+    }
+
+    /// Adds a finding unless its code is suppressed.
+    pub fn push(
+        &mut self,
+        options: &ValidationOptions,
+        code: Code,
+        path: Option<json::Path>,
+        message: String,
+    ) {
+        if let Some(severity) = options.severity(code) {
+            self.diagnostics.push(Diagnostic {
+                code,
+                severity,
+                path,
+                message,
+            });
+        }
+    }
+
+    /// Returns the highest severity of the findings, or `None` if there are
+    /// none.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.diagnostics.iter().map(|x| x.severity).max()
+    }
+
+    /// Returns `true` if there are no findings.
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+}
@@ -0,0 +1,408 @@
+//! [`dump_accessors`] decodes every accessor of a document into tightly packed
+//! little-endian bytes and passes them to a callback one at a time, so that
+//! only a single accessor is held in memory. [`AccessorRecordHeader::to_bytes`]
+//! provides a fixed-size framing for writing the records to an archive.
+//!
+//! [`to_glb`] writes a whole document and its buffer data as a `.glb` file.
+
+use alloc::borrow::Cow;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::{fmt, mem};
+
+use json::validation::{Checked, USize64};
+
+use crate::accessor::{self, DataType, Dimensions, Elements};
+use crate::binary::{ChunkAlignment, Glb, Header};
+use crate::mesh::Semantic;
+use crate::{buffer, Document};
+
+/// How an accessor is used by the document.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Usage {
+    /// A vertex attribute of a primitive.
+    Attribute {
+        /// The index of the mesh.
+        mesh: usize,
+        /// The index of the primitive within the mesh.
+        primitive: usize,
+        /// The semantic of the attribute.
+        semantic: Semantic,
+    },
+
+    /// The indices of a primitive.
+    Indices {
+        /// The index of the mesh.
+        mesh: usize,
+        /// The index of the primitive within the mesh.
+        primitive: usize,
+    },
+
+    /// An attribute displacement of a morph target of a primitive.
+    MorphTarget {
+        /// The index of the mesh.
+        mesh: usize,
+        /// The index of the primitive within the mesh.
+        primitive: usize,
+        /// The index of the morph target.
+        target: usize,
+        /// The semantic of the displaced attribute.
+        semantic: Semantic,
+    },
+
+    /// The inverse bind matrices of a skin.
+    InverseBindMatrices {
+        /// The index of the skin.
+        skin: usize,
+    },
+
+    /// The keyframe times of an animation sampler.
+    AnimationInput {
+        /// The index of the animation.
+        animation: usize,
+        /// The index of the sampler within the animation.
+        sampler: usize,
+    },
+
+    /// The keyframe values of an animation sampler.
+    AnimationOutput {
+        /// The index of the animation.
+        animation: usize,
+        /// The index of the sampler within the animation.
+        sampler: usize,
+    },
+}
+
+impl Usage {
+    /// Returns whether integer data with this usage may be quantized, as
+    /// allowed by `KHR_mesh_quantization`, rather than holding indices.
+    fn is_quantizable(&self) -> bool {
+        match self {
+            Usage::Attribute { semantic, .. } => matches!(
+                semantic,
+                Semantic::Positions
+                    | Semantic::Normals
+                    | Semantic::Tangents
+                    | Semantic::TexCoords(_)
+            ),
+            Usage::MorphTarget { .. } | Usage::AnimationOutput { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+/// Options for [`dump_accessors_with`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DumpOptions {
+    /// Whether to convert quantized integer data to `f32`.
+    ///
+    /// This applies to normalized accessors, which are mapped to floats as
+    /// the specification defines, and to integer accessors used as positions,
+    /// normals, tangents, texture co-ordinates, morph targets or animation
+    /// outputs, whose values are converted as is. Indices and joints are
+    /// never converted. The default is `false`.
+    pub dequantize: bool,
+}
+
+/// The description of the data of an [`AccessorRecord`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AccessorRecordHeader {
+    /// The index of the accessor.
+    pub index: usize,
+
+    /// The component type of the data, which is `F32` for dequantized data.
+    pub data_type: DataType,
+
+    /// The element type of the data.
+    pub dimensions: Dimensions,
+
+    /// Whether the integer components are normalized. This is `false` for
+    /// dequantized data.
+    pub normalized: bool,
+
+    /// The number of elements.
+    pub count: usize,
+
+    /// The length of the data in bytes.
+    pub byte_length: usize,
+}
+
+impl AccessorRecordHeader {
+    /// The length of the output of [`Self::to_bytes`].
+    pub const SIZE: usize = 24;
+
+    /// Encodes the header as a fixed-size frame that can precede the data of
+    /// the record in an archive.
+    ///
+    /// All values are little-endian:
+    ///
+    /// | Bytes    | Value                                              |
+    /// |----------|----------------------------------------------------|
+    /// | `0..4`   | accessor index as `u32`                            |
+    /// | `4..6`   | component type `GLenum` as `u16`                   |
+    /// | `6`      | element type, from `1` for `SCALAR` to `7` for `MAT4` |
+    /// | `7`      | `1` if normalized, otherwise `0`                   |
+    /// | `8..16`  | element count as `u64`                             |
+    /// | `16..24` | data length in bytes as `u64`                      |
+    ///
+    /// The element types are numbered in the order of [`Dimensions`].
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        bytes[0..4].copy_from_slice(&(self.index as u32).to_le_bytes());
+        bytes[4..6].copy_from_slice(&(self.data_type.to_gl() as u16).to_le_bytes());
+        bytes[6] = self.dimensions as u8;
+        bytes[7] = self.normalized as u8;
+        bytes[8..16].copy_from_slice(&(self.count as u64).to_le_bytes());
+        bytes[16..24].copy_from_slice(&(self.byte_length as u64).to_le_bytes());
+        bytes
+    }
+}
+
+/// The decoded data of an accessor, as passed to the sink of
+/// [`dump_accessors`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessorRecord<'r> {
+    /// The description of the data.
+    pub header: AccessorRecordHeader,
+
+    /// The uses of the accessor, in document order.
+    pub usages: &'r [Usage],
+
+    /// The elements as tightly packed little-endian components, with sparse
+    /// substitutions applied and without matrix column padding.
+    pub data: &'r [u8],
+}
+
+/// Errors that may occur when dumping accessors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DumpError<E> {
+    /// The data of an accessor could not be read.
+    Accessor(accessor::Error),
+
+    /// The sink returned an error.
+    Sink(E),
+}
+
+impl<E: fmt::Display> fmt::Display for DumpError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DumpError::Accessor(error) => error.fmt(f),
+            DumpError::Sink(error) => error.fmt(f),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> core::error::Error for DumpError<E> {}
+
+/// Collects the uses of every accessor of a document.
+fn usages(document: &Document) -> Vec<Vec<Usage>> {
+    let root = document.as_json();
+    let mut usages = vec![Vec::new(); root.accessors.len()];
+    let mut add = |index: json::Index<json::Accessor>, usage: Usage| {
+        if let Some(usages) = usages.get_mut(index.value()) {
+            usages.push(usage);
+        }
+    };
+    for (mesh, json) in root.meshes.iter().enumerate() {
+        for (primitive, json) in json.primitives.iter().enumerate() {
+            for (semantic, &index) in &json.attributes {
+                if let Checked::Valid(semantic) = semantic {
+                    let semantic = semantic.clone();
+                    add(
+                        index,
+                        Usage::Attribute {
+                            mesh,
+                            primitive,
+                            semantic,
+                        },
+                    );
+                }
+            }
+            if let Some(index) = json.indices {
+                add(index, Usage::Indices { mesh, primitive });
+            }
+            for (target, json) in json.targets.iter().flatten().enumerate() {
+                let displacements = [
+                    (json.positions, Semantic::Positions),
+                    (json.normals, Semantic::Normals),
+                    (json.tangents, Semantic::Tangents),
+                ];
+                for (index, semantic) in displacements {
+                    if let Some(index) = index {
+                        let usage = Usage::MorphTarget {
+                            mesh,
+                            primitive,
+                            target,
+                            semantic,
+                        };
+                        add(index, usage);
+                    }
+                }
+            }
+        }
+    }
+    #[cfg(feature = "skinning")]
+    for (skin, json) in root.skins.iter().enumerate() {
+        if let Some(index) = json.inverse_bind_matrices {
+            add(index, Usage::InverseBindMatrices { skin });
+        }
+    }
+    #[cfg(feature = "animation")]
+    for (animation, json) in root.animations.iter().enumerate() {
+        for (sampler, json) in json.samplers.iter().enumerate() {
+            add(json.input, Usage::AnimationInput { animation, sampler });
+            add(json.output, Usage::AnimationOutput { animation, sampler });
+        }
+    }
+    usages
+}
+
+/// Decodes every accessor of a document and passes it to `sink`, in index
+/// order.
+///
+/// See [`dump_accessors_with`] for details.
+pub fn dump_accessors<E>(
+    document: &Document,
+    buffers: &[buffer::Data],
+    sink: &mut dyn FnMut(AccessorRecord) -> Result<(), E>,
+) -> Result<(), DumpError<E>> {
+    dump_accessors_with(document, buffers, DumpOptions::default(), sink)
+}
+
+/// Decodes every accessor of a document and passes it to `sink`, in index
+/// order.
+///
+/// Each record holds the uses of the accessor and its elements as tightly
+/// packed little-endian bytes, optionally dequantized to `f32`. The data of
+/// one accessor is decoded at a time, and the buffer holding it is reused for
+/// the next accessor. Stops at the first accessor whose data cannot be read
+/// or the first error returned by `sink`.
+pub fn dump_accessors_with<E>(
+    document: &Document,
+    buffers: &[buffer::Data],
+    options: DumpOptions,
+    sink: &mut dyn FnMut(AccessorRecord) -> Result<(), E>,
+) -> Result<(), DumpError<E>> {
+    let usages = usages(document);
+    let get_buffer_data = |buffer: buffer::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+    let mut data = Vec::new();
+    let mut values = Vec::new();
+    for accessor in document.accessors() {
+        let usages = &usages[accessor.index()];
+        let mut elements =
+            Elements::new(&accessor, &get_buffer_data).map_err(DumpError::Accessor)?;
+        let dequantize = options.dequantize
+            && accessor.data_type() != DataType::F32
+            && (accessor.normalized() || usages.iter().any(Usage::is_quantizable));
+
+        data.clear();
+        if dequantize {
+            values.resize(elements.components(), 0.0);
+            while elements.next_values(true, &mut values) {
+                for &value in &values {
+                    data.extend_from_slice(&(value as f32).to_le_bytes());
+                }
+            }
+        } else {
+            while elements.next_components(|bytes| data.extend_from_slice(bytes)) {}
+        }
+
+        let header = AccessorRecordHeader {
+            index: accessor.index(),
+            data_type: if dequantize {
+                DataType::F32
+            } else {
+                accessor.data_type()
+            },
+            dimensions: accessor.dimensions(),
+            normalized: accessor.normalized() && !dequantize,
+            count: accessor.count(),
+            byte_length: data.len(),
+        };
+        sink(AccessorRecord {
+            header,
+            usages,
+            data: &data,
+        })
+        .map_err(DumpError::Sink)?;
+    }
+    Ok(())
+}
+
+/// Writes a document and its buffer data as a `.glb` file.
+///
+/// The buffers are concatenated into the single `BIN` chunk, each at a
+/// 4-byte boundary, and the buffer views are rewritten to refer to it, so the
+/// output has one buffer without a URI whatever the buffers of `document`.
+/// `buffers` holds the data of each buffer in index order, as returned by
+/// `import`; data that is missing or shorter than its buffer is
+/// zero-filled, and data beyond the length of its buffer is dropped.
+///
+/// Returns an error if the JSON cannot be serialized or the output would
+/// exceed the 32-bit length of a `.glb` file.
+pub fn to_glb(document: &Document, buffers: &[buffer::Data]) -> Result<Vec<u8>, crate::Error> {
+    let mut root = document.as_json().clone();
+    let mut buffers = buffers.to_vec();
+    merge_buffers(&mut root, &mut buffers);
+    let glb = Glb {
+        header: Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: Cow::Owned(root.to_vec()?),
+        bin: buffers.pop().map(|data| Cow::Owned(data.0)),
+        extra_chunks: Vec::new(),
+        length_read: None,
+        chunk_alignment: ChunkAlignment::Exact,
+    };
+    glb.to_vec()
+}
+
+/// Moves the buffer data into a single buffer without a URI, for the `BIN`
+/// chunk of GLB.
+///
+/// The first buffer is extended with the others at 4-byte boundaries, each
+/// of which is released once it is copied. Data that is missing from
+/// `buffers` is zero-filled.
+pub(crate) fn merge_buffers(root: &mut json::Root, buffers: &mut Vec<buffer::Data>) {
+    if root.buffers.is_empty() {
+        buffers.clear();
+        return;
+    }
+    buffers.resize_with(root.buffers.len(), || buffer::Data(Vec::new()));
+    let aligned = |length: usize| (length + 3) / 4 * 4;
+    let mut offsets = Vec::with_capacity(root.buffers.len());
+    let mut length = 0;
+    for buffer in root.buffers.iter() {
+        let offset = aligned(length);
+        offsets.push(offset);
+        length = offset + buffer.byte_length.0 as usize;
+    }
+
+    let mut merged = mem::take(&mut buffers[0].0);
+    merged.resize(root.buffers[0].byte_length.0 as usize, 0);
+    merged.reserve_exact(length.saturating_sub(merged.len()));
+    for (index, buffer) in root.buffers.iter().enumerate().skip(1) {
+        let data = mem::take(&mut buffers[index].0);
+        merged.resize(offsets[index], 0);
+        merged.extend_from_slice(&data[..data.len().min(buffer.byte_length.0 as usize)]);
+        merged.resize(offsets[index] + buffer.byte_length.0 as usize, 0);
+    }
+
+    for view in root.buffer_views.iter_mut() {
+        let offset = offsets
+            .get(view.buffer.value())
+            .copied()
+            .unwrap_or_default();
+        view.byte_offset = Some(USize64::from(
+            view.byte_offset.unwrap_or_default().0 as usize + offset,
+        ));
+        view.buffer = json::Index::new(0);
+    }
+    root.buffers.truncate(1);
+    let buffer = &mut root.buffers[0];
+    buffer.uri = None;
+    buffer.byte_length = USize64::from(merged.len());
+    *buffers = vec![buffer::Data(merged)];
+}
@@ -0,0 +1,92 @@
+//! # Usage
+//!
+//! Reading a vendor extension of a node into a struct.
+//!
+//! ```
+//! use gltf::HasExtensions;
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Tags<'a> {
+//!     #[serde(borrow)]
+//!     tags: Vec<&'a str>,
+//! }
+//!
+//! # fn main() -> Result<(), gltf::Error> {
+//! let gltf = gltf::Gltf::from_slice(br#"{
+//!     "asset": {"version": "2.0"},
+//!     "nodes": [{"extensions": {"EXT_studio_tags": {"tags": ["prop"]}}}]
+//! }"#)?;
+//! let node = gltf.nodes().next().unwrap();
+//! if let Some(tags) = node.extension_as::<Tags>("EXT_studio_tags") {
+//!     assert_eq!(tags?.tags, ["prop"]);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::string::String;
+use json::Value;
+use serde::Deserialize;
+use serde_json::Map;
+
+use crate::{Document, Material, Mesh, Node, Primitive, Scene, Texture};
+
+/// Entities that preserve the extension data unknown to this crate version.
+pub trait HasExtensions {
+    /// Returns the extension data unknown to this crate version.
+    fn extensions(&self) -> Option<&Map<String, Value>>;
+
+    /// Deserializes the data of the extension `name`.
+    ///
+    /// Returns `None` if the entity does not have the extension, and an
+    /// error if its data does not deserialize to `T`. The data is
+    /// deserialized on every call; strings may be borrowed from it.
+    fn extension_as<'a, T: Deserialize<'a>>(
+        &'a self,
+        name: &str,
+    ) -> Option<Result<T, json::Error>> {
+        self.extensions()?.get(name).map(T::deserialize)
+    }
+}
+
+impl HasExtensions for Document {
+    fn extensions(&self) -> Option<&Map<String, Value>> {
+        Document::extensions(self)
+    }
+}
+
+impl<'a> HasExtensions for Material<'a> {
+    fn extensions(&self) -> Option<&Map<String, Value>> {
+        Material::extensions(self)
+    }
+}
+
+impl<'a> HasExtensions for Mesh<'a> {
+    fn extensions(&self) -> Option<&Map<String, Value>> {
+        Mesh::extensions(self)
+    }
+}
+
+impl<'a> HasExtensions for Node<'a> {
+    fn extensions(&self) -> Option<&Map<String, Value>> {
+        Node::extensions(self)
+    }
+}
+
+impl<'a> HasExtensions for Primitive<'a> {
+    fn extensions(&self) -> Option<&Map<String, Value>> {
+        Primitive::extensions(self)
+    }
+}
+
+impl<'a> HasExtensions for Scene<'a> {
+    fn extensions(&self) -> Option<&Map<String, Value>> {
+        Scene::extensions(self)
+    }
+}
+
+impl<'a> HasExtensions for Texture<'a> {
+    fn extensions(&self) -> Option<&Map<String, Value>> {
+        Texture::extensions(self)
+    }
+}
@@ -0,0 +1,321 @@
+//! [`import_slice_resilient`] records what it could not load as
+//! [`ImportFault`]s instead of failing, and marks the entities concerned, as
+//! well as every entity that depends on them, as faulted. Faulted entities
+//! may be reported by [`Document::is_faulted`]; their data is never read.
+//!
+//! [`import_slice_resilient`]: crate::import_slice_resilient
+//! [`Document::is_faulted`]: crate::Document::is_faulted
+
+#[cfg(feature = "import")]
+use alloc::collections::BTreeSet;
+#[cfg(feature = "import")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+#[cfg(feature = "import")]
+use crate::span::SourceSpans;
+
+/// The kind of a document entity.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum EntityKind {
+    /// The document itself, for faults that concern no single entity.
+    Root,
+
+    /// A buffer.
+    Buffer,
+
+    /// A buffer view.
+    View,
+
+    /// An accessor.
+    Accessor,
+
+    /// An image.
+    Image,
+
+    /// A mesh.
+    Mesh,
+
+    /// A primitive of a mesh.
+    Primitive {
+        /// The index of the mesh.
+        mesh: usize,
+    },
+
+    /// A skin.
+    Skin,
+
+    /// An animation.
+    Animation,
+
+    /// A camera.
+    Camera,
+
+    /// A material.
+    Material,
+
+    /// A node.
+    Node,
+
+    /// A texture sampler.
+    Sampler,
+
+    /// A scene.
+    Scene,
+
+    /// A texture.
+    Texture,
+}
+
+/// Why an entity is faulted.
+#[derive(Debug)]
+pub enum FaultError {
+    /// The document or buffer data could not be imported.
+    Import(crate::Error),
+
+    /// The entity failed validation.
+    Validation(json::Path, json::validation::Error),
+
+    /// The entity depends on another entity that is faulted.
+    Poisoned {
+        /// The kind of the faulted dependency.
+        entity_kind: EntityKind,
+
+        /// The index of the faulted dependency.
+        index: usize,
+    },
+}
+
+impl fmt::Display for FaultError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FaultError::Import(error) => error.fmt(f),
+            FaultError::Validation(path, error) => write!(f, "{}: {}", path, error),
+            FaultError::Poisoned { entity_kind, index } => {
+                write!(f, "depends on faulted {:?} {}", entity_kind, index)
+            }
+        }
+    }
+}
+
+impl core::error::Error for FaultError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            FaultError::Import(error) => Some(error),
+            FaultError::Validation(_, error) => Some(error),
+            FaultError::Poisoned { .. } => None,
+        }
+    }
+}
+
+/// An entity that could not be loaded.
+#[derive(Debug)]
+pub struct ImportFault {
+    /// The kind of the entity.
+    pub entity_kind: EntityKind,
+
+    /// The index of the entity, within its mesh for primitives.
+    pub index: usize,
+
+    /// Why the entity is faulted.
+    pub error: FaultError,
+
+    /// The byte span of the entity within the JSON, if known; see
+    /// [`span::SourceSpans`](crate::span::SourceSpans).
+    pub span: Option<Range<usize>>,
+}
+
+/// Collects faults and the set of faulted entities.
+#[cfg(feature = "import")]
+#[derive(Debug, Default)]
+pub(crate) struct Faults {
+    pub faults: Vec<ImportFault>,
+    pub faulted: BTreeSet<(EntityKind, usize)>,
+    pub spans: SourceSpans,
+}
+
+#[cfg(feature = "import")]
+impl Faults {
+    /// Records a fault of an entity.
+    pub fn fault(&mut self, entity_kind: EntityKind, index: usize, error: FaultError) {
+        self.faulted.insert((entity_kind, index));
+        self.faults.push(ImportFault {
+            entity_kind,
+            index,
+            error,
+            span: self.spans.get(entity_kind, index),
+        });
+    }
+
+    /// Records a validation error against the entity its path points into.
+    pub fn validation(&mut self, path: json::Path, error: json::validation::Error) {
+        let (entity_kind, index) = entity(path.as_str());
+        self.fault(entity_kind, index, FaultError::Validation(path, error));
+    }
+
+    fn contains(&self, entity_kind: EntityKind, index: usize) -> bool {
+        self.faulted.contains(&(entity_kind, index))
+    }
+
+    /// Faults an entity because of the first faulted dependency, if any.
+    fn poison<I>(&mut self, entity_kind: EntityKind, index: usize, dependencies: I)
+    where
+        I: IntoIterator<Item = (EntityKind, usize)>,
+    {
+        if self.contains(entity_kind, index) {
+            return;
+        }
+        let cause = dependencies
+            .into_iter()
+            .find(|&(kind, index)| self.contains(kind, index));
+        if let Some((kind, cause)) = cause {
+            let error = FaultError::Poisoned {
+                entity_kind: kind,
+                index: cause,
+            };
+            self.fault(entity_kind, index, error);
+        }
+    }
+
+    /// Faults every entity that depends on a faulted entity, transitively.
+    ///
+    /// Faulted buffers poison their views, faulted views poison their
+    /// accessors and images, and faulted accessors poison the primitives,
+    /// skins and animations that read them. Faulted images and samplers
+    /// poison their textures, and faulted textures the materials that use
+    /// them. Faulted meshes poison their primitives.
+    pub fn propagate(&mut self, root: &json::Root) {
+        use EntityKind::*;
+        for (index, view) in root.buffer_views.iter().enumerate() {
+            self.poison(View, index, [(Buffer, view.buffer.value())]);
+        }
+        for (index, accessor) in root.accessors.iter().enumerate() {
+            let mut views: Vec<_> = accessor.buffer_view.iter().map(|x| x.value()).collect();
+            if let Some(sparse) = accessor.sparse.as_ref() {
+                views.push(sparse.indices.buffer_view.value());
+                views.push(sparse.values.buffer_view.value());
+            }
+            self.poison(Accessor, index, views.into_iter().map(|x| (View, x)));
+        }
+        for (index, image) in root.images.iter().enumerate() {
+            let views = image.buffer_view.iter().map(|x| (View, x.value()));
+            self.poison(Image, index, views);
+        }
+        for (index, texture) in root.textures.iter().enumerate() {
+            let mut dependencies: Vec<_> = texture
+                .sampler
+                .iter()
+                .map(|x| (Sampler, x.value()))
+                .collect();
+            // The visitor borrows mutably, so it visits a copy.
+            crate::transform::for_each_image_mut(&mut texture.clone(), |image| {
+                dependencies.push((Image, image.value()))
+            });
+            self.poison(Texture, index, dependencies);
+        }
+        for (index, material) in root.materials.iter().enumerate() {
+            let mut dependencies = Vec::new();
+            crate::transform::for_each_texture_mut(&mut material.clone(), |texture| {
+                dependencies.push((Texture, texture.value()))
+            });
+            self.poison(Material, index, dependencies);
+        }
+        for (mesh_index, mesh) in root.meshes.iter().enumerate() {
+            for (index, primitive) in mesh.primitives.iter().enumerate() {
+                let mut dependencies = Vec::new();
+                dependencies.push((Mesh, mesh_index));
+                dependencies.extend(primitive.attributes.values().map(|x| (Accessor, x.value())));
+                dependencies.extend(primitive.indices.iter().map(|x| (Accessor, x.value())));
+                for target in primitive.targets.iter().flatten() {
+                    let accessors = [&target.positions, &target.normals, &target.tangents];
+                    dependencies.extend(
+                        accessors
+                            .iter()
+                            .flat_map(|x| x.iter())
+                            .map(|x| (Accessor, x.value())),
+                    );
+                }
+                self.poison(Primitive { mesh: mesh_index }, index, dependencies);
+            }
+        }
+        #[cfg(feature = "skinning")]
+        for (index, skin) in root.skins.iter().enumerate() {
+            let accessors = skin
+                .inverse_bind_matrices
+                .iter()
+                .map(|x| (Accessor, x.value()));
+            self.poison(Skin, index, accessors);
+        }
+        #[cfg(feature = "animation")]
+        for (index, animation) in root.animations.iter().enumerate() {
+            let accessors = animation
+                .samplers
+                .iter()
+                .flat_map(|x| [(Accessor, x.input.value()), (Accessor, x.output.value())]);
+            self.poison(Animation, index, accessors);
+        }
+    }
+}
+
+/// Returns the entity that a validation path points into.
+pub(crate) fn entity(path: &str) -> (EntityKind, usize) {
+    use EntityKind::*;
+    let (field, index, rest) = match indexed(path) {
+        Some(indexed) => indexed,
+        None => return (Root, 0),
+    };
+    let kind = match field {
+        "accessors" => Accessor,
+        "animations" => Animation,
+        "buffers" => Buffer,
+        "bufferViews" => View,
+        "cameras" => Camera,
+        "images" => Image,
+        "materials" => Material,
+        "meshes" => match rest.strip_prefix('.').and_then(indexed) {
+            Some(("primitives", primitive, _)) => return (Primitive { mesh: index }, primitive),
+            _ => Mesh,
+        },
+        "nodes" => Node,
+        "samplers" => Sampler,
+        "scenes" => Scene,
+        "skins" => Skin,
+        "textures" => Texture,
+        _ => return (Root, 0),
+    };
+    (kind, index)
+}
+
+/// Splits a path of the form `field[index]rest`.
+pub(crate) fn indexed(path: &str) -> Option<(&str, usize, &str)> {
+    let (field, rest) = path.split_once('[')?;
+    let (index, rest) = rest.split_once(']')?;
+    if field.contains('.') {
+        return None;
+    }
+    Some((field, index.parse().ok()?, rest))
+}
+
+#[cfg(all(test, feature = "import"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entity_paths() {
+        use EntityKind::*;
+        assert_eq!(entity("accessors[3].bufferView"), (Accessor, 3));
+        assert_eq!(entity("meshes[2].weights"), (Mesh, 2));
+        assert_eq!(
+            entity("meshes[2].primitives[1].attributes[\"POSITION\"]"),
+            (Primitive { mesh: 2 }, 1)
+        );
+        assert_eq!(entity("bufferViews[0]"), (View, 0));
+        assert_eq!(entity("asset.version"), (Root, 0));
+        assert_eq!(entity("scene"), (Root, 0));
+        assert_eq!(
+            entity("extensions.KHR_lights_punctual.lights[0]"),
+            (Root, 0)
+        );
+    }
+}
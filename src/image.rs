@@ -1,3 +1,5 @@
+#[cfg(feature = "import")]
+use alloc::borrow::Cow;
 use alloc::string::String;
 use alloc::vec::Vec;
 #[allow(unused)]
@@ -56,6 +58,15 @@ pub enum Source<'a> {
         /// The image data MIME type.
         mime_type: &'a str,
     },
+
+    /// Image data is contained in an external data source or a `data:` URI.
+    Uri {
+        /// The URI of the image.
+        uri: &'a str,
+
+        /// The image data MIME type, if declared.
+        mime_type: Option<&'a str>,
+    },
 }
 
 /// Image data used to create a texture.
@@ -89,6 +100,210 @@ pub struct Data {
     pub height: u32,
 }
 
+/// Decodes images for [`import_images_with`].
+///
+/// Decoders are passed the encoded data and the MIME type of each image,
+/// which is that declared by the image, by its `data:` URI, or sniffed from
+/// the data, and `None` if none of these is known. Decoders may report their
+/// own errors as [`Error::Image`] and unknown encodings as
+/// [`Error::UnsupportedImageEncoding`]. Closures taking the same arguments
+/// are decoders too.
+///
+/// [`import_images_with`]: crate::import_images_with
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+pub trait ImageDecoder {
+    /// Decodes the encoded data of an image.
+    fn decode(&mut self, bytes: &[u8], mime_type: Option<&str>) -> Result<Data>;
+}
+
+#[cfg(feature = "import")]
+impl<F> ImageDecoder for F
+where
+    F: FnMut(&[u8], Option<&str>) -> Result<Data>,
+{
+    fn decode(&mut self, bytes: &[u8], mime_type: Option<&str>) -> Result<Data> {
+        self(bytes, mime_type)
+    }
+}
+
+/// The encoded data of an image, as imported by [`import_images_raw`].
+///
+/// [`import_images_raw`]: crate::import_images_raw
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+#[derive(Clone, Debug)]
+pub struct EncodedData<'a> {
+    /// The encoded image data, borrowed from the buffer data for images in a
+    /// buffer view and owned for images with a `data:` URI.
+    pub data: Cow<'a, [u8]>,
+
+    /// The image data MIME type, if known.
+    pub mime_type: Option<&'a str>,
+}
+
+/// Image properties read from the header of encoded image data.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Metadata {
+    /// The image width in pixels.
+    pub width: u32,
+
+    /// The image height in pixels.
+    pub height: u32,
+}
+
+impl Metadata {
+    /// Reads the image dimensions from the header of PNG or JPEG encoded data,
+    /// without decoding the image.
+    ///
+    /// Returns `None` if the data is not recognised or the header is truncated.
+    pub fn peek(data: &[u8]) -> Option<Self> {
+        if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            if data.get(12..16)? != b"IHDR" {
+                return None;
+            }
+            Some(Self {
+                width: be_u32(data.get(16..20)?),
+                height: be_u32(data.get(20..24)?),
+            })
+        } else if data.starts_with(&[0xFF, 0xD8]) {
+            peek_jpeg(data)
+        } else {
+            None
+        }
+    }
+}
+
+fn be_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn be_u16(bytes: &[u8]) -> u16 {
+    u16::from_be_bytes([bytes[0], bytes[1]])
+}
+
+fn peek_jpeg(data: &[u8]) -> Option<Metadata> {
+    let header = jpeg_frame(data)?.get(1..5)?;
+    Some(Metadata {
+        height: be_u16(&header[0..2]) as u32,
+        width: be_u16(&header[2..4]) as u32,
+    })
+}
+
+/// Scans the JPEG segments for the first start-of-frame marker, and returns
+/// the frame header from its sample precision onwards.
+fn jpeg_frame(data: &[u8]) -> Option<&[u8]> {
+    let mut offset = 2;
+    loop {
+        if *data.get(offset)? != 0xFF {
+            return None;
+        }
+        let marker = *data.get(offset + 1)?;
+        offset += 2;
+        match marker {
+            // Fill bytes.
+            0xFF => offset -= 1,
+            // Standalone markers without a length.
+            0x01 | 0xD0..=0xD8 => {}
+            // Start of frame, excluding DHT, JPG and DAC.
+            0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                return data.get(offset + 2..);
+            }
+            // Start of scan or end of image before any frame header.
+            0xD9 | 0xDA => return None,
+            _ => offset += be_u16(data.get(offset..offset + 2)?) as usize,
+        }
+    }
+}
+
+/// Reads the number of bytes per pixel of decoded image data from the header
+/// of PNG or JPEG encoded data.
+///
+/// Palette images count as RGB, and 16-bit PNG channels as two bytes.
+pub(crate) fn bytes_per_pixel(data: &[u8]) -> Option<u32> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        if data.get(12..16)? != b"IHDR" {
+            return None;
+        }
+        let channels = match *data.get(25)? {
+            0 => 1,
+            2 | 3 => 3,
+            4 => 2,
+            6 => 4,
+            _ => return None,
+        };
+        Some(channels * if *data.get(24)? == 16 { 2 } else { 1 })
+    } else if data.starts_with(&[0xFF, 0xD8]) {
+        jpeg_frame(data)?
+            .get(5)
+            .map(|&components| components as u32)
+    } else {
+        None
+    }
+}
+
+/// Guesses the MIME type of encoded image data from its signature.
+///
+/// Only the formats permitted by the core specification are recognised.
+pub fn sniff_mime_type(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else {
+        None
+    }
+}
+
+/// Encodings of image data recognised by [`guess_format`].
+#[cfg(feature = "guess_mime_type")]
+#[cfg_attr(docsrs, doc(cfg(feature = "guess_mime_type")))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ImageFormat {
+    /// PNG, signed `\x89PNG\r\n\x1a\n`.
+    Png,
+
+    /// JPEG, signed `\xFF\xD8\xFF`.
+    Jpeg,
+
+    /// KTX2, signed `«KTX 20»\r\n\x1a\n`.
+    Ktx2,
+
+    /// WebP, signed `RIFF` followed by the length and `WEBP`.
+    WebP,
+}
+
+#[cfg(feature = "guess_mime_type")]
+impl ImageFormat {
+    /// Returns the MIME type of the encoding.
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "image/png",
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Ktx2 => "image/ktx2",
+            ImageFormat::WebP => "image/webp",
+        }
+    }
+}
+
+/// Guesses the encoding of image data from its signature.
+///
+/// Unlike [`sniff_mime_type`], this also recognises the encodings of the
+/// `KHR_texture_basisu` and `EXT_texture_webp` extensions.
+#[cfg(feature = "guess_mime_type")]
+#[cfg_attr(docsrs, doc(cfg(feature = "guess_mime_type")))]
+pub fn guess_format(data: &[u8]) -> Option<ImageFormat> {
+    match sniff_mime_type(data) {
+        Some("image/png") => Some(ImageFormat::Png),
+        Some("image/jpeg") => Some(ImageFormat::Jpeg),
+        _ if data.starts_with(b"\xABKTX 20\xBB\r\n\x1a\n") => Some(ImageFormat::Ktx2),
+        _ if data.starts_with(b"RIFF") && data.get(8..12) == Some(b"WEBP") => {
+            Some(ImageFormat::WebP)
+        }
+        _ => None,
+    }
+}
+
 impl<'a> Image<'a> {
     /// Constructs an `Image` from owned data.
     pub(crate) fn new(document: &'a Document, index: usize, json: &'a json::image::Image) -> Self {
@@ -108,7 +323,7 @@ impl<'a> Image<'a> {
     #[cfg(feature = "names")]
     #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
     }
 
     /// Returns the image data source.
@@ -118,10 +333,38 @@ impl<'a> Image<'a> {
             let mime_type = self.json.mime_type.as_ref().map(|x| x.0.as_str()).unwrap();
             Source::View { view, mime_type }
         } else {
-            panic!();
+            let uri = self.json.uri.as_ref().unwrap();
+            let mime_type = self.json.mime_type.as_ref().map(|x| x.0.as_str());
+            Source::Uri { uri, mime_type }
         }
     }
 
+    /// Returns the image MIME type.
+    ///
+    /// A blank `mimeType` is rejected by validation. When validation was
+    /// skipped, the MIME type of an image stored in a buffer view is instead
+    /// sniffed from the encoded data.
+    pub fn mime_type(&self, buffers: &[buffer::Data]) -> Option<&'a str> {
+        let declared = self.json.mime_type.as_ref().map(|mime| mime.0.as_str());
+        crate::non_blank(declared).or_else(|| sniff_mime_type(self.view_data(buffers)?))
+    }
+
+    /// Returns the encoded image data, if the image is stored in a buffer view.
+    pub fn view_data<'s>(&self, buffers: &'s [buffer::Data]) -> Option<&'s [u8]> {
+        let view = self
+            .document
+            .views()
+            .nth(self.json.buffer_view.as_ref()?.value())?;
+        let buffer = buffers.get(view.buffer().index())?;
+        buffer.get(view.offset()..view.offset() + view.length())
+    }
+
+    /// Reads the image dimensions from the header of the encoded image data,
+    /// if the image is stored in a buffer view.
+    pub fn metadata(&self, buffers: &[buffer::Data]) -> Option<Metadata> {
+        Metadata::peek(self.view_data(buffers)?)
+    }
+
     /// Returns extension data unknown to this crate version.
     #[cfg(feature = "extensions")]
     #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
@@ -173,3 +416,58 @@ impl<'a> Image<'a> {
 //         })
 //     }
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::Metadata;
+
+    #[test]
+    fn peek_png() {
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        png.extend_from_slice(&640u32.to_be_bytes());
+        png.extend_from_slice(&480u32.to_be_bytes());
+        let metadata = Metadata::peek(&png).unwrap();
+        assert_eq!((metadata.width, metadata.height), (640, 480));
+        assert_eq!(Metadata::peek(&png[..20]), None);
+        assert_eq!(super::bytes_per_pixel(&png), None);
+        png.extend_from_slice(&[16, 6]);
+        assert_eq!(super::bytes_per_pixel(&png), Some(8));
+    }
+
+    #[test]
+    fn peek_jpeg() {
+        let jpeg = [
+            0xFF, 0xD8, // SOI
+            0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00, // APP0
+            0xFF, 0xC0, 0x00, 0x0B, 0x08, 0x01, 0xE0, 0x02, 0x80, // SOF0
+        ];
+        let metadata = Metadata::peek(&jpeg).unwrap();
+        assert_eq!((metadata.width, metadata.height), (640, 480));
+        assert_eq!(super::bytes_per_pixel(&jpeg), None);
+        let mut jpeg = jpeg.to_vec();
+        jpeg.push(3);
+        assert_eq!(super::bytes_per_pixel(&jpeg), Some(3));
+        assert_eq!(Metadata::peek(b"GIF89a"), None);
+    }
+
+    #[cfg(feature = "guess_mime_type")]
+    #[test]
+    fn guess_format() {
+        use super::{guess_format, ImageFormat};
+
+        let formats: [(&[u8], _); 4] = [
+            (b"\x89PNG\r\n\x1a\n", ImageFormat::Png),
+            (b"\xFF\xD8\xFF", ImageFormat::Jpeg),
+            (b"\xABKTX 20\xBB\r\n\x1a\n", ImageFormat::Ktx2),
+            (b"RIFF\x24\0\0\0WEBP", ImageFormat::WebP),
+        ];
+        for (data, format) in formats {
+            assert_eq!(guess_format(data), Some(format));
+            assert_eq!(guess_format(&data[..data.len() - 1]), None, "{:?}", format);
+        }
+        assert_eq!(ImageFormat::Ktx2.mime_type(), "image/ktx2");
+        assert_eq!(guess_format(b"RIFF\x24\0\0\0WAVEfmt "), None);
+        assert_eq!(guess_format(b"GIF89a\x01\0\x01\0"), None);
+        assert_eq!(guess_format(&[]), None);
+    }
+}
@@ -1,42 +1,170 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
-use crate::buffer;
-// use crate::image;
+use core::mem;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use crate::diagnostic::ValidationOptions;
+use crate::fault::{EntityKind, FaultError, Faults, ImportFault};
+use crate::{binary, buffer, image};
 
 use crate::{Document, Error, Gltf, Result};
+use core::result;
+use serde_json::Value;
 
 /// Return type of `import`.
 type Import = (Document, Vec<buffer::Data>);
 
 impl buffer::Data {
-    /// Construct a buffer data object by reading the given source.
-    /// If `base` is provided, then external filesystem references will
-    /// be resolved from this directory.
-    /// `blob` represents the `BIN` section of a binary glTF file,
-    /// and it will be taken to fill the buffer if the `source` refers to it.
-    pub fn from_blob(
-        blob: &mut Option<Vec<u8>>,
-    ) -> Result<Self> {
-        let mut data = blob.take().ok_or(Error::MissingBlob)?;
+    /// Construct a buffer data object from the source of a buffer.
+    ///
+    /// `blob` is taken if the buffer has no URI, which refers to the `BIN`
+    /// chunk of binary glTF, and reported as [`Error::MissingBlob`] if it is
+    /// absent. `data:` URIs are decoded, both base 64 encoded and
+    /// percent-encoded; malformed base 64 is reported as
+    /// [`Error::MalformedBase64`]. Other URIs cannot be resolved without a
+    /// filesystem and are reported as [`Error::UnresolvedUri`].
+    pub fn from_buffer(buffer: &buffer::Buffer<'_>, blob: &mut Option<Vec<u8>>) -> Result<Self> {
+        let uri = match buffer.source() {
+            buffer::Source::Bin => match blob.take() {
+                Some(data) => return Ok(Self::padded(data)),
+                None => {
+                    return Err(Error::MissingBlob {
+                        buffer: buffer.index(),
+                        length: buffer.length(),
+                    })
+                }
+            },
+            buffer::Source::Uri(uri) => uri,
+        };
+        let data = match uri.strip_prefix("data:") {
+            Some(data) => data,
+            None => {
+                return Err(Error::UnresolvedUri {
+                    buffer: buffer.index(),
+                    length: buffer.length(),
+                    uri: uri.into(),
+                })
+            }
+        };
+        match data.split_once(',') {
+            Some((header, data)) if header.ends_with(";base64") => STANDARD
+                .decode(data)
+                .map(Self::padded)
+                .map_err(|error| Error::MalformedBase64 {
+                    buffer: buffer.index(),
+                    error,
+                }),
+            Some((_, data)) => Ok(Self::padded(percent_decode(data))),
+            None => Err(Error::UnsupportedScheme),
+        }
+    }
+
+    /// Pads data to a multiple of four bytes.
+    fn padded(mut data: Vec<u8>) -> Self {
         while data.len() % 4 != 0 {
             data.push(0);
         }
-        Ok(buffer::Data(data))
+        buffer::Data(data)
+    }
+}
+
+/// Decodes the percent-encoded bytes of a `data:` URI.
+///
+/// A `%` that is not followed by two hexadecimal digits is kept as is.
+fn percent_decode(data: &str) -> Vec<u8> {
+    let hex = |byte: u8| (byte as char).to_digit(16).map(|x| x as u8);
+    let bytes = data.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = match bytes.get(i..i + 3) {
+            Some([b'%', high, low]) => hex(*high).zip(hex(*low)),
+            _ => None,
+        };
+        match escaped {
+            Some((high, low)) => {
+                decoded.push(high << 4 | low);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
     }
+    decoded
 }
 
 /// Import buffer data referenced by a glTF document.
 ///
+/// Only a buffer without a URI is filled from the `BIN` chunk of binary glTF,
+/// which is left untouched if no buffer refers to it; see
+/// [`Gltf::unreferenced_blob`]. Buffers with `data:` URIs are decoded, and
+/// external buffers, which require [`import_buffers_with`], are reported as
+/// [`Error::UnresolvedUri`]. With the `EXT_meshopt_compression` feature, fallback
+/// buffers without a URI are represented by empty data, so that the data
+/// stays aligned with the buffer indices.
+///
 /// ### Note
 ///
 /// This function is intended for advanced users who wish to forego loading image data.
 /// A typical user should call [`import`] instead.
-pub fn import_buffers(
+pub fn import_buffers(document: &Document, blob: Option<Vec<u8>>) -> Result<Vec<buffer::Data>> {
+    import_buffers_impl(document, blob, |buffer, uri| {
+        Err(Error::UnresolvedUri {
+            buffer: buffer.index(),
+            length: buffer.length(),
+            uri: uri.into(),
+        })
+    })
+}
+
+/// Import buffer data referenced by a glTF document, fetching external
+/// buffers with `resolver`.
+///
+/// `resolver` is called with the URI of every buffer that is neither the
+/// `BIN` chunk of binary glTF, which is taken from `blob`, nor a `data:`
+/// URI. Its data is checked against the buffer length like that of
+/// [`import_buffers`], and its errors are reported as
+/// [`Error::ExternalBuffer`].
+pub fn import_buffers_with<F, E>(
+    document: &Document,
+    blob: Option<Vec<u8>>,
+    mut resolver: F,
+) -> Result<Vec<buffer::Data>>
+where
+    F: FnMut(&str) -> result::Result<Vec<u8>, E>,
+    E: Into<Box<dyn core::error::Error + Send + Sync>>,
+{
+    import_buffers_impl(document, blob, |buffer, uri| {
+        resolver(uri).map_err(|error| Error::ExternalBuffer {
+            index: buffer.index(),
+            uri: uri.into(),
+            error: error.into(),
+        })
+    })
+}
+
+fn import_buffers_impl(
     document: &Document,
     mut blob: Option<Vec<u8>>,
+    mut resolve: impl FnMut(&buffer::Buffer<'_>, &str) -> Result<Vec<u8>>,
 ) -> Result<Vec<buffer::Data>> {
     let mut buffers = Vec::new();
     for buffer in document.buffers() {
-        let data = buffer::Data::from_blob(&mut blob)?;
+        if !buffer.has_data() {
+            buffers.push(buffer::Data(Vec::new()));
+            continue;
+        }
+        let data = match buffer.source() {
+            buffer::Source::Uri(uri) if !uri.starts_with("data:") => {
+                buffer::Data::padded(resolve(&buffer, uri)?)
+            }
+            _ => buffer::Data::from_buffer(&buffer, &mut blob)?,
+        };
         if data.len() < buffer.length() {
             return Err(Error::BufferLength {
                 buffer: buffer.index(),
@@ -49,60 +177,190 @@ pub fn import_buffers(
     Ok(buffers)
 }
 
-// impl image::Data {
-//     /// Construct an image data object by reading the given source.
-//     /// If `base` is provided, then external filesystem references will
-//     /// be resolved from this directory.
-//     pub fn from_source(
-//         source: image::Source<'_>,
-//         buffer_data: &[buffer::Data],
-//     ) -> Result<Self> {
-//         #[cfg(feature = "guess_mime_type")]
-//         let guess_format = |encoded_image: &[u8]| match image_crate::guess_format(encoded_image) {
-//             Ok(image_crate::ImageFormat::Png) => Some(Png),
-//             Ok(`image_crate::ImageFormat::Jpeg) => Some(Jpeg),
-//             _ => None,
-//         };
-//         #[cfg(not(feature = "guess_mime_type"))]
-//         let guess_format = |_encoded_image: &[u8]| None;
-//         let decoded_image = match source {
-//             image::Source::View { view, mime_type } => {
-//                 let parent_buffer_data = &buffer_data[view.buffer().index()].0;
-//                 let begin = view.offset();
-//                 let end = begin + view.length();
-//                 let encoded_image = &parent_buffer_data[begin..end];
-//                 let encoded_format = match mime_type {
-//                     "image/png" => Png,
-//                     "image/jpeg" => Jpeg,
-//                     _ => match guess_format(encoded_image) {
-//                         Some(format) => format,
-//                         None => return Err(Error::UnsupportedImageEncoding),
-//                     },
-//                 };
-//                 image_crate::load_from_memory_with_format(encoded_image, encoded_format)?
-//             }
-//         };
-// 
-//         image::Data::new(decoded_image)
-//     }
-// }
-
-// /// Import image data referenced by a glTF document.
-// ///
-// /// ### Note
-// ///
-// /// This function is intended for advanced users who wish to forego loading buffer data.
-// /// A typical user should call [`import`] instead.
-// pub fn import_images(
-//     document: &Document,
-//     buffer_data: &[buffer::Data],
-// ) -> Result<Vec<image::Data>> {
-//     let mut images = Vec::new();
-//     for image in document.images() {
-//         images.push(image::Data::from_source(image.source(), buffer_data)?);
-//     }
-//     Ok(images)
-// }
+/// Import buffer data from a possibly truncated binary glTF in a degraded mode.
+///
+/// The first buffer, if it has no URI, is filled with whatever is present of
+/// the BIN chunk, and buffers with `data:` URIs are decoded. Other buffers,
+/// and buffers whose `data:` URIs are malformed, are left empty. Rather than
+/// failing, the indices of the accessors whose data lies beyond the available
+/// bytes are returned alongside the buffer data. Accessor iterators constructed with
+/// [`accessor::Iter::try_new`] report [`accessor::Error::MissingData`] for
+/// those accessors.
+///
+/// [`accessor::Iter::try_new`]: crate::accessor::Iter::try_new
+/// [`accessor::Error::MissingData`]: crate::accessor::Error::MissingData
+pub fn import_buffers_partial(
+    document: &Document,
+    glb: &binary::PartialGlb,
+) -> (Vec<buffer::Data>, Vec<usize>) {
+    let buffers: Vec<_> = document
+        .buffers()
+        .map(|buffer| match buffer.source() {
+            _ if !buffer.has_data() => buffer::Data(Vec::new()),
+            buffer::Source::Bin if buffer.index() == 0 => {
+                buffer::Data(glb.bin.as_deref().unwrap_or_default().to_vec())
+            }
+            buffer::Source::Uri(uri) if uri.starts_with("data:") => {
+                buffer::Data::from_buffer(&buffer, &mut None)
+                    .unwrap_or_else(|_| buffer::Data(Vec::new()))
+            }
+            _ => buffer::Data(Vec::new()),
+        })
+        .collect();
+    let unusable = document
+        .accessors()
+        .filter(|accessor| {
+            accessor
+                .check_data(|buffer| buffers.get(buffer.index()).map(|x| &*x.0))
+                .is_err()
+        })
+        .map(|accessor| accessor.index())
+        .collect();
+    (buffers, unusable)
+}
+
+/// Import image data referenced by a glTF document, decoding every image with
+/// `decoder`.
+///
+/// The encoded data of every image is passed to `decoder` like that returned
+/// by [`import_images_raw`], and errors are reported likewise.
+///
+/// ### Note
+///
+/// This function is intended for advanced users who wish to forego loading buffer data.
+/// A typical user should call [`import_slice`] instead.
+pub fn import_images_with<D>(
+    document: &Document,
+    buffer_data: &[buffer::Data],
+    decoder: &mut D,
+) -> Result<Vec<image::Data>>
+where
+    D: image::ImageDecoder + ?Sized,
+{
+    let fallbacks = fallback_mime_types(document);
+    let mut images = Vec::new();
+    for image in document.images() {
+        let encoded = encoded_image(&image, buffer_data, fallbacks[image.index()])?;
+        images.push(decoder.decode(&encoded.data, encoded.mime_type)?);
+    }
+    Ok(images)
+}
+
+/// Returns the MIME type of each image of a document that a texture refers to
+/// through the `KHR_texture_basisu` or `EXT_texture_webp` extension.
+fn fallback_mime_types(document: &Document) -> Vec<Option<&'static str>> {
+    #[allow(unused_mut)]
+    let mut mime_types = vec![None; document.as_json().images.len()];
+    #[allow(unused_variables)]
+    for texture in document.textures() {
+        #[cfg(feature = "KHR_texture_basisu")]
+        if let Some(image) = texture.basisu_source() {
+            mime_types[image.index()] = Some("image/ktx2");
+        }
+        #[cfg(feature = "EXT_texture_webp")]
+        if let Some(image) = texture.webp_source() {
+            mime_types[image.index()] = Some("image/webp");
+        }
+    }
+    mime_types
+}
+
+/// Returns the known MIME type of image data or, with the `guess_mime_type`
+/// feature, that of its encoding as recognised by [`image::guess_format`].
+///
+/// With the `guess_mime_type` feature, data of an unknown MIME type whose
+/// encoding is not recognised is reported as
+/// [`Error::UnsupportedImageEncoding`]. WebP data is `image/webp` even if
+/// another MIME type is known, since tools that convert images to WebP often
+/// leave their MIME type as it was.
+#[cfg_attr(not(feature = "guess_mime_type"), allow(unused_variables))]
+fn resolve_mime_type<'a>(known: Option<&'a str>, data: &[u8]) -> Result<Option<&'a str>> {
+    #[cfg(feature = "guess_mime_type")]
+    match (known, image::guess_format(data)) {
+        (_, Some(image::ImageFormat::WebP)) => return Ok(Some("image/webp")),
+        (None, Some(format)) => return Ok(Some(format.mime_type())),
+        (None, None) => {
+            return Err(Error::UnsupportedImageEncoding {
+                prefix: data.iter().take(8).copied().collect(),
+            })
+        }
+        (Some(_), _) => {}
+    }
+    Ok(known)
+}
+
+/// Import the encoded data of the images referenced by a glTF document,
+/// without decoding them.
+///
+/// The data of an image in a buffer view is borrowed from `buffer_data`, and
+/// that of an image with a `data:` URI is decoded from the URI. Images in a
+/// view beyond the end of its buffer are reported as [`Error::BufferLength`],
+/// and images with external URIs, which cannot be resolved without a
+/// filesystem, as [`Error::ExternalReferenceInSliceImport`].
+///
+/// With the `KHR_texture_basisu` and `EXT_texture_webp` features, the images
+/// that textures refer to through these extensions are `image/ktx2` and
+/// `image/webp` respectively unless declared otherwise.
+pub fn import_images_raw<'a>(
+    document: &'a Document,
+    buffer_data: &'a [buffer::Data],
+) -> Result<Vec<image::EncodedData<'a>>> {
+    let fallbacks = fallback_mime_types(document);
+    document
+        .images()
+        .map(|image| encoded_image(&image, buffer_data, fallbacks[image.index()]))
+        .collect()
+}
+
+/// Reads the encoded data of an image and its MIME type, which is that
+/// declared by the image, by its `data:` URI, sniffed from the data, or
+/// `fallback`.
+///
+/// With the `guess_mime_type` feature, data of an unknown MIME type is
+/// reported as [`Error::UnsupportedImageEncoding`] unless its encoding is
+/// guessed; see [`resolve_mime_type`].
+fn encoded_image<'a>(
+    image: &image::Image<'a>,
+    buffer_data: &'a [buffer::Data],
+    fallback: Option<&'static str>,
+) -> Result<image::EncodedData<'a>> {
+    match image.source() {
+        image::Source::View { view, .. } => {
+            let data = image.view_data(buffer_data).ok_or(Error::BufferLength {
+                buffer: view.buffer().index(),
+                expected: view.offset() + view.length(),
+                actual: buffer_data
+                    .get(view.buffer().index())
+                    .map_or(0, |x| x.len()),
+            })?;
+            Ok(image::EncodedData {
+                data: Cow::Borrowed(data),
+                mime_type: resolve_mime_type(image.mime_type(buffer_data).or(fallback), data)?,
+            })
+        }
+        image::Source::Uri { uri, mime_type } => {
+            let (header, data) = uri
+                .strip_prefix("data:")
+                .ok_or(Error::ExternalReferenceInSliceImport)?
+                .split_once(',')
+                .ok_or(Error::UnsupportedScheme)?;
+            let data = match header.strip_suffix(";base64") {
+                Some(_) => STANDARD.decode(data).map_err(Error::Base64)?,
+                None => percent_decode(data),
+            };
+            let media_type = header.split(';').next().filter(|x| !x.is_empty());
+            let known = crate::non_blank(mime_type)
+                .or(media_type)
+                .or_else(|| image::sniff_mime_type(&data))
+                .or(fallback);
+            let mime_type = resolve_mime_type(known, &data)?;
+            Ok(image::EncodedData {
+                data: Cow::Owned(data),
+                mime_type,
+            })
+        }
+    }
+}
 
 fn import_impl(Gltf { document, blob }: Gltf) -> Result<Import> {
     let buffer_data = import_buffers(&document, blob)?;
@@ -151,3 +409,279 @@ where
 {
     import_slice_impl(slice.as_ref())
 }
+
+/// Import glTF 2.0 from a slice like [`import_slice`], requiring only the
+/// extensions in `supported` to be supported.
+///
+/// The extensions required by the asset are checked before it is validated or
+/// any buffer is loaded, and any that are not in `supported` fail the import
+/// with [`Error::UnsupportedExtensions`]. Required extensions that the caller
+/// supports but this crate does not, such as `KHR_draco_mesh_compression` for
+/// a caller that decodes it, are accepted by validation; decoding their data
+/// is left to the caller. Pass [`json::extensions::SUPPORTED`] to accept those
+/// of this crate only.
+pub fn import_slice_with_extensions<S>(slice: S, supported: &[&str]) -> Result<Import>
+where
+    S: AsRef<[u8]>,
+{
+    let gltf = Gltf::from_slice_without_validation(slice.as_ref())?;
+    gltf.document.check_required_extensions(supported)?;
+    match gltf.document.validate() {
+        Err(Error::Validation(mut errors)) => {
+            errors.retain(|(path, error)| {
+                *error != json::validation::Error::Unsupported
+                    || !path.as_str().starts_with("extensionsRequired")
+            });
+            if !errors.is_empty() {
+                return Err(Error::Validation(errors));
+            }
+        }
+        result => result?,
+    }
+    import_impl(gltf)
+}
+
+/// Import glTF 2.0 from a slice, recording what cannot be loaded instead of
+/// failing.
+///
+/// Buffers whose data cannot be decoded or is shorter than declared, and
+/// entities that fail validation, are recorded as [`ImportFault`]s. These
+/// entities and every entity that depends on them, such as the views and
+/// accessors of a faulted buffer and the primitives that read those
+/// accessors, are marked as faulted; see [`Document::is_faulted`]. Faulted
+/// buffers are left empty, readers of faulted accessors report
+/// [`accessor::Error::Faulted`], and all other entities load normally.
+///
+/// If the slice is not glTF at all, an empty document is returned with a fault
+/// of the [`EntityKind::Root`].
+///
+/// [`accessor::Error::Faulted`]: crate::accessor::Error::Faulted
+pub fn import_slice_resilient<S>(slice: S) -> (Document, Vec<buffer::Data>, Vec<ImportFault>)
+where
+    S: AsRef<[u8]>,
+{
+    use json::validation::Validate;
+    let mut faults = Faults::default();
+    let Gltf {
+        mut document,
+        mut blob,
+    } = match Gltf::from_slice_without_validation_with(slice.as_ref(), Default::default(), true) {
+        Ok(gltf) => gltf,
+        Err(error) => {
+            faults.fault(EntityKind::Root, 0, FaultError::Import(error));
+            let document = Document::from_json_without_validation(Default::default());
+            return (document, Vec::new(), faults.faults);
+        }
+    };
+    faults.spans = document.source_spans().clone();
+    let root = document.as_json();
+    root.validate(root, json::Path::new, &mut |path, error| {
+        faults.validation(path(), error)
+    });
+
+    let mut buffers = Vec::new();
+    for buffer in document.buffers() {
+        let index = buffer.index();
+        if faults.faulted.contains(&(EntityKind::Buffer, index)) || !buffer.has_data() {
+            buffers.push(buffer::Data(Vec::new()));
+            continue;
+        }
+        let error = match buffer::Data::from_buffer(&buffer, &mut blob) {
+            Ok(data) if data.len() >= buffer.length() => {
+                buffers.push(data);
+                continue;
+            }
+            Ok(data) => Error::BufferLength {
+                buffer: index,
+                expected: buffer.length(),
+                actual: data.len(),
+            },
+            Err(error) => error,
+        };
+        faults.fault(EntityKind::Buffer, index, FaultError::Import(error));
+        buffers.push(buffer::Data(Vec::new()));
+    }
+
+    faults.propagate(document.as_json());
+    document.1 = faults.faulted;
+    (document, buffers, faults.faults)
+}
+
+/// Import glTF 2.0 from a slice like [`import_slice_resilient`], leaving out
+/// the faults whose codes `options` suppresses.
+///
+/// The entities of suppressed faults are still faulted; see
+/// [`Document::is_faulted`]. Use [`Report::from_faults`] to apply the severity
+/// overrides of `options` to the remaining faults.
+///
+/// [`Report::from_faults`]: crate::diagnostic::Report::from_faults
+pub fn import_slice_resilient_with<S>(
+    slice: S,
+    options: &ValidationOptions,
+) -> (Document, Vec<buffer::Data>, Vec<ImportFault>)
+where
+    S: AsRef<[u8]>,
+{
+    let (document, buffers, mut faults) = import_slice_resilient(slice);
+    faults.retain(|fault| options.severity(fault.error.code()).is_some());
+    (document, buffers, faults)
+}
+
+/// The changes applied to an imported asset by [`update_in_place`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UpdateReport {
+    /// The kind of an entity whose structure changed, if the new asset could
+    /// not be applied as a patch.
+    ///
+    /// In that case the existing document and buffers were replaced as a
+    /// whole, everything must be reloaded, and the other fields are empty.
+    /// Entities are structurally changed if any are added or removed, if
+    /// anything other than materials, nodes and buffer contents differs, or if
+    /// buffer data changes length. Changes to the top level properties of the
+    /// asset are reported as [`EntityKind::Root`].
+    pub reload: Option<EntityKind>,
+
+    /// The indices of the materials that were replaced.
+    pub materials: Vec<usize>,
+
+    /// The indices of the nodes that were replaced.
+    pub nodes: Vec<usize>,
+
+    /// The byte ranges of the buffers whose contents were overwritten.
+    pub ranges: buffer::RangeSet,
+}
+
+/// Returns the kind of the entities stored under a top level key of glTF JSON.
+fn entity_kind(key: &str) -> EntityKind {
+    match key {
+        "accessors" => EntityKind::Accessor,
+        "animations" => EntityKind::Animation,
+        "buffers" => EntityKind::Buffer,
+        "bufferViews" => EntityKind::View,
+        "cameras" => EntityKind::Camera,
+        "images" => EntityKind::Image,
+        "materials" => EntityKind::Material,
+        "meshes" => EntityKind::Mesh,
+        "nodes" => EntityKind::Node,
+        "samplers" => EntityKind::Sampler,
+        "scenes" => EntityKind::Scene,
+        "skins" => EntityKind::Skin,
+        "textures" => EntityKind::Texture,
+        _ => EntityKind::Root,
+    }
+}
+
+/// Returns the indices of the items that differ between two JSON arrays of
+/// the same length, or `None` if the lengths differ.
+fn changed_items(old: Option<&Value>, new: Option<&Value>) -> Option<Vec<usize>> {
+    let items = |value: Option<&Value>| match value {
+        Some(Value::Array(items)) => items.clone(),
+        _ => Vec::new(),
+    };
+    let (old, new) = (items(old), items(new));
+    if old.len() != new.len() {
+        return None;
+    }
+    Some((0..old.len()).filter(|&i| old[i] != new[i]).collect())
+}
+
+/// Adds the ranges of bytes that differ between two equally long buffers to
+/// a set.
+fn changed_ranges(ranges: &mut buffer::RangeSet, buffer: usize, old: &[u8], new: &[u8]) {
+    let mut start = None;
+    for (i, (a, b)) in old.iter().zip(new).enumerate() {
+        match (a != b, start) {
+            (true, None) => start = Some(i),
+            (false, Some(first)) => {
+                ranges.insert(buffer, first..i);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(first) = start {
+        ranges.insert(buffer, first..old.len());
+    }
+}
+
+/// Updates an imported asset to match a newer version of it, changing only
+/// what differs.
+///
+/// The new asset is imported like [`import_slice`] and compared with the
+/// existing document. Materials and nodes that differ have their JSON
+/// replaced, and buffer bytes that differ are copied into the existing
+/// allocations, so that the report tells which materials, nodes and byte
+/// ranges must be uploaded again. Structural changes cannot be patched; the
+/// existing asset is then replaced by the new one and
+/// [`UpdateReport::reload`] says why. The source spans of a document loaded
+/// with them are recomputed for the new JSON.
+///
+/// On error the existing asset is left untouched.
+pub fn update_in_place(existing: &mut Import, slice: &[u8]) -> Result<UpdateReport> {
+    // The spans of an existing document that has them are recomputed for the
+    // new JSON.
+    let record_spans = !existing.0.source_spans().is_empty();
+    let options = binary::GlbOptions::default();
+    let gltf = Gltf::from_slice_without_validation_with(slice, options, record_spans)?;
+    gltf.document.validate()?;
+    let (mut document, buffers) = import_impl(gltf)?;
+    let mut report = UpdateReport::default();
+
+    let old = serde_json::to_value(existing.0.as_json()).map_err(Error::Serialize)?;
+    let new = serde_json::to_value(document.as_json()).map_err(Error::Serialize)?;
+    let (old, new) = match (old, new) {
+        (Value::Object(old), Value::Object(new)) => (old, new),
+        _ => unreachable!(),
+    };
+    let mut keys: Vec<&String> = old.keys().chain(new.keys()).collect();
+    keys.sort_unstable();
+    keys.dedup();
+    for key in keys {
+        let (old, new) = (old.get(key), new.get(key));
+        let items = match key.as_str() {
+            "materials" => &mut report.materials,
+            "nodes" => &mut report.nodes,
+            _ if old == new => continue,
+            _ => {
+                report.reload = Some(entity_kind(key));
+                break;
+            }
+        };
+        match changed_items(old, new) {
+            Some(changed) => *items = changed,
+            None => {
+                report.reload = Some(entity_kind(key));
+                break;
+            }
+        }
+    }
+    let lengths = |buffers: &[buffer::Data]| buffers.iter().map(|x| x.len()).collect::<Vec<_>>();
+    if report.reload.is_none() && lengths(&existing.1) != lengths(&buffers) {
+        report.reload = Some(EntityKind::Buffer);
+    }
+    if report.reload.is_some() {
+        *existing = (document, buffers);
+        return Ok(UpdateReport {
+            reload: report.reload,
+            ..Default::default()
+        });
+    }
+
+    let (existing_document, existing_buffers) = existing;
+    let root = &mut existing_document.0;
+    for &index in &report.materials {
+        mem::swap(&mut root.materials[index], &mut document.0.materials[index]);
+    }
+    for &index in &report.nodes {
+        mem::swap(&mut root.nodes[index], &mut document.0.nodes[index]);
+    }
+    existing_document.1 = document.1;
+    existing_document.2 = document.2;
+    for (index, (old, new)) in existing_buffers.iter_mut().zip(&buffers).enumerate() {
+        changed_ranges(&mut report.ranges, index, &old.0, &new.0);
+        for range in report.ranges.get(index) {
+            old.0[range.clone()].copy_from_slice(&new.0[range.clone()]);
+        }
+    }
+    Ok(report)
+}
@@ -2,13 +2,16 @@ use alloc::string::String;
 use core::{iter, slice};
 
 use crate::accessor::Accessor;
+#[cfg(feature = "animation")]
 use crate::animation::Animation;
 use crate::buffer::{Buffer, View};
+#[cfg(feature = "cameras")]
 use crate::camera::Camera;
 use crate::image::Image;
 use crate::material::Material;
 use crate::mesh::Mesh;
 use crate::scene::{Node, Scene};
+#[cfg(feature = "skinning")]
 use crate::skin::Skin;
 use crate::texture::{Sampler, Texture};
 use crate::Document;
@@ -32,6 +35,8 @@ pub struct Accessors<'a> {
 }
 
 /// An `Iterator` that visits every animation in a glTF asset.
+#[cfg(feature = "animation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
 #[derive(Clone, Debug)]
 pub struct Animations<'a> {
     /// Internal animation iterator.
@@ -62,6 +67,8 @@ pub struct Views<'a> {
 }
 
 /// An `Iterator` that visits every camera in a glTF asset.
+#[cfg(feature = "cameras")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cameras")))]
 #[derive(Clone, Debug)]
 pub struct Cameras<'a> {
     /// Internal buffer view iterator.
@@ -156,6 +163,8 @@ pub struct Scenes<'a> {
 }
 
 /// An `Iterator` that visits every skin in a glTF asset.
+#[cfg(feature = "skinning")]
+#[cfg_attr(docsrs, doc(cfg(feature = "skinning")))]
 #[derive(Clone, Debug)]
 pub struct Skins<'a> {
     /// Internal skin iterator.
@@ -202,7 +211,9 @@ impl<'a> Iterator for Accessors<'a> {
     }
 }
 
+#[cfg(feature = "animation")]
 impl<'a> ExactSizeIterator for Animations<'a> {}
+#[cfg(feature = "animation")]
 impl<'a> Iterator for Animations<'a> {
     type Item = Animation<'a>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -323,7 +334,9 @@ impl<'a> Iterator for Views<'a> {
     }
 }
 
+#[cfg(feature = "cameras")]
 impl<'a> ExactSizeIterator for Cameras<'a> {}
+#[cfg(feature = "cameras")]
 impl<'a> Iterator for Cameras<'a> {
     type Item = Camera<'a>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -572,7 +585,9 @@ impl<'a> Iterator for Scenes<'a> {
     }
 }
 
+#[cfg(feature = "skinning")]
 impl<'a> ExactSizeIterator for Skins<'a> {}
+#[cfg(feature = "skinning")]
 impl<'a> Iterator for Skins<'a> {
     type Item = Skin<'a>;
     fn next(&mut self) -> Option<Self::Item> {
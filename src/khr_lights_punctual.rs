@@ -1,3 +1,6 @@
+use alloc::vec::Vec;
+use core::f32::consts::PI;
+use core::intrinsics::{cosf32, sinf32};
 use crate::Document;
 use gltf_json::Extras;
 
@@ -41,7 +44,7 @@ impl<'a> Light<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
     }
 
     /// Optional application specific data.
@@ -61,6 +64,56 @@ impl<'a> Light<'a> {
         self.json.range
     }
 
+    /// Generates a wireframe gizmo of the light in the local space of its
+    /// node, where the light points down the negative Z axis.
+    ///
+    /// * Directional lights are drawn as an arrow of length `fallback_range`.
+    /// * Point lights are drawn as three orthogonal circles approximating the
+    ///   sphere of their range.
+    /// * Spot lights are drawn as the outer cone, with four edges from the
+    ///   apex, and the base circle of the inner cone if it is not empty. Each
+    ///   edge of the cone is as long as the range.
+    ///
+    /// Circles have `segments` segments, at least four. `fallback_range` is
+    /// used in place of an undefined, infinite range.
+    pub fn gizmo(&self, segments: u32, fallback_range: f32) -> Gizmo {
+        let segments = segments.max(4);
+        let range = self.range().unwrap_or(fallback_range);
+        let mut gizmo = Gizmo::default();
+        match self.kind() {
+            Kind::Directional => {
+                let length = fallback_range;
+                let origin = gizmo.push([0.0; 3]);
+                let tip = gizmo.push([0.0, 0.0, -length]);
+                gizmo.lines.push([origin, tip]);
+                let head = gizmo.positions.len() as u32;
+                for [x, y] in [[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0], [0.0, -1.0]] {
+                    gizmo.push([0.1 * length * x, 0.1 * length * y, -0.8 * length]);
+                }
+                gizmo.lines.extend((head..head + 4).map(|base| [tip, base]));
+            }
+            Kind::Point => {
+                gizmo.circle(segments, |cos, sin| [range * cos, range * sin, 0.0]);
+                gizmo.circle(segments, |cos, sin| [0.0, range * cos, range * sin]);
+                gizmo.circle(segments, |cos, sin| [range * sin, 0.0, range * cos]);
+            }
+            Kind::Spot {
+                inner_cone_angle,
+                outer_cone_angle,
+            } => {
+                let apex = gizmo.push([0.0; 3]);
+                let base = gizmo.cone_base(segments, outer_cone_angle, range);
+                gizmo
+                    .lines
+                    .extend((0..4).map(|i| [apex, base + i * segments / 4]));
+                if inner_cone_angle > 0.0 {
+                    gizmo.cone_base(segments, inner_cone_angle, range);
+                }
+            }
+        }
+        gizmo
+    }
+
     /// Specifies the light subcategory.
     pub fn kind(&self) -> Kind {
         use json::extensions::scene::khr_lights_punctual::Type;
@@ -78,6 +131,46 @@ impl<'a> Light<'a> {
     }
 }
 
+/// Wireframe geometry in the local space of a light's node, as generated by
+/// [`Light::gizmo`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Gizmo {
+    /// The vertex positions.
+    pub positions: Vec<[f32; 3]>,
+
+    /// The index pairs of `positions` that form line segments.
+    pub lines: Vec<[u32; 2]>,
+}
+
+impl Gizmo {
+    fn push(&mut self, position: [f32; 3]) -> u32 {
+        self.positions.push(position);
+        self.positions.len() as u32 - 1
+    }
+
+    /// Adds a closed loop of `segments` points, returning the first index.
+    fn circle<F>(&mut self, segments: u32, point: F) -> u32
+    where
+        F: Fn(f32, f32) -> [f32; 3],
+    {
+        let first = self.positions.len() as u32;
+        for i in 0..segments {
+            let angle = 2.0 * PI * i as f32 / segments as f32;
+            self.push(point(cosf32(angle), sinf32(angle)));
+            self.lines.push([first + i, first + (i + 1) % segments]);
+        }
+        first
+    }
+
+    /// Adds a circle around the negative Z axis at the given cone angle, with
+    /// the given slant distance from the apex.
+    fn cone_base(&mut self, segments: u32, angle: f32, slant: f32) -> u32 {
+        let radius = slant * sinf32(angle);
+        let z = -slant * cosf32(angle);
+        self.circle(segments, |cos, sin| [radius * cos, radius * sin, z])
+    }
+}
+
 /// Light subcategory.
 pub enum Kind {
     /// Directional lights are light sources that act as though they are infinitely far away
@@ -117,3 +210,70 @@ pub enum Kind {
         outer_cone_angle: f32,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GLTF: &str = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["KHR_lights_punctual"],
+        "extensions": {"KHR_lights_punctual": {"lights": [
+            {"type": "directional"},
+            {"type": "point", "range": 2},
+            {"type": "spot", "range": 4,
+             "spot": {"innerConeAngle": 0.5, "outerConeAngle": 0.7853982}},
+            {"type": "spot", "spot": {"innerConeAngle": 0, "outerConeAngle": 0.5}}
+        ]}}
+    }"#;
+
+    fn light_gizmo(index: usize, segments: u32) -> Gizmo {
+        let document = crate::Gltf::from_slice(GLTF.as_bytes()).unwrap().document;
+        let light = document.lights().unwrap().nth(index).unwrap();
+        light.gizmo(segments, 10.0)
+    }
+
+    fn length(gizmo: &Gizmo, [a, b]: [u32; 2]) -> f32 {
+        let (a, b) = (gizmo.positions[a as usize], gizmo.positions[b as usize]);
+        let d = [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+        unsafe { core::intrinsics::sqrtf32(d[0] * d[0] + d[1] * d[1] + d[2] * d[2]) }
+    }
+
+    #[test]
+    fn directional_arrow() {
+        let gizmo = light_gizmo(0, 16);
+        assert_eq!(gizmo.positions[1], [0.0, 0.0, -10.0]);
+        assert_eq!(gizmo.lines.len(), 5);
+        assert!(gizmo.positions.iter().all(|p| p[2] <= 0.0));
+    }
+
+    #[test]
+    fn point_sphere() {
+        let gizmo = light_gizmo(1, 3);
+        assert_eq!(gizmo.positions.len(), 12);
+        assert_eq!(gizmo.lines.len(), 12);
+        for position in &gizmo.positions {
+            let radius = position.iter().map(|x| x * x).sum::<f32>();
+            assert_relative_eq!(radius, 4.0, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn spot_cones() {
+        let gizmo = light_gizmo(2, 8);
+        assert_eq!(gizmo.positions.len(), 17);
+        assert_eq!(gizmo.lines.len(), 20);
+        for &line in &gizmo.lines[8..12] {
+            assert_eq!(line[0], 0);
+            assert_relative_eq!(length(&gizmo, line), 4.0, epsilon = 1e-5);
+        }
+        // The outer cone opens at 45 degrees.
+        let [x, _, z] = gizmo.positions[1];
+        assert_relative_eq!(x, -z, epsilon = 1e-5);
+
+        // An empty inner cone is not drawn, and an infinite range falls back.
+        let gizmo = light_gizmo(3, 8);
+        assert_eq!(gizmo.positions.len(), 9);
+        assert_relative_eq!(length(&gizmo, gizmo.lines[8]), 10.0, epsilon = 1e-5);
+    }
+}
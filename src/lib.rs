@@ -74,7 +74,7 @@
 //! You can read glTF without loading resources by constructing the [`Gltf`]
 //! (standard glTF) or [`Glb`] (binary glTF) data structures explicitly. Buffer
 //! and image data can then be imported separately using [`import_buffers`] and
-//! [`import_images`] respectively.
+//! [`import_images_with`] respectively.
 //!
 //! [glTF 2.0]: https://www.khronos.org/gltf
 //! [`Gltf`]: struct.Gltf.html
@@ -97,21 +97,67 @@ extern crate alloc;
 /// Accessors for reading vertex attributes from buffer views.
 pub mod accessor;
 
+/// Read-only inspections of glTF assets.
+pub mod analysis;
+
 /// Animations, their channels, targets, and samplers.
+#[cfg(feature = "animation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
 pub mod animation;
 
+/// Arena storage for the names, URIs and extras of imported documents.
+#[cfg(feature = "arena")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arena")))]
+pub use json::arena;
+
 /// Primitives for working with binary glTF.
 pub mod binary;
 
+/// Programmatic construction of documents.
+pub mod build;
+
 /// Buffers and buffer views.
 pub mod buffer;
 
 /// Cameras and their projections.
+#[cfg(feature = "cameras")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cameras")))]
 pub mod camera;
 
+/// Validation-only checks of glTF assets that do not build a document.
+pub mod check;
+
+/// sRGB transfer functions.
+pub mod color;
+
+/// Descriptions of errors in terms of the names and uses of entities.
+pub mod context;
+
+/// Unit and up axis conventions declared in asset extras.
+pub mod convention;
+
+/// Stable codes and severities of the findings about assets.
+pub mod diagnostic;
+
+/// Streaming export of accessor data for offline processing, and `.glb` export.
+pub mod export;
+
+/// Typed access to the extension data unknown to this crate version.
+#[cfg(feature = "extensions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
+pub mod extension;
+
+/// Faults recorded by fault-tolerant imports.
+pub mod fault;
+
 /// Images that may be used by textures.
 pub mod image;
 
+/// Compact binary snapshots of documents for loading without parsing JSON.
+#[cfg(feature = "bake")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bake")))]
+pub mod bake;
+
 /// The reference importer.
 #[cfg(feature = "import")]
 #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
@@ -133,21 +179,41 @@ pub mod khr_materials_variants;
 /// Material properties of primitives.
 pub mod material;
 
+/// For internal use.
+mod debug_json;
+
 /// For internal use.
 mod math;
 
 /// Meshes and their primitives.
 pub mod mesh;
 
+/// Transcoding of assets to GLB through a list of transforms.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+pub mod pipeline;
+
 /// The glTF node heirarchy.
 pub mod scene;
 
+/// Owned snapshots of documents for storing as `'static` resources.
+pub mod snapshot;
+
 /// Mesh skinning primitives.
+#[cfg(feature = "skinning")]
+#[cfg_attr(docsrs, doc(cfg(feature = "skinning")))]
 pub mod skin;
 
+/// Byte spans of entities within the JSON text of an asset.
+pub mod span;
+
 /// Textures and their samplers.
 pub mod texture;
 
+/// Transformations that rewrite glTF assets.
+pub mod transform;
+
+use alloc::collections::BTreeSet;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::{ops, result};
@@ -158,14 +224,21 @@ use serde_json::Map;
 
 #[doc(inline)]
 pub use self::accessor::Accessor;
+#[cfg(feature = "animation")]
 #[doc(inline)]
 pub use self::animation::Animation;
 #[doc(inline)]
-pub use self::binary::Glb;
+pub use self::binary::{
+    ChunkAlignment, DebugJsonOptions, Glb, GlbLayout, GlbOptions, PartialGlb, ViewRangeTable,
+};
 #[doc(inline)]
 pub use self::buffer::Buffer;
+#[cfg(feature = "cameras")]
 #[doc(inline)]
 pub use self::camera::Camera;
+#[cfg(feature = "extensions")]
+#[doc(inline)]
+pub use self::extension::HasExtensions;
 #[doc(inline)]
 pub use self::image::Image;
 // #[cfg(feature = "import")]
@@ -174,18 +247,37 @@ pub use self::image::Image;
 #[cfg(feature = "import")]
 #[doc(inline)]
 pub use self::import::import_buffers;
-// #[cfg(feature = "import")]
-// #[doc(inline)]
-// pub use self::import::import_images;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::import_buffers_partial;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::import_buffers_with;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::import_images_raw;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::import_images_with;
 #[cfg(feature = "import")]
 #[doc(inline)]
 pub use self::import::import_slice;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::import_slice_with_extensions;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::{import_slice_resilient, import_slice_resilient_with};
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::{update_in_place, UpdateReport};
 #[doc(inline)]
 pub use self::material::Material;
 #[doc(inline)]
 pub use self::mesh::{Attribute, Mesh, Primitive, Semantic};
 #[doc(inline)]
 pub use self::scene::{Node, Scene};
+#[cfg(feature = "skinning")]
 #[doc(inline)]
 pub use self::skin::Skin;
 #[doc(inline)]
@@ -195,6 +287,27 @@ pub(crate) trait Normalize<T> {
     fn normalize(self) -> T;
 }
 
+/// Converts a size or offset of the JSON to `usize`, saturating on targets
+/// where it does not fit, so that any range it bounds fails its checks.
+pub(crate) fn saturating_usize(value: u64) -> usize {
+    usize::try_from(value).unwrap_or(usize::MAX)
+}
+
+/// Treats empty and whitespace-only strings as absent.
+pub(crate) fn non_blank(value: Option<&str>) -> Option<&str> {
+    value.filter(|value| !value.trim().is_empty())
+}
+
+/// Parses a glTF version of the form `<major>.<minor>`.
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let (major, minor) = version.split_once('.')?;
+    let number = |digits: &str| match digits.bytes().all(|x| x.is_ascii_digit()) {
+        true => digits.parse().ok(),
+        false => None,
+    };
+    Some((number(major)?, number(minor)?))
+}
+
 /// Result type for convenience.
 pub type Result<T> = result::Result<T, Error>;
 
@@ -223,18 +336,82 @@ pub enum Error {
         actual: usize,
     },
 
+    /// The asset was rejected by [`Gltf::check`].
+    Check(check::CheckError),
+
     /// JSON deserialization error.
     Deserialize(json::Error),
 
-    // /// Image decoding error.
-    // #[cfg(feature = "import")]
-    // #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
-    // Image(image_crate::ImageError),
+    /// JSON serialization error.
+    Serialize(json::Error),
+
+    /// An entity that was faulted by a fault-tolerant import was looked up;
+    /// see [`Document::is_faulted`].
+    Faulted {
+        /// The kind of the faulted entity.
+        entity_kind: fault::EntityKind,
+
+        /// The index of the faulted entity, within its mesh for primitives.
+        index: usize,
+    },
+
+    /// Image decoding error, reported by an [`image::ImageDecoder`].
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    Image(alloc::boxed::Box<dyn core::error::Error + Send + Sync>),
+
+    /// A buffer refers to the `BIN` chunk of binary glTF, which does not
+    /// exist.
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    MissingBlob {
+        /// The index of the buffer.
+        buffer: usize,
+
+        /// The declared `byteLength` of the buffer.
+        length: usize,
+    },
 
-    /// The `BIN` chunk of binary glTF is referenced but does not exist.
+    /// A buffer has an external URI, which cannot be resolved without a
+    /// resolver; see [`import_buffers_with`].
     #[cfg(feature = "import")]
     #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
-    MissingBlob,
+    UnresolvedUri {
+        /// The index of the buffer.
+        buffer: usize,
+
+        /// The declared `byteLength` of the buffer.
+        length: usize,
+
+        /// The URI of the buffer.
+        uri: String,
+    },
+
+    /// A buffer `data:` URI is marked as base 64 but is not valid base 64.
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    MalformedBase64 {
+        /// The index of the offending buffer.
+        buffer: usize,
+
+        /// The base 64 decoding error.
+        error: base64::DecodeError,
+    },
+
+    /// An external buffer could not be fetched by the resolver passed to
+    /// [`import_buffers_with`].
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    ExternalBuffer {
+        /// The index of the buffer.
+        index: usize,
+
+        /// The URI of the buffer.
+        uri: String,
+
+        /// The error of the resolver.
+        error: alloc::boxed::Box<dyn core::error::Error + Send + Sync>,
+    },
 
     /// An external file is referenced in a slice only import without path
     #[cfg(feature = "import")]
@@ -244,7 +421,10 @@ pub enum Error {
     /// Unsupported image encoding.
     #[cfg(feature = "import")]
     #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
-    UnsupportedImageEncoding,
+    UnsupportedImageEncoding {
+        /// The first 8 bytes of the encoded data, or all of it if shorter.
+        prefix: Vec<u8>,
+    },
 
     // /// Unsupported image format.
     // #[cfg(feature = "import")]
@@ -256,10 +436,110 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
     UnsupportedScheme,
 
+    /// The asset requires extensions that are not supported; see
+    /// [`Document::check_required_extensions`].
+    UnsupportedExtensions(Vec<String>),
+
+    /// `asset.version` or `asset.minVersion` is not a glTF 2.0 version.
+    ///
+    /// Assets with a newer 2.x minor version are accepted for forward
+    /// compatibility unless they declare a `minVersion` above 2.0.
+    UnsupportedVersion {
+        /// The major version number.
+        major: u32,
+
+        /// The minor version number.
+        minor: u32,
+    },
+
     /// glTF validation error.
     Validation(Vec<(json::Path, json::validation::Error)>),
+
+    /// glTF validation error reported by [`Gltf::from_slice_with_spans`],
+    /// with the byte span of the entity that each error concerns.
+    SpannedValidation(
+        Vec<(
+            json::Path,
+            json::validation::Error,
+            Option<ops::Range<usize>>,
+        )>,
+    ),
 }
 
+/// Represents a condition that does not prevent loading but may indicate a
+/// problem with the asset.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Warning {
+    /// The `BIN` chunk of binary glTF is present but no buffer refers to it.
+    UnreferencedBlob {
+        /// The length of the chunk in bytes.
+        length: usize,
+    },
+
+    /// A primitive lacks vertex attributes that its material reads.
+    MissingAttributes {
+        /// The index of the mesh.
+        mesh: usize,
+
+        /// The index of the primitive within the mesh.
+        primitive: usize,
+
+        /// The missing attribute semantics.
+        semantics: Vec<mesh::Semantic>,
+    },
+
+    /// A mesh has no primitives, although the specification requires at
+    /// least one. The mesh draws nothing.
+    EmptyMesh {
+        /// The index of the mesh.
+        mesh: usize,
+    },
+
+    /// A material factor exceeds the range of `f32`.
+    ///
+    /// The factor is clamped to the range when accessed.
+    FactorOutOfRange {
+        /// The path of the factor.
+        path: json::Path,
+    },
+
+    /// An animation channel targets a node outside the scenes; see
+    /// [`analysis::dangling_animation_targets`].
+    DanglingAnimationTarget {
+        /// The index of the animation.
+        animation: usize,
+
+        /// The index of the channel within the animation.
+        channel: usize,
+
+        /// The index of the targeted node.
+        node: usize,
+    },
+
+    /// The asset uses an extension that has been deprecated by Khronos.
+    ///
+    /// This is informational, since the extension may still be supported.
+    DeprecatedExtension {
+        /// The name of the extension.
+        name: &'static str,
+    },
+
+    /// A primitive whose material has a normal map lacks `TANGENT`.
+    ///
+    /// This is informational, since the specification lets clients generate
+    /// the tangents.
+    GeneratedTangents {
+        /// The index of the mesh.
+        mesh: usize,
+
+        /// The index of the primitive within the mesh.
+        primitive: usize,
+    },
+}
+
+/// Extensions that Khronos has deprecated in favour of newer ones.
+const DEPRECATED_EXTENSIONS: &[&str] = &["KHR_materials_pbrSpecularGlossiness"];
+
 /// glTF JSON wrapper plus binary payload.
 #[derive(Clone, Debug)]
 pub struct Gltf {
@@ -272,31 +552,196 @@ pub struct Gltf {
 
 /// glTF JSON wrapper.
 #[derive(Clone, Debug)]
-pub struct Document(json::Root);
+pub struct Document(
+    json::Root,
+    BTreeSet<(fault::EntityKind, usize)>,
+    span::SourceSpans,
+);
 
 impl Gltf {
     /// Loads glTF from a slice of bytes without performing validation
     /// checks.
     pub fn from_slice_without_validation(slice: &[u8]) -> Result<Self> {
-        let (json, blob): (json::Root, Option<Vec<u8>>);
+        Self::from_slice_without_validation_with(slice, binary::GlbOptions::default(), false)
+    }
+
+    /// Loads glTF without validation, recording the source spans of the
+    /// entities if `record_spans` is set.
+    pub(crate) fn from_slice_without_validation_with(
+        slice: &[u8],
+        options: binary::GlbOptions,
+        record_spans: bool,
+    ) -> Result<Self> {
+        let scan = |text: &[u8]| match record_spans {
+            true => span::SourceSpans::scan(text),
+            false => span::SourceSpans::default(),
+        };
+        let (json, blob, spans): (json::Root, Option<Vec<u8>>, span::SourceSpans);
         if slice.starts_with(b"glTF") {
-            let mut glb = binary::Glb::from_slice(slice)?;
+            let mut glb = binary::Glb::from_slice_with(slice, options)?;
             json = json::deserialize::from_slice(&glb.json)?;
+            spans = scan(&glb.json);
             blob = glb.bin.take().map(|x| x.into_owned());
         } else {
             json = json::deserialize::from_slice(slice)?;
+            spans = scan(slice);
             blob = None;
         };
-        let document = Document::from_json_without_validation(json);
+        let mut document = Document::from_json_without_validation(json);
+        document.2 = spans;
+        Ok(Gltf { document, blob })
+    }
+
+    /// Assembles glTF from pre-deserialized JSON and the binary payload, if
+    /// any, performing the same validation checks as [`Gltf::from_slice`].
+    pub fn from_parts(json: json::Root, blob: Option<Vec<u8>>) -> Result<Self> {
+        let document = Document::from_json(json)?;
         Ok(Gltf { document, blob })
     }
 
     /// Loads glTF from a slice of bytes.
     pub fn from_slice(slice: &[u8]) -> Result<Self> {
-        let gltf = Self::from_slice_without_validation(slice)?;
+        Self::from_slice_with(slice, binary::GlbOptions::default())
+    }
+
+    /// Loads glTF from a slice of bytes, accepting the GLB container versions
+    /// of `options`.
+    ///
+    /// The glTF version of the asset is checked independently of the
+    /// container version; see [`Error::UnsupportedVersion`].
+    pub fn from_slice_with(slice: &[u8], options: binary::GlbOptions) -> Result<Self> {
+        let gltf = Self::from_slice_without_validation_with(slice, options, false)?;
         gltf.document.validate()?;
         Ok(gltf)
     }
+
+    /// Loads glTF from a slice of bytes, recording the byte spans of its
+    /// entities within the JSON; see [`Document::source_span`].
+    ///
+    /// The JSON is scanned once more before it is deserialized. Validation
+    /// errors are reported as [`Error::SpannedValidation`], with the span of
+    /// the entity that each error concerns.
+    pub fn from_slice_with_spans(slice: &[u8]) -> Result<Self> {
+        let options = binary::GlbOptions::default();
+        let gltf = Self::from_slice_without_validation_with(slice, options, true)?;
+        match gltf.document.validate() {
+            Ok(()) => Ok(gltf),
+            Err(Error::Validation(errors)) => {
+                let errors = errors
+                    .into_iter()
+                    .map(|(path, error)| {
+                        let span = gltf.document.2.get_path(&path);
+                        (path, error, span)
+                    })
+                    .collect();
+                Err(Error::SpannedValidation(errors))
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Loads glTF from a slice of bytes, storing the names, URIs and extras
+    /// of the document in `arena` rather than on the heap.
+    ///
+    /// See [`json::Root::from_slice_in`] for details.
+    #[cfg(feature = "arena")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arena")))]
+    pub fn from_slice_in(slice: &[u8], arena: &arena::ImportArena) -> Result<Self> {
+        let mut gltf = arena.scope(slice, || Self::from_slice(slice))?;
+        gltf.document.0.move_strings_into(arena);
+        Ok(gltf)
+    }
+
+    /// Checks that a slice of bytes looks like a loadable glTF asset, without
+    /// building the document.
+    ///
+    /// This validates the GLB structure, the JSON syntax and `asset.version`,
+    /// checks `extensionsRequired` against [`json::extensions::SUPPORTED`],
+    /// and compares the lengths of the top-level arrays and the declared
+    /// buffer and embedded image sizes against `limits`. The JSON is scanned
+    /// in place, so memory use is proportional to its nesting depth only.
+    ///
+    /// Passing the check does not imply that [`Gltf::from_slice`] succeeds,
+    /// since most of the validation rules are not applied.
+    pub fn check(slice: &[u8], limits: &check::CheckLimits) -> Result<check::CheckSummary> {
+        check::check(slice, limits)
+    }
+
+    /// Returns the binary payload if no buffer refers to it.
+    ///
+    /// Such a `BIN` chunk is either vestigial, because every buffer uses a
+    /// URI, or carries custom data for the application. It is ignored on
+    /// import.
+    pub fn unreferenced_blob(&self) -> Option<&[u8]> {
+        let referenced = self
+            .document
+            .buffers()
+            .any(|buffer| buffer.has_data() && matches!(buffer.source(), buffer::Source::Bin));
+        match self.blob.as_deref() {
+            Some(blob) if !referenced => Some(blob),
+            _ => None,
+        }
+    }
+
+    /// Reports conditions that do not prevent loading but may indicate a
+    /// problem with the asset.
+    pub fn warnings(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+        if let Some(blob) = self.unreferenced_blob() {
+            warnings.push(Warning::UnreferencedBlob { length: blob.len() });
+        }
+        for mesh in self.document.meshes() {
+            if mesh.primitives().len() == 0 {
+                warnings.push(Warning::EmptyMesh { mesh: mesh.index() });
+            }
+            for primitive in mesh.primitives() {
+                let mut semantics = primitive.missing_attributes(&primitive.material());
+                let tangents = semantics.contains(&Semantic::Tangents);
+                semantics.retain(|semantic| *semantic != Semantic::Tangents);
+                if tangents {
+                    warnings.push(Warning::GeneratedTangents {
+                        mesh: mesh.index(),
+                        primitive: primitive.index(),
+                    });
+                }
+                if !semantics.is_empty() {
+                    warnings.push(Warning::MissingAttributes {
+                        mesh: mesh.index(),
+                        primitive: primitive.index(),
+                        semantics,
+                    });
+                }
+            }
+        }
+        for material in self.document.materials() {
+            for path in material.out_of_range_factors() {
+                warnings.push(Warning::FactorOutOfRange { path });
+            }
+        }
+        #[cfg(feature = "animation")]
+        for (animation, channel, node) in analysis::dangling_animation_targets(&self.document) {
+            warnings.push(Warning::DanglingAnimationTarget {
+                animation,
+                channel,
+                node,
+            });
+        }
+        for &name in DEPRECATED_EXTENSIONS {
+            if self.document.extensions_used().any(|used| used == name) {
+                warnings.push(Warning::DeprecatedExtension { name });
+            }
+        }
+        warnings
+    }
+
+    /// Reports the validation errors and [warnings](Gltf::warnings) of the
+    /// asset, with the suppressions and severity overrides of `options`.
+    ///
+    /// The asset is validated as by [`Gltf::from_slice`], which makes this
+    /// useful for assets loaded with [`Gltf::from_slice_without_validation`].
+    pub fn report(&self, options: &diagnostic::ValidationOptions) -> diagnostic::Report {
+        diagnostic::Report::new(self, options)
+    }
 }
 
 impl ops::Deref for Gltf {
@@ -312,6 +757,12 @@ impl ops::DerefMut for Gltf {
     }
 }
 
+impl From<Gltf> for (Document, Option<Vec<u8>>) {
+    fn from(gltf: Gltf) -> Self {
+        (gltf.document, gltf.blob)
+    }
+}
+
 impl Document {
     /// Loads glTF from pre-deserialized JSON.
     pub fn from_json(json: json::Root) -> Result<Self> {
@@ -323,7 +774,18 @@ impl Document {
     /// Loads glTF from pre-deserialized JSON without performing
     /// validation checks.
     pub fn from_json_without_validation(json: json::Root) -> Self {
-        Document(json)
+        Document(json, BTreeSet::new(), span::SourceSpans::default())
+    }
+
+    /// Wraps JSON from a trusted source without performing validation checks.
+    ///
+    /// This is equivalent to [`Document::from_json_without_validation`]. No
+    /// index is checked, so any method that follows an out-of-range index may
+    /// panic; use [`Document::from_json`] unless the JSON is known to be
+    /// valid, for example because it was produced by another validated
+    /// document.
+    pub fn from_root_unchecked(json: json::Root) -> Self {
+        Self::from_json_without_validation(json)
     }
 
     /// Unwraps the glTF document.
@@ -336,10 +798,122 @@ impl Document {
         &self.0
     }
 
+    /// Serializes the glTF document as a JSON byte vector.
+    ///
+    /// Properties that are absent or have their default value are omitted.
+    /// With the `extensions` feature enabled, extensions that this crate does
+    /// not model are written back with the same values, and with the `extras`
+    /// feature enabled, extras are written back verbatim.
+    ///
+    /// Modify the document by editing the JSON of [`Document::into_json`] and
+    /// loading it again with [`Document::from_json`].
+    pub fn to_json_vec(&self) -> result::Result<Vec<u8>, json::Error> {
+        self.0.to_vec()
+    }
+
+    /// Serializes the glTF document as a JSON string; see
+    /// [`Document::to_json_vec`].
+    pub fn to_json_string(&self) -> result::Result<String, json::Error> {
+        self.0.to_string()
+    }
+
+    /// Classifies the primitive instances of the default scene, or of the
+    /// first scene if there is no default, by alpha mode.
+    ///
+    /// Primitives with a light transmitting material are placed in the blend
+    /// bucket. Nodes are visited as by [`Scene::traverse`], so nodes after a
+    /// cycle in the hierarchy are not classified.
+    pub fn render_buckets(&self) -> analysis::RenderBuckets {
+        analysis::render_buckets(self, &material::MaterialOverrides::new(self))
+    }
+
+    /// Classifies the primitive instances like [`Document::render_buckets`],
+    /// using the materials that `overrides` substitutes for some instances.
+    pub fn render_buckets_with(
+        &self,
+        overrides: &material::MaterialOverrides,
+    ) -> analysis::RenderBuckets {
+        analysis::render_buckets(self, overrides)
+    }
+
+    /// Returns, per buffer, the byte ranges read by the accessors that the
+    /// policy keeps on the CPU, including their sparse storage.
+    ///
+    /// Pass the ranges of each buffer to [`buffer::RetainedData::retain_ranges`]
+    /// to free the rest of the buffer data after uploading it.
+    pub fn cpu_required_ranges(&self, policy: analysis::RetentionPolicy) -> buffer::RangeSet {
+        analysis::cpu_required_ranges(self, policy)
+    }
+
+    /// Returns the nodes targeted by each animation, keyed by animation and
+    /// node index.
+    ///
+    /// See [`Animation::binding_table`] for the table of a single animation.
+    #[cfg(feature = "animation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
+    pub fn animation_bindings(&self) -> animation::AnimationBindings {
+        animation::animation_bindings(self)
+    }
+
+    /// Copies the nodes, meshes, materials and animations of the document into
+    /// owned structs that do not borrow it.
+    ///
+    /// See [`Document::snapshot_with`] for details.
+    pub fn snapshot(
+        &self,
+        buffers: &[buffer::Data],
+    ) -> result::Result<snapshot::OwnedScene, accessor::Error> {
+        snapshot::snapshot(self, buffers, snapshot::SnapshotOptions::default())
+    }
+
+    /// Copies the nodes, meshes, materials and animations of the document into
+    /// owned structs that do not borrow it.
+    ///
+    /// Buffer data is only read for animation keyframes, which fails if their
+    /// data is unavailable. Texture references are resolved to their images
+    /// and samplers.
+    pub fn snapshot_with(
+        &self,
+        buffers: &[buffer::Data],
+        options: snapshot::SnapshotOptions,
+    ) -> result::Result<snapshot::OwnedScene, accessor::Error> {
+        snapshot::snapshot(self, buffers, options)
+    }
+
+    /// Returns the `(accessor, element)` pairs of float elements that contain
+    /// NaN, listing at most [`analysis::NAN_HITS_PER_ACCESSOR`] elements per
+    /// accessor.
+    ///
+    /// Accessors whose data lies outside the available buffer data are
+    /// skipped. See [`analysis::accessor_stats`] for further statistics.
+    pub fn scan_for_nans(&self, buffers: &[buffer::Data]) -> Vec<(usize, usize)> {
+        analysis::scan_for_nans(self, buffers)
+    }
+
     /// Perform validation checks on loaded glTF.
     pub(crate) fn validate(&self) -> Result<()> {
         use json::validation::Validate;
         let mut errors = Vec::new();
+        let asset = &self.0.asset;
+        let versions = [
+            (Some(&asset.version), "version"),
+            (asset.min_version.as_ref(), "minVersion"),
+        ];
+        for (version, field) in versions {
+            let version = match version {
+                Some(version) => version,
+                None => continue,
+            };
+            match parse_version(version) {
+                Some((2, 0)) => {}
+                Some((2, _)) if field == "version" => {}
+                Some((major, minor)) => return Err(Error::UnsupportedVersion { major, minor }),
+                None => errors.push((
+                    json::Path::new().field("asset").field(field),
+                    json::validation::Error::Invalid,
+                )),
+            }
+        }
         self.0
             .validate(&self.0, json::Path::new, &mut |path, error| {
                 errors.push((path(), error))
@@ -351,6 +925,52 @@ impl Document {
         }
     }
 
+    /// Returns `true` if an entity was faulted by
+    /// [`import_slice_resilient`], either because it could not be loaded or
+    /// because it depends on a faulted entity.
+    ///
+    /// The data of faulted entities is never read; readers report
+    /// [`accessor::Error::Faulted`] for faulted accessors and
+    /// [`accessor::Error::FaultedPrimitive`] for faulted primitives instead.
+    /// The `try_` lookups of primitives, such as [`Primitive::try_get`] and
+    /// [`Primitive::try_material`], report [`Error::Faulted`] rather than
+    /// returning a faulted entity. Other methods of faulted entities may
+    /// panic, as the entities may be invalid.
+    ///
+    /// [`Primitive::try_get`]: mesh::Primitive::try_get
+    /// [`Primitive::try_material`]: mesh::Primitive::try_material
+    pub fn is_faulted(&self, entity_kind: fault::EntityKind, index: usize) -> bool {
+        self.1.contains(&(entity_kind, index))
+    }
+
+    /// Returns `Err(Error::Faulted { .. })` if an entity is faulted.
+    pub(crate) fn check_faulted(&self, entity_kind: fault::EntityKind, index: usize) -> Result<()> {
+        match self.is_faulted(entity_kind, index) {
+            true => Err(Error::Faulted { entity_kind, index }),
+            false => Ok(()),
+        }
+    }
+
+    /// Returns the byte span of an entity within the JSON it was loaded
+    /// from, with the index of a primitive within its mesh.
+    ///
+    /// Spans are only recorded by [`Gltf::from_slice_with_spans`] and
+    /// [`import_slice_resilient`]; this returns `None` for documents loaded
+    /// otherwise.
+    pub fn source_span(
+        &self,
+        entity_kind: fault::EntityKind,
+        index: usize,
+    ) -> Option<ops::Range<usize>> {
+        self.2.get(entity_kind, index)
+    }
+
+    /// Returns the byte spans of the entities within the JSON it was loaded
+    /// from; see [`Document::source_span`].
+    pub fn source_spans(&self) -> &span::SourceSpans {
+        &self.2
+    }
+
     /// Returns an `Iterator` that visits the accessors of the glTF asset.
     pub fn accessors(&self) -> iter::Accessors {
         iter::Accessors {
@@ -360,6 +980,8 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the animations of the glTF asset.
+    #[cfg(feature = "animation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
     pub fn animations(&self) -> iter::Animations {
         iter::Animations {
             iter: self.0.animations.iter().enumerate(),
@@ -376,6 +998,8 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the cameras of the glTF asset.
+    #[cfg(feature = "cameras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cameras")))]
     pub fn cameras(&self) -> iter::Cameras {
         iter::Cameras {
             iter: self.0.cameras.iter().enumerate(),
@@ -383,6 +1007,36 @@ impl Document {
         }
     }
 
+    /// Returns an `Iterator` that visits the nodes of the scene at index
+    /// `scene` that reference a camera, with the camera and the world
+    /// transform of the node.
+    ///
+    /// Nodes are visited in the order of [`Scene::traverse`], so a camera
+    /// referenced by several nodes is visited once for each of them, and
+    /// nodes after a cycle in the hierarchy are not visited. Nothing is
+    /// visited if there is no scene at that index. The world transform places
+    /// the camera in the scene; its inverse is returned by
+    /// [`camera::view_matrix`].
+    #[cfg(feature = "cameras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cameras")))]
+    pub fn cameras_in_scene(
+        &self,
+        scene: usize,
+    ) -> impl Iterator<Item = (Node<'_>, Camera<'_>, [[f32; 4]; 4])> + '_ {
+        self.scenes()
+            .nth(scene)
+            .into_iter()
+            .flat_map(|scene| scene.cameras())
+    }
+
+    /// Returns the parent of every node, the inverse of their children.
+    ///
+    /// The map is built once, in time linear in the number of nodes and
+    /// children, and answers [`NodeParentMap::parent_of`] in constant time.
+    pub fn node_parents(&self) -> scene::NodeParentMap {
+        scene::NodeParentMap::new(self)
+    }
+
     /// Returns the default scene, if provided.
     pub fn default_scene(&self) -> Option<Scene> {
         self.0
@@ -401,6 +1055,25 @@ impl Document {
         iter::ExtensionsRequired(self.0.extensions_required.iter())
     }
 
+    /// Checks that every extension required by this asset is `supported`.
+    ///
+    /// Pass [`json::extensions::SUPPORTED`] for the extensions that this
+    /// build of the crate implements, which validation checks as well, or the
+    /// set of the caller when it decodes other extensions itself. Returns
+    /// [`Error::UnsupportedExtensions`] with the other required extensions, in
+    /// the order of the asset.
+    pub fn check_required_extensions(&self, supported: &[&str]) -> Result<()> {
+        let unsupported: Vec<String> = self
+            .extensions_required()
+            .filter(|name| !supported.contains(name))
+            .map(String::from)
+            .collect();
+        match unsupported.is_empty() {
+            true => Ok(()),
+            false => Err(Error::UnsupportedExtensions(unsupported)),
+        }
+    }
+
     /// Returns an `Iterator` that visits the pre-loaded images of the glTF asset.
     pub fn images(&self) -> iter::Images {
         iter::Images {
@@ -425,6 +1098,11 @@ impl Document {
         root.others.get(ext_name)
     }
 
+    /// Optional application specific data of the asset.
+    pub fn extras(&self) -> &json::Extras {
+        &self.0.extras
+    }
+
     /// Returns an `Iterator` that visits the lights of the glTF asset as defined by the
     /// `KHR_lights_punctual` extension.
     #[cfg(feature = "KHR_lights_punctual")]
@@ -508,6 +1186,8 @@ impl Document {
     }
 
     /// Returns an `Iterator` that visits the skins of the glTF asset.
+    #[cfg(feature = "skinning")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "skinning")))]
     pub fn skins(&self) -> iter::Skins {
         iter::Skins {
             iter: self.0.skins.iter().enumerate(),
@@ -551,23 +1231,67 @@ impl core::fmt::Display for Error {
                     buffer, expected, actual
                 )
             }
+            Error::Check(ref e) => e.fmt(f),
             Error::Deserialize(ref e) => e.fmt(f),
-            // #[cfg(feature = "import")]
-            // Error::Image(ref e) => e.fmt(f),
+            Error::Serialize(ref e) => e.fmt(f),
+            Error::Faulted { entity_kind, index } => {
+                write!(f, "{:?} {} is faulted", entity_kind, index)
+            }
             #[cfg(feature = "import")]
-            Error::MissingBlob => write!(f, "missing binary portion of binary glTF"),
+            Error::Image(ref e) => e.fmt(f),
+            #[cfg(feature = "import")]
+            Error::MissingBlob { buffer, length } => write!(
+                f,
+                "buffer {}: missing binary portion of binary glTF for {} bytes",
+                buffer, length
+            ),
+            #[cfg(feature = "import")]
+            Error::UnresolvedUri {
+                buffer,
+                length,
+                uri,
+            } => write!(
+                f,
+                "buffer {}: cannot resolve {} for {} bytes without a resolver",
+                buffer, uri, length
+            ),
+            #[cfg(feature = "import")]
+            Error::MalformedBase64 { buffer, error } => {
+                write!(
+                    f,
+                    "buffer {}: malformed base 64 data URI: {}",
+                    buffer, error
+                )
+            }
+            #[cfg(feature = "import")]
+            Error::ExternalBuffer { index, uri, error } => {
+                write!(f, "buffer {}: failed to resolve {}: {}", index, uri, error)
+            }
             #[cfg(feature = "import")]
             Error::ExternalReferenceInSliceImport => {
                 write!(f, "external reference in slice only import")
             }
             #[cfg(feature = "import")]
-            Error::UnsupportedImageEncoding => write!(f, "unsupported image encoding"),
+            Error::UnsupportedImageEncoding { prefix } => {
+                write!(f, "unsupported image encoding starting with [")?;
+                for (i, byte) in prefix.iter().enumerate() {
+                    let separator = if i == 0 { "" } else { " " };
+                    write!(f, "{}{:02x}", separator, byte)?;
+                }
+                write!(f, "]")
+            }
             // #[cfg(feature = "import")]
             // Error::UnsupportedImageFormat(image) => {
             //     write!(f, "unsupported image format: {:?}", image.color())
             // }
             #[cfg(feature = "import")]
             Error::UnsupportedScheme => write!(f, "unsupported URI scheme"),
+            Error::UnsupportedExtensions(ref names) => {
+                write!(f, "unsupported required extensions: {}", names.join(", "))
+            }
+            Error::UnsupportedVersion { major, minor } => {
+                write!(f, "unsupported glTF asset version {}.{}", major, minor)
+            }
             Error::Validation(ref xs) => {
                 write!(f, "invalid glTF:")?;
                 for (ref path, ref error) in xs {
@@ -575,11 +1299,92 @@ impl core::fmt::Display for Error {
                 }
                 Ok(())
             }
+            Error::SpannedValidation(ref xs) => {
+                write!(f, "invalid glTF:")?;
+                for (ref path, ref error, ref span) in xs {
+                    write!(f, " {}: {}", path, error)?;
+                    if let Some(span) = span {
+                        write!(f, " (bytes {}..{})", span.start, span.end)?;
+                    }
+                    write!(f, ";")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::Binary(error) => Some(error),
+            Error::Check(error) => Some(error),
+            Error::Deserialize(error) => Some(error),
+            Error::Serialize(error) => Some(error),
+            #[cfg(feature = "import")]
+            Error::ExternalBuffer { error, .. } => Some(&**error),
+            #[cfg(feature = "import")]
+            Error::Image(error) => Some(&**error),
+            _ => None,
         }
     }
 }
 
-impl core::error::Error for Error {}
+impl core::fmt::Display for Warning {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Warning::UnreferencedBlob { length } => {
+                write!(
+                    f,
+                    "binary chunk of {} bytes is not referenced by any buffer",
+                    length
+                )
+            }
+            Warning::MissingAttributes {
+                mesh,
+                primitive,
+                semantics,
+            } => {
+                write!(
+                    f,
+                    "primitive {} of mesh {} lacks attributes read by its material:",
+                    primitive, mesh
+                )?;
+                for (i, semantic) in semantics.iter().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(
+                        f,
+                        "{}{}",
+                        separator,
+                        alloc::string::ToString::to_string(semantic)
+                    )?;
+                }
+                Ok(())
+            }
+            Warning::EmptyMesh { mesh } => write!(f, "mesh {} has no primitives", mesh),
+            Warning::FactorOutOfRange { path } => {
+                write!(f, "{} exceeds the range of f32 and is clamped", path)
+            }
+            Warning::DanglingAnimationTarget {
+                animation,
+                channel,
+                node,
+            } => write!(
+                f,
+                "channel {} of animation {} targets node {}, which is outside the scenes",
+                channel, animation, node
+            ),
+            Warning::DeprecatedExtension { name } => {
+                write!(f, "deprecated extension {} is used", name)
+            }
+            Warning::GeneratedTangents { mesh, primitive } => write!(
+                f,
+                "primitive {} of mesh {} has a normal map but no tangents, which will be generated",
+                primitive, mesh
+            ),
+        }
+    }
+}
 
 impl From<binary::Error> for Error {
     fn from(err: binary::Error) -> Self {
@@ -632,7 +1437,7 @@ impl Normalize<u16> for i8 {
 
 impl Normalize<f32> for i8 {
     fn normalize(self) -> f32 {
-        (self as f32 * 127.0_f32.recip()).max(-1.0)
+        (self as f32 / 127.0).max(-1.0)
     }
 }
 
@@ -662,7 +1467,7 @@ impl Normalize<u16> for u8 {
 
 impl Normalize<f32> for u8 {
     fn normalize(self) -> f32 {
-        self as f32 * 255.0_f32.recip()
+        self as f32 / 255.0
     }
 }
 
@@ -692,7 +1497,7 @@ impl Normalize<u16> for i16 {
 
 impl Normalize<f32> for i16 {
     fn normalize(self) -> f32 {
-        (self as f32 * 32767.0_f32.recip()).max(-1.0)
+        (self as f32 / 32767.0).max(-1.0)
     }
 }
 
@@ -722,7 +1527,7 @@ impl Normalize<u16> for u16 {
 
 impl Normalize<f32> for u16 {
     fn normalize(self) -> f32 {
-        self as f32 * 65535.0_f32.recip()
+        self as f32 / 65535.0
     }
 }
 
@@ -1,10 +1,25 @@
 use alloc::string::String;
-use crate::{texture, Document};
+use alloc::vec::Vec;
+use crate::mesh::Semantic;
+use crate::{color, texture, Document};
 
 pub use json::material::AlphaMode;
 #[cfg(feature = "extensions")]
 use serde_json::{Map, Value};
 
+mod overrides;
+
+#[doc(inline)]
+pub use self::overrides::{MaterialOverrides, Override, SyntheticMaterial};
+
+/// Clamps a factor to the finite range of `f32`.
+///
+/// JSON numbers beyond the range of `f32` parse as infinities, which
+/// [`Gltf::warnings`](crate::Gltf::warnings) reports.
+fn finite(factor: f32) -> f32 {
+    factor.clamp(f32::MIN, f32::MAX)
+}
+
 lazy_static! {
     static ref DEFAULT_MATERIAL: json::material::Material = Default::default();
 }
@@ -18,6 +33,9 @@ pub struct Material<'a> {
     /// The corresponding JSON index - `None` when the default material.
     index: Option<usize>,
 
+    /// The index of the synthetic material in its `MaterialOverrides`.
+    synthetic: Option<usize>,
+
     /// The corresponding JSON struct.
     json: &'a json::material::Material,
 }
@@ -32,6 +50,7 @@ impl<'a> Material<'a> {
         Self {
             document,
             index: Some(index),
+            synthetic: None,
             json,
         }
     }
@@ -41,10 +60,25 @@ impl<'a> Material<'a> {
         Self {
             document,
             index: None,
+            synthetic: None,
             json: &DEFAULT_MATERIAL,
         }
     }
 
+    /// Constructs a synthetic `Material`.
+    pub(crate) fn synthetic(
+        document: &'a Document,
+        index: usize,
+        json: &'a json::material::Material,
+    ) -> Self {
+        Self {
+            document,
+            index: None,
+            synthetic: Some(index),
+            json,
+        }
+    }
+
     /// Returns the internal JSON index if this `Material` was explicity defined.
     ///
     /// This function returns `None` if the `Material` is the default material.
@@ -52,9 +86,15 @@ impl<'a> Material<'a> {
         self.index
     }
 
+    /// Returns the index of this `Material` within its [`MaterialOverrides`]
+    /// if it is a synthetic material, which has no JSON index.
+    pub fn synthetic_index(&self) -> Option<usize> {
+        self.synthetic
+    }
+
     ///  The optional alpha cutoff value of the material.
     pub fn alpha_cutoff(&self) -> Option<f32> {
-        self.json.alpha_cutoff.map(|value| value.0)
+        self.json.alpha_cutoff.map(|value| finite(value.0))
     }
 
     /// The alpha rendering mode of the material.  The material's alpha rendering
@@ -88,7 +128,7 @@ impl<'a> Material<'a> {
     #[cfg(feature = "names")]
     #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
     }
 
     /// Parameter values that define the metallic-roughness material model from
@@ -232,7 +272,40 @@ impl<'a> Material<'a> {
     ///
     /// The default value is `[0.0, 0.0, 0.0]`.
     pub fn emissive_factor(&self) -> [f32; 3] {
-        self.json.emissive_factor.0
+        self.json.emissive_factor.0.map(finite)
+    }
+
+    /// The emissive color of the material in linear color space.
+    ///
+    /// The factor is stored in linear color space, so this is the same as
+    /// [`Self::emissive_factor`].
+    pub fn emissive_factor_linear(&self) -> [f32; 3] {
+        self.emissive_factor()
+    }
+
+    /// The emissive color of the material converted to sRGB.
+    pub fn emissive_factor_srgb(&self) -> [f32; 3] {
+        color::linear_to_srgb_rgb(self.emissive_factor())
+    }
+
+    /// The base color factor of the material in linear color space.
+    ///
+    /// The factor is stored in linear color space, so this is the same as
+    /// [`PbrMetallicRoughness::base_color_factor`]. Multiply it with
+    /// base color texture samples only after decoding them from sRGB.
+    pub fn base_color_factor_linear(&self) -> [f32; 4] {
+        self.json
+            .pbr_metallic_roughness
+            .base_color_factor
+            .0
+            .map(finite)
+    }
+
+    /// The base color factor of the material converted to sRGB.
+    ///
+    /// The alpha component is linear and is returned unchanged.
+    pub fn base_color_factor_srgb(&self) -> [f32; 4] {
+        color::linear_to_srgb_rgba(self.base_color_factor_linear())
     }
 
     /// Specifies whether the material is unlit.
@@ -250,12 +323,195 @@ impl<'a> Material<'a> {
             .map_or(false, |extensions| extensions.unlit.is_some())
     }
 
+    /// Returns the vertex attributes this material reads from the primitives
+    /// it is applied to.
+    ///
+    /// Texture coordinate sets honour the `texCoord` override of
+    /// `KHR_texture_transform`. Unlit materials only sample their base color
+    /// texture.
+    pub fn required_attributes(&self) -> RequiredAttributes {
+        let mut required = RequiredAttributes::default();
+        let mut tex_coords = Vec::new();
+        let mut sample = |info: &Option<json::texture::Info>| {
+            if let Some(info) = info.as_ref() {
+                tex_coords.push(tex_coord(info));
+            }
+        };
+        let pbr = &self.json.pbr_metallic_roughness;
+        sample(&pbr.base_color_texture);
+        if !unlit(self.json) {
+            sample(&pbr.metallic_roughness_texture);
+            sample(&self.json.emissive_texture);
+            if let Some(extensions) = self.json.extensions.as_ref() {
+                for info in extension_textures(extensions) {
+                    sample(info);
+                }
+            }
+            if let Some(normal) = self.json.normal_texture.as_ref() {
+                required.normal = true;
+                required.tangent = true;
+                tex_coords.push(normal_tex_coord(normal));
+            }
+            if let Some(occlusion) = self.json.occlusion_texture.as_ref() {
+                tex_coords.push(occlusion_tex_coord(occlusion));
+            }
+        }
+        tex_coords.sort_unstable();
+        tex_coords.dedup();
+        required.tex_coords = tex_coords;
+        required
+    }
+
+    /// Returns the paths of the factors that exceed the range of `f32`.
+    pub(crate) fn out_of_range_factors(&self) -> Vec<json::Path> {
+        let json = self.json;
+        let pbr = &json.pbr_metallic_roughness;
+        let base = match self.index {
+            Some(index) => json::Path::new().field("materials").index(index),
+            None => json::Path::new(),
+        };
+        let pbr_path = || base.field("pbrMetallicRoughness");
+        let mut factors = Vec::new();
+        let mut check = |values: &[f32], path: json::Path| {
+            if values.iter().any(|value| value.is_infinite()) {
+                factors.push(path);
+            }
+        };
+        if let Some(cutoff) = json.alpha_cutoff {
+            check(&[cutoff.0], base.field("alphaCutoff"));
+        }
+        check(&json.emissive_factor.0, base.field("emissiveFactor"));
+        check(
+            &pbr.base_color_factor.0,
+            pbr_path().field("baseColorFactor"),
+        );
+        check(&[pbr.metallic_factor.0], pbr_path().field("metallicFactor"));
+        check(
+            &[pbr.roughness_factor.0],
+            pbr_path().field("roughnessFactor"),
+        );
+        if let Some(normal) = &json.normal_texture {
+            check(&[normal.scale], base.field("normalTexture").field("scale"));
+        }
+        if let Some(occlusion) = &json.occlusion_texture {
+            let path = base.field("occlusionTexture").field("strength");
+            check(&[occlusion.strength.0], path);
+        }
+        factors
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 }
 
+/// The vertex attributes a material reads from the primitives it is applied
+/// to, as returned by [`Material::required_attributes`].
+///
+/// `COLOR_0` is never required, since vertex colors only modulate the base
+/// color when they are present.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RequiredAttributes {
+    /// Whether `NORMAL` is read, which is the case when the material has a
+    /// normal map.
+    pub normal: bool,
+
+    /// Whether `TANGENT` is read, which is the case when the material has a
+    /// normal map.
+    ///
+    /// The specification lets clients generate missing tangents, so
+    /// [`Gltf::warnings`] reports their absence as
+    /// [`Warning::GeneratedTangents`] rather than as missing.
+    ///
+    /// [`Gltf::warnings`]: crate::Gltf::warnings
+    /// [`Warning::GeneratedTangents`]: crate::Warning::GeneratedTangents
+    pub tangent: bool,
+
+    /// The `TEXCOORD_n` sets sampled by the material's textures, in ascending
+    /// order.
+    pub tex_coords: Vec<u32>,
+}
+
+impl RequiredAttributes {
+    /// Returns the required attribute semantics.
+    pub fn semantics(&self) -> Vec<Semantic> {
+        let mut semantics = Vec::new();
+        if self.normal {
+            semantics.push(Semantic::Normals);
+        }
+        if self.tangent {
+            semantics.push(Semantic::Tangents);
+        }
+        semantics.extend(self.tex_coords.iter().map(|&set| Semantic::TexCoords(set)));
+        semantics
+    }
+}
+
+/// Defines a function returning the `TEXCOORD_n` set that a texture reference
+/// samples, which `KHR_texture_transform` may override.
+macro_rules! tex_coord {
+    ($name:ident, $ty:ty) => {
+        #[cfg(feature = "KHR_texture_transform")]
+        fn $name(texture: &$ty) -> u32 {
+            texture
+                .extensions
+                .as_ref()
+                .and_then(|extensions| extensions.texture_transform.as_ref())
+                .and_then(|transform| transform.tex_coord)
+                .unwrap_or(texture.tex_coord)
+        }
+
+        #[cfg(not(feature = "KHR_texture_transform"))]
+        fn $name(texture: &$ty) -> u32 {
+            texture.tex_coord
+        }
+    };
+}
+
+tex_coord!(tex_coord, json::texture::Info);
+tex_coord!(normal_tex_coord, json::material::NormalTexture);
+tex_coord!(occlusion_tex_coord, json::material::OcclusionTexture);
+
+#[cfg(feature = "KHR_materials_unlit")]
+fn unlit(json: &json::material::Material) -> bool {
+    json.extensions
+        .as_ref()
+        .map_or(false, |extensions| extensions.unlit.is_some())
+}
+
+#[cfg(not(feature = "KHR_materials_unlit"))]
+fn unlit(_: &json::material::Material) -> bool {
+    false
+}
+
+/// Returns the texture slots of the supported material extensions.
+#[allow(unused_variables, unused_mut)]
+fn extension_textures(
+    extensions: &json::extensions::material::Material,
+) -> Vec<&Option<json::texture::Info>> {
+    let mut textures = Vec::new();
+    #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+    if let Some(pbr) = extensions.pbr_specular_glossiness.as_ref() {
+        textures.push(&pbr.diffuse_texture);
+        textures.push(&pbr.specular_glossiness_texture);
+    }
+    #[cfg(feature = "KHR_materials_transmission")]
+    if let Some(transmission) = extensions.transmission.as_ref() {
+        textures.push(&transmission.transmission_texture);
+    }
+    #[cfg(feature = "KHR_materials_volume")]
+    if let Some(volume) = extensions.volume.as_ref() {
+        textures.push(&volume.thickness_texture);
+    }
+    #[cfg(feature = "KHR_materials_specular")]
+    if let Some(specular) = extensions.specular.as_ref() {
+        textures.push(&specular.specular_texture);
+        textures.push(&specular.specular_color_texture);
+    }
+    textures
+}
+
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
 pub struct PbrMetallicRoughness<'a> {
@@ -279,7 +535,7 @@ impl<'a> PbrMetallicRoughness<'a> {
     ///
     /// The default value is `[1.0, 1.0, 1.0, 1.0]`.
     pub fn base_color_factor(&self) -> [f32; 4] {
-        self.json.base_color_factor.0
+        self.json.base_color_factor.0.map(finite)
     }
 
     /// Returns the base color texture. The texture contains RGB(A) components
@@ -295,7 +551,7 @@ impl<'a> PbrMetallicRoughness<'a> {
     ///
     /// The default value is `1.0`.
     pub fn metallic_factor(&self) -> f32 {
-        self.json.metallic_factor.0
+        finite(self.json.metallic_factor.0)
     }
 
     /// Returns the roughness factor of the material.
@@ -305,7 +561,7 @@ impl<'a> PbrMetallicRoughness<'a> {
     ///
     /// The default value is `1.0`.
     pub fn roughness_factor(&self) -> f32 {
-        self.json.roughness_factor.0
+        finite(self.json.roughness_factor.0)
     }
 
     /// The metallic-roughness texture.
@@ -576,6 +832,87 @@ impl<'a> PbrSpecularGlossiness<'a> {
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
+
+    /// Approximates the factors of the material in the metallic-roughness
+    /// model, for renderers without a specular-glossiness path.
+    ///
+    /// Only the factors are converted. Textures must be converted texel by
+    /// texel with [`ConvertedPbr::from_specular_glossiness`], after decoding
+    /// their colors to linear space.
+    pub fn to_metallic_roughness(&self) -> ConvertedPbr {
+        ConvertedPbr::from_specular_glossiness(
+            self.diffuse_factor(),
+            self.specular_factor(),
+            self.glossiness_factor(),
+        )
+    }
+}
+
+/// Metallic-roughness parameters converted from the specular-glossiness
+/// model by [`PbrSpecularGlossiness::to_metallic_roughness`].
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_pbrSpecularGlossiness")))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConvertedPbr {
+    /// The linear base color and alpha.
+    pub base_color_factor: [f32; 4],
+
+    /// The metalness, from 0.0 for dielectrics to 1.0 for metals.
+    pub metallic_factor: f32,
+
+    /// The roughness, which is one minus the glossiness.
+    pub roughness_factor: f32,
+}
+
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_pbrSpecularGlossiness")))]
+impl ConvertedPbr {
+    /// The specular reflectance of dielectrics at normal incidence.
+    const DIELECTRIC_SPECULAR: f32 = 0.04;
+
+    /// Converts linear specular-glossiness values with the approximation
+    /// published alongside the `KHR_materials_pbrSpecularGlossiness`
+    /// extension, which estimates the metalness from the specular color.
+    pub fn from_specular_glossiness(
+        diffuse: [f32; 4],
+        specular: [f32; 3],
+        glossiness: f32,
+    ) -> Self {
+        const EPSILON: f32 = 1e-6;
+        let dielectric = Self::DIELECTRIC_SPECULAR;
+        let brightness = |[r, g, b]: [f32; 3]| {
+            core::intrinsics::sqrtf32(0.299 * r * r + 0.587 * g * g + 0.114 * b * b)
+        };
+        let one_minus_specular = 1.0 - specular[0].max(specular[1]).max(specular[2]);
+
+        let diffuse_brightness = brightness([diffuse[0], diffuse[1], diffuse[2]]);
+        let specular_brightness = brightness(specular);
+        let metallic = if specular_brightness < dielectric {
+            0.0
+        } else {
+            let a = dielectric;
+            let b =
+                diffuse_brightness * one_minus_specular / (1.0 - a) + specular_brightness - 2.0 * a;
+            let c = a - specular_brightness;
+            let discriminant = (b * b - 4.0 * a * c).max(0.0);
+            ((-b + core::intrinsics::sqrtf32(discriminant)) / (2.0 * a)).clamp(0.0, 1.0)
+        };
+
+        let from_diffuse = one_minus_specular / (1.0 - dielectric) / (1.0 - metallic).max(EPSILON);
+        let from_specular = 1.0 / metallic.max(EPSILON);
+        let weight = metallic * metallic;
+        let mut base_color_factor = [0.0, 0.0, 0.0, diffuse[3]];
+        for i in 0..3 {
+            let diffuse = diffuse[i] * from_diffuse;
+            let specular = (specular[i] - dielectric * (1.0 - metallic)) * from_specular;
+            base_color_factor[i] = (diffuse + (specular - diffuse) * weight).clamp(0.0, 1.0);
+        }
+        Self {
+            base_color_factor,
+            metallic_factor: metallic,
+            roughness_factor: 1.0 - glossiness,
+        }
+    }
 }
 
 /// Defines the normal texture of a material.
@@ -598,7 +935,7 @@ impl<'a> NormalTexture<'a> {
 
     /// Returns the scalar multiplier applied to each normal vector of the texture.
     pub fn scale(&self) -> f32 {
-        self.json.scale
+        finite(self.json.scale)
     }
 
     /// The set index of the texture's `TEXCOORD` attribute.
@@ -611,6 +948,18 @@ impl<'a> NormalTexture<'a> {
         self.texture.clone()
     }
 
+    /// Returns texture transform information
+    #[cfg(feature = "KHR_texture_transform")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_transform")))]
+    pub fn texture_transform(&self) -> Option<texture::TextureTransform<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .texture_transform
+            .as_ref()
+            .map(texture::TextureTransform::new)
+    }
+
     /// Returns extension data unknown to this crate version.
     #[cfg(feature = "extensions")]
     #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
@@ -653,7 +1002,7 @@ impl<'a> OcclusionTexture<'a> {
 
     /// Returns the scalar multiplier controlling the amount of occlusion applied.
     pub fn strength(&self) -> f32 {
-        self.json.strength.0
+        finite(self.json.strength.0)
     }
 
     /// Returns the set index of the texture's `TEXCOORD` attribute.
@@ -666,6 +1015,18 @@ impl<'a> OcclusionTexture<'a> {
         self.texture.clone()
     }
 
+    /// Returns texture transform information
+    #[cfg(feature = "KHR_texture_transform")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_transform")))]
+    pub fn texture_transform(&self) -> Option<texture::TextureTransform<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .texture_transform
+            .as_ref()
+            .map(texture::TextureTransform::new)
+    }
+
     /// Returns extension data unknown to this crate version.
     #[cfg(feature = "extensions")]
     #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
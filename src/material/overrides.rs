@@ -0,0 +1,181 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use super::AlphaMode;
+use crate::{Document, Material};
+
+/// A material that replaces the material of a primitive instance; see
+/// [`MaterialOverrides::set`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Override {
+    /// A material of the document, by index.
+    Material(usize),
+
+    /// A synthetic material, as returned by [`MaterialOverrides::add`].
+    Synthetic(usize),
+}
+
+impl From<usize> for Override {
+    fn from(index: usize) -> Self {
+        Override::Material(index)
+    }
+}
+
+/// Builds a material that is not part of the document, such as a selection
+/// highlight created at runtime.
+#[derive(Clone, Debug, Default)]
+pub struct SyntheticMaterial {
+    json: json::material::Material,
+}
+
+impl SyntheticMaterial {
+    /// Starts from the default material.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts from a copy of an existing material, including its textures.
+    pub fn from_material(material: &Material) -> Self {
+        Self {
+            json: material.json.clone(),
+        }
+    }
+
+    /// Sets the base color factor, in linear space.
+    pub fn base_color_factor(mut self, factor: [f32; 4]) -> Self {
+        self.json.pbr_metallic_roughness.base_color_factor =
+            json::material::PbrBaseColorFactor(factor);
+        self
+    }
+
+    /// Sets the metalness factor.
+    pub fn metallic_factor(mut self, factor: f32) -> Self {
+        self.json.pbr_metallic_roughness.metallic_factor = json::material::StrengthFactor(factor);
+        self
+    }
+
+    /// Sets the roughness factor.
+    pub fn roughness_factor(mut self, factor: f32) -> Self {
+        self.json.pbr_metallic_roughness.roughness_factor = json::material::StrengthFactor(factor);
+        self
+    }
+
+    /// Sets the emissive factor, in linear space.
+    pub fn emissive_factor(mut self, factor: [f32; 3]) -> Self {
+        self.json.emissive_factor = json::material::EmissiveFactor(factor);
+        self
+    }
+
+    /// Sets the alpha rendering mode.
+    pub fn alpha_mode(mut self, mode: AlphaMode) -> Self {
+        self.json.alpha_mode = json::validation::Checked::Valid(mode);
+        self
+    }
+
+    /// Sets the alpha cutoff of the `MASK` alpha mode.
+    pub fn alpha_cutoff(mut self, cutoff: f32) -> Self {
+        self.json.alpha_cutoff = Some(json::material::AlphaCutoff(cutoff));
+        self
+    }
+
+    /// Sets whether back-face culling is disabled.
+    pub fn double_sided(mut self, double_sided: bool) -> Self {
+        self.json.double_sided = double_sided;
+        self
+    }
+
+    /// Sets the name of the material.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name(mut self, name: &str) -> Self {
+        self.json.name = Some(name.into());
+        self
+    }
+}
+
+/// Material replacements for individual primitive instances, keyed by node
+/// and primitive index.
+///
+/// The document is never modified: overrides are applied when the material is
+/// resolved with [`Primitive::material_with`] or by
+/// [`Document::render_buckets_with`].
+///
+/// [`Primitive::material_with`]: crate::Primitive::material_with
+#[derive(Clone, Debug)]
+pub struct MaterialOverrides<'a> {
+    /// The document the overrides apply to.
+    document: &'a Document,
+
+    /// The override of each primitive instance.
+    overrides: BTreeMap<(usize, usize), Override>,
+
+    /// The synthetic materials, indexed by `Override::Synthetic`.
+    synthetic: Vec<json::material::Material>,
+}
+
+impl<'a> MaterialOverrides<'a> {
+    /// Creates an empty set of overrides for a document.
+    pub fn new(document: &'a Document) -> Self {
+        Self {
+            document,
+            overrides: BTreeMap::new(),
+            synthetic: Vec::new(),
+        }
+    }
+
+    /// Adds a synthetic material, returning the override that refers to it.
+    pub fn add(&mut self, material: SyntheticMaterial) -> Override {
+        self.synthetic.push(material.json);
+        Override::Synthetic(self.synthetic.len() - 1)
+    }
+
+    /// Replaces the material of a primitive of the mesh instanced by a node.
+    ///
+    /// `material` is either the index of a material of the document or a
+    /// synthetic material returned by [`MaterialOverrides::add`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the material does not exist.
+    pub fn set<M: Into<Override>>(&mut self, node: usize, primitive: usize, material: M) {
+        let material = material.into();
+        match material {
+            Override::Material(index) => assert!(
+                index < self.document.as_json().materials.len(),
+                "material {} does not exist",
+                index
+            ),
+            Override::Synthetic(index) => assert!(
+                index < self.synthetic.len(),
+                "synthetic material {} does not exist",
+                index
+            ),
+        }
+        self.overrides.insert((node, primitive), material);
+    }
+
+    /// Removes the override of a primitive instance, returning it.
+    pub fn remove(&mut self, node: usize, primitive: usize) -> Option<Override> {
+        self.overrides.remove(&(node, primitive))
+    }
+
+    /// Returns the override of a primitive instance.
+    pub fn get(&self, node: usize, primitive: usize) -> Option<Override> {
+        self.overrides.get(&(node, primitive)).copied()
+    }
+
+    /// Returns `true` if no primitive instance is overridden.
+    pub fn is_empty(&self) -> bool {
+        self.overrides.is_empty()
+    }
+
+    /// Returns the overriding material of a primitive instance.
+    pub fn material(&self, node: usize, primitive: usize) -> Option<Material<'_>> {
+        Some(match self.get(node, primitive)? {
+            Override::Material(index) => self.document.materials().nth(index).unwrap(),
+            Override::Synthetic(index) => {
+                Material::synthetic(self.document, index, &self.synthetic[index])
+            }
+        })
+    }
+}
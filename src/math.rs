@@ -126,7 +126,18 @@ impl Vector3 {
         self.z *= s;
     }
 
-    #[cfg(test)]
+    pub fn dot(&self, other: &Vector3) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Vector3) -> Vector3 {
+        Vector3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
     pub fn normalize(self) -> Vector3 {
         self * (1.0 / self.magnitude())
     }
@@ -165,7 +176,6 @@ impl Vector4 {
         [self.x, self.y, self.z, self.w]
     }
 
-    #[cfg(test)]
     pub fn from_array([x, y, z, w]: [f32; 4]) -> Self {
         Self { x, y, z, w }
     }
@@ -252,7 +262,6 @@ impl Matrix4 {
         }
     }
 
-    #[cfg(test)]
     pub fn from_array([x, y, z, w]: [[f32; 4]; 4]) -> Matrix4 {
         Matrix4 {
             x: Vector4::from_array(x),
@@ -318,6 +327,70 @@ impl Matrix4 {
             self.w.as_array(),
         ]
     }
+
+    /// Transforms a point by an affine transformation matrix.
+    pub fn transform_point(&self, [x, y, z]: [f32; 3]) -> [f32; 3] {
+        let Vector4 { x, y, z, .. } = self.x * x + self.y * y + self.z * z + self.w;
+        [x, y, z]
+    }
+
+    /// Inverts the matrix by its adjugate, or returns `None` if it is singular
+    /// or its inverse is not finite.
+    #[cfg(all(feature = "skinning", feature = "utils"))]
+    pub fn invert(&self) -> Option<Matrix4> {
+        // The determinants of the 2x2 submatrices of the upper and lower two
+        // rows, as in the Laplace expansion along them.
+        let m = self.as_array();
+        let a = |r: usize, c: usize| m[c][r];
+        let upper = |c0: usize, c1: usize| a(0, c0) * a(1, c1) - a(0, c1) * a(1, c0);
+        let lower = |c0: usize, c1: usize| a(2, c0) * a(3, c1) - a(2, c1) * a(3, c0);
+        let (s0, s1, s2) = (upper(0, 1), upper(0, 2), upper(0, 3));
+        let (s3, s4, s5) = (upper(1, 2), upper(1, 3), upper(2, 3));
+        let (c5, c4, c3) = (lower(2, 3), lower(1, 3), lower(1, 2));
+        let (c2, c1, c0) = (lower(0, 3), lower(0, 2), lower(0, 1));
+        let determinant = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+        let d = 1.0 / determinant;
+        // The rows of the adjugate.
+        let rows = [
+            [
+                a(1, 1) * c5 - a(1, 2) * c4 + a(1, 3) * c3,
+                -a(0, 1) * c5 + a(0, 2) * c4 - a(0, 3) * c3,
+                a(3, 1) * s5 - a(3, 2) * s4 + a(3, 3) * s3,
+                -a(2, 1) * s5 + a(2, 2) * s4 - a(2, 3) * s3,
+            ],
+            [
+                -a(1, 0) * c5 + a(1, 2) * c2 - a(1, 3) * c1,
+                a(0, 0) * c5 - a(0, 2) * c2 + a(0, 3) * c1,
+                -a(3, 0) * s5 + a(3, 2) * s2 - a(3, 3) * s1,
+                a(2, 0) * s5 - a(2, 2) * s2 + a(2, 3) * s1,
+            ],
+            [
+                a(1, 0) * c4 - a(1, 1) * c2 + a(1, 3) * c0,
+                -a(0, 0) * c4 + a(0, 1) * c2 - a(0, 3) * c0,
+                a(3, 0) * s4 - a(3, 1) * s2 + a(3, 3) * s0,
+                -a(2, 0) * s4 + a(2, 1) * s2 - a(2, 3) * s0,
+            ],
+            [
+                -a(1, 0) * c3 + a(1, 1) * c1 - a(1, 2) * c0,
+                a(0, 0) * c3 - a(0, 1) * c1 + a(0, 2) * c0,
+                -a(3, 0) * s3 + a(3, 1) * s1 - a(3, 2) * s0,
+                a(2, 0) * s3 - a(2, 1) * s1 + a(2, 2) * s0,
+            ],
+        ];
+        let mut inverse = [[0.0; 4]; 4];
+        for (r, row) in rows.iter().enumerate() {
+            for (c, x) in row.iter().enumerate() {
+                inverse[c][r] = x * d;
+            }
+        }
+        match inverse.iter().flatten().all(|x| x.is_finite()) {
+            true => Some(Matrix4::from_array(inverse)),
+            false => None,
+        }
+    }
 }
 
 impl ops::Mul<Matrix4> for Matrix4 {
@@ -397,3 +470,29 @@ impl Quaternion {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transform_point() {
+        let mut m = Matrix4::from_nonuniform_scale(2.0, 1.0, 1.0);
+        m.w = Vector4::new(5.0, 6.0, 7.0, 1.0);
+        assert_eq!(m.transform_point([1.0, 1.0, 1.0]), [7.0, 7.0, 8.0]);
+    }
+
+    #[cfg(all(feature = "skinning", feature = "utils"))]
+    #[test]
+    fn invert() {
+        let identity = Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0);
+        let mut m = Matrix4::new(
+            0.0, 2.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 4.0, 0.0, 5.0, 6.0, 7.0, 1.0,
+        );
+        let inverse = m.invert().unwrap();
+        assert_relative_eq!(m * inverse, identity);
+        assert_relative_eq!(inverse * m, identity);
+        m.z = Vector4::new(0.0, 0.0, 0.0, 0.0);
+        assert_eq!(m.invert(), None);
+    }
+}
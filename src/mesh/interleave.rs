@@ -0,0 +1,97 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::accessor::{self, DataType, Dimensions, Elements};
+use crate::buffer;
+use crate::mesh::{Primitive, Semantic};
+
+/// A vertex attribute of [`Interleaved`] vertex data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VertexAttribute {
+    /// The semantic of the attribute.
+    pub semantic: Semantic,
+
+    /// The byte offset of the attribute within each vertex.
+    pub offset: usize,
+
+    /// The component data type, as stored by the accessor.
+    pub data_type: DataType,
+
+    /// The dimensions of the attribute.
+    pub dimensions: Dimensions,
+
+    /// Whether integer components are normalized.
+    pub normalized: bool,
+}
+
+/// The vertex attributes of a primitive interleaved into a single buffer,
+/// as computed by [`interleave`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Interleaved {
+    /// The attributes in the order of [`Primitive::attributes`].
+    pub attributes: Vec<VertexAttribute>,
+
+    /// The number of bytes between the starts of consecutive vertices.
+    pub stride: usize,
+
+    /// The number of vertices.
+    pub count: usize,
+
+    /// The vertex data, `stride * count` bytes long.
+    pub data: Vec<u8>,
+}
+
+/// Interleaves the vertex attributes of a primitive for uploading as a
+/// single vertex buffer.
+///
+/// Components are copied as stored, including sparse substitutions, and
+/// each attribute starts on a 4-byte boundary within the vertex, as vertex
+/// buffer layouts typically require; the padding is zero-filled. The number
+/// of vertices is that of the shortest attribute. Returns an error if the
+/// data of an attribute is unavailable.
+pub fn interleave(
+    primitive: &Primitive,
+    buffers: &[buffer::Data],
+) -> Result<Interleaved, accessor::Error> {
+    let mut attributes = Vec::new();
+    let mut stride = 0;
+    let mut count = usize::MAX;
+    for (semantic, accessor) in primitive.attributes() {
+        let size = accessor.size();
+        attributes.push(VertexAttribute {
+            semantic,
+            offset: stride,
+            data_type: accessor.data_type(),
+            dimensions: accessor.dimensions(),
+            normalized: accessor.normalized(),
+        });
+        stride += (size + 3) & !3;
+        count = count.min(accessor.count());
+    }
+    if attributes.is_empty() {
+        count = 0;
+    }
+
+    let get_buffer_data = |buffer: buffer::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+    // The data of every attribute is checked before any is allocated for it.
+    let mut elements = primitive
+        .attributes()
+        .map(|(_, accessor)| Elements::new(&accessor, &get_buffer_data))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut data = vec![0; stride * count];
+    for (elements, attribute) in elements.iter_mut().zip(&attributes) {
+        for vertex in 0..count {
+            let mut offset = vertex * stride + attribute.offset;
+            elements.next_components(|bytes| {
+                data[offset..offset + bytes.len()].copy_from_slice(bytes);
+                offset += bytes.len();
+            });
+        }
+    }
+    Ok(Interleaved {
+        attributes,
+        stride,
+        count,
+        data,
+    })
+}
@@ -56,12 +56,16 @@ pub mod iter;
 #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
 pub mod util;
 
-use alloc::string::String;
-use crate::{Accessor, Buffer, Document, Material};
+mod interleave;
 
-#[cfg(feature = "utils")]
-use crate::accessor;
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::fault::EntityKind;
+use crate::material::MaterialOverrides;
+use crate::{accessor, Accessor, Buffer, Document, Material};
 
+#[doc(inline)]
+pub use self::interleave::{interleave, Interleaved, VertexAttribute};
 pub use json::mesh::{Mode, Semantic};
 use json::validation::Checked;
 #[cfg(feature = "extensions")]
@@ -75,6 +79,7 @@ pub type BoundingBox = Bounds<[f32; 3]>;
 
 /// The minimum and maximum values for a generic accessor.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Bounds<T> {
     /// Minimum value.
     pub min: T,
@@ -83,6 +88,19 @@ pub struct Bounds<T> {
     pub max: T,
 }
 
+/// Extends `bounds` to contain a point, starting them at the point if they
+/// are `None`.
+pub(crate) fn extend_bounds(bounds: &mut Option<BoundingBox>, point: [f32; 3]) {
+    let bounds = bounds.get_or_insert(Bounds {
+        min: point,
+        max: point,
+    });
+    for (i, &x) in point.iter().enumerate() {
+        bounds.min[i] = bounds.min[i].min(x);
+        bounds.max[i] = bounds.max[i].max(x);
+    }
+}
+
 /// A set of primitives to be rendered.
 #[derive(Clone, Debug)]
 pub struct Mesh<'a> {
@@ -123,6 +141,27 @@ pub struct Primitive<'a> {
 }
 
 /// Mesh primitive reader.
+///
+/// The `read_*` methods return typed iterators over the attributes of the
+/// primitive, or `None` if an attribute is absent. They honour the offset and
+/// stride of every buffer view, sparse substitution, and the component type
+/// and normalization of every accessor.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// let gltf = gltf::Gltf::from_slice(include_bytes!("../../examples/Box.glb"))?;
+/// let blob = gltf.blob.as_deref();
+/// let mesh = gltf.document.meshes().next().unwrap();
+/// let primitive = mesh.primitives().next().unwrap();
+/// let reader = primitive.reader(|_| blob);
+/// let positions: Vec<[f32; 3]> = reader.read_positions().unwrap().collect();
+/// let indices: Vec<u32> = reader.read_indices().unwrap().into_u32().collect();
+/// assert_eq!((positions.len(), indices.len()), (24, 36));
+/// assert!(reader.read_tex_coords(0).is_none());
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
 #[derive(Clone, Debug)]
 pub struct Reader<'a, 's, F>
 where
@@ -132,6 +171,10 @@ where
     pub(crate) primitive: &'a Primitive<'a>,
     #[allow(dead_code)]
     pub(crate) get_buffer_data: F,
+    #[cfg(feature = "utils")]
+    pub(crate) sanitizer: Option<accessor::util::Sanitizer>,
+    #[cfg(feature = "utils")]
+    pub(crate) cache: Option<&'s accessor::AccessorLayoutCache>,
 }
 
 impl<'a> Mesh<'a> {
@@ -174,10 +217,46 @@ impl<'a> Mesh<'a> {
     #[cfg(feature = "names")]
     #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
+    }
+
+    /// Returns the union of the bounding boxes of the primitives, or `None` if
+    /// no primitive has a `POSITION` attribute, as for a mesh without
+    /// primitives.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        self.primitives()
+            .filter(|primitive| primitive.get(&Semantic::Positions).is_some())
+            .map(|primitive| primitive.bounding_box())
+            .reduce(|a, b| {
+                let mut union = a;
+                for i in 0..3 {
+                    union.min[i] = union.min[i].min(b.min[i]);
+                    union.max[i] = union.max[i].max(b.max[i]);
+                }
+                union
+            })
+    }
+
+    /// Returns the union of the bounding boxes of the primitives as computed
+    /// by [`Primitive::compute_bounding_box`], or `None` if no primitive has
+    /// any, as for a mesh without primitives.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn compute_bounding_box(&self, buffers: &[crate::buffer::Data]) -> Option<BoundingBox> {
+        let mut bounds = None;
+        for primitive in self.primitives() {
+            if let Some(primitive) = primitive.compute_bounding_box(buffers) {
+                extend_bounds(&mut bounds, primitive.min);
+                extend_bounds(&mut bounds, primitive.max);
+            }
+        }
+        bounds
     }
 
     /// Defines the geometry to be renderered with a material.
+    ///
+    /// The iterator is empty if the mesh has no primitives, which validation
+    /// accepts but [`Gltf::warnings`](crate::Gltf::warnings) reports.
     pub fn primitives(&self) -> iter::Primitives<'a> {
         iter::Primitives {
             mesh: self.clone(),
@@ -198,6 +277,11 @@ impl<'a> Primitive<'a> {
     }
 
     /// Returns the bounds of the `POSITION` vertex attribute.
+    ///
+    /// These are the declared `min` and `max` of its accessor, which
+    /// validation requires. See [`Primitive::compute_bounding_box`] for
+    /// documents that were not validated, and [`Primitive::try_bounding_box`]
+    /// for those of a fault-tolerant import.
     pub fn bounding_box(&self) -> BoundingBox {
         // NOTE: cannot panic if validated "minimally"
         let pos_accessor_index = self
@@ -211,11 +295,46 @@ impl<'a> Primitive<'a> {
             .accessors()
             .nth(pos_accessor_index.value())
             .unwrap();
-        let min: [f32; 3] = json::deserialize::from_value(pos_accessor.min().unwrap()).unwrap();
-        let max: [f32; 3] = json::deserialize::from_value(pos_accessor.max().unwrap()).unwrap();
+        let min = crate::accessor::bound_f32(pos_accessor.min_f64()).unwrap();
+        let max = crate::accessor::bound_f32(pos_accessor.max_f64()).unwrap();
         Bounds { min, max }
     }
 
+    /// Returns the bounds of the `POSITION` vertex attribute, or
+    /// `Err(Error::Faulted { .. })` if the primitive is faulted.
+    ///
+    /// [`Error::Faulted`]: crate::Error::Faulted
+    pub fn try_bounding_box(&self) -> crate::Result<BoundingBox> {
+        self.check_lookup()?;
+        Ok(self.bounding_box())
+    }
+
+    /// Returns the bounds of the `POSITION` vertex attribute, from the
+    /// declared `min` and `max` of its accessor or else by reading the
+    /// positions.
+    ///
+    /// Returns `None` if the primitive has no `POSITION` attribute or no
+    /// vertices, or if its positions cannot be read.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn compute_bounding_box(&self, buffers: &[crate::buffer::Data]) -> Option<BoundingBox> {
+        let positions = self.try_get(&Semantic::Positions).ok()??;
+        if positions.count() == 0 {
+            return None;
+        }
+        let min = accessor::bound_f32(positions.min_f64());
+        let max = accessor::bound_f32(positions.max_f64());
+        if let (Some(min), Some(max)) = (min, max) {
+            return Some(Bounds { min, max });
+        }
+        let reader = self.reader(|buffer| buffers.get(buffer.index()).map(|x| &*x.0));
+        let mut bounds = None;
+        for position in reader.read_positions()? {
+            extend_bounds(&mut bounds, position);
+        }
+        bounds
+    }
+
     /// Returns extension data unknown to this crate version.
     #[cfg(feature = "extensions")]
     #[cfg_attr(docsrs, doc(cfg(feature = "extensions")))]
@@ -238,6 +357,9 @@ impl<'a> Primitive<'a> {
     }
 
     /// Return the accessor with the given semantic.
+    ///
+    /// Panics if the primitive is faulted and the accessor does not exist;
+    /// see [`Primitive::try_get`].
     pub fn get(&self, semantic: &Semantic) -> Option<Accessor<'a>> {
         self.json
             .attributes
@@ -245,12 +367,55 @@ impl<'a> Primitive<'a> {
             .map(|index| self.mesh.document.accessors().nth(index.value()).unwrap())
     }
 
+    /// Returns the accessor with the given semantic, or
+    /// `Err(Error::Faulted { .. })` if the primitive is faulted.
+    ///
+    /// [`Error::Faulted`]: crate::Error::Faulted
+    pub fn try_get(&self, semantic: &Semantic) -> crate::Result<Option<Accessor<'a>>> {
+        self.check_lookup()?;
+        Ok(self.get(semantic))
+    }
+
     /// Returns the internal JSON index.
     pub fn index(&self) -> usize {
         self.index
     }
 
+    /// Returns `true` if the primitive was faulted by a fault-tolerant import.
+    ///
+    /// See [`Document::is_faulted`].
+    pub fn is_faulted(&self) -> bool {
+        let mesh = self.mesh.index();
+        self.mesh
+            .document
+            .is_faulted(EntityKind::Primitive { mesh }, self.index)
+    }
+
+    /// Returns `Err(Error::Faulted { .. })` if the primitive is faulted, for
+    /// the `try_` lookups.
+    fn check_lookup(&self) -> crate::Result<()> {
+        let mesh = self.mesh.index();
+        self.mesh
+            .document
+            .check_faulted(EntityKind::Primitive { mesh }, self.index)
+    }
+
+    /// Returns `Err(accessor::Error::FaultedPrimitive { .. })` if the
+    /// primitive is faulted, for the readers of its accessors.
+    pub(crate) fn check_faulted(&self) -> Result<(), accessor::Error> {
+        match self.is_faulted() {
+            true => Err(accessor::Error::FaultedPrimitive {
+                mesh: self.mesh.index(),
+                primitive: self.index,
+            }),
+            false => Ok(()),
+        }
+    }
+
     /// Returns the accessor containing the primitive indices, if provided.
+    ///
+    /// Panics if the primitive is faulted and the accessor does not exist;
+    /// see [`Primitive::try_indices`].
     pub fn indices(&self) -> Option<Accessor<'a>> {
         self.json
             .indices
@@ -258,7 +423,68 @@ impl<'a> Primitive<'a> {
             .map(|index| self.mesh.document.accessors().nth(index.value()).unwrap())
     }
 
+    /// Returns the accessor containing the primitive indices, if provided,
+    /// or `Err(Error::Faulted { .. })` if the primitive is faulted.
+    ///
+    /// [`Error::Faulted`]: crate::Error::Faulted
+    pub fn try_indices(&self) -> crate::Result<Option<Accessor<'a>>> {
+        self.check_lookup()?;
+        Ok(self.indices())
+    }
+
+    /// Returns the number of vertices drawn by the primitive: the number of
+    /// indices, or of vertices if it has none; see
+    /// [`Primitive::vertex_count`].
+    pub fn index_count(&self) -> Result<usize, accessor::Error> {
+        self.check_faulted()?;
+        match self.indices() {
+            Some(indices) => Ok(indices.count()),
+            None => self.vertex_count(),
+        }
+    }
+
+    /// Returns the number of vertices of the primitive, which is the number
+    /// of elements of its `POSITION` attribute, or of its first attribute if
+    /// it has none, or zero if it has no attributes.
+    ///
+    /// Returns `Err(accessor::Error::AttributeCount { .. })` for the first
+    /// attribute, including those of morph targets, with a different number
+    /// of elements, which the specification forbids, and
+    /// `Err(accessor::Error::FaultedPrimitive { .. })` if the primitive is
+    /// faulted.
+    pub fn vertex_count(&self) -> Result<usize, accessor::Error> {
+        self.check_faulted()?;
+        let expected = match self.get(&Semantic::Positions) {
+            Some(positions) => positions.count(),
+            None => match self.attributes().next() {
+                Some((_, accessor)) => accessor.count(),
+                None => return Ok(0),
+            },
+        };
+        let targets = self.morph_targets().flat_map(|target| {
+            [target.positions(), target.normals(), target.tangents()]
+                .into_iter()
+                .flatten()
+        });
+        let mismatch = self
+            .attributes()
+            .map(|(_, accessor)| accessor)
+            .chain(targets)
+            .find(|accessor| accessor.count() != expected);
+        match mismatch {
+            Some(accessor) => Err(accessor::Error::AttributeCount {
+                accessor: accessor.index(),
+                count: accessor.count(),
+                expected,
+            }),
+            None => Ok(expected),
+        }
+    }
+
     /// Returns an `Iterator` that visits the vertex attributes.
+    ///
+    /// The iterator panics if the primitive is faulted and an attribute is
+    /// invalid; see [`Primitive::try_attributes`].
     pub fn attributes(&self) -> iter::Attributes<'a> {
         iter::Attributes {
             document: self.mesh.document,
@@ -267,7 +493,19 @@ impl<'a> Primitive<'a> {
         }
     }
 
+    /// Returns an `Iterator` that visits the vertex attributes, or
+    /// `Err(Error::Faulted { .. })` if the primitive is faulted.
+    ///
+    /// [`Error::Faulted`]: crate::Error::Faulted
+    pub fn try_attributes(&self) -> crate::Result<iter::Attributes<'a>> {
+        self.check_lookup()?;
+        Ok(self.attributes())
+    }
+
     /// Returns the material to apply to this primitive when rendering
+    ///
+    /// Panics if the primitive is faulted and the material does not exist;
+    /// see [`Primitive::try_material`].
     pub fn material(&self) -> Material<'a> {
         self.json
             .material
@@ -276,12 +514,54 @@ impl<'a> Primitive<'a> {
             .unwrap_or_else(|| Material::default(self.mesh.document))
     }
 
+    /// Returns the material to apply to this primitive when rendering, or
+    /// `Err(Error::Faulted { .. })` if the primitive or its material is
+    /// faulted.
+    ///
+    /// [`Error::Faulted`]: crate::Error::Faulted
+    pub fn try_material(&self) -> crate::Result<Material<'a>> {
+        self.check_lookup()?;
+        let material = self.material();
+        if let Some(index) = material.index() {
+            self.mesh
+                .document
+                .check_faulted(EntityKind::Material, index)?;
+        }
+        Ok(material)
+    }
+
+    /// Returns the material to apply to this primitive when rendering it as
+    /// part of the mesh instanced by `node`, taking `overrides` into account.
+    pub fn material_with<'o>(&self, overrides: &'o MaterialOverrides, node: usize) -> Material<'o>
+    where
+        'a: 'o,
+    {
+        overrides
+            .material(node, self.index)
+            .unwrap_or_else(|| self.material())
+    }
+
+    /// Returns the attributes that `material` reads but this primitive lacks.
+    ///
+    /// The specification permits the mismatch, but renderers typically fall
+    /// back to defaults that make the material look wrong. See
+    /// [`Material::required_attributes`].
+    pub fn missing_attributes(&self, material: &Material) -> Vec<Semantic> {
+        let mut missing = material.required_attributes().semantics();
+        missing.retain(|semantic| self.get(semantic).is_none());
+        missing
+    }
+
     /// The type of primitives to render.
     pub fn mode(&self) -> Mode {
         self.json.mode.unwrap()
     }
 
     /// Returns an `Iterator` that visits the morph targets of the primitive.
+    ///
+    /// The iterator panics if the primitive is faulted and a target refers
+    /// to an accessor that does not exist; see
+    /// [`Primitive::try_morph_targets`].
     pub fn morph_targets(&self) -> iter::MorphTargets<'a> {
         if let Some(slice) = self.json.targets.as_ref() {
             iter::MorphTargets {
@@ -296,6 +576,15 @@ impl<'a> Primitive<'a> {
         }
     }
 
+    /// Returns an `Iterator` that visits the morph targets of the primitive,
+    /// or `Err(Error::Faulted { .. })` if the primitive is faulted.
+    ///
+    /// [`Error::Faulted`]: crate::Error::Faulted
+    pub fn try_morph_targets(&self) -> crate::Result<iter::MorphTargets<'a>> {
+        self.check_lookup()?;
+        Ok(self.morph_targets())
+    }
+
     /// Get the material variants.
     #[cfg(feature = "KHR_materials_variants")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
@@ -318,14 +607,79 @@ impl<'a> Primitive<'a> {
     #[cfg(feature = "utils")]
     #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
     pub fn reader<'s, F>(&'a self, get_buffer_data: F) -> Reader<'a, 's, F>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        self.reader_with(get_buffer_data, Default::default())
+    }
+
+    /// Constructs a primitive reader that sanitizes the floating-point
+    /// components it reads according to `options`.
+    ///
+    /// The components replaced by all the iterators of the reader are
+    /// counted by [`Reader::replaced`].
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn reader_with<'s, F>(
+        &'a self,
+        get_buffer_data: F,
+        options: accessor::ReaderOptions,
+    ) -> Reader<'a, 's, F>
     where
         F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
     {
         Reader {
             primitive: self,
             get_buffer_data,
+            sanitizer: accessor::util::Sanitizer::new(options),
+            cache: None,
         }
     }
+
+    /// Constructs a primitive reader that reads the layouts of the accessors
+    /// from a cache, resolving them on first use.
+    ///
+    /// See [`AccessorLayoutCache`](accessor::AccessorLayoutCache).
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn reader_cached<'s, F>(
+        &'a self,
+        cache: &'s accessor::AccessorLayoutCache,
+        get_buffer_data: F,
+    ) -> Reader<'a, 's, F>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Reader {
+            cache: Some(cache),
+            ..self.reader(get_buffer_data)
+        }
+    }
+
+    /// Visits the triangles of the primitive as triples of vertex indices,
+    /// read by a `reader` of the primitive.
+    ///
+    /// `TRIANGLES` are passed through, dropping any incomplete last triangle.
+    /// `TRIANGLE_STRIP` and `TRIANGLE_FAN` are expanded as the specification
+    /// defines, flipping every other strip triangle to keep the winding
+    /// order. Degenerate triangles, as at strip restarts, are yielded as
+    /// they are. A primitive without indices draws each vertex once.
+    ///
+    /// Returns `None` for points and lines, or where
+    /// [`Reader::read_or_generate_indices`] fails.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn triangles<'s, F>(&self, reader: &Reader<'a, 's, F>) -> Option<util::Triangles<'s>>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let mode = match self.json.mode {
+            Checked::Valid(mode) => mode,
+            Checked::Invalid => return None,
+        };
+        let indices = reader.read_or_generate_indices().ok()??;
+        util::Triangles::new(indices, mode)
+    }
 }
 
 #[cfg(feature = "utils")]
@@ -333,121 +687,330 @@ impl<'a, 's, F> Reader<'a, 's, F>
 where
     F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
 {
+    /// Returns the accessor of an attribute, or
+    /// `Err(accessor::Error::FaultedPrimitive { .. })` if the primitive is
+    /// faulted.
+    fn try_attribute(&self, semantic: &Semantic) -> Result<Option<Accessor<'a>>, accessor::Error> {
+        self.primitive.check_faulted()?;
+        Ok(self.primitive.get(semantic))
+    }
+
+    /// Returns the sanitized items of an accessor.
+    fn try_iter<T: accessor::Item>(
+        &self,
+        accessor: Accessor<'a>,
+    ) -> Result<Option<accessor::Iter<'s, T>>, accessor::Error> {
+        let get_buffer_data = self.get_buffer_data.clone();
+        let sanitizer = self.sanitizer.as_ref();
+        accessor::Iter::try_new_cached(accessor, get_buffer_data, sanitizer, self.cache)
+    }
+
+    /// Returns the sanitized components of an accessor converted to `f32`.
+    fn try_floats<const N: usize>(
+        &self,
+        accessor: Accessor<'a>,
+    ) -> Result<Option<util::ReadFloats<'s, N>>, accessor::Error> {
+        let get_buffer_data = self.get_buffer_data.clone();
+        util::ReadFloats::try_new(
+            accessor,
+            get_buffer_data,
+            self.sanitizer.as_ref(),
+            self.cache,
+        )
+    }
+
+    /// Returns the number of floating-point components replaced so far by
+    /// the iterators of the reader, according to the options it was
+    /// constructed with; see [`Primitive::reader_with`].
+    pub fn replaced(&self) -> usize {
+        self.sanitizer
+            .as_ref()
+            .map_or(0, accessor::util::Sanitizer::replaced)
+    }
+
     /// Visits the vertex positions of a primitive.
+    ///
+    /// Returns `None` where [`Reader::try_read_positions`] fails.
     pub fn read_positions(&self) -> Option<util::ReadPositions<'s>> {
-        self.primitive
-            .get(&Semantic::Positions)
-            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
+        self.try_read_positions().ok().flatten()
+    }
+
+    /// Visits the vertex positions of a primitive, reporting why they cannot
+    /// be read.
+    ///
+    /// Returns `Ok(None)` if the primitive has no positions or they are not
+    /// three components of a type that converts to `f32`, and
+    /// `Err(accessor::Error::FaultedPrimitive { .. })` if the primitive is
+    /// faulted. Fails otherwise as [`accessor::Iter::try_new`] does. The
+    /// other `try_read_` methods fail likewise.
+    pub fn try_read_positions(&self) -> Result<Option<util::ReadPositions<'s>>, accessor::Error> {
+        match self.try_attribute(&Semantic::Positions)? {
+            Some(accessor) => self.try_floats(accessor),
+            None => Ok(None),
+        }
     }
 
     /// Visits the vertex normals of a primitive.
+    ///
+    /// Returns `None` where [`Reader::try_read_normals`] fails.
     pub fn read_normals(&self) -> Option<util::ReadNormals<'s>> {
-        self.primitive
-            .get(&Semantic::Normals)
-            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
+        self.try_read_normals().ok().flatten()
+    }
+
+    /// Visits the vertex normals of a primitive, reporting why they cannot be
+    /// read; see [`Reader::try_read_positions`].
+    pub fn try_read_normals(&self) -> Result<Option<util::ReadNormals<'s>>, accessor::Error> {
+        match self.try_attribute(&Semantic::Normals)? {
+            Some(accessor) => self.try_floats(accessor),
+            None => Ok(None),
+        }
     }
 
     /// Visits the vertex tangents of a primitive.
+    ///
+    /// Returns `None` where [`Reader::try_read_tangents`] fails.
     pub fn read_tangents(&self) -> Option<util::ReadTangents<'s>> {
-        self.primitive
-            .get(&Semantic::Tangents)
-            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
+        self.try_read_tangents().ok().flatten()
+    }
+
+    /// Visits the vertex tangents of a primitive, reporting why they cannot
+    /// be read; see [`Reader::try_read_positions`].
+    pub fn try_read_tangents(&self) -> Result<Option<util::ReadTangents<'s>>, accessor::Error> {
+        match self.try_attribute(&Semantic::Tangents)? {
+            Some(accessor) => self.try_floats(accessor),
+            None => Ok(None),
+        }
     }
 
     /// Visits the vertex colors of a primitive.
+    ///
+    /// Returns `None` where [`Reader::try_read_colors`] fails.
     pub fn read_colors(&self, set: u32) -> Option<util::ReadColors<'s>> {
+        self.try_read_colors(set).ok().flatten()
+    }
+
+    /// Visits the vertex colors of a primitive, reporting why they cannot be
+    /// read; see [`Reader::try_read_positions`].
+    pub fn try_read_colors(
+        &self,
+        set: u32,
+    ) -> Result<Option<util::ReadColors<'s>>, accessor::Error> {
         use self::util::ReadColors;
         use accessor::DataType::{F32, U16, U8};
         use accessor::Dimensions::{Vec3, Vec4};
-        self.primitive
-            .get(&Semantic::Colors(set))
-            .and_then(
-                |accessor| match (accessor.data_type(), accessor.dimensions()) {
-                    (U8, Vec3) => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                        .map(ReadColors::RgbU8),
-                    (U16, Vec3) => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                        .map(ReadColors::RgbU16),
-                    (F32, Vec3) => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                        .map(ReadColors::RgbF32),
-                    (U8, Vec4) => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                        .map(ReadColors::RgbaU8),
-                    (U16, Vec4) => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                        .map(ReadColors::RgbaU16),
-                    (F32, Vec4) => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                        .map(ReadColors::RgbaF32),
-                    _ => unreachable!(),
-                },
-            )
+        let accessor = match self.try_attribute(&Semantic::Colors(set))? {
+            Some(accessor) => accessor,
+            None => return Ok(None),
+        };
+        Ok(match (accessor.data_type(), accessor.dimensions()) {
+            (U8, Vec3) => self.try_iter(accessor)?.map(ReadColors::RgbU8),
+            (U16, Vec3) => self.try_iter(accessor)?.map(ReadColors::RgbU16),
+            (F32, Vec3) => self.try_iter(accessor)?.map(ReadColors::RgbF32),
+            (U8, Vec4) => self.try_iter(accessor)?.map(ReadColors::RgbaU8),
+            (U16, Vec4) => self.try_iter(accessor)?.map(ReadColors::RgbaU16),
+            (F32, Vec4) => self.try_iter(accessor)?.map(ReadColors::RgbaF32),
+            _ => None,
+        })
     }
 
     /// Visits the vertex draw sequence of a primitive.
+    ///
+    /// Returns `None` where [`Reader::try_read_indices`] fails.
     pub fn read_indices(&self) -> Option<util::ReadIndices<'s>> {
+        self.try_read_indices().ok().flatten()
+    }
+
+    /// Visits the vertex draw sequence of a primitive, generating `0..n` for
+    /// a primitive of `n` vertices without indices, as it is drawn as a
+    /// vertex array.
+    ///
+    /// Returns `Ok(None)` where [`Reader::try_read_indices`] does for a
+    /// primitive with indices, and for one without if it has more vertices
+    /// than `u32` indices can address. Fails as [`Reader::try_read_indices`]
+    /// does otherwise, or as [`Primitive::vertex_count`] does if the
+    /// attributes of a primitive without indices disagree on its number of
+    /// vertices.
+    pub fn read_or_generate_indices(
+        &self,
+    ) -> Result<Option<util::DrawIndices<'s>>, accessor::Error> {
+        self.primitive.check_faulted()?;
+        if self.primitive.indices().is_some() {
+            let indices = self.try_read_indices()?;
+            return Ok(indices.map(|indices| util::DrawIndices::Read(indices.into_u32())));
+        }
+        let count = self.primitive.vertex_count()?;
+        Ok(u32::try_from(count)
+            .ok()
+            .map(|count| util::DrawIndices::Generated(0..count)))
+    }
+
+    /// Visits the vertex draw sequence of a primitive, reporting why it
+    /// cannot be read.
+    ///
+    /// Returns `Ok(None)` if the primitive has no indices, or if its indices
+    /// are not scalar unsigned integers,
+    /// `Err(accessor::Error::IndexStride { .. })` if their buffer view
+    /// declares a byte stride, and
+    /// `Err(accessor::Error::FaultedPrimitive { .. })` if the primitive is
+    /// faulted. Fails otherwise as [`accessor::Iter::try_new`] does, for
+    /// example if the indices overrun their buffer view.
+    pub fn try_read_indices(&self) -> Result<Option<util::ReadIndices<'s>>, accessor::Error> {
         use self::util::ReadIndices;
         use accessor::DataType;
-        self.primitive
-            .indices()
-            .and_then(|accessor| match accessor.data_type() {
-                DataType::U8 => {
-                    accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadIndices::U8)
-                }
-                DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                    .map(ReadIndices::U16),
-                DataType::U32 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                    .map(ReadIndices::U32),
-                _ => unreachable!(),
-            })
+        self.primitive.check_faulted()?;
+        let accessor = match self.primitive.indices() {
+            Some(accessor) => accessor,
+            None => return Ok(None),
+        };
+        if accessor.dimensions() != accessor::Dimensions::Scalar {
+            return Ok(None);
+        }
+        if let Some(stride) = accessor.view().and_then(|view| view.stride()) {
+            return Err(accessor::Error::IndexStride {
+                accessor: accessor.index(),
+                stride,
+            });
+        }
+        let get_buffer_data = self.get_buffer_data.clone();
+        let sanitizer = self.sanitizer.as_ref();
+        Ok(match accessor.data_type() {
+            DataType::U8 => {
+                accessor::Iter::try_new_cached(accessor, get_buffer_data, sanitizer, self.cache)?
+                    .map(ReadIndices::U8)
+            }
+            DataType::U16 => {
+                accessor::Iter::try_new_cached(accessor, get_buffer_data, sanitizer, self.cache)?
+                    .map(ReadIndices::U16)
+            }
+            DataType::U32 => {
+                accessor::Iter::try_new_cached(accessor, get_buffer_data, sanitizer, self.cache)?
+                    .map(ReadIndices::U32)
+            }
+            _ => None,
+        })
     }
 
     /// Visits the joint indices of the primitive.
+    ///
+    /// Returns `None` where [`Reader::try_read_joints`] fails.
     pub fn read_joints(&self, set: u32) -> Option<util::ReadJoints<'s>> {
+        self.try_read_joints(set).ok().flatten()
+    }
+
+    /// Visits the joint indices of the primitive, reporting why they cannot
+    /// be read; see [`Reader::try_read_positions`].
+    pub fn try_read_joints(
+        &self,
+        set: u32,
+    ) -> Result<Option<util::ReadJoints<'s>>, accessor::Error> {
         use self::util::ReadJoints;
         use accessor::DataType;
-        self.primitive
-            .get(&Semantic::Joints(set))
-            .and_then(|accessor| match accessor.data_type() {
-                DataType::U8 => {
-                    accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadJoints::U8)
-                }
-                DataType::U16 => {
-                    accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadJoints::U16)
-                }
-                _ => unreachable!(),
-            })
+        let accessor = match self.try_attribute(&Semantic::Joints(set))? {
+            Some(accessor) if accessor.dimensions() == accessor::Dimensions::Vec4 => accessor,
+            _ => return Ok(None),
+        };
+        Ok(match accessor.data_type() {
+            DataType::U8 => self.try_iter(accessor)?.map(ReadJoints::U8),
+            DataType::U16 => self.try_iter(accessor)?.map(ReadJoints::U16),
+            _ => None,
+        })
     }
 
     /// Visits the vertex texture co-ordinates of a primitive.
+    ///
+    /// Returns `None` where [`Reader::try_read_tex_coords`] fails.
     pub fn read_tex_coords(&self, set: u32) -> Option<util::ReadTexCoords<'s>> {
+        self.try_read_tex_coords(set).ok().flatten()
+    }
+
+    /// Visits the vertex texture co-ordinates of a primitive, reporting why
+    /// they cannot be read; see [`Reader::try_read_positions`].
+    pub fn try_read_tex_coords(
+        &self,
+        set: u32,
+    ) -> Result<Option<util::ReadTexCoords<'s>>, accessor::Error> {
         use self::util::ReadTexCoords;
         use accessor::DataType;
-        self.primitive
-            .get(&Semantic::TexCoords(set))
-            .and_then(|accessor| match accessor.data_type() {
-                DataType::U8 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                    .map(ReadTexCoords::U8),
-                DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                    .map(ReadTexCoords::U16),
-                DataType::F32 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                    .map(ReadTexCoords::F32),
-                _ => unreachable!(),
-            })
+        let accessor = match self.try_attribute(&Semantic::TexCoords(set))? {
+            Some(accessor) if accessor.dimensions() == accessor::Dimensions::Vec2 => accessor,
+            _ => return Ok(None),
+        };
+        Ok(match (accessor.data_type(), accessor.normalized()) {
+            (DataType::U8, true) => self.try_iter(accessor)?.map(ReadTexCoords::U8),
+            (DataType::U16, true) => self.try_iter(accessor)?.map(ReadTexCoords::U16),
+            (DataType::F32, _) => self.try_iter(accessor)?.map(ReadTexCoords::F32),
+            _ => self.try_floats(accessor)?.map(ReadTexCoords::Quantized),
+        })
     }
 
     /// Visits the joint weights of the primitive.
+    ///
+    /// Returns `None` where [`Reader::try_read_weights`] fails.
     pub fn read_weights(&self, set: u32) -> Option<util::ReadWeights<'s>> {
+        self.try_read_weights(set).ok().flatten()
+    }
+
+    /// Visits the joint weights of the primitive, reporting why they cannot
+    /// be read; see [`Reader::try_read_positions`].
+    pub fn try_read_weights(
+        &self,
+        set: u32,
+    ) -> Result<Option<util::ReadWeights<'s>>, accessor::Error> {
         use self::accessor::DataType;
         use self::util::ReadWeights;
-        self.primitive
-            .get(&Semantic::Weights(set))
-            .and_then(|accessor| match accessor.data_type() {
-                DataType::U8 => {
-                    accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadWeights::U8)
-                }
-                DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                    .map(ReadWeights::U16),
-                DataType::F32 => accessor::Iter::new(accessor, self.get_buffer_data.clone())
-                    .map(ReadWeights::F32),
-                _ => unreachable!(),
-            })
+        let accessor = match self.try_attribute(&Semantic::Weights(set))? {
+            Some(accessor) if accessor.dimensions() == accessor::Dimensions::Vec4 => accessor,
+            _ => return Ok(None),
+        };
+        Ok(match accessor.data_type() {
+            DataType::U8 => self.try_iter(accessor)?.map(ReadWeights::U8),
+            DataType::U16 => self.try_iter(accessor)?.map(ReadWeights::U16),
+            DataType::F32 => self.try_iter(accessor)?.map(ReadWeights::F32),
+            _ => None,
+        })
+    }
+
+    /// Visits the components of an attribute as stored by its accessor,
+    /// without the conversions of the other methods.
+    ///
+    /// Returns `None` if the attribute is absent or is not a vector of
+    /// `i8`, `u8`, `i16`, `u16` or `f32` components, or where
+    /// [`Reader::try_read_raw`] fails.
+    pub fn read_raw(&self, semantic: &Semantic) -> Option<util::ReadRaw<'s>> {
+        self.try_read_raw(semantic).ok().flatten()
+    }
+
+    /// Visits the components of an attribute as stored by its accessor,
+    /// reporting why they cannot be read; see [`Reader::try_read_positions`].
+    pub fn try_read_raw(
+        &self,
+        semantic: &Semantic,
+    ) -> Result<Option<util::ReadRaw<'s>>, accessor::Error> {
+        use self::util::ReadRaw;
+        use accessor::DataType::{F32, I16, I8, U16, U8};
+        use accessor::Dimensions::{Vec2, Vec3, Vec4};
+        let accessor = match self.try_attribute(semantic)? {
+            Some(accessor) => accessor,
+            None => return Ok(None),
+        };
+        Ok(match (accessor.dimensions(), accessor.data_type()) {
+            (Vec2, I8) => self.try_iter(accessor)?.map(ReadRaw::Vec2I8),
+            (Vec2, U8) => self.try_iter(accessor)?.map(ReadRaw::Vec2U8),
+            (Vec2, I16) => self.try_iter(accessor)?.map(ReadRaw::Vec2I16),
+            (Vec2, U16) => self.try_iter(accessor)?.map(ReadRaw::Vec2U16),
+            (Vec2, F32) => self.try_iter(accessor)?.map(ReadRaw::Vec2F32),
+            (Vec3, I8) => self.try_iter(accessor)?.map(ReadRaw::Vec3I8),
+            (Vec3, U8) => self.try_iter(accessor)?.map(ReadRaw::Vec3U8),
+            (Vec3, I16) => self.try_iter(accessor)?.map(ReadRaw::Vec3I16),
+            (Vec3, U16) => self.try_iter(accessor)?.map(ReadRaw::Vec3U16),
+            (Vec3, F32) => self.try_iter(accessor)?.map(ReadRaw::Vec3F32),
+            (Vec4, I8) => self.try_iter(accessor)?.map(ReadRaw::Vec4I8),
+            (Vec4, U8) => self.try_iter(accessor)?.map(ReadRaw::Vec4U8),
+            (Vec4, I16) => self.try_iter(accessor)?.map(ReadRaw::Vec4I16),
+            (Vec4, U16) => self.try_iter(accessor)?.map(ReadRaw::Vec4U16),
+            (Vec4, F32) => self.try_iter(accessor)?.map(ReadRaw::Vec4F32),
+            _ => None,
+        })
     }
 
     /// Visits the morph targets of the primitive.
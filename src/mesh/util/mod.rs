@@ -1,3 +1,51 @@
+//! # Conversion of vertex attributes
+//!
+//! The `read_*` methods of [`Reader`] convert the
+//! components of an attribute from the storage type of its accessor as the
+//! specification and `KHR_mesh_quantization` define, and never reinterpret
+//! the stored bytes as another type:
+//!
+//! | Semantic | Storage | Read as |
+//! |----------|---------|---------|
+//! | `POSITION` | `f32`, `i8`, `u8`, `i16`, `u16`, any normalized | [`ReadPositions`] |
+//! | `NORMAL` | `f32`, normalized `i8` or `i16` | [`ReadNormals`] |
+//! | `TANGENT` | `f32`, normalized `i8` or `i16` | [`ReadTangents`] |
+//! | `TEXCOORD_n` | `f32`, normalized `u8` or `u16` | [`ReadTexCoords::F32`], [`ReadTexCoords::U8`], [`ReadTexCoords::U16`] |
+//! | `TEXCOORD_n` | `i8`, `u8`, `i16`, `u16`, normalized `i8` or `i16` | [`ReadTexCoords::Quantized`] |
+//! | `COLOR_n` | `f32`, normalized `u8` or `u16` | [`ReadColors`] |
+//! | `JOINTS_n` | `u8`, `u16` | [`ReadJoints`] |
+//! | `WEIGHTS_n` | `f32`, normalized `u8` or `u16` | [`ReadWeights`] |
+//! | morph target `POSITION` | `f32`, `i8`, `i16`, normalized `i8` or `i16` | [`ReadPositionDisplacements`] |
+//! | morph target `NORMAL`, `TANGENT` | `f32`, normalized `i8` or `i16` | [`ReadNormalDisplacements`], [`ReadTangentDisplacements`] |
+//!
+//! [`ReadFloats`] yields normalized integer components mapped to the unit
+//! range, that is `c / 255` for `u8`, `max(c / 127, -1)` for `i8`, `c / 65535`
+//! for `u16` and `max(c / 32767, -1)` for `i16`, and other integer
+//! components converted to the same value as `f32`. Colors and weights are
+//! always treated as normalized, as the specification requires. An accessor
+//! of any other component type, or of the wrong dimensions, is not read and
+//! the method returns `None`.
+//!
+//! [`Reader::read_raw`] visits the components of any attribute as stored,
+//! without conversion.
+//!
+//! [`Reader`]: crate::mesh::Reader
+//! [`Reader::read_raw`]: crate::mesh::Reader::read_raw
+//! [`ReadFloats`]: crate::mesh::util::ReadFloats
+//! [`ReadPositions`]: crate::mesh::util::ReadPositions
+//! [`ReadNormals`]: crate::mesh::util::ReadNormals
+//! [`ReadTangents`]: crate::mesh::util::ReadTangents
+//! [`ReadTexCoords::F32`]: crate::mesh::util::ReadTexCoords::F32
+//! [`ReadTexCoords::U8`]: crate::mesh::util::ReadTexCoords::U8
+//! [`ReadTexCoords::U16`]: crate::mesh::util::ReadTexCoords::U16
+//! [`ReadTexCoords::Quantized`]: crate::mesh::util::ReadTexCoords::Quantized
+//! [`ReadColors`]: crate::mesh::util::ReadColors
+//! [`ReadJoints`]: crate::mesh::util::ReadJoints
+//! [`ReadWeights`]: crate::mesh::util::ReadWeights
+//! [`ReadPositionDisplacements`]: crate::mesh::util::ReadPositionDisplacements
+//! [`ReadNormalDisplacements`]: crate::mesh::util::ReadNormalDisplacements
+//! [`ReadTangentDisplacements`]: crate::mesh::util::ReadTangentDisplacements
+
 /// Casting iterator adapters for colors.
 pub mod colors;
 
@@ -13,29 +61,106 @@ pub mod tex_coords;
 /// Casting iterator adapters for node weights.
 pub mod weights;
 
+mod triangles;
+
+pub use self::triangles::Triangles;
+
+use core::ops;
+
 use crate::mesh;
 
-use crate::accessor::Iter;
-use crate::Buffer;
+use crate::accessor::util::Sanitizer;
+use crate::accessor::{self, AccessorLayoutCache, DataType, Dimensions, Iter};
+use crate::{Accessor, Buffer, Normalize};
 
 /// XYZ vertex positions of type `[f32; 3]`.
-pub type ReadPositions<'a> = Iter<'a, [f32; 3]>;
+pub type ReadPositions<'a> = ReadFloats<'a, 3>;
 
 /// XYZ vertex normals of type `[f32; 3]`.
-pub type ReadNormals<'a> = Iter<'a, [f32; 3]>;
+pub type ReadNormals<'a> = ReadFloats<'a, 3>;
 
 /// XYZW vertex tangents of type `[f32; 4]` where the `w` component is a
 /// sign value (-1 or +1) indicating the handedness of the tangent basis.
-pub type ReadTangents<'a> = Iter<'a, [f32; 4]>;
+pub type ReadTangents<'a> = ReadFloats<'a, 4>;
 
 /// XYZ vertex position displacements of type `[f32; 3]`.
-pub type ReadPositionDisplacements<'a> = Iter<'a, [f32; 3]>;
+pub type ReadPositionDisplacements<'a> = ReadFloats<'a, 3>;
 
 /// XYZ vertex normal displacements of type `[f32; 3]`.
-pub type ReadNormalDisplacements<'a> = Iter<'a, [f32; 3]>;
+pub type ReadNormalDisplacements<'a> = ReadFloats<'a, 3>;
 
 /// XYZ vertex tangent displacements.
-pub type ReadTangentDisplacements<'a> = Iter<'a, [f32; 3]>;
+pub type ReadTangentDisplacements<'a> = ReadFloats<'a, 3>;
+
+/// Vertex attribute components of type `[f32; N]`, converted from the
+/// storage type of the accessor.
+///
+/// See the [module documentation](self) for the conversions.
+#[derive(Clone, Debug)]
+pub struct ReadFloats<'a, const N: usize>(Floats<'a, N>);
+
+/// The storage of [`ReadFloats`], with whether integer components are
+/// normalized.
+#[derive(Clone, Debug)]
+enum Floats<'a, const N: usize> {
+    I8(Iter<'a, [i8; N]>, bool),
+    U8(Iter<'a, [u8; N]>, bool),
+    I16(Iter<'a, [i16; N]>, bool),
+    U16(Iter<'a, [u16; N]>, bool),
+    F32(Iter<'a, [f32; N]>),
+}
+
+/// Converts integer components to `f32`.
+fn to_f32<T, const N: usize>(item: [T; N], normalized: bool) -> [f32; N]
+where
+    T: Copy + Into<f32> + Normalize<f32>,
+{
+    let mut out = [0.0; N];
+    for (out, component) in out.iter_mut().zip(item) {
+        *out = if normalized {
+            component.normalize()
+        } else {
+            component.into()
+        };
+    }
+    out
+}
+
+/// Vertex attribute components as stored by the accessor, as returned by
+/// [`Reader::read_raw`](crate::mesh::Reader::read_raw).
+#[derive(Clone, Debug)]
+pub enum ReadRaw<'a> {
+    /// Two-component data of type `[i8; 2]`.
+    Vec2I8(Iter<'a, [i8; 2]>),
+    /// Two-component data of type `[u8; 2]`.
+    Vec2U8(Iter<'a, [u8; 2]>),
+    /// Two-component data of type `[i16; 2]`.
+    Vec2I16(Iter<'a, [i16; 2]>),
+    /// Two-component data of type `[u16; 2]`.
+    Vec2U16(Iter<'a, [u16; 2]>),
+    /// Two-component data of type `[f32; 2]`.
+    Vec2F32(Iter<'a, [f32; 2]>),
+    /// Three-component data of type `[i8; 3]`.
+    Vec3I8(Iter<'a, [i8; 3]>),
+    /// Three-component data of type `[u8; 3]`.
+    Vec3U8(Iter<'a, [u8; 3]>),
+    /// Three-component data of type `[i16; 3]`.
+    Vec3I16(Iter<'a, [i16; 3]>),
+    /// Three-component data of type `[u16; 3]`.
+    Vec3U16(Iter<'a, [u16; 3]>),
+    /// Three-component data of type `[f32; 3]`.
+    Vec3F32(Iter<'a, [f32; 3]>),
+    /// Four-component data of type `[i8; 4]`.
+    Vec4I8(Iter<'a, [i8; 4]>),
+    /// Four-component data of type `[u8; 4]`.
+    Vec4U8(Iter<'a, [u8; 4]>),
+    /// Four-component data of type `[i16; 4]`.
+    Vec4I16(Iter<'a, [i16; 4]>),
+    /// Four-component data of type `[u16; 4]`.
+    Vec4U16(Iter<'a, [u16; 4]>),
+    /// Four-component data of type `[f32; 4]`.
+    Vec4F32(Iter<'a, [f32; 4]>),
+}
 
 /// Vertex colors.
 #[derive(Clone, Debug)]
@@ -65,6 +190,18 @@ pub enum ReadIndices<'a> {
     U32(Iter<'a, u32>),
 }
 
+/// Vertex indices of type `u32`, read from the indices of a primitive or
+/// generated for one without; see [`Reader::read_or_generate_indices`].
+///
+/// [`Reader::read_or_generate_indices`]: crate::mesh::Reader::read_or_generate_indices
+#[derive(Clone, Debug)]
+pub enum DrawIndices<'a> {
+    /// Indices read from the indices accessor.
+    Read(self::indices::CastingIter<'a, self::indices::U32>),
+    /// The indices of the vertices of a primitive without indices, in order.
+    Generated(ops::Range<u32>),
+}
+
 /// Vertex joints.
 #[derive(Clone, Debug)]
 pub enum ReadJoints<'a> {
@@ -87,6 +224,9 @@ pub enum ReadTexCoords<'a> {
     U16(Iter<'a, [u16; 2]>),
     /// UV texture co-ordinates of type `[f32; 2]`.
     F32(Iter<'a, [f32; 2]>),
+    /// UV texture co-ordinates stored as signed or non-normalized integers,
+    /// as `KHR_mesh_quantization` permits, converted to `[f32; 2]`.
+    Quantized(ReadFloats<'a, 2>),
 }
 
 /// Weights.
@@ -125,21 +265,27 @@ where
         Option<ReadTangentDisplacements<'s>>,
     );
     fn next(&mut self) -> Option<Self::Item> {
+        if self.reader.primitive.is_faulted() {
+            return None;
+        }
         self.index += 1;
         self.reader
             .primitive
             .morph_targets()
             .nth(self.index - 1)
             .map(|morph_target| {
-                let positions = morph_target
-                    .positions()
-                    .and_then(|accessor| Iter::new(accessor, self.reader.get_buffer_data.clone()));
-                let normals = morph_target
-                    .normals()
-                    .and_then(|accessor| Iter::new(accessor, self.reader.get_buffer_data.clone()));
-                let tangents = morph_target
-                    .tangents()
-                    .and_then(|accessor| Iter::new(accessor, self.reader.get_buffer_data.clone()));
+                let reader = &self.reader;
+                let read = |accessor| {
+                    ReadFloats::new(
+                        accessor,
+                        reader.get_buffer_data.clone(),
+                        reader.sanitizer.as_ref(),
+                        reader.cache,
+                    )
+                };
+                let positions = morph_target.positions().and_then(read);
+                let normals = morph_target.normals().and_then(read);
+                let tangents = morph_target.tangents().and_then(read);
                 (positions, normals, tangents)
             })
     }
@@ -149,7 +295,153 @@ where
     }
 }
 
+impl<'a, const N: usize> ReadFloats<'a, N> {
+    /// Returns the components of an accessor converted to `f32`, or `None`
+    /// if the accessor does not have `N` components of a type that converts,
+    /// or its data is unavailable. `f32` components are sanitized by
+    /// `sanitizer`, if any, and the layout is read from `cache`, if any.
+    pub(crate) fn new<'b, F>(
+        accessor: Accessor<'b>,
+        get_buffer_data: F,
+        sanitizer: Option<&Sanitizer>,
+        cache: Option<&'a AccessorLayoutCache>,
+    ) -> Option<Self>
+    where
+        F: Clone + Fn(Buffer<'b>) -> Option<&'a [u8]>,
+    {
+        Self::try_new(accessor, get_buffer_data, sanitizer, cache)
+            .ok()
+            .flatten()
+    }
+
+    /// Like [`ReadFloats::new`], but fails as [`Iter::try_new`] does if the
+    /// data of the accessor is unavailable.
+    pub(crate) fn try_new<'b, F>(
+        accessor: Accessor<'b>,
+        get_buffer_data: F,
+        sanitizer: Option<&Sanitizer>,
+        cache: Option<&'a AccessorLayoutCache>,
+    ) -> Result<Option<Self>, accessor::Error>
+    where
+        F: Clone + Fn(Buffer<'b>) -> Option<&'a [u8]>,
+    {
+        match (N, accessor.dimensions()) {
+            (2, Dimensions::Vec2) | (3, Dimensions::Vec3) | (4, Dimensions::Vec4) => {}
+            _ => return Ok(None),
+        }
+        let normalized = accessor.normalized();
+        // Only `f32` components are sanitized.
+        let sanitizer = match accessor.data_type() {
+            DataType::F32 => sanitizer,
+            _ => None,
+        };
+        let floats = match accessor.data_type() {
+            DataType::I8 => Iter::try_new_cached(accessor, get_buffer_data, sanitizer, cache)?
+                .map(|iter| Floats::I8(iter, normalized)),
+            DataType::U8 => Iter::try_new_cached(accessor, get_buffer_data, sanitizer, cache)?
+                .map(|iter| Floats::U8(iter, normalized)),
+            DataType::I16 => Iter::try_new_cached(accessor, get_buffer_data, sanitizer, cache)?
+                .map(|iter| Floats::I16(iter, normalized)),
+            DataType::U16 => Iter::try_new_cached(accessor, get_buffer_data, sanitizer, cache)?
+                .map(|iter| Floats::U16(iter, normalized)),
+            DataType::F32 => {
+                Iter::try_new_cached(accessor, get_buffer_data, sanitizer, cache)?.map(Floats::F32)
+            }
+            DataType::U32 => None,
+        };
+        Ok(floats.map(ReadFloats))
+    }
+
+    /// Returns the components in a range of the remaining ones, or `None` if
+    /// the range is out of bounds; see [`Iter::slice`].
+    pub fn slice(self, range: ops::Range<usize>) -> Option<Self> {
+        let floats = match self.0 {
+            Floats::I8(iter, normalized) => Floats::I8(iter.slice(range)?, normalized),
+            Floats::U8(iter, normalized) => Floats::U8(iter.slice(range)?, normalized),
+            Floats::I16(iter, normalized) => Floats::I16(iter.slice(range)?, normalized),
+            Floats::U16(iter, normalized) => Floats::U16(iter.slice(range)?, normalized),
+            Floats::F32(iter) => Floats::F32(iter.slice(range)?),
+        };
+        Some(ReadFloats(floats))
+    }
+}
+
+impl<'a, const N: usize> ExactSizeIterator for ReadFloats<'a, N> {}
+impl<'a, const N: usize> Iterator for ReadFloats<'a, N> {
+    type Item = [f32; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.0 {
+            Floats::I8(ref mut i, normalized) => i.next().map(|x| to_f32(x, normalized)),
+            Floats::U8(ref mut i, normalized) => i.next().map(|x| to_f32(x, normalized)),
+            Floats::I16(ref mut i, normalized) => i.next().map(|x| to_f32(x, normalized)),
+            Floats::U16(ref mut i, normalized) => i.next().map(|x| to_f32(x, normalized)),
+            Floats::F32(ref mut i) => i.next(),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.0 {
+            Floats::I8(ref mut i, normalized) => i.nth(n).map(|x| to_f32(x, normalized)),
+            Floats::U8(ref mut i, normalized) => i.nth(n).map(|x| to_f32(x, normalized)),
+            Floats::I16(ref mut i, normalized) => i.nth(n).map(|x| to_f32(x, normalized)),
+            Floats::U16(ref mut i, normalized) => i.nth(n).map(|x| to_f32(x, normalized)),
+            Floats::F32(ref mut i) => i.nth(n),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.0 {
+            Floats::I8(ref i, _) => i.size_hint(),
+            Floats::U8(ref i, _) => i.size_hint(),
+            Floats::I16(ref i, _) => i.size_hint(),
+            Floats::U16(ref i, _) => i.size_hint(),
+            Floats::F32(ref i) => i.size_hint(),
+        }
+    }
+}
+
+impl<'a> ReadRaw<'a> {
+    /// Returns the components in a range of the remaining ones, or `None` if
+    /// the range is out of bounds; see [`Iter::slice`].
+    pub fn slice(self, range: ops::Range<usize>) -> Option<Self> {
+        match self {
+            ReadRaw::Vec2I8(iter) => iter.slice(range).map(ReadRaw::Vec2I8),
+            ReadRaw::Vec2U8(iter) => iter.slice(range).map(ReadRaw::Vec2U8),
+            ReadRaw::Vec2I16(iter) => iter.slice(range).map(ReadRaw::Vec2I16),
+            ReadRaw::Vec2U16(iter) => iter.slice(range).map(ReadRaw::Vec2U16),
+            ReadRaw::Vec2F32(iter) => iter.slice(range).map(ReadRaw::Vec2F32),
+            ReadRaw::Vec3I8(iter) => iter.slice(range).map(ReadRaw::Vec3I8),
+            ReadRaw::Vec3U8(iter) => iter.slice(range).map(ReadRaw::Vec3U8),
+            ReadRaw::Vec3I16(iter) => iter.slice(range).map(ReadRaw::Vec3I16),
+            ReadRaw::Vec3U16(iter) => iter.slice(range).map(ReadRaw::Vec3U16),
+            ReadRaw::Vec3F32(iter) => iter.slice(range).map(ReadRaw::Vec3F32),
+            ReadRaw::Vec4I8(iter) => iter.slice(range).map(ReadRaw::Vec4I8),
+            ReadRaw::Vec4U8(iter) => iter.slice(range).map(ReadRaw::Vec4U8),
+            ReadRaw::Vec4I16(iter) => iter.slice(range).map(ReadRaw::Vec4I16),
+            ReadRaw::Vec4U16(iter) => iter.slice(range).map(ReadRaw::Vec4U16),
+            ReadRaw::Vec4F32(iter) => iter.slice(range).map(ReadRaw::Vec4F32),
+        }
+    }
+}
+
 impl<'a> ReadColors<'a> {
+    /// Returns the colors in a range of the remaining ones, or `None` if the
+    /// range is out of bounds; see [`Iter::slice`].
+    pub fn slice(self, range: ops::Range<usize>) -> Option<Self> {
+        match self {
+            ReadColors::RgbU8(iter) => iter.slice(range).map(ReadColors::RgbU8),
+            ReadColors::RgbU16(iter) => iter.slice(range).map(ReadColors::RgbU16),
+            ReadColors::RgbF32(iter) => iter.slice(range).map(ReadColors::RgbF32),
+            ReadColors::RgbaU8(iter) => iter.slice(range).map(ReadColors::RgbaU8),
+            ReadColors::RgbaU16(iter) => iter.slice(range).map(ReadColors::RgbaU16),
+            ReadColors::RgbaF32(iter) => iter.slice(range).map(ReadColors::RgbaF32),
+        }
+    }
+
     /// Reinterpret colors as RGB u8, discarding alpha, if present.  Lossy if
     /// the underlying iterator yields u16, f32 or any RGBA.
     pub fn into_rgb_u8(self) -> self::colors::CastingIter<'a, self::colors::RgbU8> {
@@ -188,13 +480,61 @@ impl<'a> ReadColors<'a> {
 }
 
 impl<'a> ReadIndices<'a> {
+    /// Returns the indices in a range of the remaining ones, or `None` if the
+    /// range is out of bounds; see [`Iter::slice`].
+    pub fn slice(self, range: ops::Range<usize>) -> Option<Self> {
+        match self {
+            ReadIndices::U8(iter) => iter.slice(range).map(ReadIndices::U8),
+            ReadIndices::U16(iter) => iter.slice(range).map(ReadIndices::U16),
+            ReadIndices::U32(iter) => iter.slice(range).map(ReadIndices::U32),
+        }
+    }
+
     /// Reinterpret indices as u32, which can fit any possible index.
     pub fn into_u32(self) -> self::indices::CastingIter<'a, self::indices::U32> {
         self::indices::CastingIter::new(self)
     }
 }
 
+impl<'a> ExactSizeIterator for DrawIndices<'a> {}
+impl<'a> Iterator for DrawIndices<'a> {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            DrawIndices::Read(i) => i.next(),
+            DrawIndices::Generated(i) => i.next(),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self {
+            DrawIndices::Read(i) => i.nth(n),
+            DrawIndices::Generated(i) => i.nth(n),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            DrawIndices::Read(i) => i.size_hint(),
+            DrawIndices::Generated(i) => i.size_hint(),
+        }
+    }
+}
+
 impl<'a> ReadJoints<'a> {
+    /// Returns the joints in a range of the remaining ones, or `None` if the
+    /// range is out of bounds; see [`Iter::slice`].
+    pub fn slice(self, range: ops::Range<usize>) -> Option<Self> {
+        match self {
+            ReadJoints::U8(iter) => iter.slice(range).map(ReadJoints::U8),
+            ReadJoints::U16(iter) => iter.slice(range).map(ReadJoints::U16),
+        }
+    }
+
     /// Reinterpret joints as u16, which can fit any possible joint.
     pub fn into_u16(self) -> self::joints::CastingIter<'a, self::joints::U16> {
         self::joints::CastingIter::new(self)
@@ -202,6 +542,17 @@ impl<'a> ReadJoints<'a> {
 }
 
 impl<'a> ReadTexCoords<'a> {
+    /// Returns the texture co-ordinates in a range of the remaining ones, or `None` if the
+    /// range is out of bounds; see [`Iter::slice`].
+    pub fn slice(self, range: ops::Range<usize>) -> Option<Self> {
+        match self {
+            ReadTexCoords::U8(iter) => iter.slice(range).map(ReadTexCoords::U8),
+            ReadTexCoords::U16(iter) => iter.slice(range).map(ReadTexCoords::U16),
+            ReadTexCoords::F32(iter) => iter.slice(range).map(ReadTexCoords::F32),
+            ReadTexCoords::Quantized(iter) => iter.slice(range).map(ReadTexCoords::Quantized),
+        }
+    }
+
     /// Reinterpret texture coordinates as u8.  Lossy if the underlying iterator
     /// yields u16 or f32.
     pub fn into_u8(self) -> self::tex_coords::CastingIter<'a, self::tex_coords::U8> {
@@ -222,6 +573,16 @@ impl<'a> ReadTexCoords<'a> {
 }
 
 impl<'a> ReadWeights<'a> {
+    /// Returns the weights in a range of the remaining ones, or `None` if the
+    /// range is out of bounds; see [`Iter::slice`].
+    pub fn slice(self, range: ops::Range<usize>) -> Option<Self> {
+        match self {
+            ReadWeights::U8(iter) => iter.slice(range).map(ReadWeights::U8),
+            ReadWeights::U16(iter) => iter.slice(range).map(ReadWeights::U16),
+            ReadWeights::F32(iter) => iter.slice(range).map(ReadWeights::F32),
+        }
+    }
+
     /// Reinterpret weights as u8.  Lossy if the underlying iterator yields u16
     /// or f32.
     pub fn into_u8(self) -> self::weights::CastingIter<'a, self::weights::U8> {
@@ -55,6 +55,7 @@ impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
             ReadTexCoords::U8(ref mut i) => i.next().map(A::cast_u8),
             ReadTexCoords::U16(ref mut i) => i.next().map(A::cast_u16),
             ReadTexCoords::F32(ref mut i) => i.next().map(A::cast_f32),
+            ReadTexCoords::Quantized(ref mut i) => i.next().map(A::cast_f32),
         }
     }
 
@@ -64,6 +65,7 @@ impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
             ReadTexCoords::U8(ref mut i) => i.nth(x).map(A::cast_u8),
             ReadTexCoords::U16(ref mut i) => i.nth(x).map(A::cast_u16),
             ReadTexCoords::F32(ref mut i) => i.nth(x).map(A::cast_f32),
+            ReadTexCoords::Quantized(ref mut i) => i.nth(x).map(A::cast_f32),
         }
     }
 
@@ -72,6 +74,7 @@ impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
             ReadTexCoords::U8(i) => i.last().map(A::cast_u8),
             ReadTexCoords::U16(i) => i.last().map(A::cast_u16),
             ReadTexCoords::F32(i) => i.last().map(A::cast_f32),
+            ReadTexCoords::Quantized(i) => i.last().map(A::cast_f32),
         }
     }
 
@@ -85,6 +88,7 @@ impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
             ReadTexCoords::U8(ref i) => i.size_hint(),
             ReadTexCoords::U16(ref i) => i.size_hint(),
             ReadTexCoords::F32(ref i) => i.size_hint(),
+            ReadTexCoords::Quantized(ref i) => i.size_hint(),
         }
     }
 }
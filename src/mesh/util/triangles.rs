@@ -0,0 +1,113 @@
+use super::DrawIndices;
+use crate::mesh::Mode;
+
+/// Vertex index triples of the triangles of a primitive; see
+/// [`Primitive::triangles`].
+///
+/// [`Primitive::triangles`]: crate::Primitive::triangles
+#[derive(Clone, Debug)]
+pub struct Triangles<'a> {
+    indices: DrawIndices<'a>,
+    mode: Mode,
+    /// The last two indices of a strip, or the first and last indices of a
+    /// fan.
+    previous: [u32; 2],
+    /// The number of triangles yet to be yielded.
+    remaining: usize,
+    /// The number of triangles yielded so far.
+    yielded: usize,
+}
+
+impl<'a> Triangles<'a> {
+    /// Assembles the indices of a primitive of `mode`, returning `None` for
+    /// modes other than triangles.
+    pub(crate) fn new(mut indices: DrawIndices<'a>, mode: Mode) -> Option<Self> {
+        let count = indices.size_hint().0;
+        let (remaining, previous) = match mode {
+            Mode::Triangles => (count / 3, [0; 2]),
+            Mode::TriangleStrip | Mode::TriangleFan => {
+                let previous = match (indices.next(), indices.next()) {
+                    (Some(a), Some(b)) => [a, b],
+                    _ => [0; 2],
+                };
+                (count.saturating_sub(2), previous)
+            }
+            _ => return None,
+        };
+        Some(Self {
+            indices,
+            mode,
+            previous,
+            remaining,
+            yielded: 0,
+        })
+    }
+}
+
+impl<'a> ExactSizeIterator for Triangles<'a> {}
+impl<'a> Iterator for Triangles<'a> {
+    type Item = [u32; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let [a, b] = self.previous;
+        let triangle = match self.mode {
+            Mode::TriangleStrip => {
+                let c = self.indices.next()?;
+                self.previous = [b, c];
+                // Every other triangle is flipped to keep the winding order.
+                if self.yielded % 2 == 0 {
+                    [a, b, c]
+                } else {
+                    [a, c, b]
+                }
+            }
+            Mode::TriangleFan => {
+                let c = self.indices.next()?;
+                self.previous = [a, c];
+                [b, c, a]
+            }
+            _ => [
+                self.indices.next()?,
+                self.indices.next()?,
+                self.indices.next()?,
+            ],
+        };
+        self.remaining -= 1;
+        self.yielded += 1;
+        Some(triangle)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn triangles(mode: Mode, count: u32) -> Option<Vec<[u32; 3]>> {
+        Triangles::new(DrawIndices::Generated(0..count), mode).map(Iterator::collect)
+    }
+
+    #[test]
+    fn assemble_triangles() {
+        assert_eq!(
+            triangles(Mode::Triangles, 7),
+            Some(vec![[0, 1, 2], [3, 4, 5]])
+        );
+        assert_eq!(
+            triangles(Mode::TriangleFan, 5),
+            Some(vec![[1, 2, 0], [2, 3, 0], [3, 4, 0]])
+        );
+        assert_eq!(triangles(Mode::TriangleStrip, 2), Some(vec![]));
+        assert_eq!(triangles(Mode::TriangleFan, 0), Some(vec![]));
+        assert_eq!(triangles(Mode::LineLoop, 3), None);
+        assert_eq!(triangles(Mode::Points, 3), None);
+    }
+}
@@ -0,0 +1,455 @@
+//! [`run`] loads a glTF or GLB asset, applies a list of [`Step`]s to it and
+//! writes the result as GLB in one call.
+//!
+//! The steps share a single working set, the JSON root and the buffer data,
+//! which every transform edits in place: no step holds a copy of the document,
+//! and those that repack the buffer data allocate the packed buffer once. The
+//! [`Limits`] bound the input, the output and the buffer data between steps,
+//! and a [`PipelineReport`] collects what every step did.
+//!
+//! ```
+//! # fn run() -> Result<(), gltf::pipeline::PipelineError> {
+//! use gltf::pipeline::{self, Limits, Step};
+//! use gltf::transform::QuantizeOptions;
+//!
+//! let recipe = [
+//!     Step::Prune,
+//!     Step::DedupTextures,
+//!     Step::NarrowIndices,
+//!     Step::Quantize(QuantizeOptions::default()),
+//!     Step::Canonicalize,
+//! ];
+//! let input = std::fs::read("examples/Box.glb").unwrap();
+//! let limits = Limits {
+//!     max_buffer_bytes: 1 << 20,
+//!     ..Limits::default()
+//! };
+//! let glb = pipeline::run(&input, &recipe, &limits)?;
+//! assert_eq!(&glb[..4], b"glTF");
+//! # Ok(())
+//! # }
+//! # fn main() {
+//! #     run().expect("test failure");
+//! # }
+//! ```
+//!
+//! [`run`]: crate::pipeline::run
+//! [`Step`]: crate::pipeline::Step
+//! [`Limits`]: crate::pipeline::Limits
+//! [`PipelineReport`]: crate::pipeline::PipelineReport
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::{fmt, mem};
+
+use crate::binary::{ChunkAlignment, Glb, Header};
+use crate::convention::Convention;
+use crate::export::merge_buffers;
+#[cfg(feature = "animation")]
+use crate::transform::StrippedChannels;
+use crate::transform::{
+    self, DedupedTextures, EditError, Normalization, NormalizeTarget, QuantizeOptions,
+    QuantizedAccessor,
+};
+use crate::{buffer, Document, Gltf};
+
+/// A transform applied by [`run`], with its options.
+///
+/// Transforms that need callbacks to encode images, such as
+/// [`transform::pack_orm`], are not available as steps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Step {
+    /// Applies [`transform::prune`].
+    Prune,
+
+    /// Applies [`transform::strip_dangling_channels`].
+    #[cfg(feature = "animation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
+    StripDanglingChannels,
+
+    /// Applies [`transform::dedup_accessors`].
+    DedupAccessors,
+
+    /// Applies [`transform::dedup_textures`].
+    DedupTextures,
+
+    /// Applies [`transform::narrow_indices`].
+    NarrowIndices,
+
+    /// Applies [`transform::quantize`].
+    Quantize(QuantizeOptions),
+
+    /// Applies [`transform::canonicalize`].
+    Canonicalize,
+
+    /// Applies [`transform::convert_coordinates`] to a scene.
+    ConvertCoordinates {
+        /// The index of the scene.
+        scene: usize,
+
+        /// The conventions the scene is in.
+        from: Convention,
+    },
+
+    /// Applies [`transform::normalize_scene`] to a scene.
+    NormalizeScene {
+        /// The index of the scene.
+        scene: usize,
+
+        /// The cube to fit the scene into.
+        target: NormalizeTarget,
+    },
+}
+
+impl Step {
+    /// Returns the name of the transform the step applies.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Step::Prune => "prune",
+            #[cfg(feature = "animation")]
+            Step::StripDanglingChannels => "strip_dangling_channels",
+            Step::DedupAccessors => "dedup_accessors",
+            Step::DedupTextures => "dedup_textures",
+            Step::NarrowIndices => "narrow_indices",
+            Step::Quantize(_) => "quantize",
+            Step::Canonicalize => "canonicalize",
+            Step::ConvertCoordinates { .. } => "convert_coordinates",
+            Step::NormalizeScene { .. } => "normalize_scene",
+        }
+    }
+
+    fn apply(
+        &self,
+        root: &mut json::Root,
+        buffers: &mut Vec<buffer::Data>,
+    ) -> Result<Outcome, EditError> {
+        Ok(match *self {
+            Step::Prune => {
+                transform::prune(root, buffers);
+                Outcome::Pruned
+            }
+            #[cfg(feature = "animation")]
+            Step::StripDanglingChannels => {
+                Outcome::StrippedChannels(transform::strip_dangling_channels(root, buffers))
+            }
+            Step::DedupAccessors => {
+                Outcome::DedupedAccessors(transform::dedup_accessors(root, buffers))
+            }
+            Step::DedupTextures => {
+                Outcome::DedupedTextures(transform::dedup_textures(root, buffers))
+            }
+            Step::NarrowIndices => {
+                Outcome::NarrowedIndices(transform::narrow_indices(root, buffers))
+            }
+            Step::Quantize(options) => {
+                Outcome::Quantized(transform::quantize(root, buffers, options))
+            }
+            Step::Canonicalize => {
+                transform::canonicalize(root, buffers);
+                Outcome::Canonicalized
+            }
+            Step::ConvertCoordinates { scene, from } => {
+                Outcome::ConvertedCoordinates(transform::convert_coordinates(root, scene, from))
+            }
+            Step::NormalizeScene { scene, target } => {
+                Outcome::NormalizedScene(transform::normalize_scene(root, buffers, scene, target)?)
+            }
+        })
+    }
+}
+
+/// What a step did, as returned by its transform.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    /// The document was pruned.
+    Pruned,
+
+    /// The channels and samplers that were removed.
+    #[cfg(feature = "animation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
+    StrippedChannels(StrippedChannels),
+
+    /// The new index of every original accessor.
+    DedupedAccessors(Vec<usize>),
+
+    /// The new indices of the original images and textures.
+    DedupedTextures(DedupedTextures),
+
+    /// The accessors whose indices were narrowed.
+    NarrowedIndices(Vec<usize>),
+
+    /// The accessors that were quantized.
+    Quantized(Vec<QuantizedAccessor>),
+
+    /// The document was canonicalized.
+    Canonicalized,
+
+    /// The index of the inserted wrapper node, if the scene was converted.
+    ConvertedCoordinates(Option<usize>),
+
+    /// The applied transform, if the scene was normalized.
+    NormalizedScene(Option<Normalization>),
+}
+
+/// The report of a step of [`run_with_report`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct StepReport {
+    /// The step.
+    pub step: Step,
+
+    /// What the step did.
+    pub outcome: Outcome,
+
+    /// The length of the buffer data after the step.
+    pub buffer_bytes: usize,
+}
+
+/// The report of [`run_with_report`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PipelineReport {
+    /// The length of the buffer data after loading.
+    pub loaded_buffer_bytes: usize,
+
+    /// The reports of the steps, in order.
+    pub steps: Vec<StepReport>,
+
+    /// The largest length of the buffer data between steps.
+    pub peak_buffer_bytes: usize,
+
+    /// The length of the output.
+    pub output_bytes: usize,
+}
+
+/// Bounds enforced by [`run`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Limits {
+    /// The maximum length of the input.
+    pub max_input_bytes: usize,
+
+    /// The maximum total length of the buffer data, checked after loading
+    /// and after every step.
+    pub max_buffer_bytes: usize,
+
+    /// The maximum length of the output.
+    ///
+    /// It is checked before the output is allocated.
+    pub max_output_bytes: usize,
+}
+
+impl Default for Limits {
+    /// Limits nothing.
+    fn default() -> Self {
+        Self {
+            max_input_bytes: usize::MAX,
+            max_buffer_bytes: usize::MAX,
+            max_output_bytes: usize::MAX,
+        }
+    }
+}
+
+/// Reasons for [`run`] to fail.
+#[derive(Debug)]
+pub enum PipelineError {
+    /// The input is longer than [`Limits::max_input_bytes`].
+    InputTooLarge {
+        /// The length of the input.
+        length: usize,
+
+        /// The limit.
+        limit: usize,
+    },
+
+    /// The input is not valid glTF, or its buffers cannot be loaded.
+    ///
+    /// Buffers are loaded like [`import_buffers`] does, so external buffers
+    /// are not supported.
+    ///
+    /// [`import_buffers`]: crate::import_buffers
+    Load(crate::Error),
+
+    /// A step failed, and the working set was discarded.
+    Step {
+        /// The index of the step.
+        index: usize,
+
+        /// The step.
+        step: Step,
+
+        /// The error of its transform.
+        error: EditError,
+    },
+
+    /// The buffer data exceeded [`Limits::max_buffer_bytes`].
+    BufferLimit {
+        /// The index of the step after which the limit was exceeded, or
+        /// `None` if it was exceeded by loading.
+        after: Option<usize>,
+
+        /// The length of the buffer data.
+        length: usize,
+
+        /// The limit.
+        limit: usize,
+    },
+
+    /// The transformed document is not valid, or is too large for GLB.
+    Write(crate::Error),
+
+    /// The output would be longer than [`Limits::max_output_bytes`].
+    OutputTooLarge {
+        /// The length of the output.
+        length: usize,
+
+        /// The limit.
+        limit: usize,
+    },
+}
+
+impl fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PipelineError::InputTooLarge { length, limit } => write!(
+                f,
+                "input of {} bytes exceeds the limit of {} bytes",
+                length, limit
+            ),
+            PipelineError::Load(error) => write!(f, "failed to load the input: {}", error),
+            PipelineError::Step { index, step, error } => {
+                write!(f, "step {} ({}) failed: {}", index, step.name(), error)
+            }
+            PipelineError::BufferLimit {
+                after,
+                length,
+                limit,
+            } => {
+                write!(f, "buffer data of {} bytes ", length)?;
+                match after {
+                    Some(index) => write!(f, "after step {}", index)?,
+                    None => write!(f, "after loading")?,
+                }
+                write!(f, " exceeds the limit of {} bytes", limit)
+            }
+            PipelineError::Write(error) => write!(f, "failed to write the output: {}", error),
+            PipelineError::OutputTooLarge { length, limit } => write!(
+                f,
+                "output of {} bytes exceeds the limit of {} bytes",
+                length, limit
+            ),
+        }
+    }
+}
+
+impl core::error::Error for PipelineError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            PipelineError::Load(error) | PipelineError::Write(error) => Some(error),
+            PipelineError::Step { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Loads a glTF or GLB asset, applies the steps in order and writes the
+/// result as GLB.
+///
+/// See [`run_with_report`].
+pub fn run(slice: &[u8], steps: &[Step], limits: &Limits) -> Result<Vec<u8>, PipelineError> {
+    run_with_report(slice, steps, limits).map(|(glb, _)| glb)
+}
+
+/// Loads a glTF or GLB asset, applies the steps in order and writes the
+/// result as GLB, returning it with the report of every step.
+///
+/// The buffer data is packed into the `BIN` chunk. Buffers are moved into it
+/// one at a time, so that steps which leave several buffers, or buffers with
+/// URIs, do not need to be followed by [`Step::Prune`]; the data of unused
+/// buffer views, such as the original data of quantized accessors, is only
+/// dropped by a later [`Step::Prune`]. The document is validated before it is
+/// written.
+pub fn run_with_report(
+    slice: &[u8],
+    steps: &[Step],
+    limits: &Limits,
+) -> Result<(Vec<u8>, PipelineReport), PipelineError> {
+    if slice.len() > limits.max_input_bytes {
+        return Err(PipelineError::InputTooLarge {
+            length: slice.len(),
+            limit: limits.max_input_bytes,
+        });
+    }
+    let Gltf { document, blob } = Gltf::from_slice(slice).map_err(PipelineError::Load)?;
+    let mut buffers = crate::import_buffers(&document, blob).map_err(PipelineError::Load)?;
+    let mut root = document.into_json();
+
+    let mut report = PipelineReport::default();
+    report.loaded_buffer_bytes = check(&buffers, None, limits, &mut report)?;
+    for (index, &step) in steps.iter().enumerate() {
+        let outcome = step
+            .apply(&mut root, &mut buffers)
+            .map_err(|error| PipelineError::Step { index, step, error })?;
+        let buffer_bytes = check(&buffers, Some(index), limits, &mut report)?;
+        report.steps.push(StepReport {
+            step,
+            outcome,
+            buffer_bytes,
+        });
+    }
+
+    let glb = write(root, buffers, limits)?;
+    report.output_bytes = glb.len();
+    Ok((glb, report))
+}
+
+/// Checks the length of the buffer data against the limits, and returns it.
+fn check(
+    buffers: &[buffer::Data],
+    after: Option<usize>,
+    limits: &Limits,
+    report: &mut PipelineReport,
+) -> Result<usize, PipelineError> {
+    let length = buffers.iter().map(|data| data.len()).sum();
+    if length > limits.max_buffer_bytes {
+        return Err(PipelineError::BufferLimit {
+            after,
+            length,
+            limit: limits.max_buffer_bytes,
+        });
+    }
+    report.peak_buffer_bytes = report.peak_buffer_bytes.max(length);
+    Ok(length)
+}
+
+/// Validates the document and writes it as GLB.
+fn write(
+    mut root: json::Root,
+    mut buffers: Vec<buffer::Data>,
+    limits: &Limits,
+) -> Result<Vec<u8>, PipelineError> {
+    merge_buffers(&mut root, &mut buffers);
+    let document = Document::from_json(root).map_err(PipelineError::Write)?;
+    let json = document
+        .as_json()
+        .to_vec()
+        .map_err(|error| PipelineError::Write(error.into()))?;
+    mem::drop(document);
+    let glb = Glb {
+        header: Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: Cow::Owned(json),
+        bin: buffers.first().map(|data| Cow::Borrowed(&data[..])),
+        extra_chunks: Vec::new(),
+        length_read: None,
+        chunk_alignment: ChunkAlignment::Exact,
+    };
+    let length = glb.output_length().map_err(PipelineError::Write)?;
+    if length > limits.max_output_bytes {
+        return Err(PipelineError::OutputTooLarge {
+            length,
+            limit: limits.max_output_bytes,
+        });
+    }
+    glb.to_vec().map_err(PipelineError::Write)
+}
@@ -1,4 +1,8 @@
+use alloc::vec;
+use alloc::vec::Vec;
 use core::slice;
+use crate::math::Matrix4;
+use crate::scene::CycleError;
 use crate::{Document, Node};
 
 /// An `Iterator` that visits the nodes in a scene.
@@ -21,6 +25,200 @@ pub struct Children<'a> {
     pub(crate) iter: slice::Iter<'a, json::Index<json::scene::Node>>,
 }
 
+/// An `Iterator` that visits every node in a scene depth-first, parents
+/// before their children.
+///
+/// Each node is visited at most once, so the walk terminates even if the node
+/// hierarchy contains cycles or nodes with multiple parents. Out of range node
+/// indices are skipped.
+#[derive(Clone, Debug)]
+pub struct Walk<'a> {
+    /// The parent `Document` struct.
+    pub(crate) document: &'a Document,
+
+    /// Node index iterators for the nodes yet to be fully visited, with the
+    /// index of the node whose children they visit.
+    pub(crate) stack: Vec<(
+        Option<usize>,
+        slice::Iter<'a, json::Index<json::scene::Node>>,
+    )>,
+
+    /// Marks the nodes that have already been visited.
+    pub(crate) visited: Vec<bool>,
+}
+
+impl<'a> Walk<'a> {
+    pub(crate) fn new(
+        document: &'a Document,
+        roots: slice::Iter<'a, json::Index<json::scene::Node>>,
+    ) -> Self {
+        Self {
+            document,
+            stack: vec![(None, roots)],
+            visited: vec![false; document.as_json().nodes.len()],
+        }
+    }
+
+    /// Visits the next node, along with the index of the parent it was
+    /// reached from, or `None` for a root node.
+    pub(crate) fn next_with_parent(&mut self) -> Option<(Option<usize>, Node<'a>)> {
+        while let Some((parent, iter)) = self.stack.last_mut() {
+            let parent = *parent;
+            let index = match iter.next() {
+                Some(index) => index.value(),
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+            match self.visited.get_mut(index) {
+                Some(visited) if !*visited => *visited = true,
+                _ => continue,
+            }
+            let json = &self.document.as_json().nodes[index];
+            if let Some(children) = json.children.as_ref() {
+                self.stack.push((Some(index), children.iter()));
+            }
+            return Some((parent, Node::new(self.document, index, json)));
+        }
+        None
+    }
+}
+
+impl<'a> Iterator for Walk<'a> {
+    type Item = Node<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_parent().map(|(_, node)| node)
+    }
+}
+
+/// An `Iterator` that visits every node in a scene depth-first, parents
+/// before their children, with its world transform.
+///
+/// Nodes reached again from another parent are skipped, as by [`Walk`], and
+/// out of range node indices are skipped. A node that is its own ancestor
+/// ends the traversal with a [`CycleError`].
+#[derive(Clone, Debug)]
+pub struct Traverse<'a> {
+    /// The parent `Document` struct.
+    pub(crate) document: &'a Document,
+
+    /// The node to visit first, if the traversal starts at a node.
+    pub(crate) start: Option<usize>,
+
+    /// Node index iterators for the nodes yet to be fully visited, with the
+    /// index and world transform of the node whose children they visit.
+    pub(crate) stack: Vec<(
+        Option<usize>,
+        Matrix4,
+        slice::Iter<'a, json::Index<json::scene::Node>>,
+    )>,
+
+    /// Marks the nodes that have already been visited.
+    pub(crate) visited: Vec<bool>,
+
+    /// Marks the nodes whose children are on the stack.
+    pub(crate) ancestors: Vec<bool>,
+}
+
+impl<'a> Traverse<'a> {
+    pub(crate) fn new(
+        document: &'a Document,
+        start: Option<usize>,
+        roots: slice::Iter<'a, json::Index<json::scene::Node>>,
+        root_transform: [[f32; 4]; 4],
+    ) -> Self {
+        let count = document.as_json().nodes.len();
+        Self {
+            document,
+            start,
+            stack: vec![(None, Matrix4::from_array(root_transform), roots)],
+            visited: vec![false; count],
+            ancestors: vec![false; count],
+        }
+    }
+
+    /// Visits a node reached from the node at the top of the stack, or
+    /// returns `None` to skip it.
+    fn visit(&mut self, index: usize) -> Option<<Self as Iterator>::Item> {
+        let (parent, parent_world, _) = self.stack.last()?;
+        if self.ancestors.get(index) == Some(&true) {
+            let error = CycleError {
+                node: index,
+                parent: parent.unwrap_or(index),
+            };
+            self.stack.clear();
+            return Some(Err(error));
+        }
+        match self.visited.get_mut(index) {
+            Some(visited) if !*visited => *visited = true,
+            _ => return None,
+        }
+        let json = &self.document.as_json().nodes[index];
+        let node = Node::new(self.document, index, json);
+        let world = *parent_world * Matrix4::from_array(node.transform().matrix());
+        if let Some(children) = json.children.as_ref() {
+            self.ancestors[index] = true;
+            self.stack.push((Some(index), world, children.iter()));
+        }
+        Some(Ok((node, world.as_array())))
+    }
+}
+
+impl<'a> Iterator for Traverse<'a> {
+    type Item = Result<(Node<'a>, [[f32; 4]; 4]), CycleError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(index) = self.start.take() {
+            if let Some(item) = self.visit(index) {
+                return Some(item);
+            }
+        }
+        while let Some((parent, _, iter)) = self.stack.last_mut() {
+            let index = match iter.next() {
+                Some(index) => index.value(),
+                None => {
+                    if let Some(parent) = *parent {
+                        self.ancestors[parent] = false;
+                    }
+                    self.stack.pop();
+                    continue;
+                }
+            };
+            if let Some(item) = self.visit(index) {
+                return Some(item);
+            }
+        }
+        None
+    }
+}
+
+/// An `Iterator` over the indices of the ancestors of a node; see
+/// [`NodeParentMap::ancestors`](crate::scene::NodeParentMap::ancestors).
+#[derive(Clone, Debug)]
+pub struct Ancestors<'a> {
+    /// The parent of each node, indexed by node.
+    pub(crate) parents: &'a [Option<usize>],
+
+    /// The node whose ancestors are visited.
+    pub(crate) node: usize,
+
+    /// The next ancestor to visit.
+    pub(crate) next: Option<usize>,
+
+    /// The number of ancestors that may still be visited.
+    pub(crate) remaining: usize,
+}
+
+impl<'a> Iterator for Ancestors<'a> {
+    type Item = usize;
+    fn next(&mut self) -> Option<Self::Item> {
+        let ancestor = self.next.filter(|&next| next != self.node)?;
+        self.remaining = self.remaining.checked_sub(1)?;
+        self.next = self.parents.get(ancestor).copied().flatten();
+        Some(ancestor)
+    }
+}
+
 impl<'a> ExactSizeIterator for Nodes<'a> {}
 impl<'a> Iterator for Nodes<'a> {
     type Item = Node<'a>;
@@ -1,13 +1,26 @@
 use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "cameras")]
 #[cfg(feature = "extensions")]
 use serde_json::{Map, Value};
 
 use crate::math::*;
-use crate::{Camera, Document, Mesh, Skin};
+#[cfg(feature = "utils")]
+use crate::mesh::{extend_bounds, BoundingBox};
+#[cfg(feature = "cameras")]
+use crate::Camera;
+#[cfg(feature = "skinning")]
+use crate::Skin;
+use crate::{Document, Mesh};
 
 /// Iterators.
 pub mod iter;
 
+mod parents;
+
+pub(crate) use self::parents::world_transforms;
+pub use self::parents::{NodeParentMap, ParentConflict};
+
 /// The transform for a `Node`.
 #[derive(Clone, Debug)]
 pub enum Transform {
@@ -54,7 +67,10 @@ impl Transform {
     /// Returns a decomposed representation of this transform.
     ///
     /// If the transform is `Matrix`, then the decomposition is extracted from the
-    /// matrix.
+    /// matrix. A matrix that mirrors has the z scale negated, so that the
+    /// rotation is proper. Axes scaled to zero have no direction, so the
+    /// rotation is completed from the others, or with the identity where
+    /// they do not determine it.
     pub fn decomposed(self) -> ([f32; 3], [f32; 4], [f32; 3]) {
         match self {
             Transform::Matrix { matrix: m } => {
@@ -69,9 +85,16 @@ impl Transform {
                 let sy = i.y.magnitude();
                 let sz = (if i.determinant() < 0.0 {-1.0} else {1.0}) * i.z.magnitude();
                 let scale = [sx, sy, sz];
-                i.x.multiply(1.0 / sx);
-                i.y.multiply(1.0 / sy);
-                i.z.multiply(1.0 / sz);
+                let mut axes = [i.x, i.y, i.z];
+                for (axis, &s) in axes.iter_mut().zip(&scale) {
+                    if s != 0.0 {
+                        axis.multiply(1.0 / s);
+                    }
+                }
+                complete_basis(&mut axes, scale.map(|s| s != 0.0));
+                i.x = axes[0];
+                i.y = axes[1];
+                i.z = axes[2];
                 let r = Quaternion::from_matrix(i);
                 let rotation = [r.v.x, r.v.y, r.v.z, r.s];
                 (translation, rotation, scale)
@@ -85,6 +108,61 @@ impl Transform {
     }
 }
 
+/// Replaces the axes of a rotation matrix that are not `present` with ones
+/// that complete a right-handed orthonormal basis.
+fn complete_basis(axes: &mut [Vector3; 3], present: [bool; 3]) {
+    let unit = [
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    ];
+    match present.iter().filter(|&&x| x).count() {
+        3 => {}
+        2 => {
+            let k = present.iter().position(|&x| !x).unwrap();
+            axes[k] = axes[(k + 1) % 3].cross(&axes[(k + 2) % 3]);
+        }
+        1 => {
+            // Orthogonalize the next unit axis against the present one,
+            // falling back to the one after if they are parallel.
+            let k = present.iter().position(|&x| x).unwrap();
+            let u = axes[k];
+            let mut next = unit[(k + 1) % 3];
+            if next.cross(&u).magnitude() < 1e-3 {
+                next = unit[(k + 2) % 3];
+            }
+            let d = next.dot(&u);
+            let next = Vector3::new(next.x - d * u.x, next.y - d * u.y, next.z - d * u.z);
+            let next = next.normalize();
+            axes[(k + 1) % 3] = next;
+            axes[(k + 2) % 3] = u.cross(&next);
+        }
+        _ => *axes = unit,
+    }
+}
+
+/// Error returned by [`iter::Traverse`] when a node is its own ancestor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CycleError {
+    /// The index of the node.
+    pub node: usize,
+
+    /// The index of the descendant of the node that lists it as a child.
+    pub parent: usize,
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "node {} is an ancestor of node {}, which lists it as a child",
+            self.node, self.parent
+        )
+    }
+}
+
+impl core::error::Error for CycleError {}
+
 /// A node in the node hierarchy.
 ///
 /// When a node contains a skin, all its meshes contain `JOINTS_0` and `WEIGHTS_0`
@@ -131,6 +209,8 @@ impl<'a> Node<'a> {
     }
 
     /// Returns the camera referenced by this node.
+    #[cfg(feature = "cameras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cameras")))]
     pub fn camera(&self) -> Option<Camera<'a>> {
         self.json
             .camera
@@ -138,6 +218,16 @@ impl<'a> Node<'a> {
             .map(|index| self.document.cameras().nth(index.value()).unwrap())
     }
 
+    /// Returns an `Iterator` that visits the node and its descendants
+    /// depth-first with their world transforms.
+    ///
+    /// The world transform of the node is `root_transform` multiplied by its
+    /// own, so that a subtree can be instanced anywhere. See
+    /// [`Scene::traverse`].
+    pub fn traverse(&self, root_transform: [[f32; 4]; 4]) -> iter::Traverse<'a> {
+        iter::Traverse::new(self.document, Some(self.index), [].iter(), root_transform)
+    }
+
     /// Returns an `Iterator` that visits the node's children.
     pub fn children(&self) -> iter::Children<'a> {
         iter::Children {
@@ -194,7 +284,7 @@ impl<'a> Node<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
     }
 
     /// Returns the node's transform.
@@ -218,6 +308,8 @@ impl<'a> Node<'a> {
     }
 
     /// Returns the skin referenced by this node.
+    #[cfg(feature = "skinning")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "skinning")))]
     pub fn skin(&self) -> Option<Skin<'a>> {
         self.json
             .skin
@@ -270,7 +362,7 @@ impl<'a> Scene<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
     }
 
     /// Returns an `Iterator` that visits each root node of the scene.
@@ -280,13 +372,96 @@ impl<'a> Scene<'a> {
             iter: self.json.nodes.iter(),
         }
     }
+
+    /// Returns an `Iterator` that visits every node of the scene depth-first,
+    /// starting from the root nodes.
+    ///
+    /// Nodes are visited at most once, so the walk terminates on invalid
+    /// hierarchies even when validation was skipped. Nothing is visited if the
+    /// scene has no nodes.
+    pub fn walk(&self) -> iter::Walk<'a> {
+        iter::Walk::new(self.document, self.json.nodes.iter())
+    }
+
+    /// Returns an `Iterator` that visits every node of the scene depth-first
+    /// with its world transform, starting from the root nodes.
+    ///
+    /// The world transform of a node is that of its parent multiplied by its
+    /// own, in column-major order. Unlike [`Scene::walk`], cycles in the
+    /// hierarchy are reported; see [`iter::Traverse`].
+    pub fn traverse(&self) -> iter::Traverse<'a> {
+        let identity = Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0).as_array();
+        iter::Traverse::new(self.document, None, self.json.nodes.iter(), identity)
+    }
+
+    /// Returns the world-space bounding box of the meshes of the scene.
+    ///
+    /// The eight corners of the bounding box of each primitive, as computed
+    /// by [`Primitive::compute_bounding_box`], are transformed by the world
+    /// transform of its node; see [`Scene::traverse`]. Returns `None` if no
+    /// primitive has a bounding box. Nodes after a cycle in the hierarchy are
+    /// not visited.
+    ///
+    /// [`Primitive::compute_bounding_box`]: crate::Primitive::compute_bounding_box
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn bounding_box(&self, buffers: &[crate::buffer::Data]) -> Option<BoundingBox> {
+        let mut bounds = None;
+        for (node, world) in self.traverse().map_while(Result::ok) {
+            let mesh = match node.mesh() {
+                Some(mesh) => mesh,
+                None => continue,
+            };
+            let world = Matrix4::from_array(world);
+            for primitive in mesh.primitives() {
+                let local = match primitive.compute_bounding_box(buffers) {
+                    Some(local) => local,
+                    None => continue,
+                };
+                for corner in 0..8 {
+                    let pick = |i: usize| match corner >> i & 1 {
+                        0 => local.min[i],
+                        _ => local.max[i],
+                    };
+                    let point = world.x * pick(0) + world.y * pick(1) + world.z * pick(2) + world.w;
+                    extend_bounds(&mut bounds, [point.x, point.y, point.z]);
+                }
+            }
+        }
+        bounds
+    }
+
+    /// Returns the first camera node of the scene in traversal order, with its
+    /// camera and world transform.
+    ///
+    /// This is the camera that a viewer without a camera of its own would
+    /// typically activate. See [`Document::cameras_in_scene`].
+    #[cfg(feature = "cameras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cameras")))]
+    pub fn first_camera(&self) -> Option<(Node<'a>, Camera<'a>, [[f32; 4]; 4])> {
+        self.cameras().next()
+    }
+
+    /// Returns an `Iterator` that visits the camera nodes of the scene in
+    /// walk order, with their world transforms; see [`Scene::traverse`].
+    #[cfg(feature = "cameras")]
+    pub(crate) fn cameras(
+        &self,
+    ) -> impl Iterator<Item = (Node<'a>, Camera<'a>, [[f32; 4]; 4])> + 'a {
+        self.traverse()
+            .map_while(Result::ok)
+            .filter_map(|(node, world)| node.camera().map(|camera| (node, camera, world)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use core::f32::consts::PI;
     use crate::math::*;
-    use crate::scene::Transform;
+    use crate::scene::{CycleError, Transform};
+    use crate::Document;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
 
     fn rotate(x: f32, y: f32, z: f32, r: f32) -> [f32; 4] {
         let r = Quaternion::from_axis_angle(Vector3::new(x, y, z).normalize(), r);
@@ -627,4 +802,143 @@ mod tests {
         let scale = [10.0, 0.1, -0.1];
         test_decompose_scale(scale);
     }
+
+    #[test]
+    fn decompose_zero_scale() {
+        for scale in [
+            [0.0, 1.0, 1.0],
+            [2.0, 0.0, 3.0],
+            [1.0, 1.0, 0.0],
+            [0.0, 0.0, 3.0],
+            [0.0, -2.0, 0.0],
+            [0.0, 0.0, 0.0],
+        ] {
+            test_decompose_scale(scale);
+            let matrix = Transform::Decomposed {
+                translation: [1.0, 2.0, 3.0],
+                rotation: rotate(1.0, 1.0, 0.0, PI / 3.0),
+                scale,
+            }
+            .matrix();
+            let (_, rotation, decomposed) = Transform::Matrix { matrix }.decomposed();
+            let length: f32 = rotation.iter().map(|x| x * x).sum();
+            assert_relative_eq!(length, 1.0, epsilon = 1e-5);
+            let check = Transform::Decomposed {
+                translation: [1.0, 2.0, 3.0],
+                rotation,
+                scale: decomposed,
+            }
+            .matrix();
+            assert_relative_eq!(
+                Matrix4::from_array(check),
+                Matrix4::from_array(matrix),
+                epsilon = 1e-5
+            );
+        }
+        let (_, rotation, _) = Transform::Matrix {
+            matrix: [[0.0; 4], [0.0; 4], [0.0; 4], [0.0, 0.0, 0.0, 1.0]],
+        }
+        .decomposed();
+        assert_eq!(rotation, [0.0, 0.0, 0.0, 1.0]);
+    }
+
+    fn walk_indices(json: &str) -> Vec<usize> {
+        let root = json::Root::from_str(json).unwrap();
+        let document = Document::from_json_without_validation(root);
+        let scene = document.scenes().next().unwrap();
+        scene.walk().map(|node| node.index()).collect()
+    }
+
+    #[test]
+    fn walk_depth_first() {
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "scenes": [{ "nodes": [0, 3] }],
+            "nodes": [{ "children": [1, 2] }, {}, {}, {}]
+        }"#;
+        assert_eq!(walk_indices(json), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn walk_terminates_on_invalid_hierarchy() {
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "scenes": [{ "nodes": [0, 2] }],
+            "nodes": [{ "children": [0, 1, 9] }, { "children": [2, 0] }, { "children": [1] }]
+        }"#;
+        assert_eq!(walk_indices(json), [0, 1, 2]);
+    }
+
+    fn translation(matrix: [[f32; 4]; 4]) -> [f32; 3] {
+        [matrix[3][0], matrix[3][1], matrix[3][2]]
+    }
+
+    #[test]
+    fn traverse_world_transforms() {
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "scenes": [{ "nodes": [0, 3] }],
+            "nodes": [
+                { "children": [1], "translation": [1, 0, 0], "scale": [2, 2, 2] },
+                { "children": [2], "translation": [0, 1, 0] },
+                { "translation": [0, 0, 1] },
+                { "children": [2] }
+            ]
+        }"#;
+        let root = json::Root::from_str(json).unwrap();
+        let document = Document::from_json_without_validation(root);
+        let scene = document.scenes().next().unwrap();
+        let visited: Vec<_> = scene
+            .traverse()
+            .map(|item| {
+                let (node, world) = item.unwrap();
+                (node.index(), translation(world))
+            })
+            .collect();
+        // Node 2 is skipped when reached again from node 3.
+        assert_eq!(
+            visited,
+            [
+                (0, [1.0, 0.0, 0.0]),
+                (1, [1.0, 2.0, 0.0]),
+                (2, [1.0, 2.0, 2.0]),
+                (3, [0.0, 0.0, 0.0])
+            ]
+        );
+
+        let mut root_transform = Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0).as_array();
+        root_transform[3] = [0.0, 0.0, 5.0, 1.0];
+        let node = document.nodes().nth(1).unwrap();
+        let visited: Vec<_> = node
+            .traverse(root_transform)
+            .map(|item| {
+                let (node, world) = item.unwrap();
+                (node.index(), translation(world))
+            })
+            .collect();
+        assert_eq!(visited, [(1, [0.0, 1.0, 5.0]), (2, [0.0, 1.0, 6.0])]);
+    }
+
+    #[test]
+    fn traverse_reports_cycles() {
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "scenes": [{ "nodes": [0] }],
+            "nodes": [{ "children": [1, 9] }, { "children": [2] }, { "children": [0] }]
+        }"#;
+        let root = json::Root::from_str(json).unwrap();
+        let document = Document::from_json_without_validation(root);
+        let scene = document.scenes().next().unwrap();
+        let mut traverse = scene.traverse();
+        for index in 0..3 {
+            assert_eq!(traverse.next().unwrap().unwrap().0.index(), index);
+        }
+        let error = CycleError { node: 0, parent: 2 };
+        assert_eq!(traverse.next().map(|item| item.err()), Some(Some(error)));
+        assert!(traverse.next().is_none());
+        assert_eq!(
+            error.to_string(),
+            "node 0 is an ancestor of node 2, which lists it as a child"
+        );
+    }
 }
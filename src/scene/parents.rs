@@ -0,0 +1,174 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::math::Matrix4;
+use crate::scene::iter;
+use crate::{Document, Node};
+
+/// The parent of every node of a document; see [`Document::node_parents`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NodeParentMap {
+    /// The parent of each node, indexed by node.
+    parents: Vec<Option<usize>>,
+
+    /// The children lists whose entries were ignored.
+    conflicts: Vec<ParentConflict>,
+}
+
+/// A node listed as a child by more than one node, or more than once by the
+/// same node, which the specification forbids.
+///
+/// Validation reports the entry as [`Error::Invalid`] at
+/// [`ParentConflict::path`].
+///
+/// [`Error::Invalid`]: json::validation::Error::Invalid
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParentConflict {
+    /// The index of the child node.
+    pub node: usize,
+
+    /// The index of the node that listed the child first, which is taken as
+    /// its parent.
+    pub parent: usize,
+
+    /// The index of the node whose entry was ignored.
+    pub other: usize,
+
+    /// The position of the ignored entry in the children of `other`.
+    pub position: usize,
+}
+
+impl NodeParentMap {
+    /// Builds the map from the children of every node.
+    ///
+    /// A node listed as a child more than once takes the first node in
+    /// document order that lists it as its parent, and the other entries are
+    /// recorded as [`conflicts`](Self::conflicts). Nodes listing themselves
+    /// and out of range children are ignored.
+    pub(crate) fn new(document: &Document) -> Self {
+        let nodes = &document.as_json().nodes;
+        let mut map = NodeParentMap {
+            parents: vec![None; nodes.len()],
+            conflicts: Vec::new(),
+        };
+        for (index, node) in nodes.iter().enumerate() {
+            for (position, child) in node.children.iter().flatten().enumerate() {
+                let child = child.value();
+                if child == index || child >= nodes.len() {
+                    continue;
+                }
+                match map.parents[child] {
+                    Some(parent) => map.conflicts.push(ParentConflict {
+                        node: child,
+                        parent,
+                        other: index,
+                        position,
+                    }),
+                    None => map.parents[child] = Some(index),
+                }
+            }
+        }
+        map
+    }
+
+    /// Returns the index of the parent of a node, or `None` for a root node
+    /// or an out of range index.
+    pub fn parent_of(&self, node: usize) -> Option<usize> {
+        self.parents.get(node).copied().flatten()
+    }
+
+    /// Returns an `Iterator` over the indices of the ancestors of a node,
+    /// from its parent up to the root.
+    ///
+    /// If the node hierarchy has cycles, which validation rejects, the
+    /// iterator stops before the node itself or after visiting as many
+    /// nodes as there are.
+    pub fn ancestors(&self, node: usize) -> iter::Ancestors<'_> {
+        iter::Ancestors {
+            parents: &self.parents,
+            node,
+            next: self.parent_of(node),
+            remaining: self.parents.len(),
+        }
+    }
+
+    /// Returns the children entries that were ignored because the child
+    /// already had a parent.
+    pub fn conflicts(&self) -> &[ParentConflict] {
+        &self.conflicts
+    }
+}
+
+impl ParentConflict {
+    /// Returns the path of the ignored entry, such as `nodes[2].children[0]`.
+    pub fn path(&self) -> json::Path {
+        json::Path::new()
+            .field("nodes")
+            .index(self.other)
+            .field("children")
+            .index(self.position)
+    }
+}
+
+impl fmt::Display for ParentConflict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: node {} is already a child of node {}",
+            self.path(),
+            self.node,
+            self.parent
+        )
+    }
+}
+
+impl<'a> Node<'a> {
+    /// Returns the world transform of the node, multiplying the local
+    /// transforms of its ancestors in column-major order.
+    ///
+    /// Only the ancestors of the node are visited, so this is cheaper than
+    /// [`Scene::traverse`](crate::Scene::traverse) for a single node.
+    pub fn global_transform(&self, parents: &NodeParentMap) -> [[f32; 4]; 4] {
+        let nodes = &self.document.as_json().nodes;
+        let local = |index: usize| {
+            let node = Node::new(self.document, index, &nodes[index]);
+            Matrix4::from_array(node.transform().matrix())
+        };
+        parents
+            .ancestors(self.index)
+            .fold(local(self.index), |world, ancestor| local(ancestor) * world)
+            .as_array()
+    }
+}
+
+/// Returns the world transform of every node of a document.
+///
+/// The nodes without a parent are traversed with [`iter::Traverse`]. Nodes
+/// it does not reach, because the hierarchy has a cycle, take the transform
+/// of [`Node::global_transform`].
+pub(crate) fn world_transforms(document: &Document) -> Vec<Matrix4> {
+    let parents = document.node_parents();
+    let nodes = &document.as_json().nodes;
+    let roots: Vec<_> = (0..nodes.len())
+        .filter(|&node| parents.parent_of(node).is_none())
+        .map(|node| json::Index::new(node as u32))
+        .collect();
+    let identity = Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0).as_array();
+    let mut worlds = vec![None; nodes.len()];
+    for (node, world) in
+        iter::Traverse::new(document, None, roots.iter(), identity).map_while(Result::ok)
+    {
+        worlds[node.index()] = Some(Matrix4::from_array(world));
+    }
+    worlds
+        .into_iter()
+        .enumerate()
+        .map(|(index, world)| {
+            world.unwrap_or_else(|| {
+                let node = Node::new(document, index, &nodes[index]);
+                Matrix4::from_array(node.global_transform(&parents))
+            })
+        })
+        .collect()
+}
@@ -15,10 +15,20 @@ pub mod iter;
 #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
 pub mod util;
 
+#[cfg(feature = "utils")]
+mod pose;
+
 #[cfg(feature = "utils")]
 #[doc(inline)]
 pub use self::util::Reader;
 
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+pub use self::pose::{
+    apply_pose, apply_pose_with, joint_matrices, joint_matrices_in_node_space, PoseOptions,
+    SkinError, SkinnedVertices,
+};
+
 /// Joints and matrices defining a skin.
 #[derive(Clone, Debug)]
 pub struct Skin<'a> {
@@ -105,7 +115,7 @@ impl<'a> Skin<'a> {
     #[cfg(feature = "names")]
     #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
     pub fn name(&self) -> Option<&'a str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
     }
 
     /// Returns the node used as the skeleton root. When `None`, joints
@@ -0,0 +1,422 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+use core::intrinsics::sqrtf32;
+
+use crate::math::Matrix4;
+use crate::mesh::{self, Semantic};
+use crate::{accessor, Buffer};
+
+use super::Reader;
+
+type Matrix = [[f32; 4]; 4];
+
+const IDENTITY: Matrix = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
+
+/// Error returned when skinning a primitive on the CPU.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SkinError {
+    /// The primitive lacks an attribute required for skinning, or its data
+    /// is unavailable.
+    MissingAttribute(Semantic),
+
+    /// An attribute has a different number of elements than `POSITION`.
+    AttributeCount {
+        /// The attribute.
+        semantic: Semantic,
+        /// The number of elements of the attribute.
+        count: usize,
+        /// The number of positions.
+        expected: usize,
+    },
+
+    /// The number of joint matrices is not the number of joints of the skin.
+    JointCount {
+        /// The number of joints of the skin.
+        expected: usize,
+        /// The number of joint matrices given.
+        actual: usize,
+    },
+
+    /// A vertex is influenced by a joint that has no joint matrix.
+    JointIndex {
+        /// The index of the vertex.
+        vertex: usize,
+        /// The index of the joint.
+        joint: usize,
+    },
+
+    /// The weights of a vertex are negative, not finite, or all zero.
+    Weights {
+        /// The index of the vertex.
+        vertex: usize,
+    },
+
+    /// A joint node has no world transform.
+    NodeTransform {
+        /// The index of the node.
+        node: usize,
+    },
+
+    /// The world transform of the node whose space joint matrices are
+    /// computed in cannot be inverted.
+    SingularTransform {
+        /// The index of the node.
+        node: usize,
+    },
+
+    /// The inverse bind matrices of the skin are unavailable.
+    InverseBindMatrices(Option<accessor::Error>),
+
+    /// The skin has fewer inverse bind matrices than joints.
+    InverseBindMatrixCount {
+        /// The number of joints of the skin.
+        expected: usize,
+        /// The number of inverse bind matrices.
+        actual: usize,
+    },
+}
+
+impl fmt::Display for SkinError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SkinError::MissingAttribute(semantic) => {
+                write!(f, "missing {} attribute", semantic.to_string())
+            }
+            SkinError::AttributeCount {
+                semantic,
+                count,
+                expected,
+            } => write!(
+                f,
+                "{} has {} elements but there are {} positions",
+                semantic.to_string(),
+                count,
+                expected
+            ),
+            SkinError::JointCount { expected, actual } => write!(
+                f,
+                "{} joint matrices given for a skin of {} joints",
+                actual, expected
+            ),
+            SkinError::JointIndex { vertex, joint } => {
+                write!(
+                    f,
+                    "vertex {} is influenced by missing joint {}",
+                    vertex, joint
+                )
+            }
+            SkinError::Weights { vertex } => write!(f, "vertex {} has invalid weights", vertex),
+            SkinError::NodeTransform { node } => {
+                write!(f, "joint node {} has no world transform", node)
+            }
+            SkinError::SingularTransform { node } => {
+                write!(f, "world transform of node {} is not invertible", node)
+            }
+            SkinError::InverseBindMatrices(Some(error)) => {
+                write!(f, "inverse bind matrices: {}", error)
+            }
+            SkinError::InverseBindMatrices(None) => {
+                write!(f, "inverse bind matrices are missing")
+            }
+            SkinError::InverseBindMatrixCount { expected, actual } => write!(
+                f,
+                "{} inverse bind matrices given for a skin of {} joints",
+                actual, expected
+            ),
+        }
+    }
+}
+
+impl core::error::Error for SkinError {}
+
+/// Options for [`apply_pose_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PoseOptions {
+    /// Also deforms the normals, with the inverse transpose of the blended
+    /// joint matrix.
+    pub normals: bool,
+}
+
+/// Vertex attributes deformed by [`apply_pose_with`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SkinnedVertices {
+    /// The deformed positions.
+    pub positions: Vec<[f32; 3]>,
+
+    /// The deformed unit normals, if [`PoseOptions::normals`] is set.
+    pub normals: Option<Vec<[f32; 3]>>,
+}
+
+/// Computes the skinned positions of a primitive at a pose.
+///
+/// See [`apply_pose_with`].
+pub fn apply_pose<'a, 'b, 's, 't, F, G>(
+    primitive: &mesh::Reader<'a, 's, F>,
+    skin: &Reader<'b, 't, G>,
+    joint_matrices: &[Matrix],
+) -> Result<Vec<[f32; 3]>, SkinError>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    G: Clone + Fn(Buffer<'b>) -> Option<&'t [u8]>,
+{
+    apply_pose_with(primitive, skin, joint_matrices, Default::default())
+        .map(|vertices| vertices.positions)
+}
+
+/// Deforms the vertices of a primitive at a pose with linear blend skinning.
+///
+/// `joint_matrices` holds one column-major matrix per joint of the skin, in
+/// the order of [`Skin::joints`], mapping the bind pose to the pose; see
+/// [`joint_matrices`]. Each vertex is transformed by the sum of the joint
+/// matrices of `JOINTS_0` weighted by `WEIGHTS_0`. The weights of each vertex
+/// are normalized; joints with zero weight are ignored.
+///
+/// The result is in the space of the joint matrices, which is world space for
+/// those computed by [`joint_matrices`]: the transform of the skinned node is
+/// not applied, as the specification requires.
+///
+/// [`Skin::joints`]: crate::Skin::joints
+pub fn apply_pose_with<'a, 'b, 's, 't, F, G>(
+    primitive: &mesh::Reader<'a, 's, F>,
+    skin: &Reader<'b, 't, G>,
+    joint_matrices: &[Matrix],
+    options: PoseOptions,
+) -> Result<SkinnedVertices, SkinError>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    G: Clone + Fn(Buffer<'b>) -> Option<&'t [u8]>,
+{
+    let joint_count = skin.skin.joints().len();
+    if joint_matrices.len() != joint_count {
+        return Err(SkinError::JointCount {
+            expected: joint_count,
+            actual: joint_matrices.len(),
+        });
+    }
+    let positions = primitive
+        .read_positions()
+        .ok_or(SkinError::MissingAttribute(Semantic::Positions))?;
+    let count = positions.len();
+    let check = |semantic: Semantic, len: usize| match len == count {
+        true => Ok(()),
+        false => Err(SkinError::AttributeCount {
+            semantic,
+            count: len,
+            expected: count,
+        }),
+    };
+    let joints = primitive
+        .read_joints(0)
+        .ok_or(SkinError::MissingAttribute(Semantic::Joints(0)))?
+        .into_u16();
+    check(Semantic::Joints(0), joints.len())?;
+    let weights = primitive
+        .read_weights(0)
+        .ok_or(SkinError::MissingAttribute(Semantic::Weights(0)))?
+        .into_f32();
+    check(Semantic::Weights(0), weights.len())?;
+    let mut normals = match options.normals {
+        true => {
+            let normals = primitive
+                .read_normals()
+                .ok_or(SkinError::MissingAttribute(Semantic::Normals))?;
+            check(Semantic::Normals, normals.len())?;
+            Some(normals)
+        }
+        false => None,
+    };
+
+    let mut output = SkinnedVertices {
+        positions: Vec::with_capacity(count),
+        normals: normals.as_ref().map(|_| Vec::with_capacity(count)),
+    };
+    for (vertex, ((position, joints), weights)) in positions.zip(joints).zip(weights).enumerate() {
+        let matrix = blend(vertex, joints, weights, joint_matrices)?;
+        output
+            .positions
+            .push(Matrix4::from_array(matrix).transform_point(position));
+        if let (Some(normals), Some(output)) = (normals.as_mut(), output.normals.as_mut()) {
+            let normal = normals.next().unwrap_or_default();
+            output.push(transform_normal(&matrix, normal));
+        }
+    }
+    Ok(output)
+}
+
+/// Computes the joint matrices of a skin from the world transforms of the
+/// nodes, for [`apply_pose`].
+///
+/// `world_transforms` holds the column-major world transform of each node of
+/// the document, indexed by node. The matrix of each joint is the world
+/// transform of its node multiplied by its inverse bind matrix, or the world
+/// transform alone if the skin has no inverse bind matrices, as the
+/// specification requires. The skin must have at least as many inverse bind
+/// matrices as joints.
+pub fn joint_matrices<'a, 's, F>(
+    skin: &Reader<'a, 's, F>,
+    world_transforms: &[Matrix],
+) -> Result<Vec<Matrix>, SkinError>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let joints = skin.skin.joints();
+    let mut inverse_bind_matrices = match skin.skin.inverse_bind_matrices() {
+        Some(accessor) => {
+            let iter = accessor::Iter::try_new(accessor, skin.get_buffer_data.clone())
+                .map_err(|error| SkinError::InverseBindMatrices(Some(error)))?
+                .ok_or(SkinError::InverseBindMatrices(None))?;
+            if iter.len() < joints.len() {
+                return Err(SkinError::InverseBindMatrixCount {
+                    expected: joints.len(),
+                    actual: iter.len(),
+                });
+            }
+            Some(iter)
+        }
+        None => None,
+    };
+    joints
+        .map(|joint| {
+            let world = world_transforms
+                .get(joint.index())
+                .ok_or(SkinError::NodeTransform {
+                    node: joint.index(),
+                })?;
+            let inverse_bind = inverse_bind_matrices
+                .as_mut()
+                .and_then(|iter| iter.next())
+                .unwrap_or(IDENTITY);
+            Ok((Matrix4::from_array(*world) * Matrix4::from_array(inverse_bind)).as_array())
+        })
+        .collect()
+}
+
+/// Computes the joint matrices of a skin in the space of a node, usually the
+/// node that instantiates the skinned mesh.
+///
+/// Each matrix of [`joint_matrices`] is multiplied by the inverse of the
+/// world transform of `node`, so that drawing the skinned mesh with the
+/// transform of its node, as renderers do for other meshes, places it as the
+/// specification requires.
+pub fn joint_matrices_in_node_space<'a, 's, F>(
+    skin: &Reader<'a, 's, F>,
+    world_transforms: &[Matrix],
+    node: usize,
+) -> Result<Vec<Matrix>, SkinError>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let world = world_transforms
+        .get(node)
+        .ok_or(SkinError::NodeTransform { node })?;
+    let inverse = Matrix4::from_array(*world)
+        .invert()
+        .ok_or(SkinError::SingularTransform { node })?;
+    let mut matrices = joint_matrices(skin, world_transforms)?;
+    for matrix in &mut matrices {
+        *matrix = (inverse * Matrix4::from_array(*matrix)).as_array();
+    }
+    Ok(matrices)
+}
+
+/// Returns the weighted sum of the joint matrices that influence a vertex.
+fn blend(
+    vertex: usize,
+    joints: [u16; 4],
+    weights: [f32; 4],
+    joint_matrices: &[Matrix],
+) -> Result<Matrix, SkinError> {
+    let sane = weights.iter().all(|&w| w.is_finite() && w >= 0.0);
+    let total: f32 = weights.iter().sum();
+    if !sane || total <= 0.0 || !total.is_finite() {
+        return Err(SkinError::Weights { vertex });
+    }
+    let mut matrix = [[0.0; 4]; 4];
+    for (&joint, &weight) in joints.iter().zip(&weights) {
+        if weight == 0.0 {
+            continue;
+        }
+        let joint = joint as usize;
+        let joint_matrix = joint_matrices
+            .get(joint)
+            .ok_or(SkinError::JointIndex { vertex, joint })?;
+        let weight = weight / total;
+        for (column, joint_column) in matrix.iter_mut().zip(joint_matrix) {
+            for (x, y) in column.iter_mut().zip(joint_column) {
+                *x += weight * y;
+            }
+        }
+    }
+    Ok(matrix)
+}
+
+/// Transforms a normal by the inverse transpose of the upper 3x3 of `m`,
+/// computed as its cofactor matrix, and normalizes the result.
+fn transform_normal(m: &Matrix, [x, y, z]: [f32; 3]) -> [f32; 3] {
+    let a = |r: usize, c: usize| m[c % 3][r % 3];
+    // The cofactor of element (r, c) of a 3x3 matrix.
+    let cofactor =
+        |r: usize, c: usize| a(r + 1, c + 1) * a(r + 2, c + 2) - a(r + 1, c + 2) * a(r + 2, c + 1);
+    let determinant =
+        a(0, 0) * cofactor(0, 0) + a(0, 1) * cofactor(0, 1) + a(0, 2) * cofactor(0, 2);
+    let sign = if determinant < 0.0 { -1.0 } else { 1.0 };
+    let row = |r: usize| sign * (cofactor(r, 0) * x + cofactor(r, 1) * y + cofactor(r, 2) * z);
+    let n = [row(0), row(1), row(2)];
+    #[allow(unused_unsafe)]
+    let length = unsafe { sqrtf32(n[0] * n[0] + n[1] * n[1] + n[2] * n[2]) };
+    match length > 0.0 {
+        true => [n[0] / length, n[1] / length, n[2] / length],
+        false => n,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normal_matrix() {
+        // Scaling x by 2 halves the x component of normals before they are
+        // normalized.
+        let mut m = IDENTITY;
+        m[0][0] = 2.0;
+        m[3] = [5.0, 6.0, 7.0, 1.0];
+        let n = transform_normal(&m, [1.0, 1.0, 0.0]);
+        assert_relative_eq!(n[0], 0.447_213_6);
+        assert_relative_eq!(n[1], 0.894_427_2);
+
+        // A mirror keeps normals pointing out of the surface.
+        let mut m = IDENTITY;
+        m[2][2] = -1.0;
+        assert_eq!(transform_normal(&m, [0.0, 0.0, 1.0]), [0.0, 0.0, -1.0]);
+    }
+
+    #[test]
+    fn blend_weights() {
+        let mut moved = IDENTITY;
+        moved[3] = [4.0, 0.0, 0.0, 1.0];
+        let matrices = [IDENTITY, moved];
+        // Weights are normalized, and joints without weight are not looked up.
+        let m = blend(0, [0, 1, 7, 0], [1.0, 3.0, 0.0, 0.0], &matrices).unwrap();
+        assert_eq!(m[3], [3.0, 0.0, 0.0, 1.0]);
+        assert_eq!(
+            blend(5, [0, 7, 0, 0], [0.5, 0.5, 0.0, 0.0], &matrices),
+            Err(SkinError::JointIndex {
+                vertex: 5,
+                joint: 7
+            })
+        );
+        for weights in [[0.0; 4], [1.0, -0.5, 0.0, 0.0], [f32::NAN, 0.0, 0.0, 0.0]] {
+            assert_eq!(
+                blend(2, [0; 4], weights, &matrices),
+                Err(SkinError::Weights { vertex: 2 })
+            );
+        }
+    }
+}
@@ -0,0 +1,534 @@
+//! [`Document::snapshot`] copies what is needed to render and animate an asset
+//! into plain owned structs that hold indices instead of references, so that
+//! they may be stored as `'static` resources after the document is dropped.
+//! With the `serde` feature, a snapshot may be serialized as a baked runtime
+//! format.
+//!
+//! Entities keep their document indices: `nodes[i]` is node `i`, and so on.
+
+#[cfg(feature = "names")]
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::accessor;
+#[cfg(feature = "animation")]
+use crate::animation::{read_floats, BindingTable, Interpolation};
+use crate::material::AlphaMode;
+use crate::mesh::{BoundingBox, Bounds, Mode, Semantic};
+use crate::texture::{self, MagFilter, MinFilter, WrappingMode};
+use crate::{buffer, Document, Primitive};
+
+/// Options for [`Document::snapshot_with`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SnapshotOptions {
+    /// Whether to copy the keyframe times and values of animation samplers.
+    ///
+    /// Without keyframes, a snapshot can be taken without buffer data. Has no
+    /// effect unless the `animation` feature is enabled. The default is
+    /// `true`.
+    pub keyframes: bool,
+}
+
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        Self { keyframes: true }
+    }
+}
+
+/// An owned copy of the renderable and animatable parts of a document.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct OwnedScene {
+    /// The root nodes of the default scene, or of the first scene if there
+    /// is no default.
+    pub roots: Vec<usize>,
+
+    /// Every node of the document.
+    pub nodes: Vec<OwnedNode>,
+
+    /// Every mesh of the document.
+    pub meshes: Vec<OwnedMesh>,
+
+    /// Every material of the document.
+    pub materials: Vec<OwnedMaterial>,
+
+    /// Every animation of the document.
+    #[cfg(feature = "animation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
+    pub animations: Vec<OwnedAnimation>,
+}
+
+/// An owned copy of a node.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct OwnedNode {
+    /// The name of the node.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub name: Option<String>,
+
+    /// The index of the parent node, if any.
+    pub parent: Option<usize>,
+
+    /// The indices of the child nodes.
+    pub children: Vec<usize>,
+
+    /// The local translation.
+    pub translation: [f32; 3],
+
+    /// The local rotation as an `[x, y, z, w]` quaternion.
+    pub rotation: [f32; 4],
+
+    /// The local scale.
+    pub scale: [f32; 3],
+
+    /// The world transform in column-major order, composed from the root of
+    /// the node's hierarchy.
+    pub world: [[f32; 4]; 4],
+
+    /// The index of the mesh, if any.
+    pub mesh: Option<usize>,
+
+    /// The index of the skin, if any.
+    ///
+    /// Always `None` unless the `skinning` feature is enabled.
+    pub skin: Option<usize>,
+
+    /// The index of the camera, if any.
+    ///
+    /// Always `None` unless the `cameras` feature is enabled.
+    pub camera: Option<usize>,
+
+    /// The morph target weights overriding those of the mesh, if any.
+    pub weights: Option<Vec<f32>>,
+}
+
+/// An owned copy of a mesh.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct OwnedMesh {
+    /// The name of the mesh.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub name: Option<String>,
+
+    /// The primitives of the mesh.
+    pub primitives: Vec<OwnedPrimitive>,
+
+    /// The default morph target weights, if any.
+    pub weights: Option<Vec<f32>>,
+}
+
+/// A vertex attribute of an [`OwnedPrimitive`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct OwnedAttribute {
+    /// The semantic of the attribute.
+    #[cfg_attr(feature = "serde", serde(with = "checked"))]
+    pub semantic: Semantic,
+
+    /// The index of the accessor holding the attribute data.
+    pub accessor: usize,
+}
+
+/// An owned description of how to draw a primitive.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct OwnedPrimitive {
+    /// The topology of the primitive.
+    #[cfg_attr(feature = "serde", serde(with = "checked"))]
+    pub mode: Mode,
+
+    /// The vertex attributes, in document order.
+    pub attributes: Vec<OwnedAttribute>,
+
+    /// The index of the accessor holding the vertex indices, if any.
+    pub indices: Option<usize>,
+
+    /// The index of the material, or `None` for the default material.
+    pub material: Option<usize>,
+
+    /// The bounds of the `POSITION` attribute, if it declares them.
+    pub bounding_box: Option<BoundingBox>,
+
+    /// The number of morph targets.
+    pub morph_targets: usize,
+}
+
+/// An owned copy of the sampler of a texture.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct SamplerDescriptor {
+    /// The magnification filter, or `None` to let the renderer decide.
+    #[cfg_attr(feature = "serde", serde(with = "checked::option"))]
+    pub mag_filter: Option<MagFilter>,
+
+    /// The minification filter, or `None` to let the renderer decide.
+    #[cfg_attr(feature = "serde", serde(with = "checked::option"))]
+    pub min_filter: Option<MinFilter>,
+
+    /// The wrapping mode of the `s` co-ordinate.
+    #[cfg_attr(feature = "serde", serde(with = "checked"))]
+    pub wrap_s: WrappingMode,
+
+    /// The wrapping mode of the `t` co-ordinate.
+    #[cfg_attr(feature = "serde", serde(with = "checked"))]
+    pub wrap_t: WrappingMode,
+}
+
+/// A texture reference of an [`OwnedMaterial`], resolved to its image and
+/// sampler.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct OwnedTextureRef {
+    /// The index of the texture.
+    pub texture: usize,
+
+    /// The index of the image, or `None` if the texture has no source that
+    /// this crate understands.
+    pub image: Option<usize>,
+
+    /// The texture co-ordinate set.
+    pub tex_coord: u32,
+
+    /// The sampler of the texture, which is the default sampler if the
+    /// texture has none.
+    pub sampler: SamplerDescriptor,
+}
+
+/// An owned copy of a material, with defaults filled in.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct OwnedMaterial {
+    /// The name of the material.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub name: Option<String>,
+
+    /// The alpha rendering mode.
+    #[cfg_attr(feature = "serde", serde(with = "checked"))]
+    pub alpha_mode: AlphaMode,
+
+    /// The alpha cutoff of the `MASK` alpha mode, which defaults to `0.5`.
+    pub alpha_cutoff: f32,
+
+    /// Whether back-face culling must be disabled.
+    pub double_sided: bool,
+
+    /// The base color factor.
+    pub base_color_factor: [f32; 4],
+
+    /// The base color texture.
+    pub base_color_texture: Option<OwnedTextureRef>,
+
+    /// The metalness factor.
+    pub metallic_factor: f32,
+
+    /// The roughness factor.
+    pub roughness_factor: f32,
+
+    /// The metallic-roughness texture.
+    pub metallic_roughness_texture: Option<OwnedTextureRef>,
+
+    /// The normal texture.
+    pub normal_texture: Option<OwnedTextureRef>,
+
+    /// The scale of the normal texture.
+    pub normal_scale: f32,
+
+    /// The occlusion texture.
+    pub occlusion_texture: Option<OwnedTextureRef>,
+
+    /// The strength of the occlusion texture.
+    pub occlusion_strength: f32,
+
+    /// The emissive factor.
+    pub emissive_factor: [f32; 3],
+
+    /// The emissive texture.
+    pub emissive_texture: Option<OwnedTextureRef>,
+}
+
+/// An owned copy of an animation sampler.
+#[cfg(feature = "animation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct OwnedAnimationSampler {
+    /// The interpolation algorithm.
+    #[cfg_attr(feature = "serde", serde(with = "checked"))]
+    pub interpolation: Interpolation,
+
+    /// The index of the accessor holding the keyframe times.
+    pub input: usize,
+
+    /// The index of the accessor holding the keyframe values.
+    pub output: usize,
+
+    /// The keyframe times, or empty if keyframes were not copied.
+    pub times: Vec<f32>,
+
+    /// The keyframe values as consecutive components, with normalized
+    /// integers mapped to floats, or empty if keyframes were not copied.
+    pub values: Vec<f32>,
+
+    /// The number of components of each output element.
+    pub components: usize,
+}
+
+/// An owned copy of an animation.
+#[cfg(feature = "animation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct OwnedAnimation {
+    /// The name of the animation.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub name: Option<String>,
+
+    /// The nodes targeted by the animation and the channels targeting them.
+    pub bindings: BindingTable,
+
+    /// The sampler of each channel, in channel order.
+    pub channel_samplers: Vec<usize>,
+
+    /// The samplers of the animation.
+    pub samplers: Vec<OwnedAnimationSampler>,
+
+    /// The latest keyframe time of any sampler, in seconds.
+    pub duration: f32,
+}
+
+/// Serializes types that this crate deserializes through
+/// [`Checked`](json::validation::Checked).
+#[cfg(feature = "serde")]
+mod checked {
+    use json::validation::Checked;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T: Serialize, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        Checked<T>: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        match Checked::<T>::deserialize(deserializer)? {
+            Checked::Valid(value) => Ok(value),
+            Checked::Invalid => Err(de::Error::custom("invalid enumeration value")),
+        }
+    }
+
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<T: Serialize, S: Serializer>(
+            value: &Option<T>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            value.serialize(serializer)
+        }
+
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+        where
+            Checked<T>: Deserialize<'de>,
+            D: Deserializer<'de>,
+        {
+            match Option::<Checked<T>>::deserialize(deserializer)? {
+                Some(Checked::Valid(value)) => Ok(Some(value)),
+                Some(Checked::Invalid) => Err(de::Error::custom("invalid enumeration value")),
+                None => Ok(None),
+            }
+        }
+    }
+}
+
+/// Returns the declared bounds of the `POSITION` attribute of a primitive.
+fn bounding_box(primitive: &Primitive) -> Option<BoundingBox> {
+    let accessor = primitive.get(&Semantic::Positions)?;
+    let min = crate::accessor::bound_f32(accessor.min_f64())?;
+    let max = crate::accessor::bound_f32(accessor.max_f64())?;
+    Some(Bounds { min, max })
+}
+
+fn texture_ref(texture: texture::Texture, tex_coord: u32) -> OwnedTextureRef {
+    let sampler = texture.sampler();
+    OwnedTextureRef {
+        texture: texture.index(),
+        image: texture.source().map(|image| image.index()),
+        tex_coord,
+        sampler: SamplerDescriptor {
+            mag_filter: sampler.mag_filter(),
+            min_filter: sampler.min_filter(),
+            wrap_s: sampler.wrap_s(),
+            wrap_t: sampler.wrap_t(),
+        },
+    }
+}
+
+fn material(material: crate::Material) -> OwnedMaterial {
+    let pbr = material.pbr_metallic_roughness();
+    let info = |info: texture::Info| texture_ref(info.texture(), info.tex_coord());
+    let normal = material.normal_texture();
+    let occlusion = material.occlusion_texture();
+    OwnedMaterial {
+        #[cfg(feature = "names")]
+        name: material.name().map(String::from),
+        alpha_mode: material.alpha_mode(),
+        alpha_cutoff: material.alpha_cutoff().unwrap_or(0.5),
+        double_sided: material.double_sided(),
+        base_color_factor: pbr.base_color_factor(),
+        base_color_texture: pbr.base_color_texture().map(info),
+        metallic_factor: pbr.metallic_factor(),
+        roughness_factor: pbr.roughness_factor(),
+        metallic_roughness_texture: pbr.metallic_roughness_texture().map(info),
+        normal_texture: normal
+            .as_ref()
+            .map(|x| texture_ref(x.texture(), x.tex_coord())),
+        normal_scale: normal.as_ref().map_or(1.0, |x| x.scale()),
+        occlusion_texture: occlusion
+            .as_ref()
+            .map(|x| texture_ref(x.texture(), x.tex_coord())),
+        occlusion_strength: occlusion.as_ref().map_or(1.0, |x| x.strength()),
+        emissive_factor: material.emissive_factor(),
+        emissive_texture: material.emissive_texture().map(info),
+    }
+}
+
+#[cfg(feature = "animation")]
+fn animation(
+    root: &json::Root,
+    animation: crate::Animation,
+    buffers: &[buffer::Data],
+    options: SnapshotOptions,
+) -> Result<OwnedAnimation, accessor::Error> {
+    let mut samplers = Vec::new();
+    let mut duration = 0.0f32;
+    for sampler in animation.samplers() {
+        let (input, output) = (sampler.input(), sampler.output());
+        let (times, values) = if options.keyframes {
+            (
+                read_floats(&input, buffers)?,
+                read_floats(&output, buffers)?,
+            )
+        } else {
+            (Vec::new(), Vec::new())
+        };
+        let end = accessor::bound_f32(input.max_f64())
+            .map(|[max]| max)
+            .or_else(|| times.last().copied())
+            .unwrap_or(0.0);
+        duration = duration.max(end);
+        samplers.push(OwnedAnimationSampler {
+            interpolation: sampler.interpolation(),
+            input: input.index(),
+            output: output.index(),
+            times,
+            values,
+            components: output.dimensions().multiplicity(),
+        });
+    }
+    Ok(OwnedAnimation {
+        #[cfg(feature = "names")]
+        name: animation.name().map(String::from),
+        bindings: animation.binding_table(),
+        channel_samplers: root.animations[animation.index()]
+            .channels
+            .iter()
+            .map(|channel| channel.sampler.value())
+            .collect(),
+        samplers,
+        duration,
+    })
+}
+
+#[cfg_attr(not(feature = "animation"), allow(unused_variables))]
+pub(crate) fn snapshot(
+    document: &Document,
+    buffers: &[buffer::Data],
+    options: SnapshotOptions,
+) -> Result<OwnedScene, accessor::Error> {
+    let root = document.as_json();
+    let parents = document.node_parents();
+    let worlds = crate::scene::world_transforms(document);
+    let nodes: Vec<OwnedNode> = document
+        .nodes()
+        .map(|node| {
+            let (translation, rotation, scale) = node.transform().decomposed();
+            OwnedNode {
+                #[cfg(feature = "names")]
+                name: node.name().map(String::from),
+                parent: parents.parent_of(node.index()),
+                children: node.children().map(|child| child.index()).collect(),
+                translation,
+                rotation,
+                scale,
+                world: worlds[node.index()].as_array(),
+                mesh: node.mesh().map(|mesh| mesh.index()),
+                #[cfg(feature = "skinning")]
+                skin: node.skin().map(|skin| skin.index()),
+                #[cfg(not(feature = "skinning"))]
+                skin: None,
+                #[cfg(feature = "cameras")]
+                camera: node.camera().map(|camera| camera.index()),
+                #[cfg(not(feature = "cameras"))]
+                camera: None,
+                weights: node.weights().map(<[f32]>::to_vec),
+            }
+        })
+        .collect();
+
+    let meshes = document
+        .meshes()
+        .map(|mesh| OwnedMesh {
+            #[cfg(feature = "names")]
+            name: mesh.name().map(String::from),
+            primitives: mesh
+                .primitives()
+                .map(|primitive| OwnedPrimitive {
+                    mode: primitive.mode(),
+                    attributes: primitive
+                        .attributes()
+                        .map(|(semantic, accessor)| OwnedAttribute {
+                            semantic,
+                            accessor: accessor.index(),
+                        })
+                        .collect(),
+                    indices: primitive.indices().map(|accessor| accessor.index()),
+                    material: primitive.material().index(),
+                    bounding_box: bounding_box(&primitive),
+                    morph_targets: primitive.morph_targets().len(),
+                })
+                .collect(),
+            weights: mesh.weights().map(<[f32]>::to_vec),
+        })
+        .collect();
+
+    let roots = root
+        .scene
+        .or_else(|| (!root.scenes.is_empty()).then(|| json::Index::new(0)))
+        .and_then(|scene| root.scenes.get(scene.value()))
+        .map(|scene| scene.nodes.iter().map(|node| node.value()).collect())
+        .unwrap_or_default();
+
+    Ok(OwnedScene {
+        roots,
+        nodes,
+        meshes,
+        materials: document.materials().map(material).collect(),
+        #[cfg(feature = "animation")]
+        animations: document
+            .animations()
+            .map(|x| animation(root, x, buffers, options))
+            .collect::<Result<_, _>>()?,
+    })
+}
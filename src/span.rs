@@ -0,0 +1,125 @@
+use alloc::collections::BTreeMap;
+use core::ops::Range;
+
+use crate::check::{CheckLimits, Scanner};
+use crate::fault::{self, EntityKind};
+
+/// The byte spans of the entities of a document within its JSON text.
+///
+/// Spans are recorded by [`Gltf::from_slice_with_spans`] and by
+/// [`import_slice_resilient`] for every element of the top-level arrays, such
+/// as `accessors` and `nodes`, and for every primitive of a mesh. They are
+/// offsets into the JSON chunk for binary glTF.
+///
+/// [`Gltf::from_slice_with_spans`]: crate::Gltf::from_slice_with_spans
+/// [`import_slice_resilient`]: crate::import_slice_resilient
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SourceSpans {
+    spans: BTreeMap<(EntityKind, usize), Range<usize>>,
+}
+
+impl SourceSpans {
+    /// Records the spans of the entities in JSON text.
+    ///
+    /// The text is scanned once, before and independently of
+    /// deserialization. Scanning stops at the first syntax error, keeping
+    /// the spans found up to there.
+    pub fn scan(json: &[u8]) -> Self {
+        let scanner = Scanner::new(json, CheckLimits::default().max_depth);
+        let mut spans = BTreeMap::new();
+        let start = scanner.skip_ws(0);
+        let _ = scanner.object(start, 0, |scanner, key, pos| {
+            let entity_kind = match kind(key) {
+                Some(entity_kind) => entity_kind,
+                None => return scanner.skip(pos, 1),
+            };
+            scanner.array(pos, 1, |scanner, pos, index| {
+                let end = match entity_kind {
+                    EntityKind::Mesh => scanner.object(pos, 2, |scanner, key, pos| match key {
+                        "primitives" => scanner.array(pos, 3, |scanner, pos, primitive| {
+                            let end = scanner.skip(pos, 4)?;
+                            let entity_kind = EntityKind::Primitive { mesh: index };
+                            spans.insert((entity_kind, primitive), pos..end);
+                            Ok(end)
+                        }),
+                        _ => scanner.skip(pos, 3),
+                    })?,
+                    _ => scanner.skip(pos, 2)?,
+                };
+                spans.insert((entity_kind, index), pos..end);
+                Ok(end)
+            })
+        });
+        Self { spans }
+    }
+
+    /// Returns the span of an entity, with the index of a primitive within
+    /// its mesh.
+    pub fn get(&self, entity_kind: EntityKind, index: usize) -> Option<Range<usize>> {
+        self.spans.get(&(entity_kind, index)).cloned()
+    }
+
+    /// Returns the span of the entity that a JSON path points into, such as
+    /// the path of a validation error.
+    pub fn get_path(&self, path: &json::Path) -> Option<Range<usize>> {
+        let (entity_kind, index) = fault::entity(path.as_str());
+        self.get(entity_kind, index)
+    }
+
+    /// Returns `true` if no spans were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+}
+
+/// Returns the kind of the entities of a top-level array.
+fn kind(name: &str) -> Option<EntityKind> {
+    use EntityKind::*;
+    Some(match name {
+        "accessors" => Accessor,
+        "animations" => Animation,
+        "buffers" => Buffer,
+        "bufferViews" => View,
+        "cameras" => Camera,
+        "images" => Image,
+        "materials" => Material,
+        "meshes" => Mesh,
+        "nodes" => Node,
+        "samplers" => Sampler,
+        "scenes" => Scene,
+        "skins" => Skin,
+        "textures" => Texture,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entity_spans() {
+        let json = br#"{"asset": {"version": "2.0"},
+            "nodes": [{"name": "a"}, {"children": [0]}],
+            "meshes": [{"primitives": [{"attributes": {}}, {"attributes": {"POSITION": 0}}]}],
+            "extras": {"nodes": [{}]}}"#;
+        let spans = SourceSpans::scan(json);
+        let text = |range: Option<Range<usize>>| range.map(|range| &json[range]);
+        assert_eq!(
+            text(spans.get(EntityKind::Node, 0)),
+            Some(&br#"{"name": "a"}"#[..])
+        );
+        assert_eq!(
+            text(spans.get(EntityKind::Node, 1)),
+            Some(&br#"{"children": [0]}"#[..])
+        );
+        assert_eq!(spans.get(EntityKind::Node, 2), None);
+        assert_eq!(
+            text(spans.get(EntityKind::Primitive { mesh: 0 }, 1)),
+            Some(&br#"{"attributes": {"POSITION": 0}}"#[..])
+        );
+        let path = json::Path::new().field("nodes").index(1).field("children");
+        assert_eq!(spans.get_path(&path), spans.get(EntityKind::Node, 1));
+        assert_eq!(spans.get_path(&json::Path::new().field("asset")), None);
+    }
+}
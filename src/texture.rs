@@ -1,5 +1,6 @@
 use alloc::string::String;
-use crate::Document;
+use core::fmt;
+use crate::{Document, Image};
 
 pub use json::texture::{MagFilter, MinFilter, WrappingMode};
 #[cfg(feature = "extensions")]
@@ -9,6 +10,47 @@ lazy_static! {
     static ref DEFAULT_SAMPLER: json::texture::Sampler = Default::default();
 }
 
+/// Errors that may occur when resolving the references of a texture.
+///
+/// These can only occur if the document was loaded without validation.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Error {
+    /// The texture refers to a sampler that does not exist.
+    SamplerOutOfRange {
+        /// The index of the texture.
+        texture: usize,
+        /// The index of the missing sampler.
+        sampler: usize,
+    },
+
+    /// The texture refers to an image that does not exist.
+    SourceOutOfRange {
+        /// The index of the texture.
+        texture: usize,
+        /// The index of the missing image.
+        source: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::SamplerOutOfRange { texture, sampler } => write!(
+                f,
+                "texture {} refers to sampler {}, which does not exist",
+                texture, sampler
+            ),
+            Error::SourceOutOfRange { texture, source } => write!(
+                f,
+                "texture {} refers to image {}, which does not exist",
+                texture, source
+            ),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
 /// A reference to a `Texture`.
 #[derive(Clone, Debug)]
 pub struct Info<'a> {
@@ -89,7 +131,7 @@ impl<'a> Sampler<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
     }
 
     /// `s` wrapping mode.
@@ -146,37 +188,106 @@ impl<'a> Texture<'a> {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     pub fn name(&self) -> Option<&str> {
-        self.json.name.as_deref()
+        crate::non_blank(self.json.name.as_deref())
     }
 
     /// Returns the sampler used by this texture.
+    ///
+    /// If the texture does not specify a sampler, a default sampler is
+    /// returned, which repeats on both axes and leaves the filters to the
+    /// implementation. Its [`Sampler::index`] is `None`. The default sampler is
+    /// also returned if the sampler does not exist; see [`Self::try_sampler`].
     pub fn sampler(&self) -> Sampler<'a> {
-        self.json
-            .sampler
-            .as_ref()
-            .map(|index| self.document.samplers().nth(index.value()).unwrap())
-            .unwrap_or_else(|| Sampler::default(self.document))
-    }
-
-    // /// Returns the image used by this texture.
-    // #[cfg(feature = "allow_empty_texture")]
-    // pub fn source(&self) -> Option<image::Image<'a>> {
-    //     let index = self.json.source.value();
-    //     if index == u32::MAX as usize {
-    //         None
-    //     } else {
-    //         Some(self.document.images().nth(index).unwrap())
-    //     }
-    // }
-
-    // /// Returns the image used by this texture.
-    // #[cfg(not(feature = "allow_empty_texture"))]
-    // pub fn source(&self) -> image::Image<'a> {
-    //     self.document
-    //         .images()
-    //         .nth(self.json.source.value())
-    //         .unwrap()
-    // }
+        self.try_sampler()
+            .unwrap_or_else(|_| Sampler::default(self.document))
+    }
+
+    /// Returns the sampler used by this texture, or an error if it does not
+    /// exist.
+    ///
+    /// See [`Self::sampler`] for textures that do not specify a sampler.
+    pub fn try_sampler(&self) -> Result<Sampler<'a>, Error> {
+        let index = match self.json.sampler {
+            Some(index) => index.value(),
+            None => return Ok(Sampler::default(self.document)),
+        };
+        match self.document.as_json().samplers.get(index) {
+            Some(json) => Ok(Sampler::new(self.document, index, json)),
+            None => Err(Error::SamplerOutOfRange {
+                texture: self.index,
+                sampler: index,
+            }),
+        }
+    }
+
+    /// Returns the image used by this texture.
+    ///
+    /// This is `None` if the texture has no core source, like a
+    /// `KHR_texture_basisu` texture without a fallback image, which is only
+    /// valid with the `allow_empty_texture` feature. It is also `None` if the
+    /// image does not exist; see [`Self::try_source`].
+    pub fn source(&self) -> Option<Image<'a>> {
+        self.try_source().ok().flatten()
+    }
+
+    /// Returns the image used by this texture, or an error if it does not
+    /// exist.
+    pub fn try_source(&self) -> Result<Option<Image<'a>>, Error> {
+        let index = self.json.source.value();
+        if index == u32::MAX as usize {
+            return Ok(None);
+        }
+        match self.document.as_json().images.get(index) {
+            Some(json) => Ok(Some(Image::new(self.document, index, json))),
+            None => Err(Error::SourceOutOfRange {
+                texture: self.index,
+                source: index,
+            }),
+        }
+    }
+
+    /// Returns the KTX2 image of the `KHR_texture_basisu` extension of this
+    /// texture, if any.
+    ///
+    /// This is also `None` if the image does not exist.
+    #[cfg(feature = "KHR_texture_basisu")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_basisu")))]
+    pub fn basisu_source(&self) -> Option<Image<'a>> {
+        let extension = self.json.extensions.as_ref()?.texture_basisu.as_ref()?;
+        let index = extension.source.value();
+        let json = self.document.as_json().images.get(index)?;
+        Some(Image::new(self.document, index, json))
+    }
+
+    /// Returns the WebP image of the `EXT_texture_webp` extension of this
+    /// texture, if any.
+    ///
+    /// This is also `None` if the image does not exist.
+    #[cfg(feature = "EXT_texture_webp")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_texture_webp")))]
+    pub fn webp_source(&self) -> Option<Image<'a>> {
+        let extension = self.json.extensions.as_ref()?.texture_webp.as_ref()?;
+        let index = extension.source.value();
+        let json = self.document.as_json().images.get(index)?;
+        Some(Image::new(self.document, index, json))
+    }
+
+    /// Returns the image used by this texture, preferring that of the
+    /// `KHR_texture_basisu` extension, then that of the `EXT_texture_webp`
+    /// extension, over the core source.
+    ///
+    /// Without either feature, this is [`Self::source`].
+    pub fn source_any(&self) -> Option<Image<'a>> {
+        #[cfg(feature = "KHR_texture_basisu")]
+        if let Some(image) = self.basisu_source() {
+            return Some(image);
+        }
+        #[cfg(feature = "EXT_texture_webp")]
+        if let Some(image) = self.webp_source() {
+            return Some(image);
+        }
+        self.source()
+    }
 
     /// Returns extension data unknown to this crate version.
     #[cfg(feature = "extensions")]
@@ -0,0 +1,391 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::intrinsics::{cosf32, sinf32};
+
+use json::extensions::texture::{
+    TextureTransform, TextureTransformOffset, TextureTransformRotation, TextureTransformScale,
+};
+use json::texture::WrappingMode;
+use json::validation::Checked;
+
+use crate::buffer;
+
+/// Relative tolerance for deciding that a scale is uniform or that a rotation
+/// is a multiple of a quarter turn.
+const EPSILON: f32 = 1e-5;
+
+/// The region of the atlas that replaces a texture.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AtlasPlacement {
+    /// The index of the replaced texture.
+    pub texture: usize,
+
+    /// The position of the region's origin in the atlas, in UV units.
+    pub offset: [f32; 2],
+
+    /// The size of the region in UV units.
+    pub scale: [f32; 2],
+}
+
+/// A packed atlas image and where each texture was placed in it.
+///
+/// Packing and encoding the pixels is left to the caller.
+#[derive(Clone, Debug)]
+pub struct AtlasPlan {
+    /// The encoded atlas image.
+    pub data: Vec<u8>,
+
+    /// The MIME type of the encoded image, e.g. `"image/png"`.
+    pub mime_type: String,
+
+    /// The sampler of the atlas texture, or `None` for the default sampler.
+    pub sampler: Option<usize>,
+
+    /// The placement of each texture in the atlas.
+    pub placements: Vec<AtlasPlacement>,
+}
+
+/// A condition found by [`atlas_textures`] that may make the atlased
+/// materials look different.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AtlasWarning {
+    /// A placed texture samples with `REPEAT` or `MIRRORED_REPEAT` wrapping,
+    /// which an atlas region cannot reproduce. The texture is atlased anyway.
+    Wrapping {
+        /// The index of the texture before atlasing.
+        texture: usize,
+    },
+
+    /// Composing the placement with the existing transform of a reference
+    /// would shear the texture, which `KHR_texture_transform` cannot express.
+    /// The reference is left unchanged.
+    Shear {
+        /// The index of the material.
+        material: usize,
+
+        /// The index of the texture before atlasing.
+        texture: usize,
+    },
+}
+
+/// The outcome of [`atlas_textures`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AtlasReport {
+    /// The index of the atlas texture, or `None` if no reference was
+    /// rewritten and the atlas was not added.
+    pub texture: Option<usize>,
+
+    /// The number of material texture references that were rewritten.
+    pub references: usize,
+
+    /// The conditions found while atlasing.
+    pub warnings: Vec<AtlasWarning>,
+}
+
+/// Composes the placement of a texture in the atlas with the existing transform
+/// of a reference to it.
+///
+/// A transform maps texture coordinates `uv` to `offset + R(rotation) *
+/// S(scale) * uv`, and the placement maps them on to `offset + S(scale) * uv`
+/// in the atlas. The composition keeps the rotation, scaling and offsetting by
+/// the placement, and is only expressible if the placement scale is uniform
+/// or the rotation is a multiple of a quarter turn, in which case the
+/// placement scale swaps its axes. Returns `None` otherwise.
+fn compose(
+    transform: Option<&TextureTransform>,
+    placement: &AtlasPlacement,
+) -> Option<TextureTransform> {
+    let mut composed = transform.cloned().unwrap_or_default();
+    let [ox, oy] = composed.offset.0;
+    let [sx, sy] = composed.scale.0;
+    let [px, py] = placement.scale;
+    let rotation = composed.rotation.0;
+    #[allow(unused_unsafe)]
+    let (sin, cos) = unsafe { (sinf32(rotation), cosf32(rotation)) };
+
+    let uniform = (px - py).abs() <= EPSILON * px.abs().max(py.abs());
+    composed.scale = if uniform || sin.abs() <= EPSILON {
+        TextureTransformScale([px * sx, py * sy])
+    } else if cos.abs() <= EPSILON {
+        TextureTransformScale([py * sx, px * sy])
+    } else {
+        return None;
+    };
+    composed.offset =
+        TextureTransformOffset([px * ox + placement.offset[0], py * oy + placement.offset[1]]);
+    composed.rotation = TextureTransformRotation(rotation);
+    Some(composed)
+}
+
+/// The `extensions` of a material texture reference.
+trait ReferenceExtensions {
+    fn transform(&self) -> Option<&TextureTransform>;
+
+    /// Returns the transform, creating the extensions object if needed.
+    fn transform_mut(&mut self) -> &mut Option<TextureTransform>;
+}
+
+impl ReferenceExtensions for Option<json::extensions::texture::Info> {
+    fn transform(&self) -> Option<&TextureTransform> {
+        self.as_ref()?.texture_transform.as_ref()
+    }
+
+    fn transform_mut(&mut self) -> &mut Option<TextureTransform> {
+        &mut self.get_or_insert_with(Default::default).texture_transform
+    }
+}
+
+impl ReferenceExtensions for Option<json::extensions::material::NormalTexture> {
+    fn transform(&self) -> Option<&TextureTransform> {
+        self.as_ref()?.texture_transform.as_ref()
+    }
+
+    fn transform_mut(&mut self) -> &mut Option<TextureTransform> {
+        &mut self.get_or_insert_with(Default::default).texture_transform
+    }
+}
+
+impl ReferenceExtensions for Option<json::extensions::material::OcclusionTexture> {
+    fn transform(&self) -> Option<&TextureTransform> {
+        self.as_ref()?.texture_transform.as_ref()
+    }
+
+    fn transform_mut(&mut self) -> &mut Option<TextureTransform> {
+        &mut self.get_or_insert_with(Default::default).texture_transform
+    }
+}
+
+/// Visits every texture reference of a material together with its extensions,
+/// like [`for_each_texture_mut`](super::for_each_texture_mut).
+fn for_each_reference_mut<F>(material: &mut json::Material, mut f: F)
+where
+    F: FnMut(&mut json::Index<json::Texture>, &mut dyn ReferenceExtensions),
+{
+    let mut info = |info: &mut Option<json::texture::Info>| {
+        if let Some(info) = info.as_mut() {
+            f(&mut info.index, &mut info.extensions);
+        }
+    };
+    let pbr = &mut material.pbr_metallic_roughness;
+    info(&mut pbr.base_color_texture);
+    info(&mut pbr.metallic_roughness_texture);
+    info(&mut material.emissive_texture);
+
+    #[allow(unused_variables)]
+    if let Some(extensions) = material.extensions.as_mut() {
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        if let Some(sg) = extensions.pbr_specular_glossiness.as_mut() {
+            info(&mut sg.diffuse_texture);
+            info(&mut sg.specular_glossiness_texture);
+        }
+        #[cfg(feature = "KHR_materials_transmission")]
+        if let Some(transmission) = extensions.transmission.as_mut() {
+            info(&mut transmission.transmission_texture);
+        }
+        #[cfg(feature = "KHR_materials_volume")]
+        if let Some(volume) = extensions.volume.as_mut() {
+            info(&mut volume.thickness_texture);
+        }
+        #[cfg(feature = "KHR_materials_specular")]
+        if let Some(specular) = extensions.specular.as_mut() {
+            info(&mut specular.specular_texture);
+            info(&mut specular.specular_color_texture);
+        }
+    }
+
+    if let Some(normal) = material.normal_texture.as_mut() {
+        f(&mut normal.index, &mut normal.extensions);
+    }
+    if let Some(occlusion) = material.occlusion_texture.as_mut() {
+        f(&mut occlusion.index, &mut occlusion.extensions);
+    }
+}
+
+/// Returns `true` if a texture clamps its coordinates on both axes.
+fn clamps(root: &json::Root, texture: usize) -> bool {
+    let sampler = root.textures[texture]
+        .sampler
+        .and_then(|sampler| root.samplers.get(sampler.value()));
+    let clamp = Checked::Valid(WrappingMode::ClampToEdge);
+    sampler.map_or(false, |sampler| {
+        sampler.wrap_s == clamp && sampler.wrap_t == clamp
+    })
+}
+
+/// Adds an extension name to a list if it is not present.
+fn declare(names: &mut Vec<String>, name: &str) {
+    if !names.iter().any(|x| x == name) {
+        names.push(name.into());
+    }
+}
+
+/// Replaces textures with regions of a texture atlas.
+///
+/// The atlas image of `plan` is appended to the first buffer and referenced by
+/// a new texture. Every material texture reference to a placed texture is
+/// pointed at the atlas, and its `KHR_texture_transform` is composed with the
+/// placement, so that references to the same texture with different existing
+/// transforms each keep their own. `KHR_texture_transform` is added to
+/// `extensionsUsed` and `extensionsRequired`, since the atlas cannot be
+/// rendered correctly without it.
+///
+/// The replaced textures and their images are removed if nothing references
+/// them afterwards; the bytes of their buffer views are left in place.
+/// References in extensions that are unknown to this crate version or not
+/// enabled as crate features are not rewritten, so the textures they use are
+/// kept.
+pub fn atlas_textures(
+    root: &mut json::Root,
+    buffers: &mut Vec<buffer::Data>,
+    plan: &AtlasPlan,
+) -> AtlasReport {
+    let textures = root.textures.len();
+    let placement = |texture: usize| {
+        plan.placements
+            .iter()
+            .find(|placement| placement.texture == texture && texture < textures)
+    };
+
+    let mut report = AtlasReport::default();
+    let mut placed = Vec::new();
+    for (index, material) in root.materials.iter_mut().enumerate() {
+        for_each_reference_mut(material, |reference, extensions| {
+            let texture = reference.value();
+            if let Some(placement) = placement(texture) {
+                match compose(extensions.transform(), placement) {
+                    Some(_) => {
+                        report.references += 1;
+                        placed.push(texture);
+                    }
+                    None => report.warnings.push(AtlasWarning::Shear {
+                        material: index,
+                        texture,
+                    }),
+                }
+            }
+        });
+    }
+    placed.sort_unstable();
+    placed.dedup();
+    for &texture in &placed {
+        if !clamps(root, texture) {
+            report.warnings.push(AtlasWarning::Wrapping { texture });
+        }
+    }
+    if report.references == 0 {
+        return report;
+    }
+
+    let view = super::append_view(root, buffers, &plan.data);
+    let source = root.push(json::Image {
+        buffer_view: Some(view),
+        mime_type: Some(json::image::MimeType(plan.mime_type.clone())),
+        #[cfg(feature = "names")]
+        name: None,
+        uri: None,
+        extensions: None,
+        extras: Default::default(),
+    });
+    let atlas = root.push(json::Texture {
+        #[cfg(feature = "names")]
+        name: None,
+        sampler: plan.sampler.map(|sampler| json::Index::new(sampler as u32)),
+        source,
+        extensions: None,
+        extras: Default::default(),
+    });
+    for material in root.materials.iter_mut() {
+        for_each_reference_mut(material, |reference, extensions| {
+            if let Some(placement) = placement(reference.value()) {
+                if let Some(composed) = compose(extensions.transform(), placement) {
+                    *extensions.transform_mut() = Some(composed);
+                    *reference = atlas;
+                }
+            }
+        });
+    }
+    declare(&mut root.extensions_used, "KHR_texture_transform");
+    declare(&mut root.extensions_required, "KHR_texture_transform");
+
+    super::prune_textures(root, &placed);
+    report.texture = Some(root.textures.len() - 1);
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUARTER_TURN: f32 = core::f32::consts::FRAC_PI_2;
+
+    fn transform(offset: [f32; 2], rotation: f32, scale: [f32; 2]) -> TextureTransform {
+        TextureTransform {
+            offset: TextureTransformOffset(offset),
+            rotation: TextureTransformRotation(rotation),
+            scale: TextureTransformScale(scale),
+            ..Default::default()
+        }
+    }
+
+    /// Applies a transform to texture coordinates as specified by
+    /// `KHR_texture_transform`.
+    fn apply(transform: &TextureTransform, [u, v]: [f32; 2]) -> [f32; 2] {
+        let [sx, sy] = transform.scale.0;
+        let (u, v) = (u * sx, v * sy);
+        let rotation = transform.rotation.0;
+        let (sin, cos) = unsafe { (sinf32(rotation), cosf32(rotation)) };
+        let [ox, oy] = transform.offset.0;
+        [cos * u + sin * v + ox, -sin * u + cos * v + oy]
+    }
+
+    fn assert_close([a, b]: [f32; 2], [c, d]: [f32; 2]) {
+        assert!(
+            (a - c).abs() < 1e-5 && (b - d).abs() < 1e-5,
+            "{:?}",
+            [a, b, c, d]
+        );
+    }
+
+    #[test]
+    fn composition() {
+        let placement = AtlasPlacement {
+            texture: 0,
+            offset: [0.5, 0.25],
+            scale: [0.5, 0.25],
+        };
+        let existing = [
+            None,
+            Some(transform([0.1, 0.2], 0.0, [2.0, 3.0])),
+            Some(transform([0.1, 0.2], QUARTER_TURN, [2.0, 3.0])),
+            Some(transform([0.0, 1.0], -QUARTER_TURN, [1.0, 1.0])),
+            Some(transform([0.3, 0.0], core::f32::consts::PI, [1.0, -1.0])),
+        ];
+        for existing in &existing {
+            let composed = compose(existing.as_ref(), &placement).unwrap();
+            for uv in [[0.0, 0.0], [1.0, 0.0], [0.3, 0.7], [-2.0, 5.0]] {
+                let uv_texture = match existing {
+                    Some(existing) => apply(existing, uv),
+                    None => uv,
+                };
+                let expected = [
+                    placement.offset[0] + placement.scale[0] * uv_texture[0],
+                    placement.offset[1] + placement.scale[1] * uv_texture[1],
+                ];
+                assert_close(apply(&composed, uv), expected);
+            }
+        }
+
+        let eighth_turn = transform([0.0, 0.0], QUARTER_TURN / 2.0, [1.0, 1.0]);
+        assert!(compose(Some(&eighth_turn), &placement).is_none());
+        let uniform = AtlasPlacement {
+            scale: [0.5, 0.5],
+            ..placement
+        };
+        let composed = compose(Some(&eighth_turn), &uniform).unwrap();
+        assert_close(composed.scale.0, [0.5, 0.5]);
+        assert_close(apply(&composed, [1.0, 0.0]), {
+            let [u, v] = apply(&eighth_turn, [1.0, 0.0]);
+            [0.5 + 0.5 * u, 0.25 + 0.5 * v]
+        });
+    }
+}
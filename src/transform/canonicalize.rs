@@ -0,0 +1,413 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::accessor::content_hash;
+use crate::{buffer, Document};
+use json::Index;
+
+use super::extract::{extract, remap, Selection};
+use super::{for_each_image_mut, for_each_texture_mut};
+
+/// Serializes an entity for comparison.
+///
+/// This is a macro since `serde` is only a dependency with the `extensions`
+/// feature.
+macro_rules! encode {
+    ($value:expr) => {
+        serde_json::to_vec($value).unwrap_or_default()
+    };
+}
+
+/// Sorts the top-level arrays of a document into a canonical order, so that
+/// documents with the same content serialize identically regardless of the
+/// order in which their entities were created.
+///
+/// Entities are ordered by keys that do not depend on their indices, in this
+/// order, before every reference to them is rewritten:
+///
+/// * accessors by the [`content_hash`] of their elements, then by their
+///   properties other than buffer views;
+/// * images by a hash of their data, then by their properties;
+/// * buffer views by their first use, from the accessors in order and then
+///   the images; unused views follow by a hash of their data;
+/// * samplers, textures, meshes, cameras, lights, skins and animations by their
+///   properties, which refer to already sorted entities;
+/// * materials by name, then by their properties;
+/// * scenes by their properties and the structure of their node hierarchies;
+/// * nodes in depth-first order from the scenes, followed by the nodes that
+///   no scene contains, ordered by the structure of their hierarchies.
+///
+/// Properties are compared in their serialized JSON form. Entities with equal
+/// keys, which are duplicates such as those merged by
+/// [`dedup_accessors`](super::dedup_accessors), keep their relative order.
+/// The extension names of the asset are sorted as well.
+///
+/// The buffer view data is then packed into a single buffer without a URI in
+/// the new order of the views, with zero padding to 4-byte boundaries, like
+/// [`prune`](super::prune) does; data that is missing from `buffers` is
+/// zero-filled.
+pub fn canonicalize(root: &mut json::Root, buffers: &mut Vec<buffer::Data>) {
+    sort_accessors(root, buffers);
+    sort_images(root, buffers);
+    sort_views(root, buffers);
+    sort_materials(root);
+    sort_meshes(root);
+    sort_nodes(root);
+    #[cfg(feature = "animation")]
+    {
+        let keys = root.animations.iter().map(|item| encode!(item)).collect();
+        arrange(&mut root.animations, by_key(keys));
+    }
+    for names in [&mut root.extensions_used, &mut root.extensions_required] {
+        names.sort();
+        names.dedup();
+    }
+
+    let selection = Selection::all(root);
+    let scenes: Vec<usize> = (0..root.scenes.len()).collect();
+    let (mut packed, data) = extract(root, buffers, &selection, &scenes);
+    packed.scene = root.scene;
+    *root = packed;
+    *buffers = match root.buffers.is_empty() {
+        true => Vec::new(),
+        false => vec![buffer::Data(data)],
+    };
+}
+
+/// Returns the 64-bit FNV-1a hash of some bytes.
+pub(crate) fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Returns the indices of the keys in ascending key order, keeping equal keys
+/// in their original order.
+fn by_key<K: Ord>(keys: Vec<K>) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..keys.len()).collect();
+    order.sort_by(|&a, &b| keys[a].cmp(&keys[b]));
+    order
+}
+
+/// Moves the items into the given order of their old indices, and returns a
+/// mapping from old to new indices.
+fn arrange<T>(items: &mut Vec<T>, order: Vec<usize>) -> Vec<Option<u32>> {
+    let mut map = vec![None; items.len()];
+    let mut old: Vec<Option<T>> = mem::take(items).into_iter().map(Some).collect();
+    for (new, index) in order.into_iter().enumerate() {
+        map[index] = Some(new as u32);
+        items.extend(old[index].take());
+    }
+    map
+}
+
+/// Returns the data of a buffer view, if it is available.
+pub(super) fn view_data<'a>(
+    root: &json::Root,
+    buffers: &'a [buffer::Data],
+    view: Index<json::buffer::View>,
+) -> Option<&'a [u8]> {
+    let view = root.buffer_views.get(view.value())?;
+    let start = view.byte_offset.unwrap_or_default().0 as usize;
+    let end = start.checked_add(view.byte_length.0 as usize)?;
+    buffers.get(view.buffer.value())?.get(start..end)
+}
+
+fn sort_accessors(root: &mut json::Root, buffers: &[buffer::Data]) {
+    let keys = super::with_document(root, |document| accessor_keys(document, buffers));
+    let map = arrange(&mut root.accessors, by_key(keys));
+    super::for_each_accessor_mut(root, |accessor| *accessor = remap(&map, *accessor));
+}
+
+fn accessor_keys(document: &Document, buffers: &[buffer::Data]) -> Vec<(Option<u64>, Vec<u8>)> {
+    let get_buffer_data = |buffer: buffer::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+    document
+        .accessors()
+        .map(|accessor| {
+            let mut json = document.as_json().accessors[accessor.index()].clone();
+            json.buffer_view = None;
+            if let Some(sparse) = json.sparse.as_mut() {
+                sparse.indices.buffer_view = Index::new(0);
+                sparse.values.buffer_view = Index::new(0);
+            }
+            (
+                content_hash(&accessor, get_buffer_data).ok(),
+                encode!(&json),
+            )
+        })
+        .collect()
+}
+
+fn sort_images(root: &mut json::Root, buffers: &[buffer::Data]) {
+    let keys = root
+        .images
+        .iter()
+        .map(|image| {
+            let data = image
+                .buffer_view
+                .and_then(|view| view_data(root, buffers, view));
+            let json = json::Image {
+                buffer_view: None,
+                ..image.clone()
+            };
+            (data.map(hash), encode!(&json))
+        })
+        .collect();
+    let map = arrange(&mut root.images, by_key(keys));
+    for texture in root.textures.iter_mut() {
+        for_each_image_mut(texture, |image| *image = remap(&map, *image));
+    }
+}
+
+fn sort_views(root: &mut json::Root, buffers: &[buffer::Data]) {
+    let mut used = vec![false; root.buffer_views.len()];
+    let mut order = Vec::with_capacity(used.len());
+    let mut mark = |view: Index<json::buffer::View>| {
+        if let Some(used) = used.get_mut(view.value()).filter(|used| !**used) {
+            *used = true;
+            order.push(view.value());
+        }
+    };
+    for accessor in root.accessors.iter() {
+        accessor.buffer_view.into_iter().for_each(&mut mark);
+        if let Some(sparse) = accessor.sparse.as_ref() {
+            mark(sparse.indices.buffer_view);
+            mark(sparse.values.buffer_view);
+        }
+    }
+    for image in root.images.iter() {
+        image.buffer_view.into_iter().for_each(&mut mark);
+    }
+    let unused: Vec<usize> = (0..used.len()).filter(|&view| !used[view]).collect();
+    let keys = unused
+        .iter()
+        .map(|&index| {
+            let view = &root.buffer_views[index];
+            let data = view_data(root, buffers, Index::new(index as u32));
+            let json = json::buffer::View {
+                buffer: Index::new(0),
+                byte_offset: None,
+                ..view.clone()
+            };
+            (data.map(hash), encode!(&json))
+        })
+        .collect();
+    order.extend(by_key(keys).into_iter().map(|index| unused[index]));
+
+    let map = arrange(&mut root.buffer_views, order);
+    for accessor in root.accessors.iter_mut() {
+        accessor.buffer_view = accessor.buffer_view.map(|view| remap(&map, view));
+        if let Some(sparse) = accessor.sparse.as_mut() {
+            sparse.indices.buffer_view = remap(&map, sparse.indices.buffer_view);
+            sparse.values.buffer_view = remap(&map, sparse.values.buffer_view);
+        }
+    }
+    for image in root.images.iter_mut() {
+        image.buffer_view = image.buffer_view.map(|view| remap(&map, view));
+    }
+}
+
+/// Sorts the samplers, textures and materials.
+fn sort_materials(root: &mut json::Root) {
+    let keys = root.samplers.iter().map(|item| encode!(item)).collect();
+    let map = arrange(&mut root.samplers, by_key(keys));
+    for texture in root.textures.iter_mut() {
+        texture.sampler = texture.sampler.map(|sampler| remap(&map, sampler));
+    }
+
+    let keys = root.textures.iter().map(|item| encode!(item)).collect();
+    let map = arrange(&mut root.textures, by_key(keys));
+    for material in root.materials.iter_mut() {
+        for_each_texture_mut(material, |texture| *texture = remap(&map, *texture));
+    }
+
+    let keys = root
+        .materials
+        .iter()
+        .map(|material| {
+            #[cfg(feature = "names")]
+            let name = material.name.clone();
+            #[cfg(not(feature = "names"))]
+            let name: Option<alloc::string::String> = None;
+            (name, encode!(material))
+        })
+        .collect();
+    let map = arrange(&mut root.materials, by_key(keys));
+    for primitive in root
+        .meshes
+        .iter_mut()
+        .flat_map(|mesh| mesh.primitives.iter_mut())
+    {
+        primitive.material = primitive.material.map(|material| remap(&map, material));
+        #[cfg(feature = "KHR_materials_variants")]
+        if let Some(variants) = primitive
+            .extensions
+            .as_mut()
+            .and_then(|extensions| extensions.khr_materials_variants.as_mut())
+        {
+            for mapping in variants.mappings.iter_mut() {
+                if let Some(Some(material)) = map.get(mapping.material as usize) {
+                    mapping.material = *material;
+                }
+            }
+        }
+    }
+}
+
+/// Sorts the meshes, cameras and lights.
+fn sort_meshes(root: &mut json::Root) {
+    let keys = root.meshes.iter().map(|item| encode!(item)).collect();
+    let map = arrange(&mut root.meshes, by_key(keys));
+    for node in root.nodes.iter_mut() {
+        node.mesh = node.mesh.map(|mesh| remap(&map, mesh));
+    }
+
+    #[cfg(feature = "cameras")]
+    {
+        let keys = root.cameras.iter().map(|item| encode!(item)).collect();
+        let map = arrange(&mut root.cameras, by_key(keys));
+        for node in root.nodes.iter_mut() {
+            node.camera = node.camera.map(|camera| remap(&map, camera));
+        }
+    }
+
+    #[cfg(feature = "KHR_lights_punctual")]
+    if let Some(extension) = root
+        .extensions
+        .as_mut()
+        .and_then(|extensions| extensions.khr_lights_punctual.as_mut())
+    {
+        let keys = extension.lights.iter().map(|item| encode!(item)).collect();
+        let map = arrange(&mut extension.lights, by_key(keys));
+        for light in root
+            .nodes
+            .iter_mut()
+            .filter_map(|node| node.extensions.as_mut())
+            .filter_map(|extensions| extensions.khr_lights_punctual.as_mut())
+        {
+            light.light = remap(&map, light.light);
+        }
+    }
+}
+
+/// Hashes the structure of the hierarchy below a node, memoizing the result.
+///
+/// The hash covers the properties of the node other than its children and
+/// skin, the joint count and properties of the skin, and the hashes of the
+/// children in order. Nodes in a cycle hash to zero.
+fn structure(root: &json::Root, index: usize, hashes: &mut Vec<Option<u64>>) -> u64 {
+    match hashes.get(index) {
+        Some(Some(hash)) => return *hash,
+        Some(None) => hashes[index] = Some(0),
+        None => return 0,
+    }
+    let node = &root.nodes[index];
+    let mut bytes = encode!(&json::Node {
+        children: None,
+        #[cfg(feature = "skinning")]
+        skin: None,
+        ..node.clone()
+    });
+    #[cfg(feature = "skinning")]
+    if let Some(skin) = node.skin.and_then(|skin| root.skins.get(skin.value())) {
+        bytes.extend_from_slice(&(skin.joints.len() as u64).to_le_bytes());
+        bytes.extend(encode!(&json::Skin {
+            joints: Vec::new(),
+            skeleton: None,
+            ..skin.clone()
+        }));
+    }
+    for child in node.children.iter().flatten() {
+        let hash = structure(root, child.value(), hashes);
+        bytes.extend_from_slice(&hash.to_le_bytes());
+    }
+    let hash = hash(&bytes);
+    hashes[index] = Some(hash);
+    hash
+}
+
+/// Sorts the scenes, nodes and skins.
+fn sort_nodes(root: &mut json::Root) {
+    let mut hashes = vec![None; root.nodes.len()];
+    let keys: Vec<u64> = (0..root.nodes.len())
+        .map(|node| structure(root, node, &mut hashes))
+        .collect();
+
+    let scene_keys = root
+        .scenes
+        .iter()
+        .map(|scene| {
+            let nodes: Vec<u64> = scene
+                .nodes
+                .iter()
+                .map(|node| keys.get(node.value()).copied().unwrap_or_default())
+                .collect();
+            let json = json::Scene {
+                nodes: Vec::new(),
+                ..scene.clone()
+            };
+            (encode!(&json), nodes)
+        })
+        .collect();
+    let map = arrange(&mut root.scenes, by_key(scene_keys));
+    root.scene = root.scene.map(|scene| remap(&map, scene));
+
+    let mut visited = vec![false; root.nodes.len()];
+    let mut order = Vec::with_capacity(visited.len());
+    let mut stack = Vec::new();
+    let scenes = root.scenes.iter().flat_map(|scene| scene.nodes.iter());
+    let roots: Vec<usize> = scenes
+        .map(|node| node.value())
+        .chain(by_key(keys))
+        .collect();
+    for node in roots {
+        stack.push(node);
+        while let Some(node) = stack.pop() {
+            match visited.get_mut(node) {
+                Some(visited) if !*visited => *visited = true,
+                _ => continue,
+            }
+            order.push(node);
+            let children = root.nodes[node].children.iter().flatten();
+            stack.extend(children.rev().map(|child| child.value()));
+        }
+    }
+
+    let map = arrange(&mut root.nodes, order);
+    for node in root.nodes.iter_mut() {
+        if let Some(children) = node.children.as_mut() {
+            for child in children.iter_mut() {
+                *child = remap(&map, *child);
+            }
+        }
+    }
+    for scene in root.scenes.iter_mut() {
+        for node in scene.nodes.iter_mut() {
+            *node = remap(&map, *node);
+        }
+    }
+    #[cfg(feature = "animation")]
+    for channel in root
+        .animations
+        .iter_mut()
+        .flat_map(|animation| animation.channels.iter_mut())
+    {
+        channel.target.node = remap(&map, channel.target.node);
+    }
+
+    #[cfg(feature = "skinning")]
+    {
+        for skin in root.skins.iter_mut() {
+            for joint in skin.joints.iter_mut() {
+                *joint = remap(&map, *joint);
+            }
+            skin.skeleton = skin.skeleton.map(|node| remap(&map, node));
+        }
+        let keys = root.skins.iter().map(|item| encode!(item)).collect();
+        let map = arrange(&mut root.skins, by_key(keys));
+        for node in root.nodes.iter_mut() {
+            node.skin = node.skin.map(|skin| remap(&map, skin));
+        }
+    }
+}
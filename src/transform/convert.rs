@@ -0,0 +1,29 @@
+use super::normalize::wrap;
+use crate::convention::{Axis, Convention};
+
+/// Converts a scene from the given convention to the meters and +Y up of
+/// glTF.
+///
+/// A wrapper node carrying the rotation of [`Axis::rotation_to_y_up`] and a
+/// uniform scale of the unit scale becomes the only root node of the scene,
+/// leaving everything else intact. `asset.extras` is left untouched, so a
+/// document converted [`Convention::FromDeclared`] still declares its
+/// original conventions and must not be converted again.
+///
+/// Returns the index of the wrapper node, or `None`, leaving the document
+/// untouched, if the scene does not exist or is already in the conventions
+/// of glTF.
+pub fn convert_coordinates(root: &mut json::Root, scene: usize, from: Convention) -> Option<usize> {
+    if scene >= root.scenes.len() {
+        return None;
+    }
+    let (unit_scale, up_axis) = from.resolve_asset(&root.asset);
+    if unit_scale == 1.0 && up_axis == Axis::Y {
+        return None;
+    }
+    Some(wrap(root, scene, |node| {
+        node.rotation =
+            (up_axis != Axis::Y).then(|| json::scene::UnitQuaternion(up_axis.rotation_to_y_up()));
+        node.scale = (unit_scale != 1.0).then(|| [unit_scale as f32; 3]);
+    }))
+}
@@ -0,0 +1,184 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::accessor::{content_eq, content_hash};
+use crate::{buffer, Accessor, Document};
+
+use super::canonicalize::{hash, view_data};
+use super::{compact, for_each_accessor_mut, for_each_image_mut, for_each_texture_mut};
+
+/// The images and textures merged by [`dedup_textures`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DedupedTextures {
+    /// The new index of every original image.
+    pub images: Vec<usize>,
+
+    /// The new index of every original texture.
+    pub textures: Vec<usize>,
+}
+
+/// Merges accessors with identical contents, returning the new index of every
+/// original accessor.
+///
+/// Accessors are identical if they have the same component type, type,
+/// normalization and number of elements, and their elements, including any
+/// sparse substitutions, are equal byte for byte. References are redirected
+/// to the first accessor of each group, which takes over the bounds of a
+/// duplicate if it has none, and the duplicates are removed. Their buffer
+/// views are left in place for [`prune`](super::prune) to remove. Accessors
+/// whose data is unavailable are not merged.
+pub fn dedup_accessors(root: &mut json::Root, buffers: &[buffer::Data]) -> Vec<usize> {
+    let first = super::with_document(root, |document| duplicate_accessors(document, buffers));
+    for (index, &first) in first.iter().enumerate() {
+        if first != index {
+            let (min, max) = {
+                let duplicate = &root.accessors[index];
+                (duplicate.min.clone(), duplicate.max.clone())
+            };
+            let accessor = &mut root.accessors[first];
+            if accessor.min.is_none() {
+                accessor.min = min;
+            }
+            if accessor.max.is_none() {
+                accessor.max = max;
+            }
+        }
+    }
+
+    let remap = merge(&mut root.accessors, &first);
+    for_each_accessor_mut(root, |accessor| {
+        if let Some(&new) = remap.get(accessor.value()) {
+            *accessor = json::Index::new(new as u32);
+        }
+    });
+    remap
+}
+
+/// Returns the index of the first accessor with the same contents as each
+/// accessor.
+///
+/// Accessors are grouped by [`content_hash`] and compared within a group by
+/// [`content_eq`], which streams their elements, so that the contents of the
+/// accessors are never held in memory.
+fn duplicate_accessors(document: &Document, buffers: &[buffer::Data]) -> Vec<usize> {
+    let get_buffer_data = |buffer: buffer::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+    let accessors: Vec<Accessor> = document.accessors().collect();
+    let mut groups = BTreeMap::<_, Vec<usize>>::new();
+    let mut first: Vec<usize> = (0..accessors.len()).collect();
+    for accessor in &accessors {
+        let hash = match content_hash(accessor, get_buffer_data) {
+            Ok(hash) => hash,
+            Err(_) => continue,
+        };
+        let group = groups.entry(hash).or_default();
+        let original = group.iter().find(|&&other| {
+            let a = (accessor.clone(), get_buffer_data);
+            let b = (accessors[other].clone(), get_buffer_data);
+            content_eq(a, b).unwrap_or(false)
+        });
+        match original {
+            Some(&index) => first[accessor.index()] = index,
+            None => group.push(accessor.index()),
+        }
+    }
+    first
+}
+
+/// Merges images with identical data and properties, then textures with
+/// identical properties, including those that became identical by sharing a
+/// merged image.
+///
+/// Names are not compared. References are redirected to the first image or
+/// texture of each group, and the duplicates are removed. The buffer views of
+/// duplicate images are left in place for [`prune`](super::prune) to remove,
+/// and images whose buffer view data is unavailable are not merged. With the
+/// `extensions` feature, texture references in extensions that are unknown to
+/// this crate version are redirected where they follow the convention of the
+/// Khronos extensions.
+pub fn dedup_textures(root: &mut json::Root, buffers: &[buffer::Data]) -> DedupedTextures {
+    let mut groups = BTreeMap::<_, Vec<usize>>::new();
+    let mut first: Vec<usize> = (0..root.images.len()).collect();
+    for (index, image) in root.images.iter().enumerate() {
+        let data = match image.buffer_view {
+            Some(view) => match view_data(root, buffers, view) {
+                Some(data) => Some(data),
+                None => continue,
+            },
+            None => None,
+        };
+        let json = json::Image {
+            buffer_view: None,
+            #[cfg(feature = "names")]
+            name: None,
+            ..image.clone()
+        };
+        let key = (
+            data.map(hash),
+            serde_json::to_vec(&json).unwrap_or_default(),
+        );
+        let group = groups.entry(key).or_default();
+        let original = group.iter().find(|&&other| {
+            let other = root.images[other].buffer_view;
+            other.and_then(|view| view_data(root, buffers, view)) == data
+        });
+        match original {
+            Some(&other) => first[index] = other,
+            None => group.push(index),
+        }
+    }
+    let images = merge(&mut root.images, &first);
+    for texture in root.textures.iter_mut() {
+        for_each_image_mut(texture, |image| {
+            if let Some(&new) = images.get(image.value()) {
+                *image = json::Index::new(new as u32);
+            }
+        });
+    }
+
+    let mut groups = BTreeMap::new();
+    let first: Vec<usize> = root
+        .textures
+        .iter()
+        .enumerate()
+        .map(|(index, texture)| {
+            let json = json::Texture {
+                #[cfg(feature = "names")]
+                name: None,
+                ..texture.clone()
+            };
+            *groups
+                .entry(serde_json::to_vec(&json).unwrap_or_default())
+                .or_insert(index)
+        })
+        .collect();
+    let textures = merge(&mut root.textures, &first);
+    for material in root.materials.iter_mut() {
+        for_each_texture_mut(material, |texture| {
+            if let Some(&new) = textures.get(texture.value()) {
+                *texture = json::Index::new(new as u32);
+            }
+        });
+    }
+    DedupedTextures { images, textures }
+}
+
+/// Removes the items that are not their own first occurrence, and returns the
+/// new index of the first occurrence of every original item.
+fn merge<T>(items: &mut Vec<T>, first: &[usize]) -> Vec<usize> {
+    let keep: Vec<bool> = first
+        .iter()
+        .enumerate()
+        .map(|(index, &first)| index == first)
+        .collect();
+    let map = compact(&keep);
+    let remap = first
+        .iter()
+        .map(|&first| map[first].unwrap() as usize)
+        .collect();
+    let mut index = 0;
+    items.retain(|_| {
+        index += 1;
+        keep[index - 1]
+    });
+    remap
+}
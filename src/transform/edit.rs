@@ -0,0 +1,818 @@
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use byteorder::{ByteOrder, LE};
+use core::fmt;
+
+use crate::accessor::{extent, DataType, Dimensions};
+use crate::{buffer, saturating_usize, Document};
+use json::validation::{Checked, USize64};
+
+/// A glTF document together with its buffer data, opened for editing.
+///
+/// A `Document` and its buffers can be taken apart and put back together with
+/// [`DocumentMut::new`] and [`DocumentMut::into_document`].
+#[derive(Clone, Debug)]
+pub struct DocumentMut {
+    /// The JSON root of the document.
+    pub root: json::Root,
+
+    /// The buffer data, indexed like `root.buffers`.
+    pub buffers: Vec<buffer::Data>,
+}
+
+/// Errors that may occur when editing a document.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EditError {
+    /// The accessor index is out of range.
+    NoSuchAccessor(usize),
+
+    /// The image index is out of range.
+    NoSuchImage(usize),
+
+    /// The accessor is sparse, or has no buffer view.
+    Sparse(usize),
+
+    /// The accessor's byte range lies outside its buffer data.
+    MissingData(usize),
+
+    /// The element layout is not supported, e.g. padded matrix columns.
+    UnsupportedLayout(usize),
+
+    /// The accessor's component type or element type is invalid.
+    InvalidType(usize),
+
+    /// The element index is not less than the accessor count.
+    ElementOutOfRange {
+        /// The requested element.
+        element: usize,
+        /// The number of elements in the accessor.
+        count: usize,
+    },
+
+    /// The number of values supplied for an element does not match the
+    /// number of components.
+    Dimensions {
+        /// The number of components per element.
+        expected: usize,
+        /// The number of values supplied.
+        actual: usize,
+    },
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EditError::NoSuchAccessor(index) => write!(f, "accessor {} does not exist", index),
+            EditError::NoSuchImage(index) => write!(f, "image {} does not exist", index),
+            EditError::Sparse(index) => {
+                write!(f, "accessor {} is sparse or has no buffer view", index)
+            }
+            EditError::MissingData(index) => {
+                write!(f, "accessor {} lies outside its buffer data", index)
+            }
+            EditError::UnsupportedLayout(index) => {
+                write!(f, "accessor {} has an unsupported element layout", index)
+            }
+            EditError::InvalidType(index) => {
+                write!(
+                    f,
+                    "accessor {} has an invalid component or element type",
+                    index
+                )
+            }
+            EditError::ElementOutOfRange { element, count } => {
+                write!(f, "element {} out of range for count {}", element, count)
+            }
+            EditError::Dimensions { expected, actual } => {
+                write!(f, "expected {} components but found {}", expected, actual)
+            }
+        }
+    }
+}
+
+impl core::error::Error for EditError {}
+
+/// Specifies how [`DocumentMut::replace_image_with`] treats the space freed
+/// when an image shrinks.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Shrink {
+    /// Leave the unused bytes in the buffer.
+    Gap,
+
+    /// Move the following buffer data down to reclaim the unused bytes.
+    Repack,
+}
+
+impl Default for Shrink {
+    fn default() -> Self {
+        Shrink::Gap
+    }
+}
+
+/// Options for [`DocumentMut::replace_image_with`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ReplaceOptions {
+    /// How the space freed by smaller image data is treated.
+    pub shrink: Shrink,
+}
+
+/// The user of a buffer view that is about to be edited.
+#[derive(Clone, Copy)]
+pub(super) enum Owner {
+    Accessor(usize),
+    Image(usize),
+}
+
+impl DocumentMut {
+    /// Opens a document and its buffer data for editing.
+    pub fn new(document: Document, buffers: Vec<buffer::Data>) -> Self {
+        Self {
+            root: document.into_json(),
+            buffers,
+        }
+    }
+
+    /// Validates the edited document and returns it with its buffer data.
+    pub fn into_document(self) -> crate::Result<(Document, Vec<buffer::Data>)> {
+        Ok((Document::from_json(self.root)?, self.buffers))
+    }
+
+    /// Returns an editing handle for the given accessor.
+    ///
+    /// If the accessor's buffer view is shared with anything else, or overlaps
+    /// another buffer view, the accessor data is first copied into a new
+    /// buffer view so that edits cannot affect other data. Buffer views
+    /// referenced only from extension data unknown to this crate are not
+    /// detected.
+    pub fn accessor_mut(&mut self, index: usize) -> Result<AccessorMut<'_>, EditError> {
+        let Layout {
+            view: view_index,
+            mut buffer,
+            mut offset,
+            length,
+            stride,
+            count,
+            data_type,
+            dimensions,
+        } = layout(&self.root, &self.buffers, index)?;
+        if is_view_shared(&self.root, Owner::Accessor(index), view_index) {
+            let view = &self.root.buffer_views[view_index];
+            let bytes = self.buffers[buffer][offset..offset + length].to_vec();
+            let stride = view.byte_stride;
+            let target = view.target;
+            let new_view = super::append_view(&mut self.root, &mut self.buffers, &bytes);
+            let json_view = &mut self.root.buffer_views[new_view.value()];
+            json_view.byte_stride = stride;
+            json_view.target = target;
+            offset = json_view.byte_offset.unwrap_or_default().0 as usize;
+            buffer = json_view.buffer.value();
+            let accessor = &mut self.root.accessors[index];
+            accessor.buffer_view = Some(new_view);
+            accessor.byte_offset = None;
+        }
+
+        let normalized = self.root.accessors[index].normalized;
+        Ok(AccessorMut {
+            document: self,
+            index,
+            buffer,
+            offset,
+            stride,
+            count,
+            data_type,
+            components: dimensions.multiplicity(),
+            normalized,
+            dirty: false,
+        })
+    }
+
+    /// Replaces the encoded data of an image, leaving every texture and
+    /// material reference to it untouched.
+    ///
+    /// Freed space is left as a gap in the buffer. See
+    /// [`DocumentMut::replace_image_with`] for details.
+    pub fn replace_image(
+        &mut self,
+        index: usize,
+        data: Vec<u8>,
+        mime: &str,
+    ) -> Result<(), EditError> {
+        self.replace_image_with(index, data, mime, ReplaceOptions::default())
+    }
+
+    /// Replaces the encoded data of an image, leaving every texture and
+    /// material reference to it untouched.
+    ///
+    /// If the image is the only user of its buffer view, the view is resized
+    /// in place, moving the following buffer data up when the new data is
+    /// larger. Otherwise, including for images that refer to a URI, the data
+    /// is appended to the first buffer in a new view and the image is
+    /// converted to an embedded one. The image's `mimeType` is set to `mime`.
+    pub fn replace_image_with(
+        &mut self,
+        index: usize,
+        data: Vec<u8>,
+        mime: &str,
+        options: ReplaceOptions,
+    ) -> Result<(), EditError> {
+        let image = self
+            .root
+            .images
+            .get(index)
+            .ok_or(EditError::NoSuchImage(index))?;
+        let in_place = image.buffer_view.and_then(|view| {
+            let view = view.value();
+            let json = self.root.buffer_views.get(view)?;
+            let start = saturating_usize(json.byte_offset.unwrap_or_default().0);
+            let end = start.checked_add(saturating_usize(json.byte_length.0))?;
+            let buffer = json.buffer.value();
+            let available = self.buffers.get(buffer)?.len();
+            let exclusive =
+                end <= available && !is_view_shared(&self.root, Owner::Image(index), view);
+            exclusive.then(|| (view, buffer, start, end))
+        });
+
+        let view = match in_place {
+            Some((view, buffer, start, end)) => {
+                let length = end - start;
+                let shift = if data.len() > length {
+                    // Keep the following data 4-byte aligned.
+                    ((data.len() - length + 3) & !3) as isize
+                } else if options.shrink == Shrink::Repack {
+                    -(((length - data.len()) & !3) as isize)
+                } else {
+                    0
+                };
+                if shift != 0 {
+                    shift_buffer(&mut self.root, &mut self.buffers, buffer, end, shift);
+                }
+                self.buffers[buffer].0[start..start + data.len()].copy_from_slice(&data);
+                self.root.buffer_views[view].byte_length = USize64::from(data.len());
+                json::Index::new(view as u32)
+            }
+            None => super::append_view(&mut self.root, &mut self.buffers, &data),
+        };
+
+        let image = &mut self.root.images[index];
+        image.buffer_view = Some(view);
+        image.mime_type = Some(json::image::MimeType(mime.to_string()));
+        image.uri = None;
+        Ok(())
+    }
+}
+
+/// Where the elements of an accessor lie, as found by [`layout`].
+struct Layout {
+    view: usize,
+    buffer: usize,
+    offset: usize,
+    length: usize,
+    stride: usize,
+    count: usize,
+    data_type: DataType,
+    dimensions: Dimensions,
+}
+
+/// Locates the elements of an accessor that [`DocumentMut::accessor_mut`] can
+/// edit.
+fn layout(root: &json::Root, buffers: &[buffer::Data], index: usize) -> Result<Layout, EditError> {
+    let json = root
+        .accessors
+        .get(index)
+        .ok_or(EditError::NoSuchAccessor(index))?;
+    let view_index = match (json.buffer_view, json.sparse.as_ref()) {
+        (Some(view), None) => view.value(),
+        _ => return Err(EditError::Sparse(index)),
+    };
+    let view = root
+        .buffer_views
+        .get(view_index)
+        .ok_or(EditError::MissingData(index))?;
+    let (data_type, dimensions) = match (json.component_type.as_ref(), json.type_.as_ref()) {
+        (Checked::Valid(component_type), Checked::Valid(type_)) => (component_type.0, *type_),
+        _ => return Err(EditError::InvalidType(index)),
+    };
+    let size = data_type.size() * dimensions.multiplicity();
+    let is_matrix = matches!(
+        dimensions,
+        Dimensions::Mat2 | Dimensions::Mat3 | Dimensions::Mat4
+    );
+    if is_matrix && data_type.size() < 4 {
+        return Err(EditError::UnsupportedLayout(index));
+    }
+    let stride = view.byte_stride.map(|stride| stride.0).unwrap_or(size);
+    let count = saturating_usize(json.count.0);
+    let length = extent(count, stride, size);
+    let view_offset = saturating_usize(view.byte_offset.unwrap_or_default().0);
+    let byte_offset = saturating_usize(json.byte_offset.unwrap_or_default().0);
+    let offset = view_offset.saturating_add(byte_offset);
+    let buffer = view.buffer.value();
+    let end = offset.saturating_add(length);
+    if end > view_offset.saturating_add(saturating_usize(view.byte_length.0))
+        || buffers.get(buffer).map_or(true, |data| end > data.len())
+    {
+        return Err(EditError::MissingData(index));
+    }
+    Ok(Layout {
+        view: view_index,
+        buffer,
+        offset,
+        length,
+        stride,
+        count,
+        data_type,
+        dimensions,
+    })
+}
+
+/// Checks that [`DocumentMut::accessor_mut`] can open the given accessor, and
+/// returns its number of components, without changing the document.
+pub(super) fn check_accessor(
+    root: &json::Root,
+    buffers: &[buffer::Data],
+    index: usize,
+) -> Result<usize, EditError> {
+    layout(root, buffers, index).map(|layout| layout.dimensions.multiplicity())
+}
+
+/// Returns `true` if anything other than the given owner references the
+/// buffer view, or if another view overlaps its byte range.
+pub(super) fn is_view_shared(root: &json::Root, owner: Owner, view: usize) -> bool {
+    let referenced_elsewhere = root.accessors.iter().enumerate().any(|(i, json)| {
+        let sparse = json.sparse.as_ref().map_or(false, |sparse| {
+            sparse.indices.buffer_view.value() == view || sparse.values.buffer_view.value() == view
+        });
+        let other = !matches!(owner, Owner::Accessor(accessor) if accessor == i);
+        sparse || (other && json.buffer_view.map(|x| x.value()) == Some(view))
+    }) || root.images.iter().enumerate().any(|(i, json)| {
+        let other = !matches!(owner, Owner::Image(image) if image == i);
+        other && json.buffer_view.map(|x| x.value()) == Some(view)
+    });
+    let range = |json: &json::buffer::View| {
+        let start = json.byte_offset.unwrap_or_default().0;
+        (json.buffer.value(), start, start.saturating_add(json.byte_length.0))
+    };
+    let (buffer, start, end) = range(&root.buffer_views[view]);
+    let overlapping = root.buffer_views.iter().enumerate().any(|(i, json)| {
+        let (other_buffer, other_start, other_end) = range(json);
+        i != view && other_buffer == buffer && other_start < end && start < other_end
+    });
+    referenced_elsewhere || overlapping
+}
+
+/// Moves the buffer data following `end` by `shift` bytes, updating the
+/// offsets of the buffer views that lie there.
+pub(super) fn shift_buffer(
+    root: &mut json::Root,
+    buffers: &mut [buffer::Data],
+    buffer: usize,
+    end: usize,
+    shift: isize,
+) {
+    let bytes = &mut buffers[buffer].0;
+    if shift > 0 {
+        bytes.splice(end..end, core::iter::repeat(0).take(shift as usize));
+    } else {
+        bytes.drain(end - shift.unsigned_abs()..end);
+    }
+    root.buffers[buffer].byte_length = USize64::from(bytes.len());
+    for view in root.buffer_views.iter_mut() {
+        let offset = view.byte_offset.unwrap_or_default().0 as usize;
+        if view.buffer.value() == buffer && offset >= end {
+            view.byte_offset = Some(USize64::from((offset as isize + shift) as usize));
+        }
+    }
+}
+
+/// An editing handle for the elements of a single accessor.
+///
+/// Values are exchanged as `f64`. For normalized integer accessors they are
+/// the normalized values, e.g. `0.0..=1.0` for `UNSIGNED_BYTE`; otherwise they
+/// are the stored values, rounded and clamped to the component type on
+/// writing.
+///
+/// The accessor `min` and `max` are recomputed by [`AccessorMut::commit`], or
+/// when the handle is dropped, if any element was modified.
+#[derive(Debug)]
+pub struct AccessorMut<'d> {
+    document: &'d mut DocumentMut,
+    index: usize,
+    buffer: usize,
+    offset: usize,
+    stride: usize,
+    count: usize,
+    data_type: DataType,
+    components: usize,
+    normalized: bool,
+    dirty: bool,
+}
+
+impl<'d> AccessorMut<'d> {
+    /// Returns the internal JSON index of the accessor.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the number of elements.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the number of components per element.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    fn check(&self, element: usize, values: usize) -> Result<usize, EditError> {
+        if element >= self.count {
+            return Err(EditError::ElementOutOfRange {
+                element,
+                count: self.count,
+            });
+        }
+        if values != self.components {
+            return Err(EditError::Dimensions {
+                expected: self.components,
+                actual: values,
+            });
+        }
+        Ok(self.offset + element * self.stride)
+    }
+
+    /// Reads the components of an element into `values`.
+    pub fn element(&self, element: usize, values: &mut [f64]) -> Result<(), EditError> {
+        let start = self.check(element, values.len())?;
+        let size = self.data_type.size();
+        let data = &self.document.buffers[self.buffer];
+        for (i, value) in values.iter_mut().enumerate() {
+            let bytes = &data[start + i * size..];
+            *value = read_component(self.data_type, self.normalized, bytes);
+        }
+        Ok(())
+    }
+
+    /// Overwrites the components of an element.
+    pub fn set_element(&mut self, element: usize, values: &[f64]) -> Result<(), EditError> {
+        let start = self.check(element, values.len())?;
+        let size = self.data_type.size();
+        let data = &mut self.document.buffers[self.buffer].0;
+        for (i, &value) in values.iter().enumerate() {
+            let bytes = &mut data[start + i * size..];
+            write_component(self.data_type, self.normalized, bytes, value);
+        }
+        self.dirty = true;
+        Ok(())
+    }
+
+    /// Applies `f` to the components of every element in turn.
+    pub fn map_elements<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut [f64]),
+    {
+        let mut values = vec![0.0; self.components];
+        for element in 0..self.count {
+            self.element(element, &mut values).unwrap();
+            f(&mut values);
+            self.set_element(element, &values).unwrap();
+        }
+    }
+
+    /// Returns the elements as a mutable `f32` slice, if the accessor holds
+    /// tightly packed, suitably aligned `FLOAT` components on a little-endian
+    /// target.
+    ///
+    /// The slice contains `count * components` values.
+    pub fn as_mut_slice_f32(&mut self) -> Option<&mut [f32]> {
+        let size = 4 * self.components;
+        if self.data_type != DataType::F32
+            || (self.stride != size && self.count > 1)
+            || cfg!(target_endian = "big")
+        {
+            return None;
+        }
+        let data = &mut self.document.buffers[self.buffer].0;
+        let bytes = &mut data[self.offset..self.offset + size * self.count];
+        // Safety: any bit pattern is a valid `f32`.
+        let (prefix, floats, suffix) = unsafe { bytes.align_to_mut::<f32>() };
+        if !prefix.is_empty() || !suffix.is_empty() {
+            return None;
+        }
+        self.dirty = true;
+        Some(floats)
+    }
+
+    /// Recomputes the accessor `min` and `max` if any element was modified.
+    pub fn commit(mut self) {
+        self.update_bounds();
+    }
+
+    fn update_bounds(&mut self) {
+        if !self.dirty {
+            return;
+        }
+        self.dirty = false;
+        let size = self.data_type.size();
+        let mut min = vec![f64::INFINITY; self.components];
+        let mut max = vec![f64::NEG_INFINITY; self.components];
+        let data = &self.document.buffers[self.buffer];
+        for element in 0..self.count {
+            let start = self.offset + element * self.stride;
+            for i in 0..self.components {
+                // Bounds are given in the stored, not the normalized, values.
+                let value = read_component(self.data_type, false, &data[start + i * size..]);
+                if !value.is_nan() {
+                    min[i] = min[i].min(value);
+                    max[i] = max[i].max(value);
+                }
+            }
+        }
+        let to_json = |values: Vec<f64>| {
+            let values: Vec<json::Value> = values
+                .into_iter()
+                .map(|value| match self.data_type {
+                    DataType::F32 => json::Value::from(value as f32),
+                    _ => json::Value::from(value as i64),
+                })
+                .collect();
+            json::Value::from(values)
+        };
+        let (min, max) = match self.count {
+            0 => (None, None),
+            _ => (Some(to_json(min)), Some(to_json(max))),
+        };
+        let accessor = &mut self.document.root.accessors[self.index];
+        accessor.min = min;
+        accessor.max = max;
+    }
+}
+
+impl<'d> Drop for AccessorMut<'d> {
+    fn drop(&mut self) {
+        self.update_bounds();
+    }
+}
+
+pub(crate) fn read_component(data_type: DataType, normalized: bool, bytes: &[u8]) -> f64 {
+    let (value, scale) = match data_type {
+        DataType::I8 => (bytes[0] as i8 as f64, 127.0),
+        DataType::U8 => (bytes[0] as f64, 255.0),
+        DataType::I16 => (LE::read_i16(bytes) as f64, 32767.0),
+        DataType::U16 => (LE::read_u16(bytes) as f64, 65535.0),
+        DataType::U32 => return LE::read_u32(bytes) as f64,
+        DataType::F32 => return LE::read_f32(bytes) as f64,
+    };
+    if normalized {
+        (value / scale).max(-1.0)
+    } else {
+        value
+    }
+}
+
+pub(super) fn write_component(data_type: DataType, normalized: bool, bytes: &mut [u8], value: f64) {
+    let integer = |scale: f64| {
+        let value = if normalized { value * scale } else { value };
+        // Round half away from zero; `as` saturates out of range values.
+        if value < 0.0 {
+            (value - 0.5) as i64
+        } else {
+            (value + 0.5) as i64
+        }
+    };
+    match data_type {
+        DataType::I8 => bytes[0] = integer(127.0).clamp(-128, 127) as i8 as u8,
+        DataType::U8 => bytes[0] = integer(255.0).clamp(0, 255) as u8,
+        DataType::I16 => LE::write_i16(bytes, integer(32767.0).clamp(-32768, 32767) as i16),
+        DataType::U16 => LE::write_u16(bytes, integer(65535.0).clamp(0, 65535) as u16),
+        DataType::U32 => LE::write_u32(bytes, integer(1.0).clamp(0, u32::MAX as i64) as u32),
+        DataType::F32 => LE::write_f32(bytes, value as f32),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use byteorder::{ByteOrder, LE};
+
+    use super::{DocumentMut, EditError, ReplaceOptions, Shrink};
+    use crate::buffer;
+
+    /// Two accessors reading the same VEC2 float buffer view, and one
+    /// normalized UNSIGNED_BYTE accessor in a view of its own.
+    fn document() -> DocumentMut {
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "buffers": [{ "byteLength": 20 }],
+            "bufferViews": [
+                { "buffer": 0, "byteLength": 16 },
+                { "buffer": 0, "byteOffset": 16, "byteLength": 4 }
+            ],
+            "accessors": [
+                { "bufferView": 0, "count": 2, "componentType": 5126, "type": "VEC2" },
+                { "bufferView": 0, "count": 1, "componentType": 5126, "type": "VEC2" },
+                { "bufferView": 1, "count": 4, "componentType": 5121, "type": "SCALAR",
+                  "normalized": true }
+            ]
+        }"#;
+        let mut bytes = vec![0; 20];
+        LE::write_f32_into(&[1.0, 2.0, 3.0, 4.0], &mut bytes[..16]);
+        bytes[16..].copy_from_slice(&[0, 51, 102, 255]);
+        DocumentMut {
+            root: json::Root::from_str(json).unwrap(),
+            buffers: vec![buffer::Data(bytes)],
+        }
+    }
+
+    #[test]
+    fn copy_on_write() {
+        let mut document = document();
+        let mut accessor = document.accessor_mut(0).unwrap();
+        accessor.set_element(1, &[-5.0, 10.0]).unwrap();
+        accessor.commit();
+
+        let root = &document.root;
+        assert_eq!(root.accessors[0].buffer_view.unwrap().value(), 2);
+        assert_eq!(root.accessors[1].buffer_view.unwrap().value(), 0);
+        assert_eq!(root.accessors[0].min, Some(serde_json::json!([-5.0, 2.0])));
+        assert_eq!(root.accessors[0].max, Some(serde_json::json!([1.0, 10.0])));
+
+        // The shared view is left untouched.
+        assert_eq!(LE::read_f32(&document.buffers[0][8..]), 3.0);
+        let mut values = [0.0; 2];
+        document
+            .accessor_mut(0)
+            .unwrap()
+            .element(1, &mut values)
+            .unwrap();
+        assert_eq!(values, [-5.0, 10.0]);
+    }
+
+    #[test]
+    fn normalized_elements() {
+        let mut document = document();
+        let mut accessor = document.accessor_mut(2).unwrap();
+        let mut value = [0.0];
+        accessor.element(1, &mut value).unwrap();
+        assert_eq!(value, [0.2]);
+        accessor.map_elements(|values| values[0] = 1.0 - values[0]);
+        drop(accessor);
+
+        assert_eq!(document.root.accessors[2].buffer_view.unwrap().value(), 1);
+        assert_eq!(&document.buffers[0][16..], &[255, 204, 153, 0]);
+        assert_eq!(document.root.accessors[2].min, Some(serde_json::json!([0])));
+        assert_eq!(
+            document.root.accessors[2].max,
+            Some(serde_json::json!([255]))
+        );
+    }
+
+    #[test]
+    fn f32_slice() {
+        let mut document = document();
+        let mut accessor = document.accessor_mut(0).unwrap();
+        accessor.as_mut_slice_f32().unwrap()[0] = 7.0;
+        accessor.commit();
+        assert_eq!(
+            document.root.accessors[0].max,
+            Some(serde_json::json!([7.0, 4.0]))
+        );
+        assert!(document
+            .accessor_mut(2)
+            .unwrap()
+            .as_mut_slice_f32()
+            .is_none());
+    }
+
+    #[test]
+    fn errors() {
+        let mut document = document();
+        assert_eq!(
+            document.accessor_mut(3).err(),
+            Some(EditError::NoSuchAccessor(3))
+        );
+        let mut accessor = document.accessor_mut(2).unwrap();
+        assert_eq!(
+            accessor.set_element(4, &[0.0]),
+            Err(EditError::ElementOutOfRange {
+                element: 4,
+                count: 4
+            })
+        );
+        assert_eq!(
+            accessor.set_element(0, &[0.0, 1.0]),
+            Err(EditError::Dimensions {
+                expected: 1,
+                actual: 2
+            })
+        );
+
+        let mut document = self::document();
+        document.root.accessors[1].component_type = json::validation::Checked::Invalid;
+        assert_eq!(
+            document.accessor_mut(1).err(),
+            Some(EditError::InvalidType(1))
+        );
+    }
+
+    /// An embedded image followed by an accessor in the same buffer, and an
+    /// image referring to a URI.
+    fn image_document() -> DocumentMut {
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "buffers": [{ "byteLength": 16 }],
+            "bufferViews": [
+                { "buffer": 0, "byteLength": 8 },
+                { "buffer": 0, "byteOffset": 8, "byteLength": 8 }
+            ],
+            "accessors": [
+                { "bufferView": 1, "count": 1, "componentType": 5126, "type": "VEC2" }
+            ],
+            "images": [
+                { "bufferView": 0, "mimeType": "image/png" },
+                { "uri": "label.png" }
+            ],
+            "textures": [{ "source": 0 }, { "source": 1 }],
+            "materials": [
+                { "pbrMetallicRoughness": { "baseColorTexture": { "index": 1 } } }
+            ]
+        }"#;
+        let mut bytes = vec![0xAA; 16];
+        LE::write_f32_into(&[1.0, 2.0], &mut bytes[8..]);
+        DocumentMut {
+            root: json::Root::from_str(json).unwrap(),
+            buffers: vec![buffer::Data(bytes)],
+        }
+    }
+
+    fn accessor_values(document: &mut DocumentMut) -> [f64; 2] {
+        let mut values = [0.0; 2];
+        document
+            .accessor_mut(0)
+            .unwrap()
+            .element(0, &mut values)
+            .unwrap();
+        values
+    }
+
+    #[test]
+    fn grow_image() {
+        let mut document = image_document();
+        document
+            .replace_image(0, vec![1; 10], "image/jpeg")
+            .unwrap();
+        let root = &document.root;
+        assert_eq!(root.buffer_views[0].byte_length.0, 10);
+        assert_eq!(root.buffer_views[1].byte_offset.unwrap().0, 12);
+        assert_eq!(root.buffers[0].byte_length.0, 20);
+        assert_eq!(root.images[0].mime_type.as_ref().unwrap().0, "image/jpeg");
+        assert_eq!(&document.buffers[0][..10], &[1; 10]);
+        assert_eq!(accessor_values(&mut document), [1.0, 2.0]);
+    }
+
+    #[test]
+    fn shrink_image() {
+        let mut document = image_document();
+        document.replace_image(0, vec![1; 3], "image/png").unwrap();
+        assert_eq!(document.root.buffer_views[0].byte_length.0, 3);
+        assert_eq!(document.root.buffer_views[1].byte_offset.unwrap().0, 8);
+        assert_eq!(document.buffers[0].len(), 16);
+
+        let mut document = image_document();
+        let options = ReplaceOptions {
+            shrink: Shrink::Repack,
+        };
+        document
+            .replace_image_with(0, vec![1; 3], "image/png", options)
+            .unwrap();
+        assert_eq!(document.root.buffer_views[1].byte_offset.unwrap().0, 4);
+        assert_eq!(document.root.buffers[0].byte_length.0, 12);
+        assert_eq!(accessor_values(&mut document), [1.0, 2.0]);
+    }
+
+    #[test]
+    fn embed_uri_image() {
+        let mut document = image_document();
+        document.replace_image(1, vec![7; 5], "image/png").unwrap();
+        let root = &document.root;
+        let image = &root.images[1];
+        assert!(image.uri.is_none());
+        assert_eq!(image.buffer_view.unwrap().value(), 2);
+        assert_eq!(root.buffer_views[2].byte_offset.unwrap().0, 16);
+        assert_eq!(root.textures[1].source.value(), 1);
+        assert_eq!(
+            root.materials[0]
+                .pbr_metallic_roughness
+                .base_color_texture
+                .as_ref()
+                .unwrap()
+                .index
+                .value(),
+            1
+        );
+        assert_eq!(
+            document.replace_image(2, vec![], "image/png"),
+            Err(EditError::NoSuchImage(2))
+        );
+        document.into_document().unwrap();
+    }
+}
@@ -0,0 +1,202 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use super::edit::shift_buffer;
+use crate::buffer;
+use crate::fault::EntityKind;
+
+/// A file moved out of the document by [`externalize`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExternalFile {
+    /// The kind of the entity that refers to the file, either
+    /// [`EntityKind::Buffer`] or [`EntityKind::Image`].
+    pub entity_kind: EntityKind,
+
+    /// The index of the entity in the rewritten document.
+    pub index: usize,
+
+    /// The name generated by the caller.
+    pub name: String,
+
+    /// The URI that the entity now refers to, which is `name` with unsafe
+    /// characters percent-encoded.
+    pub uri: String,
+
+    /// The contents of the file.
+    pub data: Vec<u8>,
+}
+
+/// Encodes every byte of `name` except unreserved characters and `/`.
+fn percent_encode(name: &str) -> String {
+    let mut uri = String::with_capacity(name.len());
+    for byte in name.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                uri.push(byte as char)
+            }
+            _ => {
+                let _ = write!(uri, "%{:02X}", byte);
+            }
+        }
+    }
+    uri
+}
+
+/// Returns `true` if another buffer view overlaps the byte range of a view.
+fn is_overlapped(root: &json::Root, view: usize) -> bool {
+    let range = |json: &json::buffer::View| {
+        let start = json.byte_offset.unwrap_or_default().0;
+        (json.buffer.value(), start, start + json.byte_length.0)
+    };
+    let (buffer, start, end) = range(&root.buffer_views[view]);
+    root.buffer_views.iter().enumerate().any(|(i, json)| {
+        let (other_buffer, other_start, other_end) = range(json);
+        i != view && other_buffer == buffer && other_start < end && start < other_end
+    })
+}
+
+/// Moves embedded buffers and images out of the document, making them refer
+/// to external files instead.
+///
+/// Every image stored in a buffer view is given the URI returned by `naming`
+/// for it, and its encoded data is returned as a file. Buffer views that no
+/// longer have any user are removed, along with their bytes; the following
+/// buffer data is moved up, keeping its alignment, and buffers left without
+/// views are removed. Every remaining buffer that is stored in the `BIN`
+/// chunk or in a `data:` URI is then given a URI and returned as a file.
+///
+/// `naming` is passed the kind and index of each entity in the rewritten
+/// document, and its name if the `names` feature is enabled. The generated
+/// names should be unique; unsafe characters are percent-encoded in the URIs.
+/// Images with `data:` URIs and buffers whose data is missing from `buffers`
+/// are left untouched.
+pub fn externalize(
+    root: &mut json::Root,
+    buffers: &[buffer::Data],
+    naming: &dyn Fn(EntityKind, usize, Option<&str>) -> String,
+) -> Vec<ExternalFile> {
+    let mut data = buffers.to_vec();
+    let mut files = Vec::new();
+
+    let mut views = Vec::new();
+    for (index, image) in root.images.iter_mut().enumerate() {
+        let view = match image.buffer_view {
+            Some(view) => view.value(),
+            None => continue,
+        };
+        let bytes = root.buffer_views.get(view).and_then(|json| {
+            let start = json.byte_offset.unwrap_or_default().0 as usize;
+            let end = start + json.byte_length.0 as usize;
+            data.get(json.buffer.value())?.get(start..end)
+        });
+        let bytes = match bytes {
+            Some(bytes) => bytes.to_vec(),
+            None => continue,
+        };
+        #[cfg(feature = "names")]
+        let name = naming(EntityKind::Image, index, image.name.as_deref());
+        #[cfg(not(feature = "names"))]
+        let name = naming(EntityKind::Image, index, None);
+        let uri = percent_encode(&name);
+        image.uri = Some(uri.as_str().into());
+        image.buffer_view = None;
+        files.push(ExternalFile {
+            entity_kind: EntityKind::Image,
+            index,
+            name,
+            uri,
+            data: bytes,
+        });
+        views.push(view);
+    }
+    views.sort_unstable();
+    views.dedup();
+
+    // Free the bytes of the views that `prune_views` is about to remove,
+    // starting from the end of each buffer so that the offsets still to be
+    // visited are not shifted.
+    let referenced = |root: &json::Root, view: usize| {
+        root.accessors.iter().any(|accessor| {
+            accessor.buffer_view.map(|x| x.value()) == Some(view)
+                || accessor.sparse.as_ref().map_or(false, |sparse| {
+                    sparse.indices.buffer_view.value() == view
+                        || sparse.values.buffer_view.value() == view
+                })
+        }) || root
+            .images
+            .iter()
+            .any(|image| image.buffer_view.map(|x| x.value()) == Some(view))
+    };
+    let mut freed: Vec<(usize, usize, usize)> = views
+        .iter()
+        .filter(|&&view| !referenced(root, view) && !is_overlapped(root, view))
+        .map(|&view| {
+            let json = &root.buffer_views[view];
+            let start = json.byte_offset.unwrap_or_default().0 as usize;
+            (json.buffer.value(), start, json.byte_length.0 as usize)
+        })
+        .collect();
+    freed.sort_unstable_by(|a, b| b.cmp(a));
+    for &(buffer, start, length) in &freed {
+        let length = length & !3;
+        if length > 0
+            && data
+                .get(buffer)
+                .map_or(false, |x| start + length <= x.len())
+        {
+            shift_buffer(root, &mut data, buffer, start + length, -(length as isize));
+        }
+    }
+    super::prune_views(root, &views);
+    let candidates: Vec<usize> = freed.iter().map(|&(buffer, ..)| buffer).collect();
+    let keep = super::prune_buffers(root, &candidates);
+    let mut buffer = 0;
+    data.retain(|_| {
+        buffer += 1;
+        keep.get(buffer - 1).copied().unwrap_or(true)
+    });
+
+    for (index, buffer) in root.buffers.iter_mut().enumerate() {
+        if !buffer
+            .uri
+            .as_deref()
+            .map_or(true, |uri| uri.starts_with("data:"))
+        {
+            continue;
+        }
+        let length = buffer.byte_length.0 as usize;
+        let bytes = match data.get(index).and_then(|data| data.get(..length)) {
+            Some(bytes) => bytes.to_vec(),
+            None => continue,
+        };
+        #[cfg(feature = "names")]
+        let name = naming(EntityKind::Buffer, index, buffer.name.as_deref());
+        #[cfg(not(feature = "names"))]
+        let name = naming(EntityKind::Buffer, index, None);
+        let uri = percent_encode(&name);
+        buffer.uri = Some(uri.as_str().into());
+        files.push(ExternalFile {
+            entity_kind: EntityKind::Buffer,
+            index,
+            name,
+            uri,
+            data: bytes,
+        });
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsafe_characters() {
+        assert_eq!(
+            percent_encode("textures/a b#1.png"),
+            "textures/a%20b%231.png"
+        );
+        assert_eq!(percent_encode("ä?.bin"), "%C3%A4%3F.bin");
+    }
+}
@@ -0,0 +1,438 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::analysis::for_each_reference;
+use crate::buffer;
+use crate::fault::EntityKind;
+use json::validation::USize64;
+use json::Index;
+
+use super::{compact, for_each_image_mut, for_each_texture_mut};
+
+/// Entities of a document selected for extraction into a new asset.
+#[derive(Clone, Debug)]
+pub(crate) struct Selection {
+    pub accessors: Vec<bool>,
+    #[cfg(feature = "animation")]
+    pub animations: Vec<bool>,
+    #[cfg(feature = "cameras")]
+    pub cameras: Vec<bool>,
+    pub images: Vec<bool>,
+    #[cfg(feature = "KHR_lights_punctual")]
+    pub lights: Vec<bool>,
+    pub materials: Vec<bool>,
+    pub meshes: Vec<bool>,
+    pub nodes: Vec<bool>,
+    pub samplers: Vec<bool>,
+    #[cfg(feature = "skinning")]
+    pub skins: Vec<bool>,
+    pub textures: Vec<bool>,
+    pub views: Vec<bool>,
+}
+
+/// Marks an item as selected, returning `true` if it was not selected before.
+fn select(items: &mut [bool], index: usize) -> bool {
+    match items.get_mut(index) {
+        Some(selected) if !*selected => {
+            *selected = true;
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(feature = "KHR_lights_punctual")]
+fn lights(root: &json::Root) -> &[json::extensions::scene::khr_lights_punctual::Light] {
+    root.as_ref()
+}
+
+#[cfg(feature = "animation")]
+fn is_selected(items: &[bool], index: usize) -> bool {
+    items.get(index).copied().unwrap_or(false)
+}
+
+impl Selection {
+    /// Creates an empty selection sized for the given document.
+    pub(crate) fn new(root: &json::Root) -> Self {
+        Self::filled(root, false)
+    }
+
+    /// Creates a selection of every entity of the document.
+    pub(crate) fn all(root: &json::Root) -> Self {
+        Self::filled(root, true)
+    }
+
+    /// Creates a selection of what the nodes of the scenes use, or what any
+    /// node uses if the document has no scenes.
+    ///
+    /// Nodes outside the scenes are selected if they are the joints or the
+    /// skeleton of a skin that a selected node uses.
+    pub(crate) fn scenes(root: &json::Root) -> Self {
+        let mut selection = Self::new(root);
+        if root.scenes.is_empty() {
+            (0..root.nodes.len()).for_each(|node| selection.node(root, node));
+        }
+        for scene in root.scenes.iter() {
+            for node in scene.nodes.iter() {
+                selection.node(root, node.value());
+            }
+        }
+        selection
+    }
+
+    fn filled(root: &json::Root, selected: bool) -> Self {
+        Self {
+            accessors: vec![selected; root.accessors.len()],
+            #[cfg(feature = "animation")]
+            animations: vec![selected; root.animations.len()],
+            #[cfg(feature = "cameras")]
+            cameras: vec![selected; root.cameras.len()],
+            images: vec![selected; root.images.len()],
+            #[cfg(feature = "KHR_lights_punctual")]
+            lights: vec![selected; lights(root).len()],
+            materials: vec![selected; root.materials.len()],
+            meshes: vec![selected; root.meshes.len()],
+            nodes: vec![selected; root.nodes.len()],
+            samplers: vec![selected; root.samplers.len()],
+            #[cfg(feature = "skinning")]
+            skins: vec![selected; root.skins.len()],
+            textures: vec![selected; root.textures.len()],
+            views: vec![selected; root.buffer_views.len()],
+        }
+    }
+
+    /// Selects a node, its descendants, and everything they reference.
+    ///
+    /// Skin joints and skeletons are selected as well, even when they lie
+    /// outside the subtree.
+    pub(crate) fn node(&mut self, root: &json::Root, index: usize) {
+        self.entity(root, EntityKind::Node, index);
+    }
+
+    /// Selects an animation together with the accessors of the samplers used by
+    /// channels targeting selected nodes.
+    ///
+    /// Nodes must be selected first; channels targeting other nodes are dropped
+    /// on extraction.
+    #[cfg(feature = "animation")]
+    pub(crate) fn animation(&mut self, root: &json::Root, index: usize) {
+        if !select(&mut self.animations, index) {
+            return;
+        }
+        let animation = &root.animations[index];
+        for channel in animation.channels.iter() {
+            if !is_selected(&self.nodes, channel.target.node.value()) {
+                continue;
+            }
+            if let Some(sampler) = animation.samplers.get(channel.sampler.value()) {
+                self.entity(root, EntityKind::Accessor, sampler.input.value());
+                self.entity(root, EntityKind::Accessor, sampler.output.value());
+            }
+        }
+    }
+
+    /// Selects an entity and everything it references, following the edges
+    /// of the [dependency graph](crate::analysis::dependency_graph).
+    ///
+    /// Primitives are selected with their mesh, and buffers are not selected
+    /// since extraction packs the selected views into a new buffer.
+    fn entity(&mut self, root: &json::Root, kind: EntityKind, index: usize) {
+        let mut stack = vec![(kind, index)];
+        while let Some((kind, index)) = stack.pop() {
+            let items = match kind {
+                EntityKind::Accessor => &mut self.accessors,
+                #[cfg(feature = "cameras")]
+                EntityKind::Camera => &mut self.cameras,
+                EntityKind::Image => &mut self.images,
+                EntityKind::Material => &mut self.materials,
+                EntityKind::Mesh => &mut self.meshes,
+                EntityKind::Node => &mut self.nodes,
+                EntityKind::Sampler => &mut self.samplers,
+                #[cfg(feature = "skinning")]
+                EntityKind::Skin => &mut self.skins,
+                EntityKind::Texture => &mut self.textures,
+                EntityKind::View => &mut self.views,
+                EntityKind::Primitive { .. } => {
+                    for_each_reference(root, kind, index, |kind, index| stack.push((kind, index)));
+                    continue;
+                }
+                _ => continue,
+            };
+            if !select(items, index) {
+                continue;
+            }
+            #[cfg(feature = "KHR_lights_punctual")]
+            if let Some(light) = root
+                .nodes
+                .get(index)
+                .filter(|_| kind == EntityKind::Node)
+                .and_then(|node| node.extensions.as_ref())
+                .and_then(|extensions| extensions.khr_lights_punctual.as_ref())
+            {
+                select(&mut self.lights, light.light.value());
+            }
+            for_each_reference(root, kind, index, |kind, index| stack.push((kind, index)));
+        }
+    }
+}
+
+/// Translates an index through a mapping built by `compact`.
+///
+/// Indices of items that were not kept are left unchanged; a selection never
+/// leaves them referenced from kept items of a valid document.
+pub(super) fn remap<T>(map: &[Option<u32>], index: Index<T>) -> Index<T> {
+    match map.get(index.value()) {
+        Some(Some(new)) => Index::new(*new),
+        _ => index,
+    }
+}
+
+/// Clones the kept items of a slice.
+fn retain<T: Clone>(items: &[T], keep: &[bool]) -> Vec<T> {
+    items
+        .iter()
+        .zip(keep)
+        .filter(|(_, &keep)| keep)
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+/// Copies the selected entities and the given scenes into a new document.
+///
+/// The data of the selected buffer views is packed into a single buffer,
+/// which is returned alongside the document. Buffer view data that is not
+/// available in `buffers` is zero-filled.
+pub(crate) fn extract(
+    root: &json::Root,
+    buffers: &[buffer::Data],
+    selection: &Selection,
+    scenes: &[usize],
+) -> (json::Root, Vec<u8>) {
+    let accessors = compact(&selection.accessors);
+    #[cfg(feature = "cameras")]
+    let cameras = compact(&selection.cameras);
+    let images = compact(&selection.images);
+    #[cfg(feature = "KHR_lights_punctual")]
+    let lights = compact(&selection.lights);
+    let materials = compact(&selection.materials);
+    let meshes = compact(&selection.meshes);
+    let nodes = compact(&selection.nodes);
+    let samplers = compact(&selection.samplers);
+    #[cfg(feature = "skinning")]
+    let skins = compact(&selection.skins);
+    let textures = compact(&selection.textures);
+    let views = compact(&selection.views);
+
+    let mut output = json::Root {
+        asset: root.asset.clone(),
+        extensions_used: root.extensions_used.clone(),
+        extensions_required: root.extensions_required.clone(),
+        extensions: root.extensions.clone(),
+        extras: root.extras.clone(),
+        ..Default::default()
+    };
+
+    let packed = retain(&root.buffer_views, &selection.views);
+    // The packed length is computed first so that the buffer is allocated
+    // once, rather than grown while the source buffers are held as well.
+    let length = packed.iter().fold(0, |length, view| {
+        (length + 3) / 4 * 4 + view.byte_length.0 as usize
+    });
+    let mut blob = Vec::with_capacity(length);
+    for view in packed {
+        while blob.len() % 4 != 0 {
+            blob.push(0);
+        }
+        let offset = blob.len();
+        let start = view.byte_offset.unwrap_or_default().0 as usize;
+        let length = view.byte_length.0 as usize;
+        let data = buffers
+            .get(view.buffer.value())
+            .and_then(|data| data.get(start..start.saturating_add(length)));
+        match data {
+            Some(data) => blob.extend_from_slice(data),
+            None => blob.resize(offset + length, 0),
+        }
+        output.buffer_views.push(json::buffer::View {
+            buffer: Index::new(0),
+            byte_offset: Some(USize64::from(offset)),
+            ..view
+        });
+    }
+    if !output.buffer_views.is_empty() {
+        output.buffers.push(json::Buffer {
+            byte_length: USize64::from(blob.len()),
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+            extensions: None,
+            extras: Default::default(),
+        });
+    }
+
+    output.accessors = retain(&root.accessors, &selection.accessors);
+    for accessor in output.accessors.iter_mut() {
+        accessor.buffer_view = accessor.buffer_view.map(|view| remap(&views, view));
+        if let Some(sparse) = accessor.sparse.as_mut() {
+            sparse.indices.buffer_view = remap(&views, sparse.indices.buffer_view);
+            sparse.values.buffer_view = remap(&views, sparse.values.buffer_view);
+        }
+    }
+
+    output.images = retain(&root.images, &selection.images);
+    for image in output.images.iter_mut() {
+        image.buffer_view = image.buffer_view.map(|view| remap(&views, view));
+    }
+
+    output.samplers = retain(&root.samplers, &selection.samplers);
+    output.textures = retain(&root.textures, &selection.textures);
+    for texture in output.textures.iter_mut() {
+        texture.sampler = texture.sampler.map(|sampler| remap(&samplers, sampler));
+        for_each_image_mut(texture, |image| *image = remap(&images, *image));
+    }
+
+    output.materials = retain(&root.materials, &selection.materials);
+    for material in output.materials.iter_mut() {
+        for_each_texture_mut(material, |texture| *texture = remap(&textures, *texture));
+    }
+
+    output.meshes = retain(&root.meshes, &selection.meshes);
+    for primitive in output
+        .meshes
+        .iter_mut()
+        .flat_map(|mesh| mesh.primitives.iter_mut())
+    {
+        for accessor in primitive.attributes.values_mut() {
+            *accessor = remap(&accessors, *accessor);
+        }
+        primitive.indices = primitive
+            .indices
+            .map(|accessor| remap(&accessors, accessor));
+        primitive.material = primitive
+            .material
+            .map(|material| remap(&materials, material));
+        for target in primitive.targets.iter_mut().flatten() {
+            for accessor in [
+                &mut target.positions,
+                &mut target.normals,
+                &mut target.tangents,
+            ] {
+                *accessor = accessor.map(|accessor| remap(&accessors, accessor));
+            }
+        }
+        #[cfg(feature = "KHR_materials_variants")]
+        if let Some(variants) = primitive
+            .extensions
+            .as_mut()
+            .and_then(|extensions| extensions.khr_materials_variants.as_mut())
+        {
+            for mapping in variants.mappings.iter_mut() {
+                if let Some(Some(material)) = materials.get(mapping.material as usize) {
+                    mapping.material = *material;
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "cameras")]
+    {
+        output.cameras = retain(&root.cameras, &selection.cameras);
+    }
+
+    #[cfg(feature = "skinning")]
+    {
+        output.skins = retain(&root.skins, &selection.skins);
+    }
+    #[cfg(feature = "skinning")]
+    for skin in output.skins.iter_mut() {
+        skin.inverse_bind_matrices = skin
+            .inverse_bind_matrices
+            .map(|accessor| remap(&accessors, accessor));
+        for joint in skin.joints.iter_mut() {
+            *joint = remap(&nodes, *joint);
+        }
+        skin.skeleton = skin.skeleton.map(|node| remap(&nodes, node));
+    }
+
+    output.nodes = retain(&root.nodes, &selection.nodes);
+    for node in output.nodes.iter_mut() {
+        #[cfg(feature = "cameras")]
+        {
+            node.camera = node.camera.map(|camera| remap(&cameras, camera));
+        }
+        node.mesh = node.mesh.map(|mesh| remap(&meshes, mesh));
+        #[cfg(feature = "skinning")]
+        {
+            node.skin = node.skin.map(|skin| remap(&skins, skin));
+        }
+        if let Some(children) = node.children.as_mut() {
+            for child in children.iter_mut() {
+                *child = remap(&nodes, *child);
+            }
+        }
+        #[cfg(feature = "KHR_lights_punctual")]
+        if let Some(light) = node
+            .extensions
+            .as_mut()
+            .and_then(|extensions| extensions.khr_lights_punctual.as_mut())
+        {
+            light.light = remap(&lights, light.light);
+        }
+    }
+
+    #[cfg(feature = "KHR_lights_punctual")]
+    if let Some(extension) = output
+        .extensions
+        .as_mut()
+        .and_then(|extensions| extensions.khr_lights_punctual.as_mut())
+    {
+        extension.lights = retain(&extension.lights, &selection.lights);
+    }
+
+    #[cfg(feature = "animation")]
+    for animation in retain(&root.animations, &selection.animations) {
+        let mut used = vec![false; animation.samplers.len()];
+        let channels: Vec<_> = animation
+            .channels
+            .iter()
+            .filter(|channel| is_selected(&selection.nodes, channel.target.node.value()))
+            .inspect(|channel| {
+                select(&mut used, channel.sampler.value());
+            })
+            .cloned()
+            .collect();
+        let animation_samplers = compact(&used);
+        output.animations.push(json::Animation {
+            channels: channels
+                .into_iter()
+                .map(|mut channel| {
+                    channel.sampler = remap(&animation_samplers, channel.sampler);
+                    channel.target.node = remap(&nodes, channel.target.node);
+                    channel
+                })
+                .collect(),
+            samplers: retain(&animation.samplers, &used)
+                .into_iter()
+                .map(|mut sampler| {
+                    sampler.input = remap(&accessors, sampler.input);
+                    sampler.output = remap(&accessors, sampler.output);
+                    sampler
+                })
+                .collect(),
+            ..animation
+        });
+    }
+
+    for &scene in scenes {
+        let mut scene = root.scenes[scene].clone();
+        for node in scene.nodes.iter_mut() {
+            *node = remap(&nodes, *node);
+        }
+        output.scenes.push(scene);
+    }
+    if !output.scenes.is_empty() {
+        output.scene = Some(Index::new(0));
+    }
+
+    (output, blob)
+}
@@ -0,0 +1,220 @@
+use alloc::vec::Vec;
+
+use crate::{buffer, Document};
+use json::Index;
+
+use super::{for_each_accessor_mut, for_each_image_mut, for_each_texture_mut};
+
+/// Adds `by` to an index.
+fn offset<T>(index: &mut Index<T>, by: usize) {
+    *index = Index::new((index.value() + by) as u32);
+}
+
+/// Appends another document and its buffer data to a document, for drawing
+/// several assets as one.
+///
+/// Every entity of `other` is added after those of `root` and its references
+/// are offset accordingly, so that the entities of both documents keep their
+/// meaning. `other_buffers` holds the data of each buffer of `other` and is
+/// appended to `buffers`, which is first padded with empty data to the
+/// number of buffers of `root`; [`export::to_glb`] then packs the buffers into
+/// one. The scenes of `other` are added as separate scenes, and the default
+/// scene of `root` is kept if it has one.
+///
+/// Names are left as they are, even where they collide. The extensions used
+/// and required by `other` are added to those of `root`, but the asset
+/// information, extras and root extensions of `root` are kept, except for
+/// the lights of `KHR_lights_punctual` and the variants of
+/// `KHR_materials_variants`, which are appended.
+///
+/// [`export::to_glb`]: crate::export::to_glb
+pub fn merge(
+    root: &mut json::Root,
+    buffers: &mut Vec<buffer::Data>,
+    other: &Document,
+    other_buffers: &[buffer::Data],
+) {
+    let mut appended = other.as_json().clone();
+    let accessors = root.accessors.len();
+    let buffer_count = root.buffers.len();
+    #[cfg(feature = "cameras")]
+    let cameras = root.cameras.len();
+    let images = root.images.len();
+    let materials = root.materials.len();
+    let meshes = root.meshes.len();
+    let nodes = root.nodes.len();
+    let samplers = root.samplers.len();
+    #[cfg(feature = "skinning")]
+    let skins = root.skins.len();
+    let textures = root.textures.len();
+    let views = root.buffer_views.len();
+    #[cfg(feature = "KHR_lights_punctual")]
+    let lights = root
+        .extensions
+        .as_ref()
+        .and_then(|extensions| extensions.khr_lights_punctual.as_ref())
+        .map_or(0, |extension| extension.lights.len());
+    #[cfg(feature = "KHR_materials_variants")]
+    let variants = root
+        .extensions
+        .as_ref()
+        .and_then(|extensions| extensions.khr_materials_variants.as_ref())
+        .map_or(0, |extension| extension.variants.len());
+
+    for view in appended.buffer_views.iter_mut() {
+        offset(&mut view.buffer, buffer_count);
+    }
+    for accessor in appended.accessors.iter_mut() {
+        if let Some(view) = accessor.buffer_view.as_mut() {
+            offset(view, views);
+        }
+        if let Some(sparse) = accessor.sparse.as_mut() {
+            offset(&mut sparse.indices.buffer_view, views);
+            offset(&mut sparse.values.buffer_view, views);
+        }
+    }
+    for image in appended.images.iter_mut() {
+        if let Some(view) = image.buffer_view.as_mut() {
+            offset(view, views);
+        }
+    }
+    for texture in appended.textures.iter_mut() {
+        if let Some(sampler) = texture.sampler.as_mut() {
+            offset(sampler, samplers);
+        }
+        for_each_image_mut(texture, |image| offset(image, images));
+    }
+    for material in appended.materials.iter_mut() {
+        for_each_texture_mut(material, |texture| offset(texture, textures));
+    }
+    for_each_accessor_mut(&mut appended, |accessor| offset(accessor, accessors));
+    for primitive in appended
+        .meshes
+        .iter_mut()
+        .flat_map(|mesh| mesh.primitives.iter_mut())
+    {
+        if let Some(material) = primitive.material.as_mut() {
+            offset(material, materials);
+        }
+        #[cfg(feature = "KHR_materials_variants")]
+        if let Some(extension) = primitive
+            .extensions
+            .as_mut()
+            .and_then(|extensions| extensions.khr_materials_variants.as_mut())
+        {
+            for mapping in extension.mappings.iter_mut() {
+                mapping.material += materials as u32;
+                mapping
+                    .variants
+                    .iter_mut()
+                    .for_each(|variant| *variant += variants as u32);
+            }
+        }
+    }
+    #[cfg(feature = "skinning")]
+    for skin in appended.skins.iter_mut() {
+        skin.joints
+            .iter_mut()
+            .for_each(|joint| offset(joint, nodes));
+        if let Some(skeleton) = skin.skeleton.as_mut() {
+            offset(skeleton, nodes);
+        }
+    }
+    for node in appended.nodes.iter_mut() {
+        #[cfg(feature = "cameras")]
+        if let Some(camera) = node.camera.as_mut() {
+            offset(camera, cameras);
+        }
+        if let Some(mesh) = node.mesh.as_mut() {
+            offset(mesh, meshes);
+        }
+        #[cfg(feature = "skinning")]
+        if let Some(skin) = node.skin.as_mut() {
+            offset(skin, skins);
+        }
+        for child in node.children.iter_mut().flatten() {
+            offset(child, nodes);
+        }
+        #[cfg(feature = "KHR_lights_punctual")]
+        if let Some(light) = node
+            .extensions
+            .as_mut()
+            .and_then(|extensions| extensions.khr_lights_punctual.as_mut())
+        {
+            offset(&mut light.light, lights);
+        }
+    }
+    #[cfg(feature = "animation")]
+    for channel in appended
+        .animations
+        .iter_mut()
+        .flat_map(|animation| animation.channels.iter_mut())
+    {
+        offset(&mut channel.target.node, nodes);
+    }
+    for scene in appended.scenes.iter_mut() {
+        scene.nodes.iter_mut().for_each(|node| offset(node, nodes));
+    }
+
+    buffers.resize_with(buffer_count, || buffer::Data(Vec::new()));
+    buffers.extend(other_buffers.iter().take(appended.buffers.len()).cloned());
+    buffers.resize_with(buffer_count + appended.buffers.len(), || {
+        buffer::Data(Vec::new())
+    });
+
+    if root.scene.is_none() {
+        root.scene = appended
+            .scene
+            .map(|scene| Index::new((scene.value() + root.scenes.len()) as u32));
+    }
+    root.accessors.append(&mut appended.accessors);
+    #[cfg(feature = "animation")]
+    root.animations.append(&mut appended.animations);
+    root.buffers.append(&mut appended.buffers);
+    root.buffer_views.append(&mut appended.buffer_views);
+    #[cfg(feature = "cameras")]
+    root.cameras.append(&mut appended.cameras);
+    root.images.append(&mut appended.images);
+    root.materials.append(&mut appended.materials);
+    root.meshes.append(&mut appended.meshes);
+    root.nodes.append(&mut appended.nodes);
+    root.samplers.append(&mut appended.samplers);
+    root.scenes.append(&mut appended.scenes);
+    #[cfg(feature = "skinning")]
+    root.skins.append(&mut appended.skins);
+    root.textures.append(&mut appended.textures);
+    for name in appended.extensions_used.iter() {
+        if !root.extensions_used.contains(name) {
+            root.extensions_used.push(name.clone());
+        }
+    }
+    for name in appended.extensions_required.iter() {
+        if !root.extensions_required.contains(name) {
+            root.extensions_required.push(name.clone());
+        }
+    }
+
+    #[allow(unused_variables)]
+    let extensions = match appended.extensions {
+        Some(extensions) => extensions,
+        None => return,
+    };
+    #[cfg(feature = "KHR_lights_punctual")]
+    if let Some(extension) = extensions.khr_lights_punctual {
+        root.extensions
+            .get_or_insert_with(Default::default)
+            .khr_lights_punctual
+            .get_or_insert_with(Default::default)
+            .lights
+            .extend(extension.lights);
+    }
+    #[cfg(feature = "KHR_materials_variants")]
+    if let Some(extension) = extensions.khr_materials_variants {
+        root.extensions
+            .get_or_insert_with(Default::default)
+            .khr_materials_variants
+            .get_or_insert_with(Default::default)
+            .variants
+            .extend(extension.variants);
+    }
+}
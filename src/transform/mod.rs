@@ -0,0 +1,476 @@
+//! Transforms operate on the JSON root together with the buffer data so that
+//! both can be edited consistently. A [`Document`](crate::Document) can be
+//! unwrapped with [`Document::into_json`](crate::Document::into_json) and
+//! rebuilt afterwards with [`Document::from_json`](crate::Document::from_json).
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::{buffer, Document};
+use json::validation::USize64;
+
+#[cfg(feature = "KHR_texture_transform")]
+mod atlas;
+mod canonicalize;
+mod convert;
+mod dedup;
+mod edit;
+mod externalize;
+mod extract;
+mod merge;
+mod narrow;
+mod normalize;
+mod orm;
+mod prune;
+mod quantize;
+mod split;
+
+#[cfg(feature = "KHR_texture_transform")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_transform")))]
+#[doc(inline)]
+pub use self::atlas::{atlas_textures, AtlasPlacement, AtlasPlan, AtlasReport, AtlasWarning};
+#[doc(inline)]
+pub use self::canonicalize::canonicalize;
+#[cfg(feature = "bake")]
+pub(crate) use self::canonicalize::hash;
+#[doc(inline)]
+pub use self::convert::convert_coordinates;
+#[doc(inline)]
+pub use self::dedup::{dedup_accessors, dedup_textures, DedupedTextures};
+pub(crate) use self::edit::read_component;
+#[doc(inline)]
+pub use self::edit::{AccessorMut, DocumentMut, EditError, ReplaceOptions, Shrink};
+#[doc(inline)]
+pub use self::externalize::{externalize, ExternalFile};
+#[doc(inline)]
+pub use self::merge::merge;
+#[doc(inline)]
+pub use self::narrow::narrow_indices;
+#[doc(inline)]
+pub use self::normalize::{normalize_scene, Normalization, NormalizeTarget};
+#[doc(inline)]
+pub use self::orm::{pack_orm, MergedImage};
+#[cfg(feature = "animation")]
+pub(crate) use self::prune::dangling_channels;
+#[doc(inline)]
+pub use self::prune::prune;
+#[cfg(feature = "animation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
+#[doc(inline)]
+pub use self::prune::{strip_dangling_channels, StrippedChannels};
+#[doc(inline)]
+pub use self::quantize::{quantize, Bits, QuantizeOptions, QuantizedAccessor};
+#[doc(inline)]
+pub use self::split::{
+    split_by_scene, split_by_scene_with, PartialAnimation, PartialAnimations, SceneSplit,
+    SplitOptions,
+};
+
+/// Appends `data` to the first buffer, creating one if the asset has none, and
+/// returns a new buffer view over it.
+///
+/// The data is aligned to a 4-byte boundary within the buffer.
+pub(crate) fn append_view(
+    root: &mut json::Root,
+    buffers: &mut Vec<buffer::Data>,
+    data: &[u8],
+) -> json::Index<json::buffer::View> {
+    if root.buffers.is_empty() {
+        root.buffers.push(json::Buffer {
+            byte_length: USize64(0),
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+            extensions: None,
+            extras: Default::default(),
+        });
+    }
+    if buffers.is_empty() {
+        buffers.push(buffer::Data(Vec::new()));
+    }
+    let bytes = &mut buffers[0].0;
+    while bytes.len() % 4 != 0 {
+        bytes.push(0);
+    }
+    let offset = bytes.len();
+    bytes.extend_from_slice(data);
+    root.buffers[0].byte_length = USize64::from(bytes.len());
+    root.push(json::buffer::View {
+        buffer: json::Index::new(0),
+        byte_length: USize64::from(data.len()),
+        byte_offset: Some(USize64::from(offset)),
+        byte_stride: None,
+        #[cfg(feature = "names")]
+        name: None,
+        target: None,
+        extensions: None,
+        extras: Default::default(),
+    })
+}
+
+/// Lends the root to a document for reading through the wrapper types, without
+/// copying it.
+///
+/// The root is left empty if `f` panics.
+pub(crate) fn with_document<R>(root: &mut json::Root, f: impl FnOnce(&Document) -> R) -> R {
+    let document = Document::from_json_without_validation(mem::take(root));
+    let result = f(&document);
+    *root = document.into_json();
+    result
+}
+
+/// Visits every accessor reference of the document.
+pub(crate) fn for_each_accessor_mut<F>(root: &mut json::Root, mut f: F)
+where
+    F: FnMut(&mut json::Index<json::Accessor>),
+{
+    for primitive in root
+        .meshes
+        .iter_mut()
+        .flat_map(|mesh| mesh.primitives.iter_mut())
+    {
+        primitive.attributes.values_mut().for_each(&mut f);
+        primitive.indices.iter_mut().for_each(&mut f);
+        for target in primitive.targets.iter_mut().flatten() {
+            target.positions.iter_mut().for_each(&mut f);
+            target.normals.iter_mut().for_each(&mut f);
+            target.tangents.iter_mut().for_each(&mut f);
+        }
+    }
+    #[cfg(feature = "skinning")]
+    for skin in root.skins.iter_mut() {
+        skin.inverse_bind_matrices.iter_mut().for_each(&mut f);
+    }
+    #[cfg(feature = "animation")]
+    for sampler in root
+        .animations
+        .iter_mut()
+        .flat_map(|animation| animation.samplers.iter_mut())
+    {
+        f(&mut sampler.input);
+        f(&mut sampler.output);
+    }
+}
+
+/// Visits every texture reference of a material.
+///
+/// With the `extensions` feature, the references of extensions that are
+/// unknown to this crate version or not enabled as crate features are visited
+/// too; see [`for_each_unknown_texture_mut`].
+pub(crate) fn for_each_texture_mut<F>(material: &mut json::Material, mut f: F)
+where
+    F: FnMut(&mut json::Index<json::Texture>),
+{
+    let pbr = &mut material.pbr_metallic_roughness;
+    if let Some(info) = pbr.base_color_texture.as_mut() {
+        f(&mut info.index);
+    }
+    if let Some(info) = pbr.metallic_roughness_texture.as_mut() {
+        f(&mut info.index);
+    }
+    if let Some(info) = material.normal_texture.as_mut() {
+        f(&mut info.index);
+    }
+    if let Some(info) = material.occlusion_texture.as_mut() {
+        f(&mut info.index);
+    }
+    if let Some(info) = material.emissive_texture.as_mut() {
+        f(&mut info.index);
+    }
+
+    #[allow(unused_variables)]
+    let extensions = match material.extensions.as_mut() {
+        Some(extensions) => extensions,
+        None => return,
+    };
+    #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+    if let Some(sg) = extensions.pbr_specular_glossiness.as_mut() {
+        if let Some(info) = sg.diffuse_texture.as_mut() {
+            f(&mut info.index);
+        }
+        if let Some(info) = sg.specular_glossiness_texture.as_mut() {
+            f(&mut info.index);
+        }
+    }
+    #[cfg(feature = "KHR_materials_transmission")]
+    if let Some(info) = extensions
+        .transmission
+        .as_mut()
+        .and_then(|transmission| transmission.transmission_texture.as_mut())
+    {
+        f(&mut info.index);
+    }
+    #[cfg(feature = "KHR_materials_volume")]
+    if let Some(info) = extensions
+        .volume
+        .as_mut()
+        .and_then(|volume| volume.thickness_texture.as_mut())
+    {
+        f(&mut info.index);
+    }
+    #[cfg(feature = "KHR_materials_specular")]
+    if let Some(specular) = extensions.specular.as_mut() {
+        if let Some(info) = specular.specular_texture.as_mut() {
+            f(&mut info.index);
+        }
+        if let Some(info) = specular.specular_color_texture.as_mut() {
+            f(&mut info.index);
+        }
+    }
+    #[cfg(feature = "extensions")]
+    for (key, value) in extensions.others.iter_mut() {
+        for_each_unknown_texture_mut(key, value, &mut f);
+    }
+}
+
+/// Visits the texture references within the JSON of an unknown material
+/// extension.
+///
+/// These follow the convention of the Khronos material extensions: an object
+/// under a key ending in `Texture` with an integral `index` is a texture info.
+#[cfg(feature = "extensions")]
+fn for_each_unknown_texture_mut<F>(key: &str, value: &mut json::Value, f: &mut F)
+where
+    F: FnMut(&mut json::Index<json::Texture>),
+{
+    match value {
+        json::Value::Object(object) => {
+            if key.ends_with("Texture") {
+                visit_unknown_index(object.get_mut("index"), &mut *f);
+            }
+            for (key, value) in object.iter_mut() {
+                for_each_unknown_texture_mut(key, value, f);
+            }
+        }
+        json::Value::Array(values) => {
+            for value in values {
+                for_each_unknown_texture_mut(key, value, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Visits an index stored in unknown extension JSON, if it is a `u32`.
+#[cfg(feature = "extensions")]
+fn visit_unknown_index<T, F>(value: Option<&mut json::Value>, f: F)
+where
+    F: FnOnce(&mut json::Index<T>),
+{
+    let value = match value {
+        Some(value) => value,
+        None => return,
+    };
+    if let Some(index) = value.as_u64().and_then(|x| u32::try_from(x).ok()) {
+        let mut index = json::Index::new(index);
+        f(&mut index);
+        *value = json::Value::from(index.value() as u64);
+    }
+}
+
+/// Visits every image reference of a texture: its source and the images of the
+/// `KHR_texture_basisu` and `EXT_texture_webp` extensions, if enabled.
+///
+/// With the `extensions` feature, the `source` of every extension that is
+/// unknown to this crate version or not enabled as a crate feature is visited
+/// too, following the convention of the Khronos texture extensions.
+pub(crate) fn for_each_image_mut<F>(texture: &mut json::Texture, mut f: F)
+where
+    F: FnMut(&mut json::Index<json::Image>),
+{
+    f(&mut texture.source);
+    #[cfg(feature = "KHR_texture_basisu")]
+    if let Some(basisu) = texture
+        .extensions
+        .as_mut()
+        .and_then(|extensions| extensions.texture_basisu.as_mut())
+    {
+        f(&mut basisu.source);
+    }
+    #[cfg(feature = "EXT_texture_webp")]
+    if let Some(webp) = texture
+        .extensions
+        .as_mut()
+        .and_then(|extensions| extensions.texture_webp.as_mut())
+    {
+        f(&mut webp.source);
+    }
+    #[cfg(feature = "extensions")]
+    for value in texture
+        .extensions
+        .iter_mut()
+        .flat_map(|extensions| extensions.others.values_mut())
+    {
+        if let json::Value::Object(object) = value {
+            visit_unknown_index(object.get_mut("source"), &mut f);
+        }
+    }
+}
+
+/// Builds a mapping from old to new indices for the items that are kept, or
+/// `None` for removed items.
+fn compact(keep: &[bool]) -> Vec<Option<u32>> {
+    let mut next = 0;
+    keep.iter()
+        .map(|&keep| {
+            keep.then(|| {
+                next += 1;
+                next - 1
+            })
+        })
+        .collect()
+}
+
+/// Removes the given textures if no material references them any more, then
+/// does the same for the images that were their sources.
+///
+/// Indices of the remaining textures and images are updated throughout the
+/// document.
+pub(crate) fn prune_textures(root: &mut json::Root, candidates: &[usize]) {
+    let mut keep = vec![true; root.textures.len()];
+    for &texture in candidates {
+        if let Some(keep) = keep.get_mut(texture) {
+            *keep = false;
+        }
+    }
+    for material in root.materials.iter_mut() {
+        for_each_texture_mut(material, |index| {
+            if let Some(keep) = keep.get_mut(index.value()) {
+                *keep = true;
+            }
+        });
+    }
+    let mut images = Vec::new();
+    for &texture in candidates {
+        if !keep.get(texture).copied().unwrap_or(true) {
+            for_each_image_mut(&mut root.textures[texture], |image| {
+                images.push(image.value())
+            });
+        }
+    }
+
+    let remap = compact(&keep);
+    let mut texture = 0;
+    root.textures.retain(|_| {
+        texture += 1;
+        keep[texture - 1]
+    });
+    for material in root.materials.iter_mut() {
+        for_each_texture_mut(material, |index| {
+            if let Some(Some(new)) = remap.get(index.value()) {
+                *index = json::Index::new(*new);
+            }
+        });
+    }
+
+    prune_images(root, &images);
+}
+
+/// Removes the given images if no texture references them any more.
+pub(crate) fn prune_images(root: &mut json::Root, candidates: &[usize]) {
+    let mut keep = vec![true; root.images.len()];
+    for &image in candidates {
+        if let Some(keep) = keep.get_mut(image) {
+            *keep = false;
+        }
+    }
+    for texture in root.textures.iter_mut() {
+        for_each_image_mut(texture, |image| {
+            if let Some(keep) = keep.get_mut(image.value()) {
+                *keep = true;
+            }
+        });
+    }
+
+    let remap = compact(&keep);
+    let mut image = 0;
+    root.images.retain(|_| {
+        image += 1;
+        keep[image - 1]
+    });
+    for texture in root.textures.iter_mut() {
+        for_each_image_mut(texture, |image| {
+            if let Some(Some(new)) = remap.get(image.value()) {
+                *image = json::Index::new(*new);
+            }
+        });
+    }
+}
+
+/// Removes the given buffer views if no accessor or image references them any
+/// more.
+pub(crate) fn prune_views(root: &mut json::Root, candidates: &[usize]) {
+    let mut keep = vec![true; root.buffer_views.len()];
+    for &view in candidates {
+        if let Some(keep) = keep.get_mut(view) {
+            *keep = false;
+        }
+    }
+    let mut mark = |view: json::Index<json::buffer::View>| {
+        if let Some(keep) = keep.get_mut(view.value()) {
+            *keep = true;
+        }
+    };
+    for accessor in root.accessors.iter() {
+        accessor.buffer_view.into_iter().for_each(&mut mark);
+        if let Some(sparse) = accessor.sparse.as_ref() {
+            mark(sparse.indices.buffer_view);
+            mark(sparse.values.buffer_view);
+        }
+    }
+    for image in root.images.iter() {
+        image.buffer_view.into_iter().for_each(&mut mark);
+    }
+
+    let map = compact(&keep);
+    let remap = |view: &mut json::Index<json::buffer::View>| {
+        if let Some(Some(new)) = map.get(view.value()) {
+            *view = json::Index::new(*new);
+        }
+    };
+    let mut view = 0;
+    root.buffer_views.retain(|_| {
+        view += 1;
+        keep[view - 1]
+    });
+    for accessor in root.accessors.iter_mut() {
+        accessor.buffer_view.iter_mut().for_each(remap);
+        if let Some(sparse) = accessor.sparse.as_mut() {
+            remap(&mut sparse.indices.buffer_view);
+            remap(&mut sparse.values.buffer_view);
+        }
+    }
+    for image in root.images.iter_mut() {
+        image.buffer_view.iter_mut().for_each(remap);
+    }
+}
+
+/// Removes the given buffers if no buffer view references them any more, and
+/// returns whether each buffer is kept.
+pub(crate) fn prune_buffers(root: &mut json::Root, candidates: &[usize]) -> Vec<bool> {
+    let mut keep = vec![true; root.buffers.len()];
+    for &buffer in candidates {
+        if let Some(keep) = keep.get_mut(buffer) {
+            *keep = false;
+        }
+    }
+    for view in root.buffer_views.iter() {
+        if let Some(keep) = keep.get_mut(view.buffer.value()) {
+            *keep = true;
+        }
+    }
+
+    let remap = compact(&keep);
+    let mut buffer = 0;
+    root.buffers.retain(|_| {
+        buffer += 1;
+        keep[buffer - 1]
+    });
+    for view in root.buffer_views.iter_mut() {
+        if let Some(Some(new)) = remap.get(view.buffer.value()) {
+            view.buffer = json::Index::new(*new);
+        }
+    }
+    keep
+}
@@ -0,0 +1,109 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use byteorder::{ByteOrder, LE};
+
+use super::edit::{is_view_shared, Owner};
+use crate::accessor::{extent, DataType};
+use crate::{buffer, saturating_usize};
+use json::accessor::GenericComponentType;
+use json::validation::{Checked, USize64};
+
+/// Stores the indices of primitives as `UNSIGNED_SHORT` where they are stored
+/// as `UNSIGNED_INT` but every index fits, returning the narrowed accessors.
+///
+/// Indices fit if they are less than 65535, which primitive restart reserves.
+/// Accessors that are used other than as indices, sparse accessors and those
+/// whose data is unavailable are left as they are. An accessor that is the
+/// only user of its buffer view is narrowed in place, shrinking the view;
+/// otherwise the narrowed indices are appended to the first buffer in a new
+/// view, and the old one is left in place for [`prune`](super::prune) to
+/// remove.
+pub fn narrow_indices(root: &mut json::Root, buffers: &mut Vec<buffer::Data>) -> Vec<usize> {
+    let mut uses = vec![0; root.accessors.len()];
+    super::for_each_accessor_mut(root, |accessor| {
+        if let Some(uses) = uses.get_mut(accessor.value()) {
+            *uses += 1;
+        }
+    });
+    let mut index_uses = vec![0; root.accessors.len()];
+    for primitive in root.meshes.iter().flat_map(|mesh| mesh.primitives.iter()) {
+        if let Some(uses) = primitive
+            .indices
+            .and_then(|indices| index_uses.get_mut(indices.value()))
+        {
+            *uses += 1;
+        }
+    }
+
+    let mut narrowed = Vec::new();
+    for index in 0..root.accessors.len() {
+        if index_uses[index] > 0 && index_uses[index] == uses[index] && narrow(root, buffers, index)
+        {
+            narrowed.push(index);
+        }
+    }
+    narrowed
+}
+
+/// Narrows the given accessor if it holds `UNSIGNED_INT` indices that fit,
+/// returning whether it did.
+fn narrow(root: &mut json::Root, buffers: &mut Vec<buffer::Data>, index: usize) -> bool {
+    let json = &root.accessors[index];
+    let is_u32 = matches!(
+        json.component_type,
+        Checked::Valid(GenericComponentType(DataType::U32))
+    );
+    if !is_u32 || json.sparse.is_some() {
+        return false;
+    }
+    let view_index = match json.buffer_view {
+        Some(view) => view.value(),
+        None => return false,
+    };
+    let view = match root.buffer_views.get(view_index) {
+        Some(view) => view,
+        None => return false,
+    };
+    let count = saturating_usize(json.count.0);
+    let stride = view.byte_stride.map_or(4, |stride| stride.0);
+    let view_offset = saturating_usize(view.byte_offset.unwrap_or_default().0);
+    let byte_offset = saturating_usize(json.byte_offset.unwrap_or_default().0);
+    let offset = view_offset.saturating_add(byte_offset);
+    let end = offset.saturating_add(extent(count, stride, 4));
+    let view_end = view_offset.saturating_add(saturating_usize(view.byte_length.0));
+    let buffer = view.buffer.value();
+    let data = match buffers.get(buffer).and_then(|data| data.get(offset..end)) {
+        Some(data) if end <= view_end => data,
+        _ => return false,
+    };
+    let read = |element: usize| LE::read_u32(&data[element * stride..]);
+    if (0..count).any(|element| read(element) >= 0xffff) {
+        return false;
+    }
+
+    if is_view_shared(root, Owner::Accessor(index), view_index) {
+        let mut narrowed = vec![0; 2 * count];
+        for element in 0..count {
+            LE::write_u16(&mut narrowed[2 * element..], read(element) as u16);
+        }
+        let view = super::append_view(root, buffers, &narrowed);
+        root.buffer_views[view.value()].target =
+            Some(Checked::Valid(json::buffer::Target::ElementArrayBuffer));
+        let accessor = &mut root.accessors[index];
+        accessor.buffer_view = Some(view);
+        accessor.byte_offset = None;
+    } else {
+        // Every index is read before it, or any later one, is overwritten,
+        // since it starts at least twice as far into the view.
+        let data = &mut buffers[buffer].0[offset..end];
+        for element in 0..count {
+            let value = LE::read_u32(&data[element * stride..]);
+            LE::write_u16(&mut data[2 * element..], value as u16);
+        }
+        let view = &mut root.buffer_views[view_index];
+        view.byte_length = USize64::from(offset + 2 * count - view_offset);
+        view.byte_stride = None;
+    }
+    root.accessors[index].component_type = Checked::Valid(GenericComponentType(DataType::U16));
+    true
+}
@@ -0,0 +1,437 @@
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem;
+
+use super::edit::{check_accessor, DocumentMut, EditError};
+use crate::convention::{Axis, Convention};
+use crate::math::Matrix4;
+use crate::mesh::{extend_bounds, BoundingBox, Semantic};
+use crate::{buffer, Document, Node};
+use json::validation::Checked;
+
+/// Options for [`normalize_scene`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NormalizeTarget {
+    /// The edge length of the cube, centered at the origin, that the scene is
+    /// fitted into.
+    ///
+    /// The default is `1.0`.
+    pub size: f32,
+
+    /// Bakes the transform into the geometry and nodes of the scene instead
+    /// of inserting a wrapper node.
+    ///
+    /// The default is `false`.
+    pub bake: bool,
+
+    /// The conventions the scene is in, whose up axis is turned into the +Y
+    /// axis of glTF. The unit scale does not matter, since the scene is
+    /// rescaled anyway.
+    ///
+    /// The default is [`Convention::Gltf`].
+    pub convention: Convention,
+}
+
+impl Default for NormalizeTarget {
+    fn default() -> Self {
+        Self {
+            size: 1.0,
+            bake: false,
+            convention: Convention::Gltf,
+        }
+    }
+}
+
+/// The transform applied by [`normalize_scene`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Normalization {
+    /// The world-space bounds of the scene before normalization.
+    pub bounds: BoundingBox,
+
+    /// The rotation applied to the scene before scaling, as a unit
+    /// quaternion `[x, y, z, w]`, from the up axis of
+    /// [`NormalizeTarget::convention`].
+    pub rotation: [f32; 4],
+
+    /// The uniform scale applied to the scene.
+    pub scale: f32,
+
+    /// The translation applied after scaling.
+    pub translation: [f32; 3],
+
+    /// The index of the inserted wrapper node, or `None` if the transform was
+    /// baked.
+    pub wrapper: Option<usize>,
+}
+
+/// Recenters and rescales a scene to fit a cube at the origin.
+///
+/// The world-space bounds of the scene are the declared bounds of the
+/// `POSITION` attributes of its meshes, transformed by the nodes instancing
+/// them. Skinned meshes are instead bounded by their positions in the rest
+/// pose, when the `utils` feature is enabled and their data is available.
+/// The largest extent of the bounds is scaled to [`NormalizeTarget::size`];
+/// a scene whose bounds are a single point is only recentered. A scene whose
+/// convention has an up axis other than +Y is rotated to be +Y up.
+///
+/// By default a wrapper node carrying the compensating translation and scale
+/// becomes the only root node of the scene, leaving everything else intact.
+/// Since animations of the former root nodes now play inside the wrapper,
+/// their motion is rescaled along with the geometry.
+///
+/// With [`NormalizeTarget::bake`], the positions and morph target
+/// displacements of the meshes, the translations of the nodes, the
+/// translation columns of the inverse bind matrices of skins and the outputs
+/// of translation animations are scaled instead, and the root nodes and their
+/// translation animations are offset. Other scenes sharing these become
+/// normalized as well; cameras and lights are left unchanged. The document is
+/// left untouched if a `POSITION` or animation accessor cannot be edited, such
+/// as a sparse one, and the error is returned. Quantized positions are
+/// rounded to their component type, so they may lose precision or saturate.
+/// A rotation is not baked but carried by a wrapper node, which is inserted
+/// only if there is one.
+///
+/// Returns `None`, leaving the document untouched, if the scene does not exist
+/// or has no bounded geometry.
+pub fn normalize_scene(
+    root: &mut json::Root,
+    buffers: &mut Vec<buffer::Data>,
+    scene: usize,
+    target: NormalizeTarget,
+) -> Result<Option<Normalization>, EditError> {
+    let frame = super::with_document(root, |document| frame(document, buffers, scene, target));
+    let Frame {
+        mut normalization,
+        up_axis,
+        nodes,
+        roots,
+    } = match frame {
+        Some(frame) => frame,
+        None => return Ok(None),
+    };
+    let Normalization {
+        rotation,
+        scale,
+        translation,
+        ..
+    } = normalization;
+
+    if target.bake {
+        // The scene is rotated after the baked translation, which is thus
+        // rotated back.
+        let baked = up_axis.rotate_from_y_up(translation);
+        bake(root, buffers, &nodes, &roots, scale, baked)?;
+        if up_axis != Axis::Y {
+            normalization.wrapper = Some(wrap(root, scene, |node| {
+                node.rotation = Some(json::scene::UnitQuaternion(rotation));
+            }));
+        }
+    } else {
+        normalization.wrapper = Some(wrap(root, scene, |node| {
+            node.rotation = (up_axis != Axis::Y).then(|| json::scene::UnitQuaternion(rotation));
+            node.scale = Some([scale; 3]);
+            node.translation = Some(translation);
+        }));
+    }
+    Ok(Some(normalization))
+}
+
+/// The transform that normalizes a scene, with the nodes it applies to.
+struct Frame {
+    normalization: Normalization,
+    up_axis: Axis,
+    nodes: Vec<usize>,
+    roots: Vec<usize>,
+}
+
+/// Computes the transform that normalizes a scene, if it exists and has
+/// bounded geometry.
+fn frame(
+    document: &Document,
+    buffers: &[buffer::Data],
+    scene: usize,
+    target: NormalizeTarget,
+) -> Option<Frame> {
+    let scene = document.scenes().nth(scene)?;
+    let bounds = scene_bounds(document, buffers, scene.walk())?;
+    let (_, up_axis) = target.convention.resolve(document);
+    let mut rotated = None;
+    extend_bounds(&mut rotated, up_axis.rotate_to_y_up(bounds.min));
+    extend_bounds(&mut rotated, up_axis.rotate_to_y_up(bounds.max));
+    let rotated = rotated.unwrap();
+    let extent = (0..3)
+        .map(|i| bounds.max[i] - bounds.min[i])
+        .fold(0.0, f32::max);
+    let scale = if extent > 0.0 {
+        target.size / extent
+    } else {
+        1.0
+    };
+    let mut translation = [0.0; 3];
+    for (i, x) in translation.iter_mut().enumerate() {
+        *x = -0.5 * (rotated.min[i] + rotated.max[i]) * scale;
+    }
+    Some(Frame {
+        normalization: Normalization {
+            bounds,
+            rotation: up_axis.rotation_to_y_up(),
+            scale,
+            translation,
+            wrapper: None,
+        },
+        up_axis,
+        nodes: scene.walk().map(|node| node.index()).collect(),
+        roots: scene.nodes().map(|node| node.index()).collect(),
+    })
+}
+
+/// Inserts a wrapper node, set up by `f`, as the only root node of a scene
+/// and returns its index.
+pub(crate) fn wrap(
+    root: &mut json::Root,
+    scene: usize,
+    f: impl FnOnce(&mut json::scene::Node),
+) -> usize {
+    let children = core::mem::take(&mut root.scenes[scene].nodes);
+    let mut node = json::scene::Node {
+        children: Some(children),
+        ..Default::default()
+    };
+    f(&mut node);
+    let wrapper = root.push(node);
+    root.scenes[scene].nodes = vec![wrapper];
+    wrapper.value()
+}
+
+/// Returns the world-space bounds of the meshes of the given nodes.
+fn scene_bounds<'a>(
+    document: &'a Document,
+    buffers: &[buffer::Data],
+    nodes: impl Iterator<Item = Node<'a>>,
+) -> Option<BoundingBox> {
+    let worlds = crate::scene::world_transforms(document);
+    let mut bounds = None;
+    for node in nodes {
+        let mesh = match node.mesh() {
+            Some(mesh) => mesh,
+            None => continue,
+        };
+        if skinned_bounds(&node, &worlds, buffers, &mut bounds) {
+            continue;
+        }
+        let local = match mesh.bounding_box() {
+            Some(local) => local,
+            None => continue,
+        };
+        let world = &worlds[node.index()];
+        for corner in 0..8 {
+            let pick = |i: usize| match corner >> i & 1 {
+                0 => local.min[i],
+                _ => local.max[i],
+            };
+            extend_bounds(
+                &mut bounds,
+                world.transform_point([pick(0), pick(1), pick(2)]),
+            );
+        }
+    }
+    bounds
+}
+
+/// Extends `bounds` by the rest pose positions of a skinned mesh, returning
+/// `false` if the node is not skinned or the positions are unavailable.
+#[cfg(all(feature = "skinning", feature = "utils"))]
+fn skinned_bounds(
+    node: &Node,
+    worlds: &[Matrix4],
+    buffers: &[buffer::Data],
+    bounds: &mut Option<BoundingBox>,
+) -> bool {
+    let (mesh, skin) = match (node.mesh(), node.skin()) {
+        (Some(mesh), Some(skin)) => (mesh, skin),
+        _ => return false,
+    };
+    let get_buffer_data = |buffer: buffer::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+    let worlds: Vec<_> = worlds.iter().map(Matrix4::as_array).collect();
+    let skin = skin.reader(get_buffer_data);
+    let joints = match crate::skin::joint_matrices(&skin, &worlds) {
+        Ok(joints) => joints,
+        Err(_) => return false,
+    };
+    let mut points = Vec::new();
+    for primitive in mesh.primitives() {
+        let reader = primitive.reader(get_buffer_data);
+        match crate::skin::apply_pose(&reader, &skin, &joints) {
+            Ok(positions) => points.extend(positions),
+            Err(_) => return false,
+        }
+    }
+    for point in points {
+        extend_bounds(bounds, point);
+    }
+    true
+}
+
+#[cfg(not(all(feature = "skinning", feature = "utils")))]
+fn skinned_bounds(
+    _: &Node,
+    _: &[Matrix4],
+    _: &[buffer::Data],
+    _: &mut Option<BoundingBox>,
+) -> bool {
+    false
+}
+
+/// Scales the geometry and translations of the given nodes and offsets the
+/// roots among them.
+///
+/// Every accessor is checked before anything is edited, so that the document
+/// is left untouched on error without keeping a copy of it.
+fn bake(
+    root: &mut json::Root,
+    buffers: &mut Vec<buffer::Data>,
+    nodes: &[usize],
+    roots: &[usize],
+    scale: f32,
+    translation: [f32; 3],
+) -> Result<(), EditError> {
+    let mut positions = BTreeSet::new();
+    #[allow(unused_mut)]
+    let mut inverse_bind_matrices = BTreeSet::new();
+    for &index in nodes {
+        let node = &root.nodes[index];
+        #[cfg(feature = "skinning")]
+        if let Some(skin) = node.skin.and_then(|skin| root.skins.get(skin.value())) {
+            inverse_bind_matrices.extend(skin.inverse_bind_matrices.map(|x| x.value()));
+        }
+        let mesh = match node.mesh.and_then(|mesh| root.meshes.get(mesh.value())) {
+            Some(mesh) => mesh,
+            None => continue,
+        };
+        for primitive in &mesh.primitives {
+            let attribute = primitive
+                .attributes
+                .get(&Checked::Valid(Semantic::Positions));
+            positions.extend(attribute.map(|x| x.value()));
+            for target in primitive.targets.iter().flatten() {
+                positions.extend(target.positions.map(|x| x.value()));
+            }
+        }
+    }
+
+    // Translation outputs, with whether they are offset with a root and
+    // hold cubic spline tangents.
+    #[allow(unused_mut)]
+    let mut outputs: Vec<(usize, bool, bool)> = Vec::new();
+    #[cfg(feature = "animation")]
+    for animation in &root.animations {
+        for channel in &animation.channels {
+            let node = channel.target.node.value();
+            if channel.target.path != Checked::Valid(json::animation::Property::Translation)
+                || !nodes.contains(&node)
+            {
+                continue;
+            }
+            let sampler = match animation.samplers.get(channel.sampler.value()) {
+                Some(sampler) => sampler,
+                None => continue,
+            };
+            let cubic = sampler.interpolation
+                == Checked::Valid(json::animation::Interpolation::CubicSpline);
+            let output = sampler.output.value();
+            if !outputs.iter().any(|&(index, _, _)| index == output) {
+                outputs.push((output, roots.contains(&node), cubic));
+            }
+        }
+    }
+
+    let expect = |index: usize, expected: usize| match check_accessor(root, buffers, index)? {
+        actual if actual == expected => Ok(()),
+        actual => Err(EditError::Dimensions { expected, actual }),
+    };
+    for &index in &positions {
+        check_accessor(root, buffers, index)?;
+    }
+    for &index in &inverse_bind_matrices {
+        expect(index, 16)?;
+    }
+    for &(index, _, _) in &outputs {
+        expect(index, 3)?;
+    }
+
+    for &index in nodes {
+        let is_root = roots.contains(&index);
+        let node = &mut root.nodes[index];
+        let offset = if is_root { translation } else { [0.0; 3] };
+        match node.matrix.as_mut() {
+            Some(matrix) => {
+                for i in 0..3 {
+                    matrix[12 + i] = matrix[12 + i] * scale + offset[i];
+                }
+            }
+            None if node.translation.is_some() || is_root => {
+                let mut t = node.translation.unwrap_or_default();
+                for i in 0..3 {
+                    t[i] = t[i] * scale + offset[i];
+                }
+                node.translation = Some(t);
+            }
+            None => {}
+        }
+    }
+
+    let mut edit = DocumentMut {
+        root: mem::take(root),
+        buffers: mem::take(buffers),
+    };
+    let result = scale_accessors(
+        &mut edit,
+        positions,
+        inverse_bind_matrices,
+        outputs,
+        scale,
+        translation,
+    );
+    *root = edit.root;
+    *buffers = edit.buffers;
+    result
+}
+
+/// Scales the accessors found by [`bake`] and offsets the translation outputs
+/// of the roots.
+fn scale_accessors(
+    edit: &mut DocumentMut,
+    positions: BTreeSet<usize>,
+    inverse_bind_matrices: BTreeSet<usize>,
+    outputs: Vec<(usize, bool, bool)>,
+    scale: f32,
+    translation: [f32; 3],
+) -> Result<(), EditError> {
+    let scale = scale as f64;
+    for index in positions {
+        edit.accessor_mut(index)?
+            .map_elements(|values| values.iter_mut().for_each(|x| *x *= scale));
+    }
+    for index in inverse_bind_matrices {
+        edit.accessor_mut(index)?
+            .map_elements(|values| values[12..15].iter_mut().for_each(|x| *x *= scale));
+    }
+    for (index, offset, cubic) in outputs {
+        let mut accessor = edit.accessor_mut(index)?;
+        for element in 0..accessor.count() {
+            let mut values = [0.0; 3];
+            accessor.element(element, &mut values)?;
+            // Cubic spline tangents are scaled but not offset.
+            let offset = offset && (!cubic || element % 3 == 1);
+            for (x, t) in values.iter_mut().zip(translation) {
+                *x *= scale;
+                if offset {
+                    *x += t as f64;
+                }
+            }
+            accessor.set_element(element, &values)?;
+        }
+    }
+    Ok(())
+}
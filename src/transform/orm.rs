@@ -0,0 +1,224 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::analysis::PackableTextures;
+use crate::buffer;
+
+/// Encoded image data produced by the merge callback of [`pack_orm`].
+#[derive(Clone, Debug)]
+pub struct MergedImage {
+    /// The encoded image data.
+    pub data: Vec<u8>,
+
+    /// The MIME type of the encoded data, e.g. `"image/png"`.
+    pub mime_type: String,
+}
+
+/// Packs separate occlusion and metallic-roughness images into single
+/// occlusion-roughness-metallic images.
+///
+/// Each entry of `packable` is typically produced by
+/// [`find_packable_textures`](crate::analysis::find_packable_textures). Since
+/// image decoding and encoding is left to the caller, `merge` receives the
+/// encoded occlusion and metallic-roughness images and returns the encoded
+/// merged image, with occlusion in the red channel, roughness in green and
+/// metallic in blue. Returning `None` leaves the material unchanged.
+///
+/// The merged image is appended to the first buffer and referenced by a new
+/// texture using the sampler of the metallic-roughness texture. Materials
+/// sharing the same pair of textures share the merged texture. The original
+/// textures and images are removed if nothing references them afterwards; the
+/// bytes of their buffer views are left in place.
+///
+/// Returns the number of materials that were rewritten.
+pub fn pack_orm<F>(
+    root: &mut json::Root,
+    buffers: &mut Vec<buffer::Data>,
+    packable: &[PackableTextures],
+    mut merge: F,
+) -> usize
+where
+    F: FnMut(&[u8], &[u8]) -> Option<MergedImage>,
+{
+    let mut merged = BTreeMap::new();
+    let mut replaced = Vec::new();
+    let mut count = 0;
+    for candidate in packable {
+        let key = (candidate.occlusion, candidate.metallic_roughness);
+        let texture = match merged.get(&key) {
+            Some(&texture) => texture,
+            None => {
+                let image = match encoded(root, buffers, candidate.occlusion)
+                    .zip(encoded(root, buffers, candidate.metallic_roughness))
+                    .and_then(|(occlusion, mr)| merge(occlusion, mr))
+                {
+                    Some(image) => image,
+                    None => continue,
+                };
+                let view = super::append_view(root, buffers, &image.data);
+                let source = root.push(json::Image {
+                    buffer_view: Some(view),
+                    mime_type: Some(json::image::MimeType(image.mime_type)),
+                    #[cfg(feature = "names")]
+                    name: None,
+                    uri: None,
+                    extensions: None,
+                    extras: Default::default(),
+                });
+                let texture = root.push(json::Texture {
+                    #[cfg(feature = "names")]
+                    name: None,
+                    sampler: root.textures[candidate.metallic_roughness].sampler,
+                    source,
+                    extensions: None,
+                    extras: Default::default(),
+                });
+                merged.insert(key, texture);
+                replaced.extend_from_slice(&[candidate.occlusion, candidate.metallic_roughness]);
+                texture
+            }
+        };
+
+        let material = &mut root.materials[candidate.material];
+        if let Some(info) = material.occlusion_texture.as_mut() {
+            info.index = texture;
+        }
+        if let Some(info) = material
+            .pbr_metallic_roughness
+            .metallic_roughness_texture
+            .as_mut()
+        {
+            info.index = texture;
+        }
+        count += 1;
+    }
+
+    super::prune_textures(root, &replaced);
+    count
+}
+
+/// Returns the encoded data of the image used by a texture.
+fn encoded<'s>(root: &json::Root, buffers: &'s [buffer::Data], texture: usize) -> Option<&'s [u8]> {
+    let image = root
+        .images
+        .get(root.textures.get(texture)?.source.value())?;
+    let view = root.buffer_views.get(image.buffer_view?.value())?;
+    let offset = view.byte_offset.unwrap_or_default().0 as usize;
+    let length = view.byte_length.0 as usize;
+    buffers
+        .get(view.buffer.value())?
+        .get(offset..offset + length)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::{analysis, buffer, transform, Document};
+
+    fn png(width: u32, height: u32, tag: u8) -> Vec<u8> {
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        png.extend_from_slice(&width.to_be_bytes());
+        png.extend_from_slice(&height.to_be_bytes());
+        png.extend_from_slice(&[tag; 4]);
+        png
+    }
+
+    fn asset(second_width: u32) -> (Document, Vec<buffer::Data>) {
+        let mut bytes = png(4, 4, 1);
+        bytes.extend(png(second_width, 4, 2));
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "buffers": [{ "byteLength": 56 }],
+            "bufferViews": [
+                { "buffer": 0, "byteLength": 28 },
+                { "buffer": 0, "byteOffset": 28, "byteLength": 28 }
+            ],
+            "images": [
+                { "bufferView": 0, "mimeType": "image/png" },
+                { "bufferView": 1, "mimeType": "image/png" }
+            ],
+            "textures": [{ "source": 0 }, { "source": 1 }],
+            "materials": [{
+                "occlusionTexture": { "index": 0 },
+                "pbrMetallicRoughness": { "metallicRoughnessTexture": { "index": 1 } }
+            }]
+        }"#;
+        let root = json::Root::from_str(json).unwrap();
+        (
+            Document::from_json_without_validation(root),
+            vec![buffer::Data(bytes)],
+        )
+    }
+
+    #[test]
+    fn find_packable_textures() {
+        let (document, buffers) = asset(4);
+        let packable = analysis::find_packable_textures(&document, &buffers);
+        assert_eq!(packable.len(), 1);
+        assert_eq!(
+            (packable[0].occlusion, packable[0].metallic_roughness),
+            (0, 1)
+        );
+
+        let (document, buffers) = asset(8);
+        assert!(analysis::find_packable_textures(&document, &buffers).is_empty());
+    }
+
+    #[test]
+    fn pack_orm() {
+        let (document, mut buffers) = asset(4);
+        let packable = analysis::find_packable_textures(&document, &buffers);
+        let mut root = document.into_json();
+        let count = transform::pack_orm(&mut root, &mut buffers, &packable, |a, b| {
+            assert_eq!((a[24], b[24]), (1, 2));
+            Some(transform::MergedImage {
+                data: png(4, 4, 3),
+                mime_type: "image/png".to_string(),
+            })
+        });
+        assert_eq!(count, 1);
+        assert_eq!(root.textures.len(), 1);
+        assert_eq!(root.images.len(), 1);
+        assert_eq!(root.textures[0].source.value(), 0);
+        let material = &root.materials[0];
+        assert_eq!(
+            material.occlusion_texture.as_ref().unwrap().index.value(),
+            0
+        );
+
+        let document = Document::from_json(root).unwrap();
+        let image = document.images().next().unwrap();
+        assert_eq!(image.view_data(&buffers).unwrap(), &png(4, 4, 3)[..]);
+    }
+
+    #[cfg(feature = "extensions")]
+    #[test]
+    fn pack_orm_remaps_unknown_extensions() {
+        let (document, mut buffers) = asset(4);
+        let packable = analysis::find_packable_textures(&document, &buffers);
+        let mut root = document.into_json();
+        let material = r#"{"extensions": {
+            "VENDOR_materials_detail": {"detailTexture": {"index": 1, "texCoord": 1}}
+        }}"#;
+        root.materials
+            .push(json::deserialize::from_str(material).unwrap());
+        let count = transform::pack_orm(&mut root, &mut buffers, &packable, |_, _| {
+            Some(transform::MergedImage {
+                data: png(4, 4, 3),
+                mime_type: "image/png".to_string(),
+            })
+        });
+        assert_eq!(count, 1);
+        assert_eq!(root.textures.len(), 2);
+        let info = &root.materials[0].occlusion_texture.as_ref().unwrap();
+        assert_eq!(info.index.value(), 1);
+        let others = &root.materials[1].extensions.as_ref().unwrap().others;
+        let detail = &others["VENDOR_materials_detail"]["detailTexture"];
+        assert_eq!(detail["index"], 0);
+        assert_eq!(detail["texCoord"], 1);
+    }
+}
@@ -0,0 +1,202 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::buffer;
+
+use super::extract::{extract, Selection};
+
+/// Removes the entities that the scenes of a document do not use.
+///
+/// Everything reachable from the nodes of the scenes is kept, or from every
+/// node if the document has no scenes. Animations are kept with the channels
+/// that target kept nodes, and removed if they target none. The data of the
+/// remaining buffer views is packed into a single buffer without a URI,
+/// suitable for a GLB `BIN` chunk, which replaces `buffers`; data that is
+/// missing from `buffers` is zero-filled.
+///
+/// Buffer views that other transforms leave without users, such as the
+/// original data of [`quantize`](super::quantize)d accessors, are removed as
+/// well.
+pub fn prune(root: &mut json::Root, buffers: &mut Vec<buffer::Data>) {
+    #[allow(unused_mut)]
+    let mut selection = Selection::scenes(root);
+    #[cfg(feature = "animation")]
+    for (index, animation) in root.animations.iter().enumerate() {
+        let targeted = animation.channels.iter().any(|channel| {
+            let node = channel.target.node.value();
+            selection.nodes.get(node).copied().unwrap_or(false)
+        });
+        if targeted {
+            selection.animation(root, index);
+        }
+    }
+
+    let scenes: Vec<usize> = (0..root.scenes.len()).collect();
+    let (mut pruned, data) = extract(root, buffers, &selection, &scenes);
+    pruned.scene = root.scene;
+    *root = pruned;
+    *buffers = match root.buffers.is_empty() {
+        true => Vec::new(),
+        false => vec![buffer::Data(data)],
+    };
+}
+
+/// Returns the channels that target nodes outside the scenes, as
+/// `(animation, channel, node)`; see [`Selection::scenes`].
+#[cfg(feature = "animation")]
+pub(crate) fn dangling_channels(root: &json::Root) -> Vec<(usize, usize, usize)> {
+    let selection = Selection::scenes(root);
+    let mut dangling = Vec::new();
+    for (index, animation) in root.animations.iter().enumerate() {
+        for (channel, json) in animation.channels.iter().enumerate() {
+            let node = json.target.node.value();
+            if !selection.nodes.get(node).copied().unwrap_or(false) {
+                dangling.push((index, channel, node));
+            }
+        }
+    }
+    dangling
+}
+
+/// The entities removed by [`strip_dangling_channels`], by their indices
+/// before the transform.
+#[cfg(feature = "animation")]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StrippedChannels {
+    /// The removed channels, as `(animation, channel, node)`.
+    pub channels: Vec<(usize, usize, usize)>,
+
+    /// The samplers that no remaining channel used, as `(animation, sampler)`.
+    pub samplers: Vec<(usize, usize)>,
+
+    /// The animations that no channel remained in.
+    pub animations: Vec<usize>,
+
+    /// The accessors that only the removed samplers used.
+    pub accessors: Vec<usize>,
+
+    /// The buffer views that only the removed accessors used.
+    pub views: Vec<usize>,
+}
+
+/// Removes the animation channels that target nodes outside the scenes, as
+/// reported by
+/// [`dangling_animation_targets`](crate::analysis::dangling_animation_targets).
+///
+/// Samplers that no remaining channel uses are removed with them, as are
+/// animations left without channels, and then the accessors and buffer views
+/// that only they used. The nodes themselves are kept. If anything is
+/// removed, the data of the remaining buffer views is packed into a single
+/// buffer as by [`prune`].
+#[cfg(feature = "animation")]
+pub fn strip_dangling_channels(
+    root: &mut json::Root,
+    buffers: &mut Vec<buffer::Data>,
+) -> StrippedChannels {
+    let mut report = StrippedChannels {
+        channels: dangling_channels(root),
+        ..Default::default()
+    };
+    if report.channels.is_empty() {
+        return report;
+    }
+
+    let mut candidates = Vec::new();
+    for (index, animation) in root.animations.iter_mut().enumerate() {
+        let dangling = |channel| {
+            report
+                .channels
+                .iter()
+                .any(|&(a, c, _)| (a, c) == (index, channel))
+        };
+        let mut channel = 0;
+        animation.channels.retain(|_| {
+            channel += 1;
+            !dangling(channel - 1)
+        });
+        let mut used = vec![false; animation.samplers.len()];
+        for channel in animation.channels.iter() {
+            if let Some(used) = used.get_mut(channel.sampler.value()) {
+                *used = true;
+            }
+        }
+        for (sampler, json) in animation.samplers.iter().enumerate() {
+            if !used[sampler] {
+                report.samplers.push((index, sampler));
+                candidates.extend([json.input.value(), json.output.value()]);
+            }
+        }
+        let map = super::compact(&used);
+        let mut sampler = 0;
+        animation.samplers.retain(|_| {
+            sampler += 1;
+            used[sampler - 1]
+        });
+        for channel in animation.channels.iter_mut() {
+            channel.sampler = super::extract::remap(&map, channel.sampler);
+        }
+        if animation.channels.is_empty() {
+            report.animations.push(index);
+        }
+    }
+
+    let mut selection = Selection::all(root);
+    for &animation in report.animations.iter() {
+        selection.animations[animation] = false;
+    }
+    let mut referenced = vec![false; root.accessors.len()];
+    super::for_each_accessor_mut(root, |index| {
+        if let Some(referenced) = referenced.get_mut(index.value()) {
+            *referenced = true;
+        }
+    });
+    candidates.sort_unstable();
+    candidates.dedup();
+    for accessor in candidates {
+        if accessor < referenced.len() && !referenced[accessor] {
+            selection.accessors[accessor] = false;
+            report.accessors.push(accessor);
+        }
+    }
+
+    let mut views = vec![false; root.buffer_views.len()];
+    let mark = |used: &mut Vec<bool>, view: json::Index<json::buffer::View>| {
+        if let Some(used) = used.get_mut(view.value()) {
+            *used = true;
+        }
+    };
+    let mut removed = vec![false; root.buffer_views.len()];
+    for (index, accessor) in root.accessors.iter().enumerate() {
+        let used = match selection.accessors[index] {
+            true => &mut views,
+            false => &mut removed,
+        };
+        accessor.buffer_view.into_iter().for_each(|x| mark(used, x));
+        if let Some(sparse) = accessor.sparse.as_ref() {
+            mark(used, sparse.indices.buffer_view);
+            mark(used, sparse.values.buffer_view);
+        }
+    }
+    for image in root.images.iter() {
+        image
+            .buffer_view
+            .into_iter()
+            .for_each(|x| mark(&mut views, x));
+    }
+    for (view, (&removed, &used)) in removed.iter().zip(views.iter()).enumerate() {
+        if removed && !used {
+            selection.views[view] = false;
+            report.views.push(view);
+        }
+    }
+
+    let scenes: Vec<usize> = (0..root.scenes.len()).collect();
+    let (mut stripped, data) = extract(root, buffers, &selection, &scenes);
+    stripped.scene = root.scene;
+    *root = stripped;
+    *buffers = match root.buffers.is_empty() {
+        true => Vec::new(),
+        false => vec![buffer::Data(data)],
+    };
+    report
+}
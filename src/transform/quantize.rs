@@ -0,0 +1,600 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use byteorder::{ByteOrder, LE};
+
+use super::edit::{read_component, write_component};
+use crate::accessor::{extent, DataType, Dimensions};
+use crate::mesh::Semantic;
+use crate::{buffer, saturating_usize};
+use json::accessor::GenericComponentType;
+use json::validation::Checked;
+
+/// The name of the extension required by quantized documents.
+const EXTENSION: &str = "KHR_mesh_quantization";
+
+/// Component bit depth of a quantized vertex attribute.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Bits {
+    /// Byte components.
+    Eight,
+
+    /// Short components.
+    Sixteen,
+}
+
+/// Options for [`quantize`].
+///
+/// A `None` bit depth leaves the corresponding attributes as floats.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct QuantizeOptions {
+    /// Bit depth of `POSITION` attributes, stored as normalized unsigned
+    /// integers. Defaults to 16 bits.
+    pub position: Option<Bits>,
+
+    /// Bit depth of `NORMAL` attributes, stored as normalized signed integers.
+    /// Defaults to 8 bits.
+    pub normal: Option<Bits>,
+
+    /// Bit depth of `TANGENT` attributes, stored as normalized signed
+    /// integers. Defaults to 8 bits.
+    pub tangent: Option<Bits>,
+
+    /// Bit depth of `TEXCOORD_n` attributes, stored as normalized unsigned
+    /// integers. Defaults to 16 bits.
+    pub tex_coord: Option<Bits>,
+
+    /// If `true`, texture coordinates with components outside `[0, 1]` are
+    /// left as floats; otherwise they are clamped. Defaults to `true`.
+    pub skip_out_of_range_tex_coords: bool,
+}
+
+impl Default for QuantizeOptions {
+    fn default() -> Self {
+        Self {
+            position: Some(Bits::Sixteen),
+            normal: Some(Bits::Eight),
+            tangent: Some(Bits::Eight),
+            tex_coord: Some(Bits::Sixteen),
+            skip_out_of_range_tex_coords: true,
+        }
+    }
+}
+
+/// An accessor rewritten by [`quantize`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantizedAccessor {
+    /// The index of the accessor.
+    pub accessor: usize,
+
+    /// The semantic the accessor is used with.
+    pub semantic: Semantic,
+
+    /// The largest absolute difference between an original and a dequantized
+    /// component, in the units of the original data.
+    pub max_error: f32,
+}
+
+/// How an accessor is referenced from the document.
+#[derive(Clone, Debug, PartialEq)]
+enum Use {
+    /// As a vertex attribute of the given mesh.
+    Attribute(usize, Semantic),
+
+    /// In any other way, which rules out quantization.
+    Other,
+}
+
+fn uses(root: &json::Root) -> Vec<Vec<Use>> {
+    let mut uses = vec![Vec::new(); root.accessors.len()];
+    let mut add = |index: usize, usage: Use| {
+        if let Some(uses) = uses.get_mut(index) {
+            uses.push(usage);
+        }
+    };
+    for (mesh, json) in root.meshes.iter().enumerate() {
+        for primitive in json.primitives.iter() {
+            for (semantic, accessor) in primitive.attributes.iter() {
+                let usage = match semantic {
+                    Checked::Valid(semantic) => Use::Attribute(mesh, semantic.clone()),
+                    Checked::Invalid => Use::Other,
+                };
+                add(accessor.value(), usage);
+            }
+            if let Some(accessor) = primitive.indices {
+                add(accessor.value(), Use::Other);
+            }
+            for target in primitive.targets.iter().flatten() {
+                let accessors = [target.positions, target.normals, target.tangents];
+                for accessor in accessors.iter().flatten() {
+                    add(accessor.value(), Use::Other);
+                }
+            }
+        }
+    }
+    #[cfg(feature = "animation")]
+    for sampler in root
+        .animations
+        .iter()
+        .flat_map(|animation| animation.samplers.iter())
+    {
+        add(sampler.input.value(), Use::Other);
+        add(sampler.output.value(), Use::Other);
+    }
+    #[cfg(feature = "skinning")]
+    for skin in root.skins.iter() {
+        if let Some(accessor) = skin.inverse_bind_matrices {
+            add(accessor.value(), Use::Other);
+        }
+    }
+    uses
+}
+
+/// Reads the components of a tightly or regularly strided float accessor.
+fn read_floats(
+    root: &json::Root,
+    buffers: &[buffer::Data],
+    index: usize,
+    dimensions: Dimensions,
+) -> Option<Vec<f32>> {
+    let accessor = &root.accessors[index];
+    let is_float = matches!(
+        accessor.component_type,
+        Checked::Valid(GenericComponentType(DataType::F32))
+    );
+    if !is_float || accessor.normalized || accessor.sparse.is_some() {
+        return None;
+    }
+    if accessor.type_ != Checked::Valid(dimensions) {
+        return None;
+    }
+    let view = root.buffer_views.get(accessor.buffer_view?.value())?;
+    let components = dimensions.multiplicity();
+    let size = 4 * components;
+    let stride = view.byte_stride.map_or(size, |stride| stride.0);
+    let count = saturating_usize(accessor.count.0);
+    let view_start = saturating_usize(view.byte_offset.unwrap_or_default().0);
+    let byte_offset = saturating_usize(accessor.byte_offset.unwrap_or_default().0);
+    let start = view_start.saturating_add(byte_offset);
+    let end = start.saturating_add(extent(count, stride, size));
+    let view_end = view_start.saturating_add(saturating_usize(view.byte_length.0));
+    let data = buffers.get(view.buffer.value())?;
+    if end > view_end || end > data.len() {
+        return None;
+    }
+    let mut values = Vec::with_capacity(count * components);
+    for element in 0..count {
+        for component in 0..components {
+            let offset = start + element * stride + 4 * component;
+            values.push(LE::read_f32(&data[offset..]));
+        }
+    }
+    Some(values)
+}
+
+/// Quantized components ready to be written to a new buffer view.
+struct Encoded {
+    data_type: DataType,
+    components: usize,
+    values: Vec<f32>,
+}
+
+impl Encoded {
+    fn new(values: Vec<f32>, components: usize, bits: Bits, signed: bool) -> Self {
+        let data_type = match (bits, signed) {
+            (Bits::Eight, true) => DataType::I8,
+            (Bits::Eight, false) => DataType::U8,
+            (Bits::Sixteen, true) => DataType::I16,
+            (Bits::Sixteen, false) => DataType::U16,
+        };
+        Self {
+            data_type,
+            components,
+            values,
+        }
+    }
+
+    /// Writes the normalized components with each element padded to a 4-byte
+    /// boundary, returning the data and its stride.
+    fn bytes(&self) -> (Vec<u8>, usize) {
+        let size = self.data_type.size();
+        let stride = (self.components * size + 3) & !3;
+        let count = self.values.len() / self.components;
+        let mut data = vec![0; count * stride];
+        for (i, &value) in self.values.iter().enumerate() {
+            let offset = (i / self.components) * stride + (i % self.components) * size;
+            write_component(self.data_type, true, &mut data[offset..], value as f64);
+        }
+        (data, stride)
+    }
+}
+
+/// Writes encoded data to a new buffer view and points the accessor at it,
+/// returning the largest absolute error relative to `original`.
+///
+/// `dequantize` maps a normalized component back to the original units.
+fn replace<F>(
+    root: &mut json::Root,
+    buffers: &mut Vec<buffer::Data>,
+    index: usize,
+    encoded: &Encoded,
+    original: &[f32],
+    dequantize: F,
+) -> f32
+where
+    F: Fn(usize, f64) -> f64,
+{
+    let (data, stride) = encoded.bytes();
+    let size = encoded.data_type.size();
+    let components = encoded.components;
+    let mut min = vec![i64::MAX; components];
+    let mut max = vec![i64::MIN; components];
+    let mut max_error = 0.0f64;
+    for (i, &value) in original.iter().enumerate() {
+        let component = i % components;
+        let offset = (i / components) * stride + component * size;
+        let stored = read_component(encoded.data_type, false, &data[offset..]) as i64;
+        min[component] = min[component].min(stored);
+        max[component] = max[component].max(stored);
+        let normalized = read_component(encoded.data_type, true, &data[offset..]);
+        let error = (dequantize(component, normalized) - value as f64).abs();
+        max_error = max_error.max(error);
+    }
+
+    let old_view = root.accessors[index].buffer_view.map(|view| view.value());
+    let target = old_view.and_then(|view| root.buffer_views[view].target);
+    let view = super::append_view(root, buffers, &data);
+    let json_view = &mut root.buffer_views[view.value()];
+    json_view.target = target;
+    if stride != components * size {
+        json_view.byte_stride = Some(json::buffer::Stride(stride));
+    }
+
+    let to_json = |values: Vec<i64>| {
+        let values: Vec<json::Value> = values.into_iter().map(json::Value::from).collect();
+        json::Value::from(values)
+    };
+    let accessor = &mut root.accessors[index];
+    accessor.buffer_view = Some(view);
+    accessor.byte_offset = None;
+    accessor.component_type = Checked::Valid(GenericComponentType(encoded.data_type));
+    accessor.normalized = true;
+    if original.is_empty() {
+        accessor.min = None;
+        accessor.max = None;
+    } else {
+        accessor.min = Some(to_json(min));
+        accessor.max = Some(to_json(max));
+    }
+    max_error as f32
+}
+
+/// Quantizes float vertex attributes to normalized integers, as permitted by
+/// the `KHR_mesh_quantization` extension, which is added to
+/// `extensionsUsed` and `extensionsRequired` if anything was quantized.
+///
+/// Positions are mapped onto the unit cube spanned by the bounds of all
+/// `POSITION` attributes of their mesh. Each node instancing the mesh has it
+/// moved to a new child node whose translation and scale undo the mapping.
+/// Meshes that are skinned or have morph targets keep float positions, as do
+/// meshes that share a `POSITION` accessor with another mesh.
+///
+/// Only float accessors that are neither sparse nor referenced other than as
+/// vertex attributes of a single semantic are quantized. The quantized data
+/// is appended to the first buffer; the original data is left in place.
+///
+/// The typed readers of [`mesh::Reader`](crate::mesh::Reader) convert the
+/// quantized attributes back to `f32`, so the resulting document can be read
+/// and validated by this crate like any other.
+///
+/// Returns the quantized accessors in index order.
+pub fn quantize(
+    root: &mut json::Root,
+    buffers: &mut Vec<buffer::Data>,
+    options: QuantizeOptions,
+) -> Vec<QuantizedAccessor> {
+    let uses = uses(root);
+    let mut quantized = Vec::new();
+
+    // Normals, tangents, and texture coordinates.
+    for (index, uses) in uses.iter().enumerate() {
+        let semantic = match uses.first() {
+            Some(Use::Attribute(_, semantic)) => semantic.clone(),
+            _ => continue,
+        };
+        let consistent = uses
+            .iter()
+            .all(|usage| matches!(usage, Use::Attribute(_, other) if *other == semantic));
+        if !consistent {
+            continue;
+        }
+        let (bits, dimensions, signed) = match semantic {
+            Semantic::Normals => (options.normal, Dimensions::Vec3, true),
+            Semantic::Tangents => (options.tangent, Dimensions::Vec4, true),
+            Semantic::TexCoords(_) => (options.tex_coord, Dimensions::Vec2, false),
+            _ => continue,
+        };
+        let bits = match bits {
+            Some(bits) => bits,
+            None => continue,
+        };
+        let mut values = match read_floats(root, buffers, index, dimensions) {
+            Some(values) => values,
+            None => continue,
+        };
+        let original = values.clone();
+        if let Semantic::TexCoords(_) = semantic {
+            let in_range = values.iter().all(|value| (0.0..=1.0).contains(value));
+            if !in_range && options.skip_out_of_range_tex_coords {
+                continue;
+            }
+            for value in values.iter_mut() {
+                *value = value.clamp(0.0, 1.0);
+            }
+        }
+        let encoded = Encoded::new(values, dimensions.multiplicity(), bits, signed);
+        let max_error = replace(root, buffers, index, &encoded, &original, |_, value| value);
+        quantized.push(QuantizedAccessor {
+            accessor: index,
+            semantic,
+            max_error,
+        });
+    }
+
+    // Positions.
+    if let Some(bits) = options.position {
+        for mesh in 0..root.meshes.len() {
+            quantize_positions(root, buffers, &uses, mesh, bits, &mut quantized);
+        }
+    }
+
+    if !quantized.is_empty() {
+        for names in [&mut root.extensions_used, &mut root.extensions_required] {
+            if !names.iter().any(|name| name == EXTENSION) {
+                names.push(String::from(EXTENSION));
+            }
+        }
+    }
+    quantized.sort_by_key(|accessor| accessor.accessor);
+    quantized
+}
+
+fn quantize_positions(
+    root: &mut json::Root,
+    buffers: &mut Vec<buffer::Data>,
+    uses: &[Vec<Use>],
+    mesh: usize,
+    bits: Bits,
+    quantized: &mut Vec<QuantizedAccessor>,
+) {
+    let json = &root.meshes[mesh];
+    if json
+        .primitives
+        .iter()
+        .any(|primitive| primitive.targets.is_some())
+    {
+        return;
+    }
+    let instances: Vec<usize> = root
+        .nodes
+        .iter()
+        .enumerate()
+        .filter(|(_, node)| node.mesh.map(|index| index.value()) == Some(mesh))
+        .map(|(index, _)| index)
+        .collect();
+    #[cfg(feature = "skinning")]
+    let skinned = instances
+        .iter()
+        .any(|&node| root.nodes[node].skin.is_some());
+    #[cfg(not(feature = "skinning"))]
+    let skinned = false;
+    if instances.is_empty() || skinned {
+        return;
+    }
+
+    let mut accessors = Vec::new();
+    for primitive in json.primitives.iter() {
+        let index = match primitive
+            .attributes
+            .get(&Checked::Valid(Semantic::Positions))
+        {
+            Some(index) => index.value(),
+            None => continue,
+        };
+        let exclusive = uses[index]
+            .iter()
+            .all(|usage| *usage == Use::Attribute(mesh, Semantic::Positions));
+        if !exclusive {
+            return;
+        }
+        if !accessors.contains(&index) {
+            accessors.push(index);
+        }
+    }
+    let mut data = Vec::with_capacity(accessors.len());
+    for &index in accessors.iter() {
+        match read_floats(root, buffers, index, Dimensions::Vec3) {
+            Some(values) => data.push(values),
+            None => return,
+        }
+    }
+
+    let mut min = [f32::INFINITY; 3];
+    let mut max = [f32::NEG_INFINITY; 3];
+    for (i, &value) in data.iter().flatten().enumerate() {
+        min[i % 3] = min[i % 3].min(value);
+        max[i % 3] = max[i % 3].max(value);
+    }
+    if data.iter().all(Vec::is_empty) {
+        return;
+    }
+    let mut scale = [1.0; 3];
+    for i in 0..3 {
+        if max[i] > min[i] {
+            scale[i] = max[i] - min[i];
+        }
+    }
+
+    for (&index, original) in accessors.iter().zip(data) {
+        let values = original
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| (value - min[i % 3]) / scale[i % 3])
+            .collect();
+        let encoded = Encoded::new(values, 3, bits, false);
+        let dequantize = |i: usize, value: f64| value * scale[i] as f64 + min[i] as f64;
+        let max_error = replace(root, buffers, index, &encoded, &original, dequantize);
+        quantized.push(QuantizedAccessor {
+            accessor: index,
+            semantic: Semantic::Positions,
+            max_error,
+        });
+    }
+
+    for node in instances {
+        let mesh = root.nodes[node].mesh.take();
+        let child = root.push(json::Node {
+            mesh,
+            translation: Some(min),
+            scale: Some(scale),
+            ..Default::default()
+        });
+        root.nodes[node]
+            .children
+            .get_or_insert_with(Vec::new)
+            .push(child);
+    }
+}
+
+#[cfg(all(test, feature = "utils"))]
+mod tests {
+    use super::*;
+    use crate::Document;
+
+    const GLTF: &str = r#"{
+        "asset": {"version": "2.0"},
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+             "min": [-1, 0, 2], "max": [1, 4, 2]},
+            {"bufferView": 0, "byteOffset": 36, "componentType": 5126, "count": 3,
+             "type": "VEC3"},
+            {"bufferView": 0, "byteOffset": 72, "componentType": 5126, "count": 3,
+             "type": "VEC2"},
+            {"bufferView": 0, "byteOffset": 96, "componentType": 5126, "count": 3,
+             "type": "VEC2"}
+        ],
+        "bufferViews": [{"buffer": 0, "byteLength": 120}],
+        "buffers": [{"byteLength": 120}],
+        "meshes": [
+            {"primitives": [
+                {"attributes": {"POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2,
+                                "TEXCOORD_1": 3}}
+            ]}
+        ],
+        "nodes": [{"mesh": 0, "translation": [5, 0, 0]}],
+        "scenes": [{"nodes": [0]}]
+    }"#;
+
+    fn document() -> (json::Root, Vec<buffer::Data>) {
+        let root = crate::Gltf::from_slice(GLTF.as_bytes())
+            .unwrap()
+            .document
+            .into_json();
+        let floats: [f32; 30] = [
+            // POSITION
+            -1.0, 0.0, 2.0, 1.0, 4.0, 2.0, 0.5, 1.0, 2.0, //
+            // NORMAL
+            0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, -1.0, 0.0, //
+            // TEXCOORD_0
+            0.0, 0.0, 0.5, 0.25, 1.0, 1.0, //
+            // TEXCOORD_1, out of range
+            0.0, 0.0, 2.0, 0.0, 1.0, -1.0,
+        ];
+        let mut data = vec![0; 120];
+        LE::write_f32_into(&floats, &mut data);
+        (root, vec![buffer::Data(data)])
+    }
+
+    fn read(document: &Document, buffers: &[buffer::Data], index: usize) -> Vec<f32> {
+        use crate::accessor::Iter;
+        let accessor = document.accessors().nth(index).unwrap();
+        let get = |buffer: crate::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+        match accessor.dimensions() {
+            Dimensions::Vec3 => match accessor.data_type() {
+                DataType::U16 => Iter::<[u16; 3]>::new(accessor, get)
+                    .unwrap()
+                    .flatten()
+                    .map(|x| x as f32 / 65535.0)
+                    .collect(),
+                DataType::I8 => Iter::<[i8; 3]>::new(accessor, get)
+                    .unwrap()
+                    .flatten()
+                    .map(|x| (x as f32 / 127.0).max(-1.0))
+                    .collect(),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn quantize_document() {
+        let (mut root, mut buffers) = document();
+        let report = quantize(&mut root, &mut buffers, QuantizeOptions::default());
+        let accessors: Vec<_> = report
+            .iter()
+            .map(|x| (x.accessor, x.semantic.clone()))
+            .collect();
+        assert_eq!(
+            accessors,
+            [
+                (0, Semantic::Positions),
+                (1, Semantic::Normals),
+                (2, Semantic::TexCoords(0)),
+            ]
+        );
+        // Half a quantization step over a 4 unit range.
+        assert!(report[0].max_error <= 2.0 / 65535.0);
+        assert!(report[1].max_error <= 0.5 / 127.0);
+        assert_eq!(root.extensions_required, [EXTENSION]);
+        assert_eq!(
+            root.accessors[0].min,
+            Some(json::Value::from(vec![0, 0, 0]))
+        );
+        assert_eq!(
+            root.accessors[0].max,
+            Some(json::Value::from(vec![65535, 65535, 0]))
+        );
+        let view = root.accessors[0].buffer_view.unwrap().value();
+        assert_eq!(root.buffer_views[view].byte_stride.map(|x| x.0), Some(8));
+
+        // The mesh moved to a child that undoes the normalization.
+        assert_eq!(root.nodes[0].mesh, None);
+        let child = &root.nodes[1];
+        assert_eq!(child.mesh.map(|x| x.value()), Some(0));
+        assert_eq!(child.translation, Some([-1.0, 0.0, 2.0]));
+        assert_eq!(child.scale, Some([2.0, 4.0, 1.0]));
+
+        let document = Document::from_json_without_validation(root);
+        let positions = read(&document, &buffers, 0);
+        assert_eq!(&positions[..6], &[0.0, 0.0, 0.0, 1.0, 1.0, 0.0]);
+        let normals = read(&document, &buffers, 1);
+        assert_eq!(&normals[..3], &[0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn clamp_tex_coords() {
+        let (mut root, mut buffers) = document();
+        let options = QuantizeOptions {
+            position: None,
+            normal: None,
+            skip_out_of_range_tex_coords: false,
+            ..Default::default()
+        };
+        let report = quantize(&mut root, &mut buffers, options);
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[1].accessor, 3);
+        assert_eq!(report[1].max_error, 1.0);
+        assert_eq!(root.nodes[0].mesh.map(|x| x.value()), Some(0));
+    }
+}
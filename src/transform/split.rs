@@ -0,0 +1,293 @@
+use alloc::vec::Vec;
+
+use crate::{buffer, Document};
+
+use super::extract::{extract, Selection};
+
+/// Specifies how [`split_by_scene_with`] treats an animation that targets
+/// nodes both inside and outside of a scene.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PartialAnimations {
+    /// Leave the animation out of the scene's asset.
+    Drop,
+
+    /// Keep only the channels that target nodes of the scene.
+    Split,
+}
+
+impl Default for PartialAnimations {
+    fn default() -> Self {
+        PartialAnimations::Drop
+    }
+}
+
+/// Options for [`split_by_scene_with`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SplitOptions {
+    /// How animations that only partly target a scene are handled.
+    pub partial_animations: PartialAnimations,
+}
+
+/// An animation that targets nodes both inside and outside of a scene.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PartialAnimation {
+    /// The index of the scene in the original document.
+    pub scene: usize,
+
+    /// The index of the animation in the original document.
+    pub animation: usize,
+}
+
+/// The result of [`split_by_scene_with`].
+#[derive(Clone, Debug)]
+pub struct SceneSplit {
+    /// One document and packed buffer per scene, in scene order.
+    pub assets: Vec<(json::Root, Vec<u8>)>,
+
+    /// Animations that only partly targeted a scene, which were dropped or
+    /// split according to the options.
+    pub partial_animations: Vec<PartialAnimation>,
+}
+
+/// Splits a document into one independent asset per scene.
+///
+/// Animations that target nodes outside of a scene are dropped from it. See
+/// [`split_by_scene_with`] for details.
+pub fn split_by_scene(document: &Document, buffers: &[buffer::Data]) -> Vec<(json::Root, Vec<u8>)> {
+    split_by_scene_with(document, buffers, SplitOptions::default()).assets
+}
+
+/// Splits a document into one independent asset per scene.
+///
+/// Each asset is a deep copy that keeps only the entities reachable from its
+/// scene, with the data of all its buffer views packed into a single buffer
+/// suitable for a GLB `BIN` chunk. An animation belongs to a scene if every
+/// node it targets does; animations that target none of the scene's nodes are
+/// left out.
+#[cfg_attr(not(feature = "animation"), allow(unused_variables))]
+pub fn split_by_scene_with(
+    document: &Document,
+    buffers: &[buffer::Data],
+    options: SplitOptions,
+) -> SceneSplit {
+    let root = document.as_json();
+    let mut split = SceneSplit {
+        assets: Vec::with_capacity(root.scenes.len()),
+        partial_animations: Vec::new(),
+    };
+    for (scene, json) in root.scenes.iter().enumerate() {
+        let mut selection = Selection::new(root);
+        for node in json.nodes.iter() {
+            selection.node(root, node.value());
+        }
+        #[cfg(feature = "animation")]
+        for (index, animation) in root.animations.iter().enumerate() {
+            let targeted = animation
+                .channels
+                .iter()
+                .filter(|channel| {
+                    let node = channel.target.node.value();
+                    selection.nodes.get(node).copied().unwrap_or(false)
+                })
+                .count();
+            if targeted == 0 {
+                continue;
+            }
+            if targeted < animation.channels.len() {
+                split.partial_animations.push(PartialAnimation {
+                    scene,
+                    animation: index,
+                });
+                if options.partial_animations == PartialAnimations::Drop {
+                    continue;
+                }
+            }
+            selection.animation(root, index);
+        }
+        split
+            .assets
+            .push(extract(root, buffers, &selection, &[scene]));
+    }
+    split
+}
+
+#[cfg(all(test, feature = "animation"))]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use json::validation::USize64;
+
+    fn node(children: &[u32]) -> json::Node {
+        json::Node {
+            children: Some(
+                children
+                    .iter()
+                    .map(|&child| json::Index::new(child))
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    fn accessor(view: u32) -> json::Accessor {
+        json::Accessor {
+            buffer_view: Some(json::Index::new(view)),
+            byte_offset: None,
+            count: USize64(1),
+            component_type: json::validation::Checked::Valid(json::accessor::GenericComponentType(
+                json::accessor::ComponentType::F32,
+            )),
+            extensions: None,
+            extras: Default::default(),
+            type_: json::validation::Checked::Valid(json::accessor::Type::Scalar),
+            min: None,
+            max: None,
+            #[cfg(feature = "names")]
+            name: None,
+            normalized: false,
+            sparse: None,
+        }
+    }
+
+    fn view(offset: u64) -> json::buffer::View {
+        json::buffer::View {
+            buffer: json::Index::new(0),
+            byte_length: USize64(4),
+            byte_offset: Some(USize64(offset)),
+            byte_stride: None,
+            #[cfg(feature = "names")]
+            name: None,
+            target: None,
+            extensions: None,
+            extras: Default::default(),
+        }
+    }
+
+    fn sampler(input: u32, output: u32) -> json::animation::Sampler {
+        json::animation::Sampler {
+            extensions: None,
+            extras: Default::default(),
+            input: json::Index::new(input),
+            interpolation: Default::default(),
+            output: json::Index::new(output),
+        }
+    }
+
+    fn channel(sampler: u32, node: u32) -> json::animation::Channel {
+        json::animation::Channel {
+            sampler: json::Index::new(sampler),
+            target: json::animation::Target {
+                extensions: None,
+                extras: Default::default(),
+                node: json::Index::new(node),
+                path: json::validation::Checked::Valid(json::animation::Property::Translation),
+            },
+            extensions: None,
+            extras: Default::default(),
+        }
+    }
+
+    /// Two scenes of two nodes each, with one animation per scene and one
+    /// animation targeting both scenes.
+    fn document() -> (Document, Vec<buffer::Data>) {
+        let scene = |nodes: &[u32]| json::Scene {
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: None,
+            nodes: nodes.iter().map(|&node| json::Index::new(node)).collect(),
+        };
+        let animation = |samplers, channels| json::Animation {
+            extensions: None,
+            extras: Default::default(),
+            channels,
+            #[cfg(feature = "names")]
+            name: None,
+            samplers,
+        };
+        let root = json::Root {
+            accessors: (0..4).map(accessor).collect(),
+            animations: vec![
+                animation(vec![sampler(0, 1)], vec![channel(0, 1)]),
+                animation(vec![sampler(2, 3)], vec![channel(0, 3)]),
+                animation(
+                    vec![sampler(0, 1), sampler(2, 3)],
+                    vec![channel(0, 1), channel(1, 3)],
+                ),
+            ],
+            buffers: vec![json::Buffer {
+                byte_length: USize64(16),
+                #[cfg(feature = "names")]
+                name: None,
+                uri: None,
+                extensions: None,
+                extras: Default::default(),
+            }],
+            buffer_views: (0..4).map(|i| view(4 * i)).collect(),
+            nodes: vec![node(&[1]), node(&[]), node(&[3]), node(&[])],
+            scenes: vec![scene(&[0]), scene(&[2])],
+            ..Default::default()
+        };
+        let data = (0..16).collect();
+        (
+            Document::from_json_without_validation(root),
+            vec![buffer::Data(data)],
+        )
+    }
+
+    #[test]
+    fn drop_partial_animations() {
+        let (document, buffers) = document();
+        let split = split_by_scene_with(&document, &buffers, SplitOptions::default());
+        assert_eq!(
+            split.partial_animations,
+            [
+                PartialAnimation {
+                    scene: 0,
+                    animation: 2
+                },
+                PartialAnimation {
+                    scene: 1,
+                    animation: 2
+                },
+            ]
+        );
+
+        let (root, blob) = &split.assets[1];
+        assert_eq!(root.nodes.len(), 2);
+        assert_eq!(root.nodes[0].children.as_ref().unwrap()[0].value(), 1);
+        assert_eq!(root.scenes[0].nodes[0].value(), 0);
+        assert_eq!(root.animations.len(), 1);
+        assert_eq!(root.accessors.len(), 2);
+        assert_eq!(root.buffer_views[1].byte_offset, Some(USize64(4)));
+        assert_eq!(root.buffers[0].byte_length, USize64(8));
+        assert_eq!(blob, &[8, 9, 10, 11, 12, 13, 14, 15]);
+    }
+
+    #[test]
+    fn split_partial_animations() {
+        let (document, buffers) = document();
+        let options = SplitOptions {
+            partial_animations: PartialAnimations::Split,
+        };
+        let split = split_by_scene_with(&document, &buffers, options);
+        let (root, _) = &split.assets[1];
+        assert_eq!(root.animations.len(), 2);
+        let animation = &root.animations[1];
+        assert_eq!(animation.channels.len(), 1);
+        assert_eq!(animation.samplers.len(), 1);
+        assert_eq!(animation.channels[0].sampler.value(), 0);
+        assert_eq!(animation.channels[0].target.node.value(), 1);
+        assert_eq!(animation.samplers[0].input.value(), 0);
+        assert_eq!(animation.samplers[0].output.value(), 1);
+    }
+
+    #[test]
+    fn assets_are_valid() {
+        let (document, buffers) = document();
+        for (root, blob) in split_by_scene(&document, &buffers) {
+            assert_eq!(root.buffers[0].byte_length.0 as usize, blob.len());
+            Document::from_json(root).unwrap();
+        }
+    }
+}
@@ -0,0 +1,115 @@
+#![cfg(all(feature = "utils", feature = "animation"))]
+
+use std::{fs, thread};
+
+use gltf::accessor::AccessorLayoutCache;
+use gltf::animation::util::ReadOutputs;
+use gltf::{buffer, Gltf};
+
+fn load() -> (gltf::Document, Vec<buffer::Data>) {
+    let gltf = Gltf::from_slice(&fs::read("tests/box_sparse.gltf").unwrap()).unwrap();
+    let data = fs::read("tests/box_sparse.bin").unwrap();
+    (gltf.document, vec![buffer::Data(data)])
+}
+
+/// Returns everything the mesh and channel readers read, with or without
+/// the cache.
+fn read_all(
+    document: &gltf::Document,
+    buffers: &[buffer::Data],
+    cache: Option<&AccessorLayoutCache>,
+) -> String {
+    let get = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let mut read = String::new();
+    for mesh in document.meshes() {
+        for primitive in mesh.primitives() {
+            let reader = match cache {
+                Some(cache) => primitive.reader_cached(cache, get),
+                None => primitive.reader(get),
+            };
+            let positions: Option<Vec<_>> = reader.read_positions().map(Iterator::collect);
+            let indices: Option<Vec<_>> = reader
+                .read_indices()
+                .map(|indices| indices.into_u32().collect());
+            read += &format!("{:?} {:?}\n", positions, indices);
+            for (positions, normals, tangents) in reader.read_morph_targets() {
+                let positions: Option<Vec<_>> = positions.map(Iterator::collect);
+                let normals: Option<Vec<_>> = normals.map(Iterator::collect);
+                let tangents: Option<Vec<_>> = tangents.map(Iterator::collect);
+                read += &format!("{:?} {:?} {:?}\n", positions, normals, tangents);
+            }
+        }
+    }
+    for animation in document.animations() {
+        for channel in animation.channels() {
+            let reader = match cache {
+                Some(cache) => channel.reader_cached(cache, get),
+                None => channel.reader(get),
+            };
+            let inputs: Option<Vec<_>> = reader.read_inputs().map(Iterator::collect);
+            let outputs: Option<Vec<_>> = match reader.read_outputs() {
+                Some(ReadOutputs::MorphTargetWeights(weights)) => {
+                    Some(weights.into_f32().collect())
+                }
+                _ => None,
+            };
+            read += &format!("{:?} {:?}\n", inputs, outputs);
+        }
+    }
+    read
+}
+
+#[test]
+fn cached_reads_match() {
+    let (document, buffers) = load();
+    let expected = read_all(&document, &buffers, None);
+    // The morph target positions and the weights are sparse.
+    assert!(expected.contains("Some([[-0.5, 0.0, -0.5], [0.0, 0.0, 0.0]"));
+    assert!(expected.contains("Some([1.0, 2.0]) Some([0.0, 1.0])"));
+
+    let mut cache = AccessorLayoutCache::new(&document);
+    assert_eq!(cache.cached(), 0);
+    assert_eq!(read_all(&document, &buffers, Some(&cache)), expected);
+    let cached = cache.cached();
+    assert_eq!(cached, document.accessors().len());
+    assert_eq!(read_all(&document, &buffers, Some(&cache)), expected);
+    assert_eq!(cache.cached(), cached);
+
+    cache.invalidate();
+    assert_eq!(cache.cached(), 0);
+    assert_eq!(read_all(&document, &buffers, Some(&cache)), expected);
+}
+
+#[test]
+fn missing_sparse_indices_are_not_cached() {
+    let (document, buffers) = load();
+    let cache = AccessorLayoutCache::new(&document);
+    let empty = vec![buffer::Data(Vec::new())];
+    let read = read_all(&document, &empty, Some(&cache));
+    assert_eq!(read, read_all(&document, &empty, None));
+    let sparse = document
+        .accessors()
+        .filter(|x| x.sparse().is_some())
+        .count();
+    assert_eq!(cache.cached(), document.accessors().len() - sparse);
+
+    let expected = read_all(&document, &buffers, None);
+    assert_eq!(read_all(&document, &buffers, Some(&cache)), expected);
+    assert_eq!(cache.cached(), document.accessors().len());
+}
+
+#[test]
+fn shared_between_threads() {
+    let (document, buffers) = load();
+    let expected = read_all(&document, &buffers, None);
+    let cache = AccessorLayoutCache::new(&document);
+    thread::scope(|scope| {
+        let threads: Vec<_> = (0..4)
+            .map(|_| scope.spawn(|| read_all(&document, &buffers, Some(&cache))))
+            .collect();
+        for thread in threads {
+            assert_eq!(thread.join().unwrap(), expected);
+        }
+    });
+    assert_eq!(cache.cached(), document.accessors().len());
+}
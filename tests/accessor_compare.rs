@@ -0,0 +1,95 @@
+use gltf::accessor::{self, CompareOptions, Comparison};
+use gltf::{buffer, Gltf};
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 80}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 24, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 2},
+        {"buffer": 0, "byteOffset": 40, "byteLength": 16},
+        {"buffer": 0, "byteOffset": 56, "byteLength": 24}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC2"},
+        {"bufferView": 1, "componentType": 5123, "count": 3, "type": "VEC2",
+         "normalized": true},
+        {"componentType": 5126, "count": 3, "type": "VEC2",
+         "sparse": {"count": 2,
+                    "indices": {"bufferView": 2, "componentType": 5121},
+                    "values": {"bufferView": 3}}},
+        {"bufferView": 4, "componentType": 5126, "count": 3, "type": "VEC2"},
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC2"}
+    ]
+}"#;
+
+fn f32s(values: &[f32]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect()
+}
+
+fn load() -> (gltf::Document, buffer::Data) {
+    let mut data = f32s(&[0.0, 0.0, 0.5, 0.25, 1.0, 0.75]);
+    for value in [0u16, 0, 32768, 16384, 65535, 49151] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(&[1, 2, 0, 0]);
+    data.extend(f32s(&[0.5, 0.25, 1.0, 0.75]));
+    data.extend(f32s(&[0.0, 0.0, 0.5, 0.25, 1.0, 0.8]));
+    let document = Gltf::from_slice(GLTF.as_bytes()).unwrap().document;
+    (document, buffer::Data(data))
+}
+
+#[test]
+fn bytes() {
+    let (document, data) = load();
+    let get = |_: buffer::Buffer| Some(&data.0[..]);
+    let accessor = |index| document.accessors().nth(index).unwrap();
+    let eq = |a, b| accessor::content_eq((accessor(a), get), (accessor(b), get)).unwrap();
+    let hash = |index| accessor::content_hash(&accessor(index), get).unwrap();
+
+    // Sparse accessors compare by their effective values.
+    assert!(eq(0, 2));
+    assert_eq!(hash(0), hash(2));
+    assert!(!eq(0, 1));
+    assert!(!eq(0, 3));
+    assert!(!eq(0, 4));
+    assert_ne!(hash(0), hash(3));
+    assert_ne!(hash(0), hash(4));
+}
+
+#[test]
+fn values() {
+    let (document, data) = load();
+    let get = |_: buffer::Buffer| Some(&data.0[..]);
+    let accessor = |index| document.accessors().nth(index).unwrap();
+    let eq = |a, b, epsilon| {
+        let options = CompareOptions {
+            comparison: Comparison::Values { epsilon },
+        };
+        accessor::content_eq_with((accessor(a), get), (accessor(b), get), options).unwrap()
+    };
+
+    // Normalized integers compare with floats after mapping.
+    assert!(eq(1, 0, 1e-4));
+    assert!(!eq(1, 0, 1e-6));
+    assert!(eq(2, 1, 1e-4));
+    assert!(!eq(0, 3, 0.01));
+    assert!(eq(0, 3, 0.1));
+}
+
+#[test]
+fn missing_data() {
+    let (document, data) = load();
+    let a = document.accessors().next().unwrap();
+    let b = document.accessors().nth(3).unwrap();
+    let result = accessor::content_eq((a.clone(), |_| Some(&data.0[..])), (b, |_| None));
+    assert!(matches!(
+        result,
+        Err(accessor::Error::MissingData { accessor: 3, .. })
+    ));
+    assert!(accessor::content_hash(&a, |_| Some(&data.0[..8])).is_err());
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "utils")]
+
+use gltf::accessor::Iter;
+use gltf::Gltf;
+
+/// Six strided `VEC2` elements, six `u16` indices, and the sparse indices and
+/// values substituting elements 1, 2 and 5.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 128}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 72, "byteStride": 12},
+        {"buffer": 0, "byteOffset": 72, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 84, "byteLength": 3},
+        {"buffer": 0, "byteOffset": 88, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 112, "byteLength": 12}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 6, "type": "VEC2"},
+        {"bufferView": 1, "componentType": 5123, "count": 6, "type": "SCALAR"},
+        {"bufferView": 0, "componentType": 5126, "count": 6, "type": "VEC2",
+         "sparse": {"count": 3,
+                    "indices": {"bufferView": 2, "componentType": 5121},
+                    "values": {"bufferView": 3}}},
+        {"componentType": 5126, "count": 6, "type": "SCALAR",
+         "sparse": {"count": 3,
+                    "indices": {"bufferView": 2, "componentType": 5121},
+                    "values": {"bufferView": 4}}}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"TEXCOORD_0": 0}, "indices": 1}]}]
+}"#;
+
+fn data() -> Vec<u8> {
+    let mut data = Vec::new();
+    for i in 0..6 {
+        for x in [i as f32, -(i as f32), 99.0] {
+            data.extend_from_slice(&x.to_le_bytes());
+        }
+    }
+    for i in [5u16, 4, 3, 2, 1, 0] {
+        data.extend_from_slice(&i.to_le_bytes());
+    }
+    data.extend_from_slice(&[1, 2, 5, 0]);
+    for x in [10.0f32, 11.0, 20.0, 21.0, 50.0, 51.0, 0.5, 1.5, 2.5] {
+        data.extend_from_slice(&x.to_le_bytes());
+    }
+    data.resize(128, 0);
+    data
+}
+
+/// Checks every range and `nth` offset of an accessor against a full read.
+fn check<T: gltf::accessor::Item + Clone + PartialEq + std::fmt::Debug>(accessor: usize) {
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let data = data();
+    let accessor = gltf.accessors().nth(accessor).unwrap();
+    let iter = Iter::<T>::new(accessor, |_| Some(&data[..])).unwrap();
+    let full: Vec<T> = iter.clone().collect();
+    assert_eq!(full.len(), 6);
+
+    for start in 0..=full.len() {
+        for end in start..=full.len() {
+            let range: Vec<T> = iter.clone().slice(start..end).unwrap().collect();
+            assert_eq!(range, full[start..end], "{}..{}", start, end);
+            let iter = iter.clone().slice(start..end).unwrap();
+            assert_eq!(iter.len(), end - start);
+        }
+        assert_eq!(iter.clone().nth(start), full.get(start).cloned());
+        let skipped: Vec<T> = iter.clone().skip(start).collect();
+        assert_eq!(skipped, full[start..]);
+    }
+
+    // Ranges are relative to the remaining items.
+    let mut tail = iter.clone();
+    tail.next();
+    let range: Vec<T> = tail.slice(1..3).unwrap().collect();
+    assert_eq!(range, full[2..4]);
+
+    assert!(iter.clone().slice(0..7).is_none());
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = iter.slice(3..2);
+    assert!(reversed.is_none());
+}
+
+#[test]
+fn dense_ranges() {
+    check::<[f32; 2]>(0);
+    check::<u16>(1);
+}
+
+#[test]
+fn sparse_ranges() {
+    check::<[f32; 2]>(2);
+    check::<f32>(3);
+
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let data = data();
+    let accessor = gltf.accessors().nth(3).unwrap();
+    let values: Vec<f32> = Iter::new(accessor, |_| Some(&data[..])).unwrap().collect();
+    assert_eq!(values, [0.0, 0.5, 1.5, 0.0, 0.0, 2.5]);
+}
+
+#[test]
+fn reader_ranges() {
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let data = data();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&data[..]));
+    let indices = reader.read_indices().unwrap().slice(2..5).unwrap();
+    assert_eq!(indices.into_u32().collect::<Vec<_>>(), [3, 2, 1]);
+    let tex_coords = reader.read_tex_coords(0).unwrap().slice(4..6).unwrap();
+    let tex_coords: Vec<_> = tex_coords.into_f32().collect();
+    assert_eq!(tex_coords, [[4.0, -4.0], [5.0, -5.0]]);
+    assert!(reader.read_indices().unwrap().slice(5..7).is_none());
+}
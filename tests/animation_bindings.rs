@@ -0,0 +1,28 @@
+#![cfg(all(feature = "serde", feature = "animation"))]
+
+use std::fs;
+
+use gltf::animation::{AnimationBindings, Property};
+use gltf::Gltf;
+
+#[test]
+fn bake_and_reload() {
+    let gltf = Gltf::from_slice(&fs::read("tests/box_sparse.gltf").unwrap()).unwrap();
+    let bindings = gltf.document.animation_bindings();
+    assert!(!bindings.entries.is_empty());
+    for animation in gltf.document.animations() {
+        for channel in animation.channels() {
+            let target = channel.target();
+            let binding = bindings.get(animation.index(), target.node().index());
+            assert!(binding.unwrap().targets_mask.contains(target.property()));
+        }
+    }
+
+    let baked = serde_json::to_vec(&bindings).unwrap();
+    let text = std::str::from_utf8(&baked).unwrap();
+    assert!(text.contains("\"property\":\"weights\""), "{}", text);
+    let reloaded: AnimationBindings = serde_json::from_slice(&baked).unwrap();
+    assert_eq!(reloaded, bindings);
+    let (_, binding) = reloaded.entries[0].clone();
+    assert_eq!(binding.channel(Property::MorphTargetWeights), Some(0));
+}
@@ -0,0 +1,124 @@
+#![cfg(all(feature = "animation", feature = "import", feature = "utils"))]
+
+use gltf::animation::util::{CubicKeyframe, CubicKeyframes, ReadOutputs, Rotations};
+
+/// Two keyframes of a `CUBICSPLINE` translation, of a linear rotation of
+/// normalized `i16`s, and of `CUBICSPLINE` weights of two morph targets.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "nodes": [{}],
+    "animations": [{
+        "channels": [
+            {"sampler": 0, "target": {"node": 0, "path": "translation"}},
+            {"sampler": 1, "target": {"node": 0, "path": "rotation"}},
+            {"sampler": 2, "target": {"node": 0, "path": "weights"}}
+        ],
+        "samplers": [
+            {"input": 0, "output": 1, "interpolation": "CUBICSPLINE"},
+            {"input": 0, "output": 2},
+            {"input": 0, "output": 3, "interpolation": "CUBICSPLINE"}
+        ]
+    }],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR",
+         "min": [0], "max": [1]},
+        {"bufferView": 1, "componentType": 5126, "count": 6, "type": "VEC3"},
+        {"bufferView": 2, "componentType": 5122, "normalized": true, "count": 2, "type": "VEC4"},
+        {"bufferView": 3, "componentType": 5126, "count": 12, "type": "SCALAR"}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 8},
+        {"buffer": 0, "byteOffset": 8, "byteLength": 72},
+        {"buffer": 0, "byteOffset": 80, "byteLength": 16},
+        {"buffer": 0, "byteOffset": 96, "byteLength": 48}
+    ],
+    "buffers": [{"byteLength": 144, "uri": "data:application/octet-stream;base64,AAAAAAAAgD8AAAAAAACAPwAAAEAAAEBAAACAQAAAoEAAAMBAAADgQAAAAEEAABBBAAAgQQAAMEEAAEBBAABQQQAAYEEAAHBBAACAQQAAiEEAAAAAAAD/fwCAAAAAAABAAAAAAM3MzD3NzEw+mpmZPs3MzD4AAAA/mpkZPzMzMz/NzEw/ZmZmPwAAgD/NzIw/"}]
+}"#;
+
+#[test]
+fn outputs_are_typed_by_target() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    let animation = document.animations().next().unwrap();
+    let mut channels = animation.channels();
+
+    let reader = channels.next().unwrap().reader(get_buffer_data);
+    assert_eq!(
+        reader.read_inputs().unwrap().collect::<Vec<_>>(),
+        [0.0, 1.0]
+    );
+    assert_eq!(reader.output_width(), 1);
+    let translations = match reader.read_outputs().unwrap() {
+        ReadOutputs::Translations(translations) => translations,
+        _ => panic!("not translations"),
+    };
+    let keyframes: Vec<_> = CubicKeyframes::new(translations, 1).collect();
+    assert_eq!(
+        keyframes,
+        [
+            CubicKeyframe {
+                in_tangent: [0.0, 1.0, 2.0],
+                value: [3.0, 4.0, 5.0],
+                out_tangent: [6.0, 7.0, 8.0]
+            },
+            CubicKeyframe {
+                in_tangent: [9.0, 10.0, 11.0],
+                value: [12.0, 13.0, 14.0],
+                out_tangent: [15.0, 16.0, 17.0]
+            }
+        ]
+    );
+
+    let reader = channels.next().unwrap().reader(get_buffer_data);
+    let rotations = match reader.read_outputs().unwrap() {
+        ReadOutputs::Rotations(rotations @ Rotations::I16(_)) => rotations,
+        _ => panic!("not i16 rotations"),
+    };
+    let rotations: Vec<_> = rotations.into_f32().collect();
+    assert_eq!(
+        rotations,
+        [[0.0, 0.0, 0.0, 1.0], [-1.0, 0.0, 0.0, 16384.0 / 32767.0]]
+    );
+
+    let reader = channels.next().unwrap().reader(get_buffer_data);
+    assert_eq!(reader.output_width(), 2);
+    let weights = match reader.read_outputs().unwrap() {
+        ReadOutputs::MorphTargetWeights(weights) => weights.into_f32(),
+        _ => panic!("not weights"),
+    };
+    let values: Vec<_> = CubicKeyframes::new(weights, reader.output_width())
+        .map(|keyframe| (keyframe.in_tangent, keyframe.value, keyframe.out_tangent))
+        .collect();
+    assert_eq!(
+        values,
+        [
+            (0.0, 0.2, 0.4),
+            (0.1, 0.3, 0.5),
+            (0.6, 0.8, 1.0),
+            (0.7, 0.9, 1.1)
+        ]
+    );
+}
+
+#[test]
+fn invalid_outputs_are_absent() {
+    let gltf = GLTF.replace(
+        r#""componentType": 5122, "normalized": true"#,
+        r#""componentType": 5125"#,
+    );
+    let (document, buffers) = gltf::import_slice(gltf).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    let channel = document
+        .animations()
+        .next()
+        .unwrap()
+        .channels()
+        .nth(1)
+        .unwrap();
+    assert!(channel.reader(get_buffer_data).read_outputs().is_none());
+
+    let samples = [1, 2, 3, 4, 5, 6, 7];
+    let keyframes: Vec<_> = CubicKeyframes::new(samples.iter().copied(), 2).collect();
+    assert_eq!(keyframes.len(), 2);
+    assert_eq!(CubicKeyframes::new(samples.iter().copied(), 0).count(), 0);
+}
@@ -0,0 +1,159 @@
+#![cfg(all(feature = "animation", feature = "import"))]
+
+#[macro_use]
+extern crate approx;
+
+use gltf::animation::{AnimError, Output};
+
+/// Keyframes at 1, 2 and 4 seconds of a linear translation, a linear
+/// rotation of a quarter turn and then a half turn about Y whose last
+/// quaternion is negated, and a stepped scale. Also a cubic spline rotation
+/// of two keyframes at 0 and 1 seconds, and the weights of two morph targets
+/// with a single keyframe at half a second.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 260, "uri": "data:application/octet-stream;base64,AACAPwAAAEAAAIBAAAAAAAAAAAAAAAAAAACAPwAAAEAAAEBAAACgQAAAoEAAAKBAAAAAAAAAAAAAAAAAAACAPwAAAADzBDU/AAAAAPMENT8AAAAAAACAvwAAAAAAAAAAAACAPwAAgD8AAIA/AAAAQAAAAEAAAABAAABAQAAAQEAAAEBAAAAAAAAAgD8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACAPwAAAAAAAMA/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAIC/AAAAAPMENT8AAAAA8wQ1PwAAAAAAAAAAAAAAAAAAAAAAAAA/AACAPgAAQD8AAAAAAAAAAAAAAAA="}],
+    "bufferViews": [{"buffer": 0, "byteLength": 260}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "SCALAR",
+         "min": [1], "max": [4]},
+        {"bufferView": 0, "byteOffset": 12, "componentType": 5126, "count": 3, "type": "VEC3"},
+        {"bufferView": 0, "byteOffset": 48, "componentType": 5126, "count": 3, "type": "VEC4"},
+        {"bufferView": 0, "byteOffset": 96, "componentType": 5126, "count": 3, "type": "VEC3"},
+        {"bufferView": 0, "byteOffset": 132, "componentType": 5126, "count": 2, "type": "SCALAR",
+         "min": [0], "max": [1]},
+        {"bufferView": 0, "byteOffset": 140, "componentType": 5126, "count": 6, "type": "VEC4"},
+        {"bufferView": 0, "byteOffset": 236, "componentType": 5126, "count": 1, "type": "SCALAR",
+         "min": [0.5], "max": [0.5]},
+        {"bufferView": 0, "byteOffset": 240, "componentType": 5126, "count": 2, "type": "SCALAR"},
+        {"bufferView": 0, "byteOffset": 248, "componentType": 5126, "count": 1, "type": "VEC3",
+         "min": [0, 0, 0], "max": [0, 0, 0]}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 8},
+                                "targets": [{"POSITION": 8}, {"POSITION": 8}]}]}],
+    "nodes": [{"mesh": 0}, {}],
+    "animations": [{
+        "channels": [
+            {"sampler": 0, "target": {"node": 0, "path": "translation"}},
+            {"sampler": 1, "target": {"node": 0, "path": "rotation"}},
+            {"sampler": 2, "target": {"node": 0, "path": "scale"}},
+            {"sampler": 3, "target": {"node": 1, "path": "rotation"}},
+            {"sampler": 4, "target": {"node": 0, "path": "weights"}}
+        ],
+        "samplers": [
+            {"input": 0, "output": 1},
+            {"input": 0, "output": 2},
+            {"input": 0, "output": 3, "interpolation": "STEP"},
+            {"input": 4, "output": 5, "interpolation": "CUBICSPLINE"},
+            {"input": 6, "output": 7}
+        ]
+    }]
+}"#;
+
+fn samples(gltf: &str, channel: usize, times: &[f32]) -> Vec<Result<Output, AnimError>> {
+    let (document, buffers) = gltf::import_slice(gltf).unwrap();
+    let animation = document.animations().next().unwrap();
+    let channel = animation.channels().nth(channel).unwrap();
+    times
+        .iter()
+        .map(|&time| channel.sample(time, &buffers))
+        .collect()
+}
+
+fn rotations(channel: usize, times: &[f32]) -> Vec<[f32; 4]> {
+    samples(GLTF, channel, times)
+        .into_iter()
+        .map(|sample| match sample {
+            Ok(Output::Rotation(rotation)) => rotation,
+            sample => panic!("not a rotation: {:?}", sample),
+        })
+        .collect()
+}
+
+#[test]
+fn linear_and_step_keyframes_are_sampled() {
+    let translations: Vec<_> = samples(GLTF, 0, &[0.0, 1.5, 3.0, 5.0])
+        .into_iter()
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(
+        translations,
+        [
+            Output::Translation([0.0, 0.0, 0.0]),
+            Output::Translation([0.5, 1.0, 1.5]),
+            Output::Translation([3.0, 3.5, 4.0]),
+            Output::Translation([5.0, 5.0, 5.0])
+        ]
+    );
+
+    let scales: Vec<_> = samples(GLTF, 2, &[1.999, 2.0, 3.5, 10.0])
+        .into_iter()
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(
+        scales,
+        [
+            Output::Scale([1.0, 1.0, 1.0]),
+            Output::Scale([2.0, 2.0, 2.0]),
+            Output::Scale([2.0, 2.0, 2.0]),
+            Output::Scale([3.0, 3.0, 3.0])
+        ]
+    );
+}
+
+#[test]
+fn rotations_are_interpolated_spherically() {
+    // Halfway through a quarter turn and along the shorter arc from a
+    // quarter turn to a half turn, then clamped to the negated half turn.
+    let rotations = rotations(1, &[1.5, 3.0, 4.0]);
+    let (sin, cos) = (0.382_683_43, 0.923_879_5);
+    assert_relative_eq!(rotations[0][..], [0.0, sin, 0.0, cos][..], epsilon = 1e-6);
+    assert_relative_eq!(rotations[1][..], [0.0, cos, 0.0, sin][..], epsilon = 1e-6);
+    assert_eq!(rotations[2], [0.0, -1.0, 0.0, 0.0]);
+}
+
+#[test]
+fn cubic_spline_rotations_are_normalized() {
+    let rotations = rotations(3, &[-1.0, 0.25, 0.5, 2.0]);
+    assert_eq!(rotations[0], [0.0, 0.0, 0.0, 1.0]);
+    assert_relative_eq!(
+        rotations[1][..],
+        [0.0, 0.305_696_63, 0.0, 0.952_128_97][..],
+        epsilon = 1e-6
+    );
+    assert_relative_eq!(
+        rotations[2][..],
+        [0.0, 0.483_873_73, 0.0, 0.875_137_8][..],
+        epsilon = 1e-6
+    );
+    let h = core::f32::consts::FRAC_1_SQRT_2;
+    assert_eq!(rotations[3], [0.0, h, 0.0, h]);
+}
+
+#[test]
+fn single_keyframes_are_held() {
+    let weights = samples(GLTF, 4, &[0.0, 0.5, 9.0]);
+    for sample in weights {
+        assert_eq!(sample, Ok(Output::MorphTargetWeights(vec![0.25, 0.75])));
+    }
+}
+
+#[test]
+fn mismatched_outputs_are_errors() {
+    let gltf = GLTF.replace(
+        r#""byteOffset": 12, "componentType": 5126, "count": 3"#,
+        r#""byteOffset": 12, "componentType": 5126, "count": 2"#,
+    );
+    assert_eq!(
+        samples(&gltf, 0, &[0.0]),
+        [Err(AnimError::Keyframes { channel: 0 })]
+    );
+    let gltf = GLTF.replace(
+        r#""byteOffset": 240, "componentType": 5126, "count": 2"#,
+        r#""byteOffset": 240, "componentType": 5126, "count": 0"#,
+    );
+    assert_eq!(
+        samples(&gltf, 4, &[0.0]),
+        [Err(AnimError::Keyframes { channel: 4 })]
+    );
+}
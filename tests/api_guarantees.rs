@@ -0,0 +1,95 @@
+//! Compile-time checks of the properties of the public API that downstream
+//! crates rely on. A change that breaks one of them fails to build.
+
+#![cfg(all(
+    feature = "animation",
+    feature = "cameras",
+    feature = "skinning",
+    feature = "utils"
+))]
+#![allow(dead_code)]
+
+use core::ops::Deref;
+
+use gltf::{accessor, animation, binary, buffer, check, export, fault, mesh, scene, skin};
+use gltf::{iter, json, texture, transform};
+
+fn clone_send_sync<T: Clone + Send + Sync>() {}
+
+fn error<T: core::error::Error + Send + Sync + 'static>() {}
+
+fn exact_size<T: ExactSizeIterator + Clone>() {}
+
+fn data<T: Deref<Target = [u8]> + Clone + Send + Sync>() {}
+
+/// The result of [`gltf::Glb::from_slice`] borrows from the slice alone.
+fn glb(data: &[u8]) -> gltf::Glb<'_> {
+    gltf::Glb::from_slice(data).unwrap()
+}
+
+/// The result of [`binary::ChunkIter`] borrows from the slice alone.
+fn chunks(data: &[u8]) -> impl Iterator<Item = binary::Chunk<'_>> {
+    binary::ChunkIter::new(data).unwrap().map(Result::unwrap)
+}
+
+#[test]
+fn documents() {
+    clone_send_sync::<gltf::Document>();
+    clone_send_sync::<gltf::Gltf>();
+    clone_send_sync::<json::Root>();
+    clone_send_sync::<gltf::Glb<'static>>();
+    clone_send_sync::<gltf::Node<'static>>();
+    clone_send_sync::<gltf::Primitive<'static>>();
+    data::<buffer::Data>();
+}
+
+#[test]
+fn errors() {
+    error::<gltf::Error>();
+    error::<binary::Error>();
+    error::<binary::IoError>();
+    error::<accessor::Error>();
+    error::<json::validation::Error>();
+    error::<texture::Error>();
+    error::<transform::EditError>();
+    error::<check::CheckError>();
+    error::<export::DumpError<core::fmt::Error>>();
+    error::<buffer::DroppedRange>();
+    error::<skin::SkinError>();
+    error::<animation::AnimError>();
+    error::<fault::FaultError>();
+}
+
+#[test]
+fn iterators() {
+    exact_size::<iter::Accessors<'static>>();
+    exact_size::<iter::Animations<'static>>();
+    exact_size::<iter::Buffers<'static>>();
+    exact_size::<iter::Cameras<'static>>();
+    exact_size::<iter::ExtensionsRequired<'static>>();
+    exact_size::<iter::ExtensionsUsed<'static>>();
+    exact_size::<iter::Images<'static>>();
+    exact_size::<iter::Materials<'static>>();
+    exact_size::<iter::Meshes<'static>>();
+    exact_size::<iter::Nodes<'static>>();
+    exact_size::<iter::Samplers<'static>>();
+    exact_size::<iter::Scenes<'static>>();
+    exact_size::<iter::Skins<'static>>();
+    exact_size::<iter::Textures<'static>>();
+    exact_size::<iter::Views<'static>>();
+    exact_size::<animation::iter::Channels<'static>>();
+    exact_size::<animation::iter::Samplers<'static>>();
+    exact_size::<mesh::iter::Attributes<'static>>();
+    exact_size::<mesh::iter::MorphTargets<'static>>();
+    exact_size::<mesh::iter::Primitives<'static>>();
+    exact_size::<scene::iter::Children<'static>>();
+    exact_size::<scene::iter::Nodes<'static>>();
+    exact_size::<skin::iter::Joints<'static>>();
+}
+
+#[test]
+fn slices() {
+    let data = std::fs::read("examples/Box.glb").unwrap();
+    assert!(glb(&data).bin.is_some());
+    assert_eq!(chunks(&data).count(), 2);
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "arena")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use gltf::arena::ImportArena;
+use gltf::Gltf;
+
+/// Allocations of at most this many bytes are counted as small blocks.
+const SMALL: usize = 256;
+
+/// Counts the small blocks allocated on the system heap, and those alive.
+struct Counting;
+
+static SMALL_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static SMALL_BLOCKS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.size() <= SMALL {
+            SMALL_ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+            SMALL_BLOCKS.fetch_add(1, Ordering::SeqCst);
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if layout.size() <= SMALL {
+            SMALL_BLOCKS.fetch_sub(1, Ordering::SeqCst);
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// A document with many named nodes and buffers, and nodes with extras.
+fn document(count: usize) -> String {
+    let mut json = String::from(r#"{"asset": {"version": "2.0"}, "nodes": ["#);
+    for i in 0..count {
+        let separator = if i == 0 { "" } else { "," };
+        write!(
+            json,
+            r#"{}{{"name": "node {}", "extras": {{"id": {}}}}}"#,
+            separator, i, i
+        )
+        .unwrap();
+    }
+    json.push_str(r#"], "buffers": ["#);
+    for i in 0..count {
+        let separator = if i == 0 { "" } else { "," };
+        write!(
+            json,
+            r#"{}{{"name": "buffer {}", "byteLength": 4, "uri": "buffers/{}.bin"}}"#,
+            separator, i, i
+        )
+        .unwrap();
+    }
+    json.push_str("]}");
+    json
+}
+
+/// Small heap blocks used by loading a document.
+struct Blocks {
+    /// The number of blocks allocated while loading.
+    allocated: usize,
+    /// The number of blocks kept alive by the document.
+    retained: usize,
+}
+
+fn measure(load: impl FnOnce() -> Gltf) -> (Gltf, Blocks) {
+    let allocated = SMALL_ALLOCATIONS.load(Ordering::SeqCst);
+    let retained = SMALL_BLOCKS.load(Ordering::SeqCst);
+    let gltf = load();
+    let blocks = Blocks {
+        allocated: SMALL_ALLOCATIONS.load(Ordering::SeqCst) - allocated,
+        retained: SMALL_BLOCKS.load(Ordering::SeqCst).saturating_sub(retained),
+    };
+    (gltf, blocks)
+}
+
+// The global allocator is shared by the whole test binary, so everything is
+// measured from a single test.
+#[test]
+fn arena_import() {
+    let json = document(1000);
+
+    let (heap, heap_blocks) = measure(|| Gltf::from_slice(json.as_bytes()).unwrap());
+
+    let arena = ImportArena::with_capacity(256 * 1024);
+    assert_eq!(arena.capacity(), 256 * 1024);
+    let (in_arena, arena_blocks) =
+        measure(|| Gltf::from_slice_in(json.as_bytes(), &arena).unwrap());
+
+    // Every buffer URI, and with `names` and `extras` every name and extras
+    // value, is allocated in a small block on the heap, while arena imports
+    // allocate almost none, not even temporarily.
+    assert!(arena.used() > 0);
+    assert!(heap_blocks.allocated >= 1000);
+    assert!(arena_blocks.allocated * 10 < heap_blocks.allocated);
+    assert!(arena_blocks.retained * 10 < heap_blocks.retained);
+    let root = in_arena.as_json();
+    assert!(root
+        .buffers
+        .iter()
+        .all(|buffer| buffer.uri.as_ref().unwrap().is_in_arena()));
+    #[cfg(feature = "names")]
+    {
+        assert!(root
+            .buffers
+            .iter()
+            .all(|buffer| buffer.name.as_ref().unwrap().is_in_arena()));
+        assert!(root
+            .nodes
+            .iter()
+            .all(|node| node.name.as_ref().unwrap().is_in_arena()));
+    }
+
+    let uris = |gltf: &Gltf| {
+        gltf.buffers()
+            .map(|buffer| match buffer.source() {
+                gltf::buffer::Source::Uri(uri) => uri.to_owned(),
+                gltf::buffer::Source::Bin => unreachable!(),
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(uris(&heap), uris(&in_arena));
+
+    // Dropping the arena first leaves the document intact; the memory is
+    // released along with the document.
+    drop(arena);
+    assert_eq!(in_arena.buffers().nth(999).unwrap().index(), 999);
+    drop(in_arena);
+
+    // Strings that no longer fit stay on the heap.
+    let arena = ImportArena::with_capacity(16);
+    let gltf = Gltf::from_slice_in(json.as_bytes(), &arena).unwrap();
+    assert_eq!(uris(&heap), uris(&gltf));
+    assert!(arena.used() <= 16);
+}
@@ -0,0 +1,155 @@
+#![cfg(feature = "KHR_texture_transform")]
+
+use gltf::buffer;
+use gltf::transform::{atlas_textures, AtlasPlacement, AtlasPlan, AtlasWarning};
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 8}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 4, "byteLength": 4}
+    ],
+    "images": [
+        {"bufferView": 0, "mimeType": "image/png"},
+        {"bufferView": 1, "mimeType": "image/png"}
+    ],
+    "samplers": [{"wrapS": 33071, "wrapT": 33071}],
+    "textures": [
+        {"source": 0, "sampler": 0},
+        {"source": 1}
+    ],
+    "materials": [
+        {
+            "pbrMetallicRoughness": {
+                "baseColorTexture": {
+                    "index": 0,
+                    "extensions": {
+                        "KHR_texture_transform": {"offset": [0.5, 0], "scale": [0.5, 1]}
+                    }
+                }
+            },
+            "normalTexture": {"index": 1}
+        },
+        {
+            "pbrMetallicRoughness": {
+                "baseColorTexture": {"index": 0, "texCoord": 1}
+            },
+            "occlusionTexture": {
+                "index": 0,
+                "extensions": {
+                    "KHR_texture_transform": {"rotation": 0.7853982}
+                }
+            }
+        }
+    ]
+}"#;
+
+#[test]
+fn shared_texture_keeps_each_transform() {
+    let mut root = gltf::Gltf::from_slice(GLTF.as_bytes())
+        .unwrap()
+        .document
+        .into_json();
+    let mut buffers = vec![buffer::Data(vec![0; 8])];
+    let plan = AtlasPlan {
+        data: vec![1; 6],
+        mime_type: "image/png".into(),
+        sampler: Some(0),
+        placements: vec![
+            AtlasPlacement {
+                texture: 0,
+                offset: [0.0, 0.0],
+                scale: [0.5, 0.25],
+            },
+            AtlasPlacement {
+                texture: 1,
+                offset: [0.5, 0.5],
+                scale: [0.5, 0.5],
+            },
+        ],
+    };
+    let report = atlas_textures(&mut root, &mut buffers, &plan);
+
+    // Texture 1 uses the default sampler, which repeats; the 45° rotation of
+    // the occlusion texture cannot be combined with the non-uniform placement.
+    assert_eq!(report.references, 3);
+    assert_eq!(
+        report.warnings,
+        [
+            AtlasWarning::Shear {
+                material: 1,
+                texture: 0
+            },
+            AtlasWarning::Wrapping { texture: 1 },
+        ]
+    );
+    // Texture 0 is still used by the occlusion reference.
+    assert_eq!(report.texture, Some(1));
+    assert_eq!(root.textures.len(), 2);
+    assert_eq!(root.images.len(), 2);
+    assert_eq!(root.extensions_used, ["KHR_texture_transform"]);
+    assert_eq!(root.extensions_required, ["KHR_texture_transform"]);
+
+    let transform = |info: &Option<gltf::json::extensions::texture::Info>| {
+        let transform = info.as_ref().unwrap().texture_transform.clone().unwrap();
+        (transform.offset.0, transform.rotation.0, transform.scale.0)
+    };
+    let first = &root.materials[0];
+    let base_color = first.pbr_metallic_roughness.base_color_texture.as_ref();
+    assert_eq!(base_color.unwrap().index.value(), 1);
+    assert_eq!(
+        transform(&base_color.unwrap().extensions),
+        ([0.25, 0.0], 0.0, [0.25, 0.25])
+    );
+    let normal = first.normal_texture.as_ref().unwrap();
+    assert_eq!(normal.index.value(), 1);
+    let normal = normal.extensions.as_ref().unwrap();
+    let normal = normal.texture_transform.as_ref().unwrap();
+    assert_eq!((normal.offset.0, normal.scale.0), ([0.5, 0.5], [0.5, 0.5]));
+
+    let second = &root.materials[1];
+    let base_color = second.pbr_metallic_roughness.base_color_texture.as_ref();
+    assert_eq!(base_color.unwrap().index.value(), 1);
+    assert_eq!(base_color.unwrap().tex_coord, 1);
+    assert_eq!(
+        transform(&base_color.unwrap().extensions),
+        ([0.0, 0.0], 0.0, [0.5, 0.25])
+    );
+    let occlusion = second.occlusion_texture.as_ref().unwrap();
+    assert_eq!(occlusion.index.value(), 0);
+
+    let atlas = &root.textures[1];
+    assert_eq!(atlas.sampler.unwrap().value(), 0);
+    let view = root.images[atlas.source.value()].buffer_view.unwrap();
+    let view = &root.buffer_views[view.value()];
+    assert_eq!(view.byte_offset.unwrap().0, 8);
+    assert_eq!(&buffers[0].0[8..14], [1; 6]);
+
+    gltf::Document::from_json(root).unwrap();
+}
+
+#[test]
+fn nothing_to_atlas() {
+    let mut root = gltf::Gltf::from_slice(GLTF.as_bytes())
+        .unwrap()
+        .document
+        .into_json();
+    let mut buffers = vec![buffer::Data(vec![0; 8])];
+    let plan = AtlasPlan {
+        data: vec![1; 6],
+        mime_type: "image/png".into(),
+        sampler: None,
+        placements: vec![AtlasPlacement {
+            texture: 5,
+            offset: [0.0, 0.0],
+            scale: [1.0, 1.0],
+        }],
+    };
+    let report = atlas_textures(&mut root, &mut buffers, &plan);
+    assert_eq!(report.texture, None);
+    assert_eq!(report.references, 0);
+    assert_eq!(root.textures.len(), 2);
+    assert!(root.extensions_used.is_empty());
+    assert_eq!(buffers[0].0.len(), 8);
+}
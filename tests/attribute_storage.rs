@@ -0,0 +1,450 @@
+#![cfg(feature = "utils")]
+
+use gltf::mesh::util::{ReadRaw, ReadTexCoords};
+use gltf::mesh::{Reader, Semantic};
+use gltf::{buffer, Buffer, Gltf};
+
+const I8: u32 = 5120;
+const U8: u32 = 5121;
+const I16: u32 = 5122;
+const U16: u32 = 5123;
+const U32: u32 = 5125;
+const F32: u32 = 5126;
+
+/// The stored components of each component type, repeated as needed.
+fn stored(component_type: u32) -> [f64; 4] {
+    match component_type {
+        I8 => [-128.0, -127.0, 0.0, 127.0],
+        U8 => [0.0, 51.0, 255.0, 1.0],
+        I16 => [-32768.0, -32767.0, 0.0, 32767.0],
+        U16 | U32 => [0.0, 13107.0, 65535.0, 1.0],
+        F32 => [-1.5, 0.25, 2.0, 0.0],
+        _ => unreachable!(),
+    }
+}
+
+/// The stored components of each component type as the specification
+/// converts them when normalized.
+fn normalized(component_type: u32) -> [f32; 4] {
+    match component_type {
+        I8 | I16 => [-1.0, -1.0, 0.0, 1.0],
+        U8 => [0.0, 0.2, 1.0, 1.0 / 255.0],
+        U16 => [0.0, 0.2, 1.0, 1.0 / 65535.0],
+        _ => unreachable!(),
+    }
+}
+
+/// A storage type of an attribute.
+#[derive(Clone, Copy, Debug)]
+struct Storage {
+    component_type: u32,
+    normalized: bool,
+}
+
+const fn storage(component_type: u32, normalized: bool) -> Storage {
+    Storage {
+        component_type,
+        normalized,
+    }
+}
+
+impl Storage {
+    /// The components of two elements, as stored.
+    fn components(self, n: usize) -> Vec<f64> {
+        stored(self.component_type)
+            .iter()
+            .copied()
+            .cycle()
+            .take(2 * n)
+            .collect()
+    }
+
+    /// The components of two elements, as converted to `f32`.
+    fn converted(self, n: usize) -> Vec<f32> {
+        let values: Vec<f32> = if self.normalized {
+            normalized(self.component_type).to_vec()
+        } else {
+            stored(self.component_type)
+                .iter()
+                .map(|&x| x as f32)
+                .collect()
+        };
+        values.into_iter().cycle().take(2 * n).collect()
+    }
+}
+
+type GetBufferData<'f, 's> = &'f dyn Fn(Buffer) -> Option<&'s [u8]>;
+
+/// A primitive with one attribute of two elements, or one morph target
+/// attribute if `target` is set.
+struct Fixture {
+    gltf: Gltf,
+    buffers: Vec<buffer::Data>,
+}
+
+fn fixture(name: &str, target: bool, ty: &str, storage: Storage) -> Fixture {
+    let n = match ty {
+        "VEC2" => 2,
+        "VEC3" => 3,
+        "VEC4" => 4,
+        _ => unreachable!(),
+    };
+    let size = match storage.component_type {
+        I8 | U8 => 1,
+        I16 | U16 => 2,
+        _ => 4,
+    };
+    let stride = (n * size + 3) & !3;
+    let mut data = vec![0; 2 * stride];
+    for (i, value) in storage.components(n).into_iter().enumerate() {
+        let offset = i / n * stride + i % n * size;
+        let bytes = &mut data[offset..offset + size];
+        match storage.component_type {
+            I8 => bytes.copy_from_slice(&(value as i8).to_le_bytes()),
+            U8 => bytes.copy_from_slice(&(value as u8).to_le_bytes()),
+            I16 => bytes.copy_from_slice(&(value as i16).to_le_bytes()),
+            U16 => bytes.copy_from_slice(&(value as u16).to_le_bytes()),
+            U32 => bytes.copy_from_slice(&(value as u32).to_le_bytes()),
+            _ => bytes.copy_from_slice(&(value as f32).to_le_bytes()),
+        }
+    }
+    let primitive = if target {
+        format!(r#"{{"attributes": {{}}, "targets": [{{"{}": 0}}]}}"#, name)
+    } else {
+        format!(r#"{{"attributes": {{"{}": 0}}}}"#, name)
+    };
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "meshes": [{{"primitives": [{}]}}],
+            "accessors": [{{"bufferView": 0, "componentType": {}, "normalized": {},
+                            "count": 2, "type": "{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": {}, "byteStride": {}}}],
+            "buffers": [{{"byteLength": {}}}]
+        }}"#,
+        primitive,
+        storage.component_type,
+        storage.normalized,
+        ty,
+        data.len(),
+        stride,
+        data.len()
+    );
+    Fixture {
+        gltf: Gltf::from_slice_without_validation(json.as_bytes()).unwrap(),
+        buffers: vec![buffer::Data(data)],
+    }
+}
+
+impl Fixture {
+    fn read<'s, T>(&'s self, f: impl FnOnce(&Reader<'_, 's, GetBufferData<'_, 's>>) -> T) -> T {
+        let primitive = self
+            .gltf
+            .meshes()
+            .next()
+            .unwrap()
+            .primitives()
+            .next()
+            .unwrap();
+        let get_buffer_data: GetBufferData =
+            &|buffer| self.buffers.get(buffer.index()).map(|x| &*x.0);
+        f(&primitive.reader(get_buffer_data))
+    }
+}
+
+/// Returns the component type, dimensions and components of raw data.
+fn raw(read: ReadRaw) -> (u32, usize, Vec<f64>) {
+    match read {
+        ReadRaw::Vec2I8(iter) => (I8, 2, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec2U8(iter) => (U8, 2, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec2I16(iter) => (I16, 2, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec2U16(iter) => (U16, 2, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec2F32(iter) => (F32, 2, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec3I8(iter) => (I8, 3, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec3U8(iter) => (U8, 3, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec3I16(iter) => (I16, 3, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec3U16(iter) => (U16, 3, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec3F32(iter) => (F32, 3, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec4I8(iter) => (I8, 4, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec4U8(iter) => (U8, 4, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec4I16(iter) => (I16, 4, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec4U16(iter) => (U16, 4, iter.flatten().map(f64::from).collect()),
+        ReadRaw::Vec4F32(iter) => (F32, 4, iter.flatten().map(f64::from).collect()),
+    }
+}
+
+fn assert_close(actual: &[f32], expected: &[f32], storage: Storage) {
+    assert_eq!(actual.len(), expected.len(), "{:?}", storage);
+    for (a, e) in actual.iter().zip(expected) {
+        assert!(
+            (a - e).abs() < 1e-6,
+            "{:?}: {:?} != {:?}",
+            storage,
+            actual,
+            expected
+        );
+    }
+}
+
+/// Checks an attribute read through `typed` and through `read_raw`.
+fn check<const N: usize>(
+    name: &str,
+    semantic: Semantic,
+    ty: &str,
+    storages: &[Storage],
+    typed: impl for<'s> Fn(&Reader<'_, 's, GetBufferData<'_, 's>>) -> Option<Vec<[f32; N]>>,
+) {
+    for &storage in storages {
+        let fixture = fixture(name, false, ty, storage);
+        fixture.read(|reader| {
+            let values: Vec<f32> = typed(reader).unwrap().into_iter().flatten().collect();
+            assert_close(&values, &storage.converted(N), storage);
+            assert_eq!(
+                raw(reader.read_raw(&semantic).unwrap()),
+                (storage.component_type, N, storage.components(N))
+            );
+        });
+    }
+}
+
+const FLOAT: Storage = storage(F32, false);
+const I8N: Storage = storage(I8, true);
+const I16N: Storage = storage(I16, true);
+
+#[test]
+fn positions() {
+    let storages = [
+        FLOAT,
+        storage(I8, false),
+        I8N,
+        storage(U8, false),
+        storage(U8, true),
+        storage(I16, false),
+        I16N,
+        storage(U16, false),
+        storage(U16, true),
+    ];
+    check(
+        "POSITION",
+        Semantic::Positions,
+        "VEC3",
+        &storages,
+        |reader| reader.read_positions().map(Iterator::collect),
+    );
+}
+
+#[test]
+fn normals_and_tangents() {
+    let storages = [FLOAT, I8N, I16N];
+    check("NORMAL", Semantic::Normals, "VEC3", &storages, |reader| {
+        reader.read_normals().map(Iterator::collect)
+    });
+    check("TANGENT", Semantic::Tangents, "VEC4", &storages, |reader| {
+        reader.read_tangents().map(Iterator::collect)
+    });
+}
+
+#[test]
+fn tex_coords() {
+    let storages = [
+        FLOAT,
+        storage(U8, true),
+        storage(U16, true),
+        storage(I8, false),
+        I8N,
+        storage(U8, false),
+        storage(I16, false),
+        I16N,
+        storage(U16, false),
+    ];
+    check(
+        "TEXCOORD_0",
+        Semantic::TexCoords(0),
+        "VEC2",
+        &storages,
+        |reader| {
+            reader
+                .read_tex_coords(0)
+                .map(|tex_coords| tex_coords.into_f32().collect())
+        },
+    );
+
+    // Only floats and normalized unsigned integers keep their storage type.
+    for &storage in &storages {
+        fixture("TEXCOORD_0", false, "VEC2", storage).read(|reader| {
+            let quantized = matches!(
+                reader.read_tex_coords(0).unwrap(),
+                ReadTexCoords::Quantized(_)
+            );
+            let kept = match storage.component_type {
+                F32 => true,
+                U8 | U16 => storage.normalized,
+                _ => false,
+            };
+            assert_eq!(quantized, !kept, "{:?}", storage);
+        });
+    }
+}
+
+#[test]
+fn colors() {
+    let storages = [FLOAT, storage(U8, true), storage(U16, true)];
+    check(
+        "COLOR_0",
+        Semantic::Colors(0),
+        "VEC3",
+        &storages,
+        |reader| {
+            reader
+                .read_colors(0)
+                .map(|colors| colors.into_rgb_f32().collect())
+        },
+    );
+    check(
+        "COLOR_0",
+        Semantic::Colors(0),
+        "VEC4",
+        &storages,
+        |reader| {
+            reader
+                .read_colors(0)
+                .map(|colors| colors.into_rgba_f32().collect())
+        },
+    );
+}
+
+#[test]
+fn joints_and_weights() {
+    let storages = [storage(U8, false), storage(U16, false)];
+    check(
+        "JOINTS_0",
+        Semantic::Joints(0),
+        "VEC4",
+        &storages,
+        |reader| {
+            reader.read_joints(0).map(|joints| {
+                joints
+                    .into_u16()
+                    .map(|joint| joint.map(f32::from))
+                    .collect()
+            })
+        },
+    );
+
+    let storages = [FLOAT, storage(U8, true), storage(U16, true)];
+    check(
+        "WEIGHTS_0",
+        Semantic::Weights(0),
+        "VEC4",
+        &storages,
+        |reader| {
+            reader
+                .read_weights(0)
+                .map(|weights| weights.into_f32().collect())
+        },
+    );
+}
+
+#[test]
+fn morph_targets() {
+    let positions = [FLOAT, storage(I8, false), I8N, storage(I16, false), I16N];
+    let directions = [FLOAT, I8N, I16N];
+    let cases = [
+        ("POSITION", &positions[..], 0),
+        ("NORMAL", &directions[..], 1),
+        ("TANGENT", &directions[..], 2),
+    ];
+    for (name, storages, slot) in cases {
+        for &storage in storages {
+            fixture(name, true, "VEC3", storage).read(|reader| {
+                let (positions, normals, tangents) = reader.read_morph_targets().next().unwrap();
+                let mut displacements = [positions, normals, tangents];
+                for (i, displacements) in displacements.iter().enumerate() {
+                    assert_eq!(displacements.is_some(), i == slot, "{} {:?}", name, storage);
+                }
+                let values: Vec<f32> = displacements[slot].take().unwrap().flatten().collect();
+                assert_close(&values, &storage.converted(3), storage);
+            });
+        }
+    }
+}
+
+/// Every value of each normalized integer type is converted with the formula
+/// of the specification, such as `c / 255.0` or `max(c / 127.0, -1.0)`,
+/// without rounding errors.
+#[test]
+fn normalization_is_exact() {
+    let cases = [
+        (I8, -128..=127, 127.0),
+        (U8, 0..=255, 255.0),
+        (I16, -32768..=32767, 32767.0),
+        (U16, 0..=65535, 65535.0),
+    ];
+    for (component_type, values, scale) in cases {
+        let mut data = Vec::new();
+        for c in values.clone() {
+            match component_type {
+                I8 => data.extend_from_slice(&(c as i8).to_le_bytes()),
+                U8 => data.extend_from_slice(&(c as u8).to_le_bytes()),
+                I16 => data.extend_from_slice(&(c as i16).to_le_bytes()),
+                _ => data.extend_from_slice(&(c as u16).to_le_bytes()),
+            }
+        }
+        let json = format!(
+            r#"{{
+                "asset": {{"version": "2.0"}},
+                "meshes": [{{"primitives": [{{"attributes": {{"TEXCOORD_0": 0}}}}]}}],
+                "accessors": [{{"bufferView": 0, "componentType": {}, "normalized": true,
+                                "count": {}, "type": "VEC2"}}],
+                "bufferViews": [{{"buffer": 0, "byteLength": {}}}],
+                "buffers": [{{"byteLength": {}}}]
+            }}"#,
+            component_type,
+            values.clone().count() / 2,
+            data.len(),
+            data.len()
+        );
+        let fixture = Fixture {
+            gltf: Gltf::from_slice_without_validation(json.as_bytes()).unwrap(),
+            buffers: vec![buffer::Data(data)],
+        };
+        let converted: Vec<f32> = fixture.read(|reader| {
+            let tex_coords = reader.read_tex_coords(0).unwrap();
+            tex_coords.into_f32().flatten().collect()
+        });
+        let expected: Vec<f32> = values.map(|c| (c as f32 / scale).max(-1.0)).collect();
+        assert_eq!(converted, expected, "{}", component_type);
+        assert_eq!(converted[converted.len() - 1], 1.0);
+        if matches!(component_type, I8 | I16) {
+            // The most negative value clamps to the same -1 as the next one.
+            assert_eq!(converted[..2], [-1.0, -1.0], "{}", component_type);
+        } else {
+            assert_eq!(converted[0], 0.0);
+        }
+    }
+}
+
+#[test]
+fn unconvertible_storage() {
+    // Typed reads never reinterpret data of another type.
+    fixture("POSITION", false, "VEC3", storage(U32, false)).read(|reader| {
+        assert!(reader.read_positions().is_none());
+        assert!(reader.read_raw(&Semantic::Positions).is_none());
+    });
+    fixture("NORMAL", false, "VEC2", FLOAT).read(|reader| {
+        assert!(reader.read_normals().is_none());
+        assert!(reader.read_raw(&Semantic::Normals).is_some());
+    });
+    fixture("TEXCOORD_0", false, "VEC3", FLOAT).read(|reader| {
+        assert!(reader.read_tex_coords(0).is_none());
+    });
+    fixture("COLOR_0", false, "VEC2", FLOAT).read(|reader| {
+        assert!(reader.read_colors(0).is_none());
+    });
+    fixture("JOINTS_0", false, "VEC4", FLOAT).read(|reader| {
+        assert!(reader.read_joints(0).is_none());
+    });
+    fixture("WEIGHTS_0", false, "VEC2", FLOAT).read(|reader| {
+        assert!(reader.read_weights(0).is_none());
+    });
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "bake")]
+
+use gltf::bake::{self, BakeError};
+use gltf::{Document, Gltf};
+
+fn load(path: &str) -> Document {
+    Gltf::from_slice(&std::fs::read(path).unwrap())
+        .unwrap()
+        .document
+}
+
+#[test]
+fn documents_round_trip() {
+    for path in [
+        "examples/Box.gltf",
+        "examples/Lantern.gltf",
+        "tests/box_sparse.gltf",
+        "tests/number_syntax.gltf",
+    ] {
+        let document = load(path);
+        let baked = document.bake();
+        let loaded = Document::from_baked(&baked).unwrap();
+        assert_eq!(
+            loaded.as_json().to_vec().unwrap(),
+            document.as_json().to_vec().unwrap(),
+            "{}",
+            path
+        );
+        assert_eq!(loaded.bake(), baked, "{}", path);
+        assert_eq!(
+            bake::baked_content_hash(&baked).unwrap(),
+            bake::content_hash(&document)
+        );
+    }
+}
+
+#[cfg(all(feature = "extras", feature = "extensions"))]
+#[test]
+fn extras_and_extensions_round_trip() {
+    let json = r#"{
+        "asset": {"version": "2.0", "extras": {"unit_scale": 0.01, "tags": [1, -2, 2.5, null]}},
+        "extensionsUsed": ["EXT_unknown"],
+        "nodes": [{
+            "name": "a",
+            "extensions": {"EXT_unknown": {"value": "é"}},
+            "extras": [true, {"nested": "x"}]
+        }],
+        "scenes": [{"nodes": [0]}]
+    }"#;
+    let document = Gltf::from_slice(json.as_bytes()).unwrap().document;
+    let loaded = Document::from_baked(&document.bake()).unwrap();
+    assert_eq!(
+        loaded.as_json().to_vec().unwrap(),
+        document.as_json().to_vec().unwrap()
+    );
+}
+
+/// Returns `baked` with the first occurrence of `from` replaced by `to`.
+#[cfg(all(feature = "extras", feature = "extensions"))]
+fn splice(baked: &[u8], from: &[u8], to: &[u8]) -> Vec<u8> {
+    let at = baked
+        .windows(from.len())
+        .position(|window| window == from)
+        .unwrap();
+    [&baked[..at], to, &baked[at + from.len()..]].concat()
+}
+
+#[cfg(all(feature = "extras", feature = "extensions"))]
+#[test]
+fn corrupt_extras_and_extensions_are_rejected() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["EXT_unknown"],
+        "nodes": [{"extensions": {"EXT_unknown": "é"}, "extras": [true]}]
+    }"#;
+    let baked = Gltf::from_slice(json.as_bytes()).unwrap().document.bake();
+
+    let invalid = splice(&baked, b"[true]", b"[tru!]");
+    assert!(matches!(
+        Document::from_baked(&invalid),
+        Err(BakeError::Malformed)
+    ));
+
+    // Arrays nested deeper than the stack allows, each a tag and a length.
+    let mut nested = [7, 1, 0, 0, 0].repeat(100_000);
+    nested.push(0);
+    let deep = splice(&baked, &[6, 2, 0, 0, 0, 0xc3, 0xa9], &nested);
+    assert!(matches!(
+        Document::from_baked(&deep),
+        Err(BakeError::Malformed)
+    ));
+}
+
+#[test]
+fn stale_and_foreign_data_is_rejected() {
+    let document = load("examples/Box.gltf");
+    let baked = document.bake();
+
+    let mut other = document.clone().into_json();
+    other.nodes[0].translation = Some([1.0, 0.0, 0.0]);
+    let other = Document::from_json(other).unwrap();
+    assert_ne!(
+        bake::baked_content_hash(&baked).unwrap(),
+        bake::content_hash(&other)
+    );
+
+    let mut version = baked.clone();
+    version[8] += 1;
+    assert!(matches!(
+        Document::from_baked(&version),
+        Err(BakeError::Version {
+            found: 2,
+            expected: bake::VERSION
+        })
+    ));
+    let mut layout = baked.clone();
+    layout[12] ^= 1;
+    assert!(matches!(
+        Document::from_baked(&layout),
+        Err(BakeError::Layout { .. })
+    ));
+    assert!(matches!(
+        Document::from_baked(br#"{"asset": {"version": "2.0"}}"#),
+        Err(BakeError::NotBaked)
+    ));
+    let truncated = &baked[..baked.len() - 1];
+    assert!(matches!(
+        Document::from_baked(truncated),
+        Err(BakeError::Malformed)
+    ));
+}
+
+#[test]
+fn baked_documents_are_validated() {
+    let mut root = load("examples/Box.gltf").into_json();
+    root.meshes[0].primitives[0].indices = Some(gltf::json::Index::new(99));
+    let baked = Document::from_json_without_validation(root).bake();
+    assert!(matches!(
+        Document::from_baked(&baked),
+        Err(BakeError::Document(gltf::Error::Validation(_)))
+    ));
+}
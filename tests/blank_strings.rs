@@ -0,0 +1,49 @@
+use std::fs;
+
+use gltf::buffer;
+
+#[test]
+fn blank_uris_and_mime_types_are_invalid() {
+    let data = fs::read("tests/blank_strings.gltf").unwrap();
+    let errors = match gltf::Gltf::from_slice(&data) {
+        Err(gltf::Error::Validation(errors)) => errors,
+        other => panic!("expected validation errors, got {:?}", other),
+    };
+    let paths: Vec<String> = errors.iter().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(
+        paths,
+        ["buffers[0].uri", "images[0].uri", "images[1].mimeType"]
+    );
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn blank_names_are_absent() {
+    let data = fs::read("tests/blank_strings.gltf").unwrap();
+    let gltf = gltf::Gltf::from_slice_without_validation(&data).unwrap();
+    let names: Vec<_> = gltf.nodes().map(|node| node.name()).collect();
+    assert_eq!(names, [None, None, Some("root")]);
+    assert_eq!(gltf.scenes().next().unwrap().name(), None);
+    assert_eq!(gltf.images().nth(2).unwrap().name(), None);
+
+    // The raw value is still available through the JSON.
+    assert_eq!(gltf.as_json().nodes[1].name.as_deref(), Some("  "));
+}
+
+#[test]
+fn blank_mime_type_is_sniffed() {
+    let data = fs::read("tests/blank_strings.gltf").unwrap();
+    let gltf = gltf::Gltf::from_slice_without_validation(&data).unwrap();
+    let png = buffer::Data(b"\x89PNG\r\n\x1a\n".to_vec());
+    let buffers = [png];
+    let mime_types: Vec<_> = gltf
+        .images()
+        .map(|image| image.mime_type(&buffers))
+        .collect();
+    assert_eq!(mime_types, [None, Some("image/png"), Some("image/png")]);
+
+    let jpeg = buffer::Data(vec![0xFF, 0xD8, 0xFF, 0xE0, 0, 0, 0, 0]);
+    let image = gltf.images().nth(1).unwrap();
+    assert_eq!(image.mime_type(&[jpeg]), Some("image/jpeg"));
+    assert_eq!(image.mime_type(&[]), None);
+}
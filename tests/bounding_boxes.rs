@@ -0,0 +1,101 @@
+#![cfg(feature = "utils")]
+
+use approx::assert_relative_eq;
+use gltf::mesh::{BoundingBox, Bounds};
+use gltf::{buffer, Gltf};
+
+/// Three positions read by a primitive whose accessor declares their bounds,
+/// one whose accessor does not and one with no vertices, and a mesh without
+/// primitives. The first mesh is translated, the second turned a quarter
+/// about z.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "scenes": [{"nodes": [0, 1]}],
+    "nodes": [
+        {"mesh": 0, "translation": [10, 0, 0], "children": [2, 3]},
+        {"mesh": 1, "rotation": [0, 0, 0.70710678, 0.70710678]},
+        {"mesh": 2},
+        {"mesh": 3}
+    ],
+    "meshes": [
+        {"primitives": [{"attributes": {"POSITION": 0}}]},
+        {"primitives": [{"attributes": {"POSITION": 1}}, {"attributes": {"POSITION": 2}}]},
+        {"primitives": [{"attributes": {"POSITION": 2}}]},
+        {"primitives": []}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [-1, 0, 0], "max": [1, 2, 3]},
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3"},
+        {"bufferView": 0, "componentType": 5126, "count": 0, "type": "VEC3"}
+    ],
+    "bufferViews": [{"buffer": 0, "byteLength": 36}],
+    "buffers": [{"byteLength": 36}]
+}"#;
+
+const POSITIONS: [f32; 9] = [0.0, 0.0, 0.0, 1.0, 2.0, 3.0, -1.0, 0.5, 2.0];
+
+fn buffers() -> Vec<buffer::Data> {
+    let data = POSITIONS.iter().flat_map(|x| x.to_le_bytes()).collect();
+    vec![buffer::Data(data)]
+}
+
+fn assert_bounds(actual: Option<BoundingBox>, expected: BoundingBox) {
+    let actual = actual.unwrap();
+    assert_relative_eq!(actual.min[..], expected.min[..], epsilon = 1e-6);
+    assert_relative_eq!(actual.max[..], expected.max[..], epsilon = 1e-6);
+}
+
+#[test]
+fn primitive_and_mesh_bounds() {
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let buffers = buffers();
+    let mut meshes = gltf.meshes();
+    let declared = meshes.next().unwrap();
+    let primitive = declared.primitives().next().unwrap();
+    let expected = Bounds {
+        min: [-1.0, 0.0, 0.0],
+        max: [1.0, 2.0, 3.0],
+    };
+    assert_eq!(primitive.compute_bounding_box(&[]), Some(expected.clone()));
+    assert_eq!(primitive.bounding_box(), expected);
+
+    // The same positions without declared bounds are scanned.
+    let scanned = meshes.next().unwrap();
+    let mut primitives = scanned.primitives();
+    let primitive = primitives.next().unwrap();
+    assert_eq!(
+        primitive.compute_bounding_box(&buffers),
+        Some(expected.clone())
+    );
+    assert_eq!(primitive.compute_bounding_box(&[]), None);
+    assert_eq!(
+        primitives.next().unwrap().compute_bounding_box(&buffers),
+        None
+    );
+    assert_eq!(scanned.compute_bounding_box(&buffers), Some(expected));
+
+    assert_eq!(meshes.next().unwrap().compute_bounding_box(&buffers), None);
+    assert_eq!(meshes.next().unwrap().compute_bounding_box(&buffers), None);
+}
+
+#[test]
+fn scene_bounds_are_in_world_space() {
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let scene = gltf.scenes().next().unwrap();
+    assert_bounds(
+        scene.bounding_box(&buffers()),
+        Bounds {
+            min: [-2.0, -1.0, 0.0],
+            max: [11.0, 2.0, 3.0],
+        },
+    );
+
+    let gltf = GLTF.replace(
+        r#""scenes": [{"nodes": [0, 1]}]"#,
+        r#""scenes": [{"nodes": [2, 3]}]"#,
+    );
+    let gltf = Gltf::from_slice_without_validation(gltf.as_bytes()).unwrap();
+    let scene = gltf.scenes().next().unwrap();
+    assert_eq!(scene.bounding_box(&buffers()), None);
+}
@@ -0,0 +1,102 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use gltf::{Error, Gltf};
+
+/// A document with an external buffer and an embedded one.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [
+        {"byteLength": 6, "uri": "scene.bin"},
+        {"byteLength": 3, "uri": "data:application/octet-stream,%00%01%02"}
+    ]
+}"#;
+
+#[test]
+fn external_buffers_are_resolved() {
+    let document = Gltf::from_slice(GLTF.as_bytes()).unwrap().document;
+    let mut uris = Vec::new();
+    let buffers = gltf::import_buffers_with(&document, None, |uri| {
+        uris.push(uri.to_owned());
+        Ok::<_, Error>(vec![1, 2, 3, 4, 5, 6])
+    })
+    .unwrap();
+    assert_eq!(uris, ["scene.bin"]);
+    assert_eq!(buffers[0].0, [1, 2, 3, 4, 5, 6, 0, 0]);
+    assert_eq!(buffers[1].0, [0, 1, 2, 0]);
+}
+
+#[test]
+fn resolved_buffers_are_checked() {
+    let document = Gltf::from_slice(GLTF.as_bytes()).unwrap().document;
+    let result = gltf::import_buffers_with(&document, None, |_| Ok::<_, Error>(vec![1, 2]));
+    assert!(matches!(
+        result,
+        Err(Error::BufferLength {
+            buffer: 0,
+            expected: 6,
+            actual: 4
+        })
+    ));
+
+    let error = gltf::import_buffers_with(&document, None, |_| Err("not in flash")).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "buffer 0: failed to resolve scene.bin: not in flash"
+    );
+    match error {
+        Error::ExternalBuffer { index, uri, error } => {
+            assert_eq!(index, 0);
+            assert_eq!(uri, "scene.bin");
+            assert_eq!(error.to_string(), "not in flash");
+        }
+        error => panic!("unexpected error: {}", error),
+    }
+
+    match gltf::import_buffers(&document, None) {
+        Err(Error::UnresolvedUri {
+            buffer,
+            length,
+            uri,
+        }) => assert_eq!((buffer, length, &uri[..]), (0, 6, "scene.bin")),
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+/// `two_buffers.glb` holds its positions in the `BIN` chunk and its indices in
+/// the external `two_buffers.bin`.
+#[test]
+fn only_the_bin_buffer_takes_the_blob() {
+    let Gltf { document, blob } =
+        Gltf::from_slice(&std::fs::read("tests/two_buffers.glb").unwrap()).unwrap();
+    let error = gltf::import_buffers(&document, blob.clone()).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "buffer 1: cannot resolve two_buffers.bin for 6 bytes without a resolver"
+    );
+    assert_eq!(
+        gltf::import_buffers(&document, None)
+            .unwrap_err()
+            .to_string(),
+        "buffer 0: missing binary portion of binary glTF for 36 bytes"
+    );
+
+    let buffers = gltf::import_buffers_with(&document, blob, |uri| {
+        std::fs::read(format!("tests/{}", uri))
+    })
+    .unwrap();
+    assert_eq!((buffers[0].len(), buffers[1].len()), (36, 8));
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    let reader = primitive.reader(|buffer| buffers.get(buffer.index()).map(|data| &data[..]));
+    let indices: Vec<u32> = reader.read_indices().unwrap().into_u32().collect();
+    assert_eq!(indices, [0, 1, 2]);
+    assert_eq!(
+        reader.read_positions().unwrap().nth(1),
+        Some([1.0, 0.0, 0.0])
+    );
+}
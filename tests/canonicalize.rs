@@ -0,0 +1,279 @@
+#![cfg(all(
+    feature = "animation",
+    feature = "cameras",
+    feature = "names",
+    feature = "skinning",
+    feature = "utils"
+))]
+
+use gltf::json::{self, Index};
+use gltf::{buffer, transform, Document, Gltf};
+
+/// Two meshes with materials and textures, an image embedded in the buffer
+/// and one referenced by URI, a camera, a skin, an animation, and two scenes
+/// besides a node that neither contains.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["KHR_texture_transform", "KHR_materials_unlit"],
+    "scene": 1,
+    "scenes": [{"name": "main", "nodes": [0]}, {"name": "other", "nodes": [4]}],
+    "nodes": [
+        {"name": "root", "children": [1, 2]},
+        {"mesh": 0, "skin": 0},
+        {"mesh": 1, "children": [3], "translation": [1, 0, 0]},
+        {"camera": 1},
+        {"mesh": 1, "camera": 0},
+        {"name": "orphan"}
+    ],
+    "cameras": [
+        {"type": "perspective", "perspective": {"yfov": 1.0, "znear": 0.1}},
+        {"type": "orthographic",
+         "orthographic": {"xmag": 1, "ymag": 1, "zfar": 10, "znear": 0.1}}
+    ],
+    "skins": [{"joints": [0, 2]}],
+    "meshes": [
+        {"primitives": [{"attributes": {"POSITION": 0}, "indices": 2, "material": 0}]},
+        {"primitives": [{"attributes": {"POSITION": 1}, "material": 1}]}
+    ],
+    "materials": [
+        {"name": "red", "pbrMetallicRoughness": {"baseColorTexture": {"index": 0}}},
+        {"name": "blue", "pbrMetallicRoughness": {"baseColorTexture": {"index": 1}}}
+    ],
+    "textures": [{"source": 0, "sampler": 1}, {"source": 1, "sampler": 0}],
+    "samplers": [{"magFilter": 9728}, {"magFilter": 9729}],
+    "images": [
+        {"bufferView": 5, "mimeType": "image/png"},
+        {"uri": "blue.png"}
+    ],
+    "animations": [{
+        "channels": [{"sampler": 0, "target": {"node": 2, "path": "translation"}}],
+        "samplers": [{"input": 3, "output": 4}]
+    }],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0, 0], "max": [2, 2, 0]},
+        {"bufferView": 2, "componentType": 5123, "count": 3, "type": "SCALAR"},
+        {"bufferView": 3, "componentType": 5126, "count": 2, "type": "SCALAR",
+         "min": [0], "max": [1]},
+        {"bufferView": 4, "componentType": 5126, "count": 2, "type": "VEC3"}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 72, "byteLength": 6},
+        {"buffer": 0, "byteOffset": 80, "byteLength": 8},
+        {"buffer": 0, "byteOffset": 88, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 112, "byteLength": 5}
+    ],
+    "buffers": [{"byteLength": 117}]
+}"#;
+
+fn floats(values: &[f32]) -> Vec<u8> {
+    values.iter().flat_map(|x| x.to_le_bytes()).collect()
+}
+
+fn load() -> (json::Root, Vec<buffer::Data>) {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let mut data = floats(&[
+        0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, // first triangle
+        0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0, 0.0, // second triangle
+    ]);
+    data.extend([0, 0, 1, 0, 2, 0, 0, 0]);
+    data.extend(floats(&[0.0, 1.0, 1.0, 0.0, 0.0, 2.0, 0.0, 0.0]));
+    data.extend(b"\x89PNG\0");
+    (gltf.document.into_json(), vec![buffer::Data(data)])
+}
+
+/// A deterministic pseudo-random number generator.
+struct Lcg(u64);
+
+impl Lcg {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        ((self.0 >> 33) % n as u64) as usize
+    }
+
+    /// Shuffles the items, returning a mapping from old to new indices.
+    fn shuffle<T>(&mut self, items: &mut Vec<T>) -> Vec<u32> {
+        let mut order: Vec<usize> = (0..items.len()).collect();
+        for i in (1..order.len()).rev() {
+            order.swap(i, self.below(i + 1));
+        }
+        let mut old: Vec<Option<T>> = items.drain(..).map(Some).collect();
+        let mut map = vec![0; order.len()];
+        for (new, &index) in order.iter().enumerate() {
+            map[index] = new as u32;
+            items.push(old[index].take().unwrap());
+        }
+        map
+    }
+}
+
+fn remap<T>(map: &[u32], index: &mut Index<T>) {
+    *index = Index::new(map[index.value()]);
+}
+
+/// Shuffles every top-level array of the fixture and the layout of the
+/// buffer, rewriting the references accordingly.
+fn shuffle(root: &mut json::Root, buffers: &mut [buffer::Data], seed: u64) {
+    let mut rng = Lcg(seed);
+
+    let map = rng.shuffle(&mut root.accessors);
+    for primitive in root.meshes.iter_mut().flat_map(|x| x.primitives.iter_mut()) {
+        primitive
+            .attributes
+            .values_mut()
+            .for_each(|x| remap(&map, x));
+        primitive.indices.iter_mut().for_each(|x| remap(&map, x));
+    }
+    for sampler in root
+        .animations
+        .iter_mut()
+        .flat_map(|x| x.samplers.iter_mut())
+    {
+        remap(&map, &mut sampler.input);
+        remap(&map, &mut sampler.output);
+    }
+
+    // Moves the view data to new places, with garbage in between.
+    let map = rng.shuffle(&mut root.buffer_views);
+    let mut data = Vec::new();
+    for view in root.buffer_views.iter_mut() {
+        data.extend((0..rng.below(3) * 4).map(|_| 0xaa));
+        let start = view.byte_offset.unwrap_or_default().0 as usize;
+        let end = start + view.byte_length.0 as usize;
+        view.byte_offset = Some(data.len().into());
+        data.extend_from_slice(&buffers[0][start..end]);
+    }
+    root.buffers[0].byte_length = data.len().into();
+    buffers[0] = buffer::Data(data);
+    for accessor in root.accessors.iter_mut() {
+        accessor.buffer_view.iter_mut().for_each(|x| remap(&map, x));
+    }
+    for image in root.images.iter_mut() {
+        image.buffer_view.iter_mut().for_each(|x| remap(&map, x));
+    }
+
+    let map = rng.shuffle(&mut root.images);
+    root.textures
+        .iter_mut()
+        .for_each(|x| remap(&map, &mut x.source));
+    let map = rng.shuffle(&mut root.samplers);
+    for texture in root.textures.iter_mut() {
+        texture.sampler.iter_mut().for_each(|x| remap(&map, x));
+    }
+    let map = rng.shuffle(&mut root.textures);
+    for material in root.materials.iter_mut() {
+        let info = material.pbr_metallic_roughness.base_color_texture.as_mut();
+        info.into_iter().for_each(|x| remap(&map, &mut x.index));
+    }
+    let map = rng.shuffle(&mut root.materials);
+    for primitive in root.meshes.iter_mut().flat_map(|x| x.primitives.iter_mut()) {
+        primitive.material.iter_mut().for_each(|x| remap(&map, x));
+    }
+
+    let map = rng.shuffle(&mut root.meshes);
+    root.nodes
+        .iter_mut()
+        .for_each(|x| x.mesh.iter_mut().for_each(|x| remap(&map, x)));
+    let map = rng.shuffle(&mut root.cameras);
+    root.nodes
+        .iter_mut()
+        .for_each(|x| x.camera.iter_mut().for_each(|x| remap(&map, x)));
+    let map = rng.shuffle(&mut root.skins);
+    root.nodes
+        .iter_mut()
+        .for_each(|x| x.skin.iter_mut().for_each(|x| remap(&map, x)));
+
+    let map = rng.shuffle(&mut root.nodes);
+    for node in root.nodes.iter_mut() {
+        node.children
+            .iter_mut()
+            .flatten()
+            .for_each(|x| remap(&map, x));
+    }
+    for scene in root.scenes.iter_mut() {
+        scene.nodes.iter_mut().for_each(|x| remap(&map, x));
+    }
+    for skin in root.skins.iter_mut() {
+        skin.joints.iter_mut().for_each(|x| remap(&map, x));
+    }
+    for channel in root
+        .animations
+        .iter_mut()
+        .flat_map(|x| x.channels.iter_mut())
+    {
+        remap(&map, &mut channel.target.node);
+    }
+
+    let map = rng.shuffle(&mut root.scenes);
+    root.scene.iter_mut().for_each(|x| remap(&map, x));
+    rng.shuffle(&mut root.animations);
+    rng.shuffle(&mut root.extensions_used);
+}
+
+#[test]
+fn shuffled_documents_serialize_identically() {
+    let (mut expected, mut expected_buffers) = load();
+    transform::canonicalize(&mut expected, &mut expected_buffers);
+    let json = expected.to_vec().unwrap();
+    assert_eq!(expected_buffers.len(), 1);
+    assert_eq!(expected.extensions_used[0], "KHR_materials_unlit");
+
+    for seed in 0..16 {
+        let (mut root, mut buffers) = load();
+        shuffle(&mut root, &mut buffers, seed);
+        Document::from_json(root.clone()).unwrap();
+        transform::canonicalize(&mut root, &mut buffers);
+        assert_eq!(
+            String::from_utf8(root.to_vec().unwrap()).unwrap(),
+            String::from_utf8(json.clone()).unwrap(),
+            "seed {}",
+            seed
+        );
+        assert_eq!(buffers[0].0, expected_buffers[0].0, "seed {}", seed);
+    }
+}
+
+#[test]
+fn canonical_order() {
+    let (mut root, mut buffers) = load();
+    let original = Document::from_json(root.clone()).unwrap();
+    transform::canonicalize(&mut root, &mut buffers);
+    let document = Document::from_json(root).unwrap();
+
+    // The hierarchy of the scenes comes first, in depth-first order.
+    let names: Vec<_> = document.nodes().map(|node| node.name()).collect();
+    assert_eq!(names[..4], [Some("root"), None, None, None]);
+    assert_eq!(names[5], Some("orphan"));
+    let scene = original.default_scene().unwrap().name();
+    assert_eq!(document.default_scene().unwrap().name(), scene);
+
+    // Materials sort by name, and the views follow their first use.
+    let materials: Vec<_> = document.materials().map(|x| x.name()).collect();
+    assert_eq!(materials, [Some("blue"), Some("red")]);
+    let mut offset = 0;
+    for accessor in document.accessors() {
+        let view = accessor.view().unwrap();
+        assert!(view.offset() >= offset);
+        offset = view.offset();
+    }
+
+    // The data is unchanged.
+    let reader = |document: &Document, buffers: &[buffer::Data]| {
+        let mesh = document
+            .nodes()
+            .find_map(|node| node.skin().and(node.mesh()));
+        let primitive = mesh.unwrap().primitives().next().unwrap();
+        let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()].0[..]));
+        let positions: Vec<_> = reader.read_positions().unwrap().collect();
+        let indices: Vec<_> = reader.read_indices().unwrap().into_u32().collect();
+        (positions, indices)
+    };
+    assert_eq!(reader(&document, &buffers), reader(&original, &load().1));
+}
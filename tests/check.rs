@@ -0,0 +1,154 @@
+use std::fs;
+
+use gltf::check::{CheckError, CheckLimits};
+use gltf::Gltf;
+
+fn check(json: &str) -> Result<gltf::check::CheckSummary, CheckError> {
+    check_with(json, &CheckLimits::default())
+}
+
+fn check_with(json: &str, limits: &CheckLimits) -> Result<gltf::check::CheckSummary, CheckError> {
+    match Gltf::check(json.as_bytes(), limits) {
+        Ok(summary) => Ok(summary),
+        Err(gltf::Error::Check(error)) => Err(error),
+        Err(error) => panic!("unexpected error: {}", error),
+    }
+}
+
+#[test]
+fn summarize_glb() {
+    let data = fs::read("tests/box_sparse.glb").unwrap();
+    let summary = Gltf::check(&data, &CheckLimits::default()).unwrap();
+    assert!(summary.binary);
+    assert_eq!(summary.json_length, 1720);
+    assert_eq!(summary.bin_length, Some(352));
+    assert_eq!(summary.version, (2, 0));
+    assert_eq!(summary.lengths.accessors, 5);
+    assert_eq!(summary.lengths.buffer_views, 6);
+    assert_eq!(summary.lengths.nodes, 1);
+    assert_eq!(summary.lengths.textures, 0);
+    assert_eq!(summary.buffer_bytes, 352);
+
+    let truncated = Gltf::check(&data[..1000], &CheckLimits::default());
+    assert!(matches!(truncated, Err(gltf::Error::Binary(_))));
+}
+
+#[test]
+fn summarize_gltf() {
+    let data = fs::read("tests/box_sparse.gltf").unwrap();
+    let summary = Gltf::check(&data, &CheckLimits::default()).unwrap();
+    assert!(!summary.binary && summary.bin_length.is_none());
+    assert_eq!(summary.json_length, data.len());
+    assert_eq!(summary.lengths.accessors, 5);
+    Gltf::from_slice(&data).unwrap();
+}
+
+#[test]
+fn versions() {
+    assert_eq!(check(r#"{"asset": {}}"#), Err(CheckError::MissingVersion));
+    assert_eq!(
+        check(r#"{"asset": {"version": "3.0"}}"#),
+        Err(CheckError::UnsupportedVersion { major: 3, minor: 0 })
+    );
+    assert_eq!(
+        check(r#"{"asset": {"version": "2.1", "minVersion": "2.1"}}"#),
+        Err(CheckError::UnsupportedVersion { major: 2, minor: 1 })
+    );
+    assert_eq!(
+        check(r#"{"asset": {"version": 2}}"#),
+        Err(CheckError::InvalidValue { offset: 22 })
+    );
+    assert_eq!(
+        check(r#"{"asset": {"version": "2.1"}}"#).unwrap().version,
+        (2, 1)
+    );
+}
+
+#[test]
+fn required_extensions() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["EXT_unknown", "KHR_mesh_quantization"],
+        "extensionsRequired": ["EXT_unknown"]
+    }"#;
+    assert_eq!(
+        check(json),
+        Err(CheckError::UnsupportedExtension("EXT_unknown".into()))
+    );
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["EXT_unknown"]
+    }"#;
+    let summary = check(json).unwrap();
+    assert_eq!(
+        (summary.extensions_used, summary.extensions_required),
+        (1, 0)
+    );
+}
+
+#[test]
+fn limits() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "nodes": [{}, {}, {}],
+        "buffers": [{"byteLength": 1000}, {"byteLength": 24, "uri": "a.bin"}],
+        "images": [
+            {"uri": "data:image/png;base64,AAAAAAAA"},
+            {"uri": "texture.png"}
+        ]
+    }"#;
+    let summary = check(json).unwrap();
+    assert_eq!(summary.lengths.nodes, 3);
+    assert_eq!(summary.buffer_bytes, 1024);
+    assert_eq!(summary.image_bytes, 6);
+
+    let limits = CheckLimits {
+        max_array_length: 2,
+        ..Default::default()
+    };
+    assert_eq!(
+        check_with(json, &limits),
+        Err(CheckError::ArrayLength {
+            name: "nodes",
+            limit: 2
+        })
+    );
+    let limits = CheckLimits {
+        max_buffer_bytes: 1000,
+        ..Default::default()
+    };
+    assert_eq!(
+        check_with(json, &limits),
+        Err(CheckError::BufferBytes { limit: 1000 })
+    );
+    let limits = CheckLimits {
+        max_image_bytes: 5,
+        ..Default::default()
+    };
+    assert_eq!(
+        check_with(json, &limits),
+        Err(CheckError::ImageBytes { limit: 5 })
+    );
+
+    let nested = format!(
+        r#"{{"asset": {{"version": "2.0"}}, "extras": {}{}}}"#,
+        "[".repeat(127),
+        "]".repeat(127)
+    );
+    assert!(check(&nested).is_ok());
+    let nested = nested.replacen('[', "[[", 1).replacen(']', "]]", 1);
+    assert_eq!(check(&nested), Err(CheckError::Depth { limit: 128 }));
+}
+
+#[test]
+fn invalid_values() {
+    assert_eq!(
+        check(r#"{"asset": {"version": "2.0"}, "buffers": [{"byteLength": 1.5}]}"#),
+        Err(CheckError::InvalidValue { offset: 57 })
+    );
+    assert_eq!(
+        check(r#"{"asset": {"version": "2.0"}, "nodes": {}}"#),
+        Err(CheckError::InvalidValue { offset: 39 })
+    );
+    assert_eq!(check("[]"), Err(CheckError::InvalidValue { offset: 0 }));
+}
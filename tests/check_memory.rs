@@ -0,0 +1,49 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use gltf::check::CheckLimits;
+use gltf::Gltf;
+
+/// Tracks the peak number of bytes allocated at once.
+struct Counting;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+        PEAK.fetch_max(allocated, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// This is the only test of this file, so that no other test allocates while
+/// the peak is measured.
+#[test]
+fn check_100_mb_in_constant_memory() {
+    const NODE: &[u8] = br#"{"name": "node", "translation": [1.0, 2.0, 3.0]},"#;
+    const SIZE: usize = 100 << 20;
+    let mut json = Vec::with_capacity(SIZE + 64);
+    json.extend_from_slice(br#"{"asset": {"version": "2.0"}, "nodes": ["#);
+    let count = SIZE / NODE.len();
+    for _ in 0..count {
+        json.extend_from_slice(NODE);
+    }
+    json.extend_from_slice(b"{}]}");
+
+    let before = ALLOCATED.load(Ordering::SeqCst);
+    PEAK.store(before, Ordering::SeqCst);
+    let summary = Gltf::check(&json, &CheckLimits::default()).unwrap();
+    let peak = PEAK.load(Ordering::SeqCst) - before;
+    assert_eq!(summary.lengths.nodes, count + 1);
+    assert_eq!(peak, 0);
+}
@@ -0,0 +1,143 @@
+use gltf::binary::{ChunkType, Error};
+use gltf::{ChunkAlignment, Glb, GlbOptions};
+
+/// A JSON chunk of 13 bytes, padded to 16 bytes without counting the padding,
+/// followed by a BIN chunk of 4 bytes.
+fn misaligned() -> Vec<u8> {
+    let json = br#"{"asset": {}}"#;
+    assert_eq!(json.len(), 13);
+    let mut data = b"glTF".to_vec();
+    data.extend_from_slice(&2u32.to_le_bytes());
+    data.extend_from_slice(&48u32.to_le_bytes());
+    data.extend_from_slice(&13u32.to_le_bytes());
+    data.extend_from_slice(b"JSON");
+    data.extend_from_slice(json);
+    data.extend_from_slice(b"   ");
+    data.extend_from_slice(&4u32.to_le_bytes());
+    data.extend_from_slice(b"BIN\0");
+    data.extend_from_slice(&[1, 2, 3, 4]);
+    assert_eq!(data.len(), 48);
+    data
+}
+
+fn options(chunk_alignment: ChunkAlignment) -> GlbOptions<'static> {
+    GlbOptions {
+        chunk_alignment,
+        ..Default::default()
+    }
+}
+
+/// Reads a GLB both from a slice and from a stream, checking that they agree.
+fn read(data: &[u8], options: GlbOptions) -> Result<Glb<'static>, gltf::Error> {
+    let streamed = Glb::from_reader_with(data, options);
+    match Glb::from_slice_with(data, options) {
+        Ok(glb) => {
+            let streamed = streamed.unwrap();
+            assert_eq!(streamed.json, glb.json);
+            assert_eq!(streamed.bin, glb.bin);
+            Ok(streamed)
+        }
+        Err(error) => {
+            assert!(streamed.is_err());
+            Err(error)
+        }
+    }
+}
+
+#[test]
+fn unpadded_lengths() {
+    let data = misaligned();
+
+    // By default, the padding is read as the next chunk header.
+    match Glb::from_slice(&data) {
+        Err(gltf::Error::Binary(Error::ChunkLength { .. })) => {}
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+    assert!(read(&data, options(ChunkAlignment::Exact)).is_err());
+
+    let glb = read(&data, options(ChunkAlignment::Padded)).unwrap();
+    assert_eq!(&*glb.json, br#"{"asset": {}}"#);
+    assert_eq!(glb.bin.as_deref(), Some(&[1, 2, 3, 4][..]));
+    assert!(glb.extra_chunks.is_empty());
+
+    match read(&data, options(ChunkAlignment::Strict)) {
+        Err(gltf::Error::Binary(Error::ChunkAlignment {
+            ty: ChunkType::Json,
+            length: 13,
+        })) => {}
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn aligned_lengths() {
+    let glb = Glb {
+        header: gltf::binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: br#"{"asset": {}}"#[..].into(),
+        bin: Some(vec![1, 2, 3, 4, 5].into()),
+        extra_chunks: Vec::new(),
+        length_read: None,
+        chunk_alignment: ChunkAlignment::Exact,
+    };
+    let data = glb.to_vec().unwrap();
+    for alignment in [
+        ChunkAlignment::Exact,
+        ChunkAlignment::Padded,
+        ChunkAlignment::Strict,
+    ] {
+        let read = read(&data, options(alignment)).unwrap();
+        assert_eq!(read.json.len(), 16);
+        assert_eq!(read.bin.as_deref(), Some(&[1, 2, 3, 4, 5, 0, 0, 0][..]));
+    }
+
+    // A final chunk that is not padded at all is only rejected by the strict
+    // alignment.
+    let mut data = data;
+    data.truncate(49);
+    data[8..12].copy_from_slice(&49u32.to_le_bytes());
+    data[36..40].copy_from_slice(&5u32.to_le_bytes());
+    for alignment in [ChunkAlignment::Exact, ChunkAlignment::Padded] {
+        let read = read(&data, options(alignment)).unwrap();
+        assert_eq!(read.bin.as_deref(), Some(&[1, 2, 3, 4, 5][..]));
+    }
+    match read(&data, options(ChunkAlignment::Strict)) {
+        Err(gltf::Error::Binary(Error::ChunkAlignment {
+            ty: ChunkType::Bin,
+            length: 5,
+        })) => {}
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn padded_layout() {
+    // A JSON chunk of 27 bytes followed by one byte of padding that its
+    // length does not count.
+    let json = br#"{"asset":{"version":"2.0"}}"#;
+    assert_eq!(json.len(), 27);
+    let mut data = b"glTF".to_vec();
+    data.extend_from_slice(&2u32.to_le_bytes());
+    data.extend_from_slice(&60u32.to_le_bytes());
+    data.extend_from_slice(&27u32.to_le_bytes());
+    data.extend_from_slice(b"JSON");
+    data.extend_from_slice(json);
+    data.push(b' ');
+    data.extend_from_slice(&4u32.to_le_bytes());
+    data.extend_from_slice(b"BIN\0");
+    data.extend_from_slice(&[1, 2, 3, 4]);
+    assert_eq!(data.len(), 60);
+
+    let glb = read(&data, options(ChunkAlignment::Padded)).unwrap();
+    let layout = glb.layout();
+    assert_eq!(layout.json, 20..47);
+    assert_eq!(layout.bin, Some(56..60));
+    assert_eq!(&data[56..60], &[1, 2, 3, 4]);
+
+    let document = gltf::Gltf::from_slice(&glb.json).unwrap().document;
+    let ranges = glb.view_ranges(&document).unwrap();
+    assert_eq!(ranges.bin, Some(56..60));
+}
@@ -0,0 +1,179 @@
+#[cfg(feature = "extras")]
+use gltf::convention::ConventionKeys;
+use gltf::convention::{Axis, Convention};
+use gltf::transform::{self, NormalizeTarget};
+use gltf::{buffer, Document, Gltf};
+
+/// A box from `[0, 0, 0]` to `[4, 2, 2]` on a node translated to
+/// `[10, 0, 0]`, exported in centimeters with +Z up.
+const GLTF: &str = r#"{
+    "asset": {
+        "version": "2.0",
+        "extras": {"unit_scale": 0.01, "original_up": "Z", "exporter": {"name": "dcc"}}
+    },
+    "scene": 0,
+    "scenes": [{"nodes": [0]}],
+    "nodes": [{"mesh": 0, "translation": [10, 0, 0]}],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}]}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+         "min": [0, 0, 0], "max": [4, 2, 2]}
+    ],
+    "bufferViews": [{"buffer": 0, "byteLength": 24}],
+    "buffers": [{"byteLength": 24}]
+}"#;
+
+fn load() -> (gltf::json::Root, Vec<buffer::Data>) {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let data = [0.0f32, 0.0, 0.0, 4.0, 2.0, 2.0]
+        .iter()
+        .flat_map(|x| x.to_le_bytes())
+        .collect();
+    (gltf.document.into_json(), vec![buffer::Data(data)])
+}
+
+#[cfg(feature = "extras")]
+fn with_extras(extras: &str) -> Document {
+    let gltf = format!(r#"{{"asset": {{"version": "2.0", "extras": {}}}}}"#, extras);
+    Gltf::from_slice(gltf.as_bytes()).unwrap().document
+}
+
+fn assert_close(actual: [f32; 3], expected: [f32; 3]) {
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn declared_conventions() {
+    let document = Document::from_json(load().0).unwrap();
+    assert_eq!(document.declared_unit_scale(), Some(0.01));
+    assert_eq!(document.declared_up_axis(), Some(Axis::Z));
+    assert_eq!(Convention::FromDeclared.resolve(&document), (0.01, Axis::Z));
+
+    let document = with_extras(r#"{"scale": 2, "up": "+x"}"#);
+    assert_eq!(document.declared_unit_scale(), None);
+    assert_eq!(document.declared_up_axis(), None);
+    let keys = ConventionKeys {
+        unit_scale: "scale",
+        up_axis: "up",
+    };
+    assert_eq!(document.declared_unit_scale_with(keys), Some(2.0));
+    assert_eq!(document.declared_up_axis_with(keys), Some(Axis::X));
+}
+
+#[cfg(not(feature = "extras"))]
+#[test]
+fn extras_are_not_read_without_the_feature() {
+    let document = Document::from_json(load().0).unwrap();
+    assert_eq!(document.declared_unit_scale(), None);
+    assert_eq!(document.declared_up_axis(), None);
+    assert_eq!(Convention::FromDeclared.resolve(&document), (1.0, Axis::Y));
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn malformed_conventions_are_not_declared() {
+    for extras in [
+        r#"{"unit_scale": "0.01", "original_up": "W"}"#,
+        r#"{"unit_scale": -1, "original_up": 2}"#,
+        r#"{"unit_scale": 0, "original_up": null}"#,
+        r#"[0.01, "Z"]"#,
+        "null",
+    ] {
+        let document = with_extras(extras);
+        assert_eq!(document.declared_unit_scale(), None, "{}", extras);
+        assert_eq!(document.declared_up_axis(), None, "{}", extras);
+        assert_eq!(Convention::FromDeclared.resolve(&document), (1.0, Axis::Y));
+    }
+}
+
+#[test]
+fn coordinates_are_converted() {
+    let (mut root, _) = load();
+    let from = Convention::Explicit {
+        unit_scale: 1.0,
+        up_axis: Axis::Y,
+    };
+    assert_eq!(transform::convert_coordinates(&mut root, 0, from), None);
+    assert_eq!(
+        transform::convert_coordinates(&mut root, 0, Convention::Gltf),
+        None
+    );
+    assert_eq!(transform::convert_coordinates(&mut root, 1, from), None);
+    assert_eq!(root.nodes.len(), 1);
+
+    let from = Convention::Explicit {
+        unit_scale: 0.01,
+        up_axis: Axis::Z,
+    };
+    assert_eq!(transform::convert_coordinates(&mut root, 0, from), Some(1));
+    assert_eq!(root.scenes[0].nodes[0].value(), 1);
+    let wrapper = &root.nodes[1];
+    assert_eq!(wrapper.children.as_ref().unwrap()[0].value(), 0);
+    assert_eq!(wrapper.scale, Some([0.01; 3]));
+    let rotation = wrapper.rotation.unwrap().0;
+    assert_eq!(rotation, Axis::Z.rotation_to_y_up());
+
+    // +Z is now up.
+    let document = Document::from_json(root).unwrap();
+    let node = document.nodes().nth(1).unwrap();
+    let matrix = node.transform().matrix();
+    assert_close([matrix[2][0], matrix[2][1], matrix[2][2]], [0.0, 0.01, 0.0]);
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn declared_extras_round_trip() {
+    let (mut root, _) = load();
+    let extras = root.asset.extras.as_ref().unwrap().get().to_owned();
+    let wrapper = transform::convert_coordinates(&mut root, 0, Convention::FromDeclared);
+    assert_eq!(wrapper, Some(1));
+    assert_eq!(root.nodes[1].scale, Some([0.01; 3]));
+
+    let json = root.to_string().unwrap();
+    let document = Gltf::from_slice(json.as_bytes()).unwrap().document;
+    assert_eq!(
+        document.as_json().asset.extras.as_ref().unwrap().get(),
+        extras
+    );
+    assert_eq!(document.declared_unit_scale(), Some(0.01));
+    assert_eq!(document.declared_up_axis(), Some(Axis::Z));
+}
+
+#[test]
+fn normalization_turns_the_up_axis() {
+    let convention = Convention::Explicit {
+        unit_scale: 0.01,
+        up_axis: Axis::Z,
+    };
+    for bake in [false, true] {
+        let (mut root, mut buffers) = load();
+        let target = NormalizeTarget {
+            bake,
+            convention,
+            ..Default::default()
+        };
+        let normalization = transform::normalize_scene(&mut root, &mut buffers, 0, target)
+            .unwrap()
+            .unwrap();
+        assert_eq!(normalization.bounds.min, [10.0, 0.0, 0.0]);
+        assert_eq!(normalization.bounds.max, [14.0, 2.0, 2.0]);
+        assert_eq!(normalization.rotation, Axis::Z.rotation_to_y_up());
+        assert_eq!(normalization.scale, 0.25);
+        assert_eq!(normalization.translation, [-3.0, -0.25, 0.25]);
+        assert_eq!(normalization.wrapper, Some(1));
+        assert_eq!(root.nodes[1].scale.is_some(), !bake);
+
+        // The scene now fits the unit cube at the origin, with the box
+        // standing on its former XY plane.
+        let again =
+            transform::normalize_scene(&mut root, &mut buffers, 0, NormalizeTarget::default())
+                .unwrap()
+                .unwrap();
+        assert_close(again.bounds.min, [-0.5, -0.25, -0.25]);
+        assert_close(again.bounds.max, [0.5, 0.25, 0.25]);
+        assert_eq!(again.rotation, [0.0, 0.0, 0.0, 1.0]);
+    }
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "animation")]
+
+use gltf::analysis::dangling_animation_targets;
+use gltf::transform::{strip_dangling_channels, StrippedChannels};
+use gltf::{buffer, json, Document, Warning};
+
+/// A scene with an animated node, a skinned mesh whose joint is kept apart
+/// from the scene hierarchy, and a control rig outside the scene animated
+/// by an animation of its own and by a channel of the first animation.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "scene": 0,
+    "scenes": [{"nodes": [0, 1]}],
+    "nodes": [
+        {"name": "Lamp"},
+        {"name": "Body", "mesh": 0, "skin": 0},
+        {"name": "Bone"},
+        {"name": "Rig"}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}]}],
+    "skins": [{"joints": [2]}],
+    "animations": [
+        {
+            "channels": [
+                {"sampler": 0, "target": {"node": 0, "path": "translation"}},
+                {"sampler": 1, "target": {"node": 3, "path": "translation"}},
+                {"sampler": 0, "target": {"node": 2, "path": "translation"}}
+            ],
+            "samplers": [
+                {"input": 1, "output": 2},
+                {"input": 1, "output": 3}
+            ]
+        },
+        {
+            "channels": [
+                {"sampler": 0, "target": {"node": 3, "path": "translation"}}
+            ],
+            "samplers": [{"input": 4, "output": 3}]
+        }
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3",
+         "min": [0, 0, 0], "max": [0, 0, 0]},
+        {"bufferView": 1, "componentType": 5126, "count": 1, "type": "SCALAR",
+         "min": [0], "max": [0]},
+        {"bufferView": 2, "componentType": 5126, "count": 1, "type": "VEC3"},
+        {"bufferView": 3, "componentType": 5126, "count": 1, "type": "VEC3"},
+        {"bufferView": 4, "componentType": 5126, "count": 1, "type": "SCALAR",
+         "min": [1], "max": [1]}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 12, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 16, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 28, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 40, "byteLength": 4}
+    ],
+    "buffers": [{"byteLength": 44}]
+}"#;
+
+fn load() -> (json::Root, Vec<buffer::Data>) {
+    let root = json::Root::from_slice(GLTF.as_bytes()).unwrap();
+    let mut data = Vec::new();
+    for value in [0.0f32, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 1.0] {
+        data.extend(value.to_le_bytes());
+    }
+    (root, vec![buffer::Data(data)])
+}
+
+#[test]
+fn detection() {
+    let (root, _) = load();
+    let document = Document::from_json(root.clone()).unwrap();
+    assert_eq!(
+        dangling_animation_targets(&document),
+        [(0, 1, 3), (1, 0, 3)]
+    );
+
+    let gltf = gltf::Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let warnings: Vec<_> = gltf
+        .warnings()
+        .into_iter()
+        .filter(|warning| matches!(warning, Warning::DanglingAnimationTarget { .. }))
+        .collect();
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(
+        warnings[0].to_string(),
+        "channel 1 of animation 0 targets node 3, which is outside the scenes"
+    );
+
+    // Without scenes, every node is inside them.
+    let mut root = root;
+    root.scenes.clear();
+    root.scene = None;
+    let document = Document::from_json(root).unwrap();
+    assert!(dangling_animation_targets(&document).is_empty());
+}
+
+#[test]
+fn stripping() {
+    let (mut root, mut buffers) = load();
+    let stripped = strip_dangling_channels(&mut root, &mut buffers);
+    assert_eq!(
+        stripped,
+        StrippedChannels {
+            channels: vec![(0, 1, 3), (1, 0, 3)],
+            samplers: vec![(0, 1), (1, 0)],
+            animations: vec![1],
+            accessors: vec![3, 4],
+            views: vec![3, 4],
+        }
+    );
+
+    assert_eq!(root.nodes.len(), 4);
+    assert_eq!(root.animations.len(), 1);
+    let animation = &root.animations[0];
+    assert_eq!(animation.channels.len(), 2);
+    assert_eq!(animation.samplers.len(), 1);
+    assert!(animation
+        .channels
+        .iter()
+        .all(|channel| channel.sampler.value() == 0));
+    assert_eq!(root.accessors.len(), 3);
+    assert_eq!(root.buffer_views.len(), 3);
+    assert_eq!(root.scene.map(|scene| scene.value()), Some(0));
+    assert_eq!(buffers.len(), 1);
+
+    let document = Document::from_json(root.clone()).unwrap();
+    assert!(dangling_animation_targets(&document).is_empty());
+    assert_eq!(
+        strip_dangling_channels(&mut root, &mut buffers),
+        StrippedChannels::default()
+    );
+}
@@ -0,0 +1,64 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use gltf::Error;
+
+/// A triangle whose positions are in a base 64 encoded buffer and whose
+/// indices are in a percent-encoded one.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0}, "indices": 1}]}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 1, "componentType": 5121, "count": 3, "type": "SCALAR"}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 1, "byteLength": 3}
+    ],
+    "buffers": [
+        {"byteLength": 36, "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAAAA"},
+        {"byteLength": 3, "uri": "data:application/octet-stream,%00%01%02"}
+    ]
+}"#;
+
+#[test]
+fn embedded_buffers_are_decoded() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    assert_eq!(buffers[1].0, [0, 1, 2, 0]);
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()].0[..]));
+    let positions: Vec<_> = reader.read_positions().unwrap().collect();
+    assert_eq!(
+        positions,
+        [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]
+    );
+    let indices: Vec<_> = reader.read_indices().unwrap().into_u32().collect();
+    assert_eq!(indices, [0, 1, 2]);
+}
+
+#[test]
+fn plain_data_uris_are_percent_decoded() {
+    let gltf = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 9, "uri": "data:,a%20b%2%zz%41"}]
+    }"#;
+    let (_, buffers) = gltf::import_slice(gltf).unwrap();
+    assert_eq!(buffers[0].0, b"a b%2%zzA\0\0\0");
+}
+
+#[test]
+fn malformed_base64_names_the_buffer() {
+    let gltf = GLTF.replace("AAAAAAAAAAAAAAAAAACAPwAA", "AAAA*AAAAAAAAAAAAACAPwAA");
+    let error = gltf::import_slice(gltf.as_bytes()).unwrap_err();
+    assert!(matches!(error, Error::MalformedBase64 { buffer: 0, .. }));
+    assert!(error
+        .to_string()
+        .starts_with("buffer 0: malformed base 64 data URI"));
+}
@@ -0,0 +1,88 @@
+use gltf::analysis;
+use gltf::fault::EntityKind;
+use gltf::Gltf;
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "scenes": [{"nodes": [0]}],
+    "nodes": [{"name": "Root \"A\"", "mesh": 0, "children": [1]}, {}],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0}, "material": 0}]}],
+    "materials": [{"pbrMetallicRoughness": {"baseColorTexture": {"index": 0}}}],
+    "textures": [{"source": 0, "sampler": 0}],
+    "samplers": [{}],
+    "images": [{"bufferView": 1, "mimeType": "image/png"}],
+    "accessors": [{"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3",
+                   "min": [0, 0, 0], "max": [0, 0, 0]}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 12, "byteLength": 4}
+    ],
+    "buffers": [{"byteLength": 16}]
+}"#;
+
+#[test]
+fn references() {
+    use EntityKind::*;
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let graph = analysis::dependency_graph(&gltf);
+    assert_eq!(graph.entities().len(), 13);
+    if cfg!(feature = "names") {
+        assert_eq!(graph.get(Node, 0).unwrap().name, Some("Root \"A\""));
+    }
+
+    let referenced = |kind, index| -> Vec<_> {
+        graph
+            .references(kind, index)
+            .map(|entity| (entity.kind, entity.index))
+            .collect()
+    };
+    assert_eq!(referenced(Scene, 0), [(Node, 0)]);
+    assert_eq!(referenced(Node, 0), [(Node, 1), (Mesh, 0)]);
+    assert_eq!(referenced(Mesh, 0), [(Primitive { mesh: 0 }, 0)]);
+    assert_eq!(
+        referenced(Primitive { mesh: 0 }, 0),
+        [(Accessor, 0), (Material, 0)]
+    );
+    assert_eq!(referenced(Material, 0), [(Texture, 0)]);
+    assert_eq!(referenced(Texture, 0), [(Sampler, 0), (Image, 0)]);
+    assert_eq!(referenced(Image, 0), [(View, 1)]);
+    assert_eq!(referenced(Accessor, 0), [(View, 0)]);
+    assert_eq!(referenced(View, 1), [(Buffer, 0)]);
+    assert!(referenced(Node, 1).is_empty());
+    assert!(referenced(Buffer, 0).is_empty());
+    assert_eq!(graph.edges().count(), 13);
+}
+
+#[test]
+fn dot_output() {
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let graph = analysis::dependency_graph(&gltf);
+
+    let mut dot = String::new();
+    graph.to_dot(&mut dot, None).unwrap();
+    assert!(dot.starts_with("digraph gltf {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains("    primitive_0_0 -> material_0;\n"));
+    assert_eq!(dot.matches(" -> ").count(), 13);
+
+    let mut dot = String::new();
+    graph.to_dot(&mut dot, Some(EntityKind::Texture)).unwrap();
+    let expected = "digraph gltf {
+    material_0 [label=\"Material 0\"];
+    texture_0 [label=\"Texture 0\"];
+    sampler_0 [label=\"Sampler 0\"];
+    image_0 [label=\"Image 0\"];
+    material_0 -> texture_0;
+    texture_0 -> sampler_0;
+    texture_0 -> image_0;
+}
+";
+    assert_eq!(dot, expected);
+
+    #[cfg(feature = "names")]
+    {
+        let mut dot = String::new();
+        graph.to_dot(&mut dot, Some(EntityKind::Scene)).unwrap();
+        assert!(dot.contains("    node_0 [label=\"Node 0\\nRoot \\\"A\\\"\"];\n"));
+    }
+}
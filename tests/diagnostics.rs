@@ -0,0 +1,108 @@
+use gltf::diagnostic::{Code, Severity, ValidationOptions};
+use gltf::Gltf;
+
+/// Returns the listing of `DIAGNOSTICS.md`.
+fn listing() -> String {
+    let mut listing = String::from(
+        "# Diagnostic codes\n\
+         \n\
+         The codes of `gltf::diagnostic::Code`, with their default severities. Codes\n\
+         are never reused or changed; `tests/diagnostics.rs` checks this listing\n\
+         against `Code::ALL`.\n\
+         \n\
+         | Code | Severity | Description |\n\
+         |------|----------|-------------|\n",
+    );
+    for code in Code::ALL {
+        listing += &format!(
+            "| {} | {} | {} |\n",
+            code,
+            code.severity(),
+            code.description()
+        );
+    }
+    listing
+}
+
+#[test]
+fn codes_are_listed() {
+    let listed = include_str!("../DIAGNOSTICS.md").replace("\r\n", "\n");
+    assert!(
+        listed == listing(),
+        "DIAGNOSTICS.md is out of date; the codes are:\n{}",
+        listing()
+    );
+    for (i, code) in Code::ALL.iter().enumerate() {
+        assert_eq!(Code::from_code(code.code()), Some(*code));
+        assert!(Code::ALL[..i].iter().all(|x| x.code() != code.code()));
+    }
+}
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["KHR_materials_pbrSpecularGlossiness"],
+    "meshes": [{"primitives": []}],
+    "nodes": [{"mesh": 3}]
+}"#;
+
+#[test]
+fn suppression_and_promotion() {
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let report = gltf.report(&ValidationOptions::default());
+    let codes: Vec<_> = report.diagnostics.iter().map(|x| x.code.code()).collect();
+    assert_eq!(codes, ["E0001", "W0003", "I0001"]);
+    assert_eq!(report.max_severity(), Some(Severity::Error));
+    assert_eq!(
+        report.diagnostics[0].to_string(),
+        "error[E0001]: nodes[0].mesh: Index out of bounds"
+    );
+
+    let options = ValidationOptions {
+        suppress: &[Code::IndexOutOfBounds],
+        promote: &[(Code::DeprecatedExtension, Severity::Warning)],
+    };
+    let report = gltf.report(&options);
+    let findings: Vec<_> = report
+        .diagnostics
+        .iter()
+        .map(|x| (x.code, x.severity))
+        .collect();
+    assert_eq!(
+        findings,
+        [
+            (Code::EmptyMesh, Severity::Warning),
+            (Code::DeprecatedExtension, Severity::Warning)
+        ]
+    );
+    assert_eq!(report.max_severity(), Some(Severity::Warning));
+
+    let options = ValidationOptions {
+        suppress: &[
+            Code::IndexOutOfBounds,
+            Code::EmptyMesh,
+            Code::DeprecatedExtension,
+        ],
+        ..Default::default()
+    };
+    assert_eq!(gltf.report(&options).max_severity(), None);
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn resilient_import() {
+    use gltf::diagnostic::Report;
+    use gltf::fault::EntityKind;
+
+    let options = ValidationOptions::default();
+    let (_, _, faults) = gltf::import_slice_resilient_with(GLTF, &options);
+    let report = Report::from_faults(&faults, &options);
+    assert_eq!(report.diagnostics[0].code, Code::IndexOutOfBounds);
+
+    let options = ValidationOptions {
+        suppress: &[Code::IndexOutOfBounds],
+        ..Default::default()
+    };
+    let (document, _, faults) = gltf::import_slice_resilient_with(GLTF, &options);
+    assert!(faults.is_empty());
+    assert!(document.is_faulted(EntityKind::Node, 0));
+}
@@ -0,0 +1,83 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use gltf::accessor::DataType;
+use gltf::build::{DocumentBuilder, Primitive};
+use gltf::mesh::{Mode, Semantic};
+
+#[test]
+fn built_documents_round_trip_through_glb() {
+    let positions = [[0.0, 0.0, 0.0], [1.0, -1.0, 0.0], [0.0, 1.0, 2.0]];
+    let normals = [[0.0, 0.0, 1.0]; 3];
+    let wide: Vec<u32> = (0..300).collect();
+
+    let mut builder = DocumentBuilder::new();
+    let indices = builder.push_indices(&[0, 1, 2]);
+    let position_accessor = builder.push_positions(&positions);
+    let normal_accessor = builder.push_normals(&normals);
+    let wide_indices = builder.push_indices(&wide);
+    let triangle = Primitive::new(position_accessor)
+        .attribute(Semantic::Normals, normal_accessor)
+        .indices(indices);
+    let points = Primitive::new(position_accessor).mode(Mode::Points);
+    let mesh = builder.push_mesh(vec![triangle, points]);
+    let parent = builder.push_node(Default::default());
+    let child = builder.push_node(gltf::json::Node {
+        mesh: Some(mesh),
+        ..Default::default()
+    });
+    builder.add_child(parent, child);
+    builder.push_scene(&[parent]);
+    let (document, buffers) = builder.build().unwrap();
+
+    let offsets: Vec<_> = document.views().map(|view| view.offset()).collect();
+    assert_eq!(offsets, [0, 4, 40, 76]);
+    let types: Vec<_> = document.accessors().map(|a| a.data_type()).collect();
+    assert_eq!(
+        types,
+        [DataType::U8, DataType::F32, DataType::F32, DataType::U16]
+    );
+    let accessor = document.accessors().nth(1).unwrap();
+    assert_eq!(accessor.min(), Some(serde_json::json!([0.0, -1.0, 0.0])));
+    assert_eq!(accessor.max(), Some(serde_json::json!([1.0, 1.0, 2.0])));
+    assert_eq!(buffers.len(), 1);
+    assert_eq!(buffers[0].len(), 676);
+
+    let glb = gltf::export::to_glb(&document, &buffers).unwrap();
+    let (document, buffers) = gltf::import_slice(&glb).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    assert_eq!(document.default_scene().unwrap().index(), 0);
+    let node = document.nodes().next().unwrap().children().next().unwrap();
+    let mut primitives = node.mesh().unwrap().primitives();
+    let triangle = primitives.next().unwrap();
+    let reader = triangle.reader(get_buffer_data);
+    assert_eq!(
+        reader.read_positions().unwrap().collect::<Vec<_>>(),
+        positions
+    );
+    assert_eq!(reader.read_normals().unwrap().collect::<Vec<_>>(), normals);
+    let read: Vec<_> = reader.read_indices().unwrap().into_u32().collect();
+    assert_eq!(read, [0, 1, 2]);
+    assert_eq!(primitives.next().unwrap().mode(), Mode::Points);
+
+    let accessor = document.accessors().nth(wide_indices.value()).unwrap();
+    let read: Vec<_> = gltf::accessor::Iter::<u16>::new(accessor, get_buffer_data)
+        .unwrap()
+        .map(u32::from)
+        .collect();
+    assert_eq!(read, wide);
+}
+
+#[test]
+fn invalid_documents_are_not_built() {
+    let mut builder = DocumentBuilder::new();
+    let positions = builder.push_positions(&[[0.0; 3]]);
+    let missing = gltf::json::Index::new(positions.value() as u32 + 1);
+    builder.push_mesh(vec![Primitive::new(positions).indices(missing)]);
+    match builder.build() {
+        Err(gltf::Error::Validation(errors)) => {
+            let paths: Vec<_> = errors.iter().map(|(path, _)| path.as_str()).collect();
+            assert_eq!(paths, ["meshes[0].primitives[0].indices"]);
+        }
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+}
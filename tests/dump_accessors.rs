@@ -0,0 +1,169 @@
+use gltf::accessor::{DataType, Dimensions};
+use gltf::export::{self, AccessorRecordHeader, DumpError, DumpOptions, Usage};
+use gltf::{buffer, Gltf, Semantic};
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 48}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 24, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 28, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 32, "byteLength": 1},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 12}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+         "min": [1, 2, 3], "max": [4, 5, 6]},
+        {"bufferView": 1, "componentType": 5121, "normalized": true, "count": 2, "type": "VEC2"},
+        {"bufferView": 2, "componentType": 5123, "count": 2, "type": "SCALAR"},
+        {"componentType": 5126, "count": 2, "type": "VEC3",
+         "sparse": {
+            "count": 1,
+            "indices": {"bufferView": 3, "componentType": 5121},
+            "values": {"bufferView": 4}
+         }}
+    ],
+    "meshes": [{
+        "primitives": [{
+            "attributes": {"POSITION": 0, "TEXCOORD_0": 1},
+            "indices": 2,
+            "targets": [{"POSITION": 3}]
+        }]
+    }]
+}"#;
+
+fn data() -> Vec<u8> {
+    let mut data = Vec::new();
+    for x in [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0] {
+        data.extend_from_slice(&x.to_le_bytes());
+    }
+    data.extend_from_slice(&[255, 0, 0, 255]);
+    data.extend_from_slice(&[0, 0, 1, 0]);
+    data.extend_from_slice(&[1, 0, 0, 0]);
+    for x in [7.0f32, 8.0, 9.0] {
+        data.extend_from_slice(&x.to_le_bytes());
+    }
+    data
+}
+
+fn floats(data: &[u8]) -> Vec<f32> {
+    data.chunks(4)
+        .map(|x| f32::from_le_bytes([x[0], x[1], x[2], x[3]]))
+        .collect()
+}
+
+#[test]
+fn records_in_index_order() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let buffers = [buffer::Data(data())];
+    let mut records = Vec::new();
+    export::dump_accessors::<()>(&gltf, &buffers, &mut |record| {
+        records.push((record.header, record.usages.to_vec(), record.data.to_vec()));
+        Ok(())
+    })
+    .unwrap();
+
+    let indices: Vec<_> = records.iter().map(|(header, ..)| header.index).collect();
+    assert_eq!(indices, [0, 1, 2, 3]);
+
+    let (header, usages, data) = &records[0];
+    assert_eq!(header.byte_length, 24);
+    assert_eq!(floats(data), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(
+        usages,
+        &[Usage::Attribute {
+            mesh: 0,
+            primitive: 0,
+            semantic: Semantic::Positions
+        }]
+    );
+
+    let (header, _, data) = &records[1];
+    assert_eq!((header.data_type, header.normalized), (DataType::U8, true));
+    assert_eq!(data, &[255, 0, 0, 255]);
+
+    let (_, usages, data) = &records[2];
+    assert_eq!(
+        usages,
+        &[Usage::Indices {
+            mesh: 0,
+            primitive: 0
+        }]
+    );
+    assert_eq!(data, &[0, 0, 1, 0]);
+
+    let (header, usages, data) = &records[3];
+    assert_eq!((header.dimensions, header.count), (Dimensions::Vec3, 2));
+    assert_eq!(floats(data), [0.0, 0.0, 0.0, 7.0, 8.0, 9.0]);
+    assert_eq!(
+        usages,
+        &[Usage::MorphTarget {
+            mesh: 0,
+            primitive: 0,
+            target: 0,
+            semantic: Semantic::Positions
+        }]
+    );
+}
+
+#[test]
+fn dequantized_records() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let buffers = [buffer::Data(data())];
+    let options = DumpOptions { dequantize: true };
+    let mut records = Vec::new();
+    export::dump_accessors_with::<()>(&gltf, &buffers, options, &mut |record| {
+        records.push((record.header, record.data.to_vec()));
+        Ok(())
+    })
+    .unwrap();
+
+    let (header, data) = &records[1];
+    assert_eq!(
+        (header.data_type, header.normalized),
+        (DataType::F32, false)
+    );
+    assert_eq!(header.byte_length, 16);
+    assert_eq!(floats(data), [1.0, 0.0, 0.0, 1.0]);
+    // Indices are never converted.
+    assert_eq!(records[2].0.data_type, DataType::U16);
+}
+
+#[test]
+fn header_framing() {
+    let header = AccessorRecordHeader {
+        index: 3,
+        data_type: DataType::U16,
+        dimensions: Dimensions::Mat4,
+        normalized: true,
+        count: 5,
+        byte_length: 160,
+    };
+    let bytes = header.to_bytes();
+    assert_eq!(bytes.len(), AccessorRecordHeader::SIZE);
+    assert_eq!(&bytes[0..8], &[3, 0, 0, 0, 0x03, 0x14, 7, 1]);
+    assert_eq!(&bytes[8..16], &5u64.to_le_bytes());
+    assert_eq!(&bytes[16..24], &160u64.to_le_bytes());
+}
+
+#[test]
+fn errors_stop_the_dump() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let buffers = [buffer::Data(data())];
+    let mut seen = 0;
+    let result = export::dump_accessors(&gltf, &buffers, &mut |_| {
+        seen += 1;
+        if seen == 2 {
+            Err("full")
+        } else {
+            Ok(())
+        }
+    });
+    assert_eq!(result, Err(DumpError::Sink("full")));
+    assert_eq!(seen, 2);
+
+    let truncated = [buffer::Data(data()[..20].to_vec())];
+    let result = export::dump_accessors::<()>(&gltf, &truncated, &mut |_| Ok(()));
+    assert!(matches!(result, Err(DumpError::Accessor(_))));
+}
@@ -0,0 +1,80 @@
+use gltf::analysis::{RenderBuckets, RetentionPolicy};
+use gltf::check::CheckLimits;
+use gltf::{buffer, transform, Gltf, Warning};
+
+const FIXTURE: &[u8] = include_bytes!("empty_entities.gltf");
+
+#[test]
+fn empty_entities_load_with_warning() {
+    let gltf = Gltf::from_slice(FIXTURE).unwrap();
+    assert_eq!(gltf.warnings(), [Warning::EmptyMesh { mesh: 0 }]);
+    assert_eq!(
+        Warning::EmptyMesh { mesh: 0 }.to_string(),
+        "mesh 0 has no primitives"
+    );
+
+    let summary = Gltf::check(FIXTURE, &CheckLimits::default()).unwrap();
+    assert_eq!(summary.lengths.meshes, 1);
+    assert_eq!(summary.lengths.scenes, 2);
+}
+
+#[test]
+fn empty_entities_traverse() {
+    let gltf = Gltf::from_slice(FIXTURE).unwrap();
+
+    let mesh = gltf.meshes().next().unwrap();
+    assert_eq!(mesh.primitives().len(), 0);
+    assert!(mesh.primitives().next().is_none());
+    assert_eq!(mesh.bounding_box(), None);
+
+    let empty = gltf.default_scene().unwrap();
+    assert_eq!(empty.nodes().len(), 0);
+    assert_eq!(empty.walk().count(), 0);
+    let populated = gltf.scenes().nth(1).unwrap();
+    let walked: Vec<_> = populated.walk().map(|node| node.index()).collect();
+    assert_eq!(walked, [0, 1]);
+
+    let bare = gltf.nodes().nth(1).unwrap();
+    assert!(bare.mesh().is_none());
+    assert_eq!(bare.children().len(), 0);
+    #[cfg(feature = "cameras")]
+    assert!(bare.camera().is_none());
+    #[cfg(feature = "skinning")]
+    assert!(bare.skin().is_none());
+}
+
+#[test]
+fn empty_entities_analyse() {
+    let gltf = Gltf::from_slice(FIXTURE).unwrap();
+    assert_eq!(gltf.render_buckets(), RenderBuckets::default());
+    assert_eq!(
+        gltf.cpu_required_ranges(RetentionPolicy::default()),
+        buffer::RangeSet::new()
+    );
+    #[cfg(feature = "animation")]
+    assert!(gltf.animation_bindings().entries.is_empty());
+    assert!(gltf.scan_for_nans(&[]).is_empty());
+}
+
+#[test]
+fn empty_entities_transform() {
+    let gltf = Gltf::from_slice(FIXTURE).unwrap();
+    let assets = transform::split_by_scene(&gltf.document, &[]);
+    assert_eq!(assets.len(), 2);
+    assert!(assets[0].0.nodes.is_empty() && assets[0].0.meshes.is_empty());
+    assert_eq!(assets[1].0.nodes.len(), 2);
+    assert_eq!(assets[1].0.meshes.len(), 1);
+
+    let mut root = gltf.document.clone().into_json();
+    let files = transform::externalize(&mut root, &[], &|_, index, _| index.to_string());
+    assert!(files.is_empty());
+
+    // A scene without nodes omits the property when written out, which must
+    // still load.
+    let json = gltf.to_json_vec().unwrap();
+    let reloaded = Gltf::from_slice(&json).unwrap();
+    assert_eq!(reloaded.scenes().len(), 2);
+    for (root, _) in assets {
+        gltf::Document::from_json(root).unwrap();
+    }
+}
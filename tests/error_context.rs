@@ -0,0 +1,161 @@
+#![cfg(all(feature = "animation", feature = "names"))]
+
+use gltf::context::ErrorContext;
+use gltf::fault::{EntityKind, FaultError, ImportFault};
+use gltf::json::{self, validation};
+use gltf::{accessor, Document, Error};
+
+/// A chair with three primitives in a small hierarchy, a textured material
+/// and an animation of one of its legs.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "scenes": [{"nodes": [0]}],
+    "nodes": [
+        {"name": "Room", "children": [1]},
+        {"name": "Chair", "mesh": 0, "children": [2]},
+        {"name": "Leg"}
+    ],
+    "meshes": [{
+        "name": "Chair_LOD0",
+        "primitives": [
+            {"attributes": {"POSITION": 0}},
+            {"attributes": {"POSITION": 1}},
+            {"attributes": {"POSITION": 2}, "indices": 3, "material": 0}
+        ]
+    }],
+    "materials": [{
+        "name": "Oak",
+        "pbrMetallicRoughness": {"baseColorTexture": {"index": 0}}
+    }],
+    "textures": [{"name": "wood", "source": 0, "sampler": 0}],
+    "samplers": [{}],
+    "images": [{"name": "albedo", "bufferView": 5, "mimeType": "image/png"}],
+    "animations": [{
+        "name": "Wobble",
+        "channels": [{"sampler": 0, "target": {"node": 2, "path": "rotation"}}],
+        "samplers": [{"input": 4, "output": 5}]
+    }],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3",
+         "min": [0, 0, 0], "max": [0, 0, 0]},
+        {"bufferView": 1, "componentType": 5126, "count": 1, "type": "VEC3",
+         "min": [0, 0, 0], "max": [0, 0, 0]},
+        {"bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 3, "componentType": 5123, "count": 3, "type": "SCALAR"},
+        {"bufferView": 4, "componentType": 5126, "count": 1, "type": "SCALAR",
+         "min": [0], "max": [0]},
+        {"bufferView": 4, "componentType": 5126, "count": 1, "type": "VEC4"}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 12, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 24, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 60, "byteLength": 6},
+        {"buffer": 0, "byteOffset": 68, "byteLength": 16},
+        {"buffer": 0, "byteOffset": 84, "byteLength": 8}
+    ],
+    "buffers": [{"name": "geometry", "byteLength": 92}]
+}"#;
+
+fn load() -> Document {
+    let root = json::Root::from_slice(GLTF.as_bytes()).unwrap();
+    Document::from_json_without_validation(root)
+}
+
+#[test]
+fn entities() {
+    use EntityKind::*;
+    let document = load();
+    let context = ErrorContext::new(&document);
+    let entity = |kind, index| context.entity(kind, index);
+    assert_eq!(
+        entity(Accessor, 2),
+        "accessor 2 (POSITION of mesh 'Chair_LOD0' primitive 2)"
+    );
+    assert_eq!(entity(View, 3), "buffer view 3 (data of accessor 3)");
+    assert_eq!(
+        entity(Buffer, 0),
+        "buffer 0 'geometry' (buffer view 0, buffer view 1, buffer view 2, \
+         buffer view 3, and 2 more)"
+    );
+    assert_eq!(
+        entity(Image, 0),
+        "image 0 'albedo' (source of texture 'wood')"
+    );
+    assert_eq!(
+        entity(Texture, 0),
+        "texture 0 'wood' (base color texture of material 'Oak')"
+    );
+    assert_eq!(
+        entity(Accessor, 4),
+        "accessor 4 (keyframe times of animation 'Wobble' channel 0)"
+    );
+    assert_eq!(
+        entity(Node, 2),
+        "node 2 'Leg' (at Room/Chair/Leg, rotation target of animation 'Wobble' channel 0)"
+    );
+    assert_eq!(entity(Root, 0), "the document");
+}
+
+#[test]
+fn errors() {
+    let document = load();
+    let context = ErrorContext::new(&document);
+    let error = accessor::Error::MissingData {
+        accessor: 2,
+        required: 36,
+        available: 12,
+    };
+    assert_eq!(
+        context.describe(&error),
+        "accessor 2 (POSITION of mesh 'Chair_LOD0' primitive 2): \
+         accessor 2 requires 36 bytes of its buffer view but only 12 are available"
+    );
+
+    let path = json::Path::new()
+        .field("animations")
+        .index(0)
+        .field("channels")
+        .index(0)
+        .field("sampler");
+    let error = (path, validation::Error::IndexOutOfBounds);
+    assert_eq!(
+        context.describe(&error),
+        "animation 0 'Wobble' channel 0 (rotation of node 'Leg'): \
+         animations[0].channels[0].sampler: Index out of bounds"
+    );
+
+    let fault = ImportFault {
+        entity_kind: EntityKind::Primitive { mesh: 0 },
+        index: 2,
+        error: FaultError::Poisoned {
+            entity_kind: EntityKind::Accessor,
+            index: 2,
+        },
+        span: None,
+    };
+    assert_eq!(
+        context.describe(&fault),
+        "mesh 0 'Chair_LOD0' primitive 2 (mesh of node 'Chair'): \
+         depends on faulted accessor 2 (POSITION of mesh 'Chair_LOD0' primitive 2)"
+    );
+}
+
+#[test]
+fn validation_errors() {
+    let mut root = load().into_json();
+    root.textures[0].source = json::Index::new(9);
+    let document = Document::from_json_without_validation(root.clone());
+    let errors = match Document::from_json(root) {
+        Err(Error::Validation(errors)) => errors,
+        _ => panic!("expected validation errors"),
+    };
+    let context = ErrorContext::new(&document);
+    let described: Vec<_> = errors.iter().map(|x| context.describe(x)).collect();
+    assert_eq!(
+        described,
+        ["texture 0 'wood' (base color texture of material 'Oak'): \
+          textures[0].source: Index out of bounds"]
+    );
+}
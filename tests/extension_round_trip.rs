@@ -0,0 +1,203 @@
+#![cfg(feature = "extensions")]
+
+use std::fs;
+
+use gltf::json::{self, Path, Value};
+use gltf::Document;
+
+const SAMPLES: &[&str] = &[
+    "examples/Box.gltf",
+    "examples/Lantern.gltf",
+    "tests/box_sparse.gltf",
+    "tests/minimal_accessor_min_max.gltf",
+];
+
+/// Covers the extensible objects that the sample files lack.
+const EXTRA_SAMPLE: &str = r#"{
+    "asset": {"version": "2.0"},
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "MAT4"}
+    ],
+    "bufferViews": [{"buffer": 0, "byteLength": 128}],
+    "buffers": [{"byteLength": 128}],
+    "cameras": [
+        {"type": "perspective", "perspective": {"yfov": 0.8, "znear": 0.1}},
+        {"type": "orthographic",
+         "orthographic": {"xmag": 1, "ymag": 1, "znear": 0, "zfar": 10}}
+    ],
+    "images": [{"uri": "image.png"}],
+    "samplers": [{"magFilter": 9729}],
+    "textures": [{"sampler": 0, "source": 0}],
+    "materials": [{
+        "normalTexture": {"index": 0},
+        "occlusionTexture": {"index": 0},
+        "emissiveTexture": {"index": 0},
+        "pbrMetallicRoughness": {"baseColorTexture": {"index": 0}}
+    }],
+    "nodes": [{"skin": 0, "children": [1]}, {"camera": 0}],
+    "skins": [{"joints": [1], "inverseBindMatrices": 0}],
+    "scenes": [{"nodes": [0]}],
+    "scene": 0
+}"#;
+
+/// A xorshift generator, so that failures are reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+/// Generates an arbitrary JSON value, favouring containers near the top.
+fn payload(rng: &mut Rng, depth: u32) -> Value {
+    let kinds = if depth < 4 { 8 } else { 5 };
+    match rng.below(kinds) {
+        0 => Value::Null,
+        1 => Value::Bool(rng.below(2) == 1),
+        2 => Value::from(rng.below(2000) as i64 - 1000),
+        // Dyadic fractions are exactly representable and print exactly.
+        3 => Value::from((rng.below(1 << 20) as f64 - (1 << 19) as f64) / 1024.0),
+        4 => Value::from(format!("s{}\"\\\u{e9}", rng.next())),
+        5 => Value::Array(
+            (0..rng.below(5))
+                .map(|_| Value::from(rng.below(1 << 16) as f64 / 256.0 - 100.0))
+                .collect(),
+        ),
+        6 => Value::Array((0..rng.below(4)).map(|_| payload(rng, depth + 1)).collect()),
+        _ => Value::Object(
+            (0..rng.below(5))
+                .map(|i| (format!("k{}", i), payload(rng, depth + 1)))
+                .collect(),
+        ),
+    }
+}
+
+/// Collects the paths of the objects that may carry extensions.
+fn extensible(value: &Value, path: Path, paths: &mut Vec<Path>) {
+    match value {
+        Value::Object(object) => {
+            paths.push(path.clone());
+            for (key, value) in object {
+                if !matches!(
+                    key.as_str(),
+                    "extensions" | "extras" | "attributes" | "targets"
+                ) {
+                    extensible(value, path.field(key), paths);
+                }
+            }
+        }
+        Value::Array(array) => {
+            for (index, value) in array.iter().enumerate() {
+                extensible(value, path.index(index), paths);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn lookup<'a>(value: &'a Value, path: &Path) -> &'a Value {
+    let pointer = path
+        .as_str()
+        .replace('[', ".")
+        .replace(']', "")
+        .split('.')
+        .filter(|token| !token.is_empty())
+        .fold(String::new(), |pointer, token| pointer + "/" + token);
+    value.pointer(&pointer).unwrap()
+}
+
+fn round_trip(root: json::Root) -> Value {
+    let mut root = Document::from_json(root).unwrap().into_json();
+    for node in &mut root.nodes {
+        node.name = Some("edited".into());
+    }
+    root.nodes.push(json::Node::default());
+    let document = Document::from_json(root).unwrap();
+    json::deserialize::from_slice(&document.to_json_vec().unwrap()).unwrap()
+}
+
+fn samples() -> Vec<json::Root> {
+    let mut samples: Vec<_> = SAMPLES
+        .iter()
+        .map(|path| json::Root::from_slice(&fs::read(path).unwrap()).unwrap())
+        .collect();
+    samples.push(json::Root::from_str(EXTRA_SAMPLE).unwrap());
+    samples
+}
+
+#[test]
+fn unknown_extensions_survive_a_round_trip() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for mut root in samples() {
+        let mut paths = Vec::new();
+        extensible(&root.to_value().unwrap(), Path::new(), &mut paths);
+        let mut expected = Vec::new();
+        for (i, path) in paths.into_iter().enumerate() {
+            let name = format!("VENDOR_ext{}", i % 3);
+            let value = payload(&mut rng, 0);
+            root.set_extension(&path, &name, value.clone()).unwrap();
+            expected.retain(|(p, n, _): &(Path, String, Value)| (p, n) != (&path, &name));
+            expected.push((path, name, value));
+        }
+        let output = round_trip(root);
+        for (path, name, value) in &expected {
+            let extensions = &lookup(&output, path)["extensions"];
+            assert_eq!(&extensions[name], value, "{}.extensions.{}", path, name);
+        }
+        let used = output["extensionsUsed"].as_array().unwrap();
+        assert_eq!(used.len(), 3);
+    }
+}
+
+#[test]
+fn serialization_is_stable() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    let mut root = samples().remove(2);
+    for _ in 0..16 {
+        let value = payload(&mut rng, 0);
+        let path = Path::new().field("accessors").index(2).field("sparse");
+        root.set_extension(&path, "VENDOR_sparse", value).unwrap();
+    }
+    let first = round_trip(root);
+    let second = round_trip(json::deserialize::from_value(first.clone()).unwrap());
+    assert_eq!(first["accessors"], second["accessors"]);
+    assert_eq!(first["extensionsUsed"], second["extensionsUsed"]);
+}
+
+#[test]
+fn set_extension_rejects_missing_objects() {
+    let mut root = json::Root::from_str(EXTRA_SAMPLE).unwrap();
+    let path = Path::new().field("nodes").index(7);
+    assert!(root
+        .set_extension(&path, "VENDOR_ext", Value::Null)
+        .is_err());
+    let path = Path::new().field("skins").index(0).field("joints");
+    assert!(root
+        .set_extension(&path, "VENDOR_ext", Value::Null)
+        .is_err());
+    assert!(root.extensions_used.is_empty());
+
+    let path = Path::new()
+        .field("materials")
+        .index(0)
+        .field("normalTexture");
+    root.set_extension(&path, "VENDOR_ext", Value::Null)
+        .unwrap();
+    let extensions = root.materials[0]
+        .normal_texture
+        .as_ref()
+        .unwrap()
+        .extensions
+        .as_ref()
+        .unwrap();
+    assert_eq!(extensions.others["VENDOR_ext"], Value::Null);
+    assert_eq!(root.extensions_used, ["VENDOR_ext"]);
+}
@@ -0,0 +1,86 @@
+use gltf::buffer;
+use gltf::fault::EntityKind;
+use gltf::transform::externalize;
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 28}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 12, "byteLength": 10},
+        {"buffer": 0, "byteOffset": 24, "byteLength": 4}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3"},
+        {"bufferView": 2, "componentType": 5123, "count": 2, "type": "SCALAR"}
+    ],
+    "images": [
+        {"bufferView": 1, "mimeType": "image/png", "name": "base color"},
+        {"uri": "external.png"}
+    ]
+}"#;
+
+fn naming(entity_kind: EntityKind, index: usize, name: Option<&str>) -> String {
+    match (entity_kind, name) {
+        (EntityKind::Image, Some(name)) => format!("textures/{}.png", name),
+        (EntityKind::Image, None) => format!("textures/{}.png", index),
+        _ => format!("buffer {}.bin", index),
+    }
+}
+
+#[test]
+fn externalize_embedded_data() {
+    let mut root = gltf::Gltf::from_slice(GLTF.as_bytes())
+        .unwrap()
+        .document
+        .into_json();
+    let data: Vec<u8> = (0..28).collect();
+    let files = externalize(&mut root, &[buffer::Data(data.clone())], &naming);
+
+    assert_eq!(files.len(), 2);
+    let image = &files[0];
+    assert_eq!((image.entity_kind, image.index), (EntityKind::Image, 0));
+    assert_eq!(image.data, &data[12..22]);
+    if cfg!(feature = "names") {
+        assert_eq!(image.name, "textures/base color.png");
+        assert_eq!(image.uri, "textures/base%20color.png");
+    }
+    assert_eq!(root.images[0].uri.as_deref(), Some(image.uri.as_str()));
+    assert!(root.images[0].buffer_view.is_none());
+    assert_eq!(root.images[1].uri.as_deref(), Some("external.png"));
+
+    // Eight bytes of the image view are freed, keeping the following data
+    // 4-byte aligned.
+    let buffer = &files[1];
+    assert_eq!((buffer.entity_kind, buffer.index), (EntityKind::Buffer, 0));
+    assert_eq!(buffer.uri, "buffer%200.bin");
+    assert_eq!(buffer.data.len(), 20);
+    assert_eq!(root.buffers[0].byte_length.0, 20);
+    assert_eq!(root.buffers[0].uri.as_deref(), Some(buffer.uri.as_str()));
+    assert_eq!(root.buffer_views.len(), 2);
+    let view = &root.buffer_views[1];
+    assert_eq!(view.byte_offset.unwrap().0, 16);
+    assert_eq!(root.accessors[1].buffer_view.unwrap().value(), 1);
+    assert_eq!(&buffer.data[16..20], &data[24..28]);
+
+    gltf::Document::from_json(root).unwrap();
+}
+
+#[test]
+fn image_only_buffer_is_removed() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 8}],
+        "bufferViews": [{"buffer": 0, "byteLength": 8}],
+        "images": [{"bufferView": 0, "mimeType": "image/jpeg"}]
+    }"#;
+    let mut root = gltf::Gltf::from_slice(json.as_bytes())
+        .unwrap()
+        .document
+        .into_json();
+    let files = externalize(&mut root, &[buffer::Data(vec![7; 8])], &naming);
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].data, [7; 8]);
+    assert!(root.buffers.is_empty() && root.buffer_views.is_empty());
+    gltf::Document::from_json(root).unwrap();
+}
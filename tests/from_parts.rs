@@ -0,0 +1,29 @@
+use std::fs;
+
+use gltf::{Document, Gltf};
+
+#[test]
+fn from_parts_round_trip() {
+    let data = fs::read("tests/box_sparse.glb").unwrap();
+    let gltf = Gltf::from_slice(&data).unwrap();
+    let (document, blob) = gltf.clone().into();
+    let json = document.into_json();
+    let parts = Gltf::from_parts(json.clone(), blob.clone()).unwrap();
+    assert_eq!(parts.blob, gltf.blob);
+    assert_eq!(parts.accessors().len(), gltf.accessors().len());
+
+    let document = Document::from_root_unchecked(json);
+    assert_eq!(document.meshes().len(), 1);
+}
+
+#[test]
+fn from_parts_validates() {
+    let data = fs::read("tests/box_sparse.gltf").unwrap();
+    let mut json = Gltf::from_slice(&data).unwrap().document.into_json();
+    json.scenes[0].nodes.push(gltf::json::Index::new(100));
+    match Gltf::from_parts(json.clone(), None) {
+        Err(gltf::Error::Validation(errors)) => assert!(!errors.is_empty()),
+        other => panic!("expected a validation error, got {:?}", other.map(|_| ())),
+    }
+    assert_eq!(Document::from_root_unchecked(json).scenes().len(), 1);
+}
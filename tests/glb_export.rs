@@ -0,0 +1,75 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use gltf::binary::Glb;
+
+/// A triangle whose `u16` indices and positions are in separate buffers, the
+/// first of which is not a multiple of four bytes long.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [
+        {"byteLength": 6, "uri": "data:application/octet-stream;base64,AAABAAIA"},
+        {"byteLength": 36, "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAAAA"}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 6, "target": 34963},
+        {"buffer": 1, "byteLength": 36, "target": 34962}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5123, "count": 3, "type": "SCALAR"},
+        {"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 1, 0]}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 1}, "indices": 0}]}]
+}"#;
+
+type Vertices = Vec<(Vec<[f32; 3]>, Option<Vec<u32>>)>;
+
+fn vertices(document: &gltf::Document, buffers: &[gltf::buffer::Data]) -> Vertices {
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    document
+        .meshes()
+        .flat_map(|mesh| mesh.primitives())
+        .map(|primitive| {
+            let reader = primitive.reader(get_buffer_data);
+            let positions = reader.read_positions().unwrap().collect();
+            let indices = reader.read_indices().map(|iter| iter.into_u32().collect());
+            (positions, indices)
+        })
+        .collect()
+}
+
+#[test]
+fn buffers_are_merged_into_the_bin_chunk() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    let glb = gltf::export::to_glb(&document, &buffers).unwrap();
+    assert_eq!(glb.len() % 4, 0);
+
+    let parsed = Glb::from_slice(&glb).unwrap();
+    assert_eq!(parsed.header.length as usize, glb.len());
+    assert_eq!(parsed.bin.as_deref().map(<[u8]>::len), Some(44));
+
+    let (exported, exported_buffers) = gltf::import_slice(&glb).unwrap();
+    assert_eq!(exported.buffers().count(), 1);
+    let buffer = exported.buffers().next().unwrap();
+    assert_eq!(buffer.length(), 44);
+    assert!(matches!(buffer.source(), gltf::buffer::Source::Bin));
+    let offsets: Vec<_> = exported.views().map(|view| view.offset()).collect();
+    assert_eq!(offsets, [0, 8]);
+    assert_eq!(
+        vertices(&exported, &exported_buffers),
+        vertices(&document, &buffers)
+    );
+}
+
+#[test]
+fn glb_files_are_exported_unchanged() {
+    let input = std::fs::read("examples/Box.glb").unwrap();
+    let (document, buffers) = gltf::import_slice(&input).unwrap();
+    let glb = gltf::export::to_glb(&document, &buffers).unwrap();
+    let (exported, exported_buffers) = gltf::import_slice(&glb).unwrap();
+    assert_eq!(exported_buffers[0].0, buffers[0].0);
+    assert_eq!(
+        vertices(&exported, &exported_buffers),
+        vertices(&document, &buffers)
+    );
+}
@@ -0,0 +1,123 @@
+use gltf::{binary, Error, Glb, GlbOptions, Gltf};
+
+/// Builds a GLB container of the given version holding only a JSON chunk.
+fn glb(version: u32, json: &str) -> Vec<u8> {
+    let mut json = json.as_bytes().to_vec();
+    while json.len() % 4 != 0 {
+        json.push(b' ');
+    }
+    let mut glb = Vec::new();
+    glb.extend_from_slice(b"glTF");
+    glb.extend_from_slice(&version.to_le_bytes());
+    glb.extend_from_slice(&(20 + json.len() as u32).to_le_bytes());
+    glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+    glb.extend_from_slice(b"JSON");
+    glb.extend_from_slice(&json);
+    glb
+}
+
+const ASSET: &str = r#"{"asset": {"version": "2.0"}}"#;
+
+#[test]
+fn container_version_is_configurable() {
+    let data = glb(3, ASSET);
+    match Gltf::from_slice(&data) {
+        Err(Error::Binary(binary::Error::Version(3))) => {}
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+    assert!(Glb::from_slice(&data).is_err());
+    assert_eq!(
+        Glb::from_slice(&data).unwrap_err().to_string(),
+        "unsupported GLB container version 3"
+    );
+
+    let options = GlbOptions {
+        accepted_versions: &[2, 3],
+        ..Default::default()
+    };
+    let container = Glb::from_slice_with(&data, options).unwrap();
+    assert_eq!(container.header.version, 3);
+    Gltf::from_slice_with(&data, options).unwrap();
+
+    // Accepting other versions does not imply accepting version 2.
+    let options = GlbOptions {
+        accepted_versions: &[3],
+        ..Default::default()
+    };
+    assert!(Gltf::from_slice_with(&glb(2, ASSET), options).is_err());
+}
+
+#[test]
+fn asset_version_is_checked_independently() {
+    let options = GlbOptions {
+        accepted_versions: &[2, 3],
+        ..Default::default()
+    };
+    let unsupported = glb(3, r#"{"asset": {"version": "3.1"}}"#);
+    match Gltf::from_slice_with(&unsupported, options) {
+        Err(Error::UnsupportedVersion { major: 3, minor: 1 }) => {}
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+
+    // Newer minor versions load unless `minVersion` requires them.
+    Gltf::from_slice(br#"{"asset": {"version": "2.1"}}"#).unwrap();
+    let error = Gltf::from_slice(br#"{"asset": {"version": "2.1", "minVersion": "2.1"}}"#)
+        .err()
+        .unwrap();
+    assert_eq!(error.to_string(), "unsupported glTF asset version 2.1");
+
+    match Gltf::from_slice(br#"{"asset": {"version": "two"}}"#) {
+        Err(Error::Validation(errors)) => assert_eq!(errors[0].0.as_str(), "asset.version"),
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+    Gltf::from_slice_without_validation(br#"{"asset": {"version": "3.0"}}"#).unwrap();
+}
+
+#[test]
+fn length_mismatch_is_configurable() {
+    // A BIN chunk of three bytes, written without its padding but with the
+    // padding counted in the header length.
+    let mut data = glb(2, ASSET);
+    data.extend_from_slice(&3u32.to_le_bytes());
+    data.extend_from_slice(b"BIN\0");
+    data.extend_from_slice(&[1, 2, 3]);
+    let length = data.len() as u32 + 1;
+    data[8..12].copy_from_slice(&length.to_le_bytes());
+
+    match Glb::from_slice(&data) {
+        Err(Error::Binary(binary::Error::Length { .. })) => {}
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+    match Glb::from_reader(&data[..]) {
+        Err(Error::Binary(binary::Error::Io(_))) => {}
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+
+    let options = GlbOptions {
+        allow_length_mismatch: true,
+        ..Default::default()
+    };
+    let container = Glb::from_slice_with(&data, options).unwrap();
+    assert_eq!(container.length_read, Some(data.len()));
+    assert_eq!(container.bin.as_deref(), Some(&[1, 2, 3][..]));
+    let container = Glb::from_reader_with(&data[..], options).unwrap();
+    assert_eq!(container.length_read, Some(data.len()));
+    assert_eq!(container.bin.as_deref(), Some(&[1, 2, 3][..]));
+    Gltf::from_slice_with(&data, options).unwrap();
+
+    // Files of the right length are read as before.
+    let exact = glb(2, ASSET);
+    let container = Glb::from_slice_with(&exact, options).unwrap();
+    assert_eq!(container.length_read, None);
+
+    // A chunk that extends past the end of the file is still an error.
+    let truncated = &data[..data.len() - 1];
+    match Glb::from_slice_with(truncated, options) {
+        Err(Error::Binary(binary::Error::ChunkLength { .. })) => {}
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+    match Glb::from_reader_with(truncated, options) {
+        Err(Error::Binary(binary::Error::ChunkLength { .. })) => {}
+        other => panic!("{:?}", other.map(|_| ())),
+    }
+}
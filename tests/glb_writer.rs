@@ -0,0 +1,65 @@
+use std::borrow::Cow;
+
+use gltf::{binary, Error, Glb};
+
+#[test]
+fn box_roundtrip() {
+    let original = std::fs::read("examples/Box.glb").unwrap();
+    let glb = Glb::from_slice(&original).unwrap();
+    let output = glb.to_vec().unwrap();
+    let roundtrip = Glb::from_slice(&output).unwrap();
+    assert_eq!(roundtrip.json, glb.json);
+    assert_eq!(roundtrip.bin, glb.bin);
+    assert_eq!(roundtrip.header.length as usize, output.len());
+    assert_eq!(output, original);
+
+    let mut written = Vec::new();
+    glb.to_writer(&mut written).unwrap();
+    assert_eq!(written, output);
+}
+
+#[test]
+fn chunk_padding() {
+    let glb = Glb {
+        // The length is computed by the writer.
+        header: binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: Cow::Borrowed(br#"{"asset":{"version":"2.0"}}"#),
+        bin: Some(Cow::Borrowed(&[1, 2, 3, 4, 5])),
+        extra_chunks: Vec::new(),
+        length_read: None,
+        chunk_alignment: gltf::ChunkAlignment::Exact,
+    };
+    let output = glb.to_vec().unwrap();
+    assert_eq!(output.len(), 12 + 8 + 28 + 8 + 8);
+    assert_eq!(&output[8..12], &(output.len() as u32).to_le_bytes());
+    assert_eq!(&output[12..20], b"\x1c\0\0\0JSON");
+    assert_eq!(&output[20 + 27..48], b" ");
+    assert_eq!(&output[48..56], b"\x08\0\0\0BIN\0");
+    assert_eq!(&output[56..], &[1, 2, 3, 4, 5, 0, 0, 0]);
+
+    let roundtrip = Glb::from_slice(&output).unwrap();
+    assert_eq!(&roundtrip.json[..27], &glb.json[..]);
+    assert_eq!(&roundtrip.bin.unwrap()[..5], &[1, 2, 3, 4, 5]);
+
+    // Without a BIN chunk, only the JSON chunk is written.
+    let glb = Glb { bin: None, ..glb };
+    assert_eq!(glb.to_vec().unwrap().len(), 48);
+}
+
+#[test]
+fn writer_errors() {
+    let original = std::fs::read("examples/Box.glb").unwrap();
+    let glb = Glb::from_slice(&original).unwrap();
+    let mut buffer = [0; 16];
+    match glb.to_writer(&mut buffer[..]) {
+        Err(Error::Binary(binary::Error::Io(error))) => {
+            assert_eq!(error.error.kind(), core2::io::ErrorKind::WriteZero);
+            assert_eq!(error.transferred, 0);
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "utils")]
+
+use gltf::accessor::{AccessorLayoutCache, Error, Iter};
+use gltf::{buffer, Gltf};
+
+/// Two strided positions, and accessors of the same view whose ranges cannot
+/// be represented: a count of 2^62 and a byte offset of 2^64 - 1.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 24}],
+    "bufferViews": [{"buffer": 0, "byteLength": 24, "byteStride": 12}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3"},
+        {"bufferView": 0, "componentType": 5126, "count": 4611686018427387904, "type": "VEC3"},
+        {"bufferView": 0, "byteOffset": 18446744073709551615, "componentType": 5126,
+         "count": 1, "type": "VEC3"}
+    ],
+    "meshes": [{"primitives": [
+        {"attributes": {"POSITION": 1}},
+        {"attributes": {"POSITION": 2}}
+    ]}]
+}"#;
+
+#[test]
+fn view_data_is_bounded() {
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let data: Vec<u8> = (0..24).collect();
+    let accessor = gltf.accessors().next().unwrap();
+    assert_eq!(accessor.view_data(|_| Some(&data[..])), Ok(Some(&data[..])));
+    assert_eq!(
+        accessor.view_data(|_| Some(&data[..23])),
+        Err(Error::MissingData {
+            accessor: 0,
+            required: 24,
+            available: 23
+        })
+    );
+}
+
+#[test]
+fn unrepresentable_ranges_are_errors() {
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let buffers = [buffer::Data(vec![0; 24])];
+    let get_buffer_data = |_: gltf::Buffer| Some(&buffers[0][..]);
+    for accessor in gltf.accessors().skip(1) {
+        let error = Error::MissingData {
+            accessor: accessor.index(),
+            required: usize::MAX,
+            available: 24,
+        };
+        assert_eq!(accessor.view_data(get_buffer_data), Err(error));
+        assert_eq!(accessor.check_data(get_buffer_data), Err(error));
+        let iter = Iter::<[f32; 3]>::try_new(accessor.clone(), get_buffer_data);
+        assert_eq!(iter.err(), Some(error));
+        let stats = gltf::analysis::accessor_stats(&accessor, &buffers);
+        assert_eq!(stats.err(), Some(error));
+    }
+
+    let cache = AccessorLayoutCache::new(&gltf);
+    for primitive in gltf.meshes().next().unwrap().primitives() {
+        assert!(primitive.reader(get_buffer_data).read_positions().is_none());
+        let reader = primitive.reader_cached(&cache, get_buffer_data);
+        assert!(reader.read_positions().is_none());
+        assert!(gltf::mesh::interleave(&primitive, &buffers).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+#![cfg(feature = "import")]
+
+use std::borrow::Cow;
+
+use gltf::image::{Data, Format, Metadata};
+use gltf::Error;
+
+/// A PNG header of a 2 by 3 image in a buffer view, in a base 64 `data:` URI
+/// and in a percent-encoded one without a media type.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "images": [
+        {"bufferView": 0, "mimeType": "image/png"},
+        {"uri": "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAIAAAAD"},
+        {"uri": "data:,%89PNG%0D%0A%1A%0A%00%00%00%0DIHDR%00%00%00%02%00%00%00%03"}
+    ],
+    "bufferViews": [{"buffer": 0, "byteLength": 24}],
+    "buffers": [
+        {"byteLength": 24, "uri": "data:application/octet-stream;base64,iVBORw0KGgoAAAANSUhEUgAAAAIAAAAD"}
+    ]
+}"#;
+
+/// Decodes PNG headers into opaque black images.
+fn decode(bytes: &[u8], mime_type: Option<&str>) -> gltf::Result<Data> {
+    match (Metadata::peek(bytes), mime_type) {
+        (Some(Metadata { width, height }), Some("image/png")) => Ok(Data {
+            pixels: [0, 0, 0, 255].repeat((width * height) as usize),
+            format: Format::R8G8B8A8,
+            width,
+            height,
+        }),
+        _ => Err(Error::UnsupportedImageEncoding {
+            prefix: bytes.iter().take(8).copied().collect(),
+        }),
+    }
+}
+
+#[test]
+fn images_are_decoded() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    let mut mime_types = Vec::new();
+    let images = gltf::import_images_with(
+        &document,
+        &buffers,
+        &mut |bytes: &[u8], mime_type: Option<&str>| {
+            mime_types.push(mime_type.map(String::from));
+            decode(bytes, mime_type)
+        },
+    )
+    .unwrap();
+    assert_eq!(mime_types, vec![Some("image/png".to_owned()); 3]);
+    for image in &images {
+        assert_eq!((image.width, image.height), (2, 3));
+        assert_eq!(image.pixels.len(), 24);
+    }
+}
+
+#[test]
+fn decoder_errors_are_reported() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    let mut mime_types = Vec::new();
+    let error = gltf::import_images_with(
+        &document,
+        &buffers,
+        &mut |_: &[u8], mime_type: Option<&str>| {
+            mime_types.push(mime_type.map(String::from));
+            Err::<Data, _>(Error::Image("corrupt".into()))
+        },
+    )
+    .unwrap_err();
+    assert_eq!(error.to_string(), "corrupt");
+    assert_eq!(mime_types, [Some("image/png".to_owned())]);
+
+    let gltf = GLTF.replace(
+        "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAIAAAAD",
+        "image.png",
+    );
+    let (document, buffers) = gltf::import_slice(&gltf).unwrap();
+    assert!(matches!(
+        gltf::import_images_with(&document, &buffers, &mut decode),
+        Err(Error::ExternalReferenceInSliceImport)
+    ));
+}
+
+#[test]
+fn encoded_data_is_borrowed_from_views() {
+    let gltf = GLTF
+        .replace(
+            r#""byteLength": 24}]"#,
+            r#""byteOffset": 4, "byteLength": 24}]"#,
+        )
+        .replace(
+            r#""byteLength": 24, "uri": "data:application/octet-stream;base64,iVBORw0KGgoAAAANSUhEUgAAAAIAAAAD""#,
+            r#""byteLength": 28, "uri": "data:application/octet-stream;base64,AAAAAIlQTkcNChoKAAAADUlIRFIAAAACAAAAAw==""#,
+        );
+    let (document, mut buffers) = gltf::import_slice(&gltf).unwrap();
+    let images = gltf::import_images_raw(&document, &buffers).unwrap();
+    assert_eq!(images.len(), 3);
+    match &images[0].data {
+        Cow::Borrowed(data) => assert_eq!(*data, &buffers[0][4..28]),
+        Cow::Owned(_) => panic!("data of a view is copied"),
+    }
+    for image in &images[1..] {
+        assert!(matches!(image.data, Cow::Owned(_)));
+        assert_eq!(image.data, images[0].data);
+    }
+    assert!(images
+        .iter()
+        .all(|image| image.mime_type == Some("image/png")));
+
+    buffers[0].0.truncate(20);
+    assert!(matches!(
+        gltf::import_images_raw(&document, &buffers),
+        Err(Error::BufferLength {
+            buffer: 0,
+            expected: 28,
+            actual: 20
+        })
+    ));
+}
+
+#[cfg(feature = "guess_mime_type")]
+#[test]
+fn unknown_encodings_are_guessed() {
+    let gltf = GLTF.replace(
+        "data:,%89PNG%0D%0A%1A%0A%00%00%00%0DIHDR%00%00%00%02%00%00%00%03",
+        "data:;base64,q0tUWCAyMLsNChoK",
+    );
+    let (document, buffers) = gltf::import_slice(&gltf).unwrap();
+    let images = gltf::import_images_raw(&document, &buffers).unwrap();
+    assert_eq!(images[2].mime_type, Some("image/ktx2"));
+
+    let gltf = GLTF.replace(
+        "data:,%89PNG%0D%0A%1A%0A%00%00%00%0DIHDR%00%00%00%02%00%00%00%03",
+        "data:,GIF89a%01%00%01%00",
+    );
+    let (document, buffers) = gltf::import_slice(&gltf).unwrap();
+    let error = gltf::import_images_raw(&document, &buffers).unwrap_err();
+    assert!(matches!(
+        &error,
+        Error::UnsupportedImageEncoding { prefix } if prefix == b"GIF89a\x01\0"
+    ));
+    assert_eq!(
+        error.to_string(),
+        "unsupported image encoding starting with [47 49 46 38 39 61 01 00]"
+    );
+}
@@ -0,0 +1,151 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use gltf::accessor;
+use gltf::mesh::util::ReadIndices;
+
+/// Primitives indexed by `u8`s 2 bytes into their buffer view, by `u16`s, by
+/// a `u32` and not at all.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 28, "uri": "data:application/octet-stream;base64,CQkAAQIAAAADAAQABQAAAAAAAAAAAAAAAAAAAA=="}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 8},
+        {"buffer": 0, "byteOffset": 8, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 12, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 16, "byteLength": 12}
+    ],
+    "accessors": [
+        {"bufferView": 0, "byteOffset": 2, "componentType": 5121, "count": 3, "type": "SCALAR"},
+        {"bufferView": 1, "componentType": 5123, "count": 2, "type": "SCALAR"},
+        {"bufferView": 2, "componentType": 5125, "count": 1, "type": "SCALAR"},
+        {"bufferView": 3, "componentType": 5126, "count": 1, "type": "VEC3",
+         "min": [0, 0, 0], "max": [0, 0, 0]}
+    ],
+    "meshes": [{"primitives": [
+        {"attributes": {"POSITION": 3}, "indices": 0},
+        {"attributes": {"POSITION": 3}, "indices": 1},
+        {"attributes": {"POSITION": 3}, "indices": 2},
+        {"attributes": {"POSITION": 3}}
+    ]}]
+}"#;
+
+fn indices(gltf: &str) -> Vec<Result<Option<Vec<u32>>, accessor::Error>> {
+    let (document, buffers) = gltf::import_slice(gltf).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    let mesh = document.meshes().next().unwrap();
+    mesh.primitives()
+        .map(|primitive| {
+            let reader = primitive.reader(get_buffer_data);
+            let indices = reader.try_read_indices()?;
+            assert_eq!(indices.is_some(), reader.read_indices().is_some());
+            Ok(indices.map(|indices| indices.into_u32().collect()))
+        })
+        .collect()
+}
+
+#[test]
+fn indices_are_read_as_u32() {
+    assert_eq!(
+        indices(GLTF),
+        [
+            Ok(Some(vec![0, 1, 2])),
+            Ok(Some(vec![3, 4])),
+            Ok(Some(vec![5])),
+            Ok(None)
+        ]
+    );
+
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    let reader = primitive.reader(|buffer| buffers.get(buffer.index()).map(|data| &data[..]));
+    assert!(matches!(reader.read_indices(), Some(ReadIndices::U8(_))));
+}
+
+#[test]
+fn strided_indices_are_errors() {
+    let gltf = GLTF.replace(
+        r#"{"buffer": 0, "byteOffset": 8, "byteLength": 4}"#,
+        r#"{"buffer": 0, "byteOffset": 8, "byteLength": 4, "byteStride": 4}"#,
+    );
+    let error = accessor::Error::IndexStride {
+        accessor: 1,
+        stride: 4,
+    };
+    assert_eq!(indices(&gltf)[1], Err(error));
+    assert_eq!(
+        error.to_string(),
+        "accessor 1 holds indices but its buffer view has a byte stride of 4"
+    );
+}
+
+#[test]
+fn overrunning_indices_are_errors() {
+    let gltf = GLTF.replace(r#""count": 3"#, r#""count": 7"#);
+    assert_eq!(
+        indices(&gltf)[0],
+        Err(accessor::Error::MissingData {
+            accessor: 0,
+            required: 9,
+            available: 8
+        })
+    );
+}
+
+#[test]
+fn indices_are_generated_without_an_accessor() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    let mesh = document.meshes().next().unwrap();
+    let indices: Vec<_> = mesh
+        .primitives()
+        .map(|primitive| {
+            let reader = primitive.reader(get_buffer_data);
+            let indices = reader.read_or_generate_indices().unwrap().unwrap();
+            assert_eq!(primitive.vertex_count(), Ok(1));
+            assert_eq!(primitive.index_count(), Ok(indices.len()));
+            indices.collect::<Vec<_>>()
+        })
+        .collect();
+    assert_eq!(indices, [vec![0, 1, 2], vec![3, 4], vec![5], vec![0]]);
+}
+
+#[test]
+fn disagreeing_attribute_counts_are_errors() {
+    let gltf = GLTF.replace(
+        r#"{"attributes": {"POSITION": 3}}"#,
+        r#"{"attributes": {"POSITION": 3, "NORMAL": 4}}"#,
+    );
+    let gltf = gltf.replace(
+        r#""max": [0, 0, 0]}"#,
+        r#""max": [0, 0, 0]},
+        {"bufferView": 3, "componentType": 5126, "count": 0, "type": "VEC3"}"#,
+    );
+    let (document, buffers) = gltf::import_slice(gltf).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .nth(3)
+        .unwrap();
+    let error = accessor::Error::AttributeCount {
+        accessor: 4,
+        count: 0,
+        expected: 1,
+    };
+    assert_eq!(primitive.vertex_count(), Err(error));
+    let reader = primitive.reader(get_buffer_data);
+    assert_eq!(reader.read_or_generate_indices().err(), Some(error));
+    assert!(primitive.triangles(&reader).is_none());
+    assert_eq!(
+        error.to_string(),
+        "accessor 4 has 0 elements but its primitive has 1 vertices"
+    );
+}
@@ -0,0 +1,100 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use gltf::accessor::{self, AccessorLayoutCache, Iter};
+
+/// Three vertices with interleaved positions, normals and texture
+/// co-ordinates, padded to a stride of 36 bytes. The buffer view ends with
+/// the last texture co-ordinates, before the padding of the last vertex.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 104, "uri": "data:application/octet-stream;base64,AAAAAAAAAD8AAAAAAAAAAAAAAAAAAIA/AAAAAAAAgD8AAAAAAACAPwAAwD8AAIC/AACAPwAAAAAAAAAAAACAPgAAQD8AAAAAAAAAQAAAIEAAAADAAAAAAAAAAAAAAIA/AAAAPwAAAD8="}],
+    "bufferViews": [{"buffer": 0, "byteLength": 104, "byteStride": 36}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0.5, -2], "max": [2, 2.5, 0]},
+        {"bufferView": 0, "byteOffset": 12, "componentType": 5126, "count": 3, "type": "VEC3"},
+        {"bufferView": 0, "byteOffset": 24, "componentType": 5126, "count": 3, "type": "VEC2"}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2}}]}]
+}"#;
+
+#[test]
+fn interleaved_attributes_are_read() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    let cache = AccessorLayoutCache::new(&document);
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    for reader in [
+        primitive.reader(get_buffer_data),
+        primitive.reader_cached(&cache, get_buffer_data),
+    ] {
+        let positions: Vec<_> = reader.read_positions().unwrap().collect();
+        assert_eq!(
+            positions,
+            [[0.0, 0.5, 0.0], [1.0, 1.5, -1.0], [2.0, 2.5, -2.0]]
+        );
+        let normals: Vec<_> = reader.read_normals().unwrap().collect();
+        assert_eq!(normals, [[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+        let tex_coords: Vec<_> = reader.read_tex_coords(0).unwrap().into_f32().collect();
+        assert_eq!(tex_coords, [[0.0, 1.0], [0.25, 0.75], [0.5, 0.5]]);
+    }
+    assert_eq!(cache.cached(), 3);
+}
+
+#[test]
+fn interleaved_attributes_are_bounded_by_the_view() {
+    let gltf = GLTF.replace(
+        r#""byteLength": 104, "byteStride""#,
+        r#""byteLength": 103, "byteStride""#,
+    );
+    let (document, buffers) = gltf::import_slice(&gltf).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    let mut accessors = document.accessors();
+    let positions = accessors.next().unwrap();
+    assert_eq!(
+        Iter::<[f32; 3]>::try_new(positions, get_buffer_data)
+            .unwrap()
+            .map(Iterator::count),
+        Some(3)
+    );
+    let tex_coords = accessors.nth(1).unwrap();
+    assert_eq!(
+        Iter::<[f32; 2]>::try_new(tex_coords, get_buffer_data).err(),
+        Some(accessor::Error::MissingData {
+            accessor: 2,
+            required: 104,
+            available: 103
+        })
+    );
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    let reader = primitive.reader(get_buffer_data);
+    assert!(reader.read_normals().is_some());
+    assert!(reader.read_tex_coords(0).is_none());
+}
+
+#[test]
+fn strides_shorter_than_elements_are_invalid() {
+    let gltf = GLTF.replace(r#""byteStride": 36"#, r#""byteStride": 8"#);
+    match gltf::Gltf::from_slice(gltf.as_bytes()) {
+        Err(gltf::Error::Validation(errors)) => {
+            let paths: Vec<_> = errors.iter().map(|(path, _)| path.as_str()).collect();
+            assert_eq!(
+                paths,
+                ["accessors[0].bufferView", "accessors[1].bufferView"]
+            );
+        }
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+}
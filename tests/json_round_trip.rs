@@ -0,0 +1,91 @@
+#![cfg(all(feature = "extensions", feature = "extras"))]
+
+use gltf::json::{self, Value};
+use gltf::Document;
+
+/// Objects declaring only their required properties, and extras and an
+/// unknown extension whose formatting is unusual.
+const MINIMAL: &str = r#"{
+    "asset": {"version": "2.0"},
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "MAT4"},
+        {"componentType": 5126, "count": 2, "type": "SCALAR", "sparse": {"count": 1,
+         "indices": {"bufferView": 0, "componentType": 5125}, "values": {"bufferView": 0}}},
+        {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3",
+         "min": [0.5, 0.5, 0.5], "max": [1.5, 1.5, 1.5]}
+    ],
+    "animations": [{
+        "channels": [{"sampler": 0, "target": {"node": 0, "path": "translation"}}],
+        "samplers": [{"input": 1, "output": 1}]
+    }],
+    "bufferViews": [{"buffer": 0, "byteLength": 128}],
+    "buffers": [{"byteLength": 128}],
+    "cameras": [{"type": "perspective", "perspective": {"yfov": 0.8, "znear": 0.1}}],
+    "images": [{"uri": "image.png"}],
+    "samplers": [{}],
+    "textures": [{"source": 0, "sampler": 0}],
+    "materials": [
+        {
+            "normalTexture": {"index": 0},
+            "occlusionTexture": {"index": 0},
+            "emissiveTexture": {"index": 0},
+            "pbrMetallicRoughness": {"baseColorTexture": {"index": 0}},
+            "extras": {"tag" :  "red",  "weight": 1.50}
+        },
+        {"extensions": {"VENDOR_material": {"levels": [1, 2.5, {"deep": null}]}}}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 2}}]}],
+    "nodes": [{"children": [1], "name": "root"}, {"camera": 0}],
+    "scenes": [{"nodes": [0]}],
+    "scene": 0,
+    "extensionsUsed": ["VENDOR_material"]
+}"#;
+
+fn value(json: &[u8]) -> Value {
+    json::deserialize::from_slice(json).unwrap()
+}
+
+#[test]
+fn absent_and_default_properties_are_omitted() {
+    let document = Document::from_json(json::Root::from_str(MINIMAL).unwrap()).unwrap();
+    let output = document.to_json_string().unwrap();
+    assert_eq!(value(output.as_bytes()), value(MINIMAL.as_bytes()));
+    assert!(output.contains(r#""extras":{"tag" :  "red",  "weight": 1.50}"#));
+    assert_eq!(output.as_bytes(), document.to_json_vec().unwrap());
+}
+
+#[test]
+fn modified_documents_are_written_back() {
+    for path in ["examples/Box.gltf", "examples/Lantern.gltf"] {
+        let gltf = gltf::Gltf::from_slice(&std::fs::read(path).unwrap()).unwrap();
+        let mut root = gltf.document.into_json();
+        root.materials[0].pbr_metallic_roughness.metallic_factor =
+            json::material::StrengthFactor(0.25);
+        root.nodes[0].name = Some("edited".into());
+        let document = Document::from_json(root).unwrap();
+        let output = document.to_json_vec().unwrap();
+
+        let reloaded = gltf::Gltf::from_slice(&output).unwrap();
+        let material = reloaded.materials().next().unwrap();
+        assert_eq!(material.pbr_metallic_roughness().metallic_factor(), 0.25);
+        assert_eq!(reloaded.nodes().next().unwrap().name(), Some("edited"));
+        assert_eq!(
+            value(&reloaded.to_json_vec().unwrap()),
+            value(&output),
+            "{}",
+            path
+        );
+    }
+}
+
+#[cfg(feature = "KHR_materials_volume")]
+#[test]
+fn infinite_attenuation_distances_are_omitted() {
+    let gltf = MINIMAL.replace(
+        r#"{"extensions": {"VENDOR_material""#,
+        r#"{"extensions": {"KHR_materials_volume": {}, "VENDOR_material""#,
+    );
+    let document = Document::from_json(json::Root::from_str(&gltf).unwrap()).unwrap();
+    let output = document.to_json_vec().unwrap();
+    assert_eq!(value(&output), value(gltf.as_bytes()));
+}
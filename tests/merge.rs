@@ -0,0 +1,111 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use gltf::{buffer, Document, Gltf};
+
+fn load(path: &str) -> (Document, Vec<buffer::Data>) {
+    let Gltf { document, blob } = Gltf::from_slice(&std::fs::read(path).unwrap()).unwrap();
+    let buffers = gltf::import_buffers_with(&document, blob, |uri| {
+        std::fs::read(format!("tests/{}", uri))
+    })
+    .unwrap();
+    (document, buffers)
+}
+
+type Vertices = Vec<(Vec<[f32; 3]>, Option<Vec<[f32; 3]>>, Option<Vec<u32>>)>;
+
+/// The vertex data of the meshes of the scenes, in node order.
+fn vertices(
+    document: &Document,
+    buffers: &[buffer::Data],
+    scenes: std::ops::Range<usize>,
+) -> Vertices {
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    let mut vertices = Vec::new();
+    for scene in document.scenes().skip(scenes.start).take(scenes.len()) {
+        for (node, _) in scene.traverse().map_while(Result::ok) {
+            for primitive in node.mesh().iter().flat_map(|mesh| mesh.primitives()) {
+                let reader = primitive.reader(get_buffer_data);
+                vertices.push((
+                    reader.read_positions().unwrap().collect(),
+                    reader.read_normals().map(Iterator::collect),
+                    reader.read_indices().map(|iter| iter.into_u32().collect()),
+                ));
+            }
+        }
+    }
+    vertices
+}
+
+#[test]
+fn merged_documents_read_the_same_vertex_data() {
+    let (first, first_buffers) = load("examples/Box.glb");
+    let (sparse, sparse_buffers) = load("tests/box_sparse.glb");
+    let (split, split_buffers) = load("tests/two_buffers.glb");
+
+    let mut root = first.as_json().clone();
+    let mut buffers = first_buffers.clone();
+    gltf::transform::merge(&mut root, &mut buffers, &sparse, &sparse_buffers);
+    gltf::transform::merge(&mut root, &mut buffers, &split, &split_buffers);
+    assert_eq!(root.buffers.len(), 4);
+    assert_eq!(buffers.len(), 4);
+    assert_eq!(root.scene, first.as_json().scene);
+    let merged = Document::from_json(root).unwrap();
+
+    let scenes = [
+        first.scenes().len(),
+        sparse.scenes().len(),
+        split.scenes().len(),
+    ];
+    assert_eq!(merged.scenes().len(), scenes.iter().sum::<usize>());
+    let expected = [
+        vertices(&first, &first_buffers, 0..scenes[0]),
+        vertices(&sparse, &sparse_buffers, 0..scenes[1]),
+        vertices(&split, &split_buffers, 0..scenes[2]),
+    ];
+    let mut start = 0;
+    for (count, expected) in scenes.iter().zip(&expected) {
+        assert!(!expected.is_empty());
+        let scenes = start..start + count;
+        assert_eq!(&vertices(&merged, &buffers, scenes), expected);
+        start += count;
+    }
+
+    let glb = gltf::export::to_glb(&merged, &buffers).unwrap();
+    let (exported, exported_buffers) = gltf::import_slice(&glb).unwrap();
+    assert_eq!(
+        vertices(&exported, &exported_buffers, 0..start),
+        expected.concat()
+    );
+}
+
+#[test]
+fn extensions_are_unioned() {
+    let document = |extensions: &str| {
+        let json = format!(
+            r#"{{"asset": {{"version": "2.0"}}, "extensionsUsed": [{}], "nodes": [{{}}, {{"children": [0]}}], "scenes": [{{"nodes": [1]}}], "scene": 0}}"#,
+            extensions
+        );
+        Gltf::from_slice_without_validation(json.as_bytes())
+            .unwrap()
+            .document
+    };
+    let mut root = document(r#""EXT_a", "EXT_b""#).into_json();
+    root.scene = None;
+    let mut buffers = Vec::new();
+    gltf::transform::merge(
+        &mut root,
+        &mut buffers,
+        &document(r#""EXT_b", "EXT_c""#),
+        &[],
+    );
+    assert_eq!(root.extensions_used, ["EXT_a", "EXT_b", "EXT_c"]);
+    assert_eq!(root.scene.map(|scene| scene.value()), Some(1));
+    assert_eq!(root.scenes[1].nodes[0].value(), 3);
+    let children: Vec<_> = root.nodes[3]
+        .children
+        .iter()
+        .flatten()
+        .map(|n| n.value())
+        .collect();
+    assert_eq!(children, [2]);
+}
@@ -0,0 +1,64 @@
+#![cfg(all(feature = "import", feature = "EXT_meshopt_compression"))]
+
+use gltf::accessor::{self, Iter};
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["EXT_meshopt_compression"],
+    "extensionsRequired": ["EXT_meshopt_compression"],
+    "buffers": [
+        {"byteLength": 12, "uri": "data:application/octet-stream;base64,AACAPwAAAEAAAEBA"},
+        {"byteLength": 24, "extensions": {"EXT_meshopt_compression": {"fallback": true}}}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 12},
+        {"buffer": 1, "byteLength": 24}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "SCALAR"},
+        {"bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC3"}
+    ]
+}"#;
+
+#[test]
+fn fallback_buffer_is_empty() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    assert_eq!(buffers.len(), 2);
+    assert!(buffers[1].is_empty());
+    let fallback: Vec<_> = document
+        .buffers()
+        .map(|buffer| buffer.is_meshopt_fallback())
+        .collect();
+    assert_eq!(fallback, [false, true]);
+
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+    let scalars = document.accessors().next().unwrap();
+    let iter = Iter::<f32>::try_new(scalars, get_buffer_data)
+        .unwrap()
+        .unwrap();
+    assert_eq!(iter.collect::<Vec<_>>(), [1.0, 2.0, 3.0]);
+
+    let vectors = document.accessors().nth(1).unwrap();
+    assert_eq!(
+        Iter::<[f32; 3]>::try_new(vectors, get_buffer_data).err(),
+        Some(accessor::Error::MeshoptFallback {
+            accessor: 1,
+            buffer: 1
+        })
+    );
+}
+
+#[test]
+fn decompressed_fallback_is_read() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    let decompressed = [0; 24];
+    let get_buffer_data = |buffer: gltf::Buffer| match buffer.index() {
+        1 => Some(&decompressed[..]),
+        index => buffers.get(index).map(|x| &*x.0),
+    };
+    let vectors = document.accessors().nth(1).unwrap();
+    let iter = Iter::<[f32; 3]>::try_new(vectors, get_buffer_data)
+        .unwrap()
+        .unwrap();
+    assert_eq!(iter.count(), 2);
+}
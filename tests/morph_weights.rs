@@ -0,0 +1,170 @@
+#![cfg(feature = "animation")]
+
+use gltf::animation::{self, AnimError};
+use gltf::{buffer, Gltf};
+
+/// Three positions, a dense morph target moving every position along x, and
+/// a sparse one moving the last position along z.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "nodes": [
+        {"mesh": 0},
+        {"mesh": 0, "weights": [1, 0]},
+        {"mesh": 0},
+        {}
+    ],
+    "meshes": [{
+        "primitives": [{
+            "attributes": {"POSITION": 0},
+            "targets": [{"POSITION": 1}, {"POSITION": 2}]
+        }],
+        "weights": [0.25, 0.5]
+    }],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [1, 0, 0], "max": [1, 0, 0]},
+        {"componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0, 0], "max": [0, 0, 4],
+         "sparse": {"count": 1,
+                    "indices": {"bufferView": 2, "componentType": 5121},
+                    "values": {"bufferView": 3}}},
+        {"bufferView": 4, "componentType": 5126, "count": 2, "type": "SCALAR",
+         "min": [0], "max": [1]},
+        {"bufferView": 5, "componentType": 5126, "count": 4, "type": "SCALAR"}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 72, "byteLength": 1},
+        {"buffer": 0, "byteOffset": 76, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 88, "byteLength": 8},
+        {"buffer": 0, "byteOffset": 96, "byteLength": 16}
+    ],
+    "buffers": [{"byteLength": 112}],
+    "animations": [{
+        "samplers": [{"input": 3, "output": 4}],
+        "channels": [{"sampler": 0, "target": {"node": 2, "path": "weights"}}]
+    }]
+}"#;
+
+fn buffers() -> Vec<buffer::Data> {
+    let floats = |floats: &[f32]| {
+        floats
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect::<Vec<_>>()
+    };
+    let mut data = floats(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+    data.extend(floats(&[1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0]));
+    data.extend([2, 0, 0, 0]);
+    data.extend(floats(&[0.0, 0.0, 4.0]));
+    data.extend(floats(&[0.0, 1.0]));
+    data.extend(floats(&[0.0, 0.0, 1.0, 2.0]));
+    vec![buffer::Data(data)]
+}
+
+#[test]
+fn precedence() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let buffers = buffers();
+    let weights = |node, animation| {
+        let mut out = [9.0; 3];
+        let count =
+            animation::effective_morph_weights(&gltf, node, animation, &buffers, &mut out).unwrap();
+        assert_eq!(out[2], 9.0);
+        out[..count].to_vec()
+    };
+
+    assert_eq!(weights(0, None), [0.25, 0.5]);
+    assert_eq!(weights(1, None), [1.0, 0.0]);
+    assert_eq!(weights(1, Some((0, 0.5))), [1.0, 0.0]);
+    assert_eq!(weights(2, None), [0.25, 0.5]);
+    assert_eq!(weights(2, Some((0, 0.5))), [0.5, 1.0]);
+    assert_eq!(weights(2, Some((0, 5.0))), [1.0, 2.0]);
+    assert!(weights(3, Some((0, 0.5))).is_empty());
+
+    let mut out = [0.0];
+    assert_eq!(
+        animation::effective_morph_weights(&gltf, 0, None, &buffers, &mut out),
+        Err(AnimError::TargetCount {
+            expected: 2,
+            actual: 1
+        })
+    );
+    let mut out = [0.0; 2];
+    assert_eq!(
+        animation::effective_morph_weights(&gltf, 4, None, &buffers, &mut out),
+        Err(AnimError::MissingNode(4))
+    );
+    assert_eq!(
+        animation::effective_morph_weights(&gltf, 2, Some((1, 0.0)), &buffers, &mut out),
+        Err(AnimError::MissingAnimation(1))
+    );
+    assert!(matches!(
+        animation::effective_morph_weights(&gltf, 2, Some((0, 0.0)), &[], &mut out),
+        Err(AnimError::Accessor(_))
+    ));
+}
+
+#[test]
+fn blended_positions() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let buffers = buffers();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+
+    let positions = animation::apply_morph_weights(&primitive, &[0.5, 1.0], &buffers).unwrap();
+    assert_eq!(
+        positions,
+        [[0.5, 0.0, 0.0], [1.5, 0.0, 0.0], [0.5, 1.0, 4.0]]
+    );
+    let positions = animation::apply_morph_weights(&primitive, &[0.0, 0.0], &buffers).unwrap();
+    assert_eq!(
+        positions,
+        [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]
+    );
+    assert_eq!(
+        animation::apply_morph_weights(&primitive, &[1.0], &buffers),
+        Err(AnimError::TargetCount {
+            expected: 2,
+            actual: 1
+        })
+    );
+}
+
+#[cfg(feature = "utils")]
+#[test]
+fn read_targets_blend_as_applied() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let buffers = buffers();
+    let mesh = gltf.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let targets: Vec<_> = primitive
+        .morph_targets()
+        .map(|target| {
+            assert!(target.normals().is_none() && target.tangents().is_none());
+            target.positions().map(|accessor| accessor.index())
+        })
+        .collect();
+    assert_eq!(targets, [Some(1), Some(2)]);
+
+    let weights = mesh.weights().unwrap();
+    let reader = primitive.reader(|buffer| buffers.get(buffer.index()).map(|data| &data[..]));
+    let mut positions: Vec<[f32; 3]> = reader.read_positions().unwrap().collect();
+    for ((displacements, _, _), weight) in reader.read_morph_targets().zip(weights) {
+        for (position, displacement) in positions.iter_mut().zip(displacements.unwrap()) {
+            for (x, dx) in position.iter_mut().zip(displacement) {
+                *x += weight * dx;
+            }
+        }
+    }
+    assert_eq!(
+        positions,
+        [[0.25, 0.0, 0.0], [1.25, 0.0, 0.0], [0.25, 1.0, 2.0]]
+    );
+    assert_eq!(
+        animation::apply_morph_weights(&primitive, weights, &buffers),
+        Ok(positions)
+    );
+}
@@ -0,0 +1,53 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use gltf::binary::ChunkIter;
+use gltf::Glb;
+
+/// Counts the allocations made by the current thread.
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Returns the number of allocations that `f` makes.
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn layout_of_files_with_unknown_chunks_does_not_allocate() {
+    let mut data = std::fs::read("examples/Box.glb").unwrap();
+    data.extend_from_slice(&4u32.to_le_bytes());
+    data.extend_from_slice(b"EXT\0");
+    data.extend_from_slice(&[1, 2, 3, 4]);
+    let length = data.len() as u32;
+    data[8..12].copy_from_slice(&length.to_le_bytes());
+
+    let (layout, count) = allocations(|| ChunkIter::new(&data).unwrap().layout().unwrap());
+    assert_eq!(count, 0);
+
+    let glb = Glb::from_slice(&data).unwrap();
+    assert_eq!(glb.extra_chunks.len(), 1);
+    let expected = glb.layout();
+    assert_eq!(layout.json, expected.json);
+    assert_eq!(layout.bin, expected.bin);
+    assert_eq!(&data[layout.bin.unwrap()], &glb.bin.unwrap()[..]);
+}
@@ -0,0 +1,75 @@
+use gltf::scene::ParentConflict;
+use gltf::Gltf;
+
+/// A chain of three translated nodes under a scaled root, and a separate
+/// root.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "scenes": [{"nodes": [0, 3]}],
+    "nodes": [
+        {"children": [1], "scale": [2, 2, 2]},
+        {"children": [2], "translation": [1, 0, 0]},
+        {"translation": [0, 1, 0]},
+        {"translation": [0, 0, 5]}
+    ]
+}"#;
+
+#[test]
+fn parents_and_ancestors() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let parents = gltf.node_parents();
+    let found: Vec<_> = (0..5).map(|node| parents.parent_of(node)).collect();
+    assert_eq!(found, [None, Some(0), Some(1), None, None]);
+    assert_eq!(parents.ancestors(2).collect::<Vec<_>>(), [1, 0]);
+    assert_eq!(parents.ancestors(3).count(), 0);
+    assert!(parents.conflicts().is_empty());
+
+    let scene = gltf.scenes().next().unwrap();
+    for item in scene.traverse() {
+        let (node, world) = item.unwrap();
+        assert_eq!(node.global_transform(&parents), world);
+    }
+    let node = gltf.nodes().nth(2).unwrap();
+    assert_eq!(node.global_transform(&parents)[3], [2.0, 2.0, 0.0, 1.0]);
+}
+
+#[test]
+fn multiple_parents_are_conflicts() {
+    let source = GLTF.replace(r#"{"translation": [0, 0, 5]}"#, r#"{"children": [2]}"#);
+    let gltf = Gltf::from_slice_without_validation(source.as_bytes()).unwrap();
+    let parents = gltf.node_parents();
+    assert_eq!(parents.parent_of(2), Some(1));
+    assert_eq!(parents.parent_of(3), None);
+    let conflict = ParentConflict {
+        node: 2,
+        parent: 1,
+        other: 3,
+        position: 0,
+    };
+    assert_eq!(parents.conflicts(), [conflict]);
+    assert_eq!(
+        conflict.to_string(),
+        "nodes[3].children[0]: node 2 is already a child of node 1"
+    );
+
+    // Validation reports the same entry.
+    match Gltf::from_slice(source.as_bytes()) {
+        Err(gltf::Error::Validation(errors)) => {
+            let paths: Vec<_> = errors.iter().map(|(path, _)| path.as_str()).collect();
+            assert_eq!(
+                paths,
+                [r#"nodes[3].children[0] = "2 is already a child of 1""#]
+            );
+        }
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+}
+
+#[test]
+fn cycles_terminate() {
+    let gltf = GLTF.replace(r#"{"translation": [0, 1, 0]}"#, r#"{"children": [0]}"#);
+    let gltf = Gltf::from_slice_without_validation(gltf.as_bytes()).unwrap();
+    let parents = gltf.node_parents();
+    assert_eq!(parents.ancestors(0).collect::<Vec<_>>(), [2, 1]);
+    assert_eq!(parents.ancestors(1).collect::<Vec<_>>(), [0, 2]);
+}
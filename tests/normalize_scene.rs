@@ -0,0 +1,189 @@
+use gltf::transform::{self, DocumentMut, NormalizeTarget};
+use gltf::{buffer, Gltf};
+
+/// A box from `[0, 0, 0]` to `[4, 2, 2]` on a node that an animation moves
+/// from `[10, 0, 0]` to `[20, 0, 0]`.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "scene": 0,
+    "scenes": [{"nodes": [0]}],
+    "nodes": [{"mesh": 0, "translation": [10, 0, 0]}],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}]}],
+    "animations": [{
+        "channels": [{"sampler": 0, "target": {"node": 0, "path": "translation"}}],
+        "samplers": [{"input": 1, "output": 2}]
+    }],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+         "min": [0, 0, 0], "max": [4, 2, 2]},
+        {"bufferView": 1, "componentType": 5126, "count": 2, "type": "SCALAR",
+         "min": [0], "max": [1]},
+        {"bufferView": 2, "componentType": 5126, "count": 2, "type": "VEC3"}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 24, "byteLength": 8},
+        {"buffer": 0, "byteOffset": 32, "byteLength": 24}
+    ],
+    "buffers": [{"byteLength": 56}]
+}"#;
+
+fn floats(values: &[f32]) -> Vec<u8> {
+    values.iter().flat_map(|x| x.to_le_bytes()).collect()
+}
+
+fn load() -> (gltf::json::Root, Vec<buffer::Data>) {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let data = floats(&[
+        0.0, 0.0, 0.0, 4.0, 2.0, 2.0, // positions
+        0.0, 1.0, // times
+        10.0, 0.0, 0.0, 20.0, 0.0, 0.0, // translations
+    ]);
+    (gltf.document.into_json(), vec![buffer::Data(data)])
+}
+
+fn elements(root: &gltf::json::Root, buffers: &[buffer::Data], accessor: usize) -> Vec<f64> {
+    let mut edit = DocumentMut {
+        root: root.clone(),
+        buffers: buffers.to_vec(),
+    };
+    let accessor = edit.accessor_mut(accessor).unwrap();
+    let mut values = vec![0.0; accessor.count() * accessor.components()];
+    for (element, values) in values.chunks_mut(accessor.components()).enumerate() {
+        accessor.element(element, values).unwrap();
+    }
+    values
+}
+
+#[test]
+fn wrapper_node_keeps_animations_in_proportion() {
+    let (mut root, mut buffers) = load();
+    let original = buffers.clone();
+    let normalization =
+        transform::normalize_scene(&mut root, &mut buffers, 0, NormalizeTarget::default())
+            .unwrap()
+            .unwrap();
+    assert_eq!(normalization.bounds.min, [10.0, 0.0, 0.0]);
+    assert_eq!(normalization.bounds.max, [14.0, 2.0, 2.0]);
+    assert_eq!(normalization.scale, 0.25);
+    assert_eq!(normalization.translation, [-3.0, -0.25, -0.25]);
+    assert_eq!(normalization.wrapper, Some(1));
+
+    // The animated node is untouched and moves inside the wrapper.
+    assert_eq!(buffers[0].0, original[0].0);
+    assert_eq!(root.scenes[0].nodes[0].value(), 1);
+    let wrapper = &root.nodes[1];
+    assert_eq!(wrapper.children.as_ref().unwrap()[0].value(), 0);
+    assert_eq!(wrapper.scale, Some([0.25; 3]));
+    assert_eq!(root.nodes[0].translation, Some([10.0, 0.0, 0.0]));
+    let end = elements(&root, &buffers, 2)[3] as f32 * 0.25 - 3.0;
+    assert_eq!(end, 2.0);
+
+    // The scene now fits the unit cube at the origin.
+    let again = transform::normalize_scene(&mut root, &mut buffers, 0, NormalizeTarget::default())
+        .unwrap()
+        .unwrap();
+    assert_eq!(again.bounds.min, [-0.5, -0.25, -0.25]);
+    assert_eq!(again.bounds.max, [0.5, 0.25, 0.25]);
+    assert_eq!((again.scale, again.translation), (1.0, [0.0; 3]));
+    gltf::Document::from_json(root).unwrap();
+}
+
+#[test]
+fn baked_normalization() {
+    let (mut root, mut buffers) = load();
+    let target = NormalizeTarget {
+        bake: true,
+        ..Default::default()
+    };
+    let normalization = transform::normalize_scene(&mut root, &mut buffers, 0, target)
+        .unwrap()
+        .unwrap();
+    assert_eq!(normalization.wrapper, None);
+    assert_eq!(root.nodes.len(), 1);
+    assert_eq!(root.nodes[0].translation, Some([-0.5, -0.25, -0.25]));
+    assert_eq!(elements(&root, &buffers, 0), [0.0, 0.0, 0.0, 1.0, 0.5, 0.5]);
+    assert_eq!(
+        root.accessors[0].max,
+        Some(serde_json::json!([1.0, 0.5, 0.5]))
+    );
+    #[cfg(feature = "animation")]
+    assert_eq!(
+        elements(&root, &buffers, 2),
+        [-0.5, -0.25, -0.25, 2.0, -0.25, -0.25]
+    );
+
+    let again = transform::normalize_scene(&mut root, &mut buffers, 0, target)
+        .unwrap()
+        .unwrap();
+    assert_eq!((again.scale, again.translation), (1.0, [0.0; 3]));
+    gltf::Document::from_json(root).unwrap();
+}
+
+#[cfg(feature = "animation")]
+#[test]
+fn failed_bake_leaves_document_untouched() {
+    use gltf::transform::EditError;
+
+    let (mut root, mut buffers) = load();
+    // The positions can be edited, but the translation outputs cannot.
+    root.accessors[2].buffer_view = None;
+    let json = root.to_vec().unwrap();
+    let data = buffers[0].to_vec();
+    let target = NormalizeTarget {
+        bake: true,
+        ..Default::default()
+    };
+    let result = transform::normalize_scene(&mut root, &mut buffers, 0, target);
+    assert_eq!(result, Err(EditError::Sparse(2)));
+    assert_eq!(root.to_vec().unwrap(), json);
+    assert_eq!(buffers[0].to_vec(), data);
+}
+
+#[test]
+fn missing_scene() {
+    let (mut root, mut buffers) = load();
+    let result = transform::normalize_scene(&mut root, &mut buffers, 1, Default::default());
+    assert_eq!(result, Ok(None));
+    root.meshes[0].primitives[0].attributes.clear();
+    let result = transform::normalize_scene(&mut root, &mut buffers, 0, Default::default());
+    assert_eq!(result, Ok(None));
+}
+
+#[cfg(all(feature = "skinning", feature = "utils"))]
+#[test]
+fn skinned_rest_pose_bounds() {
+    // The joint lifts the mesh by 5 units, beyond its declared bounds.
+    let gltf = r#"{
+        "asset": {"version": "2.0"},
+        "scenes": [{"nodes": [0, 1]}],
+        "nodes": [{"mesh": 0, "skin": 0}, {"translation": [0, 5, 0]}],
+        "skins": [{"joints": [1]}],
+        "meshes": [{"primitives": [{"attributes": {"POSITION": 0, "JOINTS_0": 1, "WEIGHTS_0": 2}}]}],
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+             "min": [0, 0, 0], "max": [2, 2, 2]},
+            {"bufferView": 1, "componentType": 5121, "count": 2, "type": "VEC4"},
+            {"bufferView": 2, "componentType": 5126, "count": 2, "type": "VEC4"}
+        ],
+        "bufferViews": [
+            {"buffer": 0, "byteLength": 24},
+            {"buffer": 0, "byteOffset": 24, "byteLength": 8},
+            {"buffer": 0, "byteOffset": 32, "byteLength": 32}
+        ],
+        "buffers": [{"byteLength": 64}]
+    }"#;
+    let mut data = floats(&[0.0, 0.0, 0.0, 2.0, 2.0, 2.0]);
+    data.extend_from_slice(&[0; 8]);
+    data.extend(floats(&[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0]));
+    let mut root = Gltf::from_slice(gltf.as_bytes())
+        .unwrap()
+        .document
+        .into_json();
+    let mut buffers = vec![buffer::Data(data)];
+    let normalization = transform::normalize_scene(&mut root, &mut buffers, 0, Default::default())
+        .unwrap()
+        .unwrap();
+    assert_eq!(normalization.bounds.min, [0.0, 5.0, 0.0]);
+    assert_eq!(normalization.bounds.max, [2.0, 7.0, 2.0]);
+}
@@ -0,0 +1,106 @@
+use gltf::{json, Gltf, Warning};
+
+const FIXTURE: &str = include_str!("number_syntax.gltf");
+
+fn factor_warnings(gltf: &Gltf) -> Vec<Warning> {
+    let mut warnings = gltf.warnings();
+    warnings.retain(|warning| matches!(warning, Warning::FactorOutOfRange { .. }));
+    warnings
+}
+
+#[test]
+fn integral_fields_accept_float_syntax() {
+    let gltf = Gltf::from_slice(FIXTURE.as_bytes()).unwrap();
+    let accessor = gltf.accessors().next().unwrap();
+    assert_eq!(accessor.count(), 2);
+    assert_eq!(accessor.offset(), 0);
+    let view = accessor.view().unwrap();
+    assert_eq!((view.offset(), view.length()), (16, 24));
+    assert_eq!(view.stride(), Some(12));
+    assert_eq!(gltf.buffers().next().unwrap().length(), 40);
+    assert_eq!(gltf.default_scene().unwrap().index(), 0);
+}
+
+#[test]
+fn integral_fields_reject_fractions() {
+    for (field, value) in [
+        (r#""count": 2.0"#, r#""count": 2.5"#),
+        (r#""byteOffset": 1.6e1"#, r#""byteOffset": -16.0"#),
+        (r#""texCoord": 0.0"#, r#""texCoord": 1e10"#),
+        (r#""componentType": 5126.0"#, r#""componentType": 5126.1"#),
+    ] {
+        let source = FIXTURE.replace(field, value);
+        let error = Gltf::from_slice(source.as_bytes()).unwrap_err().to_string();
+        let number = value.split(": ").nth(1).unwrap();
+        let expected = json::deserialize::from_str::<f64>(number).unwrap();
+        assert!(error.contains(&expected.to_string()), "{}", error);
+        assert!(error.contains("line"), "{}", error);
+    }
+}
+
+#[test]
+fn bounds_keep_double_precision() {
+    let gltf = Gltf::from_slice(FIXTURE.as_bytes()).unwrap();
+    let accessor = gltf.accessors().next().unwrap();
+    assert_eq!(accessor.min_f64(), Some(vec![-1e39, 0.0, 0.1]));
+    assert_eq!(accessor.max_f64(), Some(vec![1e39, 1.0000000001, 0.1]));
+
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+    let bounds = primitive.bounding_box();
+    assert_eq!(bounds.min, [f32::MIN, 0.0, 0.1]);
+    assert_eq!(bounds.max, [f32::MAX, 1.0, 0.1]);
+}
+
+#[test]
+fn factors_out_of_range_are_clamped_with_warning() {
+    let gltf = Gltf::from_slice(FIXTURE.as_bytes()).unwrap();
+    let material = gltf.materials().next().unwrap();
+    assert_eq!(
+        material.pbr_metallic_roughness().metallic_factor(),
+        f32::MAX
+    );
+    assert_eq!(material.normal_texture().unwrap().scale(), f32::MIN);
+    assert_eq!(material.pbr_metallic_roughness().roughness_factor(), 1.0);
+
+    let paths = [
+        "materials[0].pbrMetallicRoughness.metallicFactor",
+        "materials[0].normalTexture.scale",
+    ];
+    let warnings: Vec<_> = paths
+        .iter()
+        .map(|path| Warning::FactorOutOfRange {
+            path: json::Path(path.to_string()),
+        })
+        .collect();
+    assert_eq!(factor_warnings(&gltf), warnings);
+    assert_eq!(
+        warnings[0].to_string(),
+        "materials[0].pbrMetallicRoughness.metallicFactor exceeds the range of f32 and is clamped"
+    );
+
+    let source = FIXTURE
+        .replace("1e39,\n", "1.0,\n")
+        .replace("-1e39}", "1.0}");
+    let gltf = Gltf::from_slice(source.as_bytes()).unwrap();
+    assert!(factor_warnings(&gltf).is_empty());
+}
+
+#[cfg(all(feature = "KHR_texture_transform", feature = "KHR_materials_variants"))]
+#[test]
+fn extension_integral_fields_accept_float_syntax() {
+    use json::extensions::{mesh::Mapping, texture::TextureTransform};
+
+    let transform: TextureTransform = json::deserialize::from_str(r#"{"texCoord": 1.0}"#).unwrap();
+    assert_eq!(transform.tex_coord, Some(1));
+    let transform: TextureTransform = json::deserialize::from_str("{}").unwrap();
+    assert_eq!(transform.tex_coord, None);
+    assert!(json::deserialize::from_str::<TextureTransform>(r#"{"texCoord": 1.5}"#).is_err());
+
+    let mapping: Mapping =
+        json::deserialize::from_str(r#"{"material": 2e0, "variants": [0, 1.0]}"#).unwrap();
+    assert_eq!(mapping.material, 2);
+    assert_eq!(mapping.variants, [0, 1]);
+    assert!(
+        json::deserialize::from_str::<Mapping>(r#"{"material": 0, "variants": [-1.0]}"#).is_err()
+    );
+}
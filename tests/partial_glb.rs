@@ -0,0 +1,85 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use std::fs;
+
+use gltf::accessor::{self, Iter};
+
+#[test]
+fn truncated_bin_chunk() {
+    let data = fs::read("tests/box_sparse.glb").unwrap();
+    // Keep the complete JSON chunk and the first 200 of 352 BIN bytes.
+    let truncated = &data[..20 + 1720 + 8 + 200];
+    assert!(gltf::Glb::from_slice(truncated).is_err());
+
+    let glb = gltf::Glb::from_slice_partial(truncated).unwrap();
+    assert_eq!(glb.bin.as_ref().unwrap().len(), 200);
+    assert_eq!(glb.bin_length, Some(352));
+
+    let document = gltf::Gltf::from_slice(&glb.json).unwrap().document;
+    let (buffers, unusable) = gltf::import_buffers_partial(&document, &glb);
+    assert_eq!(unusable, [1, 2, 3, 4]);
+
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+    let indices = document.accessors().next().unwrap();
+    let iter = Iter::<u32>::try_new(indices, get_buffer_data)
+        .unwrap()
+        .unwrap();
+    assert_eq!(iter.count(), 36);
+
+    let positions = document.accessors().nth(1).unwrap();
+    assert_eq!(
+        Iter::<[f32; 3]>::try_new(positions, get_buffer_data).err(),
+        Some(accessor::Error::MissingData {
+            accessor: 1,
+            required: 96,
+            available: 56,
+        })
+    );
+
+    let primitive = document
+        .meshes()
+        .next()
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap();
+    let reader = primitive.reader(get_buffer_data);
+    assert_eq!(reader.read_indices().unwrap().into_u32().count(), 36);
+    assert!(reader.read_positions().is_none());
+}
+
+#[test]
+fn bin_chunk_and_data_uris() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [
+            {"byteLength": 4, "uri": "external.bin"},
+            {"byteLength": 4, "uri": "data:application/octet-stream;base64,AQIDBA=="}
+        ],
+        "bufferViews": [
+            {"buffer": 0, "byteLength": 4},
+            {"buffer": 1, "byteLength": 4}
+        ],
+        "accessors": [
+            {"bufferView": 0, "componentType": 5121, "count": 4, "type": "SCALAR"},
+            {"bufferView": 1, "componentType": 5121, "count": 4, "type": "SCALAR"}
+        ]
+    }"#;
+    let glb = gltf::binary::PartialGlb {
+        header: gltf::binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: json.as_bytes().into(),
+        bin: Some(vec![5, 6, 7, 8].into()),
+        bin_length: Some(4),
+    };
+    let document = gltf::Gltf::from_slice(json.as_bytes()).unwrap().document;
+    let (buffers, unusable) = gltf::import_buffers_partial(&document, &glb);
+
+    // The first buffer declares a URI, so it does not refer to the BIN chunk.
+    assert!(buffers[0].is_empty());
+    assert_eq!(&*buffers[1], &[1, 2, 3, 4]);
+    assert_eq!(unusable, [0]);
+}
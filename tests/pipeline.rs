@@ -0,0 +1,453 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use std::borrow::Cow;
+
+use gltf::binary::Header;
+use gltf::json::validation::Checked;
+use gltf::mesh::{self, Semantic};
+use gltf::transform::{self, QuantizeOptions};
+use gltf::{buffer, Glb, Gltf};
+
+/// A scene with a mesh whose two attributes duplicate each other, and a mesh,
+/// material and accessor that the scene does not use.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "scene": 1,
+    "scenes": [{"nodes": []}, {"nodes": [0]}],
+    "nodes": [{"mesh": 1}],
+    "meshes": [
+        {"primitives": [{"attributes": {"POSITION": 0}, "material": 0}]},
+        {"primitives": [{"attributes": {"POSITION": 1, "TEXCOORD_0": 2, "COLOR_0": 3}}]}
+    ],
+    "materials": [{}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 1, 1]},
+        {"bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 2, 3]},
+        {"bufferView": 2, "componentType": 5126, "count": 2, "type": "VEC3"},
+        {"bufferView": 3, "componentType": 5121, "normalized": true, "count": 2,
+         "type": "VEC3"}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 24, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 48, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 72, "byteLength": 6}
+    ],
+    "buffers": [{"byteLength": 78}]
+}"#;
+
+fn buffers() -> Vec<buffer::Data> {
+    let mut data = Vec::new();
+    for _ in 0..3 {
+        for x in [0.0f32, 0.0, 0.0, 1.0, 2.0, 3.0] {
+            data.extend_from_slice(&x.to_le_bytes());
+        }
+    }
+    data.extend_from_slice(&[255, 0, 0, 0, 255, 0]);
+    vec![buffer::Data(data)]
+}
+
+#[test]
+fn prune_and_dedup() {
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let mut root = gltf.document.into_json();
+    let mut buffers = buffers();
+
+    transform::prune(&mut root, &mut buffers);
+    assert_eq!(root.scene.map(|scene| scene.value()), Some(1));
+    assert_eq!(root.meshes.len(), 1);
+    assert!(root.materials.is_empty());
+    assert_eq!(root.accessors.len(), 3);
+    assert_eq!(buffers.len(), 1);
+    assert_eq!(root.buffers[0].byte_length.0 as usize, buffers[0].len());
+
+    // The texture co-ordinates hold the same data as the positions, and take
+    // over their bounds.
+    let remap = transform::dedup_accessors(&mut root, &buffers);
+    assert_eq!(remap, [0, 0, 1]);
+    assert_eq!(root.accessors.len(), 2);
+    let primitive = &root.meshes[0].primitives[0];
+    let attributes: Vec<_> = primitive.attributes.values().map(|x| x.value()).collect();
+    assert_eq!(attributes, [0, 1, 0]);
+    assert!(root.accessors[0].min.is_some());
+
+    // Only the view of the removed accessor is left without users.
+    assert_eq!(root.buffer_views.len(), 3);
+    transform::prune(&mut root, &mut buffers);
+    assert_eq!(root.buffer_views.len(), 2);
+    gltf::Document::from_json(root).unwrap();
+}
+
+#[test]
+fn interleaved_attributes() {
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let buffers = buffers();
+    let primitive = gltf.meshes().nth(1).unwrap().primitives().next().unwrap();
+    let interleaved = mesh::interleave(&primitive, &buffers).unwrap();
+
+    let layout: Vec<_> = interleaved
+        .attributes
+        .iter()
+        .map(|attribute| (attribute.semantic.clone(), attribute.offset))
+        .collect();
+    assert_eq!(
+        layout,
+        [
+            (Semantic::Positions, 0),
+            (Semantic::Colors(0), 12),
+            (Semantic::TexCoords(0), 16)
+        ]
+    );
+    assert!(interleaved.attributes[1].normalized);
+    assert_eq!((interleaved.stride, interleaved.count), (28, 2));
+    let vertex = &interleaved.data[28..];
+    assert_eq!(&vertex[..12], &buffers[0][12..24]);
+    assert_eq!(&vertex[12..16], [0, 255, 0, 0]);
+    assert_eq!(&vertex[16..], &buffers[0][12..24]);
+}
+
+#[test]
+fn glb_round_trip() {
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let mut root = gltf.document.into_json();
+    let mut buffers = buffers();
+    transform::prune(&mut root, &mut buffers);
+    transform::quantize(&mut root, &mut buffers, QuantizeOptions::default());
+    transform::prune(&mut root, &mut buffers);
+
+    let json = root.to_vec().unwrap();
+    let glb = Glb {
+        header: Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: Cow::Borrowed(&json),
+        bin: Some(Cow::Borrowed(&buffers[0])),
+        extra_chunks: Vec::new(),
+        length_read: None,
+        chunk_alignment: gltf::ChunkAlignment::Exact,
+    };
+    let data = glb.to_vec().unwrap();
+    assert_eq!(data.len() % 4, 0);
+
+    let glb = Glb::from_slice(&data).unwrap();
+    assert_eq!(glb.header.length as usize, data.len());
+    let layout = glb.layout();
+    assert_eq!(layout.json.start, 20);
+    assert_eq!(&data[layout.json.clone()][..json.len()], &json[..]);
+    let bin = layout.bin.unwrap();
+    assert_eq!(
+        &data[bin.start..bin.start + buffers[0].len()],
+        &buffers[0][..]
+    );
+    assert_eq!(bin.end, data.len());
+
+    let reloaded = Gltf::from_slice_without_validation(&data).unwrap();
+    assert_eq!(reloaded.blob.as_deref().map(<[u8]>::len), Some(bin.len()));
+    assert_eq!(reloaded.accessors().count(), root.accessors.len());
+}
+
+/// A textured triangle whose two textures use images with the same data, with
+/// `UNSIGNED_INT` indices and a mesh that the scene does not use.
+const TEXTURED: &str = r#"{
+    "asset": {"version": "2.0"},
+    "scene": 0,
+    "scenes": [{"nodes": [0]}],
+    "nodes": [{"mesh": 0}],
+    "meshes": [
+        {"primitives": [{"attributes": {"POSITION": 0, "TEXCOORD_0": 1}, "indices": 2,
+                         "material": 0}]},
+        {"primitives": [{"attributes": {"POSITION": 0}}]}
+    ],
+    "materials": [{
+        "pbrMetallicRoughness": {"baseColorTexture": {"index": 0}},
+        "emissiveTexture": {"index": 1}
+    }],
+    "textures": [{"source": 0}, {"source": 1}],
+    "images": [
+        {"bufferView": 3, "mimeType": "image/png"},
+        {"bufferView": 4, "mimeType": "image/png"}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 1, 0]},
+        {"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC2"},
+        {"bufferView": 2, "componentType": 5125, "count": 3, "type": "SCALAR"}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 60, "byteLength": 12, "target": 34963},
+        {"buffer": 0, "byteOffset": 72, "byteLength": 8},
+        {"buffer": 0, "byteOffset": 80, "byteLength": 8}
+    ],
+    "buffers": [{"byteLength": 88}]
+}"#;
+
+fn textured_data() -> Vec<u8> {
+    let mut data = Vec::new();
+    for x in [0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0] {
+        data.extend_from_slice(&x.to_le_bytes());
+    }
+    for x in [0.0f32, 0.0, 1.0, 0.0, 0.0, 1.0] {
+        data.extend_from_slice(&x.to_le_bytes());
+    }
+    for index in [0u32, 1, 2] {
+        data.extend_from_slice(&index.to_le_bytes());
+    }
+    for _ in 0..2 {
+        data.extend_from_slice(b"\x89PNG\r\n\x1a\n");
+    }
+    data
+}
+
+#[test]
+fn merge_duplicate_textures() {
+    let gltf = Gltf::from_slice(TEXTURED.as_bytes()).unwrap();
+    let mut root = gltf.document.into_json();
+    let buffers = vec![buffer::Data(textured_data())];
+    let deduped = transform::dedup_textures(&mut root, &buffers);
+    assert_eq!(deduped.images, [0, 0]);
+    assert_eq!(deduped.textures, [0, 0]);
+    assert_eq!((root.images.len(), root.textures.len()), (1, 1));
+    let material = &root.materials[0];
+    assert_eq!(material.emissive_texture.as_ref().unwrap().index.value(), 0);
+
+    // Textures with different samplers are kept apart.
+    root.samplers.push(Default::default());
+    root.textures.push(gltf::json::Texture {
+        sampler: Some(gltf::json::Index::new(0)),
+        ..root.textures[0].clone()
+    });
+    let deduped = transform::dedup_textures(&mut root, &buffers);
+    assert_eq!(deduped.textures, [0, 1]);
+    gltf::Document::from_json(root).unwrap();
+}
+
+#[test]
+fn narrow_indices_in_place() {
+    let gltf = Gltf::from_slice(TEXTURED.as_bytes()).unwrap();
+    let mut root = gltf.document.into_json();
+    let mut buffers = vec![buffer::Data(textured_data())];
+    assert_eq!(transform::narrow_indices(&mut root, &mut buffers), [2]);
+    assert_eq!(buffers[0].len(), 88);
+    assert_eq!(root.buffer_views[2].byte_length.0, 6);
+    assert_eq!(&buffers[0][60..66], [0, 0, 1, 0, 2, 0]);
+    let document = gltf::Document::from_json(root.clone()).unwrap();
+    let accessor = document.accessors().nth(2).unwrap();
+    assert_eq!(accessor.data_type(), gltf::accessor::DataType::U16);
+
+    // Indices that already fit or are also used as attributes are left alone.
+    assert!(transform::narrow_indices(&mut root, &mut buffers).is_empty());
+    let gltf = Gltf::from_slice(TEXTURED.as_bytes()).unwrap();
+    let mut root = gltf.document.into_json();
+    let semantic = Checked::Valid(gltf::json::mesh::Semantic::Weights(0));
+    let attributes = &mut root.meshes[1].primitives[0].attributes;
+    attributes.insert(semantic, gltf::json::Index::new(2));
+    assert!(transform::narrow_indices(&mut root, &mut buffers).is_empty());
+}
+
+mod run {
+    use super::*;
+    use gltf::pipeline::{self, Limits, Outcome, PipelineError, Step};
+    use gltf::transform::{DedupedTextures, EditError};
+
+    fn glb(json: &str, bin: &[u8]) -> Vec<u8> {
+        Glb {
+            header: Header {
+                magic: *b"glTF",
+                version: 2,
+                length: 0,
+            },
+            json: Cow::Borrowed(json.as_bytes()),
+            bin: Some(Cow::Borrowed(bin)),
+            extra_chunks: Vec::new(),
+            length_read: None,
+            chunk_alignment: gltf::ChunkAlignment::Exact,
+        }
+        .to_vec()
+        .unwrap()
+    }
+
+    const RECIPE: [Step; 6] = [
+        Step::Prune,
+        Step::DedupTextures,
+        Step::NarrowIndices,
+        Step::Quantize(QuantizeOptions {
+            position: None,
+            normal: None,
+            tangent: None,
+            tex_coord: Some(transform::Bits::Sixteen),
+            skip_out_of_range_tex_coords: true,
+        }),
+        Step::Prune,
+        Step::Canonicalize,
+    ];
+
+    #[test]
+    fn recipe() {
+        let input = glb(TEXTURED, &textured_data());
+        let (output, report) =
+            pipeline::run_with_report(&input, &RECIPE, &Limits::default()).unwrap();
+        assert_eq!(
+            output,
+            pipeline::run(&input, &RECIPE, &Limits::default()).unwrap()
+        );
+        assert_eq!(report.output_bytes, output.len());
+        assert_eq!(report.loaded_buffer_bytes, 88);
+        let names: Vec<_> = report.steps.iter().map(|step| step.step.name()).collect();
+        assert_eq!(
+            names,
+            [
+                "prune",
+                "dedup_textures",
+                "narrow_indices",
+                "quantize",
+                "prune",
+                "canonicalize"
+            ]
+        );
+        assert_eq!(
+            report.steps[1].outcome,
+            Outcome::DedupedTextures(DedupedTextures {
+                images: vec![0, 0],
+                textures: vec![0, 0],
+            })
+        );
+        assert_eq!(report.steps[2].outcome, Outcome::NarrowedIndices(vec![2]));
+        let peak = report.steps.iter().map(|step| step.buffer_bytes).max();
+        assert_eq!(Some(report.peak_buffer_bytes), peak);
+        // Positions, quantized texture co-ordinates, indices and one image.
+        assert_eq!(report.steps[5].buffer_bytes, 36 + 12 + 8 + 8);
+
+        let gltf = Gltf::from_slice_without_validation(&output).unwrap();
+        assert_eq!(
+            gltf.extensions_required().collect::<Vec<_>>(),
+            ["KHR_mesh_quantization"]
+        );
+        assert_eq!((gltf.images().len(), gltf.textures().len()), (1, 1));
+        assert_eq!(gltf.meshes().len(), 1);
+        let blob = gltf.blob.clone().unwrap();
+        let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+        let reader = primitive.reader(|_| Some(&blob[..]));
+        let indices: Vec<u32> = reader.read_indices().unwrap().into_u32().collect();
+        assert_eq!(indices, [0, 1, 2]);
+        assert_eq!(
+            primitive.indices().unwrap().data_type(),
+            gltf::accessor::DataType::U16
+        );
+        let tex_coords = primitive.get(&Semantic::TexCoords(0)).unwrap();
+        assert_eq!(tex_coords.data_type(), gltf::accessor::DataType::U16);
+    }
+
+    fn data_uri(data: &[u8]) -> String {
+        let mut uri = String::from("data:application/octet-stream,");
+        for byte in data {
+            uri.push_str(&format!("%{:02x}", byte));
+        }
+        uri
+    }
+
+    #[test]
+    fn buffers_are_merged() {
+        let mut data = textured_data();
+        let image = data.split_off(80);
+        let buffers = format!(
+            r#"[{{"byteLength": 80, "uri": "{}"}}, {{"byteLength": 8, "uri": "{}"}}]"#,
+            data_uri(&data),
+            data_uri(&image)
+        );
+        let json = TEXTURED
+            .replace(
+                r#"{"buffer": 0, "byteOffset": 80, "byteLength": 8}"#,
+                r#"{"buffer": 1, "byteLength": 8}"#,
+            )
+            .replace(r#"[{"byteLength": 88}]"#, &buffers);
+        let output = pipeline::run(json.as_bytes(), &[], &Limits::default()).unwrap();
+        let gltf = Gltf::from_slice(&output).unwrap();
+        assert_eq!(gltf.buffers().len(), 1);
+        assert!(gltf.as_json().buffers[0].uri.is_none());
+        assert_eq!(gltf.views().nth(4).unwrap().offset(), 80);
+        assert_eq!(gltf.blob.as_deref(), Some(&textured_data()[..]));
+    }
+
+    #[test]
+    fn limits() {
+        let input = glb(TEXTURED, &textured_data());
+        let limits = |max_input_bytes, max_buffer_bytes, max_output_bytes| Limits {
+            max_input_bytes,
+            max_buffer_bytes,
+            max_output_bytes,
+        };
+        assert!(matches!(
+            pipeline::run(&input, &RECIPE, &limits(100, usize::MAX, usize::MAX)),
+            Err(PipelineError::InputTooLarge { limit: 100, .. })
+        ));
+        assert!(matches!(
+            pipeline::run(&input, &RECIPE, &limits(usize::MAX, 80, usize::MAX)),
+            Err(PipelineError::BufferLimit {
+                after: None,
+                length: 88,
+                limit: 80
+            })
+        ));
+        // Quantizing appends the texture co-ordinates to the buffer.
+        let error = pipeline::run(&input, &RECIPE, &limits(usize::MAX, 88, usize::MAX));
+        assert!(matches!(
+            error,
+            Err(PipelineError::BufferLimit {
+                after: Some(3),
+                length: 100,
+                limit: 88
+            })
+        ));
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "buffer data of 100 bytes after step 3 exceeds the limit of 88 bytes"
+        );
+        assert!(matches!(
+            pipeline::run(&input, &RECIPE, &limits(usize::MAX, usize::MAX, 100)),
+            Err(PipelineError::OutputTooLarge { limit: 100, .. })
+        ));
+    }
+
+    #[test]
+    fn failed_step() {
+        let json = TEXTURED.replace(
+            r#"{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3","#,
+            r#"{"componentType": 5126, "count": 3, "type": "VEC3",
+                "sparse": {"count": 1, "indices": {"bufferView": 2, "componentType": 5125},
+                           "values": {"bufferView": 0}},"#,
+        );
+        let input = glb(&json, &textured_data());
+        let steps = [
+            Step::Prune,
+            Step::NormalizeScene {
+                scene: 0,
+                target: transform::NormalizeTarget {
+                    bake: true,
+                    ..Default::default()
+                },
+            },
+        ];
+        let error = pipeline::run(&input, &steps, &Limits::default()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "step 1 (normalize_scene) failed: accessor 0 is sparse or has no buffer view"
+        );
+        match error {
+            PipelineError::Step { index, step, error } => {
+                assert_eq!((index, step), (1, steps[1]));
+                assert_eq!(error, EditError::Sparse(0));
+            }
+            error => panic!("unexpected error: {}", error),
+        }
+        assert!(matches!(
+            pipeline::run(b"{}", &steps, &Limits::default()),
+            Err(PipelineError::Load(_))
+        ));
+    }
+}
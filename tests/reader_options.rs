@@ -0,0 +1,138 @@
+#![cfg(feature = "utils")]
+
+use gltf::accessor::{Iter, ReaderOptions};
+use gltf::Gltf;
+
+const DENORMAL: f32 = 1e-40;
+
+/// Two positions with non-finite and subnormal components, followed by one
+/// sparse substitution for the first of them.
+fn data() -> Vec<u8> {
+    let floats = [
+        f32::NAN,
+        1.0,
+        DENORMAL,
+        f32::INFINITY,
+        -DENORMAL,
+        2.0,
+        f32::NEG_INFINITY,
+        0.0,
+        3.0,
+    ];
+    let mut data: Vec<u8> = floats.iter().flat_map(|x| x.to_le_bytes()).collect();
+    data.extend_from_slice(&[0, 0, 0, 0]);
+    data
+}
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 40}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 24, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 4},
+        {"buffer": 0, "byteLength": 24, "byteStride": 12}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 1, 1]},
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+         "sparse": {"count": 1,
+                    "indices": {"bufferView": 2, "componentType": 5125},
+                    "values": {"bufferView": 1}}},
+        {"bufferView": 3, "componentType": 5126, "count": 2, "type": "SCALAR"}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}]}]
+}"#;
+
+const OPTIONS: ReaderOptions = ReaderOptions {
+    replace_non_finite: Some(0.5),
+    flush_denormals: true,
+};
+
+fn bits<const N: usize>(items: &[[f32; N]]) -> Vec<u32> {
+    items.iter().flatten().map(|x| x.to_bits()).collect()
+}
+
+#[test]
+fn primitive_reader() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let data = data();
+    let primitive = gltf.meshes().next().unwrap().primitives().next().unwrap();
+
+    let reader = primitive.reader(|_| Some(&data[..]));
+    let positions: Vec<_> = reader.read_positions().unwrap().collect();
+    assert!(positions[0][0].is_nan());
+    assert_eq!(positions[0][2], DENORMAL);
+    assert_eq!(reader.replaced(), 0);
+
+    let reader = primitive.reader_with(|_| Some(&data[..]), OPTIONS);
+    let positions: Vec<_> = reader.read_positions().unwrap().collect();
+    let expected = [[0.5, 1.0, 0.0], [0.5, -0.0, 2.0]];
+    assert_eq!(bits(&positions), bits(&expected));
+    assert_eq!(reader.replaced(), 4);
+
+    // The count accumulates over the iterators of the reader.
+    reader.read_positions().unwrap().nth(1);
+    assert_eq!(reader.replaced(), 6);
+}
+
+#[test]
+fn options_apply_separately() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let data = data();
+    let accessor = gltf.accessors().next().unwrap();
+
+    let options = ReaderOptions {
+        flush_denormals: false,
+        ..OPTIONS
+    };
+    let iter = Iter::<[f32; 3]>::new_with(accessor.clone(), |_| Some(&data[..]), options);
+    let positions: Vec<_> = iter.unwrap().collect();
+    let expected = [[0.5, 1.0, DENORMAL], [0.5, -DENORMAL, 2.0]];
+    assert_eq!(bits(&positions), bits(&expected));
+
+    let options = ReaderOptions {
+        replace_non_finite: None,
+        ..OPTIONS
+    };
+    let iter = Iter::<[f32; 3]>::new_with(accessor, |_| Some(&data[..]), options).unwrap();
+    let positions: Vec<_> = iter.clone().collect();
+    assert!(positions[0][0].is_nan() && positions[1][0].is_infinite());
+    assert_eq!([positions[0][2], positions[1][1]], [0.0, -0.0]);
+    assert_eq!(iter.replaced(), 2);
+}
+
+#[test]
+fn bulk_and_sparse_reads() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let data = data();
+    let expected = [[0.5, 1.0, 0.0], [0.5, -0.0, 2.0]];
+
+    // Tightly packed items are read in bulk.
+    let accessor = gltf.accessors().next().unwrap();
+    let iter = Iter::<[f32; 3]>::new_with(accessor, |_| Some(&data[..]), OPTIONS).unwrap();
+    let counter = iter.clone();
+    let mut positions = Vec::new();
+    iter.read_into(&mut positions);
+    assert_eq!(bits(&positions), bits(&expected));
+    assert_eq!(counter.replaced(), 4);
+
+    // Sparse values are sanitized, and skipped items are not counted.
+    let accessor = gltf.accessors().nth(1).unwrap();
+    let mut iter = Iter::<[f32; 3]>::new_with(accessor, |_| Some(&data[..]), OPTIONS).unwrap();
+    assert_eq!(bits(&[iter.next().unwrap()]), bits(&[[0.5, 0.0, 3.0]]));
+    assert_eq!(iter.replaced(), 1);
+    let iter = iter.slice(1..1).unwrap();
+    assert_eq!(iter.replaced(), 1);
+
+    // Strided items are read one by one.
+    let accessor = gltf.accessors().nth(2).unwrap();
+    let iter = Iter::<f32>::new_with(accessor, |_| Some(&data[..]), OPTIONS).unwrap();
+    let counter = iter.clone();
+    let mut values = Vec::new();
+    iter.read_into(&mut values);
+    assert_eq!(values.len(), 2);
+    assert!(values[0] == 0.5 && values[1] == 0.5);
+    assert_eq!(counter.replaced(), 2);
+}
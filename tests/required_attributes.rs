@@ -0,0 +1,116 @@
+use gltf::diagnostic::Severity;
+use gltf::mesh::Semantic;
+use gltf::{Gltf, Warning};
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 1, 1]},
+        {"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC2"}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 24}
+    ],
+    "buffers": [{"byteLength": 60, "uri": "data.bin"}],
+    "images": [{"uri": "texture.png"}],
+    "textures": [{"source": 0}],
+    "materials": [
+        {
+            "pbrMetallicRoughness": {"baseColorTexture": {"index": 0}},
+            "normalTexture": {"index": 0, "texCoord": 1},
+            "occlusionTexture": {"index": 0, "texCoord": 1}
+        },
+        {
+            "pbrMetallicRoughness": {"baseColorTexture": {"index": 0}},
+            "normalTexture": {"index": 0, "texCoord": 1},
+            "extensions": {"KHR_materials_unlit": {}}
+        },
+        {
+            "emissiveTexture": {
+                "index": 0,
+                "extensions": {"KHR_texture_transform": {"texCoord": 2}}
+            }
+        },
+        {
+            "normalTexture": {
+                "index": 0,
+                "extensions": {"KHR_texture_transform": {"texCoord": 3}}
+            },
+            "occlusionTexture": {
+                "index": 0,
+                "extensions": {"KHR_texture_transform": {"texCoord": 4}}
+            }
+        }
+    ],
+    "meshes": [
+        {"primitives": [
+            {"attributes": {"POSITION": 0}},
+            {"attributes": {"POSITION": 0, "TEXCOORD_0": 1}, "material": 0},
+            {"attributes": {"POSITION": 0, "TEXCOORD_0": 1}, "material": 1}
+        ]}
+    ],
+    "extensionsUsed": ["KHR_materials_unlit", "KHR_texture_transform"]
+}"#;
+
+#[test]
+fn required_attributes() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let required = gltf.materials().next().unwrap().required_attributes();
+    assert!(required.normal && required.tangent);
+    assert_eq!(required.tex_coords, [0, 1]);
+}
+
+#[cfg(feature = "KHR_materials_unlit")]
+#[test]
+fn unlit_materials_only_sample_base_color() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let required = gltf.materials().nth(1).unwrap().required_attributes();
+    assert_eq!(required.semantics(), [Semantic::TexCoords(0)]);
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+#[test]
+fn texture_transform_overrides_tex_coord() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let required = gltf.materials().nth(2).unwrap().required_attributes();
+    assert_eq!(required.tex_coords, [2]);
+    let required = gltf.materials().nth(3).unwrap().required_attributes();
+    assert_eq!(required.tex_coords, [3, 4]);
+}
+
+#[test]
+fn missing_attributes() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let mesh = gltf.meshes().next().unwrap();
+    let missing: Vec<_> = mesh
+        .primitives()
+        .map(|primitive| primitive.missing_attributes(&primitive.material()))
+        .collect();
+    assert!(missing[0].is_empty());
+    assert_eq!(
+        missing[1],
+        [
+            Semantic::Normals,
+            Semantic::Tangents,
+            Semantic::TexCoords(1)
+        ]
+    );
+
+    let warning = Warning::MissingAttributes {
+        mesh: 0,
+        primitive: 1,
+        semantics: vec![Semantic::Normals, Semantic::TexCoords(1)],
+    };
+    let generated = Warning::GeneratedTangents {
+        mesh: 0,
+        primitive: 1,
+    };
+    assert_eq!(gltf.warnings()[..2], [generated.clone(), warning.clone()]);
+    assert_eq!(
+        warning.to_string(),
+        "primitive 1 of mesh 0 lacks attributes read by its material: NORMAL, TEXCOORD_1"
+    );
+    assert_eq!(generated.code().severity(), Severity::Info);
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "import")]
+
+use gltf::{Error, Gltf};
+
+/// A primitive whose positions are compressed with `KHR_draco_mesh_compression`,
+/// with uncompressed zeros as a fallback.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["KHR_draco_mesh_compression", "EXT_unknown"],
+    "extensionsRequired": ["KHR_draco_mesh_compression"],
+    "buffers": [{"byteLength": 36, "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA"}],
+    "bufferViews": [{"buffer": 0, "byteLength": 36}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]}
+    ],
+    "meshes": [{"primitives": [{
+        "attributes": {"POSITION": 0},
+        "extensions": {"KHR_draco_mesh_compression": {"bufferView": 0, "attributes": {"POSITION": 0}}}
+    }]}]
+}"#;
+
+#[test]
+fn required_extensions_are_checked() {
+    let document = Gltf::from_slice_without_validation(GLTF.as_bytes())
+        .unwrap()
+        .document;
+    let used: Vec<_> = document.extensions_used().collect();
+    assert_eq!(used, ["KHR_draco_mesh_compression", "EXT_unknown"]);
+    let required: Vec<_> = document.extensions_required().collect();
+    assert_eq!(required, ["KHR_draco_mesh_compression"]);
+
+    let error = document
+        .check_required_extensions(gltf::json::extensions::SUPPORTED)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unsupported required extensions: KHR_draco_mesh_compression"
+    );
+    match error {
+        Error::UnsupportedExtensions(names) => assert_eq!(names, ["KHR_draco_mesh_compression"]),
+        error => panic!("unexpected error: {}", error),
+    }
+    assert!(document
+        .check_required_extensions(&["KHR_draco_mesh_compression"])
+        .is_ok());
+}
+
+#[test]
+fn imports_fail_fast_on_unsupported_extensions() {
+    match gltf::import_slice_with_extensions(GLTF, gltf::json::extensions::SUPPORTED) {
+        Err(Error::UnsupportedExtensions(names)) => {
+            assert_eq!(names, ["KHR_draco_mesh_compression"])
+        }
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+    assert!(matches!(
+        gltf::import_slice(GLTF),
+        Err(Error::Validation(_))
+    ));
+
+    let (document, buffers) =
+        gltf::import_slice_with_extensions(GLTF, &["KHR_draco_mesh_compression"]).unwrap();
+    assert_eq!(document.meshes().count(), 1);
+    assert_eq!(buffers[0].len(), 36);
+
+    // Other validation errors are still reported.
+    let invalid = GLTF.replace(r#"{"POSITION": 0},"#, r#"{"POSITION": 1},"#);
+    assert!(matches!(
+        gltf::import_slice_with_extensions(invalid, &["KHR_draco_mesh_compression"]),
+        Err(Error::Validation(_))
+    ));
+}
@@ -0,0 +1,273 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use gltf::accessor::{self, Iter};
+use gltf::fault::{EntityKind, FaultError, ImportFault};
+use gltf::{buffer, Document};
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [
+        {"byteLength": 24,
+         "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAEAAAEBA"},
+        {"byteLength": 64,
+         "uri": "data:application/octet-stream;base64,AAAAAAAAAAA="},
+        {"byteLength": 8,
+         "uri": "data:application/octet-stream;base64,!!!!"}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 24},
+        {"buffer": 1, "byteLength": 24},
+        {"buffer": 2, "byteLength": 8}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 2, 3]},
+        {"bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC3",
+         "min": [0, 0, 0], "max": [0, 0, 0]},
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+         "sparse": {"count": 1,
+                    "indices": {"bufferView": 0, "componentType": 5125},
+                    "values": {"bufferView": 2}}},
+        {"bufferView": 0, "componentType": 9999, "count": 6, "type": "SCALAR"}
+    ],
+    "meshes": [
+        {"primitives": [{"attributes": {"POSITION": 0}}]},
+        {"primitives": [{"attributes": {"POSITION": 0}},
+                        {"attributes": {"POSITION": 1}}]},
+        {"primitives": [{"attributes": {"POSITION": 0}, "indices": 3}]},
+        {"primitives": [{"attributes": {"POSITION": 99}}]}
+    ],
+    "skins": [
+        {"joints": [0], "inverseBindMatrices": 2}
+    ],
+    "nodes": [{"mesh": 0}]
+}"#;
+
+fn find(faults: &[ImportFault], entity_kind: EntityKind, index: usize) -> &FaultError {
+    let fault = faults
+        .iter()
+        .find(|fault| (fault.entity_kind, fault.index) == (entity_kind, index));
+    &fault
+        .unwrap_or_else(|| panic!("{:?} {} is not faulted", entity_kind, index))
+        .error
+}
+
+fn positions(
+    document: &Document,
+    buffers: &[buffer::Data],
+    mesh: usize,
+    primitive: usize,
+) -> Option<Vec<[f32; 3]>> {
+    let mesh = document.meshes().nth(mesh).unwrap();
+    let primitive = mesh.primitives().nth(primitive).unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()].0[..]));
+    reader.read_positions().map(|iter| iter.collect())
+}
+
+#[test]
+fn faults_are_collected() {
+    let (_, buffers, faults) = gltf::import_slice_resilient(GLTF);
+    assert!(matches!(
+        find(&faults, EntityKind::Buffer, 1),
+        FaultError::Import(gltf::Error::BufferLength {
+            buffer: 1,
+            expected: 64,
+            actual: 8
+        })
+    ));
+    assert!(matches!(
+        find(&faults, EntityKind::Buffer, 2),
+        FaultError::Import(gltf::Error::MalformedBase64 { buffer: 2, .. })
+    ));
+    assert!(matches!(
+        find(&faults, EntityKind::Accessor, 3),
+        FaultError::Validation(..)
+    ));
+    assert!(matches!(
+        find(&faults, EntityKind::Primitive { mesh: 3 }, 0),
+        FaultError::Validation(..)
+    ));
+    assert_eq!(buffers.len(), 3);
+    assert!(buffers[1].is_empty() && buffers[2].is_empty());
+}
+
+#[test]
+#[cfg(feature = "skinning")]
+fn faults_poison_dependents() {
+    use EntityKind::*;
+    let (document, _, faults) = gltf::import_slice_resilient(GLTF);
+    let poisoned = |entity_kind, index| match *find(&faults, entity_kind, index) {
+        FaultError::Poisoned { entity_kind, index } => (entity_kind, index),
+        ref error => panic!("{:?}", error),
+    };
+    assert_eq!(poisoned(View, 1), (Buffer, 1));
+    assert_eq!(poisoned(View, 2), (Buffer, 2));
+    assert_eq!(poisoned(Accessor, 1), (View, 1));
+    assert_eq!(poisoned(Accessor, 2), (View, 2));
+    assert_eq!(poisoned(Primitive { mesh: 1 }, 1), (Accessor, 1));
+    assert_eq!(poisoned(Primitive { mesh: 2 }, 0), (Accessor, 3));
+    assert_eq!(poisoned(Skin, 0), (Accessor, 2));
+    for (entity_kind, index) in [(View, 1), (Accessor, 2), (Primitive { mesh: 3 }, 0)] {
+        assert!(document.is_faulted(entity_kind, index));
+    }
+    assert_eq!(faults.len(), 11);
+}
+
+#[test]
+fn independent_entities_remain_readable() {
+    use EntityKind::*;
+    let (document, buffers, faults) = gltf::import_slice_resilient(GLTF);
+    for (entity_kind, index) in [
+        (Buffer, 0),
+        (View, 0),
+        (Accessor, 0),
+        (Mesh, 1),
+        (Primitive { mesh: 1 }, 0),
+    ] {
+        assert!(!document.is_faulted(entity_kind, index));
+        assert!(!faults
+            .iter()
+            .any(|x| (x.entity_kind, x.index) == (entity_kind, index)));
+    }
+    let expected = [[0.0, 0.0, 0.0], [1.0, 2.0, 3.0]];
+    assert_eq!(positions(&document, &buffers, 0, 0).unwrap(), expected);
+    assert_eq!(positions(&document, &buffers, 1, 0).unwrap(), expected);
+    assert_eq!(positions(&document, &buffers, 1, 1), None);
+    assert_eq!(positions(&document, &buffers, 3, 0), None);
+
+    let get = |buffer: gltf::Buffer| Some(&buffers[buffer.index()].0[..]);
+    let accessor = |index| document.accessors().nth(index).unwrap();
+    assert!(Iter::<[f32; 3]>::try_new(accessor(0), get)
+        .unwrap()
+        .is_some());
+    assert!(matches!(
+        Iter::<[f32; 3]>::try_new(accessor(1), get),
+        Err(accessor::Error::Faulted { accessor: 1 })
+    ));
+    assert!(matches!(
+        accessor(2).check_data(get),
+        Err(accessor::Error::Faulted { accessor: 2 })
+    ));
+}
+
+#[test]
+fn unparsable_input() {
+    let (document, buffers, faults) = gltf::import_slice_resilient(b"{\"asset\": ");
+    assert_eq!(document.meshes().count(), 0);
+    assert!(buffers.is_empty());
+    assert_eq!(faults.len(), 1);
+    assert_eq!(faults[0].entity_kind, EntityKind::Root);
+    assert!(matches!(
+        faults[0].error,
+        FaultError::Import(gltf::Error::Deserialize(_))
+    ));
+}
+
+/// Materials using a valid texture, a texture whose image does not exist and
+/// a normal texture that does not exist.
+const MATERIALS: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [
+        {"byteLength": 24,
+         "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAEAAAEBA"}
+    ],
+    "bufferViews": [{"buffer": 0, "byteLength": 24}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 2, 3]}
+    ],
+    "images": [{"uri": "data:image/png;base64,iVBORw0KGgo="}],
+    "textures": [{"source": 0}, {"source": 7}],
+    "materials": [
+        {"pbrMetallicRoughness": {"baseColorTexture": {"index": 0}}},
+        {"pbrMetallicRoughness": {"baseColorTexture": {"index": 1}}},
+        {"normalTexture": {"index": 9}}
+    ],
+    "meshes": [
+        {"primitives": [{"attributes": {"POSITION": 0}, "material": 0},
+                        {"attributes": {"POSITION": 0}, "material": 1},
+                        {"attributes": {"POSITION": 0}, "material": 2}]}
+    ]
+}"#;
+
+fn faulted<T>(result: gltf::Result<T>) -> (EntityKind, usize) {
+    match result {
+        Err(gltf::Error::Faulted { entity_kind, index }) => (entity_kind, index),
+        Err(error) => panic!("{:?}", error),
+        Ok(_) => panic!("not faulted"),
+    }
+}
+
+#[test]
+fn faulted_primitives_fail_lookups() {
+    use gltf::Semantic;
+    let (document, buffers, _) = gltf::import_slice_resilient(GLTF);
+    for mesh in document.meshes() {
+        for primitive in mesh.primitives() {
+            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()].0[..]));
+            if !primitive.is_faulted() {
+                assert!(primitive.try_get(&Semantic::Positions).unwrap().is_some());
+                assert!(primitive.try_material().unwrap().index().is_none());
+                assert!(reader.try_read_positions().unwrap().is_some());
+                continue;
+            }
+            let expected = (
+                EntityKind::Primitive { mesh: mesh.index() },
+                primitive.index(),
+            );
+            assert_eq!(faulted(primitive.try_get(&Semantic::Positions)), expected);
+            assert_eq!(faulted(primitive.try_indices()), expected);
+            assert_eq!(faulted(primitive.try_attributes()), expected);
+            assert_eq!(faulted(primitive.try_morph_targets()), expected);
+            assert_eq!(faulted(primitive.try_bounding_box()), expected);
+            assert_eq!(faulted(primitive.try_material()), expected);
+            assert_eq!(primitive.compute_bounding_box(&buffers), None);
+
+            let error = accessor::Error::FaultedPrimitive {
+                mesh: mesh.index(),
+                primitive: primitive.index(),
+            };
+            assert_eq!(reader.try_read_positions().err(), Some(error));
+            assert_eq!(reader.try_read_normals().err(), Some(error));
+            assert_eq!(reader.try_read_tex_coords(0).err(), Some(error));
+            assert_eq!(reader.try_read_indices().err(), Some(error));
+            assert_eq!(reader.read_or_generate_indices().err(), Some(error));
+            assert_eq!(primitive.vertex_count(), Err(error));
+            assert!(reader.read_positions().is_none());
+        }
+    }
+    let mesh = document.meshes().nth(3).unwrap();
+    assert!(mesh.primitives().next().unwrap().is_faulted());
+}
+
+#[test]
+fn faulted_materials_fail_lookups() {
+    use EntityKind::*;
+    let (document, _, faults) = gltf::import_slice_resilient(MATERIALS);
+    assert!(matches!(
+        find(&faults, Texture, 1),
+        FaultError::Validation(..)
+    ));
+    assert!(matches!(
+        find(&faults, Material, 1),
+        FaultError::Poisoned {
+            entity_kind: Texture,
+            index: 1
+        }
+    ));
+    assert!(matches!(
+        find(&faults, Material, 2),
+        FaultError::Validation(..)
+    ));
+    assert!(!document.is_faulted(Material, 0));
+
+    let mesh = document.meshes().next().unwrap();
+    let primitives: Vec<_> = mesh.primitives().collect();
+    assert!(primitives.iter().all(|primitive| !primitive.is_faulted()));
+    let material = primitives[0].try_material().unwrap();
+    let texture = material.pbr_metallic_roughness().base_color_texture();
+    let image = texture.unwrap().texture().source();
+    assert_eq!(image.map(|image| image.index()), Some(0));
+    assert_eq!(faulted(primitives[1].try_material()), (Material, 1));
+    assert_eq!(faulted(primitives[2].try_material()), (Material, 2));
+}
@@ -0,0 +1,70 @@
+#![allow(clippy::single_range_in_vec_init)]
+
+use std::fs;
+
+use gltf::analysis::RetentionPolicy;
+use gltf::buffer::{self, RangeSet, RetainedData};
+
+fn load() -> (gltf::Document, RetainedData) {
+    let gltf = gltf::Gltf::from_slice(&fs::read("tests/box_sparse.gltf").unwrap()).unwrap();
+    let data = buffer::Data(fs::read("tests/box_sparse.bin").unwrap());
+    (gltf.document, RetainedData::from(data))
+}
+
+#[test]
+#[cfg(feature = "animation")]
+fn animation_data_is_retained() {
+    let (document, mut data) = load();
+    let ranges = document.cpu_required_ranges(RetentionPolicy::default());
+    // The sampler input and its sparse output indices and values adjoin.
+    assert_eq!(ranges.get(0), [240..256]);
+
+    let bytes = fs::read("tests/box_sparse.bin").unwrap();
+    assert_eq!(data.retain_ranges(ranges.get(0)), 352 - 16);
+    assert_eq!((data.len(), data.retained()), (352, 16));
+
+    let input = document.views().nth(4).unwrap();
+    assert_eq!(data.view(&input).unwrap(), &bytes[248..256]);
+    assert_eq!(data.get(242..250).unwrap(), &bytes[242..250]);
+
+    let positions = document.views().nth(1).unwrap();
+    let error = data.view(&positions).unwrap_err();
+    assert_eq!(error.range, 144..240);
+    assert!(data.get(250..257).is_err());
+}
+
+#[test]
+fn morph_targets_are_opt_in() {
+    let (document, mut data) = load();
+    let policy = RetentionPolicy {
+        animations: false,
+        morph_targets: true,
+        ..Default::default()
+    };
+    let ranges = document.cpu_required_ranges(policy);
+    assert_eq!(ranges.get(0), [256..352]);
+    assert_eq!(ranges.byte_count(0), 96);
+    assert_eq!(data.retain_ranges(ranges.get(0)), 256);
+
+    // Narrowing further never restores dropped bytes.
+    assert_eq!(data.retain_ranges(&[0..300]), 52);
+    assert_eq!(data.retained(), 44);
+    assert!(data.get(0..4).is_err());
+}
+
+#[test]
+fn range_sets_merge() {
+    let mut ranges = RangeSet::new();
+    ranges.insert(1, 10..20);
+    ranges.insert(1, 30..40);
+    ranges.insert(1, 0..5);
+    ranges.insert(1, 20..25);
+    ranges.insert(1, 4..4);
+    assert_eq!(ranges.get(0), []);
+    assert_eq!(ranges.get(1), [0..5, 10..25, 30..40]);
+    ranges.insert(1, 5..35);
+    assert_eq!(ranges.get(1), [0..40]);
+    assert!(ranges.contains(1, 3..38));
+    assert!(!ranges.contains(1, 38..41));
+    assert!(!ranges.contains(2, 0..1));
+}
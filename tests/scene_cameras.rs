@@ -0,0 +1,117 @@
+#![cfg(feature = "cameras")]
+
+use gltf::camera::view_matrix;
+use gltf::Gltf;
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "scene": 0,
+    "scenes": [{"nodes": [0, 3]}, {"nodes": [4]}],
+    "cameras": [
+        {"type": "perspective", "perspective": {"yfov": 1.0, "znear": 0.1}},
+        {"type": "orthographic",
+         "orthographic": {"xmag": 1, "ymag": 1, "znear": 0.1, "zfar": 10}}
+    ],
+    "nodes": [
+        {"translation": [0, 0, 5], "children": [1, 2]},
+        {"camera": 0, "translation": [1, 0, 0]},
+        {"camera": 1, "rotation": [0, 0.70710677, 0, 0.70710677]},
+        {"camera": 0, "scale": [2, 2, 2]},
+        {"name": "empty"}
+    ]
+}"#;
+
+fn multiply(a: [[f32; 4]; 4], b: [[f32; 4]; 4]) -> [[f32; 4]; 4] {
+    let mut product = [[0.0; 4]; 4];
+    for (column, b) in b.iter().enumerate() {
+        for (row, product) in product[column].iter_mut().enumerate() {
+            *product = (0..4).map(|k| a[k][row] * b[k]).sum();
+        }
+    }
+    product
+}
+
+fn assert_identity(matrix: [[f32; 4]; 4]) {
+    for (column, values) in matrix.iter().enumerate() {
+        for (row, &value) in values.iter().enumerate() {
+            let expected = if row == column { 1.0 } else { 0.0 };
+            assert!(
+                (value - expected).abs() < 1e-5,
+                "not an identity: {:?}",
+                matrix
+            );
+        }
+    }
+}
+
+#[test]
+fn cameras_in_scene() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let scene = gltf.default_scene().unwrap();
+    let cameras: Vec<_> = gltf
+        .cameras_in_scene(scene.index())
+        .map(|(node, camera, world)| (node.index(), camera.index(), world))
+        .collect();
+
+    // Camera 0 is instantiated by two nodes.
+    let indices: Vec<_> = cameras.iter().map(|&(n, c, _)| (n, c)).collect();
+    assert_eq!(indices, [(1, 0), (2, 1), (3, 0)]);
+    assert_eq!(cameras[0].2[3], [1.0, 0.0, 5.0, 1.0]);
+    assert_eq!(cameras[1].2[3], [0.0, 0.0, 5.0, 1.0]);
+    assert_eq!(cameras[2].2[0], [2.0, 0.0, 0.0, 0.0]);
+
+    let (node, camera, world) = scene.first_camera().unwrap();
+    assert_eq!((node.index(), camera.index()), (1, 0));
+    assert_eq!(world, cameras[0].2);
+
+    let empty = gltf.scenes().nth(1).unwrap();
+    assert_eq!(gltf.cameras_in_scene(empty.index()).count(), 0);
+    assert!(empty.first_camera().is_none());
+    assert_eq!(gltf.cameras_in_scene(2).count(), 0);
+}
+
+#[test]
+fn no_cameras() {
+    let json = r#"{"asset": {"version": "2.0"}, "scenes": [{"nodes": [0]}],
+        "nodes": [{"children": [1]}, {}]}"#;
+    let gltf = Gltf::from_slice(json.as_bytes()).unwrap();
+    let scene = gltf.scenes().next().unwrap();
+    assert_eq!(gltf.cameras_in_scene(scene.index()).count(), 0);
+    assert!(scene.first_camera().is_none());
+}
+
+#[test]
+fn view_matrices() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let scene = gltf.default_scene().unwrap();
+    for (_, _, world) in gltf.cameras_in_scene(scene.index()) {
+        assert_identity(multiply(view_matrix(world), world));
+    }
+
+    // Non-uniform scale under a rotation shears the world transform.
+    let c = std::f32::consts::FRAC_1_SQRT_2;
+    let rotation = [
+        [c, c, 0.0, 0.0],
+        [-c, c, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [3.0, -2.0, 1.0, 1.0],
+    ];
+    let scale = [
+        [4.0, 0.0, 0.0, 0.0],
+        [0.0, 0.5, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+    let sheared = multiply(scale, multiply(rotation, scale));
+    assert_identity(multiply(view_matrix(sheared), sheared));
+
+    // A zero scale drops the collapsed axis instead of producing NaNs.
+    let mut collapsed = rotation;
+    collapsed[2] = [0.0; 4];
+    let view = view_matrix(collapsed);
+    assert!(view.iter().flatten().all(|value| value.is_finite()));
+    assert_eq!(view[0][2], 0.0);
+    assert_eq!(view[1][2], 0.0);
+    let in_view = multiply(view, collapsed);
+    assert!((in_view[0][0] - 1.0).abs() < 1e-6 && (in_view[1][1] - 1.0).abs() < 1e-6);
+}
@@ -0,0 +1,223 @@
+#![cfg(all(feature = "import", feature = "utils", feature = "skinning"))]
+
+use approx::assert_relative_eq;
+use gltf::skin::{self, PoseOptions, SkinError};
+
+/// A chain of two joints, bound along the y axis, with the second joint bent
+/// by 90 degrees around z. The vertices at the joints are weighted to the
+/// first joint, halfway between both, and to the second joint.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "scene": 0,
+    "scenes": [{"nodes": [0, 1]}],
+    "nodes": [
+        {"mesh": 0, "skin": 0},
+        {"children": [2]},
+        {"translation": [0, 1, 0], "rotation": [0, 0, 0.70710678, 0.70710678]}
+    ],
+    "skins": [{"joints": [1, 2], "inverseBindMatrices": 4}],
+    "meshes": [{"primitives": [{"attributes": {
+        "POSITION": 0, "NORMAL": 1, "JOINTS_0": 2, "WEIGHTS_0": 3
+    }}]}],
+    "buffers": [{"byteLength": 260, "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAAAAAAAAgD8AAAAAAAAAAAAAAEAAAAAAAACAPwAAAAAAAAAAAACAPwAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAABAAABAAAAAACAPwAAAAAAAAAAAAAAAAAAAD8AAAA/AAAAAAAAAAAAAIA/AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAAAAAAIA/AAAAAAAAAAAAAAAAAAAAAAAAgD8AAAAAAAAAAAAAAAAAAAAAAACAPwAAgD8AAAAAAAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAAAAAAIA/AAAAAAAAAAAAAIC/AAAAAAAAgD8="}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 72, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 84, "byteLength": 48},
+        {"buffer": 0, "byteOffset": 132, "byteLength": 128}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0, 0], "max": [0, 2, 0]},
+        {"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3"},
+        {"bufferView": 2, "componentType": 5121, "count": 3, "type": "VEC4"},
+        {"bufferView": 3, "componentType": 5126, "count": 3, "type": "VEC4"},
+        {"bufferView": 4, "componentType": 5126, "count": 2, "type": "MAT4"}
+    ]
+}"#;
+
+type Matrix = [[f32; 4]; 4];
+
+const IDENTITY: Matrix = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
+
+fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut m = [[0.0; 4]; 4];
+    for (c, column) in m.iter_mut().enumerate() {
+        for (r, x) in column.iter_mut().enumerate() {
+            *x = (0..4).map(|k| a[k][r] * b[c][k]).sum();
+        }
+    }
+    m
+}
+
+/// Returns the world transform of each node.
+fn world_transforms(document: &gltf::Document) -> Vec<Matrix> {
+    let mut transforms = vec![[[0.0; 4]; 4]; document.nodes().len()];
+    let mut stack: Vec<_> = document
+        .default_scene()
+        .unwrap()
+        .nodes()
+        .map(|node| (node, IDENTITY))
+        .collect();
+    while let Some((node, parent)) = stack.pop() {
+        let world = multiply(&parent, &node.transform().matrix());
+        transforms[node.index()] = world;
+        stack.extend(node.children().map(|child| (child, world)));
+    }
+    transforms
+}
+
+fn assert_vectors(actual: &[[f32; 3]], expected: &[[f32; 3]]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        for (a, e) in a.iter().zip(e) {
+            assert_relative_eq!(a, e, epsilon = 1e-6);
+        }
+    }
+}
+
+#[test]
+fn bent_chain() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+    let node = document.nodes().next().unwrap();
+    let skin = node.skin().unwrap();
+    let skin_reader = skin.reader(get_buffer_data);
+    let mesh = node.mesh().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(get_buffer_data);
+
+    let joint_matrices = skin::joint_matrices(&skin_reader, &world_transforms(&document)).unwrap();
+    let options = PoseOptions { normals: true };
+    let skinned = skin::apply_pose_with(&reader, &skin_reader, &joint_matrices, options).unwrap();
+    assert_vectors(
+        &skinned.positions,
+        &[[0.0, 0.0, 0.0], [0.0, 1.0, 0.0], [-1.0, 1.0, 0.0]],
+    );
+    let half = 0.5f32.sqrt();
+    assert_vectors(
+        skinned.normals.as_ref().unwrap(),
+        &[[1.0, 0.0, 0.0], [half, half, 0.0], [0.0, 1.0, 0.0]],
+    );
+    assert_eq!(
+        skin::apply_pose(&reader, &skin_reader, &joint_matrices).unwrap(),
+        skinned.positions
+    );
+}
+
+#[test]
+fn invalid_joints() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+    let node = document.nodes().next().unwrap();
+    let skin = node.skin().unwrap();
+    let skin_reader = skin.reader(get_buffer_data);
+    let mesh = node.mesh().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(get_buffer_data);
+
+    assert_eq!(
+        skin::apply_pose(&reader, &skin_reader, &[IDENTITY]),
+        Err(SkinError::JointCount {
+            expected: 2,
+            actual: 1
+        })
+    );
+    assert_eq!(
+        skin::joint_matrices(&skin_reader, &[IDENTITY; 2]),
+        Err(SkinError::NodeTransform { node: 2 })
+    );
+}
+
+#[test]
+fn joint_matrices_of_the_bent_chain() {
+    let gltf = GLTF.replace(
+        r#"{"mesh": 0, "skin": 0}"#,
+        r#"{"mesh": 0, "skin": 0, "translation": [3, 0, 0]}"#,
+    );
+    let (document, buffers) = gltf::import_slice(gltf).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+    let skin = document.skins().next().unwrap();
+    let skin_reader = skin.reader(get_buffer_data);
+    let world_transforms = world_transforms(&document);
+
+    // The second joint turns a quarter about z around the point (0, 1, 0).
+    let bent = [
+        [0.0, 1.0, 0.0, 0.0],
+        [-1.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [1.0, 1.0, 0.0, 1.0],
+    ];
+    let joint_matrices = skin::joint_matrices(&skin_reader, &world_transforms).unwrap();
+    assert_eq!(joint_matrices.len(), 2);
+    assert_eq!(joint_matrices[0], IDENTITY);
+    for (a, e) in joint_matrices[1]
+        .iter()
+        .flatten()
+        .zip(bent.iter().flatten())
+    {
+        assert_relative_eq!(a, e, epsilon = 1e-6);
+    }
+
+    let mut inverse = IDENTITY;
+    inverse[3] = [-3.0, 0.0, 0.0, 1.0];
+    let in_node_space =
+        skin::joint_matrices_in_node_space(&skin_reader, &world_transforms, 0).unwrap();
+    for (a, e) in in_node_space.iter().zip(&joint_matrices) {
+        assert_eq!(*a, multiply(&inverse, e));
+    }
+    assert_eq!(
+        skin::joint_matrices_in_node_space(&skin_reader, &world_transforms, 3),
+        Err(SkinError::NodeTransform { node: 3 })
+    );
+    let mut singular = world_transforms.clone();
+    singular[0] = [[0.0; 4]; 4];
+    assert_eq!(
+        skin::joint_matrices_in_node_space(&skin_reader, &singular, 0),
+        Err(SkinError::SingularTransform { node: 0 })
+    );
+}
+
+#[test]
+fn inverse_bind_matrices_default_to_identity() {
+    let gltf = GLTF.replace(r#", "inverseBindMatrices": 4"#, "");
+    let (document, buffers) = gltf::import_slice(gltf).unwrap();
+    let skin = document.skins().next().unwrap();
+    let skin_reader = skin.reader(|buffer| buffers.get(buffer.index()).map(|x| &*x.0));
+    assert!(skin_reader.read_inverse_bind_matrices().is_none());
+    let world_transforms = world_transforms(&document);
+    assert_eq!(
+        skin::joint_matrices(&skin_reader, &world_transforms).unwrap(),
+        world_transforms[1..]
+    );
+}
+
+#[test]
+fn too_few_inverse_bind_matrices() {
+    let gltf = GLTF.replace(
+        r#""count": 2, "type": "MAT4""#,
+        r#""count": 1, "type": "MAT4""#,
+    );
+    let (document, buffers) = gltf::import_slice(gltf).unwrap();
+    let skin = document.skins().next().unwrap();
+    let skin_reader = skin.reader(|buffer| buffers.get(buffer.index()).map(|x| &*x.0));
+    assert_eq!(skin_reader.read_inverse_bind_matrices().unwrap().len(), 1);
+    let error = skin::joint_matrices(&skin_reader, &world_transforms(&document)).unwrap_err();
+    assert_eq!(
+        error,
+        SkinError::InverseBindMatrixCount {
+            expected: 2,
+            actual: 1
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "1 inverse bind matrices given for a skin of 2 joints"
+    );
+}
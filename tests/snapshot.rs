@@ -0,0 +1,117 @@
+#![cfg(feature = "animation")]
+
+use gltf::buffer;
+use gltf::material::AlphaMode;
+use gltf::snapshot::{OwnedScene, SnapshotOptions};
+use gltf::texture::WrappingMode;
+use gltf::{Gltf, Semantic};
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "scene": 0,
+    "scenes": [{"nodes": [0]}],
+    "nodes": [
+        {"name": "parent", "translation": [1, 0, 0], "children": [1]},
+        {"translation": [0, 2, 0], "mesh": 0}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0}, "material": 0}]}],
+    "materials": [{
+        "alphaMode": "MASK",
+        "pbrMetallicRoughness": {"baseColorTexture": {"index": 0, "texCoord": 1}},
+        "occlusionTexture": {"index": 0, "strength": 0.5}
+    }],
+    "textures": [{"source": 0, "sampler": 0}],
+    "samplers": [{"wrapS": 33071}],
+    "images": [{"uri": "image.png"}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3",
+         "min": [-1, -1, -1], "max": [1, 1, 1]},
+        {"bufferView": 1, "componentType": 5126, "count": 2, "type": "SCALAR",
+         "min": [0], "max": [2]},
+        {"bufferView": 2, "componentType": 5126, "count": 2, "type": "VEC3"}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 12},
+        {"buffer": 0, "byteOffset": 12, "byteLength": 8},
+        {"buffer": 0, "byteOffset": 20, "byteLength": 24}
+    ],
+    "buffers": [{"byteLength": 44}],
+    "animations": [{
+        "samplers": [{"input": 1, "output": 2}],
+        "channels": [{"sampler": 0, "target": {"node": 1, "path": "translation"}}]
+    }]
+}"#;
+
+fn buffers() -> Vec<buffer::Data> {
+    let floats = [0.0f32, 0.0, 0.0, 0.0, 2.0, 0.0, 2.0, 0.0, 1.0, 2.0, 3.0];
+    let data = floats.iter().flat_map(|x| x.to_le_bytes()).collect();
+    vec![buffer::Data(data)]
+}
+
+/// Takes a snapshot that outlives the document.
+fn snapshot(options: SnapshotOptions) -> OwnedScene {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    gltf.snapshot_with(&buffers(), options).unwrap()
+}
+
+fn is_static<T: 'static>(_: &T) {}
+
+#[test]
+fn owned_copies() {
+    let scene = snapshot(SnapshotOptions::default());
+    is_static(&scene);
+    assert_eq!(scene.roots, [0]);
+
+    let child = &scene.nodes[1];
+    assert_eq!(child.parent, Some(0));
+    assert_eq!(child.translation, [0.0, 2.0, 0.0]);
+    assert_eq!(child.world[3], [1.0, 2.0, 0.0, 1.0]);
+    #[cfg(feature = "names")]
+    assert_eq!(scene.nodes[0].name.as_deref(), Some("parent"));
+
+    let primitive = &scene.meshes[0].primitives[0];
+    assert_eq!(primitive.attributes[0].semantic, Semantic::Positions);
+    assert_eq!(primitive.material, Some(0));
+    let bounds = primitive.bounding_box.as_ref().unwrap();
+    assert_eq!((bounds.min, bounds.max), ([-1.0; 3], [1.0; 3]));
+
+    let material = &scene.materials[0];
+    assert_eq!(material.alpha_mode, AlphaMode::Mask);
+    assert_eq!(material.alpha_cutoff, 0.5);
+    let base_color = material.base_color_texture.unwrap();
+    assert_eq!((base_color.image, base_color.tex_coord), (Some(0), 1));
+    assert_eq!(base_color.sampler.wrap_s, WrappingMode::ClampToEdge);
+    assert_eq!(material.occlusion_strength, 0.5);
+    assert!(material.normal_texture.is_none());
+
+    let animation = &scene.animations[0];
+    assert_eq!(animation.duration, 2.0);
+    assert_eq!(animation.channel_samplers, [0]);
+    assert!(animation.bindings.get(1).is_some());
+    let sampler = &animation.samplers[0];
+    assert_eq!(sampler.times, [0.0, 2.0]);
+    assert_eq!(sampler.values, [0.0, 2.0, 0.0, 1.0, 2.0, 3.0]);
+    assert_eq!(sampler.components, 3);
+}
+
+#[test]
+fn without_keyframes() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    assert!(gltf.snapshot(&[]).is_err());
+
+    let options = SnapshotOptions { keyframes: false };
+    let scene = gltf.snapshot_with(&[], options).unwrap();
+    let animation = &scene.animations[0];
+    assert_eq!(animation.duration, 2.0);
+    assert!(animation.samplers[0].times.is_empty());
+    assert!(animation.samplers[0].values.is_empty());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let scene = snapshot(SnapshotOptions::default());
+    let json = serde_json::to_string(&scene).unwrap();
+    let baked: OwnedScene = serde_json::from_str(&json).unwrap();
+    assert_eq!(baked, scene);
+}
@@ -0,0 +1,75 @@
+use gltf::fault::EntityKind;
+use gltf::{Error, Gltf};
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "nodes": [
+        {"name": "root", "children": [1]},
+        {"mesh": 0}
+    ],
+    "meshes": [
+        {"primitives": [{"attributes": {"POSITION": 0}}]}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 1, 1]}
+    ],
+    "bufferViews": [{"buffer": 0, "byteLength": 36}],
+    "buffers": [{"byteLength": 36}]
+}"#;
+
+/// Returns the source text of a span.
+fn text(span: Option<std::ops::Range<usize>>) -> &'static str {
+    &GLTF[span.expect("no span")]
+}
+
+#[test]
+fn document_spans() {
+    let gltf = Gltf::from_slice_with_spans(GLTF.as_bytes()).unwrap();
+    assert_eq!(
+        text(gltf.source_span(EntityKind::Node, 0)),
+        r#"{"name": "root", "children": [1]}"#
+    );
+    assert_eq!(
+        text(gltf.source_span(EntityKind::Node, 1)),
+        r#"{"mesh": 0}"#
+    );
+    assert_eq!(
+        text(gltf.source_span(EntityKind::Primitive { mesh: 0 }, 0)),
+        r#"{"attributes": {"POSITION": 0}}"#
+    );
+    assert!(text(gltf.source_span(EntityKind::Accessor, 0)).contains("5126"));
+
+    // Spans are opt-in.
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    assert!(gltf.source_spans().is_empty());
+    assert_eq!(gltf.source_span(EntityKind::Node, 0), None);
+}
+
+#[test]
+fn validation_error_spans() {
+    let json = GLTF.replace(r#""mesh": 0"#, r#""mesh": 7"#);
+    let errors = match Gltf::from_slice_with_spans(json.as_bytes()) {
+        Err(Error::SpannedValidation(errors)) => errors,
+        other => panic!("unexpected result {:?}", other.map(|_| ())),
+    };
+    assert_eq!(errors.len(), 1);
+    let (path, _, span) = &errors[0];
+    assert_eq!(path.as_str(), "nodes[1].mesh");
+    assert_eq!(&json[span.clone().unwrap()], r#"{"mesh": 7}"#);
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn import_fault_spans() {
+    let json = GLTF.replace(r#""POSITION": 0"#, r#""POSITION": 3"#);
+    let (_, _, faults) = gltf::import_slice_resilient(json.as_bytes());
+    let fault = faults
+        .iter()
+        .find(|fault| fault.entity_kind == EntityKind::Primitive { mesh: 0 })
+        .unwrap();
+    assert_eq!(
+        &json[fault.span.clone().unwrap()],
+        r#"{"attributes": {"POSITION": 3}}"#
+    );
+}
@@ -0,0 +1,98 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use gltf::accessor::{self, AccessorLayoutCache, Iter};
+
+/// A triangle whose `POSITION` substitutes vertices 0 and 2 of its base
+/// values, and a second one with the same substitutions over zeros. Views 3
+/// and 4 hold the out-of-range sparse indices `[0, 3]` and the out-of-order
+/// ones `[2, 0]`.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 72, "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAAAAAAACAAAAoEAAAKBAAACgQAAAAAAAAABAAAAAAAAAAwACAAAA"}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 36},
+        {"buffer": 0, "byteOffset": 36, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 40, "byteLength": 24},
+        {"buffer": 0, "byteOffset": 64, "byteLength": 4},
+        {"buffer": 0, "byteOffset": 68, "byteLength": 4}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0, 0], "max": [5, 5, 5],
+         "sparse": {"count": 2,
+                    "indices": {"bufferView": 1, "componentType": 5123},
+                    "values": {"bufferView": 2}}},
+        {"componentType": 5126, "count": 3, "type": "VEC3",
+         "min": [0, 0, 0], "max": [5, 5, 5],
+         "sparse": {"count": 2,
+                    "indices": {"bufferView": 1, "componentType": 5123},
+                    "values": {"bufferView": 2}}}
+    ],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}, {"attributes": {"POSITION": 1}}]}]
+}"#;
+
+fn positions(gltf: &str) -> Vec<Option<Vec<[f32; 3]>>> {
+    let (document, buffers) = gltf::import_slice(gltf).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    let mesh = document.meshes().next().unwrap();
+    mesh.primitives()
+        .map(|primitive| {
+            let reader = primitive.reader(get_buffer_data);
+            reader.read_positions().map(Iterator::collect)
+        })
+        .collect()
+}
+
+#[test]
+fn sparse_positions_are_patched() {
+    assert_eq!(
+        positions(GLTF),
+        [
+            Some(vec![[5.0, 5.0, 5.0], [1.0, 0.0, 0.0], [0.0, 2.0, 0.0]]),
+            Some(vec![[5.0, 5.0, 5.0], [0.0, 0.0, 0.0], [0.0, 2.0, 0.0]]),
+        ]
+    );
+}
+
+#[test]
+fn invalid_sparse_indices_are_errors() {
+    for (view, position, index) in [(3, 1, 3), (4, 1, 0)] {
+        let gltf = GLTF.replace(
+            r#""indices": {"bufferView": 1"#,
+            &format!(r#""indices": {{"bufferView": {}"#, view),
+        );
+        assert_eq!(positions(&gltf), [None, None]);
+
+        let (document, buffers) = gltf::import_slice(&gltf).unwrap();
+        let get_buffer_data =
+            |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+        for accessor in document.accessors() {
+            let expected = accessor::Error::SparseIndex {
+                accessor: accessor.index(),
+                position,
+                index,
+            };
+            let result = Iter::<[f32; 3]>::try_new(accessor.clone(), get_buffer_data);
+            assert_eq!(result.err(), Some(expected));
+            let stats = gltf::analysis::accessor_stats(&accessor, &buffers);
+            assert_eq!(stats.err(), Some(expected));
+        }
+
+        let cache = AccessorLayoutCache::new(&document);
+        let mesh = document.meshes().next().unwrap();
+        for primitive in mesh.primitives() {
+            let reader = primitive.reader_cached(&cache, get_buffer_data);
+            assert!(reader.read_positions().is_none());
+        }
+        assert_eq!(cache.cached(), 0);
+    }
+    assert_eq!(
+        accessor::Error::SparseIndex {
+            accessor: 0,
+            position: 1,
+            index: 3
+        }
+        .to_string(),
+        "sparse index 1 of accessor 0 is 3, which is out of range or out of order"
+    );
+}
@@ -0,0 +1,76 @@
+use gltf::{Gltf, Warning};
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["KHR_materials_pbrSpecularGlossiness"],
+    "materials": [{
+        "extensions": {"KHR_materials_pbrSpecularGlossiness": {
+            "diffuseFactor": [0.5, 0.5, 0.5, 0.75],
+            "specularFactor": [0.04, 0.04, 0.04],
+            "glossinessFactor": 0.8
+        }}
+    }]
+}"#;
+
+#[test]
+fn deprecated_extension_is_reported() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let warning = Warning::DeprecatedExtension {
+        name: "KHR_materials_pbrSpecularGlossiness",
+    };
+    assert!(gltf.warnings().contains(&warning));
+    assert_eq!(
+        warning.to_string(),
+        "deprecated extension KHR_materials_pbrSpecularGlossiness is used"
+    );
+}
+
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+mod conversion {
+    use super::*;
+    use gltf::material::ConvertedPbr;
+
+    fn assert_close(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn dielectric() {
+        let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+        let material = gltf.materials().next().unwrap();
+        let converted = material
+            .pbr_specular_glossiness()
+            .unwrap()
+            .to_metallic_roughness();
+        assert_close(&converted.base_color_factor, &[0.5, 0.5, 0.5, 0.75]);
+        assert_close(
+            &[converted.metallic_factor, converted.roughness_factor],
+            &[0.0, 0.2],
+        );
+    }
+
+    #[test]
+    fn metal() {
+        let converted =
+            ConvertedPbr::from_specular_glossiness([0.0, 0.0, 0.0, 1.0], [0.9, 0.6, 0.2], 0.25);
+        assert_close(&converted.base_color_factor, &[0.9, 0.6, 0.2, 1.0]);
+        assert_close(
+            &[converted.metallic_factor, converted.roughness_factor],
+            &[1.0, 0.75],
+        );
+    }
+
+    #[test]
+    fn partially_metallic() {
+        let converted =
+            ConvertedPbr::from_specular_glossiness([0.3, 0.3, 0.3, 1.0], [0.5, 0.5, 0.5], 1.0);
+        assert!(converted.metallic_factor > 0.0 && converted.metallic_factor < 1.0);
+        assert!(converted
+            .base_color_factor
+            .iter()
+            .all(|&c| (0.0..=1.0).contains(&c)));
+    }
+}
@@ -0,0 +1,53 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+//! Run with `--no-default-features --features import,utils` to check that the
+//! minimal feature set still imports static meshes.
+
+use std::fs;
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "scene": 0,
+    "scenes": [{"nodes": [0, 1]}],
+    "nodes": [{"mesh": 0, "skin": 0}, {"camera": 0}],
+    "cameras": [{"type": "perspective", "perspective": {"yfov": 1, "znear": 0.1}}],
+    "skins": [{"joints": [1]}],
+    "animations": [{
+        "samplers": [{"input": 0, "output": 0}],
+        "channels": [{"sampler": 0, "target": {"node": 1, "path": "scale"}}]
+    }],
+    "meshes": [{"primitives": [{"attributes": {"POSITION": 1}}]}],
+    "buffers": [{"byteLength": 12}],
+    "bufferViews": [{"buffer": 0, "byteLength": 12}],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5126, "count": 3, "type": "SCALAR",
+         "min": [0], "max": [0]},
+        {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3",
+         "min": [0, 0, 0], "max": [0, 0, 0]}
+    ]
+}"#;
+
+#[test]
+fn import_glb() {
+    let data = fs::read("tests/box_sparse.glb").unwrap();
+    let (document, buffers) = gltf::import_slice(data).unwrap();
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    assert_eq!(reader.read_positions().unwrap().count(), 8);
+    assert_eq!(reader.read_indices().unwrap().into_u32().count(), 36);
+    assert_eq!(document.scenes().next().unwrap().nodes().count(), 1);
+}
+
+#[test]
+fn accept_disabled_arrays() {
+    let gltf = gltf::Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    assert_eq!(gltf.meshes().count(), 1);
+    assert_eq!(gltf.nodes().count(), 2);
+    #[cfg(feature = "animation")]
+    assert_eq!(gltf.animations().count(), 1);
+    #[cfg(feature = "cameras")]
+    assert!(gltf.nodes().nth(1).unwrap().camera().is_some());
+    #[cfg(feature = "skinning")]
+    assert!(gltf.nodes().next().unwrap().skin().is_some());
+}
@@ -0,0 +1,72 @@
+#![cfg(all(feature = "import", feature = "KHR_texture_basisu"))]
+
+use std::borrow::Cow;
+
+use gltf::Gltf;
+
+/// `texture_basisu.glb` has a texture whose KTX2 image 0 is in the `BIN`
+/// chunk, with the PNG image 1 as the fallback source.
+#[test]
+fn ktx2_images_are_imported() {
+    let Gltf { document, blob } =
+        Gltf::from_slice(&std::fs::read("tests/texture_basisu.glb").unwrap()).unwrap();
+    let texture = document.textures().next().unwrap();
+    assert_eq!(texture.source().map(|image| image.index()), Some(1));
+    assert_eq!(texture.basisu_source().map(|image| image.index()), Some(0));
+    assert_eq!(texture.source_any().map(|image| image.index()), Some(0));
+
+    let buffers = gltf::import_buffers(&document, blob).unwrap();
+    let images = gltf::import_images_raw(&document, &buffers).unwrap();
+    assert_eq!(images[0].mime_type, Some("image/ktx2"));
+    assert!(matches!(images[0].data, Cow::Borrowed(_)));
+    assert_eq!(images[0].data.len(), 36);
+    assert!(images[0].data.starts_with(b"\xABKTX 20\xBB\r\n\x1a\n"));
+    assert_eq!(images[1].mime_type, Some("image/png"));
+}
+
+#[test]
+fn textures_without_fallback_are_valid() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["KHR_texture_basisu"],
+        "extensionsRequired": ["KHR_texture_basisu"],
+        "textures": [{"extensions": {"KHR_texture_basisu": {"source": 0}}}],
+        "images": [{"uri": "data:;base64,q0tUWCAyMLsNChoK"}]
+    }"#;
+    let (document, buffers) = gltf::import_slice(json).unwrap();
+    let texture = document.textures().next().unwrap();
+    assert!(texture.source().is_none());
+    assert_eq!(texture.source_any().map(|image| image.index()), Some(0));
+    let images = gltf::import_images_raw(&document, &buffers).unwrap();
+    assert_eq!(images[0].mime_type, Some("image/ktx2"));
+
+    let json = json.replace(r#""source": 0"#, r#""source": 1"#);
+    assert!(Gltf::from_slice(json.as_bytes()).is_err());
+}
+
+#[test]
+fn ktx2_images_are_remapped() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["KHR_texture_basisu"],
+        "textures": [
+            {"source": 2, "extensions": {"KHR_texture_basisu": {"source": 0}}},
+            {"source": 2, "extensions": {"KHR_texture_basisu": {"source": 1}}}
+        ],
+        "images": [
+            {"bufferView": 0, "mimeType": "image/ktx2"},
+            {"bufferView": 0, "mimeType": "image/ktx2"},
+            {"bufferView": 0, "mimeType": "image/png"}
+        ],
+        "bufferViews": [{"buffer": 0, "byteLength": 4}],
+        "buffers": [{"byteLength": 4, "uri": "data:,ktx2"}]
+    }"#;
+    let (document, buffers) = gltf::import_slice(json).unwrap();
+    let mut root = document.into_json();
+    let deduped = gltf::transform::dedup_textures(&mut root, &buffers);
+    assert_eq!(deduped.images, [0, 0, 1]);
+    assert_eq!(root.images.len(), 2);
+    let basisu = root.textures[0].extensions.as_ref().unwrap();
+    assert_eq!(basisu.texture_basisu.as_ref().unwrap().source.value(), 0);
+    assert_eq!(root.textures[0].source.value(), 1);
+}
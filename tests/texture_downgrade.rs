@@ -0,0 +1,146 @@
+use gltf::analysis::{texture_downgrade_plan, DowngradeOptions, DowngradePlan};
+use gltf::transform::DocumentMut;
+use gltf::{buffer, Document, Gltf};
+
+/// Five 1024x1024 RGBA images, used as the base color, normal, occlusion and
+/// emissive textures of one material and the base color texture of another
+/// material tagged as protected, and an image stored elsewhere.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "materials": [
+        {
+            "pbrMetallicRoughness": {"baseColorTexture": {"index": 0}},
+            "normalTexture": {"index": 1},
+            "occlusionTexture": {"index": 2},
+            "emissiveTexture": {"index": 3}
+        },
+        {
+            "pbrMetallicRoughness": {"baseColorTexture": {"index": 4}},
+            "extras": {"protected": true}
+        }
+    ],
+    "textures": [
+        {"source": 0}, {"source": 1}, {"source": 2}, {"source": 3}, {"source": 4}
+    ],
+    "images": [
+        {"bufferView": 0, "mimeType": "image/png"},
+        {"bufferView": 1, "mimeType": "image/png"},
+        {"bufferView": 2, "mimeType": "image/png"},
+        {"bufferView": 3, "mimeType": "image/png"},
+        {"bufferView": 4, "mimeType": "image/png"},
+        {"uri": "elsewhere.png"}
+    ],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 26},
+        {"buffer": 0, "byteOffset": 28, "byteLength": 26},
+        {"buffer": 0, "byteOffset": 56, "byteLength": 26},
+        {"buffer": 0, "byteOffset": 84, "byteLength": 26},
+        {"buffer": 0, "byteOffset": 112, "byteLength": 26}
+    ],
+    "buffers": [{"byteLength": 140}]
+}"#;
+
+const MIB: u64 = 1 << 20;
+
+/// Returns the header of an 8-bit RGBA PNG image.
+fn png(width: u32, height: u32) -> Vec<u8> {
+    let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+    png.extend_from_slice(&width.to_be_bytes());
+    png.extend_from_slice(&height.to_be_bytes());
+    png.extend_from_slice(&[8, 6]);
+    png
+}
+
+fn load() -> (Document, Vec<buffer::Data>) {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let mut data = Vec::new();
+    for _ in 0..5 {
+        data.extend(png(1024, 1024));
+        data.extend([0, 0]);
+    }
+    (gltf.document, vec![buffer::Data(data)])
+}
+
+fn plan(document: &Document, buffers: &[buffer::Data], budget: u64) -> DowngradePlan {
+    let options = DowngradeOptions {
+        mipmaps: false,
+        ..Default::default()
+    };
+    texture_downgrade_plan(document, buffers, budget, options)
+}
+
+#[test]
+fn budget_is_met() {
+    let (document, buffers) = load();
+    let plan = plan(&document, &buffers, 14 * MIB);
+    assert_eq!(plan.original_bytes, 20 * MIB);
+    assert!(plan.fits);
+    assert!(plan.planned_bytes <= 14 * MIB);
+    assert_eq!(plan.unknown, [5]);
+    let total: u64 = plan.entries.iter().map(|entry| entry.bytes).sum::<u64>()
+        + 4 * MIB * (5 - plan.entries.len() as u64);
+    assert_eq!(total, plan.planned_bytes);
+
+    // The emissive texture is protected by default.
+    assert!(plan.entries.iter().all(|entry| entry.image != 3));
+    // Occlusion is reduced the most and the normal map the least.
+    let size = |image| {
+        let entry = plan.entries.iter().find(|entry| entry.image == image);
+        entry.map_or(1024, |entry| entry.target.width)
+    };
+    assert!(size(2) <= size(0) && size(0) <= size(1));
+    assert!(size(2) < 1024);
+
+    // Nothing fits when every image would have to be smaller than the
+    // minimum size.
+    let plan = self::plan(&document, &buffers, MIB);
+    assert!(!plan.fits);
+    assert!(plan
+        .entries
+        .iter()
+        .all(|entry| entry.target.width.max(entry.target.height) >= 64));
+}
+
+#[test]
+fn applying_the_plan() {
+    let (document, buffers) = load();
+    let plan = plan(&document, &buffers, 14 * MIB);
+    assert!(!plan.entries.is_empty());
+    let mut document = DocumentMut::new(document, buffers);
+    for entry in plan.entries.iter() {
+        let data = png(entry.target.width, entry.target.height);
+        document
+            .replace_image(entry.image, data, "image/png")
+            .unwrap();
+    }
+    let buffers = document.buffers;
+    let document = Document::from_json(document.root).unwrap();
+    let applied = self::plan(&document, &buffers, 14 * MIB);
+    assert!(applied.entries.is_empty());
+    assert_eq!(applied.original_bytes, plan.planned_bytes);
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn tagged_textures_are_protected() {
+    let (document, buffers) = load();
+    let plan = plan(&document, &buffers, 10 * MIB);
+    assert!(plan.planned_bytes <= 10 * MIB);
+    assert!(plan
+        .entries
+        .iter()
+        .all(|entry| entry.image != 3 && entry.image != 4));
+
+    let plan = texture_downgrade_plan(
+        &document,
+        &buffers,
+        10 * MIB,
+        DowngradeOptions {
+            protect_emissive: false,
+            protect_tag: "other",
+            ..Default::default()
+        },
+    );
+    assert!(plan.entries.iter().any(|entry| entry.image == 3));
+    assert!(plan.entries.iter().any(|entry| entry.image == 4));
+}
@@ -0,0 +1,61 @@
+use gltf::texture::{self, WrappingMode};
+use gltf::{Error, Gltf};
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["KHR_texture_basisu"],
+    "images": [{"uri": "image.png"}],
+    "samplers": [{"wrapS": 33071}],
+    "textures": [
+        {"source": 0},
+        {"source": 0, "sampler": 5},
+        {"extensions": {"KHR_texture_basisu": {"source": 0}}},
+        {"source": 3, "sampler": 0}
+    ]
+}"#;
+
+#[test]
+fn references_resolve_without_panicking() {
+    let gltf = Gltf::from_slice_without_validation(GLTF.as_bytes()).unwrap();
+    let textures: Vec<_> = gltf.textures().collect();
+
+    // A texture without a sampler uses the default one.
+    let sampler = textures[0].sampler();
+    assert_eq!(sampler.index(), None);
+    assert_eq!(sampler.wrap_s(), WrappingMode::Repeat);
+    assert_eq!(sampler.wrap_t(), WrappingMode::Repeat);
+    assert_eq!((sampler.mag_filter(), sampler.min_filter()), (None, None));
+    assert_eq!(textures[0].source().unwrap().index(), 0);
+
+    assert_eq!(
+        textures[1].try_sampler().unwrap_err(),
+        texture::Error::SamplerOutOfRange {
+            texture: 1,
+            sampler: 5
+        }
+    );
+    assert_eq!(textures[1].sampler().index(), None);
+
+    assert!(textures[2].source().is_none());
+    assert!(textures[2].try_source().unwrap().is_none());
+
+    assert_eq!(textures[3].sampler().index(), Some(0));
+    assert_eq!(textures[3].sampler().wrap_s(), WrappingMode::ClampToEdge);
+    assert!(textures[3].source().is_none());
+    assert_eq!(
+        textures[3].try_source().unwrap_err().to_string(),
+        "texture 3 refers to image 3, which does not exist"
+    );
+}
+
+#[test]
+fn dangling_references_fail_validation() {
+    let paths: Vec<String> = match Gltf::from_slice(GLTF.as_bytes()) {
+        Err(Error::Validation(errors)) => errors.iter().map(|(path, _)| path.to_string()).collect(),
+        other => panic!("{:?}", other.map(|_| ())),
+    };
+    assert!(paths.contains(&"textures[1].sampler".to_string()));
+    assert!(paths.contains(&"textures[3].source".to_string()));
+    let empty_source = paths.contains(&"textures[2].source".to_string());
+    assert_eq!(empty_source, !cfg!(feature = "allow_empty_texture"));
+}
@@ -0,0 +1,72 @@
+#![cfg(all(feature = "import", feature = "EXT_texture_webp"))]
+
+use gltf::Gltf;
+
+/// A texture whose WebP image 0 has no MIME type, with the PNG image 1 as
+/// the fallback source.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["EXT_texture_webp"],
+    "extensionsRequired": ["EXT_texture_webp"],
+    "textures": [{"source": 1, "extensions": {"EXT_texture_webp": {"source": 0}}}],
+    "images": [
+        {"uri": "data:;base64,UklGRgQAAABXRUJQ"},
+        {"uri": "data:image/png;base64,iVBORw0KGgo="}
+    ]
+}"#;
+
+#[test]
+fn webp_images_are_imported() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    let texture = document.textures().next().unwrap();
+    assert_eq!(texture.source().map(|image| image.index()), Some(1));
+    assert_eq!(texture.webp_source().map(|image| image.index()), Some(0));
+    assert_eq!(texture.source_any().map(|image| image.index()), Some(0));
+
+    let images = gltf::import_images_raw(&document, &buffers).unwrap();
+    assert_eq!(images[0].mime_type, Some("image/webp"));
+    assert_eq!(&images[0].data[..], b"RIFF\x04\0\0\0WEBP");
+    assert_eq!(images[1].mime_type, Some("image/png"));
+}
+
+#[test]
+fn textures_without_fallback_are_valid() {
+    let json = GLTF.replace(r#""source": 1, "#, "");
+    let document = Gltf::from_slice(json.as_bytes()).unwrap().document;
+    let texture = document.textures().next().unwrap();
+    assert!(texture.source().is_none());
+    assert_eq!(texture.source_any().map(|image| image.index()), Some(0));
+
+    let json = GLTF.replace(r#"{"source": 0}"#, r#"{"source": 2}"#);
+    assert!(Gltf::from_slice(json.as_bytes()).is_err());
+}
+
+#[cfg(feature = "guess_mime_type")]
+#[test]
+fn mislabelled_webp_images_are_sniffed() {
+    let json = GLTF.replace(
+        "data:;base64,UklGRgQAAABXRUJQ",
+        "data:image/png;base64,UklGRgQAAABXRUJQ",
+    );
+    let (document, buffers) = gltf::import_slice(json).unwrap();
+    let images = gltf::import_images_raw(&document, &buffers).unwrap();
+    assert_eq!(images[0].mime_type, Some("image/webp"));
+    assert_eq!(images[1].mime_type, Some("image/png"));
+}
+
+#[test]
+fn webp_images_are_remapped() {
+    let document = Gltf::from_slice(GLTF.as_bytes()).unwrap().document;
+    let mut root = document.into_json();
+    root.images.swap(0, 1);
+    root.textures[0].source = gltf::json::Index::new(0);
+    let webp = root.textures[0].extensions.as_mut().unwrap();
+    webp.texture_webp.as_mut().unwrap().source = gltf::json::Index::new(1);
+    gltf::transform::canonicalize(&mut root, &mut Vec::new());
+    let webp = root.textures[0].extensions.as_ref().unwrap();
+    let webp = webp.texture_webp.as_ref().unwrap().source.value();
+    assert_eq!(
+        root.images[webp].uri.as_deref(),
+        Some("data:;base64,UklGRgQAAABXRUJQ")
+    );
+}
@@ -0,0 +1,95 @@
+#![cfg(all(feature = "import", feature = "utils"))]
+
+/// Two triangle strips over a zig-zag of eight vertices in the XY plane,
+/// whose first triangle is counter-clockwise, joined by degenerate triangles,
+/// and the same indices as a triangle list, a fan and lines. The last
+/// primitives have no indices.
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "buffers": [{"byteLength": 116, "uri": "data:application/octet-stream;base64,AAABAAIAAwADAAQABAAFAAYABwAAAAAAAAAAAAAAAAAAAIA/AAAAAAAAAAAAAAAAAACAPwAAAAAAAIA/AACAPwAAAAAAAAAAAAAAQAAAAAAAAIA/AAAAQAAAAAAAAAAAAABAQAAAAAAAAIA/AABAQAAAAAA="}],
+    "bufferViews": [
+        {"buffer": 0, "byteLength": 20},
+        {"buffer": 0, "byteOffset": 20, "byteLength": 96}
+    ],
+    "accessors": [
+        {"bufferView": 0, "componentType": 5123, "count": 10, "type": "SCALAR"},
+        {"bufferView": 1, "componentType": 5126, "count": 8, "type": "VEC3",
+         "min": [0, 0, 0], "max": [1, 3, 0]}
+    ],
+    "meshes": [{"primitives": [
+        {"attributes": {"POSITION": 1}, "indices": 0, "mode": 5},
+        {"attributes": {"POSITION": 1}, "indices": 0},
+        {"attributes": {"POSITION": 1}, "mode": 5},
+        {"attributes": {"POSITION": 1}, "mode": 6},
+        {"attributes": {"POSITION": 1}, "indices": 0, "mode": 3}
+    ]}]
+}"#;
+
+#[test]
+fn triangles_of_all_modes() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    let mesh = document.meshes().next().unwrap();
+    let triangles: Vec<_> = mesh
+        .primitives()
+        .map(|primitive| {
+            let reader = primitive.reader(get_buffer_data);
+            let triangles = primitive.triangles(&reader)?;
+            assert_eq!(triangles.len(), triangles.clone().count());
+            Some(triangles.collect::<Vec<_>>())
+        })
+        .collect();
+    assert_eq!(
+        triangles,
+        [
+            Some(vec![
+                [0, 1, 2],
+                [1, 3, 2],
+                [2, 3, 3],
+                [3, 4, 3],
+                [3, 4, 4],
+                [4, 5, 4],
+                [4, 5, 6],
+                [5, 7, 6]
+            ]),
+            Some(vec![[0, 1, 2], [3, 3, 4], [4, 5, 6]]),
+            Some(vec![
+                [0, 1, 2],
+                [1, 3, 2],
+                [2, 3, 4],
+                [3, 5, 4],
+                [4, 5, 6],
+                [5, 7, 6]
+            ]),
+            Some(vec![
+                [1, 2, 0],
+                [2, 3, 0],
+                [3, 4, 0],
+                [4, 5, 0],
+                [5, 6, 0],
+                [6, 7, 0]
+            ]),
+            None
+        ]
+    );
+}
+
+#[test]
+fn strips_keep_their_winding() {
+    let (document, buffers) = gltf::import_slice(GLTF).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|data| &data[..]);
+    let mesh = document.meshes().next().unwrap();
+    for primitive in mesh
+        .primitives()
+        .filter(|p| p.mode() == gltf::mesh::Mode::TriangleStrip)
+    {
+        let reader = primitive.reader(get_buffer_data);
+        let positions: Vec<_> = reader.read_positions().unwrap().collect();
+        for [a, b, c] in primitive.triangles(&reader).unwrap() {
+            let [a, b, c] = [a, b, c].map(|i| positions[i as usize]);
+            let area = (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+            // Degenerate triangles have no area; all others face +Z.
+            assert!(area >= 0.0, "{:?} is clockwise", [a, b, c]);
+        }
+    }
+}
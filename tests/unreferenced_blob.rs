@@ -0,0 +1,65 @@
+#![cfg(feature = "import")]
+
+use std::fs;
+
+use gltf::Warning;
+
+fn glb(json: &str, bin: &[u8]) -> Vec<u8> {
+    let mut json = json.as_bytes().to_vec();
+    while json.len() % 4 != 0 {
+        json.push(b' ');
+    }
+    let length = 12 + 8 + json.len() + 8 + bin.len();
+    let mut data = Vec::with_capacity(length);
+    data.extend_from_slice(b"glTF");
+    data.extend_from_slice(&2u32.to_le_bytes());
+    data.extend_from_slice(&(length as u32).to_le_bytes());
+    data.extend_from_slice(&(json.len() as u32).to_le_bytes());
+    data.extend_from_slice(b"JSON");
+    data.extend_from_slice(&json);
+    data.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+    data.extend_from_slice(b"BIN\0");
+    data.extend_from_slice(bin);
+    data
+}
+
+#[test]
+fn vestigial_bin_chunk() {
+    let json = r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [
+            {"byteLength": 4, "uri": "data:application/octet-stream;base64,AQIDBA=="}
+        ]
+    }"#;
+    let data = glb(json, &[0xFF; 8]);
+    let gltf = gltf::Gltf::from_slice(&data).unwrap();
+    assert_eq!(gltf.unreferenced_blob(), Some(&[0xFF; 8][..]));
+    assert_eq!(gltf.warnings(), [Warning::UnreferencedBlob { length: 8 }]);
+
+    let (_, buffers) = gltf::import_slice(&data).unwrap();
+    assert_eq!(buffers.len(), 1);
+    assert_eq!(&*buffers[0], &[1, 2, 3, 4]);
+}
+
+#[test]
+fn custom_payload() {
+    let json = r#"{"asset": {"version": "2.0"}}"#;
+    let data = glb(json, b"ENGINEDATA\0\0");
+    let gltf = gltf::Gltf::from_slice(&data).unwrap();
+    assert_eq!(gltf.unreferenced_blob(), Some(&b"ENGINEDATA\0\0"[..]));
+    assert_eq!(
+        gltf.warnings()[0].to_string(),
+        "binary chunk of 12 bytes is not referenced by any buffer"
+    );
+
+    let (_, buffers) = gltf::import_slice(&data).unwrap();
+    assert!(buffers.is_empty());
+}
+
+#[test]
+fn referenced_bin_chunk() {
+    let data = fs::read("tests/box_sparse.glb").unwrap();
+    let gltf = gltf::Gltf::from_slice(&data).unwrap();
+    assert_eq!(gltf.unreferenced_blob(), None);
+    assert!(gltf.warnings().is_empty());
+}
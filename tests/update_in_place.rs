@@ -0,0 +1,116 @@
+#![cfg(feature = "import")]
+
+use gltf::fault::EntityKind;
+use gltf::UpdateReport;
+
+/// Builds binary glTF from a JSON chunk and a `BIN` chunk.
+fn glb(json: &str, bin: &[u8]) -> Vec<u8> {
+    let mut json = json.as_bytes().to_vec();
+    while json.len() % 4 != 0 {
+        json.push(b' ');
+    }
+    let mut bin = bin.to_vec();
+    while bin.len() % 4 != 0 {
+        bin.push(0);
+    }
+    let length = 12 + 8 + json.len() + 8 + bin.len();
+    let mut glb = Vec::with_capacity(length);
+    glb.extend_from_slice(b"glTF");
+    glb.extend_from_slice(&2u32.to_le_bytes());
+    glb.extend_from_slice(&(length as u32).to_le_bytes());
+    glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+    glb.extend_from_slice(b"JSON");
+    glb.extend_from_slice(&json);
+    glb.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+    glb.extend_from_slice(b"BIN\0");
+    glb.extend_from_slice(&bin);
+    glb
+}
+
+fn asset(roughness: f32, translation: f32, nodes: usize) -> String {
+    let nodes: Vec<_> = (0..nodes)
+        .map(|i| format!(r#"{{"translation": [{}, 0, 0]}}"#, translation + i as f32))
+        .collect();
+    format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 16}}],
+            "materials": [
+                {{"pbrMetallicRoughness": {{"roughnessFactor": {}}}}},
+                {{"name": "unchanged"}}
+            ],
+            "nodes": [{}]
+        }}"#,
+        roughness,
+        nodes.join(",")
+    )
+}
+
+#[test]
+fn patch_materials_nodes_and_bytes() {
+    let bin: Vec<u8> = (0..16).collect();
+    let mut existing = gltf::import_slice(glb(&asset(0.5, 0.0, 2), &bin)).unwrap();
+    let allocation = existing.1[0].0.as_ptr();
+
+    let mut changed = bin.clone();
+    changed[2] = 100;
+    changed[3] = 101;
+    changed[9] = 102;
+    let report =
+        gltf::update_in_place(&mut existing, &glb(&asset(0.25, 0.0, 2), &changed)).unwrap();
+    assert_eq!(report.reload, None);
+    assert_eq!(report.materials, [0]);
+    assert!(report.nodes.is_empty());
+    assert_eq!(report.ranges.get(0), [2..4, 9..10]);
+
+    let (document, buffers) = &existing;
+    let material = document.materials().next().unwrap();
+    assert_eq!(material.pbr_metallic_roughness().roughness_factor(), 0.25);
+    assert_eq!(buffers[0].0, changed);
+    assert_eq!(buffers[0].0.as_ptr(), allocation);
+
+    let report =
+        gltf::update_in_place(&mut existing, &glb(&asset(0.25, 3.0, 2), &changed)).unwrap();
+    assert_eq!(report.nodes, [0, 1]);
+    assert!(report.materials.is_empty() && report.ranges.get(0).is_empty());
+    let node = existing.0.nodes().nth(1).unwrap();
+    assert_eq!(node.transform().decomposed().0, [4.0, 0.0, 0.0]);
+
+    let unchanged = gltf::update_in_place(&mut existing, &glb(&asset(0.25, 3.0, 2), &changed));
+    assert_eq!(unchanged.unwrap(), UpdateReport::default());
+}
+
+#[test]
+fn structural_changes_reload() {
+    let bin = [0; 16];
+    let mut existing = gltf::import_slice(glb(&asset(0.5, 0.0, 2), &bin)).unwrap();
+    let report = gltf::update_in_place(&mut existing, &glb(&asset(0.5, 0.0, 3), &bin)).unwrap();
+    assert_eq!(report.reload, Some(EntityKind::Node));
+    assert!(report.materials.is_empty() && report.nodes.is_empty());
+    assert_eq!(existing.0.nodes().len(), 3);
+
+    let longer = asset(0.5, 0.0, 3).replace("16", "20");
+    let report = gltf::update_in_place(&mut existing, &glb(&longer, &[0; 20])).unwrap();
+    assert_eq!(report.reload, Some(EntityKind::Buffer));
+    assert_eq!(existing.1[0].0.len(), 20);
+
+    // Errors leave the existing asset untouched.
+    assert!(gltf::update_in_place(&mut existing, b"glTF").is_err());
+    assert_eq!(existing.1[0].0.len(), 20);
+}
+
+#[test]
+fn source_spans_follow_the_new_json() {
+    let bin: Vec<u8> = (0..16).collect();
+    let gltf = gltf::Gltf::from_slice_with_spans(&glb(&asset(0.5, 0.0, 2), &bin)).unwrap();
+    let buffers = gltf::import_buffers(&gltf.document, gltf.blob).unwrap();
+    let mut existing = (gltf.document, buffers);
+
+    let json = asset(0.125, 10.0, 2);
+    let report = gltf::update_in_place(&mut existing, &glb(&json, &bin)).unwrap();
+    assert_eq!(report.nodes, [0, 1]);
+    let span = existing.0.source_span(EntityKind::Node, 1).unwrap();
+    assert_eq!(&json[span], r#"{"translation": [11, 0, 0]}"#);
+    let span = existing.0.source_span(EntityKind::Material, 1).unwrap();
+    assert_eq!(&json[span], r#"{"name": "unchanged"}"#);
+}
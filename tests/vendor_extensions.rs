@@ -0,0 +1,168 @@
+#![cfg(all(feature = "extensions", feature = "extras"))]
+
+use gltf::{Gltf, HasExtensions};
+use serde::Deserialize;
+
+const GLTF: &str = r#"{
+    "asset": {"version": "2.0"},
+    "extensionsUsed": ["EXT_studio_tags", "EXT_studio_physics"],
+    "extensions": {"EXT_studio_tags": {"tags": ["level"]}},
+    "extras": {"build": 17},
+    "scenes": [{"nodes": [0], "extensions": {"EXT_studio_tags": {"tags": ["main"]}},
+                "extras": {"lighting": "dusk"}}],
+    "nodes": [
+        {"mesh": 0, "extensions": {"EXT_studio_tags": {"tags": ["crate", "prop"]}},
+         "extras": {"spawn":  true}},
+        {"extensions": {"EXT_studio_tags": {"tags": "not a list"}}}
+    ],
+    "meshes": [{
+        "primitives": [{
+            "attributes": {"POSITION": 0},
+            "material": 0,
+            "extensions": {"EXT_studio_physics": {"mass": 0.5, "shape": "hull"}},
+            "extras": [1, 2]
+        }],
+        "extensions": {"EXT_studio_physics": {"mass": 2.0}},
+        "extras": {"lod": 0}
+    }],
+    "materials": [{
+        "pbrMetallicRoughness": {"baseColorTexture": {"index": 0}},
+        "extensions": {"EXT_studio_tags": {"tags": ["wood"]}},
+        "extras": "oak"
+    }],
+    "textures": [{"source": 0, "extensions": {"EXT_studio_tags": {"tags": ["grain"]}},
+                  "extras": {"tiling": 2.50}}],
+    "images": [{"uri": "wood.png"}],
+    "accessors": [{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+                   "min": [0, 0, 0], "max": [0, 0, 0]}],
+    "bufferViews": [{"buffer": 0, "byteLength": 36}],
+    "buffers": [{"byteLength": 36}]
+}"#;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Tags<'a> {
+    #[serde(borrow)]
+    tags: Vec<&'a str>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Physics {
+    mass: f32,
+    #[serde(default)]
+    shape: Option<String>,
+}
+
+/// Returns the tags of any entity with extensions.
+fn tags(entity: &impl HasExtensions) -> Option<Vec<&str>> {
+    entity
+        .extension_as::<Tags>("EXT_studio_tags")
+        .map(|tags| tags.unwrap().tags)
+}
+
+#[test]
+fn typed_extensions() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let document = &gltf.document;
+    let scene = document.scenes().next().unwrap();
+    let node = document.nodes().next().unwrap();
+    let mesh = node.mesh().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let material = primitive.material();
+
+    assert_eq!(tags(document), Some(vec!["level"]));
+    assert_eq!(tags(&scene), Some(vec!["main"]));
+    assert_eq!(tags(&node), Some(vec!["crate", "prop"]));
+    assert_eq!(tags(&material), Some(vec!["wood"]));
+    let texture = material
+        .pbr_metallic_roughness()
+        .base_color_texture()
+        .unwrap()
+        .texture();
+    assert_eq!(tags(&texture), Some(vec!["grain"]));
+    assert_eq!(tags(&mesh), None);
+
+    let physics = mesh.extension_as::<Physics>("EXT_studio_physics");
+    assert_eq!(
+        physics.unwrap().unwrap(),
+        Physics {
+            mass: 2.0,
+            shape: None
+        }
+    );
+    let physics = primitive.extension_as::<Physics>("EXT_studio_physics");
+    assert_eq!(
+        physics.unwrap().unwrap(),
+        Physics {
+            mass: 0.5,
+            shape: Some("hull".into())
+        }
+    );
+}
+
+#[test]
+fn mismatched_extension() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let node = gltf.nodes().nth(1).unwrap();
+    assert!(node
+        .extension_as::<Tags>("EXT_studio_tags")
+        .unwrap()
+        .is_err());
+    assert!(node.extension_as::<Physics>("EXT_studio_physics").is_none());
+
+    // The root of the document is reached through the `Gltf` wrapper too.
+    assert!(gltf
+        .extension_as::<Tags>("EXT_studio_tags")
+        .unwrap()
+        .is_ok());
+}
+
+#[test]
+fn raw_extension_values() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let node = gltf.nodes().next().unwrap();
+    let mesh = node.mesh().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    assert_eq!(
+        node.extension_value("EXT_studio_tags"),
+        Some(&serde_json::json!({"tags": ["crate", "prop"]}))
+    );
+    assert_eq!(
+        primitive.extension_value("EXT_studio_physics"),
+        Some(&serde_json::json!({"mass": 0.5, "shape": "hull"}))
+    );
+    assert_eq!(mesh.extension_value("EXT_studio_tags"), None);
+    let texture = gltf.textures().next().unwrap();
+    assert!(texture.extension_value("EXT_studio_tags").is_some());
+    assert!(gltf.extension_value("EXT_studio_tags").is_some());
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn raw_extras() {
+    let gltf = Gltf::from_slice(GLTF.as_bytes()).unwrap();
+    let raw = |extras: &gltf::json::Extras| extras.as_ref().map(|raw| raw.get().to_owned());
+    let node = gltf.nodes().next().unwrap();
+    let mesh = node.mesh().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let scene = gltf.scenes().next().unwrap();
+
+    // The bytes of the JSON are preserved as written.
+    assert_eq!(raw(gltf.extras()).as_deref(), Some(r#"{"build": 17}"#));
+    assert_eq!(
+        raw(scene.extras()).as_deref(),
+        Some(r#"{"lighting": "dusk"}"#)
+    );
+    assert_eq!(raw(node.extras()).as_deref(), Some(r#"{"spawn":  true}"#));
+    assert_eq!(raw(mesh.extras()).as_deref(), Some(r#"{"lod": 0}"#));
+    assert_eq!(raw(primitive.extras()).as_deref(), Some("[1, 2]"));
+    assert_eq!(
+        raw(primitive.material().extras()).as_deref(),
+        Some(r#""oak""#)
+    );
+    let texture = gltf.textures().next().unwrap();
+    assert_eq!(
+        raw(texture.extras()).as_deref(),
+        Some(r#"{"tiling": 2.50}"#)
+    );
+    assert_eq!(raw(gltf.nodes().nth(1).unwrap().extras()), None);
+}